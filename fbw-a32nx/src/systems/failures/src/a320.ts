@@ -36,6 +36,14 @@ export const A320Failure = Object.freeze({
   Generator1: 24020,
   Generator2: 24021,
   ApuGenerator1: 24030,
+  Idg1Disconnect: 24040,
+  Idg2Disconnect: 24041,
+  Battery1ThermalRunaway: 24050,
+  Battery2ThermalRunaway: 24051,
+  Generator1VoltageRegulator: 24060,
+  Generator2VoltageRegulator: 24061,
+  Idg1Governor: 24070,
+  Idg2Governor: 24071,
   AlternatingCurrent1: 24100,
   AlternatingCurrent2: 24101,
   AlternatingCurrentEssential: 24102,
@@ -51,6 +59,19 @@ export const A320Failure = Object.freeze({
   DirectCurrentHot2: 24112,
   DirectCurrentGndFltService: 24113,
 
+  Engine1Fire: 26001,
+  Engine2Fire: 26002,
+  APUFire: 26003,
+  Engine1LoopA: 26004,
+  Engine1LoopB: 26005,
+  Engine2LoopA: 26006,
+  Engine2LoopB: 26007,
+  APULoopA: 26008,
+  APULoopB: 26009,
+  Lavatory1Smoke: 26010,
+  Lavatory2Smoke: 26011,
+  Lavatory3Smoke: 26012,
+
   Elac1Failure: 27000,
   Elac2Failure: 27001,
   Sec1Failure: 27002,
@@ -58,6 +79,35 @@ export const A320Failure = Object.freeze({
   Sec3Failure: 27004,
   Fcdc1Failure: 27005,
   Fcdc2Failure: 27006,
+  LeftAileronActuator1Jam: 27100,
+  LeftAileronActuator2Jam: 27101,
+  RightAileronActuator1Jam: 27102,
+  RightAileronActuator2Jam: 27103,
+  LeftElevatorActuator1Jam: 27104,
+  LeftElevatorActuator2Jam: 27105,
+  RightElevatorActuator1Jam: 27106,
+  RightElevatorActuator2Jam: 27107,
+  RudderActuator1Jam: 27108,
+  RudderActuator2Jam: 27109,
+  RudderActuator3Jam: 27110,
+  LeftAileronActuator1Runaway: 27120,
+  LeftAileronActuator2Runaway: 27121,
+  RightAileronActuator1Runaway: 27122,
+  RightAileronActuator2Runaway: 27123,
+  LeftElevatorActuator1Runaway: 27124,
+  LeftElevatorActuator2Runaway: 27125,
+  RightElevatorActuator1Runaway: 27126,
+  RightElevatorActuator2Runaway: 27127,
+  RudderActuator1Runaway: 27128,
+  RudderActuator2Runaway: 27129,
+  RudderActuator3Runaway: 27130,
+
+  CenterTankLeftPump: 28001,
+  LeftInnerTankPump1: 28002,
+  RightInnerTankPump1: 28003,
+  CenterTankRightPump: 28004,
+  LeftInnerTankPump2: 28005,
+  RightInnerTankPump2: 28006,
 
   GreenReservoirLeak: 29000,
   BlueReservoirLeak: 29001,
@@ -106,8 +156,39 @@ export const A320Failure = Object.freeze({
   YellowBrakeHydraulicLeak: 32101,
   YellowBrakeAccumulatorGasLeak: 32150,
 
+  TyreBurst1: 32200,
+  TyreBurst2: 32201,
+  TyreBurst3: 32202,
+  TyreBurst4: 32203,
+  TyreBurst5: 32204,
+  TyreBurst6: 32205,
+
   RadioAltimeter1: 34000,
   RadioAltimeter2: 34001,
+  GpsReceiver1: 34010,
+  GpsReceiver2: 34011,
+  Adiru1BusParityError: 34020,
+  Adiru2BusParityError: 34021,
+  Adiru3BusParityError: 34022,
+  Adiru1: 34030,
+  Adiru2: 34031,
+  Adiru3: 34032,
+  Adr1: 34040,
+  Adr2: 34041,
+  Adr3: 34042,
+  Ir1: 34050,
+  Ir2: 34051,
+  Ir3: 34052,
+  Egpwc: 34060,
+
+  Eng1PressureRegulatingValveStuck: 36000,
+  Eng2PressureRegulatingValveStuck: 36001,
+  Eng1PrecoolerFault: 36010,
+  Eng2PrecoolerFault: 36011,
+  Eng1BleedLeak: 36020,
+  Eng2BleedLeak: 36021,
+
+  ApuFault: 49000,
 });
 
 export const A320FailureDefinitions: FailureDefinition[] = [
@@ -142,6 +223,14 @@ export const A320FailureDefinitions: FailureDefinition[] = [
   [24, A320Failure.Generator1, 'Generator 1'],
   [24, A320Failure.Generator2, 'Generator 2'],
   [24, A320Failure.ApuGenerator1, 'APU Generator'],
+  [24, A320Failure.Idg1Disconnect, 'IDG 1 disconnect'],
+  [24, A320Failure.Idg2Disconnect, 'IDG 2 disconnect'],
+  [24, A320Failure.Battery1ThermalRunaway, 'Battery 1 thermal runaway'],
+  [24, A320Failure.Battery2ThermalRunaway, 'Battery 2 thermal runaway'],
+  [24, A320Failure.Generator1VoltageRegulator, 'Generator 1 voltage regulator'],
+  [24, A320Failure.Generator2VoltageRegulator, 'Generator 2 voltage regulator'],
+  [24, A320Failure.Idg1Governor, 'IDG 1 governor'],
+  [24, A320Failure.Idg2Governor, 'IDG 2 governor'],
   [24, A320Failure.AlternatingCurrent1, 'AC 1'],
   [24, A320Failure.AlternatingCurrent2, 'AC 2'],
   [24, A320Failure.AlternatingCurrentEssential, 'AC ESS'],
@@ -157,6 +246,19 @@ export const A320FailureDefinitions: FailureDefinition[] = [
   [24, A320Failure.DirectCurrentHot2, 'DC HOT 2'],
   [24, A320Failure.DirectCurrentGndFltService, 'DC GND FLT SRV'],
 
+  [26, A320Failure.Engine1Fire, 'Fire - Engine 1'],
+  [26, A320Failure.Engine2Fire, 'Fire - Engine 2'],
+  [26, A320Failure.APUFire, 'Fire - APU'],
+  [26, A320Failure.Engine1LoopA, 'Engine 1 Loop A'],
+  [26, A320Failure.Engine1LoopB, 'Engine 1 Loop B'],
+  [26, A320Failure.Engine2LoopA, 'Engine 2 Loop A'],
+  [26, A320Failure.Engine2LoopB, 'Engine 2 Loop B'],
+  [26, A320Failure.APULoopA, 'APU Loop A'],
+  [26, A320Failure.APULoopB, 'APU Loop B'],
+  [26, A320Failure.Lavatory1Smoke, 'Smoke - Lavatory 1'],
+  [26, A320Failure.Lavatory2Smoke, 'Smoke - Lavatory 2'],
+  [26, A320Failure.Lavatory3Smoke, 'Smoke - Lavatory 3'],
+
   [27, A320Failure.Elac1Failure, 'ELAC 1'],
   [27, A320Failure.Elac2Failure, 'ELAC 2'],
   [27, A320Failure.Sec1Failure, 'SEC 1'],
@@ -164,6 +266,35 @@ export const A320FailureDefinitions: FailureDefinition[] = [
   [27, A320Failure.Sec3Failure, 'SEC 3'],
   [27, A320Failure.Fcdc1Failure, 'FCDC 1'],
   [27, A320Failure.Fcdc2Failure, 'FCDC 2'],
+  [27, A320Failure.LeftAileronActuator1Jam, 'Left aileron actuator 1 jammed'],
+  [27, A320Failure.LeftAileronActuator2Jam, 'Left aileron actuator 2 jammed'],
+  [27, A320Failure.RightAileronActuator1Jam, 'Right aileron actuator 1 jammed'],
+  [27, A320Failure.RightAileronActuator2Jam, 'Right aileron actuator 2 jammed'],
+  [27, A320Failure.LeftElevatorActuator1Jam, 'Left elevator actuator 1 jammed'],
+  [27, A320Failure.LeftElevatorActuator2Jam, 'Left elevator actuator 2 jammed'],
+  [27, A320Failure.RightElevatorActuator1Jam, 'Right elevator actuator 1 jammed'],
+  [27, A320Failure.RightElevatorActuator2Jam, 'Right elevator actuator 2 jammed'],
+  [27, A320Failure.RudderActuator1Jam, 'Rudder actuator 1 jammed'],
+  [27, A320Failure.RudderActuator2Jam, 'Rudder actuator 2 jammed'],
+  [27, A320Failure.RudderActuator3Jam, 'Rudder actuator 3 jammed'],
+  [27, A320Failure.LeftAileronActuator1Runaway, 'Left aileron actuator 1 runaway'],
+  [27, A320Failure.LeftAileronActuator2Runaway, 'Left aileron actuator 2 runaway'],
+  [27, A320Failure.RightAileronActuator1Runaway, 'Right aileron actuator 1 runaway'],
+  [27, A320Failure.RightAileronActuator2Runaway, 'Right aileron actuator 2 runaway'],
+  [27, A320Failure.LeftElevatorActuator1Runaway, 'Left elevator actuator 1 runaway'],
+  [27, A320Failure.LeftElevatorActuator2Runaway, 'Left elevator actuator 2 runaway'],
+  [27, A320Failure.RightElevatorActuator1Runaway, 'Right elevator actuator 1 runaway'],
+  [27, A320Failure.RightElevatorActuator2Runaway, 'Right elevator actuator 2 runaway'],
+  [27, A320Failure.RudderActuator1Runaway, 'Rudder actuator 1 runaway'],
+  [27, A320Failure.RudderActuator2Runaway, 'Rudder actuator 2 runaway'],
+  [27, A320Failure.RudderActuator3Runaway, 'Rudder actuator 3 runaway'],
+
+  [28, A320Failure.CenterTankLeftPump, 'Center tank L pump'],
+  [28, A320Failure.LeftInnerTankPump1, 'L tank pump 1'],
+  [28, A320Failure.RightInnerTankPump1, 'R tank pump 1'],
+  [28, A320Failure.CenterTankRightPump, 'Center tank R pump'],
+  [28, A320Failure.LeftInnerTankPump2, 'L tank pump 2'],
+  [28, A320Failure.RightInnerTankPump2, 'R tank pump 2'],
 
   [29, A320Failure.GreenReservoirLeak, 'Green reservoir leak'],
   [29, A320Failure.BlueReservoirLeak, 'Blue reservoir leak'],
@@ -211,6 +342,37 @@ export const A320FailureDefinitions: FailureDefinition[] = [
   [32, A320Failure.YellowBrakeHydraulicLeak, 'Yellow brakes circuit leak'],
   [32, A320Failure.YellowBrakeAccumulatorGasLeak, 'Yellow brake accumulator gas leak'],
 
+  [32, A320Failure.TyreBurst1, 'Tyre 1 burst'],
+  [32, A320Failure.TyreBurst2, 'Tyre 2 burst'],
+  [32, A320Failure.TyreBurst3, 'Tyre 3 burst'],
+  [32, A320Failure.TyreBurst4, 'Tyre 4 burst'],
+  [32, A320Failure.TyreBurst5, 'Nose tyre 1 burst'],
+  [32, A320Failure.TyreBurst6, 'Nose tyre 2 burst'],
+
   [34, A320Failure.RadioAltimeter1, 'RA 1'],
   [34, A320Failure.RadioAltimeter2, 'RA 2'],
+  [34, A320Failure.GpsReceiver1, 'GPS 1'],
+  [34, A320Failure.GpsReceiver2, 'GPS 2'],
+  [34, A320Failure.Adiru1BusParityError, 'ADIRU 1 BUS PARITY ERROR'],
+  [34, A320Failure.Adiru2BusParityError, 'ADIRU 2 BUS PARITY ERROR'],
+  [34, A320Failure.Adiru3BusParityError, 'ADIRU 3 BUS PARITY ERROR'],
+  [34, A320Failure.Adiru1, 'ADIRU 1'],
+  [34, A320Failure.Adiru2, 'ADIRU 2'],
+  [34, A320Failure.Adiru3, 'ADIRU 3'],
+  [34, A320Failure.Adr1, 'ADR 1'],
+  [34, A320Failure.Adr2, 'ADR 2'],
+  [34, A320Failure.Adr3, 'ADR 3'],
+  [34, A320Failure.Ir1, 'IR 1'],
+  [34, A320Failure.Ir2, 'IR 2'],
+  [34, A320Failure.Ir3, 'IR 3'],
+  [34, A320Failure.Egpwc, 'EGPWC'],
+
+  [36, A320Failure.Eng1PressureRegulatingValveStuck, 'Engine 1 bleed pressure regulating valve stuck'],
+  [36, A320Failure.Eng2PressureRegulatingValveStuck, 'Engine 2 bleed pressure regulating valve stuck'],
+  [36, A320Failure.Eng1PrecoolerFault, 'Engine 1 precooler fault'],
+  [36, A320Failure.Eng2PrecoolerFault, 'Engine 2 precooler fault'],
+  [36, A320Failure.Eng1BleedLeak, 'Engine 1 bleed leak'],
+  [36, A320Failure.Eng2BleedLeak, 'Engine 2 bleed leak'],
+
+  [49, A320Failure.ApuFault, 'APU fault'],
 ];
@@ -787,7 +787,24 @@ void EngineControl_A32NX::updateFuel(double deltaTimeSeconds) {
 
   const FLOAT64 aircraftDevelopmentStateVar = msfsHandlerPtr->getAircraftDevelopmentStateVar();
 
-  if (uiFuelTamper && aircraftDevelopmentStateVar == 0) {
+  // The native fuel model of the systems simulation manages the tank quantities and feeds the
+  // engines and the APU itself. Only the fuel used is accumulated here.
+  if (simData.fuelNativeModelIsActive->getAsBool()) {
+    const double fuelBurn1 = (engine1PreFF + engine1FF) / 2 * deltaTimeHours;  // KG
+    const double fuelBurn2 = (engine2PreFF + engine2FF) / 2 * deltaTimeHours;  // KG
+
+    simData.enginePreFF[L]->set(engine1FF);
+    simData.enginePreFF[R]->set(engine2FF);
+
+    simData.engineFuelUsed[L]->set(simData.engineFuelUsed[L]->get() + fuelBurn1);
+    simData.engineFuelUsed[R]->set(simData.engineFuelUsed[R]->get() + fuelBurn2);
+
+    simData.fuelLeftPre->set(leftQuantity);          // in Pounds
+    simData.fuelRightPre->set(rightQuantity);        // in Pounds
+    simData.fuelAuxLeftPre->set(leftAuxQuantity);    // in Pounds
+    simData.fuelAuxRightPre->set(rightAuxQuantity);  // in Pounds
+    simData.fuelCenterPre->set(centerQuantity);      // in Pounds
+  } else if (uiFuelTamper && aircraftDevelopmentStateVar == 0) {
     simData.fuelLeftPre->set(fuelLeftPre);          // in Pounds
     simData.fuelRightPre->set(fuelRightPre);        // in Pounds
     simData.fuelAuxLeftPre->set(fuelAuxLeftPre);    // in Pounds
@@ -207,6 +207,7 @@ class FadecSimData_A32NX {
   NamedVariablePtr fuelAuxRightPre;  // Pounds
   NamedVariablePtr fuelCenterPre;    // Pounds
   NamedVariablePtr fuelLeftPre;      // Pounds
+  NamedVariablePtr fuelNativeModelIsActive;
   NamedVariablePtr fuelPumpState[2];
   NamedVariablePtr fuelRightPre;
   NamedVariablePtr packsState[2];
@@ -340,6 +341,8 @@ class FadecSimData_A32NX {
     fuelPumpState[R] = dm->make_named_var("A32NX_PUMP_STATE:2", UNITS.Number, AUTO_READ_WRITE);
     fuelRightPre     = dm->make_named_var("A32NX_FUEL_RIGHT_PRE", UNITS.Number, AUTO_READ_WRITE);
 
+    fuelNativeModelIsActive = dm->make_named_var("A32NX_FUEL_NATIVE_MODEL_IS_ACTIVE", UNITS.Number, AUTO_READ);
+
     thrustLimitType  = dm->make_named_var("A32NX_AUTOTHRUST_THRUST_LIMIT_TYPE", UNITS.Number, AUTO_READ);
     thrustLimitIdle  = dm->make_named_var("A32NX_AUTOTHRUST_THRUST_LIMIT_IDLE", UNITS.Number, AUTO_WRITE);
     thrustLimitClimb = dm->make_named_var("A32NX_AUTOTHRUST_THRUST_LIMIT_CLB", UNITS.Number, AUTO_WRITE);
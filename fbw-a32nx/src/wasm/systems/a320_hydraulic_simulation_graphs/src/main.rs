@@ -1,8 +1,4 @@
-use plotlib::page::Page;
-use plotlib::repr::Plot;
-use plotlib::style::LineStyle;
-use plotlib::view::ContinuousView;
-use std::time::Duration;
+use std::{io, path::Path, time::Duration};
 use systems::hydraulic::pumps::PumpCharacteristics;
 
 use systems::hydraulic::*;
@@ -29,8 +25,6 @@ use uom::si::{
 
 use a320_systems::hydraulic::A320HydraulicCircuitFactory;
 
-use rustplotlib::Figure;
-
 struct TestHydraulicCircuitController {
     should_open_fire_shutoff_valve: Vec<bool>,
 }
@@ -114,189 +108,48 @@ impl PowerTransferUnitController for TestPowerTransferUnitController {
 
 fn main() {
     println!("Launching hyd simulation...");
-    let path = "./src/systems/a320_hydraulic_simulation_graphs/";
-
-    blue_circuit_epump(path);
-}
-
-fn make_figure(h: &History) -> Figure {
-    use rustplotlib::{Axes2D, Line2D};
-
-    let mut all_axis: Vec<Option<Axes2D>> = Vec::new();
-
-    for (idx, cur_data) in h.data_vector.iter().enumerate() {
-        let mut curr_axis = Axes2D::new()
-            .add(
-                Line2D::new(h.name_vector[idx].as_str())
-                    .data(&h.time_vector, cur_data)
-                    .color("blue")
-                    // .marker("x")
-                    // .linestyle("--")
-                    .linewidth(1.0),
-            )
-            .xlabel("Time [sec]")
-            .ylabel(h.name_vector[idx].as_str())
-            .legend("best")
-            .xlim(0.0, *h.time_vector.last().unwrap());
-        // .ylim(-2.0, 2.0);
-
-        curr_axis = curr_axis.grid(true);
-        all_axis.push(Some(curr_axis));
-    }
-
-    Figure::new().subplots(all_axis.len() as u32, 1, all_axis)
-}
-
-/// History class to record a simulation
-struct History {
-    /// Simulation time starting from 0
-    time_vector: Vec<f64>,
-    /// Name of each var saved
-    name_vector: Vec<String>,
-    /// Vector data for each var saved
-    data_vector: Vec<Vec<f64>>,
-    _data_size: usize,
-}
-impl History {
-    fn new(names: Vec<String>) -> History {
-        History {
-            time_vector: Vec::new(),
-            name_vector: names.clone(),
-            data_vector: Vec::new(),
-            _data_size: names.len(),
-        }
-    }
-
-    /// Sets initialisation values of each data before first step
-    fn init(&mut self, start_time: f64, values: Vec<f64>) {
-        self.time_vector.push(start_time);
-        for v in values {
-            self.data_vector.push(vec![v]);
-        }
-    }
-
-    /// Updates all values and time vector
-    fn update(&mut self, delta_time: f64, values: Vec<f64>) {
-        self.time_vector
-            .push(self.time_vector.last().unwrap() + delta_time);
-        self.push_data(values);
-    }
-
-    fn push_data(&mut self, values: Vec<f64>) {
-        for (idx, v) in values.iter().enumerate() {
-            self.data_vector[idx].push(*v);
-        }
-    }
-
-    /// Builds a graph using rust crate plotlib
-    fn _show(self) {
-        let mut v = ContinuousView::new()
-            .x_range(0.0, *self.time_vector.last().unwrap())
-            .y_range(0.0, 3500.0)
-            .x_label("Time (s)")
-            .y_label("Value");
-
-        for cur_data in self.data_vector {
-            // Here build the 2 by Xsamples vector
-            let mut new_vector: Vec<(f64, f64)> = Vec::new();
-            for (idx, sample) in self.time_vector.iter().enumerate() {
-                new_vector.push((*sample, cur_data[idx]));
-            }
-
-            // We create our scatter plot from the data
-            let s1: Plot = Plot::new(new_vector).line_style(LineStyle::new().colour("#DD3355"));
-
-            v = v.add(s1);
-        }
-
-        // A page with a single view is then saved to an SVG file
-        Page::single(&v).save("scatter.svg").unwrap();
-    }
-
-    /// Builds a graph using matplotlib python backend. PYTHON REQUIRED AS WELL AS MATPLOTLIB PACKAGE
-    fn show_matplotlib(&self, figure_title: &str, path: &str) {
-        let fig = make_figure(self);
-
-        use rustplotlib::backend::Matplotlib;
-        use rustplotlib::Backend;
-        let mut mpl = Matplotlib::new().unwrap();
-        mpl.set_style("ggplot").unwrap();
-
-        fig.apply(&mut mpl).unwrap();
-
-        let mut final_filename: String = path.to_owned();
-        final_filename.push_str(figure_title);
-
-        let _result = mpl.savefig(&final_filename);
+    let path = Path::new("./src/systems/a320_hydraulic_simulation_graphs/");
 
-        mpl.wait().unwrap();
-    }
+    blue_circuit_epump(path).unwrap();
 }
 
-fn blue_circuit_epump(path: &str) {
-    let epump_names = vec!["Pump rpm".to_string(), "Pump displacement".to_string()];
-
-    let hyd_circuit_names = vec![
-        "Pump section Pressure".to_string(),
-        "System section Pressure".to_string(),
-        "Sections delta Pressure".to_string(),
-        "Pump section switch".to_string(),
-        "System section switch".to_string(),
-        "Accumulator fluid vol".to_string(),
-    ];
-
-    let reservoir_names = vec![
-        "Reservoir volume".to_string(),
-        "Pump section Pressure".to_string(),
-        "System section Pressure".to_string(),
-    ];
-
-    let mut hyd_circuit_history = History::new(hyd_circuit_names);
-    let mut reservoir_history = History::new(reservoir_names);
-    let mut pump_history = History::new(epump_names);
-
+fn blue_circuit_epump(path: &Path) -> io::Result<()> {
     let mut test_bed = SimulationTestBed::new(|context| {
         let hyd_loop = hydraulic_loop(context, HydraulicColor::Blue);
         let pump = electric_pump(context);
         A320SimpleMainElecHydraulicsTestAircraft::new(context, hyd_loop, pump)
     });
 
-    hyd_circuit_history.init(
-        0.0,
-        vec![
-            test_bed
-                .query(|a| a.hydraulic_circuit.pump_pressure(0))
-                .get::<psi>(),
-            test_bed.query(|a| a.hydraulic_circuit.system_section_pressure().get::<psi>()),
-            test_bed.query(|a| a.hydraulic_circuit.system_section_pressure().get::<psi>())
-                - test_bed
-                    .query(|a| a.hydraulic_circuit.pump_pressure(0))
-                    .get::<psi>(),
-            test_bed.query(|a| a.hydraulic_circuit.pump_section_pressure_switch(0) as u8 as f64),
-            test_bed.query(|a| a.hydraulic_circuit.system_section_pressure_switch() as u8 as f64),
-            test_bed.query(|a| {
-                a.hydraulic_circuit
-                    .system_accumulator_fluid_volume()
-                    .get::<gallon>()
-            }),
-        ],
-    );
-
-    reservoir_history.init(
-        0.0,
-        vec![
-            test_bed.query(|a| a.hydraulic_circuit.reservoir_level().get::<gallon>()),
-            test_bed.query(|a| a.hydraulic_circuit.pump_pressure(0).get::<psi>()),
-            test_bed.query(|a| a.hydraulic_circuit.system_section_pressure().get::<psi>()),
-        ],
-    );
-    pump_history.init(
-        0.0,
-        vec![
-            test_bed.query(|a| a.elec_pump.speed().get::<revolution_per_minute>()),
-            test_bed.query(|a| a.elec_pump.displacement().get::<cubic_inch>()),
-        ],
-    );
+    test_bed.probe("Pump section Pressure", |a| {
+        a.hydraulic_circuit.pump_pressure(0).get::<psi>()
+    });
+    test_bed.probe("System section Pressure", |a| {
+        a.hydraulic_circuit.system_section_pressure().get::<psi>()
+    });
+    test_bed.probe("Sections delta Pressure", |a| {
+        (a.hydraulic_circuit.system_section_pressure() - a.hydraulic_circuit.pump_pressure(0))
+            .get::<psi>()
+    });
+    test_bed.probe("Pump section switch", |a| {
+        a.hydraulic_circuit.pump_section_pressure_switch(0) as u8 as f64
+    });
+    test_bed.probe("System section switch", |a| {
+        a.hydraulic_circuit.system_section_pressure_switch() as u8 as f64
+    });
+    test_bed.probe("Accumulator fluid vol", |a| {
+        a.hydraulic_circuit
+            .system_accumulator_fluid_volume()
+            .get::<gallon>()
+    });
+    test_bed.probe("Reservoir volume", |a| {
+        a.hydraulic_circuit.reservoir_level().get::<gallon>()
+    });
+    test_bed.probe("Pump rpm", |a| {
+        a.elec_pump.speed().get::<revolution_per_minute>()
+    });
+    test_bed.probe("Pump displacement", |a| {
+        a.elec_pump.displacement().get::<cubic_inch>()
+    });
 
     let step_duration = Duration::from_millis(33);
 
@@ -305,46 +158,15 @@ fn blue_circuit_epump(path: &str) {
             test_bed.command(|a| a.epump_controller.command_depressurise());
         }
         test_bed.run_with_delta(step_duration);
-
-        hyd_circuit_history.update(
-            step_duration.as_secs_f64(),
-            vec![
-                test_bed.query(|a| a.hydraulic_circuit.pump_pressure(0).get::<psi>()),
-                test_bed.query(|a| a.hydraulic_circuit.system_section_pressure().get::<psi>()),
-                test_bed.query(|a| a.hydraulic_circuit.system_section_pressure().get::<psi>())
-                    - test_bed
-                        .query(|a| a.hydraulic_circuit.pump_pressure(0))
-                        .get::<psi>(),
-                test_bed
-                    .query(|a| a.hydraulic_circuit.pump_section_pressure_switch(0) as u8 as f64),
-                test_bed
-                    .query(|a| a.hydraulic_circuit.system_section_pressure_switch() as u8 as f64),
-                test_bed
-                    .query(|a| a.hydraulic_circuit.system_accumulator_fluid_volume())
-                    .get::<gallon>(),
-            ],
-        );
-        reservoir_history.update(
-            step_duration.as_secs_f64(),
-            vec![
-                test_bed.query(|a| a.hydraulic_circuit.reservoir_level().get::<gallon>()),
-                test_bed.query(|a| a.hydraulic_circuit.pump_pressure(0).get::<psi>()),
-                test_bed.query(|a| a.hydraulic_circuit.system_section_pressure().get::<psi>()),
-            ],
-        );
-
-        pump_history.update(
-            step_duration.as_secs_f64(),
-            vec![
-                test_bed.query(|a| a.elec_pump.speed().get::<revolution_per_minute>()),
-                test_bed.query(|a| a.elec_pump.displacement().get::<cubic_inch>()),
-            ],
-        );
     }
 
-    hyd_circuit_history.show_matplotlib("hyd_circuit_blue_tests", path);
-    reservoir_history.show_matplotlib("hyd_circuit_blue_reservoir_tests", path);
-    pump_history.show_matplotlib("hyd_circuit_blue_pump_tests", path);
+    test_bed
+        .probes()
+        .save_csv(path.join("hyd_circuit_blue_tests.csv"))?;
+    test_bed.probes().save_svg(
+        path.join("hyd_circuit_blue_tests.svg"),
+        "Blue circuit electric pump",
+    )
 }
 
 fn hydraulic_loop(context: &mut InitContext, loop_color: HydraulicColor) -> HydraulicCircuit {
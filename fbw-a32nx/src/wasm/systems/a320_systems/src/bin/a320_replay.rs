@@ -0,0 +1,49 @@
+//! Replays a recording of the A320 systems, such as the `systems.rec` written to the work folder
+//! when `A32NX_SYSTEMS_RECORDING_ENABLED` is set, and prints every written value which diverges
+//! from the recorded one. Recordings made by test builds don't replay identically, as some
+//! systems skip their random draws under test. The aircraft is built from the aircraft
+//! configuration stored in the recording.
+//!
+//! `cargo run -p a320_systems --bin a320_replay -- <recording> [tolerance]`
+
+use std::{env, process::ExitCode};
+
+use a320_systems::{failures::FAILURES, load_aircraft_configuration_toml, A320};
+use systems::simulation::recording::{replay, Recording};
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let (path, tolerance) = match args.as_slice() {
+        [path] => (path, 0.),
+        [path, tolerance] => match tolerance.parse() {
+            Ok(tolerance) => (path, tolerance),
+            Err(_) => return usage(),
+        },
+        _ => return usage(),
+    };
+
+    let recording = match Recording::read_file(path) {
+        Ok(recording) => recording,
+        Err(error) => {
+            eprintln!("{}: {}", path, error);
+            return ExitCode::FAILURE;
+        }
+    };
+    if let Err(error) = load_aircraft_configuration_toml(recording.configuration().to_owned()) {
+        eprintln!("{}: invalid aircraft configuration: {}", path, error);
+        return ExitCode::FAILURE;
+    }
+
+    let report = replay(&recording, A320::new, FAILURES, tolerance);
+    println!("{}", report);
+    if report.is_identical() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn usage() -> ExitCode {
+    eprintln!("usage: a320_replay <recording> [tolerance]");
+    ExitCode::FAILURE
+}
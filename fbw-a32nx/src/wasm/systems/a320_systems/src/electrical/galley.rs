@@ -1,8 +1,62 @@
 use super::{alternating_current::A320AlternatingCurrentElectrical, A320ElectricalOverheadPanel};
 use systems::{
-    electrical::{AlternatingCurrentElectricalSystem, Electricity},
-    simulation::UpdateContext,
+    electrical::{
+        AlternatingCurrentElectricalSystem, ElectricalLoadManagement, Electricity, SheddableLoad,
+    },
+    shared::{ElectricalBusType, PotentialOrigin},
+    simulation::{InitContext, UpdateContext},
 };
+use uom::si::{electric_current::ampere, f64::*, power::watt};
+
+pub(super) const MAIN_GALLEY: &str = "MAIN_GALLEY";
+pub(super) const SECONDARY_GALLEY: &str = "SECONDARY_GALLEY";
+pub(super) const IN_FLIGHT_ENTERTAINMENT: &str = "IN_FLIGHT_ENTERTAINMENT";
+pub(super) const COMMERCIAL: &str = "COMMERCIAL";
+
+/// Creates the load management of the galleys, in-flight entertainment and
+/// other commercial loads, given in the order in which they are shed.
+pub(super) fn commercial_load_management(context: &mut InitContext) -> ElectricalLoadManagement<4> {
+    let generator_rated_power = Power::new::<watt>(90_000.);
+
+    ElectricalLoadManagement::new(
+        &[
+            (PotentialOrigin::EngineGenerator(1), generator_rated_power),
+            (PotentialOrigin::EngineGenerator(2), generator_rated_power),
+            (PotentialOrigin::ApuGenerator(1), generator_rated_power),
+            (PotentialOrigin::External, generator_rated_power),
+        ],
+        [
+            SheddableLoad::new(
+                context,
+                MAIN_GALLEY,
+                ElectricalBusType::AlternatingCurrent(1),
+                Power::new::<watt>(8_000.),
+                ElectricCurrent::new::<ampere>(80.),
+            ),
+            SheddableLoad::new(
+                context,
+                SECONDARY_GALLEY,
+                ElectricalBusType::AlternatingCurrent(2),
+                Power::new::<watt>(4_000.),
+                ElectricCurrent::new::<ampere>(40.),
+            ),
+            SheddableLoad::new(
+                context,
+                IN_FLIGHT_ENTERTAINMENT,
+                ElectricalBusType::AlternatingCurrent(2),
+                Power::new::<watt>(2_000.),
+                ElectricCurrent::new::<ampere>(20.),
+            ),
+            SheddableLoad::new(
+                context,
+                COMMERCIAL,
+                ElectricalBusType::AlternatingCurrent(1),
+                Power::new::<watt>(3_000.),
+                ElectricCurrent::new::<ampere>(30.),
+            ),
+        ],
+    )
+}
 
 pub(super) struct MainGalley {
     is_shed: bool,
@@ -5,7 +5,9 @@ mod galley;
 use self::{
     alternating_current::A320AlternatingCurrentElectrical,
     direct_current::A320DirectCurrentElectrical,
-    galley::{MainGalley, SecondaryGalley},
+    galley::{
+        commercial_load_management, MainGalley, SecondaryGalley, MAIN_GALLEY, SECONDARY_GALLEY,
+    },
 };
 pub(super) use direct_current::APU_START_MOTOR_BUS_TYPE;
 
@@ -17,9 +19,9 @@ use systems::electrical::Battery;
 use systems::{
     accept_iterable,
     electrical::{
-        AlternatingCurrentElectricalSystem, BatteryPushButtons, Electricity, EmergencyElectrical,
-        EmergencyGenerator, EngineGeneratorPushButtons, ExternalPowerSource, StaticInverter,
-        TransformerRectifier,
+        AlternatingCurrentElectricalSystem, ApuGeneratorPushButtons, BatteryPushButtons,
+        ElectricalLoadManagement, Electricity, EmergencyElectrical, EmergencyGenerator,
+        EngineGeneratorPushButtons, ExternalPowerSource, StaticInverter, TransformerRectifier,
     },
     engine::Engine,
     overhead::{
@@ -45,6 +47,7 @@ pub(super) struct A320Electrical {
     direct_current: A320DirectCurrentElectrical,
     main_galley: MainGalley,
     secondary_galley: SecondaryGalley,
+    load_management: ElectricalLoadManagement<4>,
     emergency_elec: EmergencyElectrical,
     emergency_gen: EmergencyGenerator,
 }
@@ -57,6 +60,7 @@ impl A320Electrical {
             direct_current: A320DirectCurrentElectrical::new(context),
             main_galley: MainGalley::new(),
             secondary_galley: SecondaryGalley::new(),
+            load_management: commercial_load_management(context),
             emergency_elec: EmergencyElectrical::new(),
             emergency_gen: EmergencyGenerator::new(
                 context,
@@ -136,6 +140,12 @@ impl A320Electrical {
             .update(context, electricity, &self.alternating_current, overhead);
         self.secondary_galley
             .update(electricity, &self.alternating_current, overhead);
+        self.load_management.update([
+            !self.main_galley.is_shed(),
+            !self.secondary_galley.is_shed(),
+            !overhead.commercial_is_off() && !overhead.galy_and_cab_is_off(),
+            !overhead.commercial_is_off(),
+        ]);
 
         self.debug_assert_invariants();
     }
@@ -150,7 +160,10 @@ impl A320Electrical {
     }
 
     fn galley_is_shed(&self) -> bool {
-        self.main_galley.is_shed() || self.secondary_galley.is_shed()
+        self.main_galley.is_shed()
+            || self.secondary_galley.is_shed()
+            || self.load_management.is_shed(MAIN_GALLEY)
+            || self.load_management.is_shed(SECONDARY_GALLEY)
     }
 
     fn debug_assert_invariants(&self) {
@@ -206,6 +219,7 @@ impl SimulationElement for A320Electrical {
         self.alternating_current.accept(visitor);
         self.direct_current.accept(visitor);
         self.emergency_gen.accept(visitor);
+        self.load_management.accept(visitor);
 
         visitor.visit(self);
     }
@@ -333,6 +347,11 @@ impl EngineGeneratorPushButtons for A320ElectricalOverheadPanel {
         self.idgs[number - 1].is_released()
     }
 }
+impl ApuGeneratorPushButtons for A320ElectricalOverheadPanel {
+    fn apu_gen_push_button_is_on(&self, _: usize) -> bool {
+        self.apu_gen.is_on()
+    }
+}
 impl BatteryPushButtons for A320ElectricalOverheadPanel {
     fn bat_is_auto(&self, number: usize) -> bool {
         self.batteries[number - 1].is_auto()
@@ -433,9 +452,10 @@ mod a320_electrical_circuit_tests {
     use systems::{
         apu::ApuGenerator,
         electrical::{
-            ElectricalElement, ElectricalElementIdentifier, ElectricalElementIdentifierProvider,
-            Electricity, ElectricitySource, ExternalPowerSource, Potential, ProvideFrequency,
-            ProvidePotential, INTEGRATED_DRIVE_GENERATOR_STABILIZATION_TIME,
+            consumption::PowerConsumer, ElectricalElement, ElectricalElementIdentifier,
+            ElectricalElementIdentifierProvider, Electricity, ElectricitySource,
+            ExternalPowerSource, Potential, ProvideFrequency, ProvidePotential,
+            INTEGRATED_DRIVE_GENERATOR_STABILIZATION_TIME,
         },
         failures::FailureType,
         shared::{
@@ -1797,8 +1817,31 @@ mod a320_electrical_circuit_tests {
     }
 
     #[test]
-    #[ignore = "Generator overloading is not yet supported."]
-    fn when_aircraft_on_the_ground_and_apu_gen_is_overloaded_galley_is_shed() {}
+    fn when_aircraft_on_the_ground_and_apu_gen_is_overloaded_galley_is_shed() {
+        let mut test_bed = test_bed_with()
+            .running_apu()
+            .on_the_ground()
+            .and()
+            .ac_bus_1_demand(Power::new::<watt>(85_000.))
+            .run_waiting_for(Duration::from_secs(5));
+
+        assert!(test_bed.galley_is_shed());
+    }
+
+    #[test]
+    fn when_apu_gen_is_no_longer_overloaded_galley_is_restored() {
+        let mut test_bed = test_bed_with()
+            .running_apu()
+            .on_the_ground()
+            .and()
+            .ac_bus_1_demand(Power::new::<watt>(85_000.))
+            .run_waiting_for(Duration::from_secs(5))
+            .then_continue_with()
+            .ac_bus_1_demand(Power::new::<watt>(20_000.))
+            .run_waiting_for(Duration::from_secs(30));
+
+        assert!(!test_bed.galley_is_shed());
+    }
 
     #[rstest]
     #[case(1)]
@@ -2141,7 +2184,14 @@ mod a320_electrical_circuit_tests {
         }
     }
     impl ApuGenerator for TestApuGenerator {
-        fn update(&mut self, _n: Ratio, _is_emergency_shutdown: bool) {}
+        fn update(
+            &mut self,
+            _: &UpdateContext,
+            _n: Ratio,
+            _is_emergency_shutdown: bool,
+            _push_button_is_on: bool,
+        ) {
+        }
 
         fn output_within_normal_parameters(&self) -> bool {
             self.is_available
@@ -2435,6 +2485,7 @@ mod a320_electrical_circuit_tests {
         apu_overhead: TestApuOverhead,
         engine_fire_push_buttons: TestEngineFirePushButtons,
         hydraulics: TestHydraulicSystem,
+        ac_bus_1_consumer: PowerConsumer,
         force_run_emergency_gen: bool,
     }
     impl A320ElectricalTestAircraft {
@@ -2449,6 +2500,7 @@ mod a320_electrical_circuit_tests {
                 apu_overhead: TestApuOverhead::new(),
                 engine_fire_push_buttons: TestEngineFirePushButtons::new(),
                 hydraulics: TestHydraulicSystem::new(),
+                ac_bus_1_consumer: PowerConsumer::from(ElectricalBusType::AlternatingCurrent(1)),
                 force_run_emergency_gen: false,
             }
         }
@@ -2493,6 +2545,10 @@ mod a320_electrical_circuit_tests {
             self.force_run_emergency_gen = true;
         }
 
+        fn ac_bus_1_demand(&mut self, power: Power) {
+            self.ac_bus_1_consumer.demand(power);
+        }
+
         fn static_inverter_input<'a>(&self, electricity: &'a Electricity) -> Ref<'a, Potential> {
             electricity.input_of(self.elec.direct_current.static_inverter())
         }
@@ -2561,6 +2617,7 @@ mod a320_electrical_circuit_tests {
             self.overhead.accept(visitor);
             self.emergency_overhead.accept(visitor);
             self.apu.accept(visitor);
+            self.ac_bus_1_consumer.accept(visitor);
 
             visitor.visit(self);
         }
@@ -2730,6 +2787,11 @@ mod a320_electrical_circuit_tests {
             self
         }
 
+        fn ac_bus_1_demand(mut self, power: Power) -> Self {
+            self.command(|a| a.ac_bus_1_demand(power));
+            self
+        }
+
         fn apu_master_sw_pb_on(mut self) -> Self {
             self.command(|a| a.set_apu_master_sw_pb_on());
             self
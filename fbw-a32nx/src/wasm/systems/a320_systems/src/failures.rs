@@ -0,0 +1,474 @@
+use std::time::Duration;
+
+use systems::air_conditioning::{
+    acs_controller::AcscId, cabin_pressure_controller::CpcId, Channel, ZoneType,
+};
+use systems::failures::{cascade::FailureDependency, FailureType};
+use systems::shared::{
+    AirbusElectricPumpId, AirbusEngineDrivenPumpId, ElectricalBusType, FireDetectionLoopID,
+    FireDetectionZone, FlightControlSurface, GearActuatorId, HydraulicColor, LgciuId,
+    ProximityDetectorId, SmokeDetectionZone,
+};
+
+/// The failures which can be triggered in the A320, keyed by the identifier used by the failures
+/// UI in `src/systems/failures/src/a320.ts`.
+pub static FAILURES: &[(u64, FailureType)] = &[
+    (
+        21_000,
+        FailureType::Acsc(AcscId::Acsc1(Channel::ChannelOne)),
+    ),
+    (
+        21_001,
+        FailureType::Acsc(AcscId::Acsc1(Channel::ChannelTwo)),
+    ),
+    (
+        21_002,
+        FailureType::Acsc(AcscId::Acsc2(Channel::ChannelOne)),
+    ),
+    (
+        21_003,
+        FailureType::Acsc(AcscId::Acsc2(Channel::ChannelTwo)),
+    ),
+    (21_004, FailureType::HotAir(1)),
+    (21_005, FailureType::TrimAirHighPressure),
+    (21_006, FailureType::TrimAirFault(ZoneType::Cockpit)),
+    (21_007, FailureType::TrimAirFault(ZoneType::Cabin(1))),
+    (21_008, FailureType::TrimAirFault(ZoneType::Cabin(2))),
+    (21_009, FailureType::TrimAirOverheat(ZoneType::Cockpit)),
+    (21_010, FailureType::TrimAirOverheat(ZoneType::Cabin(1))),
+    (21_011, FailureType::TrimAirOverheat(ZoneType::Cabin(2))),
+    (21_012, FailureType::CabinFan(1)),
+    (21_013, FailureType::CabinFan(2)),
+    (21_014, FailureType::GalleyFans),
+    (21_015, FailureType::CpcFault(CpcId::Cpc1)),
+    (21_016, FailureType::CpcFault(CpcId::Cpc2)),
+    (21_017, FailureType::OutflowValveFault),
+    (21_018, FailureType::SafetyValveFault),
+    (21_019, FailureType::RapidDecompression),
+    (24_000, FailureType::TransformerRectifier(1)),
+    (24_001, FailureType::TransformerRectifier(2)),
+    (24_002, FailureType::TransformerRectifier(3)),
+    (24_004, FailureType::StaticInverter),
+    (24_020, FailureType::Generator(1)),
+    (24_021, FailureType::Generator(2)),
+    (24_030, FailureType::ApuGenerator(1)),
+    (24_040, FailureType::GeneratorDriveDisconnect(1)),
+    (24_041, FailureType::GeneratorDriveDisconnect(2)),
+    (24_060, FailureType::GeneratorVoltageRegulator(1)),
+    (24_061, FailureType::GeneratorVoltageRegulator(2)),
+    (24_070, FailureType::GeneratorDriveGovernor(1)),
+    (24_071, FailureType::GeneratorDriveGovernor(2)),
+    (24_050, FailureType::BatteryThermalRunaway(1)),
+    (24_051, FailureType::BatteryThermalRunaway(2)),
+    (
+        24_100,
+        FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrent(1)),
+    ),
+    (
+        24_101,
+        FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrent(2)),
+    ),
+    (
+        24_102,
+        FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrentEssential),
+    ),
+    (
+        24_103,
+        FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrentEssentialShed),
+    ),
+    (
+        24_104,
+        FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrentStaticInverter),
+    ),
+    (
+        24_105,
+        FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrentGndFltService),
+    ),
+    (
+        24_106,
+        FailureType::ElectricalBus(ElectricalBusType::DirectCurrent(1)),
+    ),
+    (
+        24_107,
+        FailureType::ElectricalBus(ElectricalBusType::DirectCurrent(2)),
+    ),
+    (
+        24_108,
+        FailureType::ElectricalBus(ElectricalBusType::DirectCurrentEssential),
+    ),
+    (
+        24_109,
+        FailureType::ElectricalBus(ElectricalBusType::DirectCurrentEssentialShed),
+    ),
+    (
+        24_110,
+        FailureType::ElectricalBus(ElectricalBusType::DirectCurrentBattery),
+    ),
+    (
+        24_111,
+        FailureType::ElectricalBus(ElectricalBusType::DirectCurrentHot(1)),
+    ),
+    (
+        24_112,
+        FailureType::ElectricalBus(ElectricalBusType::DirectCurrentHot(2)),
+    ),
+    (
+        24_113,
+        FailureType::ElectricalBus(ElectricalBusType::DirectCurrentGndFltService),
+    ),
+    (26_001, FailureType::SetOnFire(FireDetectionZone::Engine(1))),
+    (26_002, FailureType::SetOnFire(FireDetectionZone::Engine(2))),
+    (26_003, FailureType::SetOnFire(FireDetectionZone::Apu)),
+    (
+        26_004,
+        FailureType::FireDetectionLoop(FireDetectionLoopID::A, FireDetectionZone::Engine(1)),
+    ),
+    (
+        26_005,
+        FailureType::FireDetectionLoop(FireDetectionLoopID::B, FireDetectionZone::Engine(1)),
+    ),
+    (
+        26_006,
+        FailureType::FireDetectionLoop(FireDetectionLoopID::A, FireDetectionZone::Engine(2)),
+    ),
+    (
+        26_007,
+        FailureType::FireDetectionLoop(FireDetectionLoopID::B, FireDetectionZone::Engine(2)),
+    ),
+    (
+        26_008,
+        FailureType::FireDetectionLoop(FireDetectionLoopID::A, FireDetectionZone::Apu),
+    ),
+    (
+        26_009,
+        FailureType::FireDetectionLoop(FireDetectionLoopID::B, FireDetectionZone::Apu),
+    ),
+    (26_010, FailureType::Smoke(SmokeDetectionZone::Lavatory(1))),
+    (26_011, FailureType::Smoke(SmokeDetectionZone::Lavatory(2))),
+    (26_012, FailureType::Smoke(SmokeDetectionZone::Lavatory(3))),
+    (
+        27_100,
+        FailureType::FlightControlActuatorJam(FlightControlSurface::LeftAileron, 1),
+    ),
+    (
+        27_101,
+        FailureType::FlightControlActuatorJam(FlightControlSurface::LeftAileron, 2),
+    ),
+    (
+        27_102,
+        FailureType::FlightControlActuatorJam(FlightControlSurface::RightAileron, 1),
+    ),
+    (
+        27_103,
+        FailureType::FlightControlActuatorJam(FlightControlSurface::RightAileron, 2),
+    ),
+    (
+        27_104,
+        FailureType::FlightControlActuatorJam(FlightControlSurface::LeftElevator, 1),
+    ),
+    (
+        27_105,
+        FailureType::FlightControlActuatorJam(FlightControlSurface::LeftElevator, 2),
+    ),
+    (
+        27_106,
+        FailureType::FlightControlActuatorJam(FlightControlSurface::RightElevator, 1),
+    ),
+    (
+        27_107,
+        FailureType::FlightControlActuatorJam(FlightControlSurface::RightElevator, 2),
+    ),
+    (
+        27_108,
+        FailureType::FlightControlActuatorJam(FlightControlSurface::Rudder, 1),
+    ),
+    (
+        27_109,
+        FailureType::FlightControlActuatorJam(FlightControlSurface::Rudder, 2),
+    ),
+    (
+        27_110,
+        FailureType::FlightControlActuatorJam(FlightControlSurface::Rudder, 3),
+    ),
+    (
+        27_120,
+        FailureType::FlightControlActuatorRunaway(FlightControlSurface::LeftAileron, 1),
+    ),
+    (
+        27_121,
+        FailureType::FlightControlActuatorRunaway(FlightControlSurface::LeftAileron, 2),
+    ),
+    (
+        27_122,
+        FailureType::FlightControlActuatorRunaway(FlightControlSurface::RightAileron, 1),
+    ),
+    (
+        27_123,
+        FailureType::FlightControlActuatorRunaway(FlightControlSurface::RightAileron, 2),
+    ),
+    (
+        27_124,
+        FailureType::FlightControlActuatorRunaway(FlightControlSurface::LeftElevator, 1),
+    ),
+    (
+        27_125,
+        FailureType::FlightControlActuatorRunaway(FlightControlSurface::LeftElevator, 2),
+    ),
+    (
+        27_126,
+        FailureType::FlightControlActuatorRunaway(FlightControlSurface::RightElevator, 1),
+    ),
+    (
+        27_127,
+        FailureType::FlightControlActuatorRunaway(FlightControlSurface::RightElevator, 2),
+    ),
+    (
+        27_128,
+        FailureType::FlightControlActuatorRunaway(FlightControlSurface::Rudder, 1),
+    ),
+    (
+        27_129,
+        FailureType::FlightControlActuatorRunaway(FlightControlSurface::Rudder, 2),
+    ),
+    (
+        27_130,
+        FailureType::FlightControlActuatorRunaway(FlightControlSurface::Rudder, 3),
+    ),
+    (28_001, FailureType::FuelPump(1)),
+    (28_002, FailureType::FuelPump(2)),
+    (28_003, FailureType::FuelPump(3)),
+    (28_004, FailureType::FuelPump(4)),
+    (28_005, FailureType::FuelPump(5)),
+    (28_006, FailureType::FuelPump(6)),
+    (29_000, FailureType::ReservoirLeak(HydraulicColor::Green)),
+    (29_001, FailureType::ReservoirLeak(HydraulicColor::Blue)),
+    (29_002, FailureType::ReservoirLeak(HydraulicColor::Yellow)),
+    (29_003, FailureType::ReservoirAirLeak(HydraulicColor::Green)),
+    (29_004, FailureType::ReservoirAirLeak(HydraulicColor::Blue)),
+    (
+        29_005,
+        FailureType::ReservoirAirLeak(HydraulicColor::Yellow),
+    ),
+    (
+        29_006,
+        FailureType::ReservoirReturnLeak(HydraulicColor::Green),
+    ),
+    (
+        29_007,
+        FailureType::ReservoirReturnLeak(HydraulicColor::Blue),
+    ),
+    (
+        29_008,
+        FailureType::ReservoirReturnLeak(HydraulicColor::Yellow),
+    ),
+    (
+        29_009,
+        FailureType::EnginePumpOverheat(AirbusEngineDrivenPumpId::Green),
+    ),
+    (
+        29_010,
+        FailureType::ElecPumpOverheat(AirbusElectricPumpId::Blue),
+    ),
+    (
+        29_011,
+        FailureType::EnginePumpOverheat(AirbusEngineDrivenPumpId::Yellow),
+    ),
+    (
+        29_012,
+        FailureType::ElecPumpOverheat(AirbusElectricPumpId::Yellow),
+    ),
+    (32_000, FailureType::LgciuPowerSupply(LgciuId::Lgciu1)),
+    (32_001, FailureType::LgciuPowerSupply(LgciuId::Lgciu2)),
+    (32_002, FailureType::LgciuInternalError(LgciuId::Lgciu1)),
+    (32_003, FailureType::LgciuInternalError(LgciuId::Lgciu2)),
+    (
+        32_004,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::UplockGearNose1),
+    ),
+    (
+        32_005,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockGearNose2),
+    ),
+    (
+        32_006,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::UplockGearRight1),
+    ),
+    (
+        32_007,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockGearRight2),
+    ),
+    (
+        32_008,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::UplockGearLeft2),
+    ),
+    (
+        32_009,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockGearLeft1),
+    ),
+    (
+        32_010,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::UplockDoorNose1),
+    ),
+    (
+        32_011,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockDoorNose2),
+    ),
+    (
+        32_012,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::UplockDoorRight2),
+    ),
+    (
+        32_013,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockDoorRight1),
+    ),
+    (
+        32_014,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::UplockDoorLeft2),
+    ),
+    (
+        32_015,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockDoorLeft1),
+    ),
+    (
+        32_020,
+        FailureType::GearActuatorJammed(GearActuatorId::GearNose),
+    ),
+    (
+        32_021,
+        FailureType::GearActuatorJammed(GearActuatorId::GearLeft),
+    ),
+    (
+        32_022,
+        FailureType::GearActuatorJammed(GearActuatorId::GearRight),
+    ),
+    (
+        32_023,
+        FailureType::GearActuatorJammed(GearActuatorId::GearDoorNose),
+    ),
+    (
+        32_024,
+        FailureType::GearActuatorJammed(GearActuatorId::GearDoorLeft),
+    ),
+    (
+        32_025,
+        FailureType::GearActuatorJammed(GearActuatorId::GearDoorRight),
+    ),
+    (
+        32_100,
+        FailureType::BrakeHydraulicLeak(HydraulicColor::Green),
+    ),
+    (
+        32_101,
+        FailureType::BrakeHydraulicLeak(HydraulicColor::Yellow),
+    ),
+    (32_150, FailureType::BrakeAccumulatorGasLeak),
+    (32_200, FailureType::TyreBurst(1)),
+    (32_201, FailureType::TyreBurst(2)),
+    (32_202, FailureType::TyreBurst(3)),
+    (32_203, FailureType::TyreBurst(4)),
+    (32_204, FailureType::TyreBurst(5)),
+    (32_205, FailureType::TyreBurst(6)),
+    (34_000, FailureType::RadioAltimeter(1)),
+    (34_001, FailureType::RadioAltimeter(2)),
+    (34_010, FailureType::GpsReceiver(1)),
+    (34_011, FailureType::GpsReceiver(2)),
+    (34_020, FailureType::AdiruBusParityError(1)),
+    (34_021, FailureType::AdiruBusParityError(2)),
+    (34_022, FailureType::AdiruBusParityError(3)),
+    (34_030, FailureType::Adiru(1)),
+    (34_031, FailureType::Adiru(2)),
+    (34_032, FailureType::Adiru(3)),
+    (34_040, FailureType::Adr(1)),
+    (34_041, FailureType::Adr(2)),
+    (34_042, FailureType::Adr(3)),
+    (34_050, FailureType::Ir(1)),
+    (34_051, FailureType::Ir(2)),
+    (34_052, FailureType::Ir(3)),
+    (34_060, FailureType::Egpwc),
+    (36_000, FailureType::BleedValveStuck(1)),
+    (36_001, FailureType::BleedValveStuck(2)),
+    (36_010, FailureType::PrecoolerFault(1)),
+    (36_011, FailureType::PrecoolerFault(2)),
+    (36_020, FailureType::BleedLeak(1)),
+    (36_021, FailureType::BleedLeak(2)),
+    (49_000, FailureType::ApuFault),
+];
+
+/// Secondary failures which can follow the activation of a failure, e.g. an engine fire burning
+/// through a fire detection loop or a pump running dry after a reservoir leak.
+pub static FAILURE_DEPENDENCIES: &[FailureDependency] = &[
+    FailureDependency::new(
+        FailureType::SetOnFire(FireDetectionZone::Engine(1)),
+        FailureType::FireDetectionLoop(FireDetectionLoopID::A, FireDetectionZone::Engine(1)),
+        Duration::from_secs(45),
+        0.5,
+    ),
+    FailureDependency::new(
+        FailureType::SetOnFire(FireDetectionZone::Engine(1)),
+        FailureType::FireDetectionLoop(FireDetectionLoopID::B, FireDetectionZone::Engine(1)),
+        Duration::from_secs(60),
+        0.25,
+    ),
+    FailureDependency::new(
+        FailureType::SetOnFire(FireDetectionZone::Engine(1)),
+        FailureType::Generator(1),
+        Duration::from_secs(90),
+        0.3,
+    ),
+    FailureDependency::new(
+        FailureType::SetOnFire(FireDetectionZone::Engine(2)),
+        FailureType::FireDetectionLoop(FireDetectionLoopID::A, FireDetectionZone::Engine(2)),
+        Duration::from_secs(45),
+        0.5,
+    ),
+    FailureDependency::new(
+        FailureType::SetOnFire(FireDetectionZone::Engine(2)),
+        FailureType::FireDetectionLoop(FireDetectionLoopID::B, FireDetectionZone::Engine(2)),
+        Duration::from_secs(60),
+        0.25,
+    ),
+    FailureDependency::new(
+        FailureType::SetOnFire(FireDetectionZone::Engine(2)),
+        FailureType::Generator(2),
+        Duration::from_secs(90),
+        0.3,
+    ),
+    FailureDependency::new(
+        FailureType::SetOnFire(FireDetectionZone::Apu),
+        FailureType::ApuGenerator(1),
+        Duration::from_secs(30),
+        0.5,
+    ),
+    FailureDependency::new(
+        FailureType::ReservoirLeak(HydraulicColor::Green),
+        FailureType::EnginePumpOverheat(AirbusEngineDrivenPumpId::Green),
+        Duration::from_secs(120),
+        0.5,
+    ),
+    FailureDependency::new(
+        FailureType::ReservoirLeak(HydraulicColor::Blue),
+        FailureType::ElecPumpOverheat(AirbusElectricPumpId::Blue),
+        Duration::from_secs(120),
+        0.5,
+    ),
+    FailureDependency::new(
+        FailureType::ReservoirLeak(HydraulicColor::Yellow),
+        FailureType::EnginePumpOverheat(AirbusEngineDrivenPumpId::Yellow),
+        Duration::from_secs(120),
+        0.5,
+    ),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn failure_dependencies_only_refer_to_known_failures() {
+        let is_known = |failure_type| FAILURES.iter().any(|(_, known)| *known == failure_type);
+
+        assert!(FAILURE_DEPENDENCIES
+            .iter()
+            .all(|dependency| is_known(dependency.cause()) && is_known(dependency.effect())));
+    }
+}
@@ -0,0 +1,554 @@
+use std::time::Duration;
+
+use systems::{
+    accept_iterable,
+    apu::AuxiliaryPowerUnitFireOverheadPanel,
+    fire_and_smoke_protection::{
+        smoke_detection::SmokeDetectionControlUnit, ExtinguishingAgentBottle, FireDetectionUnit,
+        SetOnFireModule,
+    },
+    shared::{
+        ElectricalBusType, EngineFirePushButtons, FireDetectionZone, LgciuWeightOnWheels,
+        SmokeDetectionZone,
+    },
+    simulation::{
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        UpdateContext, VariableIdentifier,
+    },
+};
+
+pub(super) struct A320FireAndSmokeProtection {
+    fire_detection_unit: FireDetectionUnit<3>,
+    fire_extinguishing_system: FireExtinguishingSystem,
+    smoke_detection_control_unit: SmokeDetectionControlUnit<3>,
+    set_zone_on_fire: SetOnFireModule<3>,
+
+    // Each fire zone has its own TEST pushbutton on the overhead panel
+    fire_test_pushbutton_id: [VariableIdentifier; 3],
+    fire_test_pushbutton_is_pressed: [bool; 3],
+}
+
+impl A320FireAndSmokeProtection {
+    const DELAY_APU_FIRE_EXTINGUISHING: Duration = Duration::from_secs(3);
+
+    const FIRE_DETECTION_ZONES: [FireDetectionZone; 3] = [
+        FireDetectionZone::Engine(1),
+        FireDetectionZone::Engine(2),
+        FireDetectionZone::Apu,
+    ];
+
+    const SMOKE_DETECTION_ZONES: [SmokeDetectionZone; 3] = [
+        SmokeDetectionZone::Lavatory(1),
+        SmokeDetectionZone::Lavatory(2),
+        SmokeDetectionZone::Lavatory(3),
+    ];
+
+    pub(super) fn new(context: &mut InitContext) -> Self {
+        Self {
+            fire_detection_unit: FireDetectionUnit::new(
+                context,
+                Self::FIRE_DETECTION_ZONES,
+                [
+                    ElectricalBusType::DirectCurrentEssential,
+                    ElectricalBusType::DirectCurrent(2),
+                ],
+                Self::DELAY_APU_FIRE_EXTINGUISHING,
+            ),
+            fire_extinguishing_system: FireExtinguishingSystem::new(context),
+            smoke_detection_control_unit: SmokeDetectionControlUnit::new(
+                context,
+                Self::SMOKE_DETECTION_ZONES,
+                [
+                    ElectricalBusType::DirectCurrentEssential,
+                    ElectricalBusType::DirectCurrent(2),
+                ],
+            ),
+            set_zone_on_fire: SetOnFireModule::new(context, Self::FIRE_DETECTION_ZONES),
+
+            fire_test_pushbutton_id: [
+                context.get_identifier("FIRE_TEST_ENG1".to_owned()),
+                context.get_identifier("FIRE_TEST_ENG2".to_owned()),
+                context.get_identifier("FIRE_TEST_APU".to_owned()),
+            ],
+            fire_test_pushbutton_is_pressed: [false; 3],
+        }
+    }
+
+    pub(super) fn update(
+        &mut self,
+        context: &UpdateContext,
+        engine_fire_push_buttons: &impl EngineFirePushButtons,
+        apu_fire_overhead: &AuxiliaryPowerUnitFireOverheadPanel,
+        lgciu: [&impl LgciuWeightOnWheels; 2],
+    ) {
+        self.fire_detection_unit
+            .update(context, self.fire_test_pushbutton_is_pressed, lgciu);
+        self.fire_extinguishing_system.update(
+            context,
+            engine_fire_push_buttons,
+            apu_fire_overhead,
+            self.fire_test_pushbutton_is_pressed,
+            self.fire_detection_unit.should_extinguish_apu_fire(),
+        );
+        self.smoke_detection_control_unit
+            .update(context, [false; 3]);
+
+        self.set_zone_on_fire
+            .update(self.fire_extinguishing_system.bottles_discharged());
+    }
+
+    pub(super) fn apu_fire_on_ground(&self) -> bool {
+        self.fire_detection_unit.apu_fire_on_ground()
+    }
+}
+
+impl SimulationElement for A320FireAndSmokeProtection {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        for (id, is_pressed) in self
+            .fire_test_pushbutton_id
+            .iter()
+            .zip(&mut self.fire_test_pushbutton_is_pressed)
+        {
+            *is_pressed = reader.read(id);
+        }
+    }
+
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.fire_detection_unit.accept(visitor);
+        self.fire_extinguishing_system.accept(visitor);
+        self.smoke_detection_control_unit.accept(visitor);
+        self.set_zone_on_fire.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
+/// Two bottles per engine and one bottle for the APU
+struct FireExtinguishingSystem {
+    fire_extinguishing_bottles: [ExtinguishingAgentBottle; 5],
+}
+
+impl FireExtinguishingSystem {
+    fn new(context: &mut InitContext) -> Self {
+        let powered_by = [
+            ElectricalBusType::DirectCurrentHot(1),
+            ElectricalBusType::DirectCurrentHot(2),
+        ];
+        Self {
+            fire_extinguishing_bottles: [
+                ExtinguishingAgentBottle::new(context, "1_ENG_1", powered_by),
+                ExtinguishingAgentBottle::new(context, "2_ENG_1", powered_by),
+                ExtinguishingAgentBottle::new(context, "1_ENG_2", powered_by),
+                ExtinguishingAgentBottle::new(context, "2_ENG_2", powered_by),
+                ExtinguishingAgentBottle::new(context, "1_APU_1", powered_by),
+            ],
+        }
+    }
+
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        engine_fire_push_buttons: &impl EngineFirePushButtons,
+        apu_fire_overhead: &AuxiliaryPowerUnitFireOverheadPanel,
+        fire_test_pushbuttons_are_pressed: [bool; 3],
+        should_extinguish_apu_fire: bool,
+    ) {
+        for (engine_number, bottles) in [1, 2]
+            .into_iter()
+            .zip(self.fire_extinguishing_bottles[..4].chunks_mut(2))
+        {
+            bottles.iter_mut().for_each(|bottle| {
+                bottle.update(
+                    context,
+                    engine_fire_push_buttons.is_released(engine_number),
+                    fire_test_pushbuttons_are_pressed[engine_number - 1],
+                    None,
+                )
+            });
+        }
+        self.fire_extinguishing_bottles[4].update(
+            context,
+            apu_fire_overhead.fire_button_is_released(),
+            fire_test_pushbuttons_are_pressed[2],
+            Some(should_extinguish_apu_fire),
+        );
+    }
+
+    /// The number of discharged bottles per fire zone
+    fn bottles_discharged(&self) -> [usize; 3] {
+        let discharged = |bottles: &[ExtinguishingAgentBottle]| {
+            bottles
+                .iter()
+                .filter(|bottle| bottle.bottle_discharge())
+                .count()
+        };
+        [
+            discharged(&self.fire_extinguishing_bottles[..2]),
+            discharged(&self.fire_extinguishing_bottles[2..4]),
+            discharged(&self.fire_extinguishing_bottles[4..]),
+        ]
+    }
+}
+
+impl SimulationElement for FireExtinguishingSystem {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.fire_extinguishing_bottles, visitor);
+
+        visitor.visit(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use systems::{
+        electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
+        engine::EngineFireOverheadPanel,
+        failures::FailureType,
+        shared::{FireDetectionLoopID, PotentialOrigin},
+        simulation::{
+            test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+            Aircraft,
+        },
+    };
+
+    use super::*;
+
+    struct TestLgciu {
+        compressed: bool,
+    }
+    impl TestLgciu {
+        fn new(compressed: bool) -> Self {
+            Self { compressed }
+        }
+    }
+    impl LgciuWeightOnWheels for TestLgciu {
+        fn left_and_right_gear_compressed(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            self.compressed
+        }
+        fn right_gear_compressed(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            self.compressed
+        }
+        fn right_gear_extended(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            !self.compressed
+        }
+        fn left_gear_compressed(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            self.compressed
+        }
+        fn left_gear_extended(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            !self.compressed
+        }
+        fn left_and_right_gear_extended(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            !self.compressed
+        }
+        fn nose_gear_compressed(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            self.compressed
+        }
+        fn nose_gear_extended(&self, _treat_ext_pwr_as_ground: bool) -> bool {
+            !self.compressed
+        }
+    }
+
+    struct TestAircraft {
+        fire_and_smoke_protection: A320FireAndSmokeProtection,
+        engine_fire_overhead: EngineFireOverheadPanel<2>,
+        apu_fire_overhead: AuxiliaryPowerUnitFireOverheadPanel,
+        lgciu1: TestLgciu,
+        lgciu2: TestLgciu,
+
+        powered_dc_source_ess: TestElectricitySource,
+        powered_dc_source_2: TestElectricitySource,
+        powered_dc_hot_1: TestElectricitySource,
+        powered_dc_hot_2: TestElectricitySource,
+        dc_ess_bus: ElectricalBus,
+        dc_2_bus: ElectricalBus,
+        dc_hot_1_bus: ElectricalBus,
+        dc_hot_2_bus: ElectricalBus,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                fire_and_smoke_protection: A320FireAndSmokeProtection::new(context),
+                engine_fire_overhead: EngineFireOverheadPanel::new(context),
+                apu_fire_overhead: AuxiliaryPowerUnitFireOverheadPanel::new(context),
+                lgciu1: TestLgciu::new(true),
+                lgciu2: TestLgciu::new(true),
+
+                powered_dc_source_ess: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::Battery(1),
+                ),
+                powered_dc_source_2: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::Battery(2),
+                ),
+                powered_dc_hot_1: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::Battery(1),
+                ),
+                powered_dc_hot_2: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::Battery(2),
+                ),
+                dc_ess_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrentEssential),
+                dc_2_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrent(2)),
+                dc_hot_1_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrentHot(1)),
+                dc_hot_2_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrentHot(2)),
+            }
+        }
+
+        fn unpower_dc_2_bus(&mut self) {
+            self.powered_dc_source_2.unpower();
+        }
+
+        fn unpower_dc_hot_buses(&mut self) {
+            self.powered_dc_hot_1.unpower();
+            self.powered_dc_hot_2.unpower();
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _context: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            electricity.supplied_by(&self.powered_dc_source_ess);
+            electricity.supplied_by(&self.powered_dc_source_2);
+            electricity.supplied_by(&self.powered_dc_hot_1);
+            electricity.supplied_by(&self.powered_dc_hot_2);
+            electricity.flow(&self.powered_dc_source_ess, &self.dc_ess_bus);
+            electricity.flow(&self.powered_dc_source_2, &self.dc_2_bus);
+            electricity.flow(&self.powered_dc_hot_1, &self.dc_hot_1_bus);
+            electricity.flow(&self.powered_dc_hot_2, &self.dc_hot_2_bus);
+        }
+
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.fire_and_smoke_protection.update(
+                context,
+                &self.engine_fire_overhead,
+                &self.apu_fire_overhead,
+                [&self.lgciu1, &self.lgciu2],
+            );
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<V: SimulationElementVisitor>(&mut self, visitor: &mut V) {
+            self.fire_and_smoke_protection.accept(visitor);
+            self.engine_fire_overhead.accept(visitor);
+            self.apu_fire_overhead.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    struct FireProtectionTestBed {
+        test_bed: SimulationTestBed<TestAircraft>,
+    }
+    impl FireProtectionTestBed {
+        fn new() -> Self {
+            Self {
+                test_bed: SimulationTestBed::new(TestAircraft::new),
+            }
+        }
+
+        fn and_run(mut self) -> Self {
+            self.run();
+            self
+        }
+
+        fn run_with_delta_of(mut self, delta: Duration) -> Self {
+            self.run_with_delta(delta);
+            self
+        }
+
+        fn set_engine_on_fire(mut self, engine_number: usize) -> Self {
+            self.write_by_name(&format!("ENG ON FIRE:{}", engine_number), true);
+            self
+        }
+
+        fn set_apu_on_fire(mut self) -> Self {
+            self.write_by_name("APU_ON_FIRE", true);
+            self
+        }
+
+        fn set_engine_fire_pb_released(mut self, engine_number: usize) -> Self {
+            self.write_by_name(&format!("FIRE_BUTTON_ENG{}", engine_number), true);
+            self
+        }
+
+        fn set_agent_pb(mut self, bottle_number: usize, engine_number: usize) -> Self {
+            self.write_by_name(
+                &format!(
+                    "OVHD_FIRE_AGENT_{}_ENG_{}_IS_PRESSED",
+                    bottle_number, engine_number
+                ),
+                true,
+            );
+            self
+        }
+
+        fn set_fire_test_pushbutton(mut self, zone: &str, pressed: bool) -> Self {
+            self.write_by_name(&format!("FIRE_TEST_{}", zone), pressed);
+            self
+        }
+
+        fn set_loop_failure(
+            mut self,
+            zone: FireDetectionZone,
+            loop_id: FireDetectionLoopID,
+        ) -> Self {
+            self.fail(FailureType::FireDetectionLoop(loop_id, zone));
+            self
+        }
+
+        fn set_smoke_in_zone(mut self, zone: SmokeDetectionZone) -> Self {
+            self.fail(FailureType::Smoke(zone));
+            self
+        }
+
+        fn unpowered_dc_2_bus(mut self) -> Self {
+            self.command(|a| a.unpower_dc_2_bus());
+            self
+        }
+
+        fn unpowered_dc_hot_buses(mut self) -> Self {
+            self.command(|a| a.unpower_dc_hot_buses());
+            self
+        }
+
+        fn engine_on_fire_detected(&mut self, engine_number: usize) -> bool {
+            self.read_by_name(&format!("FIRE_DETECTED_ENG{}", engine_number))
+        }
+
+        fn apu_on_fire_detected(&mut self) -> bool {
+            self.read_by_name("FIRE_DETECTED_APU")
+        }
+
+        fn squib_is_armed(&mut self, id: &str) -> bool {
+            self.read_by_name(&format!("FIRE_SQUIB_{}_IS_ARMED", id))
+        }
+
+        fn squib_is_discharged(&mut self, id: &str) -> bool {
+            self.read_by_name(&format!("FIRE_SQUIB_{}_IS_DISCHARGED", id))
+        }
+
+        fn smoke_detected(&mut self, zone: SmokeDetectionZone) -> bool {
+            self.read_by_name(&format!("SMOKE_DETECTED_{}", zone))
+        }
+
+        fn apu_fire_on_ground(&self) -> bool {
+            self.query(|a| a.fire_and_smoke_protection.apu_fire_on_ground())
+        }
+    }
+    impl TestBed for FireProtectionTestBed {
+        type Aircraft = TestAircraft;
+
+        fn test_bed(&self) -> &SimulationTestBed<TestAircraft> {
+            &self.test_bed
+        }
+
+        fn test_bed_mut(&mut self) -> &mut SimulationTestBed<TestAircraft> {
+            &mut self.test_bed
+        }
+    }
+
+    fn test_bed() -> FireProtectionTestBed {
+        FireProtectionTestBed::new()
+    }
+
+    #[test]
+    fn engine_fire_is_detected() {
+        let mut test_bed = test_bed().set_engine_on_fire(2).and_run();
+
+        assert!(test_bed.engine_on_fire_detected(2));
+        assert!(!test_bed.engine_on_fire_detected(1));
+    }
+
+    #[test]
+    fn engine_fire_is_detected_with_one_loop_failed() {
+        let mut test_bed = test_bed()
+            .set_loop_failure(FireDetectionZone::Engine(1), FireDetectionLoopID::B)
+            .set_engine_on_fire(1)
+            .and_run();
+
+        assert!(test_bed.engine_on_fire_detected(1));
+    }
+
+    #[test]
+    fn engine_fire_is_detected_with_one_loop_unpowered() {
+        let mut test_bed = test_bed()
+            .and_run()
+            .unpowered_dc_2_bus()
+            .set_engine_on_fire(1)
+            .and_run();
+
+        assert!(test_bed.engine_on_fire_detected(1));
+    }
+
+    #[test]
+    fn fire_test_only_tests_its_own_zone() {
+        let mut test_bed = test_bed().set_fire_test_pushbutton("ENG1", true).and_run();
+
+        assert!(test_bed.engine_on_fire_detected(1));
+        assert!(test_bed.squib_is_armed("1_ENG_1"));
+        assert!(test_bed.squib_is_discharged("2_ENG_1"));
+        assert!(!test_bed.engine_on_fire_detected(2));
+        assert!(!test_bed.squib_is_armed("1_ENG_2"));
+        assert!(!test_bed.apu_on_fire_detected());
+    }
+
+    #[test]
+    fn agent_is_discharged_after_fire_pb_released_and_agent_pb_pressed() {
+        let mut test_bed = test_bed()
+            .set_engine_on_fire(1)
+            .and_run()
+            .set_engine_fire_pb_released(1)
+            .and_run();
+
+        assert!(test_bed.squib_is_armed("1_ENG_1"));
+        assert!(test_bed.squib_is_armed("2_ENG_1"));
+        assert!(!test_bed.squib_is_discharged("1_ENG_1"));
+
+        test_bed = test_bed.set_agent_pb(1, 1).and_run().and_run();
+
+        assert!(test_bed.squib_is_discharged("1_ENG_1"));
+        assert!(!test_bed.squib_is_discharged("2_ENG_1"));
+    }
+
+    #[test]
+    fn squibs_cannot_be_armed_when_unpowered() {
+        let mut test_bed = test_bed()
+            .unpowered_dc_hot_buses()
+            .set_engine_on_fire(1)
+            .set_engine_fire_pb_released(1)
+            .and_run();
+
+        assert!(!test_bed.squib_is_armed("1_ENG_1"));
+    }
+
+    #[test]
+    fn apu_fire_on_ground_is_automatically_extinguished() {
+        let mut test_bed = test_bed().set_apu_on_fire().and_run();
+
+        assert!(test_bed.apu_on_fire_detected());
+        assert!(test_bed.apu_fire_on_ground());
+        assert!(!test_bed.squib_is_discharged("1_APU_1"));
+
+        test_bed = test_bed
+            .run_with_delta_of(Duration::from_secs(3))
+            .and_run()
+            .and_run();
+
+        assert!(test_bed.squib_is_discharged("1_APU_1"));
+    }
+
+    #[test]
+    fn smoke_in_lavatory_is_detected() {
+        let mut test_bed = test_bed()
+            .set_smoke_in_zone(SmokeDetectionZone::Lavatory(2))
+            .run_with_delta_of(Duration::from_secs(5))
+            .and_run();
+
+        assert!(test_bed.smoke_detected(SmokeDetectionZone::Lavatory(2)));
+        assert!(!test_bed.smoke_detected(SmokeDetectionZone::Lavatory(1)));
+    }
+}
@@ -1,23 +1,28 @@
-// Note: Fuel system for now is still handled in MSFS. This is used for calculating fuel-related factors.
+// Note: Fuel quantities are only written back to the simulator when the native fuel model is
+// selected. Otherwise MSFS and the FADEC remain in charge of the fuel quantities.
 
+use crate::aircraft_configuration;
 use nalgebra::Vector3;
 use systems::{
-    fuel::{FuelCG, FuelInfo, FuelPayload, FuelSystem, FuelTank},
-    simulation::{InitContext, SimulationElement, SimulationElementVisitor},
+    accept_iterable,
+    aircraft_configuration::FuelModel,
+    electrical::CircuitBreaker,
+    fuel::{FuelCG, FuelPayload, FuelPump, FuelSystem, FuelValve},
+    shared::{ElectricalBusType, EngineFirePushButtons, EngineFuelFlow},
+    simulation::{
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+    },
+};
+use uom::si::{
+    electric_current::ampere, f64::*, length::foot, mass::kilogram, mass_rate::kilogram_per_second,
+    ratio::ratio,
 };
-use uom::si::f64::*;
 
 #[cfg(test)]
 mod test;
 
-pub trait FuelLevel {
-    fn left_inner_tank_has_fuel(&self) -> bool;
-    fn right_inner_tank_has_fuel(&self) -> bool;
-    fn left_outer_tank_has_fuel(&self) -> bool;
-    fn right_outer_tank_has_fuel(&self) -> bool;
-    fn center_tank_has_fuel(&self) -> bool;
-}
-
+#[derive(Clone, Copy)]
 pub enum A320FuelTankType {
     Center,
     LeftInner,
@@ -45,81 +50,57 @@ impl From<usize> for A320FuelTankType {
 }
 
 pub struct A320Fuel {
+    native_model_is_active_id: VariableIdentifier,
+
+    model: FuelModel,
     fuel_system: FuelSystem<5>,
+    feed_system: A320FuelFeedSystem,
 }
 impl A320Fuel {
-    pub const A320_FUEL: [FuelInfo<'static>; 5] = [
-        FuelInfo {
-            fuel_tank_id: "FUEL TANK CENTER QUANTITY",
-            position: (-4.5, 0., 1.),
-            total_capacity_gallons: 2179.,
-        },
-        FuelInfo {
-            fuel_tank_id: "FUEL TANK LEFT MAIN QUANTITY",
-            position: (-8., -13., 2.),
-            total_capacity_gallons: 1816.,
-        },
-        FuelInfo {
-            fuel_tank_id: "FUEL TANK LEFT AUX QUANTITY",
-            position: (-16.9, -27., 3.),
-            total_capacity_gallons: 228.,
-        },
-        FuelInfo {
-            fuel_tank_id: "FUEL TANK RIGHT MAIN QUANTITY",
-            position: (-8., 13., 2.),
-            total_capacity_gallons: 1816.,
-        },
-        FuelInfo {
-            fuel_tank_id: "FUEL TANK RIGHT AUX QUANTITY",
-            position: (-16.9, 27., 3.),
-            total_capacity_gallons: 228.,
-        },
-    ];
-
-    pub fn new(context: &mut InitContext) -> Self {
-        let fuel_tanks = Self::A320_FUEL.map(|f| {
-            FuelTank::new(
-                context,
-                f.fuel_tank_id,
-                Vector3::new(f.position.0, f.position.1, f.position.2),
-                false,
-            )
-        });
+    pub fn new(context: &mut InitContext, model: FuelModel) -> Self {
+        let fuel_system = aircraft_configuration()
+            .fuel_system(context, model == FuelModel::Native)
+            .unwrap_or_else(|e| panic!("Invalid A320 fuel tank configuration: {}", e));
         A320Fuel {
-            fuel_system: FuelSystem::new(context, fuel_tanks),
+            native_model_is_active_id: context
+                .get_identifier("FUEL_NATIVE_MODEL_IS_ACTIVE".to_owned()),
+
+            model,
+            fuel_system,
+            feed_system: A320FuelFeedSystem::new(context),
         }
     }
 
-    pub fn left_inner_tank_has_fuel_remaining(&self) -> bool {
-        self.fuel_system
-            .tank_has_fuel(A320FuelTankType::LeftInner.into())
+    pub fn update<T: EngineFuelFlow>(
+        &mut self,
+        context: &UpdateContext,
+        overhead: &A320FuelOverheadPanel,
+        engine_fire_push_buttons: &impl EngineFirePushButtons,
+        engines: [&T; 2],
+    ) {
+        self.feed_system.update(
+            context,
+            &mut self.fuel_system,
+            overhead,
+            engine_fire_push_buttons,
+            engines,
+        );
     }
 
-    fn center_tank_has_fuel(&self) -> bool {
-        self.fuel_system
-            .tank_has_fuel(A320FuelTankType::Center.into())
+    /// Whether the engine receives fuel. Only the native fuel model can starve an engine, as
+    /// otherwise the simulator feeds the engines regardless of the pumps and valves.
+    pub fn engine_is_fed(&self, engine_number: usize) -> bool {
+        match self.model {
+            FuelModel::Simulator => true,
+            FuelModel::Native => self.feed_system.engine_is_fed(engine_number),
+        }
     }
 
-    fn left_inner_tank_has_fuel(&self) -> bool {
+    pub fn left_inner_tank_has_fuel_remaining(&self) -> bool {
         self.fuel_system
             .tank_has_fuel(A320FuelTankType::LeftInner.into())
     }
 
-    fn left_outer_tank_has_fuel(&self) -> bool {
-        self.fuel_system
-            .tank_has_fuel(A320FuelTankType::LeftOuter.into())
-    }
-
-    fn right_inner_tank_has_fuel(&self) -> bool {
-        self.fuel_system
-            .tank_has_fuel(A320FuelTankType::RightInner.into())
-    }
-
-    fn right_outer_tank_has_fuel(&self) -> bool {
-        self.fuel_system
-            .tank_has_fuel(A320FuelTankType::RightOuter.into())
-    }
-
     fn fore_aft_center_of_gravity(&self) -> f64 {
         self.center_of_gravity().x
     }
@@ -132,23 +113,6 @@ impl A320Fuel {
         self.fuel_system.center_of_gravity()
     }
 }
-impl FuelLevel for A320Fuel {
-    fn left_inner_tank_has_fuel(&self) -> bool {
-        self.left_inner_tank_has_fuel()
-    }
-    fn right_inner_tank_has_fuel(&self) -> bool {
-        self.right_inner_tank_has_fuel()
-    }
-    fn left_outer_tank_has_fuel(&self) -> bool {
-        self.left_outer_tank_has_fuel()
-    }
-    fn right_outer_tank_has_fuel(&self) -> bool {
-        self.right_outer_tank_has_fuel()
-    }
-    fn center_tank_has_fuel(&self) -> bool {
-        self.center_tank_has_fuel()
-    }
-}
 impl FuelPayload for A320Fuel {
     fn total_load(&self) -> Mass {
         self.total_load()
@@ -165,6 +129,374 @@ impl FuelCG for A320Fuel {
 impl SimulationElement for A320Fuel {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.fuel_system.accept(visitor);
+        self.feed_system.accept(visitor);
         visitor.visit(self);
     }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        // Tells the FADEC to leave the fuel quantities to the native fuel model.
+        writer.write(
+            &self.native_model_is_active_id,
+            self.model == FuelModel::Native,
+        );
+    }
+}
+
+/// Pumps and valves which feed the engines from the wing tanks.
+///
+/// Pump numbering follows the simulator fuel system: 1 and 4 are the centre tank pumps, 2 and 5
+/// the left wing pumps and 3 and 6 the right wing pumps. Each pump is protected by a circuit
+/// breaker identified by its pump number, e.g. `FUEL_PUMP_1`.
+struct A320FuelFeedSystem {
+    wing_pumps: [[CircuitBreaker<FuelPump>; 2]; 2],
+    center_tank_pumps: [CircuitBreaker<FuelPump>; 2],
+    low_pressure_valves: [FuelValve; 2],
+    crossfeed_valve: FuelValve,
+    outer_tank_transfer_valves: [FuelValve; 2],
+
+    engine_is_fed: [bool; 2],
+    center_tank_transfer_is_active: [bool; 2],
+    outer_tank_transfer_is_latched: bool,
+}
+impl A320FuelFeedSystem {
+    const WING_TANKS: [(A320FuelTankType, A320FuelTankType); 2] = [
+        (A320FuelTankType::LeftInner, A320FuelTankType::LeftOuter),
+        (A320FuelTankType::RightInner, A320FuelTankType::RightOuter),
+    ];
+
+    const CENTER_TANK_TRANSFER_RATE_KG_PER_SECOND: f64 = 1.5;
+    const CENTER_TANK_TRANSFER_START_RATIO: f64 = 0.91;
+    const CENTER_TANK_TRANSFER_STOP_RATIO: f64 = 0.999;
+    const OUTER_TANK_TRANSFER_RATE_KG_PER_SECOND: f64 = 1.;
+    const INNER_TANK_LOW_LEVEL_KG: f64 = 750.;
+    const SUCTION_FEED_CEILING_FEET: f64 = 15000.;
+
+    const PUMP_CIRCUIT_BREAKERS: [&'static str; 6] = [
+        "FUEL_PUMP_1",
+        "FUEL_PUMP_2",
+        "FUEL_PUMP_3",
+        "FUEL_PUMP_4",
+        "FUEL_PUMP_5",
+        "FUEL_PUMP_6",
+    ];
+    const PUMP_RATED_CURRENT_AMPERE: f64 = 15.;
+
+    fn new(context: &mut InitContext) -> Self {
+        Self {
+            wing_pumps: [
+                [
+                    Self::new_pump(context, 2, ElectricalBusType::AlternatingCurrent(1)),
+                    Self::new_pump(context, 5, ElectricalBusType::AlternatingCurrent(2)),
+                ],
+                [
+                    Self::new_pump(context, 3, ElectricalBusType::AlternatingCurrent(1)),
+                    Self::new_pump(context, 6, ElectricalBusType::AlternatingCurrent(2)),
+                ],
+            ],
+            center_tank_pumps: [
+                Self::new_pump(context, 1, ElectricalBusType::AlternatingCurrent(1)),
+                Self::new_pump(context, 4, ElectricalBusType::AlternatingCurrent(2)),
+            ],
+            low_pressure_valves: [
+                FuelValve::new(context, 1, ElectricalBusType::DirectCurrentHot(1)),
+                FuelValve::new(context, 2, ElectricalBusType::DirectCurrentHot(2)),
+            ],
+            crossfeed_valve: FuelValve::new(context, 3, ElectricalBusType::DirectCurrentEssential),
+            outer_tank_transfer_valves: [
+                FuelValve::new(context, 4, ElectricalBusType::DirectCurrentEssential),
+                FuelValve::new(context, 5, ElectricalBusType::DirectCurrent(2)),
+            ],
+
+            // Engines are updated before the first fuel update, so assume they are fed until then.
+            engine_is_fed: [true; 2],
+            center_tank_transfer_is_active: [false; 2],
+            outer_tank_transfer_is_latched: false,
+        }
+    }
+
+    fn new_pump(
+        context: &mut InitContext,
+        number: usize,
+        powered_by: ElectricalBusType,
+    ) -> CircuitBreaker<FuelPump> {
+        let pump = FuelPump::new(context, number, powered_by);
+
+        CircuitBreaker::new(
+            context,
+            Self::PUMP_CIRCUIT_BREAKERS[number - 1],
+            powered_by,
+            ElectricCurrent::new::<ampere>(Self::PUMP_RATED_CURRENT_AMPERE),
+            pump,
+        )
+    }
+
+    fn update<T: EngineFuelFlow>(
+        &mut self,
+        context: &UpdateContext,
+        fuel_system: &mut FuelSystem<5>,
+        overhead: &A320FuelOverheadPanel,
+        engine_fire_push_buttons: &impl EngineFirePushButtons,
+        engines: [&T; 2],
+    ) {
+        let any_engine_is_running = engines
+            .iter()
+            .any(|engine| engine.fuel_flow().get::<kilogram_per_second>() > 0.);
+
+        self.update_center_tank_pumps(fuel_system, overhead, any_engine_is_running);
+        self.update_valves(
+            context,
+            fuel_system,
+            overhead,
+            engine_fire_push_buttons,
+            any_engine_is_running,
+        );
+
+        self.feed_engines(context, fuel_system, engines);
+        self.transfer_center_tank(context, fuel_system);
+        self.transfer_outer_tanks(context, fuel_system);
+    }
+
+    fn update_center_tank_pumps(
+        &mut self,
+        fuel_system: &FuelSystem<5>,
+        overhead: &A320FuelOverheadPanel,
+        any_engine_is_running: bool,
+    ) {
+        let center_tank_has_fuel = fuel_system.tank_has_fuel(A320FuelTankType::Center.into());
+
+        for (i, (inner, _)) in Self::WING_TANKS.into_iter().enumerate() {
+            let inner = inner.into();
+            let fill_ratio =
+                (fuel_system.tank_mass(inner) / fuel_system.tank_capacity(inner)).get::<ratio>();
+
+            // In auto mode the centre tank tops up the inner tanks whenever an engine is running.
+            // In manual mode the transfer runs for as long as the pump is selected on.
+            if overhead.mode_select_is_manual() {
+                self.center_tank_transfer_is_active[i] = true;
+            } else if !any_engine_is_running || fill_ratio >= Self::CENTER_TANK_TRANSFER_STOP_RATIO
+            {
+                self.center_tank_transfer_is_active[i] = false;
+            } else if fill_ratio < Self::CENTER_TANK_TRANSFER_START_RATIO {
+                self.center_tank_transfer_is_active[i] = true;
+            }
+
+            self.center_tank_pumps[i].element_mut().update(
+                overhead.center_tank_pump_is_on(i + 1)
+                    && center_tank_has_fuel
+                    && self.center_tank_transfer_is_active[i],
+            );
+        }
+
+        for (i, pumps) in self.wing_pumps.iter_mut().enumerate() {
+            for (j, pump) in pumps.iter_mut().enumerate() {
+                pump.element_mut()
+                    .update(overhead.wing_pump_is_on(i + 1, j + 1));
+            }
+        }
+    }
+
+    fn update_valves(
+        &mut self,
+        context: &UpdateContext,
+        fuel_system: &FuelSystem<5>,
+        overhead: &A320FuelOverheadPanel,
+        engine_fire_push_buttons: &impl EngineFirePushButtons,
+        any_engine_is_running: bool,
+    ) {
+        for (i, valve) in self.low_pressure_valves.iter_mut().enumerate() {
+            valve.update(
+                overhead.engine_master_is_on(i + 1) && !engine_fire_push_buttons.is_released(i + 1),
+            );
+        }
+
+        self.crossfeed_valve.update(overhead.crossfeed_is_on());
+
+        // The outer tank transfer valves open on both sides as soon as either inner tank reaches
+        // its low level while an engine is running. They remain latched open until the aircraft is on ground with both inner
+        // tanks above the low level again.
+        let inner_tank_is_low: Vec<bool> = Self::WING_TANKS
+            .iter()
+            .map(|(inner, _)| {
+                fuel_system.tank_mass(usize::from(*inner))
+                    < Mass::new::<kilogram>(Self::INNER_TANK_LOW_LEVEL_KG)
+            })
+            .collect();
+        if any_engine_is_running && inner_tank_is_low.iter().any(|&is_low| is_low) {
+            self.outer_tank_transfer_is_latched = true;
+        } else if context.is_on_ground() {
+            self.outer_tank_transfer_is_latched = false;
+        }
+
+        self.outer_tank_transfer_valves
+            .iter_mut()
+            .for_each(|valve| valve.update(self.outer_tank_transfer_is_latched));
+    }
+
+    fn feed_engines<T: EngineFuelFlow>(
+        &mut self,
+        context: &UpdateContext,
+        fuel_system: &mut FuelSystem<5>,
+        engines: [&T; 2],
+    ) {
+        let is_pressurised: Vec<bool> = Self::WING_TANKS
+            .iter()
+            .zip(&self.wing_pumps)
+            .map(|((inner, _), pumps)| {
+                fuel_system.tank_has_fuel(usize::from(*inner))
+                    && pumps.iter().any(|pump| pump.element().is_running())
+            })
+            .collect();
+        let suction_feed_is_possible =
+            context.pressure_altitude() < Length::new::<foot>(Self::SUCTION_FEED_CEILING_FEET);
+
+        for (i, engine) in engines.iter().enumerate() {
+            let own_tank: usize = Self::WING_TANKS[i].0.into();
+            let other_side = 1 - i;
+
+            let source = if !self.low_pressure_valves[i].is_open() {
+                None
+            } else if is_pressurised[i] {
+                Some(own_tank)
+            } else if self.crossfeed_valve.is_open() && is_pressurised[other_side] {
+                Some(Self::WING_TANKS[other_side].0.into())
+            } else {
+                (suction_feed_is_possible && fuel_system.tank_has_fuel(own_tank))
+                    .then_some(own_tank)
+            };
+
+            self.engine_is_fed[i] = source.is_some();
+            if let Some(tank) = source {
+                fuel_system.consume(tank, engine.fuel_flow() * context.delta_as_time());
+            }
+        }
+    }
+
+    fn transfer_center_tank(&self, context: &UpdateContext, fuel_system: &mut FuelSystem<5>) {
+        let transfer = Mass::new::<kilogram>(
+            Self::CENTER_TANK_TRANSFER_RATE_KG_PER_SECOND * context.delta_as_secs_f64(),
+        );
+
+        for (pump, (inner, _)) in self.center_tank_pumps.iter().zip(Self::WING_TANKS) {
+            if pump.element().is_running() {
+                fuel_system.transfer(A320FuelTankType::Center.into(), inner.into(), transfer);
+            }
+        }
+    }
+
+    fn transfer_outer_tanks(&self, context: &UpdateContext, fuel_system: &mut FuelSystem<5>) {
+        let transfer = Mass::new::<kilogram>(
+            Self::OUTER_TANK_TRANSFER_RATE_KG_PER_SECOND * context.delta_as_secs_f64(),
+        );
+
+        for (valve, (inner, outer)) in self.outer_tank_transfer_valves.iter().zip(Self::WING_TANKS)
+        {
+            if valve.is_open() {
+                fuel_system.transfer(outer.into(), inner.into(), transfer);
+            }
+        }
+    }
+
+    fn engine_is_fed(&self, engine_number: usize) -> bool {
+        self.engine_is_fed[engine_number - 1]
+    }
+}
+impl SimulationElement for A320FuelFeedSystem {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.wing_pumps
+            .iter_mut()
+            .flatten()
+            .for_each(|pump| pump.accept(visitor));
+        accept_iterable!(self.center_tank_pumps, visitor);
+        accept_iterable!(self.low_pressure_valves, visitor);
+        self.crossfeed_valve.accept(visitor);
+        accept_iterable!(self.outer_tank_transfer_valves, visitor);
+
+        visitor.visit(self);
+    }
+}
+
+/// The fuel overhead panel and engine masters are still provided by the simulator fuel system
+/// switches, so their state is read from there.
+pub struct A320FuelOverheadPanel {
+    wing_pump_switch_ids: [[VariableIdentifier; 2]; 2],
+    center_tank_pump_switch_ids: [VariableIdentifier; 2],
+    engine_master_switch_ids: [VariableIdentifier; 2],
+    crossfeed_switch_id: VariableIdentifier,
+    mode_select_manual_id: VariableIdentifier,
+
+    wing_pumps_are_on: [[bool; 2]; 2],
+    center_tank_pumps_are_on: [bool; 2],
+    engine_masters_are_on: [bool; 2],
+    crossfeed_is_on: bool,
+    mode_select_is_manual: bool,
+}
+impl A320FuelOverheadPanel {
+    pub fn new(context: &mut InitContext) -> Self {
+        Self {
+            wing_pump_switch_ids: [[2, 5], [3, 6]].map(|pumps| {
+                pumps.map(|n| context.get_identifier(format!("FUELSYSTEM PUMP SWITCH:{}", n)))
+            }),
+            center_tank_pump_switch_ids: [9, 10]
+                .map(|n| context.get_identifier(format!("FUELSYSTEM VALVE SWITCH:{}", n))),
+            engine_master_switch_ids: [1, 2]
+                .map(|n| context.get_identifier(format!("FUELSYSTEM VALVE SWITCH:{}", n))),
+            crossfeed_switch_id: context.get_identifier("FUELSYSTEM VALVE SWITCH:3".to_owned()),
+            mode_select_manual_id: context.get_identifier("OVHD_FUEL_MODESEL_MANUAL".to_owned()),
+
+            wing_pumps_are_on: [[true; 2]; 2],
+            center_tank_pumps_are_on: [true; 2],
+            engine_masters_are_on: [false; 2],
+            crossfeed_is_on: false,
+            mode_select_is_manual: false,
+        }
+    }
+
+    fn wing_pump_is_on(&self, side: usize, pump_number: usize) -> bool {
+        self.wing_pumps_are_on[side - 1][pump_number - 1]
+    }
+
+    fn center_tank_pump_is_on(&self, side: usize) -> bool {
+        self.center_tank_pumps_are_on[side - 1]
+    }
+
+    fn engine_master_is_on(&self, engine_number: usize) -> bool {
+        self.engine_masters_are_on[engine_number - 1]
+    }
+
+    fn crossfeed_is_on(&self) -> bool {
+        self.crossfeed_is_on
+    }
+
+    fn mode_select_is_manual(&self) -> bool {
+        self.mode_select_is_manual
+    }
+}
+impl SimulationElement for A320FuelOverheadPanel {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        for (ids, are_on) in self
+            .wing_pump_switch_ids
+            .iter()
+            .zip(&mut self.wing_pumps_are_on)
+        {
+            for (id, is_on) in ids.iter().zip(are_on) {
+                *is_on = reader.read(id);
+            }
+        }
+        for (id, is_on) in self
+            .center_tank_pump_switch_ids
+            .iter()
+            .zip(&mut self.center_tank_pumps_are_on)
+        {
+            *is_on = reader.read(id);
+        }
+        for (id, is_on) in self
+            .engine_master_switch_ids
+            .iter()
+            .zip(&mut self.engine_masters_are_on)
+        {
+            *is_on = reader.read(id);
+        }
+        self.crossfeed_is_on = reader.read(&self.crossfeed_switch_id);
+        self.mode_select_is_manual = reader.read(&self.mode_select_manual_id);
+    }
 }
@@ -1,38 +1,135 @@
 use std::time::Duration;
 
+use ntest::{assert_false, assert_true};
+use systems::{
+    electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
+    engine::EngineFireOverheadPanel,
+    failures::FailureType,
+    fuel::FUEL_GALLONS_TO_KG,
+    shared::{ElectricalBusType, PotentialOrigin},
+    simulation::test::ReadByName,
+};
+use uom::si::{electric_potential::volt, mass_rate::kilogram_per_hour};
+
 use super::*;
 use crate::systems::simulation::{
     test::{SimulationTestBed, TestBed, WriteByName},
     Aircraft, SimulationElement, SimulationElementVisitor,
 };
 
+struct TestEngine {
+    fuel_flow: MassRate,
+}
+impl EngineFuelFlow for TestEngine {
+    fn fuel_flow(&self) -> MassRate {
+        self.fuel_flow
+    }
+}
+
 struct FuelTestAircraft {
+    electricity_source: TestElectricitySource,
+    ac_buses: [ElectricalBus; 2],
+    dc_buses: [ElectricalBus; 4],
+    ac_is_powered: bool,
+
     fuel: A320Fuel,
+    fuel_overhead: A320FuelOverheadPanel,
+    engine_fire_overhead: EngineFireOverheadPanel<2>,
+    engines: [TestEngine; 2],
 }
 
 impl FuelTestAircraft {
     fn new(context: &mut InitContext) -> Self {
         Self {
-            fuel: A320Fuel::new(context),
+            electricity_source: TestElectricitySource::powered(
+                context,
+                PotentialOrigin::EngineGenerator(1),
+            ),
+            ac_buses: [
+                ElectricalBus::new(context, ElectricalBusType::AlternatingCurrent(1)),
+                ElectricalBus::new(context, ElectricalBusType::AlternatingCurrent(2)),
+            ],
+            dc_buses: [
+                ElectricalBus::new(context, ElectricalBusType::DirectCurrentHot(1)),
+                ElectricalBus::new(context, ElectricalBusType::DirectCurrentHot(2)),
+                ElectricalBus::new(context, ElectricalBusType::DirectCurrentEssential),
+                ElectricalBus::new(context, ElectricalBusType::DirectCurrent(2)),
+            ],
+            ac_is_powered: true,
+
+            fuel: A320Fuel::new(context, FuelModel::Native),
+            fuel_overhead: A320FuelOverheadPanel::new(context),
+            engine_fire_overhead: EngineFireOverheadPanel::new(context),
+            engines: [(); 2].map(|_| TestEngine {
+                fuel_flow: MassRate::default(),
+            }),
         }
     }
 
+    fn set_engines_fuel_flow(&mut self, fuel_flow: MassRate) {
+        self.engines
+            .iter_mut()
+            .for_each(|engine| engine.fuel_flow = fuel_flow);
+    }
+
+    fn set_ac_powered(&mut self, is_powered: bool) {
+        self.ac_is_powered = is_powered;
+    }
+
+    fn engine_is_fed(&self, engine_number: usize) -> bool {
+        self.fuel.engine_is_fed(engine_number)
+    }
+
     fn fore_aft_center_of_gravity(&self) -> f64 {
         self.fuel.fore_aft_center_of_gravity()
     }
+
+    fn tank_mass(&self, tank: A320FuelTankType) -> Mass {
+        self.fuel.fuel_system.tank_mass(tank.into())
+    }
 }
 
-impl Aircraft for FuelTestAircraft {}
+impl Aircraft for FuelTestAircraft {
+    fn update_before_power_distribution(
+        &mut self,
+        _context: &UpdateContext,
+        electricity: &mut Electricity,
+    ) {
+        self.electricity_source
+            .power_with_potential(ElectricPotential::new::<volt>(115.));
+        electricity.supplied_by(&self.electricity_source);
+
+        for bus in &self.dc_buses {
+            electricity.flow(&self.electricity_source, bus);
+        }
+        if self.ac_is_powered {
+            for bus in &self.ac_buses {
+                electricity.flow(&self.electricity_source, bus);
+            }
+        }
+    }
+
+    fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+        let [engine_1, engine_2] = &self.engines;
+        self.fuel.update(
+            context,
+            &self.fuel_overhead,
+            &self.engine_fire_overhead,
+            [engine_1, engine_2],
+        );
+    }
+}
 impl SimulationElement for FuelTestAircraft {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.fuel.accept(visitor);
+        self.fuel_overhead.accept(visitor);
+        self.engine_fire_overhead.accept(visitor);
 
         visitor.visit(self);
     }
 }
 
 const MINUTES_TO_SECONDS: u64 = 60;
-const FUEL_GALLONS_TO_KG: f64 = 3.039075693483925;
 const LBS_TO_KG: f64 = 0.4535934;
 
 struct FuelTestBed {
@@ -82,6 +179,113 @@ impl FuelTestBed {
         self
     }
 
+    fn fuel_normal(mut self) -> Self {
+        self.write_by_name("FUEL TANK LEFT MAIN QUANTITY", 4000. / FUEL_GALLONS_TO_KG);
+        self.write_by_name("FUEL TANK LEFT AUX QUANTITY", 690. / FUEL_GALLONS_TO_KG);
+        self.write_by_name("FUEL TANK RIGHT MAIN QUANTITY", 4000. / FUEL_GALLONS_TO_KG);
+        self.write_by_name("FUEL TANK RIGHT AUX QUANTITY", 690. / FUEL_GALLONS_TO_KG);
+        self.write_by_name("FUEL TANK CENTER QUANTITY", 2000. / FUEL_GALLONS_TO_KG);
+
+        self
+    }
+
+    fn inner_tanks_kg(mut self, kg: f64) -> Self {
+        self.write_by_name("FUEL TANK LEFT MAIN QUANTITY", kg / FUEL_GALLONS_TO_KG);
+        self.write_by_name("FUEL TANK RIGHT MAIN QUANTITY", kg / FUEL_GALLONS_TO_KG);
+
+        self
+    }
+
+    fn fuel_system_on(mut self) -> Self {
+        for pump in [2, 3, 5, 6] {
+            self.write_by_name(&format!("FUELSYSTEM PUMP SWITCH:{}", pump), true);
+        }
+        for valve in [1, 2, 9, 10] {
+            self.write_by_name(&format!("FUELSYSTEM VALVE SWITCH:{}", valve), true);
+        }
+
+        self
+    }
+
+    fn left_wing_pumps_off(mut self) -> Self {
+        self.write_by_name("FUELSYSTEM PUMP SWITCH:2", false);
+        self.write_by_name("FUELSYSTEM PUMP SWITCH:5", false);
+
+        self
+    }
+
+    fn crossfeed_on(mut self) -> Self {
+        self.write_by_name("FUELSYSTEM VALVE SWITCH:3", true);
+
+        self
+    }
+
+    fn engine_1_master_off(mut self) -> Self {
+        self.write_by_name("FUELSYSTEM VALVE SWITCH:1", false);
+
+        self
+    }
+
+    fn engine_1_fire_push_button_released(mut self) -> Self {
+        self.write_by_name("FIRE_BUTTON_ENG1", true);
+
+        self
+    }
+
+    fn mode_select_manual(mut self) -> Self {
+        self.write_by_name("OVHD_FUEL_MODESEL_MANUAL", true);
+
+        self
+    }
+
+    fn engines_running(mut self) -> Self {
+        self.command(|a| a.set_engines_fuel_flow(MassRate::new::<kilogram_per_hour>(3600.)));
+
+        self
+    }
+
+    fn ac_unpowered(mut self) -> Self {
+        self.command(|a| a.set_ac_powered(false));
+
+        self
+    }
+
+    fn in_cruise(mut self) -> Self {
+        self.set_on_ground(false);
+        self.set_pressure_altitude(Length::new::<foot>(35000.));
+
+        self
+    }
+
+    fn run_for(mut self, duration: Duration) -> Self {
+        self.test_bed.run_multiple_frames(duration);
+
+        self
+    }
+
+    fn and_fail(mut self, failure_type: FailureType) -> Self {
+        self.fail(failure_type);
+
+        self
+    }
+
+    fn engine_is_fed(&self, engine_number: usize) -> bool {
+        self.query(|a: &FuelTestAircraft| a.engine_is_fed(engine_number))
+    }
+
+    fn pump_is_running(&mut self, number: usize) -> bool {
+        self.read_by_name(&format!("FUEL_PUMP_{}_IS_RUNNING", number))
+    }
+
+    fn valve_is_open(&mut self, number: usize) -> bool {
+        self.read_by_name(&format!("FUEL_VALVE_{}_IS_OPEN", number))
+    }
+
+    fn tank_mass_kg(&self, tank: A320FuelTankType) -> f64 {
+        self.query(|a: &FuelTestAircraft| a.tank_mass(tank))
+            .get::<kilogram>()
+    }
+
     fn fore_aft_center_of_gravity(&self) -> f64 {
         self.query(|a: &FuelTestAircraft| a.fore_aft_center_of_gravity())
     }
@@ -144,3 +348,180 @@ fn high_fuel() {
         (test_bed.fore_aft_center_of_gravity() * 100.).round() / 100.,
     );
 }
+
+#[test]
+fn engines_are_fed_by_their_wing_pumps() {
+    let mut test_bed = test_bed_with()
+        .fuel_normal()
+        .inner_tanks_kg(5300.)
+        .fuel_system_on()
+        .in_cruise()
+        .engines_running();
+    test_bed = test_bed.run_for(Duration::from_secs(60));
+
+    assert_true!(test_bed.engine_is_fed(1));
+    assert_true!(test_bed.engine_is_fed(2));
+    assert_true!(test_bed.pump_is_running(2));
+    assert_true!(test_bed.valve_is_open(1));
+    assert!(test_bed.tank_mass_kg(A320FuelTankType::LeftInner) < 5300.);
+    assert!(test_bed.tank_mass_kg(A320FuelTankType::RightInner) < 5300.);
+}
+
+#[test]
+fn engine_is_not_fed_when_master_is_off() {
+    let mut test_bed = test_bed_with()
+        .fuel_normal()
+        .fuel_system_on()
+        .engine_1_master_off()
+        .engines_running();
+    test_bed = test_bed.run_for(Duration::from_secs(10));
+
+    assert_false!(test_bed.engine_is_fed(1));
+    assert_true!(test_bed.engine_is_fed(2));
+}
+
+#[test]
+fn engine_is_not_fed_when_fire_push_button_is_released() {
+    let mut test_bed = test_bed_with()
+        .fuel_normal()
+        .fuel_system_on()
+        .engine_1_fire_push_button_released()
+        .engines_running();
+    test_bed = test_bed.run_for(Duration::from_secs(10));
+
+    assert_false!(test_bed.engine_is_fed(1));
+    assert_true!(test_bed.engine_is_fed(2));
+}
+
+#[test]
+fn loss_of_ac_power_starves_engines_above_suction_feed_ceiling() {
+    let mut test_bed = test_bed_with()
+        .fuel_normal()
+        .fuel_system_on()
+        .in_cruise()
+        .engines_running()
+        .run_for(Duration::from_secs(1));
+    test_bed = test_bed.ac_unpowered().run_for(Duration::from_secs(10));
+
+    assert_false!(test_bed.pump_is_running(2));
+    assert_false!(test_bed.engine_is_fed(1));
+    assert_false!(test_bed.engine_is_fed(2));
+}
+
+#[test]
+fn loss_of_ac_power_below_suction_feed_ceiling_keeps_engines_fed() {
+    let mut test_bed = test_bed_with()
+        .fuel_normal()
+        .fuel_system_on()
+        .engines_running()
+        .run_for(Duration::from_secs(1));
+    test_bed = test_bed.ac_unpowered().run_for(Duration::from_secs(10));
+
+    assert_true!(test_bed.engine_is_fed(1));
+    assert_true!(test_bed.engine_is_fed(2));
+}
+
+#[test]
+fn failed_wing_pumps_starve_engine_in_cruise() {
+    let mut test_bed = test_bed_with()
+        .fuel_normal()
+        .fuel_system_on()
+        .in_cruise()
+        .engines_running()
+        .and_fail(FailureType::FuelPump(2))
+        .and_fail(FailureType::FuelPump(5));
+    test_bed = test_bed.run_for(Duration::from_secs(10));
+
+    assert_false!(test_bed.engine_is_fed(1));
+    assert_true!(test_bed.engine_is_fed(2));
+}
+
+#[test]
+fn crossfeed_feeds_engine_from_opposite_wing() {
+    let mut test_bed = test_bed_with()
+        .fuel_normal()
+        .fuel_system_on()
+        .left_wing_pumps_off()
+        .crossfeed_on()
+        .in_cruise()
+        .engines_running();
+    test_bed = test_bed.run_for(Duration::from_secs(60));
+
+    assert_true!(test_bed.engine_is_fed(1));
+    assert_true!(test_bed.engine_is_fed(2));
+    assert!(
+        test_bed.tank_mass_kg(A320FuelTankType::LeftInner)
+            > test_bed.tank_mass_kg(A320FuelTankType::RightInner)
+    );
+}
+
+#[test]
+fn center_tank_auto_mode_tops_up_inner_tanks() {
+    let mut test_bed = test_bed_with()
+        .fuel_normal()
+        .fuel_system_on()
+        .in_cruise()
+        .engines_running();
+    test_bed = test_bed.run_for(Duration::from_secs(120));
+
+    assert_true!(test_bed.pump_is_running(1));
+    assert!(test_bed.tank_mass_kg(A320FuelTankType::Center) < 2000.);
+    assert!(test_bed.tank_mass_kg(A320FuelTankType::LeftInner) > 4000.);
+}
+
+#[test]
+fn center_tank_auto_mode_does_not_transfer_into_full_inner_tanks() {
+    let mut test_bed = test_bed_with()
+        .fuel_normal()
+        .inner_tanks_kg(5300.)
+        .fuel_system_on()
+        .in_cruise()
+        .engines_running();
+    test_bed = test_bed.run_for(Duration::from_secs(60));
+
+    assert_false!(test_bed.pump_is_running(1));
+    assert!((test_bed.tank_mass_kg(A320FuelTankType::Center) - 2000.).abs() < 1.);
+}
+
+#[test]
+fn center_tank_manual_mode_transfers_into_full_inner_tanks() {
+    let mut test_bed = test_bed_with()
+        .fuel_normal()
+        .inner_tanks_kg(5300.)
+        .fuel_system_on()
+        .mode_select_manual()
+        .in_cruise()
+        .engines_running();
+    test_bed = test_bed.run_for(Duration::from_secs(60));
+
+    assert_true!(test_bed.pump_is_running(1));
+    assert!(test_bed.tank_mass_kg(A320FuelTankType::Center) < 2000.);
+}
+
+#[test]
+fn outer_tanks_transfer_when_inner_tank_reaches_low_level() {
+    let mut test_bed = test_bed_with()
+        .fuel_normal()
+        .inner_tanks_kg(700.)
+        .fuel_system_on()
+        .in_cruise()
+        .engines_running();
+    test_bed = test_bed.run_for(Duration::from_secs(60));
+
+    assert_true!(test_bed.valve_is_open(4));
+    assert_true!(test_bed.valve_is_open(5));
+    assert!(test_bed.tank_mass_kg(A320FuelTankType::LeftOuter) < 690.);
+}
+
+#[test]
+fn outer_tanks_do_not_transfer_above_inner_tank_low_level() {
+    let mut test_bed = test_bed_with()
+        .fuel_normal()
+        .fuel_system_on()
+        .in_cruise()
+        .engines_running();
+    test_bed = test_bed.run_for(Duration::from_secs(60));
+
+    assert_false!(test_bed.valve_is_open(4));
+    assert!((test_bed.tank_mass_kg(A320FuelTankType::LeftOuter) - 690.).abs() < 1.);
+}
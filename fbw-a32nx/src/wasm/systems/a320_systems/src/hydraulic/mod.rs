@@ -19,13 +19,16 @@ use uom::si::{
 
 use systems::{
     accept_iterable,
+    aircraft_configuration::BrakeTemperatureModel,
     engine::Engine,
+    failures::FailureType,
     hydraulic::{
         aerodynamic_model::AerodynamicModel,
         brake_circuit::{
             AutobrakeDecelerationGovernor, AutobrakeMode, AutobrakePanel,
             BrakeAccumulatorCharacteristics, BrakeCircuit, BrakeCircuitController,
         },
+        brake_thermal::{BrakeThermalConstants, BrakeThermalModel},
         bypass_pin::BypassPin,
         cargo_doors::{CargoDoor, HydraulicDoorController},
         electrical_generator::{GeneratorControlUnit, HydraulicGeneratorMotor},
@@ -54,7 +57,10 @@ use systems::{
         PowerTransferUnitCharacteristics, PowerTransferUnitController, PressureSwitch,
         PressureSwitchType, PriorityValve, PumpController, RamAirTurbine, Reservoir,
     },
-    landing_gear::{GearSystemSensors, LandingGearControlInterfaceUnitSet},
+    landing_gear::{
+        tyre::{TyreConstants, TyreDrag, TyreModel, TyrePressureIndicatingSystem},
+        GearSystemSensors, LandingGearControlInterfaceUnitSet,
+    },
     overhead::{
         AutoOffFaultPushButton, AutoOnFaultPushButton, MomentaryOnPushButton, MomentaryPushButton,
     },
@@ -64,9 +70,9 @@ use systems::{
         AirbusEngineDrivenPumpId, DelayedFalseLogicGate, DelayedPulseTrueLogicGate,
         DelayedTrueLogicGate, ElectricalBusType, ElectricalBuses, EmergencyElectricalRatPushButton,
         EmergencyElectricalState, EmergencyGeneratorControlUnit, EmergencyGeneratorPower,
-        EngineFirePushButtons, GearWheel, HydraulicColor, LandingGearHandle, LgciuInterface,
-        LgciuWeightOnWheels, RamAirTurbineController, ReservoirAirPressure, ReverserPosition,
-        SectionPressure, TrimmableHorizontalStabilizer,
+        EngineFirePushButtons, FlightControlSurface, GearWheel, HydraulicColor, LandingGearHandle,
+        LgciuInterface, LgciuWeightOnWheels, RamAirTurbineController, ReservoirAirPressure,
+        ReverserPosition, SectionPressure, TrimmableHorizontalStabilizer,
     },
     simulation::{
         InitContext, Read, Reader, SimulationElement, SimulationElementVisitor, SimulatorReader,
@@ -425,11 +431,20 @@ impl A320AileronFactory {
     fn a320_aileron_assembly(
         context: &mut InitContext,
         init_drooped_down: bool,
+        surface: FlightControlSurface,
     ) -> HydraulicLinearActuatorAssembly<2> {
         let aileron_body = Self::a320_aileron_body(init_drooped_down);
 
-        let aileron_actuator_outward = Self::a320_aileron_actuator(context, &aileron_body);
-        let aileron_actuator_inward = Self::a320_aileron_actuator(context, &aileron_body);
+        let aileron_actuator_outward = with_actuator_failures(
+            Self::a320_aileron_actuator(context, &aileron_body),
+            surface,
+            1,
+        );
+        let aileron_actuator_inward = with_actuator_failures(
+            Self::a320_aileron_actuator(context, &aileron_body),
+            surface,
+            2,
+        );
 
         HydraulicLinearActuatorAssembly::new(
             [aileron_actuator_outward, aileron_actuator_inward],
@@ -439,7 +454,11 @@ impl A320AileronFactory {
 
     fn new_aileron(context: &mut InitContext, id: ActuatorSide) -> AileronAssembly {
         let init_drooped_down = !context.is_in_flight();
-        let assembly = Self::a320_aileron_assembly(context, init_drooped_down);
+        let surface = match id {
+            ActuatorSide::Left => FlightControlSurface::LeftAileron,
+            ActuatorSide::Right => FlightControlSurface::RightAileron,
+        };
+        let assembly = Self::a320_aileron_assembly(context, init_drooped_down, surface);
         AileronAssembly::new(context, id, assembly, Self::new_a320_aileron_aero_model())
     }
 
@@ -676,11 +695,20 @@ impl A320ElevatorFactory {
     fn a320_elevator_assembly(
         context: &mut InitContext,
         init_drooped_down: bool,
+        surface: FlightControlSurface,
     ) -> HydraulicLinearActuatorAssembly<2> {
         let elevator_body = Self::a320_elevator_body(init_drooped_down);
 
-        let elevator_actuator_outboard = Self::a320_elevator_actuator(context, &elevator_body);
-        let elevator_actuator_inbord = Self::a320_elevator_actuator(context, &elevator_body);
+        let elevator_actuator_outboard = with_actuator_failures(
+            Self::a320_elevator_actuator(context, &elevator_body),
+            surface,
+            1,
+        );
+        let elevator_actuator_inbord = with_actuator_failures(
+            Self::a320_elevator_actuator(context, &elevator_body),
+            surface,
+            2,
+        );
 
         HydraulicLinearActuatorAssembly::new(
             [elevator_actuator_outboard, elevator_actuator_inbord],
@@ -690,7 +718,11 @@ impl A320ElevatorFactory {
 
     fn new_elevator(context: &mut InitContext, id: ActuatorSide) -> ElevatorAssembly {
         let init_drooped_down = !context.is_in_flight();
-        let assembly = Self::a320_elevator_assembly(context, init_drooped_down);
+        let surface = match id {
+            ActuatorSide::Left => FlightControlSurface::LeftElevator,
+            ActuatorSide::Right => FlightControlSurface::RightElevator,
+        };
+        let assembly = Self::a320_elevator_assembly(context, init_drooped_down, surface);
         ElevatorAssembly::new(context, id, assembly, Self::new_a320_elevator_aero_model())
     }
 
@@ -791,9 +823,21 @@ impl A320RudderFactory {
     ) -> HydraulicLinearActuatorAssembly<3> {
         let rudder_body = Self::a320_rudder_body(init_at_center);
 
-        let rudder_actuator_green = Self::a320_rudder_actuator(context, &rudder_body);
-        let rudder_actuator_blue = Self::a320_rudder_actuator(context, &rudder_body);
-        let rudder_actuator_yellow = Self::a320_rudder_actuator(context, &rudder_body);
+        let rudder_actuator_green = with_actuator_failures(
+            Self::a320_rudder_actuator(context, &rudder_body),
+            FlightControlSurface::Rudder,
+            1,
+        );
+        let rudder_actuator_blue = with_actuator_failures(
+            Self::a320_rudder_actuator(context, &rudder_body),
+            FlightControlSurface::Rudder,
+            2,
+        );
+        let rudder_actuator_yellow = with_actuator_failures(
+            Self::a320_rudder_actuator(context, &rudder_body),
+            FlightControlSurface::Rudder,
+            3,
+        );
 
         HydraulicLinearActuatorAssembly::new(
             [
@@ -1490,6 +1534,10 @@ pub(super) struct A320Hydraulic {
     braking_circuit_norm: BrakeCircuit,
     braking_circuit_altn: BrakeCircuit,
     braking_force: A320BrakingForce,
+    brake_temperatures: BrakeThermalModel<A320BrakeThermalConstants, 4>,
+    tyres: TyreModel<A320TyreConstants, 6>,
+    tpis: TyrePressureIndicatingSystem<6>,
+    tyre_drag: TyreDrag<6>,
 
     flap_system: FlapSlatAssembly,
     slat_system: FlapSlatAssembly,
@@ -1575,12 +1623,26 @@ impl A320Hydraulic {
     const RAT_CONTROL_SOLENOID2_POWER_BUS: ElectricalBusType =
         ElectricalBusType::DirectCurrentHot(2);
 
+    const BRAKE_FAN_POWER_BUS: ElectricalBusType = ElectricalBusType::AlternatingCurrent(2);
+    const BRAKE_FAN_RATED_CURRENT_AMPERE: f64 = 10.;
+    const TPIS_POWER_BUS: ElectricalBusType = ElectricalBusType::DirectCurrent(1);
+
+    // Tyres 1 to 4 are on the braked main gear wheels, 5 and 6 on the nose gear. Odd tyres are
+    // on the left.
+    const BRAKED_WHEELS: usize = 4;
+    const TYRE_LATERAL_POSITIONS_METERS: [f64; 6] = [-4.2, -3.4, 3.4, 4.2, -0.2, 0.2];
+    const LEFT_NOSE_TYRE: usize = 4;
+    const RIGHT_NOSE_TYRE: usize = 5;
+
     const ALTERNATE_BRAKE_ACCUMULATOR_GAS_PRE_CHARGE: f64 = 1000.0; // Nitrogen PSI
 
     // Refresh rate of core hydraulic simulation
     const HYDRAULIC_SIM_TIME_STEP: Duration = Duration::from_millis(10);
 
-    pub(super) fn new(context: &mut InitContext) -> A320Hydraulic {
+    pub(super) fn new(
+        context: &mut InitContext,
+        brake_temperature_model: BrakeTemperatureModel,
+    ) -> A320Hydraulic {
         let brake_accumulator_charac = BrakeAccumulatorCharacteristics::new(
             Volume::new::<gallon>(1.0),
             Pressure::new::<psi>(Self::ALTERNATE_BRAKE_ACCUMULATOR_GAS_PRE_CHARGE),
@@ -1715,6 +1777,18 @@ impl A320Hydraulic {
             ),
 
             braking_force: A320BrakingForce::new(context),
+            brake_temperatures: BrakeThermalModel::new(
+                context,
+                Self::BRAKE_FAN_POWER_BUS,
+                ElectricCurrent::new::<ampere>(Self::BRAKE_FAN_RATED_CURRENT_AMPERE),
+                brake_temperature_model == BrakeTemperatureModel::Native,
+            ),
+            tyres: TyreModel::new(context),
+            tpis: TyrePressureIndicatingSystem::new(context, Self::TPIS_POWER_BUS),
+            tyre_drag: TyreDrag::new(
+                context,
+                Self::TYRE_LATERAL_POSITIONS_METERS.map(Length::new::<meter>),
+            ),
 
             flap_system: FlapSlatAssembly::new(
                 context,
@@ -2086,6 +2160,11 @@ impl A320Hydraulic {
         engine1: &impl Engine,
         engine2: &impl Engine,
     ) {
+        self.nose_steering.set_authority(
+            (self.tyres.steering_efficiency(Self::LEFT_NOSE_TYRE)
+                + self.tyres.steering_efficiency(Self::RIGHT_NOSE_TYRE))
+                / 2.,
+        );
         self.nose_steering.update(
             context,
             self.yellow_circuit.system_section(),
@@ -2128,6 +2207,26 @@ impl A320Hydraulic {
             engine2,
             &self.bypass_pin,
         );
+        self.braking_force
+            .correct_with_brake_temperatures(&self.brake_temperatures);
+        self.braking_force.correct_with_tyres(&self.tyres);
+        self.brake_temperatures
+            .update(context, self.braking_force.wheel_braking_forces(), lgciu1);
+        self.tyres.update(
+            context,
+            std::array::from_fn(|wheel| {
+                if wheel < Self::BRAKED_WHEELS {
+                    self.brake_temperatures.temperature(wheel)
+                } else {
+                    context.ambient_temperature()
+                }
+            }),
+            std::array::from_fn(|wheel| {
+                wheel < Self::BRAKED_WHEELS && self.brake_temperatures.fuse_plug_melted(wheel)
+            }),
+        );
+        self.tpis.update(&self.tyres);
+        self.tyre_drag.update(context, &self.tyres);
 
         self.slats_flaps_complex
             .update(context, &self.flap_system, &self.slat_system);
@@ -2585,6 +2684,10 @@ impl SimulationElement for A320Hydraulic {
         self.braking_circuit_norm.accept(visitor);
         self.braking_circuit_altn.accept(visitor);
         self.braking_force.accept(visitor);
+        self.brake_temperatures.accept(visitor);
+        self.tyres.accept(visitor);
+        self.tpis.accept(visitor);
+        self.tyre_drag.accept(visitor);
 
         self.emergency_gen.accept(visitor);
         self.nose_steering.accept(visitor);
@@ -3889,6 +3992,33 @@ impl SteeringController for A320HydraulicBrakeSteerComputerUnit {
     }
 }
 
+struct A320BrakeThermalConstants;
+impl BrakeThermalConstants for A320BrakeThermalConstants {
+    const HEAT_SINK_MASS_KG: f64 = 70.;
+    const HEAT_SINK_SPECIFIC_HEAT_J_PER_KG_PER_KELVIN: f64 = 1420.;
+
+    const MAX_BRAKING_DECELERATION_METER_PER_SECOND_SQUARED: f64 = 5.;
+
+    const NATURAL_CONVECTION_WATT_PER_KELVIN: f64 = 21.;
+    const AIRFLOW_CONVECTION_WATT_PER_KELVIN_PER_METER_PER_SECOND: f64 = 2.;
+    const GEAR_BAY_CONVECTION_FACTOR: f64 = 0.5;
+    const BRAKE_FAN_CONVECTION_WATT_PER_KELVIN: f64 = 45.;
+    const BRAKE_FAN_POWER_WATT: f64 = 800.;
+
+    const BRAKES_HOT_TEMPERATURE_DEGREE_CELSIUS: f64 = 300.;
+    const FUSE_PLUG_MELT_TEMPERATURE_DEGREE_CELSIUS: f64 = 650.;
+
+    const EFFICIENCY_TEMPERATURE_BREAKPOINTS_DEGREE_CELSIUS: &'static [f64] = &[600., 900., 1200.];
+    const EFFICIENCY_RATIO: &'static [f64] = &[1., 0.8, 0.5];
+}
+
+struct A320TyreConstants;
+impl TyreConstants for A320TyreConstants {
+    const NOMINAL_COLD_PRESSURE_PSI: f64 = 200.;
+    const TREAD_LIFE_METERS: f64 = 2_000_000.;
+    const LOW_PRESSURE_RATIO: f64 = 0.88;
+}
+
 struct A320BrakingForce {
     brake_left_force_factor_id: VariableIdentifier,
     brake_right_force_factor_id: VariableIdentifier,
@@ -3968,6 +4098,42 @@ impl A320BrakingForce {
         self.update_chocks_braking(context, engine1, engine2, bypass_pin);
     }
 
+    /// Braking force applied on each wheel, brakes 1 and 2 being on the left gear.
+    fn wheel_braking_forces(&self) -> [Ratio; 4] {
+        let left = Ratio::new::<ratio>(self.left_braking_force);
+        let right = Ratio::new::<ratio>(self.right_braking_force);
+
+        [left, left, right, right]
+    }
+
+    /// Hot brakes fade and deliver less force for the same brake pressure.
+    fn correct_with_brake_temperatures(
+        &mut self,
+        brake_temperatures: &BrakeThermalModel<A320BrakeThermalConstants, 4>,
+    ) {
+        let side_efficiency = |first_wheel: usize| {
+            (brake_temperatures.braking_efficiency(first_wheel)
+                + brake_temperatures.braking_efficiency(first_wheel + 1))
+            .get::<ratio>()
+                / 2.
+        };
+
+        self.left_braking_force *= side_efficiency(0);
+        self.right_braking_force *= side_efficiency(2);
+    }
+
+    /// Deflated and worn tyres transmit less braking force.
+    fn correct_with_tyres(&mut self, tyres: &TyreModel<A320TyreConstants, 6>) {
+        let side_efficiency = |first_wheel: usize| {
+            (tyres.braking_efficiency(first_wheel) + tyres.braking_efficiency(first_wheel + 1))
+                .get::<ratio>()
+                / 2.
+        };
+
+        self.left_braking_force *= side_efficiency(0);
+        self.right_braking_force *= side_efficiency(2);
+    }
+
     fn correct_with_flaps_state(&mut self, context: &UpdateContext) {
         let flap_correction = Ratio::new::<percent>(interpolation(
             &Self::FLAPS_BREAKPOINTS,
@@ -5172,6 +5338,18 @@ impl SimulationElement for RudderSystemHydraulicController {
     }
 }
 
+/// Attaches the jam and runaway failures of the given flight control surface actuator.
+fn with_actuator_failures(
+    actuator: LinearActuator,
+    surface: FlightControlSurface,
+    number: usize,
+) -> LinearActuator {
+    actuator.with_failures(
+        FailureType::FlightControlActuatorJam(surface, number),
+        FailureType::FlightControlActuatorRunaway(surface, number),
+    )
+}
+
 #[derive(PartialEq, Clone, Copy)]
 enum ActuatorSide {
     Left,
@@ -6007,8 +6185,11 @@ mod tests {
             angular_velocity::degree_per_second,
             electric_potential::volt,
             length::foot,
+            mass::kilogram,
             mass_density::kilogram_per_cubic_meter,
             ratio::{percent, ratio},
+            thermodynamic_temperature::degree_celsius,
+            velocity::foot_per_second,
             volume::liter,
         };
 
@@ -6180,7 +6361,7 @@ mod tests {
                     pneumatics: A320TestPneumatics::new(),
                     engine_1: LeapEngine::new(context, 1),
                     engine_2: LeapEngine::new(context, 2),
-                    hydraulics: A320Hydraulic::new(context),
+                    hydraulics: A320Hydraulic::new(context, BrakeTemperatureModel::Native),
                     overhead: A320HydraulicOverheadPanel::new(context),
                     autobrake_panel: AutobrakePanel::new(context),
                     emergency_electrical_overhead: A320TestEmergencyElectricalOverheadPanel::new(
@@ -6646,6 +6827,26 @@ mod tests {
                 self.read_by_name("HYD_BRAKE_ALTN_ACC_PRESS")
             }
 
+            fn get_brake_temperature(&mut self, brake_number: usize) -> ThermodynamicTemperature {
+                self.read_by_name(&format!("BRAKE_TEMPERATURE_{}", brake_number))
+            }
+
+            fn get_brake_left_force_factor(&mut self) -> f64 {
+                self.read_by_name("BRAKE LEFT FORCE FACTOR")
+            }
+
+            fn get_brake_right_force_factor(&mut self) -> f64 {
+                self.read_by_name("BRAKE RIGHT FORCE FACTOR")
+            }
+
+            fn get_tyre_drag_delta_speed(&mut self) -> f64 {
+                self.read_by_name("TYRE_DRAG_DELTA_SPEED")
+            }
+
+            fn get_tyre_drag_angular_acceleration(&mut self) -> f64 {
+                self.read_by_name("TYRE_DRAG_ANGULAR_ACCELERATION")
+            }
+
             fn get_brake_yellow_accumulator_fluid_volume(&self) -> Volume {
                 self.query(|a| a.get_yellow_brake_accumulator_fluid_volume())
             }
@@ -8885,6 +9086,77 @@ mod tests {
             assert!(test_bed.get_brake_right_yellow_pressure() < Pressure::new::<psi>(3500.));
         }
 
+        #[test]
+        fn braking_at_speed_heats_the_brakes() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .start_eng1(Ratio::new::<percent>(100.))
+                .start_eng2(Ratio::new::<percent>(100.))
+                .set_park_brake(false)
+                .run_waiting_for(Duration::from_secs(5));
+
+            test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(15.));
+            test_bed.write_by_name("TOTAL WEIGHT", Mass::new::<kilogram>(64000.));
+            test_bed.write_by_name("GPS GROUND SPEED", Velocity::new::<knot>(100.));
+            test_bed = test_bed.run_waiting_for(Duration::from_secs(1));
+
+            let initial_temperature = test_bed.get_brake_temperature(1);
+
+            test_bed = test_bed
+                .set_left_brake(Ratio::new::<percent>(100.))
+                .set_right_brake(Ratio::new::<percent>(100.))
+                .run_waiting_for(Duration::from_secs(5));
+
+            for brake_number in 1..=4 {
+                assert!(
+                    test_bed
+                        .get_brake_temperature(brake_number)
+                        .get::<degree_celsius>()
+                        > initial_temperature.get::<degree_celsius>() + 50.
+                );
+            }
+        }
+
+        #[test]
+        fn burst_tyre_reduces_braking_and_drags_on_its_side() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .start_eng1(Ratio::new::<percent>(100.))
+                .start_eng2(Ratio::new::<percent>(100.))
+                .set_park_brake(false)
+                .run_waiting_for(Duration::from_secs(5));
+
+            test_bed.write_by_name("GPS GROUND SPEED", Velocity::new::<knot>(100.));
+            test_bed.write_by_name(
+                "VELOCITY BODY Z",
+                Velocity::new::<knot>(100.).get::<foot_per_second>(),
+            );
+            test_bed.write_by_name("TOTAL WEIGHT", Mass::new::<kilogram>(64000.));
+            test_bed.write_by_name("TOTAL WEIGHT YAW MOI", 3_000_000.);
+            test_bed.fail(FailureType::TyreBurst(1));
+            test_bed = test_bed.run_waiting_for(Duration::from_secs(1));
+
+            // The drag of the tyre is not applied through the pedal brakes
+            assert!(test_bed.get_brake_left_force_factor() < 0.01);
+            assert!(test_bed.get_brake_right_force_factor() < 0.01);
+            assert!(test_bed.get_tyre_drag_delta_speed() < 0.);
+            assert!(test_bed.get_tyre_drag_angular_acceleration() < 0.);
+
+            test_bed = test_bed
+                .set_left_brake(Ratio::new::<percent>(100.))
+                .set_right_brake(Ratio::new::<percent>(100.))
+                .run_waiting_for(Duration::from_secs(1));
+
+            assert!(
+                test_bed.get_brake_left_force_factor()
+                    < test_bed.get_brake_right_force_factor() - 0.2
+            );
+        }
+
         #[test]
         fn no_norm_brake_inversion() {
             let mut test_bed = test_bed_on_ground_with()
@@ -10453,6 +10725,33 @@ mod tests {
             assert!(test_bed.nose_steering_position().get::<degree>() >= -75.1);
         }
 
+        #[test]
+        fn nose_steering_authority_reduced_by_burst_nose_tyre() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .set_yellow_e_pump(false)
+                .start_eng1(Ratio::new::<percent>(80.))
+                .start_eng2(Ratio::new::<percent>(80.))
+                .run_waiting_for(Duration::from_secs_f64(1.));
+
+            test_bed.fail(FailureType::TyreBurst(5));
+            test_bed = test_bed
+                .set_tiller_demand(Ratio::new::<ratio>(1.))
+                .run_waiting_for(Duration::from_secs_f64(5.));
+
+            assert!(test_bed.nose_steering_position().get::<degree>() >= 50.);
+            assert!(test_bed.nose_steering_position().get::<degree>() <= 55.);
+
+            test_bed = test_bed
+                .set_tiller_demand(Ratio::new::<ratio>(-1.))
+                .run_waiting_for(Duration::from_secs_f64(10.));
+
+            assert!(test_bed.nose_steering_position().get::<degree>() <= -50.);
+            assert!(test_bed.nose_steering_position().get::<degree>() >= -55.);
+        }
+
         #[test]
         fn nose_steering_does_not_move_if_yellow_pressure_but_no_engine() {
             let mut test_bed = test_bed_on_ground_with()
@@ -3,6 +3,8 @@ extern crate systems;
 mod air_conditioning;
 mod airframe;
 mod electrical;
+pub mod failures;
+mod fire_and_smoke_protection;
 mod fuel;
 pub mod hydraulic;
 mod navigation;
@@ -12,7 +14,8 @@ mod power_consumption;
 
 use self::{
     air_conditioning::A320AirConditioning,
-    fuel::A320Fuel,
+    fire_and_smoke_protection::A320FireAndSmokeProtection,
+    fuel::{A320Fuel, A320FuelOverheadPanel},
     payload::A320Payload,
     pneumatic::{A320Pneumatic, A320PneumaticOverheadPanel},
 };
@@ -22,8 +25,14 @@ use electrical::{
     APU_START_MOTOR_BUS_TYPE,
 };
 use hydraulic::{A320Hydraulic, A320HydraulicOverheadPanel};
-use navigation::{A320AirDataInertialReferenceSystemBuilder, A320RadioAltimeters};
+use navigation::{
+    A320AirDataInertialReferenceSystemBuilder, A320MultiModeReceivers, A320RadioAltimeters,
+};
 use power_consumption::A320PowerConsumption;
+use std::{path::Path, sync::OnceLock};
+use systems::aircraft_configuration::{
+    AircraftConfiguration, AircraftConfigurationError, EngineModel, SystemModels,
+};
 use systems::enhanced_gpwc::EnhancedGroundProximityWarningComputer;
 use systems::simulation::InitContext;
 use uom::si::{f64::Length, length::nautical_mile};
@@ -36,16 +45,71 @@ use systems::{
         AuxiliaryPowerUnitOverheadPanel,
     },
     electrical::{Electricity, ElectricitySource, ExternalPowerSource},
-    engine::{leap_engine::LeapEngine, reverser_thrust::ReverserForce, EngineFireOverheadPanel},
+    engine::{
+        leap_engine::{Leap1A26Constants, LeapEngine},
+        reverser_thrust::ReverserForce,
+        spool_engine::SpoolEngine,
+        EngineFireOverheadPanel, SelectableEngine,
+    },
     hydraulic::brake_circuit::AutobrakePanel,
     landing_gear::{LandingGear, LandingGearControlInterfaceUnitSet},
     navigation::adirs::{
         AirDataInertialReferenceSystem, AirDataInertialReferenceSystemOverheadPanel,
     },
-    shared::ElectricalBusType,
+    shared::{ElectricalBusType, EngineStarterAirPressure},
     simulation::{Aircraft, SimulationElement, SimulationElementVisitor, UpdateContext},
 };
 
+type A320Engine = SelectableEngine<LeapEngine, Leap1A26Constants>;
+
+static AIRCRAFT_CONFIGURATION: OnceLock<(String, AircraftConfiguration)> = OnceLock::new();
+const EMBEDDED_AIRCRAFT_CONFIGURATION: &str = include_str!("aircraft_configuration.toml");
+
+/// Loads the aircraft configuration from the file at `path`, falling back to the embedded
+/// `aircraft_configuration.toml` when there is no such file. Has to be called before the aircraft
+/// is constructed. Once a configuration is in use, loading another one has no effect. An invalid
+/// file loads nothing, such that the aircraft is built from the embedded configuration.
+pub fn load_aircraft_configuration(
+    path: impl AsRef<Path>,
+) -> Result<(), AircraftConfigurationError> {
+    load_aircraft_configuration_toml(AircraftConfiguration::read_toml_file_or(
+        path,
+        EMBEDDED_AIRCRAFT_CONFIGURATION,
+    )?)
+}
+
+/// Loads the aircraft configuration from the given TOML text, such as the configuration stored in
+/// a recording. The same rules as for [`load_aircraft_configuration`] apply.
+pub fn load_aircraft_configuration_toml(
+    configuration: String,
+) -> Result<(), AircraftConfigurationError> {
+    let parsed = AircraftConfiguration::from_toml(&configuration)?;
+    let _ = AIRCRAFT_CONFIGURATION.set((configuration, parsed));
+
+    Ok(())
+}
+
+/// The TOML text of the configuration the aircraft is built from.
+pub fn aircraft_configuration_toml() -> &'static str {
+    &loaded_aircraft_configuration().0
+}
+
+/// Fuel tanks and payload stations of the aircraft, as loaded by [`load_aircraft_configuration`]
+/// or, when none was loaded, as described in the embedded `aircraft_configuration.toml`.
+pub(crate) fn aircraft_configuration() -> &'static AircraftConfiguration {
+    &loaded_aircraft_configuration().1
+}
+
+fn loaded_aircraft_configuration() -> &'static (String, AircraftConfiguration) {
+    AIRCRAFT_CONFIGURATION.get_or_init(|| {
+        (
+            EMBEDDED_AIRCRAFT_CONFIGURATION.to_owned(),
+            AircraftConfiguration::from_toml(EMBEDDED_AIRCRAFT_CONFIGURATION)
+                .unwrap_or_else(|e| panic!("Invalid A320 aircraft configuration: {}", e)),
+        )
+    })
+}
+
 pub struct A320 {
     adirs: AirDataInertialReferenceSystem,
     adirs_overhead: AirDataInertialReferenceSystemOverheadPanel,
@@ -60,9 +124,11 @@ pub struct A320 {
     payload: A320Payload,
     airframe: A320Airframe,
     fuel: A320Fuel,
-    engine_1: LeapEngine,
-    engine_2: LeapEngine,
+    fuel_overhead: A320FuelOverheadPanel,
+    engine_1: A320Engine,
+    engine_2: A320Engine,
     engine_fire_overhead: EngineFireOverheadPanel<2>,
+    fire_and_smoke_protection: A320FireAndSmokeProtection,
     electrical: A320Electrical,
     power_consumption: A320PowerConsumption,
     ext_pwr: ExternalPowerSource,
@@ -73,11 +139,18 @@ pub struct A320 {
     landing_gear: LandingGear,
     pneumatic: A320Pneumatic,
     radio_altimeters: A320RadioAltimeters,
+    multi_mode_receivers: A320MultiModeReceivers,
     egpwc: EnhancedGroundProximityWarningComputer,
     reverse_thrust: ReverserForce,
 }
 impl A320 {
     pub fn new(context: &mut InitContext) -> A320 {
+        A320::with_models(context, aircraft_configuration().models())
+    }
+
+    /// Creates the aircraft simulated with the given models instead of the ones selected by the
+    /// aircraft configuration.
+    pub fn with_models(context: &mut InitContext, models: SystemModels) -> A320 {
         A320 {
             adirs: A320AirDataInertialReferenceSystemBuilder::build(context),
             adirs_overhead: AirDataInertialReferenceSystemOverheadPanel::new(context),
@@ -97,10 +170,12 @@ impl A320 {
             emergency_electrical_overhead: A320EmergencyElectricalOverheadPanel::new(context),
             payload: A320Payload::new(context),
             airframe: A320Airframe::new(context),
-            fuel: A320Fuel::new(context),
-            engine_1: LeapEngine::new(context, 1),
-            engine_2: LeapEngine::new(context, 2),
+            fuel: A320Fuel::new(context, models.fuel),
+            fuel_overhead: A320FuelOverheadPanel::new(context),
+            engine_1: A320::new_engine(context, 1, models.engine),
+            engine_2: A320::new_engine(context, 2, models.engine),
             engine_fire_overhead: EngineFireOverheadPanel::new(context),
+            fire_and_smoke_protection: A320FireAndSmokeProtection::new(context),
             electrical: A320Electrical::new(context),
             power_consumption: A320PowerConsumption::new(context),
             ext_pwr: ExternalPowerSource::new(context, 1),
@@ -109,12 +184,13 @@ impl A320 {
                 ElectricalBusType::DirectCurrentEssential,
                 ElectricalBusType::DirectCurrentGndFltService,
             ),
-            hydraulic: A320Hydraulic::new(context),
+            hydraulic: A320Hydraulic::new(context, models.brake_temperature),
             hydraulic_overhead: A320HydraulicOverheadPanel::new(context),
             autobrake_panel: AutobrakePanel::new(context),
             landing_gear: LandingGear::new(context, false),
             pneumatic: A320Pneumatic::new(context),
             radio_altimeters: A320RadioAltimeters::new(context),
+            multi_mode_receivers: A320MultiModeReceivers::new(context),
             egpwc: EnhancedGroundProximityWarningComputer::new(
                 context,
                 ElectricalBusType::DirectCurrent(1),
@@ -131,6 +207,17 @@ impl A320 {
             reverse_thrust: ReverserForce::new(context),
         }
     }
+
+    fn new_engine(context: &mut InitContext, number: usize, model: EngineModel) -> A320Engine {
+        match model {
+            EngineModel::Simulator => {
+                SelectableEngine::SimulatorVariables(LeapEngine::new(context, number))
+            }
+            EngineModel::Spool => {
+                SelectableEngine::Spool(Box::new(SpoolEngine::new(context, number)))
+            }
+        }
+    }
 }
 impl Aircraft for A320 {
     fn update_before_power_distribution(
@@ -138,10 +225,21 @@ impl Aircraft for A320 {
         context: &UpdateContext,
         electricity: &mut Electricity,
     ) {
+        self.engine_1.update(
+            context,
+            self.pneumatic.starter_air_pressure(1),
+            self.fuel.engine_is_fed(1),
+        );
+        self.engine_2.update(
+            context,
+            self.pneumatic.starter_air_pressure(2),
+            self.fuel.engine_is_fed(2),
+        );
+
         self.apu.update_before_electrical(
             context,
             &self.apu_overhead,
-            false, // Todo: fire detection system
+            self.fire_and_smoke_protection.apu_fire_on_ground(),
             &self.apu_fire_overhead,
             self.pneumatic_overhead.apu_bleed_is_on(),
             // This will be replaced when integrating the whole electrical system.
@@ -150,6 +248,7 @@ impl Aircraft for A320 {
             self.electrical_overhead.apu_generator_is_on()
                 && !(self.electrical_overhead.external_power_is_on()
                     && self.electrical_overhead.external_power_is_available()),
+            &self.electrical_overhead,
             self.pneumatic.apu_bleed_air_valve(),
             self.fuel.left_inner_tank_has_fuel_remaining(),
         );
@@ -194,8 +293,22 @@ impl Aircraft for A320 {
             self.ext_pwr.output_potential().is_powered(),
         );
 
+        self.fire_and_smoke_protection.update(
+            context,
+            &self.engine_fire_overhead,
+            &self.apu_fire_overhead,
+            [self.lgcius.lgciu1(), self.lgcius.lgciu2()],
+        );
+
         self.radio_altimeters.update(context);
 
+        self.fuel.update(
+            context,
+            &self.fuel_overhead,
+            &self.engine_fire_overhead,
+            [&self.engine_1, &self.engine_2],
+        );
+
         self.hydraulic.update(
             context,
             &self.engine_1,
@@ -224,7 +337,15 @@ impl Aircraft for A320 {
 
         self.hydraulic_overhead.update(&self.hydraulic);
 
-        self.adirs.update(context, &self.adirs_overhead);
+        self.multi_mode_receivers.update(context);
+        self.adirs.update(
+            context,
+            &self.adirs_overhead,
+            [
+                self.multi_mode_receivers.mmr_1(),
+                self.multi_mode_receivers.mmr_2(),
+            ],
+        );
         self.adirs_overhead.update(context, &self.adirs);
 
         self.power_consumption.update(context);
@@ -251,7 +372,16 @@ impl Aircraft for A320 {
             [self.lgcius.lgciu1(), self.lgcius.lgciu2()],
         );
 
-        self.egpwc.update(&self.adirs, self.lgcius.lgciu1());
+        self.egpwc.update(
+            context,
+            &self.adirs,
+            self.multi_mode_receivers.mmr_1(),
+            [
+                self.radio_altimeters.radio_altimeter_1(),
+                self.radio_altimeters.radio_altimeter_2(),
+            ],
+            self.lgcius.lgciu1(),
+        );
     }
 }
 impl SimulationElement for A320 {
@@ -268,15 +398,18 @@ impl SimulationElement for A320 {
         self.electrical_overhead.accept(visitor);
         self.emergency_electrical_overhead.accept(visitor);
         self.fuel.accept(visitor);
+        self.fuel_overhead.accept(visitor);
         self.pneumatic_overhead.accept(visitor);
         self.engine_1.accept(visitor);
         self.engine_2.accept(visitor);
         self.engine_fire_overhead.accept(visitor);
+        self.fire_and_smoke_protection.accept(visitor);
         self.electrical.accept(visitor);
         self.power_consumption.accept(visitor);
         self.ext_pwr.accept(visitor);
         self.lgcius.accept(visitor);
         self.radio_altimeters.accept(visitor);
+        self.multi_mode_receivers.accept(visitor);
         self.autobrake_panel.accept(visitor);
         self.hydraulic.accept(visitor);
         self.hydraulic_overhead.accept(visitor);
@@ -288,3 +421,249 @@ impl SimulationElement for A320 {
         visitor.visit(self);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{fs, path::Path, time::Duration};
+    use systems::{
+        aircraft_configuration::{BrakeTemperatureModel, FuelModel},
+        failures::FailureType,
+        simulation::{
+            recording::replay,
+            scenario::{run_scenario, Scenario},
+            test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+        },
+    };
+    use uom::si::length::foot;
+
+    #[test]
+    fn recorded_flight_replays_identically() {
+        let failures = [(32_200, FailureType::TyreBurst(1))];
+        let mut test_bed =
+            SimulationTestBed::new_recorded(7, aircraft_configuration_toml(), &failures, A320::new);
+
+        test_bed.run_multiple_frames(Duration::from_secs(5));
+        test_bed.fail(FailureType::TyreBurst(1));
+        test_bed.run_multiple_frames(Duration::from_secs(5));
+
+        let recording = test_bed.finish_recording();
+        let report = replay(&recording, A320::new, &failures, 0.);
+
+        assert_eq!(recording.configuration(), aircraft_configuration_toml());
+        assert!(report.ticks() > 0);
+        assert!(report.is_identical(), "{:?}", report.divergences().first());
+    }
+
+    #[test]
+    fn scenarios_hold() {
+        let directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("scenarios");
+        for entry in fs::read_dir(directory).unwrap() {
+            let path = entry.unwrap().path();
+            if path
+                .extension()
+                .map_or(true, |extension| extension != "toml")
+            {
+                continue;
+            }
+
+            let scenario = Scenario::from_toml(&fs::read_to_string(&path).unwrap())
+                .unwrap_or_else(|e| panic!("{}: {}", path.display(), e));
+            let report = run_scenario(&scenario, A320::new, failures::FAILURES)
+                .unwrap_or_else(|e| panic!("{}: {}", path.display(), e));
+
+            assert!(report.is_successful(), "{}: {}", path.display(), report);
+        }
+    }
+
+    fn test_bed_with_engines_running_on(engine: EngineModel) -> SimulationTestBed<A320> {
+        test_bed_with_engines_running(SystemModels {
+            engine,
+            ..Default::default()
+        })
+    }
+
+    fn test_bed_with_engines_running(models: SystemModels) -> SimulationTestBed<A320> {
+        let mut test_bed = SimulationTestBed::new(|context| A320::with_models(context, models));
+        test_bed.write_by_name("FUEL TANK LEFT MAIN QUANTITY", 1000.);
+        test_bed.write_by_name("FUEL TANK RIGHT MAIN QUANTITY", 1000.);
+        for number in 1..=2 {
+            test_bed.write_by_name(&format!("GENERAL ENG COMBUSTION:{}", number), true);
+            test_bed.write_by_name(&format!("GENERAL ENG FUEL VALVE:{}", number), true);
+            test_bed.write_by_name(&format!("FUELSYSTEM VALVE SWITCH:{}", number), true);
+        }
+        for number in [2, 3, 5, 6] {
+            test_bed.write_by_name(&format!("FUELSYSTEM PUMP SWITCH:{}", number), true);
+        }
+        test_bed.run_multiple_frames(Duration::from_secs(10));
+
+        test_bed
+    }
+
+    fn engine_n2(test_bed: &mut SimulationTestBed<A320>, number: usize) -> f64 {
+        test_bed.read_by_name(&format!("SPOOL_ENGINE_{}_N2", number))
+    }
+
+    #[test]
+    fn spool_engines_drive_the_aircraft_systems() {
+        let mut test_bed = test_bed_with_engines_running_on(EngineModel::Spool);
+
+        let n2: f64 = test_bed.read_by_name("SPOOL_ENGINE_1_N2");
+        let green_pressure: f64 = test_bed.read_by_name("HYD_GREEN_SYSTEM_1_SECTION_PRESSURE");
+        assert!(n2 > 50.);
+        assert!(green_pressure > 2500.);
+    }
+
+    #[test]
+    fn simulator_engine_model_takes_the_engines_from_the_simulator() {
+        let mut test_bed = test_bed_with_engines_running_on(EngineModel::Simulator);
+
+        let green_pressure: f64 = test_bed.read_by_name("HYD_GREEN_SYSTEM_1_SECTION_PRESSURE");
+        assert!(!test_bed.contains_variable_with_name("SPOOL_ENGINE_1_N2"));
+        assert!(green_pressure < 500.);
+    }
+
+    #[test]
+    fn spool_engine_spools_down_when_its_fuel_valve_closes() {
+        let mut test_bed = test_bed_with_engines_running_on(EngineModel::Spool);
+
+        test_bed.write_by_name("GENERAL ENG FUEL VALVE:1", false);
+        test_bed.run_multiple_frames(Duration::from_secs(60));
+
+        let n2: f64 = test_bed.read_by_name("SPOOL_ENGINE_1_N2");
+        assert!(n2 < 20.);
+    }
+
+    fn cruise_with_engine_1_fed_by_ac_1_only(models: SystemModels) -> SimulationTestBed<A320> {
+        let mut test_bed = test_bed_with_engines_running(models);
+        test_bed.set_on_ground(false);
+        test_bed.set_pressure_altitude(Length::new::<foot>(35000.));
+        test_bed.write_by_name("FUELSYSTEM PUMP SWITCH:5", false);
+        test_bed.run_multiple_frames(Duration::from_secs(10));
+
+        test_bed
+    }
+
+    #[test]
+    fn losing_an_ac_bus_in_cruise_starves_the_engine_fed_by_it() {
+        let mut test_bed = cruise_with_engine_1_fed_by_ac_1_only(SystemModels {
+            engine: EngineModel::Spool,
+            fuel: FuelModel::Native,
+            ..Default::default()
+        });
+        assert!(engine_n2(&mut test_bed, 1) > 50.);
+
+        test_bed.fail(FailureType::ElectricalBus(
+            ElectricalBusType::AlternatingCurrent(1),
+        ));
+        test_bed.run_multiple_frames(Duration::from_secs(60));
+
+        assert!(engine_n2(&mut test_bed, 1) < 20.);
+        assert!(engine_n2(&mut test_bed, 2) > 50.);
+    }
+
+    #[test]
+    fn losing_an_ac_bus_in_cruise_does_not_starve_engines_fed_by_the_simulator() {
+        let mut test_bed = cruise_with_engine_1_fed_by_ac_1_only(SystemModels {
+            engine: EngineModel::Spool,
+            fuel: FuelModel::Simulator,
+            ..Default::default()
+        });
+
+        test_bed.fail(FailureType::ElectricalBus(
+            ElectricalBusType::AlternatingCurrent(1),
+        ));
+        test_bed.run_multiple_frames(Duration::from_secs(60));
+
+        assert!(engine_n2(&mut test_bed, 1) > 50.);
+    }
+
+    #[test]
+    fn native_fuel_model_takes_over_the_fuel_quantities_from_the_fadec() {
+        let mut test_bed = test_bed_with_engines_running(SystemModels {
+            fuel: FuelModel::Native,
+            ..Default::default()
+        });
+
+        let native_model_is_active: bool = test_bed.read_by_name("FUEL_NATIVE_MODEL_IS_ACTIVE");
+        assert!(native_model_is_active);
+    }
+
+    #[test]
+    fn simulator_fuel_model_leaves_the_fuel_quantities_to_the_fadec() {
+        let mut test_bed = test_bed_with_engines_running(SystemModels::default());
+
+        let native_model_is_active: bool = test_bed.read_by_name("FUEL_NATIVE_MODEL_IS_ACTIVE");
+        assert!(!native_model_is_active);
+    }
+
+    #[test]
+    fn instruments_brake_temperature_model_leaves_the_brake_temperatures_to_the_instruments() {
+        let mut test_bed = SimulationTestBed::new(|context| {
+            A320::with_models(
+                context,
+                SystemModels {
+                    brake_temperature: BrakeTemperatureModel::Instruments,
+                    ..Default::default()
+                },
+            )
+        });
+        test_bed.run();
+
+        assert!(!test_bed.contains_variable_with_name("BRAKE_TEMPERATURE_1"));
+        assert!(!test_bed.contains_variable_with_name("BRAKE_FUSE_PLUG_MELTED_1"));
+    }
+
+    #[test]
+    fn native_brake_temperature_model_owns_the_brake_temperatures() {
+        let mut test_bed = SimulationTestBed::new(|context| {
+            A320::with_models(
+                context,
+                SystemModels {
+                    brake_temperature: BrakeTemperatureModel::Native,
+                    ..Default::default()
+                },
+            )
+        });
+        test_bed.run();
+
+        assert!(test_bed.contains_variable_with_name("BRAKE_TEMPERATURE_1"));
+        assert!(test_bed.contains_variable_with_name("BRAKE_FUSE_PLUG_MELTED_1"));
+    }
+
+    fn test_bed_with_external_power() -> SimulationTestBed<A320> {
+        let mut test_bed = SimulationTestBed::new(A320::new);
+        test_bed.write_by_name("EXTERNAL POWER AVAILABLE:1", true);
+        test_bed.write_by_name("OVHD_ELEC_EXT_PWR_PB_IS_ON", true);
+        test_bed.run_multiple_frames(Duration::from_secs(10));
+
+        test_bed
+    }
+
+    fn fuel_pump_is_running(test_bed: &mut SimulationTestBed<A320>, number: usize) -> bool {
+        test_bed.read_by_name(&format!("FUEL_PUMP_{}_IS_RUNNING", number))
+    }
+
+    #[test]
+    fn seized_fuel_pump_trips_its_circuit_breaker_which_unpowers_the_pump_until_reset() {
+        let mut test_bed = test_bed_with_external_power();
+        test_bed.write_by_name("FUELSYSTEM PUMP SWITCH:2", true);
+        test_bed.run();
+        assert!(fuel_pump_is_running(&mut test_bed, 2));
+
+        test_bed.fail(FailureType::FuelPump(2));
+        test_bed.run_multiple_frames(Duration::from_secs(2));
+        let is_tripped: bool = test_bed.read_by_name("ELEC_CB_FUEL_PUMP_2_IS_TRIPPED");
+        assert!(is_tripped);
+
+        test_bed.unfail(FailureType::FuelPump(2));
+        test_bed.run_multiple_frames(Duration::from_secs(1));
+        assert!(!fuel_pump_is_running(&mut test_bed, 2));
+
+        test_bed.write_by_name("ELEC_CB_FUEL_PUMP_2_IS_PULLED", true);
+        test_bed.run_multiple_frames(Duration::from_secs(15));
+        test_bed.write_by_name("ELEC_CB_FUEL_PUMP_2_IS_PULLED", false);
+        test_bed.run_multiple_frames(Duration::from_secs(1));
+        assert!(fuel_pump_is_running(&mut test_bed, 2));
+    }
+}
@@ -5,8 +5,9 @@ use systems::navigation::adirs::{
 use systems::navigation::ala52b::{
     Ala52BAircraftInstallationDelay, Ala52BRadioAltimeter, Ala52BTransceiverPair,
 };
-use systems::navigation::radio_altimeter::AntennaInstallation;
-use systems::shared::{ElectricalBusType, MachNumber};
+use systems::navigation::mmr::MultiModeReceiver;
+use systems::navigation::radio_altimeter::{AntennaInstallation, RadioAltimeter};
+use systems::shared::{arinc429::Arinc429Word, ElectricalBusType, MachNumber};
 use systems::simulation::{
     InitContext, SimulationElement, SimulationElementVisitor, UpdateContext,
 };
@@ -43,6 +44,46 @@ impl A320AirDataInertialReferenceSystemBuilder {
     }
 }
 
+pub struct A320MultiModeReceivers {
+    mmr_1: MultiModeReceiver,
+    mmr_2: MultiModeReceiver,
+}
+
+impl A320MultiModeReceivers {
+    pub fn new(context: &mut InitContext) -> Self {
+        Self {
+            mmr_1: MultiModeReceiver::new(
+                context,
+                1,
+                ElectricalBusType::AlternatingCurrentEssentialShed,
+            ),
+            mmr_2: MultiModeReceiver::new(context, 2, ElectricalBusType::AlternatingCurrent(2)),
+        }
+    }
+
+    pub fn mmr_1(&self) -> &MultiModeReceiver {
+        &self.mmr_1
+    }
+
+    pub fn mmr_2(&self) -> &MultiModeReceiver {
+        &self.mmr_2
+    }
+
+    pub fn update(&mut self, context: &UpdateContext) {
+        self.mmr_1.update(context);
+        self.mmr_2.update(context);
+    }
+}
+
+impl SimulationElement for A320MultiModeReceivers {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.mmr_1.accept(visitor);
+        self.mmr_2.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
 pub struct A320RadioAltimeters {
     radio_altimeter_1: A320RadioAltimeter,
     radio_altimeter_2: A320RadioAltimeter,
@@ -87,6 +128,14 @@ impl A320RadioAltimeters {
         }
     }
 
+    pub fn radio_altimeter_1(&self) -> &A320RadioAltimeter {
+        &self.radio_altimeter_1
+    }
+
+    pub fn radio_altimeter_2(&self) -> &A320RadioAltimeter {
+        &self.radio_altimeter_2
+    }
+
     pub fn update(&mut self, context: &UpdateContext) {
         self.radio_altimeter_1.update(context);
         self.radio_altimeter_2.update(context);
@@ -131,6 +180,12 @@ impl A320RadioAltimeter {
     }
 }
 
+impl RadioAltimeter for A320RadioAltimeter {
+    fn radio_altitude(&self) -> Arinc429Word<Length> {
+        self.radio_altimeter.radio_altitude()
+    }
+}
+
 impl SimulationElement for A320RadioAltimeter {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.transceivers.accept(visitor);
@@ -4,11 +4,9 @@ use std::{cell::Cell, rc::Rc};
 
 use uom::si::{f64::Mass, mass::kilogram};
 
+use crate::aircraft_configuration;
 use systems::{
-    payload::{
-        BoardingAgent, BoardingSounds, Cargo, CargoDeck, CargoInfo, CargoPayload,
-        NumberOfPassengers, PassengerDeck, PassengerPayload, Pax, PaxInfo, PayloadManager,
-    },
+    payload::{BoardingSounds, CargoPayload, NumberOfPassengers, PassengerPayload, PayloadManager},
     simulation::{InitContext, SimulationElement, SimulationElementVisitor, UpdateContext},
 };
 
@@ -70,107 +68,19 @@ pub struct A320Payload {
 impl A320Payload {
     // Note: These constants reflect flight_model.cfg values and will have to be updated in sync with the configuration
     pub const DEFAULT_PER_PAX_WEIGHT_KG: f64 = 84.;
-    const A320_PAX: [PaxInfo<'static>; 4] = [
-        PaxInfo {
-            max_pax: 36,
-            position: (20.5, 0., 5.),
-            pax_id: "PAX_A",
-            payload_id: "PAYLOAD_STATION_1_REQ",
-        },
-        PaxInfo {
-            max_pax: 42,
-            position: (1.5, 0., 5.1),
-            pax_id: "PAX_B",
-            payload_id: "PAYLOAD_STATION_2_REQ",
-        },
-        PaxInfo {
-            max_pax: 48,
-            position: (-16.6, 0., 5.3),
-            pax_id: "PAX_C",
-            payload_id: "PAYLOAD_STATION_3_REQ",
-        },
-        PaxInfo {
-            max_pax: 48,
-            position: (-35.6, 0., 5.3),
-            pax_id: "PAX_D",
-            payload_id: "PAYLOAD_STATION_4_REQ",
-        },
-    ];
-
-    const A320_CARGO: [CargoInfo<'static>; 4] = [
-        CargoInfo {
-            max_cargo_kg: 3402.,
-            position: (17.3, 0., 0.),
-            cargo_id: "CARGO_FWD_BAGGAGE_CONTAINER",
-            payload_id: "PAYLOAD_STATION_5_REQ",
-        },
-        CargoInfo {
-            max_cargo_kg: 2426.,
-            position: (-24.1, 0., 1.),
-            cargo_id: "CARGO_AFT_CONTAINER",
-            payload_id: "PAYLOAD_STATION_6_REQ",
-        },
-        CargoInfo {
-            max_cargo_kg: 2110.,
-            position: (-34.1, 0., 1.2),
-            cargo_id: "CARGO_AFT_BAGGAGE",
-            payload_id: "PAYLOAD_STATION_7_REQ",
-        },
-        CargoInfo {
-            max_cargo_kg: 1497.,
-            position: (-42.4, 0., 1.4),
-            cargo_id: "CARGO_AFT_BULK_LOOSE",
-            payload_id: "PAYLOAD_STATION_8_REQ",
-        },
-    ];
-
     pub fn new(context: &mut InitContext) -> Self {
         let per_pax_weight = Rc::new(Cell::new(Mass::new::<kilogram>(
             Self::DEFAULT_PER_PAX_WEIGHT_KG,
         )));
         let developer_state = Rc::new(Cell::new(0));
         let boarding_sounds = BoardingSounds::new(context);
-        let pax = Self::A320_PAX.map(|p| {
-            Pax::new(
-                context.get_identifier(p.pax_id.to_owned()),
-                context.get_identifier(format!("{}_DESIRED", p.pax_id)),
-                context.get_identifier(p.payload_id.to_owned()),
-                developer_state.clone(),
-                per_pax_weight.clone(),
-                Vector3::new(p.position.0, p.position.1, p.position.2),
-                p.max_pax,
-            )
-        });
-
-        let cargo = Self::A320_CARGO.map(|c| {
-            Cargo::new(
-                context.get_identifier(c.cargo_id.to_owned()),
-                context.get_identifier(format!("{}_DESIRED", c.cargo_id)),
-                context.get_identifier(c.payload_id.to_owned()),
-                developer_state.clone(),
-                Vector3::new(c.position.0, c.position.1, c.position.2),
-                Mass::new::<kilogram>(c.max_cargo_kg),
-            )
-        });
-        let default_boarding_agent = BoardingAgent::new(None, [0, 1, 2, 3]);
-
-        let boarding_agents = [
-            BoardingAgent::new(
-                Some(context.get_identifier("INTERACTIVE POINT OPEN:0".to_owned())),
-                [0, 1, 2, 3],
-            ),
-            BoardingAgent::new(
-                Some(context.get_identifier("INTERACTIVE POINT OPEN:1".to_owned())),
-                [0, 1, 2, 3],
-            ),
-            BoardingAgent::new(
-                Some(context.get_identifier("INTERACTIVE POINT OPEN:2".to_owned())),
-                [3, 2, 1, 0],
-            ),
-        ];
-
-        let passenger_deck = PassengerDeck::new(pax, default_boarding_agent, boarding_agents);
-        let cargo_deck = CargoDeck::new(cargo);
+        let configuration = aircraft_configuration();
+        let passenger_deck = configuration
+            .passenger_deck(context, developer_state.clone(), per_pax_weight.clone())
+            .unwrap_or_else(|e| panic!("Invalid A320 passenger configuration: {}", e));
+        let cargo_deck = configuration
+            .cargo_deck(context, developer_state.clone())
+            .unwrap_or_else(|e| panic!("Invalid A320 cargo configuration: {}", e));
 
         A320Payload {
             payload_manager: PayloadManager::new(
@@ -13,6 +13,7 @@ use uom::si::{
 use systems::{
     accept_iterable,
     air_conditioning::PackFlowControllers,
+    failures::FailureType,
     overhead::{AutoOffFaultPushButton, OnOffFaultPushButton},
     pneumatic::{
         valve::*, BleedMonitoringComputerChannelOperationMode,
@@ -27,8 +28,8 @@ use systems::{
     shared::{
         pid::PidController, update_iterator::MaxStepLoop, ControllerSignal, DelayedTrueLogicGate,
         ElectricalBusType, ElectricalBuses, EngineBleedPushbutton, EngineCorrectedN1,
-        EngineCorrectedN2, EngineFirePushButtons, EngineStartState, HydraulicColor,
-        LgciuWeightOnWheels, PackFlowValveState, PneumaticBleed, PneumaticValve,
+        EngineCorrectedN2, EngineFirePushButtons, EngineStartState, EngineStarterAirPressure,
+        HydraulicColor, LgciuWeightOnWheels, PackFlowValveState, PneumaticBleed, PneumaticValve,
         ReservoirAirPressure,
     },
     simulation::{
@@ -398,6 +399,11 @@ impl EngineStartState for A320Pneumatic {
         self.fadec.engine_mode_selector()
     }
 }
+impl EngineStarterAirPressure for A320Pneumatic {
+    fn starter_air_pressure(&self, engine_number: usize) -> Pressure {
+        self.engine_systems[engine_number - 1].starter_air_pressure()
+    }
+}
 impl PackFlowValveState for A320Pneumatic {
     // pack_id: 1 or 2
     fn pack_flow_valve_is_open(&self, pack_id: usize) -> bool {
@@ -1067,6 +1073,7 @@ struct EngineBleedAirSystem {
     engine_starter_valve: DefaultValve,
     fan_air_valve: ElectroPneumaticValve,
     precooler: Precooler,
+    bleed_leak: PneumaticExhaust,
 
     transfer_pressure_transducer: PressureTransducer,
     regulated_pressure_transducer: PressureTransducer,
@@ -1074,6 +1081,7 @@ struct EngineBleedAirSystem {
     bleed_temperature_sensor: BleedTemperatureSensor,
 }
 impl EngineBleedAirSystem {
+    const BLEED_LEAK_EXHAUST_SPEED: f64 = 1.;
     const MIN_ENGINE_START_CONTAINER_PRESSURE_PSIG_HIGH: f64 = 10.;
     const MIN_ENGINE_START_CONTAINER_PRESSURE_PSIG_LOW: f64 = 5.;
 
@@ -1142,7 +1150,8 @@ impl EngineBleedAirSystem {
                 ),
                 powered_by,
             ),
-            pressure_regulating_valve: ElectroPneumaticValve::new(powered_by),
+            pressure_regulating_valve: ElectroPneumaticValve::new(powered_by)
+                .with_stuck_failure(FailureType::BleedValveStuck(number)),
             overpressure_valve: OverpressureValve::new(
                 PneumaticValveCharacteristics::new(
                     Pressure::new::<psi>(0.), // psig
@@ -1186,7 +1195,13 @@ impl EngineBleedAirSystem {
             engine_starter_pressurized: false,
             engine_starter_exhaust: PneumaticExhaust::new(10., 10., Pressure::new::<psi>(0.)),
             engine_starter_valve: DefaultValve::new_closed(),
-            precooler: Precooler::new(900. * 2.),
+            precooler: Precooler::new(900. * 2.).with_failure(FailureType::PrecoolerFault(number)),
+            bleed_leak: PneumaticExhaust::new(
+                0.,
+                Self::BLEED_LEAK_EXHAUST_SPEED,
+                Pressure::new::<psi>(0.),
+            )
+            .with_leak_failure(FailureType::BleedLeak(number)),
             transfer_pressure_transducer: PressureTransducer::new(powered_by),
             // Should be powered by 801PP for engine 1 and 202PP for engine 2
             regulated_pressure_transducer: PressureTransducer::new(powered_by),
@@ -1259,6 +1274,8 @@ impl EngineBleedAirSystem {
             &mut self.precooler_supply_pipe,
             &mut self.precooler_outlet_pipe,
         );
+        self.bleed_leak
+            .update_move_fluid(context, &mut self.precooler_outlet_pipe);
         self.engine_starter_valve
             .update_move_fluid_with_transfer_speed(
                 context,
@@ -1350,6 +1367,10 @@ impl EngineBleedAirSystem {
         self.engine_starter_valve.is_open()
     }
 
+    fn starter_air_pressure(&self) -> Pressure {
+        self.engine_starter_container.pressure()
+    }
+
     fn pressure_regulating_valve_is_open(&self) -> bool {
         self.pressure_regulating_valve.is_open()
     }
@@ -1375,6 +1396,8 @@ impl SimulationElement for EngineBleedAirSystem {
         self.high_pressure_valve.accept(visitor);
         self.pressure_regulating_valve.accept(visitor);
         self.fan_air_valve.accept(visitor);
+        self.precooler.accept(visitor);
+        self.bleed_leak.accept(visitor);
 
         self.transfer_pressure_transducer.accept(visitor);
         self.regulated_pressure_transducer.accept(visitor);
@@ -3863,6 +3886,21 @@ pub mod tests {
         assert!(test_bed.precooler_outlet_pressure(2) > Pressure::new::<psi>(35.));
     }
 
+    #[test]
+    fn bleed_leak_reduces_bleed_pressure_of_the_leaking_side() {
+        let mut test_bed = test_bed_with()
+            .idle_eng1()
+            .idle_eng2()
+            .cross_bleed_valve_selector_knob(CrossBleedValveSelectorMode::Shut);
+        test_bed.fail(FailureType::BleedLeak(1));
+        test_bed = test_bed.and_stabilize();
+
+        assert!(
+            test_bed.precooler_outlet_pressure(2) - test_bed.precooler_outlet_pressure(1)
+                > pressure_tolerance()
+        );
+    }
+
     #[test]
     fn hydraulic_reservoirs_get_pressurized() {
         let test_bed = test_bed_with()
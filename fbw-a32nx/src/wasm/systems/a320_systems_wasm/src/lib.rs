@@ -2,6 +2,7 @@ mod ailerons;
 mod autobrakes;
 mod brakes;
 mod elevators;
+mod fire;
 mod flaps;
 mod gear;
 mod nose_wheel_steering;
@@ -11,11 +12,16 @@ mod rudder;
 mod spoilers;
 mod trimmable_horizontal_stabilizer;
 
-use a320_systems::A320;
+use a320_systems::{
+    aircraft_configuration_toml,
+    failures::{FAILURES, FAILURE_DEPENDENCIES},
+    load_aircraft_configuration, A320,
+};
 use ailerons::ailerons;
 use autobrakes::autobrakes;
 use brakes::brakes;
 use elevators::elevators;
+use fire::fire;
 use flaps::flaps;
 use gear::gear;
 use nose_wheel_steering::nose_wheel_steering;
@@ -24,14 +30,7 @@ use reversers::reversers;
 use rudder::rudder;
 use spoilers::spoilers;
 use std::error::Error;
-use systems::air_conditioning::{
-    acs_controller::AcscId, cabin_pressure_controller::CpcId, Channel, ZoneType,
-};
-use systems::failures::FailureType;
-use systems::shared::{
-    AirbusElectricPumpId, AirbusEngineDrivenPumpId, ElectricalBusType, GearActuatorId,
-    HydraulicColor, LgciuId, ProximityDetectorId,
-};
+use systems::shared::ElectricalBusType;
 use systems_wasm::aspects::ExecuteOn;
 use systems_wasm::{MsfsSimulationBuilder, Variable};
 use trimmable_horizontal_stabilizer::trimmable_horizontal_stabilizer;
@@ -40,6 +39,13 @@ use trimmable_horizontal_stabilizer::trimmable_horizontal_stabilizer;
 async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
     let mut sim_connect = gauge.open_simconnect("systems")?;
 
+    if let Err(error) = load_aircraft_configuration("\\work\\aircraft_configuration.toml") {
+        eprintln!(
+            "Ignoring the invalid aircraft configuration in the work folder: {}",
+            error
+        );
+    }
+
     let key_prefix = "A32NX_";
     let (mut simulation, mut handler) = MsfsSimulationBuilder::new(
         key_prefix,
@@ -65,227 +71,13 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
     .with_auxiliary_power_unit(Variable::named("OVHD_APU_START_PB_IS_AVAILABLE"), 8, 7)?
     .with_engine_anti_ice(2)?
     .with_wing_anti_ice()?
-    .with_failures(vec![
-        (
-            21_000,
-            FailureType::Acsc(AcscId::Acsc1(Channel::ChannelOne)),
-        ),
-        (
-            21_001,
-            FailureType::Acsc(AcscId::Acsc1(Channel::ChannelTwo)),
-        ),
-        (
-            21_002,
-            FailureType::Acsc(AcscId::Acsc2(Channel::ChannelOne)),
-        ),
-        (
-            21_003,
-            FailureType::Acsc(AcscId::Acsc2(Channel::ChannelTwo)),
-        ),
-        (21_004, FailureType::HotAir(1)),
-        (21_005, FailureType::TrimAirHighPressure),
-        (21_006, FailureType::TrimAirFault(ZoneType::Cockpit)),
-        (21_007, FailureType::TrimAirFault(ZoneType::Cabin(1))),
-        (21_008, FailureType::TrimAirFault(ZoneType::Cabin(2))),
-        (21_009, FailureType::TrimAirOverheat(ZoneType::Cockpit)),
-        (21_010, FailureType::TrimAirOverheat(ZoneType::Cabin(1))),
-        (21_011, FailureType::TrimAirOverheat(ZoneType::Cabin(2))),
-        (21_012, FailureType::CabinFan(1)),
-        (21_013, FailureType::CabinFan(2)),
-        (21_014, FailureType::GalleyFans),
-        (21_015, FailureType::CpcFault(CpcId::Cpc1)),
-        (21_016, FailureType::CpcFault(CpcId::Cpc2)),
-        (21_017, FailureType::OutflowValveFault),
-        (21_018, FailureType::SafetyValveFault),
-        (21_019, FailureType::RapidDecompression),
-        (24_000, FailureType::TransformerRectifier(1)),
-        (24_001, FailureType::TransformerRectifier(2)),
-        (24_002, FailureType::TransformerRectifier(3)),
-        (24_004, FailureType::StaticInverter),
-        (24_020, FailureType::Generator(1)),
-        (24_021, FailureType::Generator(2)),
-        (24_030, FailureType::ApuGenerator(1)),
-        (
-            24_100,
-            FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrent(1)),
-        ),
-        (
-            24_101,
-            FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrent(2)),
-        ),
-        (
-            24_102,
-            FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrentEssential),
-        ),
-        (
-            24_103,
-            FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrentEssentialShed),
-        ),
-        (
-            24_104,
-            FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrentStaticInverter),
-        ),
-        (
-            24_105,
-            FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrentGndFltService),
-        ),
-        (
-            24_106,
-            FailureType::ElectricalBus(ElectricalBusType::DirectCurrent(1)),
-        ),
-        (
-            24_107,
-            FailureType::ElectricalBus(ElectricalBusType::DirectCurrent(2)),
-        ),
-        (
-            24_108,
-            FailureType::ElectricalBus(ElectricalBusType::DirectCurrentEssential),
-        ),
-        (
-            24_109,
-            FailureType::ElectricalBus(ElectricalBusType::DirectCurrentEssentialShed),
-        ),
-        (
-            24_110,
-            FailureType::ElectricalBus(ElectricalBusType::DirectCurrentBattery),
-        ),
-        (
-            24_111,
-            FailureType::ElectricalBus(ElectricalBusType::DirectCurrentHot(1)),
-        ),
-        (
-            24_112,
-            FailureType::ElectricalBus(ElectricalBusType::DirectCurrentHot(2)),
-        ),
-        (
-            24_113,
-            FailureType::ElectricalBus(ElectricalBusType::DirectCurrentGndFltService),
-        ),
-        (29_000, FailureType::ReservoirLeak(HydraulicColor::Green)),
-        (29_001, FailureType::ReservoirLeak(HydraulicColor::Blue)),
-        (29_002, FailureType::ReservoirLeak(HydraulicColor::Yellow)),
-        (29_003, FailureType::ReservoirAirLeak(HydraulicColor::Green)),
-        (29_004, FailureType::ReservoirAirLeak(HydraulicColor::Blue)),
-        (
-            29_005,
-            FailureType::ReservoirAirLeak(HydraulicColor::Yellow),
-        ),
-        (
-            29_006,
-            FailureType::ReservoirReturnLeak(HydraulicColor::Green),
-        ),
-        (
-            29_007,
-            FailureType::ReservoirReturnLeak(HydraulicColor::Blue),
-        ),
-        (
-            29_008,
-            FailureType::ReservoirReturnLeak(HydraulicColor::Yellow),
-        ),
-        (
-            29_009,
-            FailureType::EnginePumpOverheat(AirbusEngineDrivenPumpId::Green),
-        ),
-        (
-            29_010,
-            FailureType::ElecPumpOverheat(AirbusElectricPumpId::Blue),
-        ),
-        (
-            29_011,
-            FailureType::EnginePumpOverheat(AirbusEngineDrivenPumpId::Yellow),
-        ),
-        (
-            29_012,
-            FailureType::ElecPumpOverheat(AirbusElectricPumpId::Yellow),
-        ),
-        (32_000, FailureType::LgciuPowerSupply(LgciuId::Lgciu1)),
-        (32_001, FailureType::LgciuPowerSupply(LgciuId::Lgciu2)),
-        (32_002, FailureType::LgciuInternalError(LgciuId::Lgciu1)),
-        (32_003, FailureType::LgciuInternalError(LgciuId::Lgciu2)),
-        (
-            32_004,
-            FailureType::GearProxSensorDamage(ProximityDetectorId::UplockGearNose1),
-        ),
-        (
-            32_005,
-            FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockGearNose2),
-        ),
-        (
-            32_006,
-            FailureType::GearProxSensorDamage(ProximityDetectorId::UplockGearRight1),
-        ),
-        (
-            32_007,
-            FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockGearRight2),
-        ),
-        (
-            32_008,
-            FailureType::GearProxSensorDamage(ProximityDetectorId::UplockGearLeft2),
-        ),
-        (
-            32_009,
-            FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockGearLeft1),
-        ),
-        (
-            32_010,
-            FailureType::GearProxSensorDamage(ProximityDetectorId::UplockDoorNose1),
-        ),
-        (
-            32_011,
-            FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockDoorNose2),
-        ),
-        (
-            32_012,
-            FailureType::GearProxSensorDamage(ProximityDetectorId::UplockDoorRight2),
-        ),
-        (
-            32_013,
-            FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockDoorRight1),
-        ),
-        (
-            32_014,
-            FailureType::GearProxSensorDamage(ProximityDetectorId::UplockDoorLeft2),
-        ),
-        (
-            32_015,
-            FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockDoorLeft1),
-        ),
-        (
-            32_020,
-            FailureType::GearActuatorJammed(GearActuatorId::GearNose),
-        ),
-        (
-            32_021,
-            FailureType::GearActuatorJammed(GearActuatorId::GearLeft),
-        ),
-        (
-            32_022,
-            FailureType::GearActuatorJammed(GearActuatorId::GearRight),
-        ),
-        (
-            32_023,
-            FailureType::GearActuatorJammed(GearActuatorId::GearDoorNose),
-        ),
-        (
-            32_024,
-            FailureType::GearActuatorJammed(GearActuatorId::GearDoorLeft),
-        ),
-        (
-            32_025,
-            FailureType::GearActuatorJammed(GearActuatorId::GearDoorRight),
-        ),
-        (
-            32_100,
-            FailureType::BrakeHydraulicLeak(HydraulicColor::Green),
-        ),
-        (
-            32_101,
-            FailureType::BrakeHydraulicLeak(HydraulicColor::Yellow),
-        ),
-        (32_150, FailureType::BrakeAccumulatorGasLeak),
-        (34_000, FailureType::RadioAltimeter(1)),
-        (34_001, FailureType::RadioAltimeter(2)),
-    ])
+    .with_recording(
+        Variable::named(&format!("{}SYSTEMS_RECORDING_ENABLED", key_prefix)),
+        "\\work\\systems.rec",
+        aircraft_configuration_toml(),
+    )
+    .with_failures(FAILURES.to_vec())
+    .with_failure_dependencies(FAILURE_DEPENDENCIES)
     .provides_aircraft_variable("ACCELERATION BODY X", "feet per second squared", 0)?
     .provides_aircraft_variable("ACCELERATION BODY Y", "feet per second squared", 0)?
     .provides_aircraft_variable("ACCELERATION BODY Z", "feet per second squared", 0)?
@@ -306,6 +98,8 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
     .provides_aircraft_variable("CONTACT POINT COMPRESSION", "Percent", 0)?
     .provides_aircraft_variable("CONTACT POINT COMPRESSION", "Percent", 1)?
     .provides_aircraft_variable("CONTACT POINT COMPRESSION", "Percent", 2)?
+    .provides_aircraft_variable("ENG ON FIRE", "Bool", 1)?
+    .provides_aircraft_variable("ENG ON FIRE", "Bool", 2)?
     .provides_aircraft_variable("EXTERNAL POWER AVAILABLE", "Bool", 1)?
     .provides_aircraft_variable("FUEL TANK CENTER QUANTITY", "gallons", 0)?
     .provides_aircraft_variable("FUEL TANK LEFT MAIN QUANTITY", "gallons", 0)?
@@ -314,12 +108,25 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
     .provides_aircraft_variable("FUEL TANK RIGHT AUX QUANTITY", "gallons", 0)?
     .provides_aircraft_variable("FUEL TOTAL QUANTITY WEIGHT", "Pounds", 0)?
     .provides_aircraft_variable("FUELSYSTEM LINE FUEL FLOW", "gallons per hour", 18)?
+    .provides_aircraft_variable("FUELSYSTEM PUMP SWITCH", "Bool", 2)?
+    .provides_aircraft_variable("FUELSYSTEM PUMP SWITCH", "Bool", 3)?
+    .provides_aircraft_variable("FUELSYSTEM PUMP SWITCH", "Bool", 5)?
+    .provides_aircraft_variable("FUELSYSTEM PUMP SWITCH", "Bool", 6)?
+    .provides_aircraft_variable("FUELSYSTEM VALVE SWITCH", "Bool", 1)?
+    .provides_aircraft_variable("FUELSYSTEM VALVE SWITCH", "Bool", 2)?
+    .provides_aircraft_variable("FUELSYSTEM VALVE SWITCH", "Bool", 3)?
+    .provides_aircraft_variable("FUELSYSTEM VALVE SWITCH", "Bool", 9)?
+    .provides_aircraft_variable("FUELSYSTEM VALVE SWITCH", "Bool", 10)?
     .provides_aircraft_variable("GEAR ANIMATION POSITION", "Percent", 0)?
     .provides_aircraft_variable("GEAR ANIMATION POSITION", "Percent", 1)?
     .provides_aircraft_variable("GEAR ANIMATION POSITION", "Percent", 2)?
     .provides_aircraft_variable("GEAR CENTER POSITION", "Percent", 0)?
     .provides_aircraft_variable("GEAR LEFT POSITION", "Percent", 0)?
     .provides_aircraft_variable("GEAR RIGHT POSITION", "Percent", 0)?
+    .provides_aircraft_variable("GENERAL ENG COMBUSTION", "Bool", 1)?
+    .provides_aircraft_variable("GENERAL ENG COMBUSTION", "Bool", 2)?
+    .provides_aircraft_variable("GENERAL ENG FUEL VALVE", "Bool", 1)?
+    .provides_aircraft_variable("GENERAL ENG FUEL VALVE", "Bool", 2)?
     .provides_aircraft_variable("GENERAL ENG STARTER ACTIVE", "Bool", 1)?
     .provides_aircraft_variable("GENERAL ENG STARTER ACTIVE", "Bool", 2)?
     .provides_aircraft_variable("GPS GROUND SPEED", "Knots", 0)?
@@ -340,6 +147,7 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
     .provides_aircraft_variable("PLANE HEADING DEGREES TRUE", "Degrees", 0)?
     .provides_aircraft_variable("PLANE LATITUDE", "degree latitude", 0)?
     .provides_aircraft_variable("PLANE LONGITUDE", "degree longitude", 0)?
+    .provides_aircraft_variable("PLANE ALTITUDE", "Feet", 0)?
     .provides_aircraft_variable("PRESSURE ALTITUDE", "Feet", 0)?
     .provides_aircraft_variable("PUSHBACK STATE", "Enum", 0)?
     .provides_aircraft_variable("PUSHBACK ANGLE", "Radians", 0)?
@@ -442,6 +250,7 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
     .with_aspect(spoilers)?
     .with_aspect(ailerons)?
     .with_aspect(elevators)?
+    .with_aspect(fire)?
     .with_aspect(reversers)?
     .with_aspect(rudder)?
     .with_aspect(gear)?
@@ -80,6 +80,18 @@ export const A380Failure = Object.freeze({
   Generator4: 24023,
   ApuGenerator1: 24030,
   ApuGenerator2: 24031,
+  Idg1Disconnect: 24040,
+  Idg2Disconnect: 24041,
+  Idg3Disconnect: 24042,
+  Idg4Disconnect: 24043,
+  Battery1ThermalRunaway: 24050,
+  Battery2ThermalRunaway: 24051,
+  BatteryEssThermalRunaway: 24052,
+  BatteryApuThermalRunaway: 24053,
+  Generator1VoltageRegulator: 24060,
+  Generator2VoltageRegulator: 24061,
+  Generator3VoltageRegulator: 24062,
+  Generator4VoltageRegulator: 24063,
   AlternatingCurrent1: 24100,
   AlternatingCurrent2: 24101,
   AlternatingCurrent3: 24102,
@@ -117,6 +129,42 @@ export const A380Failure = Object.freeze({
   APULoopB: 26016,
   MLGLoopA: 26017,
   MLGLoopB: 26018,
+  FwdCargoSmoke: 26019,
+  BulkCargoSmoke: 26020,
+  AvionicsBaySmoke: 26021,
+  Lavatory1Smoke: 26022,
+  Lavatory2Smoke: 26023,
+  Lavatory3Smoke: 26024,
+  Lavatory4Smoke: 26025,
+  Lavatory5Smoke: 26026,
+  Lavatory6Smoke: 26027,
+  Lavatory7Smoke: 26028,
+  Lavatory8Smoke: 26029,
+  CrewRest1Smoke: 26030,
+  CrewRest2Smoke: 26031,
+  FwdCargoSmokeLoopA: 26032,
+  FwdCargoSmokeLoopB: 26033,
+  BulkCargoSmokeLoopA: 26034,
+  BulkCargoSmokeLoopB: 26035,
+  AvionicsBaySmokeLoopA: 26036,
+  AvionicsBaySmokeLoopB: 26037,
+
+  FeedTank1MainPump: 28001,
+  FeedTank1StbyPump: 28002,
+  FeedTank2MainPump: 28003,
+  FeedTank2StbyPump: 28004,
+  FeedTank3MainPump: 28005,
+  FeedTank3StbyPump: 28006,
+  FeedTank4MainPump: 28007,
+  FeedTank4StbyPump: 28008,
+  LeftOuterTankPump: 28009,
+  LeftMidTankPump: 28010,
+  LeftInnerTankPump: 28011,
+  RightInnerTankPump: 28012,
+  RightMidTankPump: 28013,
+  RightOuterTankPump: 28014,
+  TrimTankLeftPump: 28015,
+  TrimTankRightPump: 28016,
 
   GreenReservoirLeak: 29000,
   YellowReservoirLeak: 29001,
@@ -140,6 +188,19 @@ export const A380Failure = Object.freeze({
   LeftPfdDisplay: 31000,
   RightPfdDisplay: 31001,
 
+  CdsCanBus11Disturbance: 31010,
+  CdsCanBus12Disturbance: 31011,
+  CdsCanBus21Disturbance: 31012,
+  CdsCanBus22Disturbance: 31013,
+  CdsCanBus11KccuKeyboardFault: 31020,
+  CdsCanBus11KccuCursorControlFault: 31021,
+  CdsCanBus12KccuKeyboardFault: 31022,
+  CdsCanBus12KccuCursorControlFault: 31023,
+  CdsCanBus21KccuKeyboardFault: 31024,
+  CdsCanBus21KccuCursorControlFault: 31025,
+  CdsCanBus22KccuKeyboardFault: 31026,
+  CdsCanBus22KccuCursorControlFault: 31027,
+
   LgciuPowerSupply1: 32000,
   LgciuPowerSupply2: 32001,
   LgciuInternalError1: 32002,
@@ -150,11 +211,64 @@ export const A380Failure = Object.freeze({
   GearProxSensorDamageGearUplockNose1: 32006,
   GearProxSensorDamageDoorUplockLeft2: 32007,
 
+  TyreBurst1: 32200,
+  TyreBurst2: 32201,
+  TyreBurst3: 32202,
+  TyreBurst4: 32203,
+  TyreBurst5: 32204,
+  TyreBurst6: 32205,
+  TyreBurst7: 32206,
+  TyreBurst8: 32207,
+  TyreBurst9: 32208,
+  TyreBurst10: 32209,
+  TyreBurst11: 32210,
+  TyreBurst12: 32211,
+  TyreBurst13: 32212,
+  TyreBurst14: 32213,
+  TyreBurst15: 32214,
+  TyreBurst16: 32215,
+  TyreBurst17: 32216,
+  TyreBurst18: 32217,
+  TyreBurst19: 32218,
+  TyreBurst20: 32219,
+  TyreBurst21: 32220,
+  TyreBurst22: 32221,
+
   RadioAltimeter1: 34000,
   RadioAltimeter2: 34001,
   RadioAltimeter3: 34002,
   Transponder1: 34003,
   Transponder2: 34004,
+  GpsReceiver1: 34010,
+  GpsReceiver2: 34011,
+  Adiru1BusParityError: 34020,
+  Adiru2BusParityError: 34021,
+  Adiru3BusParityError: 34022,
+  Adiru1: 34030,
+  Adiru2: 34031,
+  Adiru3: 34032,
+  Adr1: 34040,
+  Adr2: 34041,
+  Adr3: 34042,
+  Ir1: 34050,
+  Ir2: 34051,
+  Ir3: 34052,
+  Egpwc: 34060,
+
+  Eng1PressureRegulatingValveStuck: 36000,
+  Eng2PressureRegulatingValveStuck: 36001,
+  Eng3PressureRegulatingValveStuck: 36002,
+  Eng4PressureRegulatingValveStuck: 36003,
+  Eng1PrecoolerFault: 36010,
+  Eng2PrecoolerFault: 36011,
+  Eng3PrecoolerFault: 36012,
+  Eng4PrecoolerFault: 36013,
+  Eng1BleedLeak: 36020,
+  Eng2BleedLeak: 36021,
+  Eng3BleedLeak: 36022,
+  Eng4BleedLeak: 36023,
+
+  ApuFault: 49000,
 });
 
 export const A380FailureDefinitions: FailureDefinition[] = [
@@ -233,6 +347,18 @@ export const A380FailureDefinitions: FailureDefinition[] = [
   [24, A380Failure.Generator4, 'Generator 4'],
   [24, A380Failure.ApuGenerator1, 'APU Generator 1'],
   [24, A380Failure.ApuGenerator2, 'APU Generator 2'],
+  [24, A380Failure.Idg1Disconnect, 'IDG 1 disconnect'],
+  [24, A380Failure.Idg2Disconnect, 'IDG 2 disconnect'],
+  [24, A380Failure.Idg3Disconnect, 'IDG 3 disconnect'],
+  [24, A380Failure.Idg4Disconnect, 'IDG 4 disconnect'],
+  [24, A380Failure.Battery1ThermalRunaway, 'Battery 1 thermal runaway'],
+  [24, A380Failure.Battery2ThermalRunaway, 'Battery 2 thermal runaway'],
+  [24, A380Failure.BatteryEssThermalRunaway, 'Battery ESS thermal runaway'],
+  [24, A380Failure.BatteryApuThermalRunaway, 'Battery APU thermal runaway'],
+  [24, A380Failure.Generator1VoltageRegulator, 'Generator 1 voltage regulator'],
+  [24, A380Failure.Generator2VoltageRegulator, 'Generator 2 voltage regulator'],
+  [24, A380Failure.Generator3VoltageRegulator, 'Generator 3 voltage regulator'],
+  [24, A380Failure.Generator4VoltageRegulator, 'Generator 4 voltage regulator'],
   [24, A380Failure.AlternatingCurrent1, 'AC 1'],
   [24, A380Failure.AlternatingCurrent2, 'AC 2'],
   [24, A380Failure.AlternatingCurrent3, 'AC 3'],
@@ -270,6 +396,42 @@ export const A380FailureDefinitions: FailureDefinition[] = [
   [26, A380Failure.APULoopB, 'APU Loop B'],
   [26, A380Failure.MLGLoopA, 'Main Landing Gear Bay Loop A'],
   [26, A380Failure.MLGLoopB, 'Main Landing Gear Bay Loop B'],
+  [26, A380Failure.FwdCargoSmoke, 'Smoke - Forward Cargo Compartment'],
+  [26, A380Failure.BulkCargoSmoke, 'Smoke - Bulk Cargo Compartment'],
+  [26, A380Failure.AvionicsBaySmoke, 'Smoke - Avionics Bay'],
+  [26, A380Failure.Lavatory1Smoke, 'Smoke - Lavatory 1'],
+  [26, A380Failure.Lavatory2Smoke, 'Smoke - Lavatory 2'],
+  [26, A380Failure.Lavatory3Smoke, 'Smoke - Lavatory 3'],
+  [26, A380Failure.Lavatory4Smoke, 'Smoke - Lavatory 4'],
+  [26, A380Failure.Lavatory5Smoke, 'Smoke - Lavatory 5'],
+  [26, A380Failure.Lavatory6Smoke, 'Smoke - Lavatory 6'],
+  [26, A380Failure.Lavatory7Smoke, 'Smoke - Lavatory 7'],
+  [26, A380Failure.Lavatory8Smoke, 'Smoke - Lavatory 8'],
+  [26, A380Failure.CrewRest1Smoke, 'Smoke - Crew Rest 1'],
+  [26, A380Failure.CrewRest2Smoke, 'Smoke - Crew Rest 2'],
+  [26, A380Failure.FwdCargoSmokeLoopA, 'Forward Cargo Compartment Smoke Loop A'],
+  [26, A380Failure.FwdCargoSmokeLoopB, 'Forward Cargo Compartment Smoke Loop B'],
+  [26, A380Failure.BulkCargoSmokeLoopA, 'Bulk Cargo Compartment Smoke Loop A'],
+  [26, A380Failure.BulkCargoSmokeLoopB, 'Bulk Cargo Compartment Smoke Loop B'],
+  [26, A380Failure.AvionicsBaySmokeLoopA, 'Avionics Bay Smoke Loop A'],
+  [26, A380Failure.AvionicsBaySmokeLoopB, 'Avionics Bay Smoke Loop B'],
+
+  [28, A380Failure.FeedTank1MainPump, 'Feed tank 1 main pump'],
+  [28, A380Failure.FeedTank1StbyPump, 'Feed tank 1 standby pump'],
+  [28, A380Failure.FeedTank2MainPump, 'Feed tank 2 main pump'],
+  [28, A380Failure.FeedTank2StbyPump, 'Feed tank 2 standby pump'],
+  [28, A380Failure.FeedTank3MainPump, 'Feed tank 3 main pump'],
+  [28, A380Failure.FeedTank3StbyPump, 'Feed tank 3 standby pump'],
+  [28, A380Failure.FeedTank4MainPump, 'Feed tank 4 main pump'],
+  [28, A380Failure.FeedTank4StbyPump, 'Feed tank 4 standby pump'],
+  [28, A380Failure.LeftOuterTankPump, 'Left outer tank pump'],
+  [28, A380Failure.LeftMidTankPump, 'Left mid tank pump'],
+  [28, A380Failure.LeftInnerTankPump, 'Left inner tank pump'],
+  [28, A380Failure.RightInnerTankPump, 'Right inner tank pump'],
+  [28, A380Failure.RightMidTankPump, 'Right mid tank pump'],
+  [28, A380Failure.RightOuterTankPump, 'Right outer tank pump'],
+  [28, A380Failure.TrimTankLeftPump, 'Trim tank left pump'],
+  [28, A380Failure.TrimTankRightPump, 'Trim tank right pump'],
 
   [29, A380Failure.GreenReservoirLeak, 'Green reservoir leak'],
   [29, A380Failure.YellowReservoirLeak, 'Yellow reservoir leak'],
@@ -292,6 +454,18 @@ export const A380FailureDefinitions: FailureDefinition[] = [
 
   [31, A380Failure.LeftPfdDisplay, 'Captain PFD display'],
   [31, A380Failure.RightPfdDisplay, 'F/O PFD display'],
+  [31, A380Failure.CdsCanBus11Disturbance, 'CDS CAN bus 1-1 disturbance'],
+  [31, A380Failure.CdsCanBus12Disturbance, 'CDS CAN bus 1-2 disturbance'],
+  [31, A380Failure.CdsCanBus21Disturbance, 'CDS CAN bus 2-1 disturbance'],
+  [31, A380Failure.CdsCanBus22Disturbance, 'CDS CAN bus 2-2 disturbance'],
+  [31, A380Failure.CdsCanBus11KccuKeyboardFault, 'CDS CAN bus 1-1 KCCU keyboard transceiver'],
+  [31, A380Failure.CdsCanBus11KccuCursorControlFault, 'CDS CAN bus 1-1 KCCU cursor control transceiver'],
+  [31, A380Failure.CdsCanBus12KccuKeyboardFault, 'CDS CAN bus 1-2 KCCU keyboard transceiver'],
+  [31, A380Failure.CdsCanBus12KccuCursorControlFault, 'CDS CAN bus 1-2 KCCU cursor control transceiver'],
+  [31, A380Failure.CdsCanBus21KccuKeyboardFault, 'CDS CAN bus 2-1 KCCU keyboard transceiver'],
+  [31, A380Failure.CdsCanBus21KccuCursorControlFault, 'CDS CAN bus 2-1 KCCU cursor control transceiver'],
+  [31, A380Failure.CdsCanBus22KccuKeyboardFault, 'CDS CAN bus 2-2 KCCU keyboard transceiver'],
+  [31, A380Failure.CdsCanBus22KccuCursorControlFault, 'CDS CAN bus 2-2 KCCU cursor control transceiver'],
 
   [32, A380Failure.LgciuPowerSupply1, 'LGCIU 1 Power supply'],
   [32, A380Failure.LgciuPowerSupply2, 'LGCIU 2 Power supply'],
@@ -300,9 +474,62 @@ export const A380FailureDefinitions: FailureDefinition[] = [
 
   [32, A380Failure.GearProxSensorDamageGearUplockNose1, 'Proximity sensor damage uplock nose gear #1'],
 
+  [32, A380Failure.TyreBurst1, 'Tyre 1 burst'],
+  [32, A380Failure.TyreBurst2, 'Tyre 2 burst'],
+  [32, A380Failure.TyreBurst3, 'Tyre 3 burst'],
+  [32, A380Failure.TyreBurst4, 'Tyre 4 burst'],
+  [32, A380Failure.TyreBurst5, 'Tyre 5 burst'],
+  [32, A380Failure.TyreBurst6, 'Tyre 6 burst'],
+  [32, A380Failure.TyreBurst7, 'Tyre 7 burst'],
+  [32, A380Failure.TyreBurst8, 'Tyre 8 burst'],
+  [32, A380Failure.TyreBurst9, 'Tyre 9 burst'],
+  [32, A380Failure.TyreBurst10, 'Tyre 10 burst'],
+  [32, A380Failure.TyreBurst11, 'Tyre 11 burst'],
+  [32, A380Failure.TyreBurst12, 'Tyre 12 burst'],
+  [32, A380Failure.TyreBurst13, 'Tyre 13 burst'],
+  [32, A380Failure.TyreBurst14, 'Tyre 14 burst'],
+  [32, A380Failure.TyreBurst15, 'Tyre 15 burst'],
+  [32, A380Failure.TyreBurst16, 'Tyre 16 burst'],
+  [32, A380Failure.TyreBurst17, 'Tyre 17 burst'],
+  [32, A380Failure.TyreBurst18, 'Tyre 18 burst'],
+  [32, A380Failure.TyreBurst19, 'Tyre 19 burst'],
+  [32, A380Failure.TyreBurst20, 'Tyre 20 burst'],
+  [32, A380Failure.TyreBurst21, 'Nose tyre 1 burst'],
+  [32, A380Failure.TyreBurst22, 'Nose tyre 2 burst'],
+
   [34, A380Failure.RadioAltimeter1, 'RA SYS A'],
   [34, A380Failure.RadioAltimeter2, 'RA SYS B'],
   [34, A380Failure.RadioAltimeter3, 'RA SYS C'],
   [34, A380Failure.Transponder1, 'XPDR 1'],
   [34, A380Failure.Transponder2, 'XPDR 2'],
+  [34, A380Failure.GpsReceiver1, 'GPS 1'],
+  [34, A380Failure.GpsReceiver2, 'GPS 2'],
+  [34, A380Failure.Adiru1BusParityError, 'ADIRU 1 BUS PARITY ERROR'],
+  [34, A380Failure.Adiru2BusParityError, 'ADIRU 2 BUS PARITY ERROR'],
+  [34, A380Failure.Adiru3BusParityError, 'ADIRU 3 BUS PARITY ERROR'],
+  [34, A380Failure.Adiru1, 'ADIRU 1'],
+  [34, A380Failure.Adiru2, 'ADIRU 2'],
+  [34, A380Failure.Adiru3, 'ADIRU 3'],
+  [34, A380Failure.Adr1, 'ADR 1'],
+  [34, A380Failure.Adr2, 'ADR 2'],
+  [34, A380Failure.Adr3, 'ADR 3'],
+  [34, A380Failure.Ir1, 'IR 1'],
+  [34, A380Failure.Ir2, 'IR 2'],
+  [34, A380Failure.Ir3, 'IR 3'],
+  [34, A380Failure.Egpwc, 'EGPWC'],
+
+  [36, A380Failure.Eng1PressureRegulatingValveStuck, 'Engine 1 bleed pressure regulating valve stuck'],
+  [36, A380Failure.Eng2PressureRegulatingValveStuck, 'Engine 2 bleed pressure regulating valve stuck'],
+  [36, A380Failure.Eng3PressureRegulatingValveStuck, 'Engine 3 bleed pressure regulating valve stuck'],
+  [36, A380Failure.Eng4PressureRegulatingValveStuck, 'Engine 4 bleed pressure regulating valve stuck'],
+  [36, A380Failure.Eng1PrecoolerFault, 'Engine 1 precooler fault'],
+  [36, A380Failure.Eng2PrecoolerFault, 'Engine 2 precooler fault'],
+  [36, A380Failure.Eng3PrecoolerFault, 'Engine 3 precooler fault'],
+  [36, A380Failure.Eng4PrecoolerFault, 'Engine 4 precooler fault'],
+  [36, A380Failure.Eng1BleedLeak, 'Engine 1 bleed leak'],
+  [36, A380Failure.Eng2BleedLeak, 'Engine 2 bleed leak'],
+  [36, A380Failure.Eng3BleedLeak, 'Engine 3 bleed leak'],
+  [36, A380Failure.Eng4BleedLeak, 'Engine 4 bleed leak'],
+
+  [49, A380Failure.ApuFault, 'APU fault'],
 ];
@@ -1,10 +1,12 @@
 import { useSimVar } from '@flybywiresim/fbw-sdk';
 import React, { FC } from 'react';
+import { useArinc429Var } from '@instruments/common/arinc429';
 
 interface WheelProps {
   x: number;
   y: number;
   number: number | null;
+  tyreNumber: number;
   isLeftSide: boolean;
   hasBrake: boolean;
   moreActive: boolean;
@@ -14,11 +16,14 @@ const roundTemperature = (rawTemp: number): number => Math.min(995, Math.max(0,
 
 const maxStaleness = 300;
 
-export const Wheel: FC<WheelProps> = ({ x, y, number, isLeftSide, hasBrake, moreActive }) => {
+export const Wheel: FC<WheelProps> = ({ x, y, number, tyreNumber, isLeftSide, hasBrake, moreActive }) => {
   const negativeSign = isLeftSide ? '-' : '';
   const rightNegativeSign = !isLeftSide ? '-' : '';
 
   const [brakeTemp] = useSimVar(`L:A32NX_REPORTED_BRAKE_TEMPERATURE_${number}`, 'celsius', maxStaleness);
+  const tyrePressure = useArinc429Var(`L:A32NX_TPIS_TYRE_${tyreNumber}_PRESSURE`, maxStaleness);
+
+  const tyrePressureText = tyrePressure.isNormalOperation() ? Math.round(tyrePressure.value) : 'XX';
 
   return (
     <g id={`wheel-${number ?? 'nose'}`} transform={`translate(${x} ${y})`}>
@@ -48,7 +53,7 @@ export const Wheel: FC<WheelProps> = ({ x, y, number, isLeftSide, hasBrake, more
         y={34}
         visibility={moreActive ? 'visible' : 'hidden'}
       >
-        220
+        {tyrePressureText}
       </text>
       {number && (
         <text className={`F22 White ${isLeftSide ? 'EndAlign' : ''}`} x={isLeftSide ? -38 : 42} y={7}>
@@ -51,6 +51,7 @@ export const WheelBogey: FC<WheelBogeyProps> = ({ x, y, position, type, moreActi
         x={xOffset}
         y={Math.floor(i / 2) * (type === WheelBogeyType.BLG ? 90 : 92)}
         number={type !== WheelBogeyType.Nose ? wheelNumber : null}
+        tyreNumber={type !== WheelBogeyType.Nose ? wheelNumber : 22 - wheelNumber}
         hasBrake={wheelNumber < 17 && type !== WheelBogeyType.Nose}
         isLeftSide={wheelNumber % 2 !== 0}
         moreActive={moreActive}
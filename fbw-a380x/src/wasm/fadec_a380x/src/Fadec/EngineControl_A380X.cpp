@@ -736,7 +736,30 @@ void EngineControl_A380X::updateFuel(double deltaTimeSeconds) {
   //--------------------------------------------
   const FLOAT64 aircraftDevelopmentStateVar = msfsHandlerPtr->getAircraftDevelopmentStateVar();
 
-  if (uiFuelTamper && aircraftDevelopmentStateVar == 0) {
+  // The native fuel model of the systems simulation manages the tank quantities and feeds the
+  // engines itself. Only the fuel used is accumulated here.
+  if (simData.fuelNativeModelIsActive->getAsBool()) {
+    const double* engineFF[4]    = {&engine1FF, &engine2FF, &engine3FF, &engine4FF};
+    const double* enginePreFF[4] = {&engine1PreFF, &engine2PreFF, &engine3PreFF, &engine4PreFF};
+
+    for (int i = 0; i < 4; i++) {
+      const double fuelBurn = (*enginePreFF[i] + *engineFF[i]) / 2 * deltaTimeHours;  // KG
+      simData.enginePreFF[i]->set(*engineFF[i]);
+      simData.engineFuelUsed[i]->set(simData.engineFuelUsed[i]->get() + fuelBurn);
+    }
+
+    simData.fuelLeftOuterPre->set(leftOuterQty);    // Pounds
+    simData.fuelFeedOnePre->set(feedOneQty);        // Pounds
+    simData.fuelLeftMidPre->set(leftMidQty);        // Pounds
+    simData.fuelLeftInnerPre->set(leftInnerQty);    // Pounds
+    simData.fuelFeedTwoPre->set(feedTwoQty);        // Pounds
+    simData.fuelFeedThreePre->set(feedThreeQty);    // Pounds
+    simData.fuelRightInnerPre->set(rightInnerQty);  // Pounds
+    simData.fuelRightMidPre->set(rightMidQty);      // Pounds
+    simData.fuelFeedFourPre->set(feedFourQty);      // Pounds
+    simData.fuelRightOuterPre->set(rightOuterQty);  // Pounds
+    simData.fuelTrimPre->set(trimQty);              // Pounds
+  } else if (uiFuelTamper && aircraftDevelopmentStateVar == 0) {
     simData.fuelLeftOuterPre->set(fuelLeftOuterPre);    // Pounds
     simData.fuelFeedOnePre->set(fuelFeedOnePre);        // Pounds
     simData.fuelLeftMidPre->set(fuelLeftMidPre);        // Pounds
@@ -224,6 +224,7 @@ class FadecSimData_A380X {
   NamedVariablePtr fuelFeedFourPre;    // Pounds
   NamedVariablePtr fuelRightOuterPre;  // Pounds
   NamedVariablePtr fuelTrimPre;        // Pounds
+  NamedVariablePtr fuelNativeModelIsActive;
   NamedVariablePtr fuelPumpState[4];
   NamedVariablePtr packsState[2];
   NamedVariablePtr refuelRate;
@@ -408,6 +409,8 @@ class FadecSimData_A380X {
     fuelRightOuterPre = dm->make_named_var("A32NX_FUEL_RIGHTOUTER_PRE", UNITS.Number, AUTO_READ_WRITE);
     fuelTrimPre       = dm->make_named_var("A32NX_FUEL_TRIM_PRE", UNITS.Number, AUTO_READ_WRITE);
 
+    fuelNativeModelIsActive = dm->make_named_var("A32NX_FUEL_NATIVE_MODEL_IS_ACTIVE", UNITS.Number, AUTO_READ);
+
     fuelPumpState[E1] = dm->make_named_var("A32NX_PUMP_STATE:1", UNITS.Number, AUTO_READ_WRITE);
     fuelPumpState[E2] = dm->make_named_var("A32NX_PUMP_STATE:2", UNITS.Number, AUTO_READ_WRITE);
     fuelPumpState[E3] = dm->make_named_var("A32NX_PUMP_STATE:3", UNITS.Number, AUTO_READ_WRITE);
@@ -5,7 +5,7 @@ use systems::{
         PressurizationOverheadShared, VcmId, VcmShared,
     },
     failures::{Failure, FailureType},
-    shared::{ControllerSignal, ElectricalBusType, ElectricalBuses},
+    shared::{CargoVentilationIsolation, ControllerSignal, ElectricalBusType, ElectricalBuses},
     simulation::{
         InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter,
         VariableIdentifier, Write,
@@ -75,6 +75,7 @@ impl VentilationControlModule {
     pub fn update(
         &mut self,
         acs_overhead: &impl AirConditioningOverheadShared,
+        cargo_ventilation_isolation: &impl CargoVentilationIsolation,
         ocsm: [&impl OcsmShared; 4],
         pressurization_overhead: &impl PressurizationOverheadShared,
     ) {
@@ -89,6 +90,7 @@ impl VentilationControlModule {
                 self.bvcs.update(
                     self.active_channel.has_fault(),
                     acs_overhead,
+                    cargo_ventilation_isolation.bulk_cargo_isolation_requested(),
                     pressurization_overhead,
                 );
                 self.orvp.update(ocsm, pressurization_overhead);
@@ -96,6 +98,7 @@ impl VentilationControlModule {
                 self.fcvcs.update(
                     self.active_channel.has_fault(),
                     acs_overhead,
+                    cargo_ventilation_isolation.fwd_cargo_isolation_requested(),
                     pressurization_overhead,
                 )
             }
@@ -236,9 +239,11 @@ impl ForwardCargoVentilationControlSystem {
         &mut self,
         active_channel_has_fault: bool,
         acs_overhead: &impl AirConditioningOverheadShared,
+        isolation_requested: bool,
         pressurization_overhead: &impl PressurizationOverheadShared,
     ) {
         self.isolation_valves_open_allowed = acs_overhead.fwd_cargo_isolation_valve_is_on()
+            && !isolation_requested
             && !pressurization_overhead.ditching_is_on()
             && !active_channel_has_fault
             && !self.fwd_isol_valve_failure.is_active();
@@ -307,9 +312,11 @@ impl BulkVentilationControlSystem {
         &mut self,
         active_channel_has_fault: bool,
         acs_overhead: &impl AirConditioningOverheadShared,
+        isolation_requested: bool,
         pressurization_overhead: &impl PressurizationOverheadShared,
     ) {
         self.isolation_valves_open_allowed = acs_overhead.bulk_isolation_valve_is_on()
+            && !isolation_requested
             && !pressurization_overhead.ditching_is_on()
             && !active_channel_has_fault
             && !self.bulk_isol_valve_failure.is_active();
@@ -20,9 +20,10 @@ use systems::{
     payload::NumberOfPassengers,
     pneumatic::PneumaticContainer,
     shared::{
-        update_iterator::MaxStepLoop, CabinSimulation, CargoDoorLocked, ControllerSignal,
-        ElectricalBusType, EngineBleedPushbutton, EngineCorrectedN1, EngineFirePushButtons,
-        EngineStartState, LgciuWeightOnWheels, PackFlowValveState, PneumaticBleed,
+        update_iterator::MaxStepLoop, CabinSimulation, CargoDoorLocked, CargoVentilationIsolation,
+        ControllerSignal, ElectricalBusType, EngineBleedPushbutton, EngineCorrectedN1,
+        EngineFirePushButtons, EngineStartState, LgciuWeightOnWheels, PackFlowValveState,
+        PneumaticBleed,
     },
     simulation::{
         InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
@@ -111,6 +112,7 @@ impl A380AirConditioning {
         context: &UpdateContext,
         adirs: &impl AdirsToAirCondInterface,
         cargo_door_open: &impl CargoDoorLocked,
+        cargo_ventilation_isolation: &impl CargoVentilationIsolation,
         cpiom_b: &impl AvionicsDataCommunicationNetwork<
             'a,
             A380AvionicsDataCommunicationNetworkMessageData,
@@ -151,6 +153,7 @@ impl A380AirConditioning {
         self.a380_air_conditioning_system.update(
             context,
             &self.a380_cabin,
+            cargo_ventilation_isolation,
             &self.cpiom_b,
             engines,
             engine_fire_push_buttons,
@@ -231,6 +234,36 @@ impl PackFlowControllers for A380AirConditioning {
     }
 }
 
+impl VcmShared for A380AirConditioning {
+    fn hp_cabin_fans_are_enabled(&self) -> bool {
+        self.a380_air_conditioning_system
+            .hp_cabin_fans_are_enabled()
+    }
+    fn fwd_extraction_fan_is_on(&self) -> bool {
+        self.a380_air_conditioning_system.fwd_extraction_fan_is_on()
+    }
+    fn fwd_isolation_valves_open_allowed(&self) -> bool {
+        self.a380_air_conditioning_system
+            .fwd_isolation_valves_open_allowed()
+    }
+    fn bulk_duct_heater_on_allowed(&self) -> bool {
+        self.a380_air_conditioning_system
+            .bulk_duct_heater_on_allowed()
+    }
+    fn bulk_extraction_fan_is_on(&self) -> bool {
+        self.a380_air_conditioning_system
+            .bulk_extraction_fan_is_on()
+    }
+    fn bulk_isolation_valves_open_allowed(&self) -> bool {
+        self.a380_air_conditioning_system
+            .bulk_isolation_valves_open_allowed()
+    }
+    fn overpressure_relief_valve_open_amount(&self) -> Ratio {
+        self.a380_air_conditioning_system
+            .overpressure_relief_valve_open_amount()
+    }
+}
+
 impl SimulationElement for A380AirConditioning {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.a380_cabin.accept(visitor);
@@ -469,6 +502,7 @@ impl A380AirConditioningSystem {
         &mut self,
         context: &UpdateContext,
         cabin_simulation: &impl CabinSimulation,
+        cargo_ventilation_isolation: &impl CargoVentilationIsolation,
         cpiom_b: &[CoreProcessingInputOutputModuleB; 4],
         engines: [&impl EngineCorrectedN1; 4],
         engine_fire_push_buttons: &impl EngineFirePushButtons,
@@ -480,6 +514,7 @@ impl A380AirConditioningSystem {
     ) {
         self.update_local_controllers(
             context,
+            cargo_ventilation_isolation,
             cpiom_b,
             engines,
             engine_fire_push_buttons,
@@ -506,6 +541,7 @@ impl A380AirConditioningSystem {
     fn update_local_controllers(
         &mut self,
         context: &UpdateContext,
+        cargo_ventilation_isolation: &impl CargoVentilationIsolation,
         cpiom_b: &[CoreProcessingInputOutputModuleB; 4],
         engines: [&impl EngineCorrectedN1; 4],
         engine_fire_push_buttons: &impl EngineFirePushButtons,
@@ -561,6 +597,7 @@ impl A380AirConditioningSystem {
         self.vcm.iter_mut().for_each(|module| {
             module.update(
                 &self.air_conditioning_overhead,
+                cargo_ventilation_isolation,
                 ocsm,
                 pressurization_overhead,
             )
@@ -1201,6 +1238,8 @@ mod tests {
             avionics_full_duplex_switch::AvionicsFullDuplexSwitch,
             core_processing_input_output_module::CoreProcessingInputOutputModule,
             input_output_module::InputOutputModule,
+            virtual_link::{VirtualLinkConfiguration, VirtualLinkIdentifier},
+            AvionicsDataCommunicationNetworkModule,
         },
         overhead::AutoOffFaultPushButton,
         pneumatic::{
@@ -1338,6 +1377,15 @@ mod tests {
         ) -> &InputOutputModule<A380AvionicsDataCommunicationNetworkMessageData> {
             unimplemented!()
         }
+
+        fn add_virtual_link(
+            &mut self,
+            _source: AvionicsDataCommunicationNetworkModule,
+            _destinations: Vec<AvionicsDataCommunicationNetworkModule>,
+            _configuration: VirtualLinkConfiguration,
+        ) -> VirtualLinkIdentifier {
+            unimplemented!()
+        }
     }
     impl SimulationElement for TestAdcn {
         fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
@@ -1829,6 +1877,31 @@ mod tests {
         }
     }
 
+    struct TestFireAndSmokeProtection {
+        fwd_cargo_isolation_requested: bool,
+        bulk_cargo_isolation_requested: bool,
+    }
+    impl TestFireAndSmokeProtection {
+        fn new() -> Self {
+            Self {
+                fwd_cargo_isolation_requested: false,
+                bulk_cargo_isolation_requested: false,
+            }
+        }
+        fn request_cargo_isolation(&mut self, fwd: bool, bulk: bool) {
+            self.fwd_cargo_isolation_requested = fwd;
+            self.bulk_cargo_isolation_requested = bulk;
+        }
+    }
+    impl CargoVentilationIsolation for TestFireAndSmokeProtection {
+        fn fwd_cargo_isolation_requested(&self) -> bool {
+            self.fwd_cargo_isolation_requested
+        }
+        fn bulk_cargo_isolation_requested(&self) -> bool {
+            self.bulk_cargo_isolation_requested
+        }
+    }
+
     struct TestLgciu {
         compressed: bool,
     }
@@ -1878,6 +1951,7 @@ mod tests {
         engine_3: TestEngine,
         engine_4: TestEngine,
         engine_fire_push_buttons: TestEngineFirePushButtons,
+        fire_and_smoke_protection: TestFireAndSmokeProtection,
         payload: TestPayload,
         pneumatic: TestPneumatic,
         pneumatic_overhead: TestPneumaticOverhead,
@@ -1922,6 +1996,7 @@ mod tests {
                 engine_3: TestEngine::new(Ratio::default()),
                 engine_4: TestEngine::new(Ratio::default()),
                 engine_fire_push_buttons: TestEngineFirePushButtons::new(),
+                fire_and_smoke_protection: TestFireAndSmokeProtection::new(),
                 payload: TestPayload::new(),
                 pneumatic: TestPneumatic::new(context),
                 pneumatic_overhead: TestPneumaticOverhead::new(context),
@@ -2130,6 +2205,7 @@ mod tests {
                 context,
                 &self.adirs,
                 &self.dsms,
+                &self.fire_and_smoke_protection,
                 &self.adcn,
                 [
                     &self.engine_1,
@@ -2579,6 +2655,14 @@ mod tests {
             self
         }
 
+        fn command_cargo_isolation_request(mut self, fwd: bool, bulk: bool) -> Self {
+            self.command(|a| {
+                a.fire_and_smoke_protection
+                    .request_cargo_isolation(fwd, bulk);
+            });
+            self
+        }
+
         fn command_number_of_passengers(mut self, number_of_passengers: u32) -> Self {
             self.write_by_name("FMS_PAX_NUMBER", number_of_passengers);
             self.command(|a| a.update_number_of_passengers(number_of_passengers));
@@ -5143,6 +5227,24 @@ mod tests {
                 assert!(!test_bed.fwd_isolation_valves_are_open());
             }
 
+            #[test]
+            fn fwd_isolation_valves_close_when_cargo_isolation_requested() {
+                let mut test_bed = test_bed()
+                    .command_fwd_isolation_valves_pb_on(true)
+                    .command_bulk_isolation_valves_pb_on(true)
+                    .iterate(5);
+
+                assert!(test_bed.fwd_isolation_valves_are_open());
+
+                test_bed = test_bed
+                    .command_cargo_isolation_request(true, false)
+                    .iterate(5);
+
+                assert!(!test_bed.fwd_extraction_fan_is_on());
+                assert!(!test_bed.fwd_isolation_valves_are_open());
+                assert!(test_bed.bulk_isolation_valves_are_open());
+            }
+
             #[test]
             fn bulk_isolation_and_fans_are_on_when_conditions_met() {
                 let test_bed = test_bed()
@@ -5184,6 +5286,24 @@ mod tests {
                 assert!(!test_bed.bulk_isolation_valves_are_open());
             }
 
+            #[test]
+            fn bulk_isolation_valves_close_when_cargo_isolation_requested() {
+                let mut test_bed = test_bed()
+                    .command_fwd_isolation_valves_pb_on(true)
+                    .command_bulk_isolation_valves_pb_on(true)
+                    .iterate(5);
+
+                assert!(test_bed.bulk_isolation_valves_are_open());
+
+                test_bed = test_bed
+                    .command_cargo_isolation_request(false, true)
+                    .iterate(5);
+
+                assert!(!test_bed.bulk_extraction_fan_is_on());
+                assert!(!test_bed.bulk_isolation_valves_are_open());
+                assert!(test_bed.fwd_isolation_valves_are_open());
+            }
+
             #[test]
             fn bulk_heater_allowed_on_when_conditions_met() {
                 let mut test_bed = test_bed()
@@ -2,12 +2,16 @@ use crate::systems::{
     integrated_modular_avionics::{
         avionics_full_duplex_switch::AvionicsFullDuplexSwitch,
         core_processing_input_output_module::CoreProcessingInputOutputModule,
-        input_output_module::InputOutputModule, AvionicsDataCommunicationNetwork,
-        AvionicsDataCommunicationNetworkMessageIdentifier,
+        input_output_module::InputOutputModule,
+        virtual_link::{
+            AfdxEndSystem, AfdxFrame, AfdxNetwork, VirtualLinkConfiguration, VirtualLinkIdentifier,
+        },
+        AvionicsDataCommunicationNetwork, AvionicsDataCommunicationNetworkMessageIdentifier,
+        AvionicsDataCommunicationNetworkModule,
     },
     shared::ElectricalBusType,
     simulation::{
-        InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter,
+        InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
         VariableIdentifier, Write,
     },
 };
@@ -16,6 +20,7 @@ use std::{
     cell::{Ref, RefCell},
     collections::VecDeque,
     rc::Rc,
+    time::Duration,
     vec::Vec,
 };
 use systems::integrated_modular_avionics::{
@@ -51,6 +56,19 @@ impl RoutingTableEntry {
     }
 }
 
+struct VirtualLink {
+    id: VirtualLinkIdentifier,
+    configuration: VirtualLinkConfiguration,
+    source: AvionicsDataCommunicationNetworkModule,
+    destinations: Vec<AvionicsDataCommunicationNetworkModule>,
+}
+
+struct FrameInTransit {
+    destination: AvionicsDataCommunicationNetworkModule,
+    remaining_latency: Duration,
+    frame: AfdxFrame<A380AvionicsDataCommunicationNetworkMessageData>,
+}
+
 // The ADCN contains information about the CDIOMs, IOMs and the AFDX networs.
 // The ADCN networks are defined by two redundant networks with same wirings.
 // The routing tables per network are defined as upper triangular matrices
@@ -67,6 +85,11 @@ impl RoutingTableEntry {
 //
 // The routing tables define the upper triangular matrix for the two networks.
 // A breadth-first-search is used to update the routing table per AFDX switch.
+//
+// Messages sent over virtual links are delivered to the end systems of the destination modules
+// as frames on both networks. The latency of a frame depends on the number of switches on the
+// shortest path between the modules, which is found by the same breadth-first-search.
+// The redundancy management of the receiving end system passes the first valid copy on.
 pub struct A380AvionicsDataCommunicationNetwork {
     afdx_switches: [Rc<
         RefCell<AvionicsFullDuplexSwitch<A380AvionicsDataCommunicationNetworkMessageData>>,
@@ -82,9 +105,20 @@ pub struct A380AvionicsDataCommunicationNetwork {
     publish_routing_table: bool,
     next_message_identifier: AvionicsDataCommunicationNetworkMessageIdentifier,
     message_identifiers: FxHashMap<String, AvionicsDataCommunicationNetworkMessageIdentifier>,
+    switch_hops: [[[Option<u8>; 8]; 8]; 2],
+    module_switches: FxHashMap<AvionicsDataCommunicationNetworkModule, usize>,
+    next_virtual_link_identifier: VirtualLinkIdentifier,
+    virtual_links: Vec<VirtualLink>,
+    frames_in_transit: Vec<FrameInTransit>,
 }
 
 impl A380AvionicsDataCommunicationNetwork {
+    /// The latency of the transmitting end system.
+    const END_SYSTEM_LATENCY: Duration = Duration::from_micros(150);
+    /// The technological latency of a switch, without the time to store the frame.
+    const SWITCH_LATENCY: Duration = Duration::from_micros(100);
+    const LINK_BITS_PER_SECOND: u64 = 100_000_000;
+
     pub fn new(context: &mut InitContext) -> Self {
         let first_network = FxHashMap::from_iter([
             (0, vec![1, 2, 7]),
@@ -168,6 +202,8 @@ impl A380AvionicsDataCommunicationNetwork {
             )
         });
 
+        let mut module_switches = FxHashMap::default();
+
         let io_modules = FxHashMap::from_iter(
             [
                 ("A1", 1, ElectricalBusType::DirectCurrentEssential), // powered by 425PP
@@ -180,6 +216,10 @@ impl A380AvionicsDataCommunicationNetwork {
                 ("A8", 4, ElectricalBusType::DirectCurrent(2)),
             ]
             .map(|(name, connected_switch, power_supply)| {
+                module_switches.insert(
+                    AvionicsDataCommunicationNetworkModule::Iom(name),
+                    Self::map_switch_id(connected_switch),
+                );
                 (
                     name,
                     InputOutputModule::new(
@@ -220,6 +260,10 @@ impl A380AvionicsDataCommunicationNetwork {
                 ("G4", 6, ElectricalBusType::DirectCurrent(2)),
             ]
             .map(|(name, connected_switch, bus)| {
+                module_switches.insert(
+                    AvionicsDataCommunicationNetworkModule::Cpiom(name),
+                    Self::map_switch_id(connected_switch),
+                );
                 (
                     name,
                     CoreProcessingInputOutputModule::new(
@@ -348,57 +392,69 @@ impl A380AvionicsDataCommunicationNetwork {
             publish_routing_table: true,
             next_message_identifier: AvionicsDataCommunicationNetworkMessageIdentifier::default(),
             message_identifiers: FxHashMap::default(),
+            switch_hops: [[[None; 8]; 8]; 2],
+            module_switches,
+            next_virtual_link_identifier: VirtualLinkIdentifier::default(),
+            virtual_links: Vec::new(),
+            frames_in_transit: Vec::new(),
         }
     }
 
-    fn switches_reachable(
+    // Returns the number of hops between two switches or `None` if they can't reach each other
+    fn shortest_path_hops(
         afdx_switches: &[Rc<RefCell<AvionicsFullDuplexSwitch<A380AvionicsDataCommunicationNetworkMessageData>>>;
              16],
         network: &FxHashMap<u8, Vec<u8>>,
         from: u8,
         to: u8,
-    ) -> bool {
+    ) -> Option<u8> {
         let mut frontier: VecDeque<u8> = VecDeque::new();
-        let mut visited: Vec<u8> = Vec::new();
+        let mut hops: Vec<Option<u8>> = Vec::new();
 
         if !afdx_switches[from as usize].borrow().is_available() {
-            return false;
+            return None;
         }
 
-        visited.resize(network.len() * 2, 0xff);
+        hops.resize(network.len() * 2, None);
         frontier.push_front(from);
-        visited[from as usize] = from;
+        hops[from as usize] = Some(0);
 
-        while !frontier.is_empty() {
-            let node = frontier.pop_front().unwrap();
+        while let Some(node) = frontier.pop_front() {
+            let node_hops = hops[node as usize].unwrap();
 
             if node == to {
-                return true;
+                return Some(node_hops);
             }
 
             let neighbors = &network[&node];
             for &neighbor in neighbors {
                 if afdx_switches[neighbor as usize].borrow().is_available()
-                    && visited[neighbor as usize] == 0xff
+                    && hops[neighbor as usize].is_none()
                 {
-                    visited[neighbor as usize] = node;
+                    hops[neighbor as usize] = Some(node_hops + 1);
                     frontier.push_back(neighbor);
                 }
             }
         }
 
-        false
+        None
     }
 
     fn update_routing_table(&mut self, network: usize, offset: usize) {
-        for (y, row) in self.routing_tables[network].iter_mut().enumerate() {
-            for (x, entry) in row.iter_mut().enumerate() {
-                entry.set_reachable(Self::switches_reachable(
+        for (from, row) in self.switch_hops[network].iter_mut().enumerate() {
+            for (to, hops) in row.iter_mut().enumerate() {
+                *hops = Self::shortest_path_hops(
                     &self.afdx_switches,
                     &self.afdx_networks[network],
-                    (y + offset) as u8,
-                    (x + offset) as u8,
-                ));
+                    (from + offset) as u8,
+                    (to + offset) as u8,
+                );
+            }
+        }
+
+        for (y, row) in self.routing_tables[network].iter_mut().enumerate() {
+            for (x, entry) in row.iter_mut().enumerate() {
+                entry.set_reachable(self.switch_hops[network][y][x].is_some());
             }
         }
     }
@@ -443,7 +499,134 @@ impl A380AvionicsDataCommunicationNetwork {
         }
     }
 
-    pub fn update(&mut self) {
+    fn end_system_mut<'b>(
+        cpio_modules: &'b mut FxHashMap<
+            &'static str,
+            CoreProcessingInputOutputModule<A380AvionicsDataCommunicationNetworkMessageData>,
+        >,
+        io_modules: &'b mut FxHashMap<
+            &'static str,
+            InputOutputModule<A380AvionicsDataCommunicationNetworkMessageData>,
+        >,
+        module: AvionicsDataCommunicationNetworkModule,
+    ) -> &'b mut AfdxEndSystem<A380AvionicsDataCommunicationNetworkMessageData> {
+        match module {
+            AvionicsDataCommunicationNetworkModule::Cpiom(name) => cpio_modules
+                .get_mut(name)
+                .expect("Unknown CPIOM")
+                .end_system_mut(),
+            AvionicsDataCommunicationNetworkModule::Iom(name) => io_modules
+                .get_mut(name)
+                .expect("Unknown IOM")
+                .end_system_mut(),
+        }
+    }
+
+    fn module_is_available(&self, module: AvionicsDataCommunicationNetworkModule) -> bool {
+        match module {
+            AvionicsDataCommunicationNetworkModule::Cpiom(name) => {
+                self.cpio_modules[name].is_available()
+            }
+            AvionicsDataCommunicationNetworkModule::Iom(name) => {
+                self.io_modules[name].is_available()
+            }
+        }
+    }
+
+    // The frame is stored and forwarded by every switch on the path, so it is transmitted once
+    // more on a link than there are switches.
+    fn frame_latency(configuration: &VirtualLinkConfiguration, hops: u8) -> Duration {
+        let switches = hops as u32 + 1;
+        let transmission_time = Duration::from_nanos(
+            configuration.max_frame_size() as u64 * 8 * 1_000_000_000 / Self::LINK_BITS_PER_SECOND,
+        );
+
+        Self::END_SYSTEM_LATENCY
+            + Self::SWITCH_LATENCY * switches
+            + transmission_time * (switches + 1)
+    }
+
+    fn update_end_systems(&mut self, delta: Duration) {
+        for cpiom in self.cpio_modules.values_mut() {
+            if cpiom.is_available() {
+                cpiom.end_system_mut().update(delta);
+            } else {
+                cpiom.end_system_mut().reset();
+            }
+        }
+        for iom in self.io_modules.values_mut() {
+            if iom.is_available() {
+                iom.end_system_mut().update(delta);
+            } else {
+                iom.end_system_mut().reset();
+            }
+        }
+    }
+
+    fn transmit_frames(&mut self, delta: Duration) {
+        for virtual_link in &self.virtual_links {
+            let Some((sequence_number, message)) = Self::end_system_mut(
+                &mut self.cpio_modules,
+                &mut self.io_modules,
+                virtual_link.source,
+            )
+            .transmit(&virtual_link.id, delta) else {
+                continue;
+            };
+
+            let source_switch = self.module_switches[&virtual_link.source];
+            for network in AfdxNetwork::ALL {
+                for &destination in &virtual_link.destinations {
+                    let destination_switch = self.module_switches[&destination];
+                    if let Some(hops) =
+                        self.switch_hops[network as usize][source_switch][destination_switch]
+                    {
+                        let latency = Self::frame_latency(&virtual_link.configuration, hops);
+                        self.frames_in_transit.push(FrameInTransit {
+                            destination,
+                            remaining_latency: latency,
+                            frame: AfdxFrame::new(
+                                virtual_link.id,
+                                network,
+                                sequence_number,
+                                latency,
+                                message.clone(),
+                            ),
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    fn deliver_frames(&mut self, delta: Duration) {
+        let (mut arrived_frames, frames_in_transit): (Vec<_>, Vec<_>) = self
+            .frames_in_transit
+            .drain(..)
+            .partition(|frame| frame.remaining_latency <= delta);
+        self.frames_in_transit = frames_in_transit
+            .into_iter()
+            .map(|mut frame| {
+                frame.remaining_latency -= delta;
+                frame
+            })
+            .collect();
+
+        // The frames are delivered in the order of their arrival
+        arrived_frames.sort_by_key(|frame| frame.remaining_latency);
+        for frame in arrived_frames {
+            if self.module_is_available(frame.destination) {
+                Self::end_system_mut(
+                    &mut self.cpio_modules,
+                    &mut self.io_modules,
+                    frame.destination,
+                )
+                .deliver(frame.frame);
+            }
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext) {
         let mut update_network_a = false;
         let mut update_network_b = false;
 
@@ -470,6 +653,10 @@ impl A380AvionicsDataCommunicationNetwork {
         }
 
         self.publish_routing_table = update_network_a | update_network_b;
+
+        self.update_end_systems(context.delta());
+        self.transmit_frames(context.delta());
+        self.deliver_frames(context.delta());
     }
 
     const fn map_switch_id(id: u8) -> usize {
@@ -518,6 +705,32 @@ impl<'a> AvionicsDataCommunicationNetwork<'a, A380AvionicsDataCommunicationNetwo
     ) -> &InputOutputModule<A380AvionicsDataCommunicationNetworkMessageData> {
         &self.io_modules[name]
     }
+
+    fn add_virtual_link(
+        &mut self,
+        source: AvionicsDataCommunicationNetworkModule,
+        destinations: Vec<AvionicsDataCommunicationNetworkModule>,
+        configuration: VirtualLinkConfiguration,
+    ) -> VirtualLinkIdentifier {
+        let id = self.next_virtual_link_identifier;
+        self.next_virtual_link_identifier = id.next();
+
+        Self::end_system_mut(&mut self.cpio_modules, &mut self.io_modules, source)
+            .add_transmit_port(id, configuration);
+        for &destination in &destinations {
+            Self::end_system_mut(&mut self.cpio_modules, &mut self.io_modules, destination)
+                .add_receive_port(id);
+        }
+
+        self.virtual_links.push(VirtualLink {
+            id,
+            configuration,
+            source,
+            destinations,
+        });
+
+        id
+    }
 }
 
 impl SimulationElement for A380AvionicsDataCommunicationNetwork {
@@ -607,7 +820,9 @@ mod tests {
             Aircraft, InitContext, SimulationElement, SimulationElementVisitor, UpdateContext,
         },
     };
-    use systems::integrated_modular_avionics::AvionicsDataCommunicationNetworkMessage;
+    use systems::integrated_modular_avionics::{
+        virtual_link::VirtualLinkReceiver, AvionicsDataCommunicationNetworkMessage,
+    };
     use uom::si::{electric_potential::volt, f64::*};
 
     struct AdcnTestAircraft {
@@ -638,8 +853,8 @@ mod tests {
             }
         }
 
-        fn update(&mut self) {
-            self.adcn.update();
+        fn update(&mut self, context: &UpdateContext) {
+            self.adcn.update(context);
         }
 
         fn set_elec_powered(&mut self, is_powered: bool) {
@@ -675,6 +890,59 @@ mod tests {
         > {
             self.adcn.get_endpoint(switch_id).recv_value(id)
         }
+
+        fn add_virtual_link(
+            &mut self,
+            source: AvionicsDataCommunicationNetworkModule,
+            destination: AvionicsDataCommunicationNetworkModule,
+        ) -> VirtualLinkIdentifier {
+            self.adcn
+                .add_virtual_link(source, vec![destination], test_virtual_link_configuration())
+        }
+
+        fn send_on_virtual_link(
+            &self,
+            source: AvionicsDataCommunicationNetworkModule,
+            virtual_link: &VirtualLinkIdentifier,
+            message: AvionicsDataCommunicationNetworkMessage<
+                A380AvionicsDataCommunicationNetworkMessageData,
+            >,
+        ) {
+            match source {
+                AvionicsDataCommunicationNetworkModule::Cpiom(name) => self
+                    .adcn
+                    .get_cpiom(name)
+                    .end_system()
+                    .send(virtual_link, message),
+                AvionicsDataCommunicationNetworkModule::Iom(name) => self
+                    .adcn
+                    .get_iom(name)
+                    .end_system()
+                    .send(virtual_link, message),
+            }
+        }
+
+        fn virtual_link_receiver(
+            &self,
+            destination: &str,
+            virtual_link: &VirtualLinkIdentifier,
+        ) -> &VirtualLinkReceiver<A380AvionicsDataCommunicationNetworkMessageData> {
+            self.adcn
+                .get_cpiom(destination)
+                .end_system()
+                .receive(virtual_link)
+        }
+    }
+
+    const CPIOM_B1: AvionicsDataCommunicationNetworkModule =
+        AvionicsDataCommunicationNetworkModule::Cpiom("B1");
+    const CPIOM_C1: AvionicsDataCommunicationNetworkModule =
+        AvionicsDataCommunicationNetworkModule::Cpiom("C1");
+    const IOM_A1: AvionicsDataCommunicationNetworkModule =
+        AvionicsDataCommunicationNetworkModule::Iom("A1");
+
+    fn test_virtual_link_configuration() -> VirtualLinkConfiguration {
+        VirtualLinkConfiguration::new(Duration::from_millis(8), 128)
     }
     impl Aircraft for AdcnTestAircraft {
         fn update_before_power_distribution(
@@ -694,8 +962,8 @@ mod tests {
             }
         }
 
-        fn update_after_power_distribution(&mut self, _: &UpdateContext) {
-            self.update();
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.update(context);
         }
     }
     impl SimulationElement for AdcnTestAircraft {
@@ -1002,4 +1270,112 @@ mod tests {
             assert_eq!(message, Some(message2.clone()));
         }
     }
+
+    #[test]
+    fn virtual_link_passes_first_copy_to_application() {
+        let mut test_bed = SimulationTestBed::new(AdcnTestAircraft::new);
+
+        let mut virtual_link = Default::default();
+        test_bed.command(|a| virtual_link = a.add_virtual_link(CPIOM_B1, CPIOM_C1));
+        let message_sent: AvionicsDataCommunicationNetworkMessage<_> =
+            A380AvionicsDataCommunicationNetworkMessageData::TestValue("testval").into();
+        test_bed.command(|a| a.set_elec_powered(true));
+        test_bed.run();
+        test_bed.command(|a| a.send_on_virtual_link(CPIOM_B1, &virtual_link, message_sent.clone()));
+        test_bed.run();
+
+        test_bed.query(|a| {
+            let receiver = a.virtual_link_receiver("C1", &virtual_link);
+            assert_eq!(receiver.message(), Some(&message_sent));
+            assert_eq!(receiver.accepted_frames(), 1);
+            assert_eq!(receiver.duplicate_frames(), 1);
+            assert_eq!(receiver.integrity_errors(), 0);
+            assert_eq!(
+                receiver.latency(),
+                A380AvionicsDataCommunicationNetwork::frame_latency(
+                    &test_virtual_link_configuration(),
+                    1
+                )
+            );
+        });
+    }
+
+    #[test]
+    fn virtual_link_is_served_by_network_b_when_network_a_fails() {
+        let mut test_bed = SimulationTestBed::new(AdcnTestAircraft::new);
+
+        let mut virtual_link = Default::default();
+        test_bed.command(|a| virtual_link = a.add_virtual_link(CPIOM_B1, CPIOM_C1));
+        let message_sent: AvionicsDataCommunicationNetworkMessage<_> =
+            A380AvionicsDataCommunicationNetworkMessageData::TestValue("testval").into();
+        test_bed.command(|a| a.set_elec_powered(true));
+        test_bed.write_by_name("AFDX_SWITCH_3_FAILURE", true);
+        test_bed.run();
+        test_bed.command(|a| a.send_on_virtual_link(CPIOM_B1, &virtual_link, message_sent.clone()));
+        test_bed.run();
+
+        test_bed.query(|a| {
+            let receiver = a.virtual_link_receiver("C1", &virtual_link);
+            assert_eq!(receiver.message(), Some(&message_sent));
+            assert_eq!(receiver.accepted_frames(), 1);
+            assert_eq!(receiver.duplicate_frames(), 0);
+        });
+    }
+
+    #[test]
+    fn virtual_link_prefers_network_with_shorter_path() {
+        let mut test_bed = SimulationTestBed::new(AdcnTestAircraft::new);
+
+        let mut virtual_link = Default::default();
+        test_bed.command(|a| virtual_link = a.add_virtual_link(IOM_A1, CPIOM_B1));
+        let message_sent: AvionicsDataCommunicationNetworkMessage<_> =
+            A380AvionicsDataCommunicationNetworkMessageData::TestValue("testval").into();
+        test_bed.command(|a| a.set_elec_powered(true));
+        // Without switch 3 the frames on network A pass one more switch
+        test_bed.write_by_name("AFDX_SWITCH_3_FAILURE", true);
+        test_bed.run();
+        test_bed.command(|a| a.send_on_virtual_link(IOM_A1, &virtual_link, message_sent.clone()));
+        test_bed.run();
+
+        test_bed.query(|a| {
+            let receiver = a.virtual_link_receiver("B1", &virtual_link);
+            assert_eq!(receiver.message(), Some(&message_sent));
+            assert_eq!(receiver.duplicate_frames(), 1);
+            assert_eq!(
+                receiver.latency(),
+                A380AvionicsDataCommunicationNetwork::frame_latency(
+                    &test_virtual_link_configuration(),
+                    2
+                )
+            );
+        });
+    }
+
+    #[test]
+    fn virtual_link_message_becomes_stale_when_both_networks_fail() {
+        let mut test_bed = SimulationTestBed::new(AdcnTestAircraft::new);
+
+        let mut virtual_link = Default::default();
+        test_bed.command(|a| virtual_link = a.add_virtual_link(CPIOM_B1, CPIOM_C1));
+        let message1: AvionicsDataCommunicationNetworkMessage<_> =
+            A380AvionicsDataCommunicationNetworkMessageData::TestValue("message1").into();
+        let message2: AvionicsDataCommunicationNetworkMessage<_> =
+            A380AvionicsDataCommunicationNetworkMessageData::TestValue("message2").into();
+        test_bed.command(|a| a.set_elec_powered(true));
+        test_bed.run();
+        test_bed.command(|a| a.send_on_virtual_link(CPIOM_B1, &virtual_link, message1.clone()));
+        test_bed.run();
+
+        test_bed.write_by_name("AFDX_SWITCH_3_FAILURE", true);
+        test_bed.write_by_name("AFDX_SWITCH_13_FAILURE", true);
+        test_bed.run_with_delta(Duration::from_millis(500));
+        test_bed.command(|a| a.send_on_virtual_link(CPIOM_B1, &virtual_link, message2.clone()));
+        test_bed.run_with_delta(Duration::from_millis(600));
+
+        test_bed.query(|a| {
+            let receiver = a.virtual_link_receiver("C1", &virtual_link);
+            assert_eq!(receiver.message(), Some(&message1));
+            assert!(receiver.is_stale(Duration::from_secs(1)));
+        });
+    }
 }
@@ -2,7 +2,7 @@ use crate::systems::{
     accept_iterable,
     indicating_recording::controls::keyboard_cursor_control_unit::KeyboardCursorControlUnit,
     shared::{can_bus::CanBus, ElectricalBusType},
-    simulation::{InitContext, SimulationElement, SimulationElementVisitor},
+    simulation::{InitContext, SimulationElement, SimulationElementVisitor, UpdateContext},
 };
 
 // they are not the same values as the real IDs
@@ -32,6 +32,7 @@ impl A380ControlDisplaySystem {
                 CanBus::new(
                     context,
                     "CDS_CAN_BUS_1_1",
+                    1,
                     [
                         CanBusFunctionIds::KccuKeyboard as u8,
                         CanBusFunctionIds::KccuCursorControl as u8,
@@ -43,6 +44,7 @@ impl A380ControlDisplaySystem {
                 CanBus::new(
                     context,
                     "CDS_CAN_BUS_1_2",
+                    2,
                     [
                         CanBusFunctionIds::KccuKeyboard as u8,
                         CanBusFunctionIds::KccuCursorControl as u8,
@@ -56,6 +58,7 @@ impl A380ControlDisplaySystem {
                 CanBus::new(
                     context,
                     "CDS_CAN_BUS_2_1",
+                    3,
                     [
                         CanBusFunctionIds::KccuKeyboard as u8,
                         CanBusFunctionIds::KccuCursorControl as u8,
@@ -67,6 +70,7 @@ impl A380ControlDisplaySystem {
                 CanBus::new(
                     context,
                     "CDS_CAN_BUS_2_2",
+                    4,
                     [
                         CanBusFunctionIds::KccuKeyboard as u8,
                         CanBusFunctionIds::KccuCursorControl as u8,
@@ -97,15 +101,15 @@ impl A380ControlDisplaySystem {
         }
     }
 
-    pub fn update(&mut self) {
+    pub fn update(&mut self, context: &UpdateContext) {
         self.kccu_capt.update(&mut self.can_bus_1);
         self.kccu_fo.update(&mut self.can_bus_2);
 
         self.can_bus_1.iter_mut().for_each(|bus| {
-            bus.update();
+            bus.update(context);
         });
         self.can_bus_2.iter_mut().for_each(|bus| {
-            bus.update();
+            bus.update(context);
         });
     }
 }
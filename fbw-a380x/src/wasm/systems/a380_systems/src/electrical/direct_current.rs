@@ -8,10 +8,13 @@ use systems::electrical::{BatteryChargeRectifierUnit, BatteryPushButtons, Emerge
 use systems::shared::{DelayedFalseLogicGate, RamAirTurbineController};
 use systems::simulation::{InitContext, UpdateContext};
 use systems::{
-    electrical::{Battery, Contactor, ElectricalBus, Electricity, StaticInverter},
+    electrical::{
+        Battery, BatteryChemistry, Contactor, ElectricalBus, Electricity, StaticInverter,
+    },
     shared::{AuxiliaryPowerUnitElectrical, ContactorSignal, ElectricalBusType},
     simulation::{SimulationElement, SimulationElementVisitor},
 };
+use uom::si::{electric_charge::ampere_hour, f64::*};
 
 pub(crate) const APU_START_MOTOR_BUS_TYPE: ElectricalBusType = ElectricalBusType::Sub("49-42-00");
 
@@ -57,6 +60,8 @@ pub(super) struct A380DirectCurrentElectrical {
     dc_ess_subbus: ElectricalBus,
 }
 impl A380DirectCurrentElectrical {
+    const BATTERY_CAPACITY_AMPERE_HOURS: f64 = 50.;
+
     pub fn new(context: &mut InitContext) -> Self {
         A380DirectCurrentElectrical {
             // 100PP
@@ -71,14 +76,14 @@ impl A380DirectCurrentElectrical {
                 context,
                 ElectricalBusType::DirectCurrentNamed("309PP"),
             ),
-            battery_1: Battery::full(context, 1),
+            battery_1: Battery::full_with_chemistry(context, 1, Self::battery_chemistry()),
             battery_1_contactor: Contactor::new(context, "990PB1"),
             battery_1_emergency_contactor: Contactor::new(context, "6PC1"),
-            battery_2: Battery::full(context, 2),
+            battery_2: Battery::full_with_chemistry(context, 2, Self::battery_chemistry()),
             battery_2_contactor: Contactor::new(context, "990PB2"),
-            battery_ess: Battery::full(context, 3),
+            battery_ess: Battery::full_with_chemistry(context, 3, Self::battery_chemistry()),
             battery_ess_contactor: Contactor::new(context, "6PB3"),
-            battery_apu: Battery::full(context, 4),
+            battery_apu: Battery::full_with_chemistry(context, 4, Self::battery_chemistry()),
             battery_apu_contactor: Contactor::new(context, "5PB"),
             static_inverter: StaticInverter::new(context),
             static_inverter_contactor: Contactor::new(context, "7XB"),
@@ -132,6 +137,12 @@ impl A380DirectCurrentElectrical {
         }
     }
 
+    fn battery_chemistry() -> BatteryChemistry {
+        BatteryChemistry::nickel_cadmium(ElectricCharge::new::<ampere_hour>(
+            Self::BATTERY_CAPACITY_AMPERE_HOURS,
+        ))
+    }
+
     pub fn update(
         &mut self,
         context: &UpdateContext,
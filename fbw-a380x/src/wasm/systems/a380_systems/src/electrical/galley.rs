@@ -1,5 +1,80 @@
 use super::{alternating_current::A380AlternatingCurrentElectrical, A380ElectricalOverheadPanel};
-use systems::electrical::{AlternatingCurrentElectricalSystem, Electricity};
+use systems::{
+    electrical::{
+        AlternatingCurrentElectricalSystem, ElectricalLoadManagement, Electricity, SheddableLoad,
+    },
+    shared::{ElectricalBusType, PotentialOrigin},
+    simulation::InitContext,
+};
+use uom::si::{electric_current::ampere, f64::*, power::watt};
+
+pub(super) const MAIN_GALLEY: &str = "MAIN_GALLEY";
+pub(super) const SECONDARY_GALLEY: &str = "SECONDARY_GALLEY";
+pub(super) const IN_FLIGHT_ENTERTAINMENT: &str = "IN_FLIGHT_ENTERTAINMENT";
+pub(super) const COMMERCIAL: &str = "COMMERCIAL";
+
+/// Creates the load management of the galleys, in-flight entertainment and
+/// other commercial loads, given in the order in which they are shed.
+pub(super) fn commercial_load_management(context: &mut InitContext) -> ElectricalLoadManagement<4> {
+    let engine_generator_rated_power = Power::new::<watt>(150_000.);
+    let apu_generator_rated_power = Power::new::<watt>(120_000.);
+    // All four external power units share a single potential origin.
+    let external_power_rated_power = Power::new::<watt>(4. * 90_000.);
+
+    ElectricalLoadManagement::new(
+        &[
+            (
+                PotentialOrigin::EngineGenerator(1),
+                engine_generator_rated_power,
+            ),
+            (
+                PotentialOrigin::EngineGenerator(2),
+                engine_generator_rated_power,
+            ),
+            (
+                PotentialOrigin::EngineGenerator(3),
+                engine_generator_rated_power,
+            ),
+            (
+                PotentialOrigin::EngineGenerator(4),
+                engine_generator_rated_power,
+            ),
+            (PotentialOrigin::ApuGenerator(1), apu_generator_rated_power),
+            (PotentialOrigin::ApuGenerator(2), apu_generator_rated_power),
+            (PotentialOrigin::External, external_power_rated_power),
+        ],
+        [
+            SheddableLoad::new(
+                context,
+                MAIN_GALLEY,
+                ElectricalBusType::AlternatingCurrent(1),
+                Power::new::<watt>(40_000.),
+                ElectricCurrent::new::<ampere>(400.),
+            ),
+            SheddableLoad::new(
+                context,
+                SECONDARY_GALLEY,
+                ElectricalBusType::AlternatingCurrent(4),
+                Power::new::<watt>(20_000.),
+                ElectricCurrent::new::<ampere>(200.),
+            ),
+            SheddableLoad::new(
+                context,
+                IN_FLIGHT_ENTERTAINMENT,
+                ElectricalBusType::AlternatingCurrent(2),
+                Power::new::<watt>(15_000.),
+                ElectricCurrent::new::<ampere>(150.),
+            ),
+            SheddableLoad::new(
+                context,
+                COMMERCIAL,
+                ElectricalBusType::AlternatingCurrent(3),
+                Power::new::<watt>(10_000.),
+                ElectricCurrent::new::<ampere>(100.),
+            ),
+        ],
+    )
+}
 
 pub(super) struct MainGalley {
     is_shed: bool,
@@ -5,7 +5,9 @@ mod galley;
 use self::{
     alternating_current::A380AlternatingCurrentElectrical,
     direct_current::A380DirectCurrentElectrical,
-    galley::{MainGalley, SecondaryGalley},
+    galley::{
+        commercial_load_management, MainGalley, SecondaryGalley, MAIN_GALLEY, SECONDARY_GALLEY,
+    },
 };
 pub(super) use direct_current::APU_START_MOTOR_BUS_TYPE;
 
@@ -18,9 +20,10 @@ use std::time::Duration;
 use systems::{
     accept_iterable,
     electrical::{
-        AlternatingCurrentElectricalSystem, BatteryPushButtons, ElectricalElement, Electricity,
-        EmergencyElectrical, EmergencyGenerator, EngineGeneratorPushButtons, ExternalPowerSource,
-        GeneratorControlUnit, RamAirTurbine, StaticInverter, TransformerRectifier,
+        AlternatingCurrentElectricalSystem, ApuGeneratorPushButtons, BatteryPushButtons,
+        ElectricalElement, ElectricalLoadManagement, Electricity, EmergencyElectrical,
+        EmergencyGenerator, EngineGeneratorPushButtons, ExternalPowerSource, GeneratorControlUnit,
+        RamAirTurbine, StaticInverter, TransformerRectifier,
     },
     engine::Engine,
     overhead::{
@@ -47,6 +50,7 @@ pub(super) struct A380Electrical {
     direct_current: A380DirectCurrentElectrical,
     main_galley: MainGalley,
     secondary_galley: SecondaryGalley,
+    load_management: ElectricalLoadManagement<4>,
     emergency_elec: EmergencyElectrical,
     emergency_gen: EmergencyGenerator,
 
@@ -75,6 +79,7 @@ impl A380Electrical {
             direct_current: A380DirectCurrentElectrical::new(context),
             main_galley: MainGalley::new(),
             secondary_galley: SecondaryGalley::new(),
+            load_management: commercial_load_management(context),
             emergency_elec: EmergencyElectrical::new(),
             emergency_gen: EmergencyGenerator::new(
                 context,
@@ -171,6 +176,12 @@ impl A380Electrical {
             .update(electricity, &self.alternating_current, overhead);
         self.secondary_galley
             .update(electricity, &self.alternating_current, overhead);
+        self.load_management.update([
+            !self.main_galley.is_shed(),
+            !self.secondary_galley.is_shed(),
+            !overhead.commercial_is_off() && !overhead.galy_and_cab_is_off(),
+            !overhead.commercial_is_off(),
+        ]);
 
         // Update relay states
         let dc_ess_powered =
@@ -225,7 +236,10 @@ impl A380Electrical {
     }
 
     fn galley_is_shed(&self) -> bool {
-        self.main_galley.is_shed() || self.secondary_galley.is_shed()
+        self.main_galley.is_shed()
+            || self.secondary_galley.is_shed()
+            || self.load_management.is_shed(MAIN_GALLEY)
+            || self.load_management.is_shed(SECONDARY_GALLEY)
     }
 
     #[cfg(test)]
@@ -307,6 +321,7 @@ impl SimulationElement for A380Electrical {
         self.emergency_gen.accept(visitor);
         self.ram_air_turbine.accept(visitor);
         self.rat_controller.accept(visitor);
+        self.load_management.accept(visitor);
 
         visitor.visit(self);
     }
@@ -428,6 +443,11 @@ impl EngineGeneratorPushButtons for A380ElectricalOverheadPanel {
         self.idgs[number - 1].is_released()
     }
 }
+impl ApuGeneratorPushButtons for A380ElectricalOverheadPanel {
+    fn apu_gen_push_button_is_on(&self, number: usize) -> bool {
+        self.apu_generator_is_on(number)
+    }
+}
 impl BatteryPushButtons for A380ElectricalOverheadPanel {
     fn bat_is_auto(&self, number: usize) -> bool {
         self.batteries[number - 1].is_auto()
@@ -572,9 +592,10 @@ mod a380_electrical_circuit_tests {
     use systems::{
         apu::ApuGenerator,
         electrical::{
-            ElectricalElement, ElectricalElementIdentifier, ElectricalElementIdentifierProvider,
-            Electricity, ElectricitySource, ExternalPowerSource, Potential, ProvideFrequency,
-            ProvidePotential, INTEGRATED_DRIVE_GENERATOR_STABILIZATION_TIME,
+            consumption::PowerConsumer, ElectricalElement, ElectricalElementIdentifier,
+            ElectricalElementIdentifierProvider, ElectricalTopology, Electricity,
+            ElectricitySource, ExternalPowerSource, Potential, ProvideFrequency, ProvidePotential,
+            INTEGRATED_DRIVE_GENERATOR_STABILIZATION_TIME,
         },
         failures::FailureType,
         shared::{
@@ -593,6 +614,7 @@ mod a380_electrical_circuit_tests {
         frequency::hertz,
         length::foot,
         mass_density::slug_per_cubic_foot,
+        power::watt,
         ratio::{percent, ratio},
         thermodynamic_temperature::degree_celsius,
         velocity::knot,
@@ -2768,6 +2790,41 @@ mod a380_electrical_circuit_tests {
         assert!(test_bed.ac_bus_output(ac_bus_2).is_unpowered());
     }
 
+    #[test]
+    fn when_bus_tie_off_topology_shows_all_bus_tie_contactors_open() {
+        let test_bed = test_bed_with().running_engines().and().bus_tie_off().run();
+
+        let topology = test_bed.electrical_topology();
+        for id in 1..=6 {
+            assert!(!topology
+                .element(&format!("980XU{id}"))
+                .unwrap()
+                .is_conductive());
+        }
+    }
+
+    #[test]
+    fn when_single_engine_running_topology_shows_its_generator_powering_ac_bus_1() {
+        let test_bed = test_bed_with().running_engine(1).run();
+
+        let topology = test_bed.electrical_topology();
+        assert!(topology.element("990XU1").unwrap().is_conductive());
+        assert!(topology.element("ENG_GEN_1").unwrap().supplied().unwrap() > Power::default());
+        assert_eq!(
+            topology.element("AC_1").unwrap().origins(),
+            &[PotentialOrigin::EngineGenerator(1)]
+        );
+        for number in 1..=4 {
+            assert_eq!(
+                topology
+                    .element(&format!("AC_{number}"))
+                    .unwrap()
+                    .is_powered(),
+                test_bed.ac_bus_output(number).is_powered()
+            );
+        }
+    }
+
     #[test]
     fn when_ac_ess_bus_powered_ac_ess_feed_does_not_have_fault() {
         let mut test_bed = test_bed_with().running_engines().run();
@@ -2822,8 +2879,47 @@ mod a380_electrical_circuit_tests {
     }
 
     #[test]
-    #[ignore = "Generator overloading is not yet supported."]
-    fn when_aircraft_on_the_ground_and_apu_gen_is_overloaded_galley_is_shed() {}
+    fn when_aircraft_on_the_ground_and_apu_gen_is_overloaded_galley_is_shed() {
+        let mut test_bed = test_bed_with()
+            .running_apu()
+            .on_the_ground()
+            .and()
+            .ac_bus_1_demand(Power::new::<watt>(200_000.))
+            .run_waiting_for(Duration::from_secs(5));
+
+        assert!(test_bed.galley_is_shed());
+        assert!(test_bed.load_is_shed(SECONDARY_GALLEY));
+        assert!(!test_bed.load_is_shed("IN_FLIGHT_ENTERTAINMENT"));
+    }
+
+    #[test]
+    fn when_aircraft_on_the_ground_and_apu_gen_is_not_overloaded_no_load_is_shed() {
+        let mut test_bed = test_bed_with()
+            .running_apu()
+            .on_the_ground()
+            .and()
+            .ac_bus_1_demand(Power::new::<watt>(100_000.))
+            .run_waiting_for(Duration::from_secs(5));
+
+        assert!(!test_bed.load_is_shed(SECONDARY_GALLEY));
+        assert!(!test_bed.load_is_shed("IN_FLIGHT_ENTERTAINMENT"));
+        assert!(!test_bed.load_is_shed("COMMERCIAL"));
+    }
+
+    #[test]
+    fn when_apu_gen_is_no_longer_overloaded_shed_load_is_restored() {
+        let mut test_bed = test_bed_with()
+            .running_apu()
+            .on_the_ground()
+            .and()
+            .ac_bus_1_demand(Power::new::<watt>(200_000.))
+            .run_waiting_for(Duration::from_secs(5))
+            .then_continue_with()
+            .ac_bus_1_demand(Power::new::<watt>(50_000.))
+            .run_waiting_for(Duration::from_secs(30));
+
+        assert!(!test_bed.load_is_shed(SECONDARY_GALLEY));
+    }
 
     #[rstest]
     #[case(1)]
@@ -3115,7 +3211,14 @@ mod a380_electrical_circuit_tests {
         }
     }
     impl ApuGenerator for TestApuGenerator {
-        fn update(&mut self, _n: Ratio, _is_emergency_shutdown: bool) {}
+        fn update(
+            &mut self,
+            _: &UpdateContext,
+            _n: Ratio,
+            _is_emergency_shutdown: bool,
+            _push_button_is_on: bool,
+        ) {
+        }
 
         fn output_within_normal_parameters(&self) -> bool {
             self.is_available
@@ -3394,6 +3497,7 @@ mod a380_electrical_circuit_tests {
         apu_overhead: TestApuOverhead,
         engine_fire_push_buttons: TestEngineFirePushButtons,
         emergency_generator: TestEmergencyGenerator,
+        ac_bus_1_consumer: PowerConsumer,
         force_run_emergency_gen: bool,
     }
     impl A380ElectricalTestAircraft {
@@ -3408,6 +3512,7 @@ mod a380_electrical_circuit_tests {
                 apu_overhead: TestApuOverhead::new(),
                 engine_fire_push_buttons: TestEngineFirePushButtons::new(),
                 emergency_generator: TestEmergencyGenerator::new(),
+                ac_bus_1_consumer: PowerConsumer::from(ElectricalBusType::AlternatingCurrent(1)),
                 force_run_emergency_gen: false,
             }
         }
@@ -3456,6 +3561,10 @@ mod a380_electrical_circuit_tests {
             self.force_run_emergency_gen = true;
         }
 
+        fn ac_bus_1_demand(&mut self, power: Power) {
+            self.ac_bus_1_consumer.demand(power);
+        }
+
         fn static_inverter_input<'a>(&self, electricity: &'a Electricity) -> Ref<'a, Potential> {
             electricity.input_of(self.elec.direct_current.static_inverter())
         }
@@ -3539,6 +3648,7 @@ mod a380_electrical_circuit_tests {
             self.overhead.accept(visitor);
             self.emergency_overhead.accept(visitor);
             self.apu.accept(visitor);
+            self.ac_bus_1_consumer.accept(visitor);
 
             visitor.visit(self);
         }
@@ -3754,6 +3864,15 @@ mod a380_electrical_circuit_tests {
             self
         }
 
+        fn ac_bus_1_demand(mut self, power: Power) -> Self {
+            self.command(|a| a.ac_bus_1_demand(power));
+            self
+        }
+
+        fn load_is_shed(&mut self, id: &str) -> bool {
+            self.read_by_name(&format!("ELEC_{}_IS_SHED", id))
+        }
+
         fn apu_master_sw_pb_on(mut self) -> Self {
             self.command(|a| a.set_apu_master_sw_pb_on());
             self
@@ -3788,6 +3907,10 @@ mod a380_electrical_circuit_tests {
             })
         }
 
+        fn electrical_topology(&self) -> ElectricalTopology {
+            self.query_elec(|_, elec| elec.topology())
+        }
+
         fn ac_ess_bus_output(&self) -> Ref<Potential> {
             self.query_elec_ref(|_, elec| {
                 elec.potential_of(ElectricalBusType::AlternatingCurrentEssential)
@@ -0,0 +1,574 @@
+use std::time::Duration;
+
+use systems::air_conditioning::{Channel, FdacId, OcsmId, VcmId};
+use systems::failures::{cascade::FailureDependency, FailureType};
+use systems::integrated_modular_avionics::core_processing_input_output_module::CpiomId;
+use systems::shared::{
+    AirbusElectricPumpId, AirbusEngineDrivenPumpId, ElectricalBusType, FireDetectionLoopID,
+    FireDetectionZone, GearActuatorId, HydraulicColor, LgciuId, ProximityDetectorId,
+    SmokeDetectionZone,
+};
+
+/// The failures which can be triggered in the A380, keyed by the identifier used by the failures
+/// UI in `src/systems/failures/src/a380.ts`.
+pub static FAILURES: &[(u64, FailureType)] = &[
+    (21_000, FailureType::RapidDecompression),
+    (21_001, FailureType::CabinFan(1)),
+    (21_002, FailureType::CabinFan(2)),
+    (21_003, FailureType::CabinFan(3)),
+    (21_004, FailureType::CabinFan(4)),
+    (21_005, FailureType::HotAir(1)),
+    (21_006, FailureType::HotAir(2)),
+    (21_007, FailureType::FwdIsolValve),
+    (21_008, FailureType::FwdExtractFan),
+    (21_009, FailureType::BulkIsolValve),
+    (21_010, FailureType::BulkExtractFan),
+    (21_011, FailureType::CargoHeater),
+    (21_012, FailureType::Fdac(FdacId::One, Channel::ChannelOne)),
+    (21_013, FailureType::Fdac(FdacId::One, Channel::ChannelTwo)),
+    (21_014, FailureType::Fdac(FdacId::Two, Channel::ChannelOne)),
+    (21_015, FailureType::Fdac(FdacId::Two, Channel::ChannelTwo)),
+    (21_016, FailureType::Tadd(Channel::ChannelOne)),
+    (21_017, FailureType::Tadd(Channel::ChannelTwo)),
+    (21_018, FailureType::Vcm(VcmId::Fwd, Channel::ChannelOne)),
+    (21_019, FailureType::Vcm(VcmId::Fwd, Channel::ChannelTwo)),
+    (21_020, FailureType::Vcm(VcmId::Aft, Channel::ChannelOne)),
+    (21_021, FailureType::Vcm(VcmId::Aft, Channel::ChannelTwo)),
+    (21_022, FailureType::OcsmAutoPartition(OcsmId::One)),
+    (21_023, FailureType::OcsmAutoPartition(OcsmId::Two)),
+    (21_024, FailureType::OcsmAutoPartition(OcsmId::Three)),
+    (21_025, FailureType::OcsmAutoPartition(OcsmId::Four)),
+    (21_026, FailureType::Ocsm(OcsmId::One, Channel::ChannelOne)),
+    (21_027, FailureType::Ocsm(OcsmId::One, Channel::ChannelTwo)),
+    (21_028, FailureType::Ocsm(OcsmId::Two, Channel::ChannelOne)),
+    (21_029, FailureType::Ocsm(OcsmId::Two, Channel::ChannelTwo)),
+    (
+        21_030,
+        FailureType::Ocsm(OcsmId::Three, Channel::ChannelOne),
+    ),
+    (
+        21_031,
+        FailureType::Ocsm(OcsmId::Three, Channel::ChannelTwo),
+    ),
+    (21_032, FailureType::Ocsm(OcsmId::Four, Channel::ChannelOne)),
+    (21_033, FailureType::Ocsm(OcsmId::Four, Channel::ChannelTwo)),
+    (21_034, FailureType::AgsApp(CpiomId::B1)),
+    (21_035, FailureType::AgsApp(CpiomId::B2)),
+    (21_036, FailureType::AgsApp(CpiomId::B3)),
+    (21_037, FailureType::AgsApp(CpiomId::B4)),
+    (21_038, FailureType::TcsApp(CpiomId::B1)),
+    (21_039, FailureType::TcsApp(CpiomId::B2)),
+    (21_040, FailureType::TcsApp(CpiomId::B3)),
+    (21_041, FailureType::TcsApp(CpiomId::B4)),
+    (21_042, FailureType::VcsApp(CpiomId::B1)),
+    (21_043, FailureType::VcsApp(CpiomId::B2)),
+    (21_044, FailureType::VcsApp(CpiomId::B3)),
+    (21_045, FailureType::VcsApp(CpiomId::B4)),
+    (21_046, FailureType::CpcsApp(CpiomId::B1)),
+    (21_047, FailureType::CpcsApp(CpiomId::B2)),
+    (21_048, FailureType::CpcsApp(CpiomId::B3)),
+    (21_049, FailureType::CpcsApp(CpiomId::B4)),
+    (24_000, FailureType::TransformerRectifier(1)),
+    (24_001, FailureType::TransformerRectifier(2)),
+    (24_002, FailureType::TransformerRectifier(3)),
+    (24_003, FailureType::TransformerRectifier(4)),
+    (24_004, FailureType::StaticInverter),
+    (24_020, FailureType::Generator(1)),
+    (24_021, FailureType::Generator(2)),
+    (24_022, FailureType::Generator(3)),
+    (24_023, FailureType::Generator(4)),
+    (24_030, FailureType::ApuGenerator(1)),
+    (24_031, FailureType::ApuGenerator(2)),
+    (24_040, FailureType::GeneratorDriveDisconnect(1)),
+    (24_041, FailureType::GeneratorDriveDisconnect(2)),
+    (24_042, FailureType::GeneratorDriveDisconnect(3)),
+    (24_043, FailureType::GeneratorDriveDisconnect(4)),
+    (24_060, FailureType::GeneratorVoltageRegulator(1)),
+    (24_061, FailureType::GeneratorVoltageRegulator(2)),
+    (24_062, FailureType::GeneratorVoltageRegulator(3)),
+    (24_063, FailureType::GeneratorVoltageRegulator(4)),
+    (24_050, FailureType::BatteryThermalRunaway(1)),
+    (24_051, FailureType::BatteryThermalRunaway(2)),
+    (24_052, FailureType::BatteryThermalRunaway(3)),
+    (24_053, FailureType::BatteryThermalRunaway(4)),
+    (
+        24_100,
+        FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrent(1)),
+    ),
+    (
+        24_101,
+        FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrent(2)),
+    ),
+    (
+        24_102,
+        FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrent(3)),
+    ),
+    (
+        24_103,
+        FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrent(4)),
+    ),
+    (
+        24_104,
+        FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrentEssential),
+    ),
+    (
+        24_105,
+        FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrentEssentialShed),
+    ),
+    (
+        24_106,
+        FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrentNamed("247XP")),
+    ),
+    (
+        24_107,
+        FailureType::ElectricalBus(ElectricalBusType::AlternatingCurrentGndFltService),
+    ),
+    (
+        24_108,
+        FailureType::ElectricalBus(ElectricalBusType::DirectCurrent(1)),
+    ),
+    (
+        24_109,
+        FailureType::ElectricalBus(ElectricalBusType::DirectCurrent(2)),
+    ),
+    (
+        24_110,
+        FailureType::ElectricalBus(ElectricalBusType::DirectCurrentEssential),
+    ),
+    (
+        24_111,
+        FailureType::ElectricalBus(ElectricalBusType::DirectCurrentNamed("247PP")),
+    ),
+    (
+        24_112,
+        FailureType::ElectricalBus(ElectricalBusType::DirectCurrentNamed("309PP")),
+    ),
+    (
+        24_113,
+        FailureType::ElectricalBus(ElectricalBusType::DirectCurrentHot(1)),
+    ),
+    (
+        24_114,
+        FailureType::ElectricalBus(ElectricalBusType::DirectCurrentHot(2)),
+    ),
+    (
+        24_115,
+        FailureType::ElectricalBus(ElectricalBusType::DirectCurrentHot(3)),
+    ),
+    (
+        24_116,
+        FailureType::ElectricalBus(ElectricalBusType::DirectCurrentHot(4)),
+    ),
+    (
+        24_117,
+        FailureType::ElectricalBus(ElectricalBusType::DirectCurrentGndFltService),
+    ),
+    (26_001, FailureType::SetOnFire(FireDetectionZone::Engine(1))),
+    (26_002, FailureType::SetOnFire(FireDetectionZone::Engine(2))),
+    (26_003, FailureType::SetOnFire(FireDetectionZone::Engine(3))),
+    (26_004, FailureType::SetOnFire(FireDetectionZone::Engine(4))),
+    (26_005, FailureType::SetOnFire(FireDetectionZone::Apu)),
+    (26_006, FailureType::SetOnFire(FireDetectionZone::Mlg)),
+    (
+        26_007,
+        FailureType::FireDetectionLoop(FireDetectionLoopID::A, FireDetectionZone::Engine(1)),
+    ),
+    (
+        26_008,
+        FailureType::FireDetectionLoop(FireDetectionLoopID::B, FireDetectionZone::Engine(1)),
+    ),
+    (
+        26_009,
+        FailureType::FireDetectionLoop(FireDetectionLoopID::A, FireDetectionZone::Engine(2)),
+    ),
+    (
+        26_010,
+        FailureType::FireDetectionLoop(FireDetectionLoopID::B, FireDetectionZone::Engine(2)),
+    ),
+    (
+        26_011,
+        FailureType::FireDetectionLoop(FireDetectionLoopID::A, FireDetectionZone::Engine(3)),
+    ),
+    (
+        26_012,
+        FailureType::FireDetectionLoop(FireDetectionLoopID::B, FireDetectionZone::Engine(3)),
+    ),
+    (
+        26_013,
+        FailureType::FireDetectionLoop(FireDetectionLoopID::A, FireDetectionZone::Engine(4)),
+    ),
+    (
+        26_014,
+        FailureType::FireDetectionLoop(FireDetectionLoopID::B, FireDetectionZone::Engine(4)),
+    ),
+    (
+        26_015,
+        FailureType::FireDetectionLoop(FireDetectionLoopID::A, FireDetectionZone::Apu),
+    ),
+    (
+        26_016,
+        FailureType::FireDetectionLoop(FireDetectionLoopID::B, FireDetectionZone::Apu),
+    ),
+    (
+        26_017,
+        FailureType::FireDetectionLoop(FireDetectionLoopID::A, FireDetectionZone::Mlg),
+    ),
+    (
+        26_018,
+        FailureType::FireDetectionLoop(FireDetectionLoopID::B, FireDetectionZone::Mlg),
+    ),
+    (26_019, FailureType::Smoke(SmokeDetectionZone::FwdCargo)),
+    (26_020, FailureType::Smoke(SmokeDetectionZone::BulkCargo)),
+    (26_021, FailureType::Smoke(SmokeDetectionZone::AvionicsBay)),
+    (26_022, FailureType::Smoke(SmokeDetectionZone::Lavatory(1))),
+    (26_023, FailureType::Smoke(SmokeDetectionZone::Lavatory(2))),
+    (26_024, FailureType::Smoke(SmokeDetectionZone::Lavatory(3))),
+    (26_025, FailureType::Smoke(SmokeDetectionZone::Lavatory(4))),
+    (26_026, FailureType::Smoke(SmokeDetectionZone::Lavatory(5))),
+    (26_027, FailureType::Smoke(SmokeDetectionZone::Lavatory(6))),
+    (26_028, FailureType::Smoke(SmokeDetectionZone::Lavatory(7))),
+    (26_029, FailureType::Smoke(SmokeDetectionZone::Lavatory(8))),
+    (26_030, FailureType::Smoke(SmokeDetectionZone::CrewRest(1))),
+    (26_031, FailureType::Smoke(SmokeDetectionZone::CrewRest(2))),
+    (
+        26_032,
+        FailureType::SmokeDetectionLoop(FireDetectionLoopID::A, SmokeDetectionZone::FwdCargo),
+    ),
+    (
+        26_033,
+        FailureType::SmokeDetectionLoop(FireDetectionLoopID::B, SmokeDetectionZone::FwdCargo),
+    ),
+    (
+        26_034,
+        FailureType::SmokeDetectionLoop(FireDetectionLoopID::A, SmokeDetectionZone::BulkCargo),
+    ),
+    (
+        26_035,
+        FailureType::SmokeDetectionLoop(FireDetectionLoopID::B, SmokeDetectionZone::BulkCargo),
+    ),
+    (
+        26_036,
+        FailureType::SmokeDetectionLoop(FireDetectionLoopID::A, SmokeDetectionZone::AvionicsBay),
+    ),
+    (
+        26_037,
+        FailureType::SmokeDetectionLoop(FireDetectionLoopID::B, SmokeDetectionZone::AvionicsBay),
+    ),
+    (28_001, FailureType::FuelPump(1)),
+    (28_002, FailureType::FuelPump(2)),
+    (28_003, FailureType::FuelPump(3)),
+    (28_004, FailureType::FuelPump(4)),
+    (28_005, FailureType::FuelPump(5)),
+    (28_006, FailureType::FuelPump(6)),
+    (28_007, FailureType::FuelPump(7)),
+    (28_008, FailureType::FuelPump(8)),
+    (28_009, FailureType::FuelPump(9)),
+    (28_010, FailureType::FuelPump(10)),
+    (28_011, FailureType::FuelPump(11)),
+    (28_012, FailureType::FuelPump(12)),
+    (28_013, FailureType::FuelPump(13)),
+    (28_014, FailureType::FuelPump(14)),
+    (28_015, FailureType::FuelPump(15)),
+    (28_016, FailureType::FuelPump(16)),
+    (29_000, FailureType::ReservoirLeak(HydraulicColor::Green)),
+    (29_001, FailureType::ReservoirLeak(HydraulicColor::Yellow)),
+    (29_002, FailureType::ReservoirAirLeak(HydraulicColor::Green)),
+    (
+        29_003,
+        FailureType::ReservoirAirLeak(HydraulicColor::Yellow),
+    ),
+    (
+        29_004,
+        FailureType::ReservoirReturnLeak(HydraulicColor::Green),
+    ),
+    (
+        29_005,
+        FailureType::ReservoirReturnLeak(HydraulicColor::Yellow),
+    ),
+    (
+        29_006,
+        FailureType::ElecPumpOverheat(AirbusElectricPumpId::GreenA),
+    ),
+    (
+        29_007,
+        FailureType::ElecPumpOverheat(AirbusElectricPumpId::GreenB),
+    ),
+    (
+        29_008,
+        FailureType::ElecPumpOverheat(AirbusElectricPumpId::YellowA),
+    ),
+    (
+        29_009,
+        FailureType::ElecPumpOverheat(AirbusElectricPumpId::YellowB),
+    ),
+    (
+        29_010,
+        FailureType::EnginePumpOverheat(AirbusEngineDrivenPumpId::Edp1a),
+    ),
+    (
+        29_011,
+        FailureType::EnginePumpOverheat(AirbusEngineDrivenPumpId::Edp1b),
+    ),
+    (
+        29_012,
+        FailureType::EnginePumpOverheat(AirbusEngineDrivenPumpId::Edp2a),
+    ),
+    (
+        29_013,
+        FailureType::EnginePumpOverheat(AirbusEngineDrivenPumpId::Edp2b),
+    ),
+    (
+        29_014,
+        FailureType::EnginePumpOverheat(AirbusEngineDrivenPumpId::Edp3a),
+    ),
+    (
+        29_015,
+        FailureType::EnginePumpOverheat(AirbusEngineDrivenPumpId::Edp3b),
+    ),
+    (
+        29_016,
+        FailureType::EnginePumpOverheat(AirbusEngineDrivenPumpId::Edp4a),
+    ),
+    (
+        29_017,
+        FailureType::EnginePumpOverheat(AirbusEngineDrivenPumpId::Edp4b),
+    ),
+    (31_010, FailureType::CanBusDisturbance(1)),
+    (31_011, FailureType::CanBusDisturbance(2)),
+    (31_012, FailureType::CanBusDisturbance(3)),
+    (31_013, FailureType::CanBusDisturbance(4)),
+    (31_020, FailureType::CanBusNodeFault(1, 42)),
+    (31_021, FailureType::CanBusNodeFault(1, 43)),
+    (31_022, FailureType::CanBusNodeFault(2, 42)),
+    (31_023, FailureType::CanBusNodeFault(2, 43)),
+    (31_024, FailureType::CanBusNodeFault(3, 42)),
+    (31_025, FailureType::CanBusNodeFault(3, 43)),
+    (31_026, FailureType::CanBusNodeFault(4, 42)),
+    (31_027, FailureType::CanBusNodeFault(4, 43)),
+    (32_000, FailureType::LgciuPowerSupply(LgciuId::Lgciu1)),
+    (32_001, FailureType::LgciuPowerSupply(LgciuId::Lgciu2)),
+    (32_002, FailureType::LgciuInternalError(LgciuId::Lgciu1)),
+    (32_003, FailureType::LgciuInternalError(LgciuId::Lgciu2)),
+    (
+        32_004,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::UplockGearNose1),
+    ),
+    (
+        32_005,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockGearNose2),
+    ),
+    (
+        32_006,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::UplockGearRight1),
+    ),
+    (
+        32_007,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockGearRight2),
+    ),
+    (
+        32_008,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::UplockGearLeft2),
+    ),
+    (
+        32_009,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockGearLeft1),
+    ),
+    (
+        32_010,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::UplockDoorNose1),
+    ),
+    (
+        32_011,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockDoorNose2),
+    ),
+    (
+        32_012,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::UplockDoorRight2),
+    ),
+    (
+        32_013,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockDoorRight1),
+    ),
+    (
+        32_014,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::UplockDoorLeft2),
+    ),
+    (
+        32_015,
+        FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockDoorLeft1),
+    ),
+    (
+        32_020,
+        FailureType::GearActuatorJammed(GearActuatorId::GearNose),
+    ),
+    (
+        32_021,
+        FailureType::GearActuatorJammed(GearActuatorId::GearLeft),
+    ),
+    (
+        32_022,
+        FailureType::GearActuatorJammed(GearActuatorId::GearRight),
+    ),
+    (
+        32_023,
+        FailureType::GearActuatorJammed(GearActuatorId::GearDoorNose),
+    ),
+    (
+        32_024,
+        FailureType::GearActuatorJammed(GearActuatorId::GearDoorLeft),
+    ),
+    (
+        32_025,
+        FailureType::GearActuatorJammed(GearActuatorId::GearDoorRight),
+    ),
+    (32_200, FailureType::TyreBurst(1)),
+    (32_201, FailureType::TyreBurst(2)),
+    (32_202, FailureType::TyreBurst(3)),
+    (32_203, FailureType::TyreBurst(4)),
+    (32_204, FailureType::TyreBurst(5)),
+    (32_205, FailureType::TyreBurst(6)),
+    (32_206, FailureType::TyreBurst(7)),
+    (32_207, FailureType::TyreBurst(8)),
+    (32_208, FailureType::TyreBurst(9)),
+    (32_209, FailureType::TyreBurst(10)),
+    (32_210, FailureType::TyreBurst(11)),
+    (32_211, FailureType::TyreBurst(12)),
+    (32_212, FailureType::TyreBurst(13)),
+    (32_213, FailureType::TyreBurst(14)),
+    (32_214, FailureType::TyreBurst(15)),
+    (32_215, FailureType::TyreBurst(16)),
+    (32_216, FailureType::TyreBurst(17)),
+    (32_217, FailureType::TyreBurst(18)),
+    (32_218, FailureType::TyreBurst(19)),
+    (32_219, FailureType::TyreBurst(20)),
+    (32_220, FailureType::TyreBurst(21)),
+    (32_221, FailureType::TyreBurst(22)),
+    (34_000, FailureType::RadioAltimeter(1)),
+    (34_001, FailureType::RadioAltimeter(2)),
+    (34_002, FailureType::RadioAltimeter(3)),
+    (34_010, FailureType::GpsReceiver(1)),
+    (34_011, FailureType::GpsReceiver(2)),
+    (34_020, FailureType::AdiruBusParityError(1)),
+    (34_021, FailureType::AdiruBusParityError(2)),
+    (34_022, FailureType::AdiruBusParityError(3)),
+    (34_030, FailureType::Adiru(1)),
+    (34_031, FailureType::Adiru(2)),
+    (34_032, FailureType::Adiru(3)),
+    (34_040, FailureType::Adr(1)),
+    (34_041, FailureType::Adr(2)),
+    (34_042, FailureType::Adr(3)),
+    (34_050, FailureType::Ir(1)),
+    (34_051, FailureType::Ir(2)),
+    (34_052, FailureType::Ir(3)),
+    (34_060, FailureType::Egpwc),
+    (36_000, FailureType::BleedValveStuck(1)),
+    (36_001, FailureType::BleedValveStuck(2)),
+    (36_002, FailureType::BleedValveStuck(3)),
+    (36_003, FailureType::BleedValveStuck(4)),
+    (36_010, FailureType::PrecoolerFault(1)),
+    (36_011, FailureType::PrecoolerFault(2)),
+    (36_012, FailureType::PrecoolerFault(3)),
+    (36_013, FailureType::PrecoolerFault(4)),
+    (36_020, FailureType::BleedLeak(1)),
+    (36_021, FailureType::BleedLeak(2)),
+    (36_022, FailureType::BleedLeak(3)),
+    (36_023, FailureType::BleedLeak(4)),
+    (49_000, FailureType::ApuFault),
+];
+
+/// Secondary failures which can follow the activation of a failure, e.g. an engine fire burning
+/// through a fire detection loop or a pump running dry after a reservoir leak.
+pub static FAILURE_DEPENDENCIES: &[FailureDependency] = &[
+    FailureDependency::new(
+        FailureType::SetOnFire(FireDetectionZone::Engine(1)),
+        FailureType::FireDetectionLoop(FireDetectionLoopID::A, FireDetectionZone::Engine(1)),
+        Duration::from_secs(45),
+        0.5,
+    ),
+    FailureDependency::new(
+        FailureType::SetOnFire(FireDetectionZone::Engine(1)),
+        FailureType::FireDetectionLoop(FireDetectionLoopID::B, FireDetectionZone::Engine(1)),
+        Duration::from_secs(60),
+        0.25,
+    ),
+    FailureDependency::new(
+        FailureType::SetOnFire(FireDetectionZone::Engine(1)),
+        FailureType::Generator(1),
+        Duration::from_secs(90),
+        0.3,
+    ),
+    FailureDependency::new(
+        FailureType::SetOnFire(FireDetectionZone::Engine(2)),
+        FailureType::FireDetectionLoop(FireDetectionLoopID::A, FireDetectionZone::Engine(2)),
+        Duration::from_secs(45),
+        0.5,
+    ),
+    FailureDependency::new(
+        FailureType::SetOnFire(FireDetectionZone::Engine(2)),
+        FailureType::FireDetectionLoop(FireDetectionLoopID::B, FireDetectionZone::Engine(2)),
+        Duration::from_secs(60),
+        0.25,
+    ),
+    FailureDependency::new(
+        FailureType::SetOnFire(FireDetectionZone::Engine(2)),
+        FailureType::Generator(2),
+        Duration::from_secs(90),
+        0.3,
+    ),
+    FailureDependency::new(
+        FailureType::SetOnFire(FireDetectionZone::Engine(3)),
+        FailureType::FireDetectionLoop(FireDetectionLoopID::A, FireDetectionZone::Engine(3)),
+        Duration::from_secs(45),
+        0.5,
+    ),
+    FailureDependency::new(
+        FailureType::SetOnFire(FireDetectionZone::Engine(3)),
+        FailureType::FireDetectionLoop(FireDetectionLoopID::B, FireDetectionZone::Engine(3)),
+        Duration::from_secs(60),
+        0.25,
+    ),
+    FailureDependency::new(
+        FailureType::SetOnFire(FireDetectionZone::Engine(3)),
+        FailureType::Generator(3),
+        Duration::from_secs(90),
+        0.3,
+    ),
+    FailureDependency::new(
+        FailureType::SetOnFire(FireDetectionZone::Engine(4)),
+        FailureType::FireDetectionLoop(FireDetectionLoopID::A, FireDetectionZone::Engine(4)),
+        Duration::from_secs(45),
+        0.5,
+    ),
+    FailureDependency::new(
+        FailureType::SetOnFire(FireDetectionZone::Engine(4)),
+        FailureType::FireDetectionLoop(FireDetectionLoopID::B, FireDetectionZone::Engine(4)),
+        Duration::from_secs(60),
+        0.25,
+    ),
+    FailureDependency::new(
+        FailureType::SetOnFire(FireDetectionZone::Engine(4)),
+        FailureType::Generator(4),
+        Duration::from_secs(90),
+        0.3,
+    ),
+    FailureDependency::new(
+        FailureType::SetOnFire(FireDetectionZone::Apu),
+        FailureType::ApuGenerator(1),
+        Duration::from_secs(30),
+        0.5,
+    ),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn failure_dependencies_only_refer_to_known_failures() {
+        let is_known = |failure_type| FAILURES.iter().any(|(_, known)| *known == failure_type);
+
+        assert!(FAILURE_DEPENDENCIES
+            .iter()
+            .all(|dependency| is_known(dependency.cause()) && is_known(dependency.effect())));
+    }
+}
@@ -2,33 +2,45 @@ use std::time::Duration;
 
 use systems::{
     accept_iterable,
-    failures::{Failure, FailureType},
+    air_conditioning::VcmShared,
+    fire_and_smoke_protection::{
+        smoke_detection::SmokeDetectionControlUnit, ExtinguishingAgentBottle, FireDetectionUnit,
+        SetOnFireModule,
+    },
     overhead::{FirePushButton, MomentaryPushButton},
     shared::{
         arinc429::{Arinc429Word, SignStatus},
-        DelayedTrueLogicGate, ElectricalBusType, ElectricalBuses, EngineFirePushButtons,
-        FireDetectionLoopID, FireDetectionZone, LgciuWeightOnWheels,
+        CargoVentilationIsolation, DelayedTrueLogicGate, ElectricalBusType, ElectricalBuses,
+        EngineFirePushButtons, FireDetectionLoopID, FireDetectionZone, LgciuWeightOnWheels,
+        SmokeDetectionZone,
     },
     simulation::{
         InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
         SimulatorWriter, UpdateContext, VariableIdentifier, Write,
     },
 };
-
-use std::iter::zip;
+use uom::si::{
+    f64::*,
+    ratio::{percent, ratio},
+    volume::cubic_meter,
+    volume_rate::cubic_meter_per_second,
+};
 
 pub(super) struct A380FireAndSmokeProtection {
     a380_fire_protection_system: FireProtectionSystem,
-    // a380_smoke_detection_function
-    set_zone_on_fire: SetOnFireModule,
+    a380_smoke_detection_function: SmokeDetectionFunction,
+    set_zone_on_fire: SetOnFireModule<6>,
 }
 
 impl A380FireAndSmokeProtection {
     pub(super) fn new(context: &mut InitContext) -> Self {
         Self {
             a380_fire_protection_system: FireProtectionSystem::new(context),
-
-            set_zone_on_fire: SetOnFireModule::new(context),
+            a380_smoke_detection_function: SmokeDetectionFunction::new(context),
+            set_zone_on_fire: SetOnFireModule::new(
+                context,
+                FireProtectionSystem::FIRE_DETECTION_ZONES,
+            ),
         }
     }
 
@@ -37,12 +49,14 @@ impl A380FireAndSmokeProtection {
         context: &UpdateContext,
         engine_fire_push_buttons: &impl EngineFirePushButtons,
         lgciu: [&impl LgciuWeightOnWheels; 2],
+        vcm: &impl VcmShared,
     ) {
         self.a380_fire_protection_system
             .update(context, engine_fire_push_buttons, lgciu);
+        self.a380_smoke_detection_function.update(context, vcm);
 
         self.set_zone_on_fire
-            .update(self.a380_fire_protection_system.bottle_discharge());
+            .update(self.a380_fire_protection_system.bottles_discharged());
     }
 
     pub fn apu_fire_on_ground(&self) -> bool {
@@ -50,9 +64,21 @@ impl A380FireAndSmokeProtection {
     }
 }
 
+impl CargoVentilationIsolation for A380FireAndSmokeProtection {
+    fn fwd_cargo_isolation_requested(&self) -> bool {
+        self.a380_smoke_detection_function
+            .cargo_isolation_requested(SmokeDetectionZone::FwdCargo)
+    }
+    fn bulk_cargo_isolation_requested(&self) -> bool {
+        self.a380_smoke_detection_function
+            .cargo_isolation_requested(SmokeDetectionZone::BulkCargo)
+    }
+}
+
 impl SimulationElement for A380FireAndSmokeProtection {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.a380_fire_protection_system.accept(visitor);
+        self.a380_smoke_detection_function.accept(visitor);
         self.set_zone_on_fire.accept(visitor);
 
         visitor.visit(self);
@@ -60,26 +86,52 @@ impl SimulationElement for A380FireAndSmokeProtection {
 }
 
 struct FireProtectionSystem {
-    fire_detection_unit: FireDetectionUnit,
+    fire_detection_unit: FireDetectionUnit<6>,
     fire_extinguishing_system: FireExtinguishingSystem,
 
     fire_test_pushbutton_id: VariableIdentifier,
     fire_test_pushbutton_is_pressed: bool,
     fire_test_pushbutton_signal: DelayedTrueLogicGate,
+
+    // The FDU sends discrete signals to the overhead panel and arinc signals to the FWS
+    // Fixme: We assume a discrete word is sent, validate with references
+    discrete_word_id: VariableIdentifier,
+    discrete_word: Arinc429Word<u32>,
 }
 
 impl FireProtectionSystem {
     const DELAY_FIRE_TEST_MILLIS: Duration = Duration::from_millis(500);
+    const DELAY_APU_FIRE_EXTINGUISHING: Duration = Duration::from_secs(10);
+
+    const FIRE_DETECTION_ZONES: [FireDetectionZone; 6] = [
+        FireDetectionZone::Engine(1),
+        FireDetectionZone::Engine(2),
+        FireDetectionZone::Engine(3),
+        FireDetectionZone::Engine(4),
+        FireDetectionZone::Apu,
+        FireDetectionZone::Mlg,
+    ];
 
     fn new(context: &mut InitContext) -> Self {
         Self {
-            fire_detection_unit: FireDetectionUnit::new(context),
+            fire_detection_unit: FireDetectionUnit::new(
+                context,
+                Self::FIRE_DETECTION_ZONES,
+                [
+                    ElectricalBusType::DirectCurrentEssential,
+                    ElectricalBusType::DirectCurrent(2),
+                ],
+                Self::DELAY_APU_FIRE_EXTINGUISHING,
+            ),
             fire_extinguishing_system: FireExtinguishingSystem::new(context),
 
             fire_test_pushbutton_id: context
                 .get_identifier("OVHD_FIRE_TEST_PB_IS_PRESSED".to_owned()),
             fire_test_pushbutton_is_pressed: false,
             fire_test_pushbutton_signal: DelayedTrueLogicGate::new(Self::DELAY_FIRE_TEST_MILLIS),
+
+            discrete_word_id: context.get_identifier("FIRE_FDU_DISCRETE_WORD".to_owned()),
+            discrete_word: Arinc429Word::new(0, SignStatus::NoComputedData),
         }
     }
 
@@ -92,196 +144,38 @@ impl FireProtectionSystem {
         // We add a delay between button press and response based on references
         self.fire_test_pushbutton_signal
             .update(context, self.fire_test_pushbutton_is_pressed);
-        self.fire_detection_unit
-            .update(context, self.fire_test_pushbutton_signal.output(), lgciu);
+        self.fire_detection_unit.update(
+            context,
+            [self.fire_test_pushbutton_signal.output(); 6],
+            lgciu,
+        );
         self.fire_extinguishing_system.update(
             context,
             engine_fire_push_buttons,
             self.fire_test_pushbutton_signal.output(),
             self.fire_detection_unit.should_extinguish_apu_fire(),
-        )
-    }
-
-    fn apu_fire_on_ground(&self) -> bool {
-        self.fire_detection_unit.apu_fire_on_ground()
-    }
-
-    fn bottle_discharge(&self) -> [bool; 9] {
-        self.fire_extinguishing_system.bottle_discharge()
-    }
-}
-
-impl SimulationElement for FireProtectionSystem {
-    fn read(&mut self, reader: &mut SimulatorReader) {
-        self.fire_test_pushbutton_is_pressed = reader.read(&self.fire_test_pushbutton_id);
-    }
-
-    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
-        self.fire_detection_unit.accept(visitor);
-        self.fire_extinguishing_system.accept(visitor);
-
-        visitor.visit(self);
-    }
-}
-
-struct FireDetectionUnit {
-    fire_detection_loop: [FireDetectionLoop; 2],
-
-    fire_detected_id: [VariableIdentifier; 6],
-
-    fire_detected: [bool; 6],
-    fire_detection_zones: [FireDetectionZone; 6],
-    interval_between_loop_failures: [Duration; 6],
-    apu_fire_on_ground: bool,
-    should_extinguish_apu_fire: DelayedTrueLogicGate,
-
-    // The FDU sends discrete signals to the overhead panel and arinc signals to the FWS
-    // Fixme: We assume a discrete word is sent, validate with references
-    discrete_word_id: VariableIdentifier,
-    discrete_word: Arinc429Word<u32>,
-}
-
-impl FireDetectionUnit {
-    const DELAY_APU_FIRE_EXTINGUISHING: Duration = Duration::from_secs(10);
-
-    fn new(context: &mut InitContext) -> Self {
-        let fire_detection_zones = [
-            FireDetectionZone::Engine(1),
-            FireDetectionZone::Engine(2),
-            FireDetectionZone::Engine(3),
-            FireDetectionZone::Engine(4),
-            FireDetectionZone::Apu,
-            FireDetectionZone::Mlg,
-        ];
-
-        Self {
-            fire_detection_loop: [
-                FireDetectionLoop::new(
-                    context,
-                    FireDetectionLoopID::A,
-                    &fire_detection_zones,
-                    ElectricalBusType::DirectCurrentEssential,
-                ),
-                FireDetectionLoop::new(
-                    context,
-                    FireDetectionLoopID::B,
-                    &fire_detection_zones,
-                    ElectricalBusType::DirectCurrent(2),
-                ),
-            ],
-
-            fire_detected_id: fire_detection_zones.map(|zone| Self::init_identifier(context, zone)),
-
-            fire_detected: [false; 6],
-            fire_detection_zones,
-            interval_between_loop_failures: [Duration::ZERO; 6],
-            apu_fire_on_ground: false,
-            should_extinguish_apu_fire: DelayedTrueLogicGate::new(
-                Self::DELAY_APU_FIRE_EXTINGUISHING,
-            ),
-
-            discrete_word_id: context.get_identifier("FIRE_FDU_DISCRETE_WORD".to_owned()),
-            discrete_word: Arinc429Word::new(0, SignStatus::NoComputedData),
-        }
-    }
-
-    fn init_identifier(
-        context: &mut InitContext,
-        zone_id: FireDetectionZone,
-    ) -> VariableIdentifier {
-        if matches!(zone_id, FireDetectionZone::Engine(_)) {
-            context.get_identifier(format!("FIRE_DETECTED_ENG{}", zone_id))
-        } else {
-            context.get_identifier(format!("FIRE_DETECTED_{}", zone_id))
-        }
-    }
-
-    fn update(
-        &mut self,
-        context: &UpdateContext,
-        fire_test_pushbutton_is_pressed: bool,
-        lgciu: [&impl LgciuWeightOnWheels; 2],
-    ) {
-        self.interval_between_loop_failures = self.calculate_interval_between_failures(context);
-
-        self.fire_detected = self.fire_detection_determination(fire_test_pushbutton_is_pressed);
-
-        self.fire_detection_loop
-            .iter_mut()
-            .for_each(|l| l.update_was_powered());
-
-        // If a fire is detected in the APU while the aircraft is on the ground, the extinguishim system is automatically activated after a delay
-        self.apu_fire_on_ground = self.fire_detected[4]
-            && !fire_test_pushbutton_is_pressed
-            && lgciu.iter().all(|a| a.left_and_right_gear_compressed(true));
-        self.should_extinguish_apu_fire
-            .update(context, self.apu_fire_on_ground);
+        );
 
         self.update_discrete_word();
     }
 
-    fn fire_detection_determination(&self, fire_test_pb: bool) -> [bool; 6] {
-        let mut fire_detected = [false; 6];
-        for ((&zone, &interval_between_loop_failures), fire_detected) in self
-            .fire_detection_zones
-            .iter()
-            .zip(&self.interval_between_loop_failures)
-            .zip(&mut fire_detected)
-        {
-            *fire_detected = (self.fire_detection_loop[0]
-                .fire_detected_in_loop(zone, fire_test_pb)
-                && self.fire_detection_loop[1].fire_detected_in_loop(zone, fire_test_pb))
-                || (self
-                    .fire_detection_loop
-                    .iter()
-                    .any(|l| l.fire_detected_in_loop(zone, fire_test_pb))
-                    && self
-                        .fire_detection_loop
-                        .iter()
-                        .any(|l| l.loop_has_failed(zone)))
-                || (self
-                    .fire_detection_loop
-                    .iter()
-                    .all(|l| l.loop_has_failed(zone))
-                    && interval_between_loop_failures < Duration::from_secs(5)
-                    && zone != FireDetectionZone::Mlg);
-        }
-        fire_detected
-    }
-
-    fn calculate_interval_between_failures(&self, context: &UpdateContext) -> [Duration; 6] {
-        let mut interval = [Duration::ZERO; 6];
-        for ((&zone, &interval_between_loop_failures), interval) in self
-            .fire_detection_zones
-            .iter()
-            .zip(&self.interval_between_loop_failures)
-            .zip(&mut interval)
-        {
-            *interval = if self
-                .fire_detection_loop
-                .iter()
-                .all(|l| !l.loop_has_failed(zone))
-            {
-                Duration::ZERO
-            } else if self
-                .fire_detection_loop
-                .iter()
-                .all(|l| l.loop_has_failed(zone))
-            {
-                interval_between_loop_failures
-            } else {
-                interval_between_loop_failures + context.delta()
-            }
-        }
-        interval
-    }
-
-    fn should_extinguish_apu_fire(&self) -> bool {
-        self.should_extinguish_apu_fire.output()
+    fn apu_fire_on_ground(&self) -> bool {
+        self.fire_detection_unit.apu_fire_on_ground()
     }
 
-    fn apu_fire_on_ground(&self) -> bool {
-        self.apu_fire_on_ground
+    /// The number of discharged bottles per fire zone
+    fn bottles_discharged(&self) -> [usize; 6] {
+        let bottle_discharge = self.fire_extinguishing_system.bottle_discharge();
+        let discharged = |bottles: &[bool]| bottles.iter().filter(|&&d| d).count();
+        [
+            discharged(&bottle_discharge[..2]),
+            discharged(&bottle_discharge[2..4]),
+            discharged(&bottle_discharge[4..6]),
+            discharged(&bottle_discharge[6..8]),
+            discharged(&bottle_discharge[8..]),
+            // MLG does not have a fire extinguishing system
+            0,
+        ]
     }
 
     fn update_discrete_word(&mut self) {
@@ -289,207 +183,41 @@ impl FireDetectionUnit {
         self.discrete_word = Arinc429Word::new(0, SignStatus::NormalOperation);
 
         // Fixme: The bit order is assumed as no references
-        self.discrete_word.set_bit(11, self.fire_detected[0]); // FIRE ENG 1
-        self.discrete_word.set_bit(12, self.fire_detected[1]); // FIRE ENG 2
-        self.discrete_word.set_bit(13, self.fire_detected[2]); // FIRE ENG 3
-        self.discrete_word.set_bit(14, self.fire_detected[3]); // FIRE ENG 4
-        self.discrete_word.set_bit(15, self.fire_detected[4]); // FIRE APU
-        self.discrete_word.set_bit(16, self.fire_detected[5]); // FIRE MLG
-        self.discrete_word.set_bit(
-            18,
-            self.fire_detection_loop[0].loop_has_failed(FireDetectionZone::Engine(1)),
-        ); // ENG 1 LOOP A has failed
-        self.discrete_word.set_bit(
-            19,
-            self.fire_detection_loop[1].loop_has_failed(FireDetectionZone::Engine(1)),
-        ); // ENG 1 LOOP B has failed
-        self.discrete_word.set_bit(
-            20,
-            self.fire_detection_loop[0].loop_has_failed(FireDetectionZone::Engine(2)),
-        ); // ENG 2 LOOP A has failed
-        self.discrete_word.set_bit(
-            21,
-            self.fire_detection_loop[1].loop_has_failed(FireDetectionZone::Engine(2)),
-        ); // ENG 2 LOOP B has failed
-        self.discrete_word.set_bit(
-            22,
-            self.fire_detection_loop[0].loop_has_failed(FireDetectionZone::Engine(3)),
-        ); // ENG 3 LOOP A has failed
-        self.discrete_word.set_bit(
-            23,
-            self.fire_detection_loop[1].loop_has_failed(FireDetectionZone::Engine(3)),
-        ); // ENG 3 LOOP B has failed
-        self.discrete_word.set_bit(
-            24,
-            self.fire_detection_loop[0].loop_has_failed(FireDetectionZone::Engine(4)),
-        ); // ENG 4 LOOP A has failed
-        self.discrete_word.set_bit(
-            25,
-            self.fire_detection_loop[1].loop_has_failed(FireDetectionZone::Engine(4)),
-        ); // ENG 4 LOOP B has failed
-        self.discrete_word.set_bit(
-            26,
-            self.fire_detection_loop[0].loop_has_failed(FireDetectionZone::Apu),
-        ); // APU LOOP A has failed
-        self.discrete_word.set_bit(
-            27,
-            self.fire_detection_loop[1].loop_has_failed(FireDetectionZone::Apu),
-        ); // APU LOOP B has failed
-        self.discrete_word.set_bit(
-            28,
-            self.fire_detection_loop[0].loop_has_failed(FireDetectionZone::Mlg),
-        ); // MLG LOOP A has failed
-        self.discrete_word.set_bit(
-            29,
-            self.fire_detection_loop[1].loop_has_failed(FireDetectionZone::Mlg),
-        ); // MLG LOOP B has failed
-    }
-}
-
-impl SimulationElement for FireDetectionUnit {
-    fn write(&self, writer: &mut SimulatorWriter) {
-        for (id, fire_detected) in self.fire_detected_id.iter().zip(self.fire_detected) {
-            writer.write(id, fire_detected);
+        // Bits 11 to 16: FIRE ENG 1-4, FIRE APU, FIRE MLG
+        for (bit, &zone) in (11..).zip(&Self::FIRE_DETECTION_ZONES) {
+            self.discrete_word
+                .set_bit(bit, self.fire_detection_unit.fire_detected(zone));
+        }
+        // Bits 18 to 29: LOOP A and LOOP B has failed, for ENG 1-4, APU and MLG
+        for (bit, &zone) in (18..).step_by(2).zip(&Self::FIRE_DETECTION_ZONES) {
+            self.discrete_word.set_bit(
+                bit,
+                self.fire_detection_unit
+                    .loop_has_failed(FireDetectionLoopID::A, zone),
+            );
+            self.discrete_word.set_bit(
+                bit + 1,
+                self.fire_detection_unit
+                    .loop_has_failed(FireDetectionLoopID::B, zone),
+            );
         }
-        writer.write(&self.discrete_word_id, self.discrete_word);
-    }
-
-    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
-        accept_iterable!(self.fire_detection_loop, visitor);
-
-        visitor.visit(self);
     }
 }
 
-struct FireDetectionLoop {
-    loop_id: FireDetectionLoopID,
-    powered_by: ElectricalBusType,
-    is_powered: bool,
-    was_powered_before: bool,
-    failures: [Failure; 6],
-
-    fire_detectors: [FireDetector; 6],
-}
-
-impl FireDetectionLoop {
-    fn new(
-        context: &mut InitContext,
-        loop_id: FireDetectionLoopID,
-        fire_detection_zones: &[FireDetectionZone; 6],
-        powered_by: ElectricalBusType,
-    ) -> Self {
-        Self {
-            loop_id,
-            powered_by,
-            is_powered: false,
-            was_powered_before: false,
-            failures: fire_detection_zones
-                .map(|zone| Failure::new(FailureType::FireDetectionLoop(loop_id, zone))),
-
-            fire_detectors: fire_detection_zones.map(|zone| FireDetector::new(context, zone)),
-        }
-    }
-
-    fn fire_detected_in_loop(
-        &self,
-        fire_detection_zone: FireDetectionZone,
-        fire_test_pushbutton_is_pressed: bool,
-    ) -> bool {
-        let failure = self
-            .failures
-            .iter()
-            .find(|&f| {
-                f.failure_type()
-                    == FailureType::FireDetectionLoop(self.loop_id, fire_detection_zone)
-            })
-            .unwrap();
-
-        !failure.is_active()
-            && self.is_powered
-            && (self
-                .fire_detectors
-                .iter()
-                .find(|detector| fire_detection_zone == detector.zone_id())
-                .unwrap()
-                .fire_detected()
-                || fire_test_pushbutton_is_pressed)
-    }
-
-    fn loop_has_failed(&self, fire_detection_zone: FireDetectionZone) -> bool {
-        let failure = self
-            .failures
-            .iter()
-            .find(|&f| {
-                f.failure_type()
-                    == FailureType::FireDetectionLoop(self.loop_id, fire_detection_zone)
-            })
-            .unwrap();
-
-        failure.is_active() || (!self.is_powered && self.was_powered_before)
+impl SimulationElement for FireProtectionSystem {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.fire_test_pushbutton_is_pressed = reader.read(&self.fire_test_pushbutton_id);
     }
 
-    /// This is to avoid a fire detection on initial load
-    fn update_was_powered(&mut self) {
-        self.was_powered_before = self.is_powered
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.discrete_word_id, self.discrete_word);
     }
-}
 
-impl SimulationElement for FireDetectionLoop {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
-        accept_iterable!(self.failures, visitor);
-        accept_iterable!(self.fire_detectors, visitor);
-        visitor.visit(self);
-    }
-
-    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
-        self.is_powered = buses.is_powered(self.powered_by);
-    }
-}
-
-// Electro-pneumatic fire detectors. There are multiple detectors in 3 fire zones per engine, one per pylon, one in the APU and one in the MLG
-// For simplicity here we simulate just one detection zone per engine, when we have deep engine simulation we can modify this accordingly
-struct FireDetector {
-    zone_id: FireDetectionZone,
-
-    fire_detection_id: VariableIdentifier,
-    fire_detected: bool,
-}
-
-impl FireDetector {
-    const ENGINE_ON_FIRE: &'static str = "ENG ON FIRE:";
-
-    fn new(context: &mut InitContext, fire_zone_id: FireDetectionZone) -> Self {
-        Self {
-            zone_id: fire_zone_id,
-
-            fire_detection_id: Self::init_identifier(context, fire_zone_id),
-            fire_detected: false,
-        }
-    }
-
-    fn init_identifier(
-        context: &mut InitContext,
-        zone_id: FireDetectionZone,
-    ) -> VariableIdentifier {
-        if matches!(zone_id, FireDetectionZone::Engine(_)) {
-            context.get_identifier(format!("{}{}", Self::ENGINE_ON_FIRE, zone_id))
-        } else {
-            context.get_identifier(format!("{}_ON_FIRE", zone_id))
-        }
-    }
-
-    fn zone_id(&self) -> FireDetectionZone {
-        self.zone_id
-    }
-
-    fn fire_detected(&self) -> bool {
-        self.fire_detected
-    }
-}
+        self.fire_detection_unit.accept(visitor);
+        self.fire_extinguishing_system.accept(visitor);
 
-impl SimulationElement for FireDetector {
-    fn read(&mut self, reader: &mut SimulatorReader) {
-        self.fire_detected = reader.read(&self.fire_detection_id);
+        visitor.visit(self);
     }
 }
 
@@ -599,88 +327,320 @@ impl SimulationElement for FireExtinguishingSystem {
     }
 }
 
-/// This struct represents the physical bottle of Halon 1301, and its possible states of armed, disarmed, full and empty
-/// There are two squibs per bottle, but for simplicity we simulate a "single" squib with two possible power sources
-struct ExtinguishingAgentBottle {
-    squib_armed_id: VariableIdentifier,
+struct SmokeDetectionFunction {
+    smoke_detection_control_unit: SmokeDetectionControlUnit<13>,
+    cargo_fire_extinguishing_system: CargoFireExtinguishingSystem,
+}
+
+impl SmokeDetectionFunction {
+    // Smoke detectors in the cargo compartments, the avionics bay, the lavatories and the crew rest areas
+    const SMOKE_DETECTION_ZONES: [SmokeDetectionZone; 13] = [
+        SmokeDetectionZone::FwdCargo,
+        SmokeDetectionZone::BulkCargo,
+        SmokeDetectionZone::AvionicsBay,
+        SmokeDetectionZone::Lavatory(1),
+        SmokeDetectionZone::Lavatory(2),
+        SmokeDetectionZone::Lavatory(3),
+        SmokeDetectionZone::Lavatory(4),
+        SmokeDetectionZone::Lavatory(5),
+        SmokeDetectionZone::Lavatory(6),
+        SmokeDetectionZone::Lavatory(7),
+        SmokeDetectionZone::Lavatory(8),
+        SmokeDetectionZone::CrewRest(1),
+        SmokeDetectionZone::CrewRest(2),
+    ];
+
+    fn new(context: &mut InitContext) -> Self {
+        Self {
+            smoke_detection_control_unit: SmokeDetectionControlUnit::new(
+                context,
+                Self::SMOKE_DETECTION_ZONES,
+                [
+                    ElectricalBusType::DirectCurrentEssential,
+                    ElectricalBusType::DirectCurrent(2),
+                ],
+            ),
+            cargo_fire_extinguishing_system: CargoFireExtinguishingSystem::new(context),
+        }
+    }
+
+    fn update(&mut self, context: &UpdateContext, vcm: &impl VcmShared) {
+        self.cargo_fire_extinguishing_system.update(context, vcm);
+        self.smoke_detection_control_unit.update(
+            context,
+            Self::SMOKE_DETECTION_ZONES.map(|zone| {
+                self.cargo_fire_extinguishing_system
+                    .fire_is_suppressed(zone)
+            }),
+        );
+    }
+
+    /// The ventilation of a cargo compartment is isolated as soon as smoke is detected in it,
+    /// and stays isolated once extinguishing agent has been discharged into it
+    fn cargo_isolation_requested(&self, zone: SmokeDetectionZone) -> bool {
+        self.smoke_detection_control_unit.smoke_detected(zone)
+            || self
+                .cargo_fire_extinguishing_system
+                .agent_discharged_into(zone)
+    }
+}
+
+impl SimulationElement for SmokeDetectionFunction {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.smoke_detection_control_unit.accept(visitor);
+        self.cargo_fire_extinguishing_system.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
+/// The cargo fire extinguishing system is shared by the forward and bulk compartments.
+/// Pressing a DISCH pushbutton routes the agent to the selected compartment: the two high rate discharge (HRD)
+/// bottles are discharged first to reach the extinguishing concentration, then the three low rate discharge (LRD)
+/// bottles are metered one after the other to keep the concentration above the suppression threshold.
+struct CargoFireExtinguishingSystem {
+    high_rate_discharge_bottles: [CargoExtinguishingAgentBottle; 2],
+    low_rate_discharge_bottles: [CargoExtinguishingAgentBottle; 3],
+    cargo_compartments: [CargoCompartment; 2],
+    discharge_push_buttons: [MomentaryPushButton; 2],
+
+    selected_compartment: Option<usize>,
+    high_rate_discharge_signal: DelayedTrueLogicGate,
+    low_rate_discharge_signal: DelayedTrueLogicGate,
+}
+
+impl CargoFireExtinguishingSystem {
+    const SQUIB_FIRING_DELAY: Duration = Duration::from_secs(1);
+    // Fixme: The delay between HRD and LRD is assumed as no references
+    const LOW_RATE_DISCHARGE_DELAY: Duration = Duration::from_secs(60);
+
+    const CARGO_COMPARTMENTS: [SmokeDetectionZone; 2] =
+        [SmokeDetectionZone::FwdCargo, SmokeDetectionZone::BulkCargo];
+
+    fn new(context: &mut InitContext) -> Self {
+        let powered_by = [
+            ElectricalBusType::DirectCurrentHot(1),
+            ElectricalBusType::DirectCurrentEssential,
+        ];
+        Self {
+            high_rate_discharge_bottles: [1, 2].map(|id| {
+                CargoExtinguishingAgentBottle::new_high_rate(
+                    context,
+                    &format!("HRD_{}", id),
+                    powered_by,
+                )
+            }),
+            low_rate_discharge_bottles: [1, 2, 3].map(|id| {
+                CargoExtinguishingAgentBottle::new_low_rate(
+                    context,
+                    &format!("LRD_{}", id),
+                    powered_by,
+                )
+            }),
+            cargo_compartments: [
+                CargoCompartment::new(context, "FWD", Volume::new::<cubic_meter>(131.)),
+                CargoCompartment::new(context, "BULK", Volume::new::<cubic_meter>(17.3)),
+            ],
+            discharge_push_buttons: [
+                MomentaryPushButton::new(context, "CARGO_SMOKE_FWD_DISCH"),
+                MomentaryPushButton::new(context, "CARGO_SMOKE_BULK_DISCH"),
+            ],
+
+            selected_compartment: None,
+            high_rate_discharge_signal: DelayedTrueLogicGate::new(Self::SQUIB_FIRING_DELAY),
+            low_rate_discharge_signal: DelayedTrueLogicGate::new(Self::LOW_RATE_DISCHARGE_DELAY),
+        }
+    }
+
+    fn update(&mut self, context: &UpdateContext, vcm: &impl VcmShared) {
+        // Fixme: We assume the agent can only be routed to one compartment per flight
+        if self.selected_compartment.is_none() {
+            self.selected_compartment = self
+                .discharge_push_buttons
+                .iter()
+                .position(|pb| pb.is_pressed());
+        }
+
+        self.high_rate_discharge_signal
+            .update(context, self.selected_compartment.is_some());
+        if self.high_rate_discharge_signal.output() {
+            self.high_rate_discharge_bottles
+                .iter_mut()
+                .for_each(|bottle| bottle.discharge());
+        }
+
+        self.low_rate_discharge_signal.update(
+            context,
+            self.high_rate_discharge_bottles
+                .iter()
+                .all(|bottle| bottle.is_discharged()),
+        );
+        if self.low_rate_discharge_signal.output() {
+            if let Some(bottle) = self
+                .low_rate_discharge_bottles
+                .iter_mut()
+                .find(|bottle| !bottle.is_empty())
+            {
+                bottle.discharge();
+            }
+        }
+
+        let agent_released: Volume = self
+            .high_rate_discharge_bottles
+            .iter_mut()
+            .chain(self.low_rate_discharge_bottles.iter_mut())
+            .fold(Volume::default(), |released, bottle| {
+                released + bottle.update(context)
+            });
+
+        let isolation_valves_open = [
+            vcm.fwd_isolation_valves_open_allowed(),
+            vcm.bulk_isolation_valves_open_allowed(),
+        ];
+        for (id, (compartment, valves_open)) in self
+            .cargo_compartments
+            .iter_mut()
+            .zip(isolation_valves_open)
+            .enumerate()
+        {
+            compartment.update(
+                context,
+                if self.selected_compartment == Some(id) {
+                    agent_released
+                } else {
+                    Volume::default()
+                },
+                valves_open,
+            );
+        }
+    }
+
+    fn compartment_index(zone: SmokeDetectionZone) -> Option<usize> {
+        Self::CARGO_COMPARTMENTS.iter().position(|&z| z == zone)
+    }
+
+    fn agent_discharged_into(&self, zone: SmokeDetectionZone) -> bool {
+        self.selected_compartment.is_some()
+            && self.selected_compartment == Self::compartment_index(zone)
+    }
+
+    fn fire_is_suppressed(&self, zone: SmokeDetectionZone) -> bool {
+        Self::compartment_index(zone)
+            .map_or(false, |id| self.cargo_compartments[id].fire_is_suppressed())
+    }
+}
+
+impl SimulationElement for CargoFireExtinguishingSystem {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.high_rate_discharge_bottles, visitor);
+        accept_iterable!(self.low_rate_discharge_bottles, visitor);
+        accept_iterable!(self.cargo_compartments, visitor);
+        accept_iterable!(self.discharge_push_buttons, visitor);
+
+        visitor.visit(self);
+    }
+}
+
+/// Agent quantities are expressed as the volume of gaseous Halon 1301 once released into the compartment
+struct CargoExtinguishingAgentBottle {
     bottle_discharged_id: VariableIdentifier,
-    agent_pb: MomentaryPushButton,
 
-    squib_is_armed: bool,
+    agent_quantity: Volume,
+    flow_rate: VolumeRate,
     bottle_is_discharged: bool,
-    system_test: bool,
 
-    timer: Duration,
     powered_by: [ElectricalBusType; 2],
     is_powered: bool,
 }
 
-impl ExtinguishingAgentBottle {
-    fn new(context: &mut InitContext, id: &str, powered_by: [ElectricalBusType; 2]) -> Self {
+impl CargoExtinguishingAgentBottle {
+    // Fixme: Quantities and flow rates are assumed as no references
+    const HIGH_RATE_AGENT_QUANTITY_CUBIC_METER: f64 = 5.;
+    const HIGH_RATE_FLOW_RATE_CUBIC_METER_PER_SECOND: f64 = 5.;
+    const LOW_RATE_AGENT_QUANTITY_CUBIC_METER: f64 = 1.44;
+    // Each LRD bottle meters its agent for 80 minutes
+    const LOW_RATE_FLOW_RATE_CUBIC_METER_PER_SECOND: f64 = 0.0003;
+
+    fn new_high_rate(
+        context: &mut InitContext,
+        id: &str,
+        powered_by: [ElectricalBusType; 2],
+    ) -> Self {
+        Self::new(
+            context,
+            id,
+            Volume::new::<cubic_meter>(Self::HIGH_RATE_AGENT_QUANTITY_CUBIC_METER),
+            VolumeRate::new::<cubic_meter_per_second>(
+                Self::HIGH_RATE_FLOW_RATE_CUBIC_METER_PER_SECOND,
+            ),
+            powered_by,
+        )
+    }
+
+    fn new_low_rate(
+        context: &mut InitContext,
+        id: &str,
+        powered_by: [ElectricalBusType; 2],
+    ) -> Self {
+        Self::new(
+            context,
+            id,
+            Volume::new::<cubic_meter>(Self::LOW_RATE_AGENT_QUANTITY_CUBIC_METER),
+            VolumeRate::new::<cubic_meter_per_second>(
+                Self::LOW_RATE_FLOW_RATE_CUBIC_METER_PER_SECOND,
+            ),
+            powered_by,
+        )
+    }
+
+    fn new(
+        context: &mut InitContext,
+        id: &str,
+        agent_quantity: Volume,
+        flow_rate: VolumeRate,
+        powered_by: [ElectricalBusType; 2],
+    ) -> Self {
         Self {
-            squib_armed_id: context.get_identifier(format!("FIRE_SQUIB_{}_IS_ARMED", id)),
             bottle_discharged_id: context
-                .get_identifier(format!("FIRE_SQUIB_{}_IS_DISCHARGED", id)),
-            agent_pb: MomentaryPushButton::new(context, &format!("FIRE_AGENT_{}", id)),
+                .get_identifier(format!("CARGO_FIRE_BOTTLE_{}_IS_DISCHARGED", id)),
 
-            squib_is_armed: false,
+            agent_quantity,
+            flow_rate,
             bottle_is_discharged: false,
-            system_test: false,
 
-            timer: Duration::ZERO,
             powered_by,
             is_powered: false,
         }
     }
 
-    fn update(
-        &mut self,
-        context: &UpdateContext,
-        engine_fire_push_button_is_pressed: bool,
-        fire_test_pushbutton_is_pressed: bool,
-        should_extinguish_fire: Option<bool>,
-    ) {
-        self.system_test = fire_test_pushbutton_is_pressed && self.is_powered;
-        self.squib_is_armed = self.is_powered && engine_fire_push_button_is_pressed;
-        if self.is_powered
-            && ((self.squib_is_armed || should_extinguish_fire.unwrap_or(false))
-                && self.timer >= Duration::from_secs(1))
-        {
-            // Once the bottle is discharged, it can't be recharged
-            self.bottle_is_discharged = true
-        } else if self.is_powered
-            && (((self.squib_is_armed)
-                && (self.agent_pb.is_pressed() || self.timer > Duration::ZERO))
-                || should_extinguish_fire.unwrap_or(false))
-            && self.timer <= Duration::from_secs(1)
-        {
-            self.timer += context.delta()
-        } else {
-            self.timer = Duration::ZERO
-        };
+    /// The squib can only be fired when powered. Once the bottle is discharged, it can't be recharged
+    fn discharge(&mut self) {
+        self.bottle_is_discharged |= self.is_powered;
     }
 
-    fn bottle_discharge(&self) -> bool {
-        self.bottle_is_discharged
+    /// Returns the quantity of agent released during this update
+    fn update(&mut self, context: &UpdateContext) -> Volume {
+        if !self.bottle_is_discharged {
+            return Volume::default();
+        }
+
+        let released = (self.flow_rate * context.delta_as_time()).min(self.agent_quantity);
+        self.agent_quantity -= released;
+        released
     }
-}
 
-impl SimulationElement for ExtinguishingAgentBottle {
-    fn write(&self, writer: &mut SimulatorWriter) {
-        writer.write(
-            &self.squib_armed_id,
-            self.squib_is_armed || self.system_test,
-        );
-        writer.write(
-            &self.bottle_discharged_id,
-            self.bottle_is_discharged || self.system_test,
-        );
+    fn is_discharged(&self) -> bool {
+        self.bottle_is_discharged
     }
 
-    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
-        self.agent_pb.accept(visitor);
+    fn is_empty(&self) -> bool {
+        self.agent_quantity <= Volume::default()
+    }
+}
 
-        visitor.visit(self);
+impl SimulationElement for CargoExtinguishingAgentBottle {
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.bottle_discharged_id, self.bottle_is_discharged);
     }
 
     fn receive_power(&mut self, buses: &impl ElectricalBuses) {
@@ -688,121 +648,61 @@ impl SimulationElement for ExtinguishingAgentBottle {
     }
 }
 
-/// Small module that sets each zone on fire when the failure is triggered. This is independent to the system implementation.
-struct SetOnFireModule {
-    fire_id: [VariableIdentifier; 6],
-
-    fire: [Failure; 6],
-    should_set_zone_on_fire: [bool; 6],
-    should_extinguish_zone: [bool; 6],
-    // We use this to avoid having a previously discharged bottle extinguish a fire
-    bottle_already_discharged: [bool; 9],
-    // We use this to know when to cancel the fire command when the failure is resolved
-    was_on_fire: [bool; 6],
+/// Simple well mixed model of the agent concentration in a cargo compartment.
+/// The agent leaks overboard through the ventilation, which is why the isolation valves close on discharge.
+struct CargoCompartment {
+    agent_concentration_id: VariableIdentifier,
+
+    volume: Volume,
+    agent_concentration: Ratio,
 }
 
-impl SetOnFireModule {
-    fn new(context: &mut InitContext) -> Self {
+impl CargoCompartment {
+    // Fixme: The leakage rates are assumed as no references
+    const LEAKAGE_VALVES_OPEN_CUBIC_METER_PER_SECOND: f64 = 0.5;
+    const LEAKAGE_VALVES_CLOSED_CUBIC_METER_PER_SECOND: f64 = 0.005;
+    const FIRE_SUPPRESSION_CONCENTRATION_PERCENT: f64 = 3.;
+
+    fn new(context: &mut InitContext, id: &str, volume: Volume) -> Self {
         Self {
-            fire_id: [
-                context.get_identifier(format!("ENG_{}_ON_FIRE", 1)),
-                context.get_identifier(format!("ENG_{}_ON_FIRE", 2)),
-                context.get_identifier(format!("ENG_{}_ON_FIRE", 3)),
-                context.get_identifier(format!("ENG_{}_ON_FIRE", 4)),
-                context.get_identifier("APU_ON_FIRE".to_owned()),
-                context.get_identifier("MLG_ON_FIRE".to_owned()),
-            ],
+            agent_concentration_id: context
+                .get_identifier(format!("CARGO_{}_AGENT_CONCENTRATION", id)),
 
-            fire: [
-                Failure::new(FailureType::SetOnFire(FireDetectionZone::Engine(1))),
-                Failure::new(FailureType::SetOnFire(FireDetectionZone::Engine(2))),
-                Failure::new(FailureType::SetOnFire(FireDetectionZone::Engine(3))),
-                Failure::new(FailureType::SetOnFire(FireDetectionZone::Engine(4))),
-                Failure::new(FailureType::SetOnFire(FireDetectionZone::Apu)),
-                Failure::new(FailureType::SetOnFire(FireDetectionZone::Mlg)),
-            ],
-            should_set_zone_on_fire: [false; 6],
-            should_extinguish_zone: [false; 6],
-            bottle_already_discharged: [false; 9],
-            was_on_fire: [false; 6],
+            volume,
+            agent_concentration: Ratio::default(),
         }
     }
 
-    fn update(&mut self, bottle_discharge: [bool; 9]) {
-        for id in 0..6 {
-            self.should_set_zone_on_fire[id] = self.fire[id].is_active()
-                && !self.should_set_zone_on_fire[id]
-                && !self.was_on_fire[id]
-        }
-
-        self.should_extinguish_zone = self.zone_extinguishing_determination(bottle_discharge);
-        self.bottle_already_discharged = bottle_discharge;
-        self.was_on_fire = self
-            .fire
-            .iter()
-            .map(|f| f.is_active())
-            .collect::<Vec<bool>>()
-            .try_into()
-            .unwrap_or_else(|v: Vec<bool>| {
-                panic!("Expected a Vec of length {} but it was {}", 6, v.len())
-            });
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        agent_released: Volume,
+        isolation_valves_open: bool,
+    ) {
+        let leakage = VolumeRate::new::<cubic_meter_per_second>(if isolation_valves_open {
+            Self::LEAKAGE_VALVES_OPEN_CUBIC_METER_PER_SECOND
+        } else {
+            Self::LEAKAGE_VALVES_CLOSED_CUBIC_METER_PER_SECOND
+        });
+        let agent_lost: Volume =
+            leakage * context.delta_as_time() * self.agent_concentration.get::<ratio>();
+
+        self.agent_concentration = Ratio::new::<ratio>(
+            (self.agent_concentration.get::<ratio>()
+                + ((agent_released - agent_lost) / self.volume).get::<ratio>())
+            .clamp(0., 1.),
+        );
     }
 
-    /// We check any "new" bottle discharges and then add a random factor on whether it should extinguish a fire
-    /// We also use this function to "extinguish" a fire if the user deselects the failure
-    fn zone_extinguishing_determination(&self, bottle_discharge: [bool; 9]) -> [bool; 6] {
-        [
-            (zip(
-                bottle_discharge[..2].iter(),
-                self.bottle_already_discharged[..2].iter(),
-            )
-            .any(|(discharge, already_discharged)| *discharge && !already_discharged)
-                && rand::random())
-                || (self.was_on_fire[0] && !self.fire[0].is_active()),
-            (zip(
-                bottle_discharge[2..4].iter(),
-                self.bottle_already_discharged[2..4].iter(),
-            )
-            .any(|(discharge, already_discharged)| *discharge && !already_discharged)
-                && rand::random())
-                || (self.was_on_fire[1] && !self.fire[1].is_active()),
-            (zip(
-                bottle_discharge[4..6].iter(),
-                self.bottle_already_discharged[4..6].iter(),
-            )
-            .any(|(discharge, already_discharged)| *discharge && !already_discharged)
-                && rand::random())
-                || (self.was_on_fire[2] && !self.fire[2].is_active()),
-            (zip(
-                bottle_discharge[6..8].iter(),
-                self.bottle_already_discharged[6..8].iter(),
-            )
-            .any(|(discharge, already_discharged)| *discharge && !already_discharged)
-                && rand::random())
-                || (self.was_on_fire[3] && !self.fire[3].is_active()),
-            (bottle_discharge[8] && !self.bottle_already_discharged[8] && rand::random())
-                || (self.was_on_fire[4] && !self.fire[4].is_active()),
-            // MLG does not have a fire extinguishing system
-            false,
-        ]
+    fn fire_is_suppressed(&self) -> bool {
+        self.agent_concentration
+            >= Ratio::new::<percent>(Self::FIRE_SUPPRESSION_CONCENTRATION_PERCENT)
     }
 }
 
-impl SimulationElement for SetOnFireModule {
-    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
-        accept_iterable!(self.fire, visitor);
-
-        visitor.visit(self);
-    }
-
+impl SimulationElement for CargoCompartment {
     fn write(&self, writer: &mut SimulatorWriter) {
-        for (id, zone) in self.fire_id.iter().enumerate() {
-            if self.should_set_zone_on_fire[id] {
-                writer.write(zone, true)
-            } else if self.should_extinguish_zone[id] {
-                writer.write(zone, false)
-            }
-        }
+        writer.write(&self.agent_concentration_id, self.agent_concentration);
     }
 }
 
@@ -811,6 +711,7 @@ mod a380_fire_and_smoke_protection_tests {
     use systems::{
         electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
         engine::EngineFireOverheadPanel,
+        failures::FailureType,
         shared::PotentialOrigin,
         simulation::{
             test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
@@ -859,11 +760,46 @@ mod a380_fire_and_smoke_protection_tests {
         }
     }
 
+    struct TestVcm {
+        fwd_isolation_valves_open: bool,
+        bulk_isolation_valves_open: bool,
+        isolation_valves_failed_open: bool,
+    }
+    impl TestVcm {
+        fn new() -> Self {
+            Self {
+                fwd_isolation_valves_open: true,
+                bulk_isolation_valves_open: true,
+                isolation_valves_failed_open: false,
+            }
+        }
+
+        fn update(&mut self, cargo_ventilation_isolation: &impl CargoVentilationIsolation) {
+            self.fwd_isolation_valves_open = self.isolation_valves_failed_open
+                || !cargo_ventilation_isolation.fwd_cargo_isolation_requested();
+            self.bulk_isolation_valves_open = self.isolation_valves_failed_open
+                || !cargo_ventilation_isolation.bulk_cargo_isolation_requested();
+        }
+
+        fn fail_isolation_valves_open(&mut self) {
+            self.isolation_valves_failed_open = true;
+        }
+    }
+    impl VcmShared for TestVcm {
+        fn fwd_isolation_valves_open_allowed(&self) -> bool {
+            self.fwd_isolation_valves_open
+        }
+        fn bulk_isolation_valves_open_allowed(&self) -> bool {
+            self.bulk_isolation_valves_open
+        }
+    }
+
     struct TestAircraft {
         a380_fire_and_smoke_protection: A380FireAndSmokeProtection,
         engine_fire_overhead_panel: EngineFireOverheadPanel<4>,
         lgciu1: TestLgciu,
         lgciu2: TestLgciu,
+        vcm: TestVcm,
 
         powered_dc_source_ess: TestElectricitySource,
         powered_dc_source_2: TestElectricitySource,
@@ -880,6 +816,7 @@ mod a380_fire_and_smoke_protection_tests {
                 engine_fire_overhead_panel: EngineFireOverheadPanel::new(context),
                 lgciu1: TestLgciu::new(false),
                 lgciu2: TestLgciu::new(false),
+                vcm: TestVcm::new(),
 
                 powered_dc_source_ess: TestElectricitySource::powered(
                     context,
@@ -923,6 +860,10 @@ mod a380_fire_and_smoke_protection_tests {
         fn unpower_dc_hot_bus(&mut self) {
             self.powered_dc_hot.unpower();
         }
+
+        fn fail_cargo_isolation_valves_open(&mut self) {
+            self.vcm.fail_isolation_valves_open();
+        }
     }
     impl Aircraft for TestAircraft {
         fn update_before_power_distribution(
@@ -943,7 +884,9 @@ mod a380_fire_and_smoke_protection_tests {
                 context,
                 &self.engine_fire_overhead_panel,
                 [&self.lgciu1, &self.lgciu2],
-            )
+                &self.vcm,
+            );
+            self.vcm.update(&self.a380_fire_and_smoke_protection);
         }
     }
     impl SimulationElement for TestAircraft {
@@ -1092,6 +1035,68 @@ mod a380_fire_and_smoke_protection_tests {
             self
         }
 
+        fn set_smoke_in_zone(mut self, zone: SmokeDetectionZone) -> Self {
+            self.fail(FailureType::Smoke(zone));
+            self
+        }
+
+        fn set_smoke_loop_failure(
+            mut self,
+            zone: SmokeDetectionZone,
+            loop_id: FireDetectionLoopID,
+        ) -> Self {
+            self.fail(FailureType::SmokeDetectionLoop(loop_id, zone));
+            self
+        }
+
+        fn set_cargo_smoke_test_pushbutton(mut self, test_pb: bool) -> Self {
+            self.write_by_name("FIRE_TEST_CARGO", test_pb);
+            self
+        }
+
+        fn set_cargo_disch_pb(mut self, compartment: &str, pressed: bool) -> Self {
+            self.write_by_name(
+                &format!("OVHD_CARGO_SMOKE_{}_DISCH_IS_PRESSED", compartment),
+                pressed,
+            );
+            self
+        }
+
+        fn cargo_isolation_valves_failed_open(mut self) -> Self {
+            self.command(|a| a.fail_cargo_isolation_valves_open());
+            self
+        }
+
+        fn smoke_detected(&mut self, zone: SmokeDetectionZone) -> bool {
+            self.read_by_name(&format!("SMOKE_DETECTED_{}", zone))
+        }
+
+        fn smoke_detection_fault(&mut self, zone: SmokeDetectionZone) -> bool {
+            self.read_by_name(&format!("SMOKE_DETECTION_FAULT_{}", zone))
+        }
+
+        fn cargo_bottle_is_discharged(&mut self, bottle_id: &str) -> bool {
+            self.read_by_name(&format!("CARGO_FIRE_BOTTLE_{}_IS_DISCHARGED", bottle_id))
+        }
+
+        fn cargo_agent_concentration(&mut self, compartment: &str) -> Ratio {
+            self.read_by_name(&format!("CARGO_{}_AGENT_CONCENTRATION", compartment))
+        }
+
+        fn fwd_cargo_isolation_requested(&self) -> bool {
+            self.query(|a| {
+                a.a380_fire_and_smoke_protection
+                    .fwd_cargo_isolation_requested()
+            })
+        }
+
+        fn bulk_cargo_isolation_requested(&self) -> bool {
+            self.query(|a| {
+                a.a380_fire_and_smoke_protection
+                    .bulk_cargo_isolation_requested()
+            })
+        }
+
         fn engine_on_fire_detected(&mut self, engine_number: usize) -> bool {
             self.read_by_name(&format!("FIRE_DETECTED_ENG{}", engine_number))
         }
@@ -1560,4 +1565,214 @@ mod a380_fire_and_smoke_protection_tests {
             );
         }
     }
+
+    mod a380_smoke_detection_tests {
+        use super::*;
+
+        #[test]
+        fn smoke_in_fwd_cargo_is_detected_after_confirmation() {
+            let mut test_bed = test_bed()
+                .with()
+                .set_smoke_in_zone(SmokeDetectionZone::FwdCargo)
+                .and_run();
+
+            assert!(!test_bed.smoke_detected(SmokeDetectionZone::FwdCargo));
+
+            test_bed = test_bed.run_with_delta_of(Duration::from_secs(5));
+
+            assert!(test_bed.smoke_detected(SmokeDetectionZone::FwdCargo));
+            assert!(!test_bed.smoke_detected(SmokeDetectionZone::BulkCargo));
+            assert!(test_bed.fwd_cargo_isolation_requested());
+            assert!(!test_bed.bulk_cargo_isolation_requested());
+        }
+
+        #[test]
+        fn smoke_in_lavatory_and_crew_rest_is_detected() {
+            let mut test_bed = test_bed()
+                .with()
+                .set_smoke_in_zone(SmokeDetectionZone::Lavatory(3))
+                .set_smoke_in_zone(SmokeDetectionZone::CrewRest(2))
+                .run_with_delta_of(Duration::from_secs(5))
+                .and_run();
+
+            assert!(test_bed.smoke_detected(SmokeDetectionZone::Lavatory(3)));
+            assert!(test_bed.smoke_detected(SmokeDetectionZone::CrewRest(2)));
+            assert!(!test_bed.smoke_detected(SmokeDetectionZone::Lavatory(4)));
+            assert!(!test_bed.fwd_cargo_isolation_requested());
+            assert!(!test_bed.bulk_cargo_isolation_requested());
+        }
+
+        #[test]
+        fn smoke_is_detected_when_one_loop_has_failed() {
+            let mut test_bed = test_bed()
+                .with()
+                .set_smoke_loop_failure(SmokeDetectionZone::AvionicsBay, FireDetectionLoopID::A)
+                .and_run();
+
+            assert!(test_bed.smoke_detection_fault(SmokeDetectionZone::AvionicsBay));
+            assert!(!test_bed.smoke_detected(SmokeDetectionZone::AvionicsBay));
+
+            test_bed = test_bed
+                .set_smoke_in_zone(SmokeDetectionZone::AvionicsBay)
+                .run_with_delta_of(Duration::from_secs(5))
+                .and_run();
+
+            assert!(test_bed.smoke_detected(SmokeDetectionZone::AvionicsBay));
+        }
+
+        #[test]
+        fn smoke_is_not_detected_when_both_loops_have_failed() {
+            let mut test_bed = test_bed()
+                .with()
+                .set_smoke_loop_failure(SmokeDetectionZone::FwdCargo, FireDetectionLoopID::A)
+                .set_smoke_loop_failure(SmokeDetectionZone::FwdCargo, FireDetectionLoopID::B)
+                .set_smoke_in_zone(SmokeDetectionZone::FwdCargo)
+                .run_with_delta_of(Duration::from_secs(5))
+                .and_run();
+
+            assert!(!test_bed.smoke_detected(SmokeDetectionZone::FwdCargo));
+            assert!(test_bed.smoke_detection_fault(SmokeDetectionZone::FwdCargo));
+        }
+
+        #[test]
+        fn loss_of_power_on_one_loop_is_a_fault_but_smoke_is_still_detected() {
+            let mut test_bed = test_bed().and_run().with().unpowered_dc_2_bus().and_run();
+
+            assert!(test_bed.smoke_detection_fault(SmokeDetectionZone::BulkCargo));
+
+            test_bed = test_bed
+                .set_smoke_in_zone(SmokeDetectionZone::BulkCargo)
+                .run_with_delta_of(Duration::from_secs(5))
+                .and_run();
+
+            assert!(test_bed.smoke_detected(SmokeDetectionZone::BulkCargo));
+            assert!(test_bed.bulk_cargo_isolation_requested());
+        }
+
+        #[test]
+        fn cargo_smoke_test_triggers_detection_in_cargo_compartments_only() {
+            let mut test_bed = test_bed()
+                .with()
+                .set_cargo_smoke_test_pushbutton(true)
+                .run_with_delta_of(Duration::from_secs(5))
+                .and_run();
+
+            assert!(test_bed.smoke_detected(SmokeDetectionZone::FwdCargo));
+            assert!(test_bed.smoke_detected(SmokeDetectionZone::BulkCargo));
+            assert!(!test_bed.smoke_detected(SmokeDetectionZone::Lavatory(1)));
+
+            test_bed = test_bed.set_cargo_smoke_test_pushbutton(false).and_run();
+
+            assert!(!test_bed.smoke_detected(SmokeDetectionZone::FwdCargo));
+            assert!(!test_bed.smoke_detected(SmokeDetectionZone::BulkCargo));
+        }
+
+        #[test]
+        fn high_rate_bottles_discharge_after_squib_delay_and_low_rate_bottles_follow() {
+            let mut test_bed = test_bed()
+                .with()
+                .set_cargo_disch_pb("FWD", true)
+                .run_with_delta_of(Duration::from_millis(500));
+
+            assert!(!test_bed.cargo_bottle_is_discharged("HRD_1"));
+            assert!(test_bed.fwd_cargo_isolation_requested());
+
+            test_bed = test_bed.run_with_delta_of(Duration::from_millis(600));
+
+            assert!(test_bed.cargo_bottle_is_discharged("HRD_1"));
+            assert!(test_bed.cargo_bottle_is_discharged("HRD_2"));
+            assert!(!test_bed.cargo_bottle_is_discharged("LRD_1"));
+
+            test_bed = test_bed.run_with_delta_of(Duration::from_secs(61));
+
+            assert!(test_bed.cargo_bottle_is_discharged("LRD_1"));
+            assert!(!test_bed.cargo_bottle_is_discharged("LRD_2"));
+        }
+
+        #[test]
+        fn agent_discharge_suppresses_fire_in_selected_compartment() {
+            let mut test_bed = test_bed()
+                .with()
+                .set_smoke_in_zone(SmokeDetectionZone::FwdCargo)
+                .run_with_delta_of(Duration::from_secs(5))
+                .and_run();
+
+            assert!(test_bed.smoke_detected(SmokeDetectionZone::FwdCargo));
+
+            test_bed = test_bed
+                .then()
+                .set_cargo_disch_pb("FWD", true)
+                .and_double_run()
+                .set_cargo_disch_pb("FWD", false)
+                .and_run();
+
+            assert!(
+                test_bed.cargo_agent_concentration("FWD")
+                    >= Ratio::new::<percent>(
+                        CargoCompartment::FIRE_SUPPRESSION_CONCENTRATION_PERCENT
+                    )
+            );
+            assert_eq!(test_bed.cargo_agent_concentration("BULK"), Ratio::default());
+            assert!(!test_bed.smoke_detected(SmokeDetectionZone::FwdCargo));
+            assert!(test_bed.fwd_cargo_isolation_requested());
+        }
+
+        #[test]
+        fn low_rate_discharge_maintains_concentration_when_compartment_is_isolated() {
+            let mut test_bed = test_bed().with().set_cargo_disch_pb("BULK", true).and_run();
+
+            for _ in 0..60 {
+                test_bed = test_bed.run_with_delta_of(Duration::from_secs(60));
+            }
+
+            assert!(test_bed.cargo_bottle_is_discharged("LRD_1"));
+            assert!(
+                test_bed.cargo_agent_concentration("BULK")
+                    >= Ratio::new::<percent>(
+                        CargoCompartment::FIRE_SUPPRESSION_CONCENTRATION_PERCENT
+                    )
+            );
+        }
+
+        #[test]
+        fn agent_leaks_overboard_when_isolation_valves_stay_open() {
+            let mut test_bed = test_bed()
+                .with()
+                .cargo_isolation_valves_failed_open()
+                .set_cargo_disch_pb("FWD", true)
+                .and_double_run();
+
+            assert!(
+                test_bed.cargo_agent_concentration("FWD")
+                    >= Ratio::new::<percent>(
+                        CargoCompartment::FIRE_SUPPRESSION_CONCENTRATION_PERCENT
+                    )
+            );
+
+            for _ in 0..20 {
+                test_bed = test_bed.run_with_delta_of(Duration::from_secs(60));
+            }
+
+            assert!(
+                test_bed.cargo_agent_concentration("FWD")
+                    < Ratio::new::<percent>(
+                        CargoCompartment::FIRE_SUPPRESSION_CONCENTRATION_PERCENT
+                    )
+            );
+        }
+
+        #[test]
+        fn cargo_bottles_do_not_discharge_when_unpowered() {
+            let mut test_bed = test_bed()
+                .with()
+                .unpowered_dc_hot_bus()
+                .unpowered_dc_ess_bus()
+                .set_cargo_disch_pb("FWD", true)
+                .and_double_run();
+
+            assert!(!test_bed.cargo_bottle_is_discharged("HRD_1"));
+            assert!(!test_bed.cargo_bottle_is_discharged("HRD_2"));
+            assert_eq!(test_bed.cargo_agent_concentration("FWD"), Ratio::default());
+        }
+    }
 }
@@ -1,12 +1,11 @@
 use std::{collections::HashMap, time::Duration};
 
 use crate::systems::simulation::SimulationElement;
-use nalgebra::Vector3;
 use serde::Deserialize;
 use systems::{
-    fuel::{self, FuelInfo, FuelSystem, FuelTank, RefuelRate},
+    fuel::{self, FuelSystem, RefuelRate},
     pneumatic::EngineState,
-    shared::{ElectricalBusType, ElectricalBuses},
+    shared::{ElectricalBusType, ElectricalBuses, EngineFuelFlow},
     simulation::{
         InitContext, Read, SimulationElementVisitor, SimulatorReader, SimulatorWriter,
         UpdateContext, VariableIdentifier, Write,
@@ -18,7 +17,7 @@ use uom::si::{
     velocity::knot,
 };
 
-use super::A380FuelTankType;
+use super::{transfer_application::TransferApplication, A380FuelOverheadPanel, A380FuelTankType};
 
 use serde_with::{serde_as, DisplayFromStr};
 
@@ -600,20 +599,11 @@ impl SimulationElement for RefuelDriver {}
 pub struct A380FuelQuantityManagementSystem {
     fuel_system: FuelSystem<11>,
     refuel_application: RefuelApplication,
+    transfer_application: TransferApplication,
     integrated_refuel_panel: IntegratedRefuelPanel,
 }
 impl A380FuelQuantityManagementSystem {
-    pub fn new(context: &mut InitContext, fuel_tanks_info: [FuelInfo; 11]) -> Self {
-        let fuel_tanks = fuel_tanks_info.map(|f| {
-            FuelTank::new(
-                context,
-                f.fuel_tank_id,
-                Vector3::new(f.position.0, f.position.1, f.position.2),
-                true,
-            )
-        });
-        let fuel_system = FuelSystem::new(context, fuel_tanks);
-
+    pub fn new(context: &mut InitContext, fuel_system: FuelSystem<11>) -> Self {
         Self {
             // TODO: This needs to be refactored when CPIOM implementation is done
             // CPIOM_COM_F1, CPIOM_MON_F3 [FQDC_1] -> 501PP
@@ -623,6 +613,7 @@ impl A380FuelQuantityManagementSystem {
                 context,
                 ElectricalBusType::DirectCurrentEssential, // 501PP
             ),
+            transfer_application: TransferApplication::new(context),
             integrated_refuel_panel: IntegratedRefuelPanel::new(
                 context,
                 ElectricalBusType::DirectCurrentEssential, // 501PP
@@ -630,12 +621,28 @@ impl A380FuelQuantityManagementSystem {
         }
     }
 
-    pub fn update(&mut self, context: &UpdateContext) {
+    pub fn update<T: EngineFuelFlow>(
+        &mut self,
+        context: &UpdateContext,
+        overhead: &A380FuelOverheadPanel,
+        engines: [&T; 4],
+    ) {
         self.refuel_application.update(
             context,
             &mut self.fuel_system,
             &mut self.integrated_refuel_panel,
         );
+        self.transfer_application.update(
+            context,
+            &mut self.fuel_system,
+            overhead,
+            engines,
+            self.integrated_refuel_panel.refuel_status(),
+        );
+    }
+
+    pub fn transfer_application(&self) -> &TransferApplication {
+        &self.transfer_application
     }
 
     #[allow(dead_code)]
@@ -651,6 +658,7 @@ impl SimulationElement for A380FuelQuantityManagementSystem {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.fuel_system.accept(visitor);
         self.refuel_application.accept(visitor);
+        self.transfer_application.accept(visitor);
         self.integrated_refuel_panel.accept(visitor);
         visitor.visit(self);
     }
@@ -1,11 +1,17 @@
-// Note: Fuel system for now is still handled in MSFS. This is used for calculating fuel-related factors.
-
 mod fuel_quantity_management_system;
+mod transfer_application;
+use crate::aircraft_configuration;
 use fuel_quantity_management_system::A380FuelQuantityManagementSystem;
 use nalgebra::Vector3;
 use systems::{
-    fuel::{FuelCG, FuelInfo, FuelPayload, FuelSystem},
-    simulation::{InitContext, SimulationElement, SimulationElementVisitor, UpdateContext},
+    accept_iterable,
+    fuel::{FuelCG, FuelPayload, FuelSystem},
+    overhead::{AutoOffFaultPushButton, OnOffFaultPushButton},
+    shared::EngineFuelFlow,
+    simulation::{
+        InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
+        VariableIdentifier, Write,
+    },
 };
 use uom::si::f64::*;
 
@@ -61,91 +67,47 @@ impl A380FuelTankType {
 }
 
 pub struct A380Fuel {
+    native_model_is_active_id: VariableIdentifier,
+
     fuel_quantity_management_system: A380FuelQuantityManagementSystem,
 }
 
 impl A380Fuel {
-    // TODO: Move to toml cfg
-    pub const A380_FUEL: [FuelInfo<'static>; 11] = [
-        FuelInfo {
-            // LEFT_OUTER - Capacity: 2731.5
-            fuel_tank_id: "FUEL_TANK_QUANTITY_1",
-            position: (-25., -100.0, 8.5),
-            total_capacity_gallons: 2731.5,
-        },
-        FuelInfo {
-            // FEED_ONE - Capacity: 7299.6
-            fuel_tank_id: "FUEL_TANK_QUANTITY_2",
-            position: (-7.45, -71.0, 7.3),
-            total_capacity_gallons: 7299.6,
-        },
-        FuelInfo {
-            // LEFT_MID - Capacity: 9632
-            fuel_tank_id: "FUEL_TANK_QUANTITY_3",
-            position: (7.1, -46.4, 5.9),
-            total_capacity_gallons: 9632.,
-        },
-        FuelInfo {
-            // LEFT_INNER - Capacity: 12189.4
-            fuel_tank_id: "FUEL_TANK_QUANTITY_4",
-            position: (16.5, -24.7, 3.2),
-            total_capacity_gallons: 12189.4,
-        },
-        FuelInfo {
-            // FEED_TWO - Capacity: 7753.2
-            fuel_tank_id: "FUEL_TANK_QUANTITY_5",
-            position: (27.3, -18.4, 1.0),
-            total_capacity_gallons: 7753.2,
-        },
-        FuelInfo {
-            // FEED_THREE - Capacity: 7753.2
-            fuel_tank_id: "FUEL_TANK_QUANTITY_6",
-            position: (27.3, 18.4, 1.0),
-            total_capacity_gallons: 7753.2,
-        },
-        FuelInfo {
-            // RIGHT_INNER - Capacity: 12189.4
-            fuel_tank_id: "FUEL_TANK_QUANTITY_7",
-            position: (16.5, 24.7, 3.2),
-            total_capacity_gallons: 12189.4,
-        },
-        FuelInfo {
-            // RIGHT_MID - Capacity: 9632
-            fuel_tank_id: "FUEL_TANK_QUANTITY_8",
-            position: (7.1, 46.4, 5.9),
-            total_capacity_gallons: 9632.,
-        },
-        FuelInfo {
-            // FEED_FOUR - Capacity: 7299.6
-            fuel_tank_id: "FUEL_TANK_QUANTITY_9",
-            position: (-7.45, 71., 7.3),
-            total_capacity_gallons: 7299.6,
-        },
-        FuelInfo {
-            // RIGHT_OUTER - Capacity: 2731.5
-            fuel_tank_id: "FUEL_TANK_QUANTITY_10",
-            position: (-25., 100., 8.5),
-            total_capacity_gallons: 2731.5,
-        },
-        FuelInfo {
-            // TRIM - Capacity: 6260.3
-            fuel_tank_id: "FUEL_TANK_QUANTITY_11",
-            position: (-87.14, 0., 12.1),
-            total_capacity_gallons: 6260.3,
-        },
-    ];
-
     pub fn new(context: &mut InitContext) -> Self {
+        let fuel_system = aircraft_configuration()
+            .fuel_system(context, true)
+            .unwrap_or_else(|e| panic!("Invalid A380 fuel tank configuration: {}", e));
         A380Fuel {
+            native_model_is_active_id: context
+                .get_identifier("FUEL_NATIVE_MODEL_IS_ACTIVE".to_owned()),
+
             fuel_quantity_management_system: A380FuelQuantityManagementSystem::new(
                 context,
-                Self::A380_FUEL,
+                fuel_system,
             ),
         }
     }
 
-    pub(crate) fn update(&mut self, context: &UpdateContext) {
-        self.fuel_quantity_management_system.update(context);
+    pub(crate) fn update<T: EngineFuelFlow>(
+        &mut self,
+        context: &UpdateContext,
+        overhead: &A380FuelOverheadPanel,
+        engines: [&T; 4],
+    ) {
+        self.fuel_quantity_management_system
+            .update(context, overhead, engines);
+    }
+
+    pub fn engine_is_fed(&self, engine_number: usize) -> bool {
+        self.fuel_quantity_management_system
+            .transfer_application()
+            .engine_is_fed(engine_number)
+    }
+
+    pub fn feed_main_pump_is_running(&self, feed_tank_number: usize) -> bool {
+        self.fuel_quantity_management_system
+            .transfer_application()
+            .feed_main_pump_is_running(feed_tank_number)
     }
 
     fn fuel_system(&self) -> &FuelSystem<11> {
@@ -277,4 +239,93 @@ impl SimulationElement for A380Fuel {
         self.fuel_quantity_management_system.accept(visitor);
         visitor.visit(self);
     }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        // Tells the FADEC to leave the fuel quantities to the native fuel model, which feeds the
+        // engines itself.
+        writer.write(&self.native_model_is_active_id, true);
+    }
+}
+
+pub struct A380FuelOverheadPanel {
+    feed_main_pumps: [OnOffFaultPushButton; 4],
+    feed_standby_pumps: [OnOffFaultPushButton; 4],
+    crossfeeds: [OnOffFaultPushButton; 4],
+    outer_tank_transfer: AutoOffFaultPushButton,
+    mid_tank_transfer: AutoOffFaultPushButton,
+    inner_tank_transfer: AutoOffFaultPushButton,
+    trim_tank_transfer: AutoOffFaultPushButton,
+    jettison_arm: OnOffFaultPushButton,
+    jettison_active: OnOffFaultPushButton,
+}
+impl A380FuelOverheadPanel {
+    pub fn new(context: &mut InitContext) -> Self {
+        Self {
+            feed_main_pumps: [1, 2, 3, 4]
+                .map(|n| OnOffFaultPushButton::new_on(context, &format!("FUEL_FEEDTK{}_MAIN", n))),
+            feed_standby_pumps: [1, 2, 3, 4]
+                .map(|n| OnOffFaultPushButton::new_on(context, &format!("FUEL_FEEDTK{}_STBY", n))),
+            crossfeeds: [1, 2, 3, 4]
+                .map(|n| OnOffFaultPushButton::new_off(context, &format!("FUEL_XFEED{}", n))),
+            outer_tank_transfer: AutoOffFaultPushButton::new_auto(context, "FUEL_OUTRTK_XFR"),
+            mid_tank_transfer: AutoOffFaultPushButton::new_auto(context, "FUEL_MIDTK_XFR"),
+            inner_tank_transfer: AutoOffFaultPushButton::new_auto(context, "FUEL_INRTK_XFR"),
+            trim_tank_transfer: AutoOffFaultPushButton::new_auto(context, "FUEL_TRIMTK_XFR"),
+            jettison_arm: OnOffFaultPushButton::new_off(context, "FUEL_JETTISON_ARM"),
+            jettison_active: OnOffFaultPushButton::new_off(context, "FUEL_JETTISON_ACTIVE"),
+        }
+    }
+
+    pub fn update(&mut self, fuel: &A380Fuel) {
+        for (i, pb) in self.feed_main_pumps.iter_mut().enumerate() {
+            pb.set_fault(pb.is_on() && !fuel.feed_main_pump_is_running(i + 1));
+        }
+    }
+
+    fn feed_main_pump_is_on(&self, feed_tank_number: usize) -> bool {
+        self.feed_main_pumps[feed_tank_number - 1].is_on()
+    }
+
+    fn feed_standby_pump_is_on(&self, feed_tank_number: usize) -> bool {
+        self.feed_standby_pumps[feed_tank_number - 1].is_on()
+    }
+
+    fn crossfeed_is_on(&self, feed_tank_number: usize) -> bool {
+        self.crossfeeds[feed_tank_number - 1].is_on()
+    }
+
+    fn outer_tank_transfer_is_auto(&self) -> bool {
+        self.outer_tank_transfer.is_auto()
+    }
+
+    fn mid_tank_transfer_is_auto(&self) -> bool {
+        self.mid_tank_transfer.is_auto()
+    }
+
+    fn inner_tank_transfer_is_auto(&self) -> bool {
+        self.inner_tank_transfer.is_auto()
+    }
+
+    fn trim_tank_transfer_is_auto(&self) -> bool {
+        self.trim_tank_transfer.is_auto()
+    }
+
+    fn jettison_is_active(&self) -> bool {
+        self.jettison_arm.is_on() && self.jettison_active.is_on()
+    }
+}
+impl SimulationElement for A380FuelOverheadPanel {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.feed_main_pumps, visitor);
+        accept_iterable!(self.feed_standby_pumps, visitor);
+        accept_iterable!(self.crossfeeds, visitor);
+        self.outer_tank_transfer.accept(visitor);
+        self.mid_tank_transfer.accept(visitor);
+        self.inner_tank_transfer.accept(visitor);
+        self.trim_tank_transfer.accept(visitor);
+        self.jettison_arm.accept(visitor);
+        self.jettison_active.accept(visitor);
+
+        visitor.visit(self);
+    }
 }
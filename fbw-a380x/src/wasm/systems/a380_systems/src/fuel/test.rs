@@ -1,25 +1,86 @@
 use std::{collections::HashMap, time::Duration};
 
 use ntest::{assert_false, assert_true};
-use systems::{electrical::Electricity, fuel::RefuelRate, simulation::test::ReadByName};
-use uom::si::mass::kilogram;
+use systems::{
+    electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
+    failures::FailureType,
+    fuel::RefuelRate,
+    shared::{ElectricalBusType, PotentialOrigin},
+    simulation::test::ReadByName,
+};
+use uom::si::{
+    electric_potential::volt, length::foot, mass::kilogram, mass_rate::kilogram_per_hour,
+    ratio::percent,
+};
 
 use super::*;
 use crate::systems::simulation::{
     test::{SimulationTestBed, TestBed, WriteByName},
     Aircraft, SimulationElement, SimulationElementVisitor,
 };
+
+struct TestEngine {
+    fuel_flow: MassRate,
+}
+impl EngineFuelFlow for TestEngine {
+    fn fuel_flow(&self) -> MassRate {
+        self.fuel_flow
+    }
+}
+
 struct FuelTestAircraft {
+    electricity_source: TestElectricitySource,
+    buses: Vec<ElectricalBus>,
+    is_powered: bool,
+
     fuel: A380Fuel,
+    fuel_overhead: A380FuelOverheadPanel,
+    engines: [TestEngine; 4],
 }
 
 impl FuelTestAircraft {
     fn new(context: &mut InitContext) -> Self {
         Self {
+            electricity_source: TestElectricitySource::powered(
+                context,
+                PotentialOrigin::EngineGenerator(1),
+            ),
+            buses: [
+                ElectricalBusType::AlternatingCurrent(1),
+                ElectricalBusType::AlternatingCurrent(2),
+                ElectricalBusType::AlternatingCurrent(3),
+                ElectricalBusType::AlternatingCurrent(4),
+                ElectricalBusType::DirectCurrent(1),
+                ElectricalBusType::DirectCurrent(2),
+                ElectricalBusType::DirectCurrentEssential,
+            ]
+            .into_iter()
+            .map(|bus_type| ElectricalBus::new(context, bus_type))
+            .collect(),
+            is_powered: true,
+
             fuel: A380Fuel::new(context),
+            fuel_overhead: A380FuelOverheadPanel::new(context),
+            engines: [(); 4].map(|_| TestEngine {
+                fuel_flow: MassRate::default(),
+            }),
         }
     }
 
+    fn set_engines_fuel_flow(&mut self, fuel_flow: MassRate) {
+        self.engines
+            .iter_mut()
+            .for_each(|engine| engine.fuel_flow = fuel_flow);
+    }
+
+    fn set_powered(&mut self, is_powered: bool) {
+        self.is_powered = is_powered;
+    }
+
+    fn engine_is_fed(&self, engine_number: usize) -> bool {
+        self.fuel.engine_is_fed(engine_number)
+    }
+
     fn fore_aft_center_of_gravity(&self) -> f64 {
         self.fuel.fore_aft_center_of_gravity()
     }
@@ -32,15 +93,34 @@ impl FuelTestAircraft {
 impl Aircraft for FuelTestAircraft {
     fn update_before_power_distribution(
         &mut self,
-        context: &UpdateContext,
-        _electricity: &mut Electricity,
+        _context: &UpdateContext,
+        electricity: &mut Electricity,
     ) {
-        self.fuel.update(context);
+        self.electricity_source
+            .power_with_potential(ElectricPotential::new::<volt>(115.));
+        electricity.supplied_by(&self.electricity_source);
+
+        if self.is_powered {
+            for bus in &self.buses {
+                electricity.flow(&self.electricity_source, bus);
+            }
+        }
+    }
+
+    fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+        let [engine_1, engine_2, engine_3, engine_4] = &self.engines;
+        self.fuel.update(
+            context,
+            &self.fuel_overhead,
+            [engine_1, engine_2, engine_3, engine_4],
+        );
+        self.fuel_overhead.update(&self.fuel);
     }
 }
 impl SimulationElement for FuelTestAircraft {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.fuel.accept(visitor);
+        self.fuel_overhead.accept(visitor);
 
         visitor.visit(self);
     }
@@ -230,6 +310,84 @@ impl FuelTestBed {
     fn tank_mass(&self, tank: usize) -> Mass {
         self.query(|a: &FuelTestAircraft| a.tank_mass(tank))
     }
+
+    fn tank_mass_kg(&self, tank: A380FuelTankType) -> f64 {
+        self.tank_mass(tank as usize).get::<kilogram>()
+    }
+
+    fn total_fuel_kg(&self) -> f64 {
+        A380FuelTankType::iterator()
+            .map(|tank| self.tank_mass_kg(tank))
+            .sum()
+    }
+
+    fn in_cruise(mut self) -> Self {
+        self.set_on_ground(false);
+        self.set_pressure_altitude(Length::new::<foot>(35000.));
+        self
+    }
+
+    fn at_altitude(mut self, altitude_feet: f64) -> Self {
+        self.set_pressure_altitude(Length::new::<foot>(altitude_feet));
+        self
+    }
+
+    fn engines_running(mut self) -> Self {
+        self.command(|a| a.set_engines_fuel_flow(MassRate::new::<kilogram_per_hour>(3600.)));
+        self
+    }
+
+    fn unpowered(mut self) -> Self {
+        self.command(|a| a.set_powered(false));
+        self
+    }
+
+    fn center_of_gravity_percent(mut self, cg: f64) -> Self {
+        self.write_by_name("CG PERCENT", Ratio::new::<percent>(cg));
+        self
+    }
+
+    fn feed_pumps_off(mut self, feed_tank_number: usize) -> Self {
+        self.write_by_name(
+            &format!("OVHD_FUEL_FEEDTK{}_MAIN_PB_IS_ON", feed_tank_number),
+            false,
+        );
+        self.write_by_name(
+            &format!("OVHD_FUEL_FEEDTK{}_STBY_PB_IS_ON", feed_tank_number),
+            false,
+        );
+        self
+    }
+
+    fn crossfeed_on(mut self, feed_tank_number: usize) -> Self {
+        self.write_by_name(
+            &format!("OVHD_FUEL_XFEED{}_PB_IS_ON", feed_tank_number),
+            true,
+        );
+        self
+    }
+
+    fn transfers_off(mut self) -> Self {
+        self.write_by_name("OVHD_FUEL_OUTRTK_XFR_PB_IS_AUTO", false);
+        self.write_by_name("OVHD_FUEL_MIDTK_XFR_PB_IS_AUTO", false);
+        self.write_by_name("OVHD_FUEL_INRTK_XFR_PB_IS_AUTO", false);
+        self.write_by_name("OVHD_FUEL_TRIMTK_XFR_PB_IS_AUTO", false);
+        self
+    }
+
+    fn jettison_armed_and_active(mut self) -> Self {
+        self.write_by_name("OVHD_FUEL_JETTISON_ARM_PB_IS_ON", true);
+        self.write_by_name("OVHD_FUEL_JETTISON_ACTIVE_PB_IS_ON", true);
+        self
+    }
+
+    fn engine_is_fed(&self, engine_number: usize) -> bool {
+        self.query(|a: &FuelTestAircraft| a.engine_is_fed(engine_number))
+    }
+
+    fn trim_transfer_mode(&mut self) -> f64 {
+        self.read_by_name("FUEL_TRIM_TANK_TRANSFER_MODE")
+    }
 }
 
 impl TestBed for FuelTestBed {
@@ -561,3 +719,286 @@ fn spawn_high_fuel_load_desired_real_200000_done() {
     assert_fuel_quantity_200000(&test_bed);
     assert_false!(test_bed.refuel_status());
 }
+
+#[test]
+fn engines_burn_fuel_from_their_feed_tanks() {
+    let mut test_bed = test_bed_with()
+        .fuel_high()
+        .in_cruise()
+        .transfers_off()
+        .engines_running()
+        .and_run();
+    let feed_one_before = test_bed.tank_mass_kg(A380FuelTankType::FeedOne);
+
+    test_bed = test_bed.run_multiple_frames(Duration::from_secs(60));
+
+    assert!((feed_one_before - test_bed.tank_mass_kg(A380FuelTankType::FeedOne) - 60.).abs() < 1.);
+    for engine_number in 1..=4 {
+        assert_true!(test_bed.engine_is_fed(engine_number));
+    }
+}
+
+#[test]
+fn fuel_burned_equals_the_integrated_fuel_flow() {
+    let mut test_bed = test_bed_with()
+        .fuel_high()
+        .in_cruise()
+        .engines_running()
+        .and_run();
+    let total_before = test_bed.total_fuel_kg();
+
+    test_bed = test_bed.run_multiple_frames(Duration::from_secs(10 * MINUTES_TO_SECONDS));
+
+    // Four engines at 3600 kg/h for ten minutes, while the transfers refill the feed tanks.
+    assert!((total_before - test_bed.total_fuel_kg() - 2400.).abs() < 1.);
+}
+
+#[test]
+fn native_fuel_model_takes_over_the_fuel_quantities_from_the_fadec() {
+    let mut test_bed = test_bed_with().and_run();
+
+    let native_model_is_active: bool = test_bed.read_by_name("FUEL_NATIVE_MODEL_IS_ACTIVE");
+    assert_true!(native_model_is_active);
+}
+
+#[test]
+fn unlimited_fuel_does_not_burn_fuel() {
+    let mut test_bed = test_bed_with()
+        .fuel_high()
+        .in_cruise()
+        .transfers_off()
+        .engines_running();
+    test_bed.write_by_name("UNLIMITED FUEL", true);
+    test_bed = test_bed.and_run();
+    let total_before = test_bed.total_fuel_kg();
+
+    test_bed = test_bed.run_multiple_frames(Duration::from_secs(60));
+
+    assert!((total_before - test_bed.total_fuel_kg()).abs() < 1e-3);
+}
+
+#[test]
+fn engine_is_not_fed_without_feed_pumps_above_suction_feed_ceiling() {
+    let test_bed = test_bed_with()
+        .fuel_high()
+        .in_cruise()
+        .engines_running()
+        .feed_pumps_off(1)
+        .and_run();
+
+    assert_false!(test_bed.engine_is_fed(1));
+    assert_true!(test_bed.engine_is_fed(2));
+}
+
+#[test]
+fn engine_is_suction_fed_without_feed_pumps_at_low_altitude() {
+    let test_bed = test_bed_with()
+        .fuel_high()
+        .in_cruise()
+        .at_altitude(10000.)
+        .engines_running()
+        .feed_pumps_off(1)
+        .and_run();
+
+    assert_true!(test_bed.engine_is_fed(1));
+}
+
+#[test]
+fn engines_are_not_fed_above_suction_feed_ceiling_without_power() {
+    let test_bed = test_bed_with()
+        .fuel_high()
+        .in_cruise()
+        .engines_running()
+        .unpowered()
+        .and_run();
+
+    for engine_number in 1..=4 {
+        assert_false!(test_bed.engine_is_fed(engine_number));
+    }
+}
+
+#[test]
+fn standby_pump_takes_over_when_main_pump_fails() {
+    let mut test_bed = test_bed_with().fuel_high().in_cruise().engines_running();
+    test_bed.fail(FailureType::FuelPump(3));
+    test_bed = test_bed.and_run();
+
+    assert_true!(test_bed.engine_is_fed(2));
+    let main_pump_has_fault: bool = test_bed.read_by_name("OVHD_FUEL_FEEDTK2_MAIN_PB_HAS_FAULT");
+    assert_true!(main_pump_has_fault);
+    let standby_pump_is_running: bool = test_bed.read_by_name("FUEL_PUMP_4_IS_RUNNING");
+    assert_true!(standby_pump_is_running);
+}
+
+#[test]
+fn engine_is_not_fed_when_both_feed_pumps_fail_above_suction_feed_ceiling() {
+    let mut test_bed = test_bed_with().fuel_high().in_cruise().engines_running();
+    test_bed.fail(FailureType::FuelPump(3));
+    test_bed.fail(FailureType::FuelPump(4));
+    test_bed = test_bed.and_run();
+
+    assert_false!(test_bed.engine_is_fed(2));
+}
+
+#[test]
+fn crossfeed_feeds_engine_from_another_feed_tank() {
+    let mut test_bed = test_bed_with()
+        .fuel_high()
+        .in_cruise()
+        .transfers_off()
+        .engines_running()
+        .feed_pumps_off(1)
+        .crossfeed_on(1)
+        .crossfeed_on(2)
+        .and_run();
+    let feed_one_before = test_bed.tank_mass_kg(A380FuelTankType::FeedOne);
+    let feed_two_before = test_bed.tank_mass_kg(A380FuelTankType::FeedTwo);
+
+    test_bed = test_bed.run_multiple_frames(Duration::from_secs(60));
+
+    assert_true!(test_bed.engine_is_fed(1));
+    assert!((feed_one_before - test_bed.tank_mass_kg(A380FuelTankType::FeedOne)).abs() < 1e-3);
+    assert!((feed_two_before - test_bed.tank_mass_kg(A380FuelTankType::FeedTwo) - 120.).abs() < 1.);
+}
+
+#[test]
+fn feed_tanks_are_refilled_from_inner_tanks() {
+    let mut test_bed = test_bed_with()
+        .fuel_high()
+        .in_cruise()
+        .center_of_gravity_percent(39.)
+        .engines_running()
+        .and_run();
+    let feed_two_before = test_bed.tank_mass_kg(A380FuelTankType::FeedTwo);
+    let left_inner_before = test_bed.tank_mass_kg(A380FuelTankType::LeftInner);
+
+    test_bed = test_bed.run_multiple_frames(Duration::from_secs(60));
+
+    assert!(test_bed.tank_mass_kg(A380FuelTankType::FeedTwo) > feed_two_before);
+    assert!(test_bed.tank_mass_kg(A380FuelTankType::LeftInner) < left_inner_before);
+}
+
+#[test]
+fn no_transfer_without_running_engines() {
+    let mut test_bed = test_bed_with()
+        .fuel_high()
+        .in_cruise()
+        .center_of_gravity_percent(30.)
+        .and_run();
+    let feed_two_before = test_bed.tank_mass_kg(A380FuelTankType::FeedTwo);
+    let trim_before = test_bed.tank_mass_kg(A380FuelTankType::Trim);
+
+    test_bed = test_bed.run_multiple_frames(Duration::from_secs(60));
+
+    assert!((feed_two_before - test_bed.tank_mass_kg(A380FuelTankType::FeedTwo)).abs() < 1e-3);
+    assert!((trim_before - test_bed.tank_mass_kg(A380FuelTankType::Trim)).abs() < 1e-3);
+}
+
+#[test]
+fn outer_tanks_are_transferred_to_inner_tanks_when_inner_tanks_are_low() {
+    let mut test_bed = test_bed_with()
+        .fuel_low()
+        .in_cruise()
+        .center_of_gravity_percent(39.)
+        .engines_running()
+        .and_run();
+
+    test_bed = test_bed.run_multiple_frames(Duration::from_secs(180));
+
+    assert!(test_bed.tank_mass_kg(A380FuelTankType::LeftOuter) < 1.);
+    assert!(test_bed.tank_mass_kg(A380FuelTankType::RightOuter) < 1.);
+}
+
+#[test]
+fn trim_tank_receives_fuel_when_center_of_gravity_is_forward() {
+    let mut test_bed = test_bed_with()
+        .fuel_high()
+        .in_cruise()
+        .center_of_gravity_percent(30.)
+        .engines_running()
+        .and_run();
+    let trim_before = test_bed.tank_mass_kg(A380FuelTankType::Trim);
+
+    test_bed = test_bed.run_multiple_frames(Duration::from_secs(60));
+
+    assert_eq!(test_bed.trim_transfer_mode(), 1.);
+    assert!(test_bed.tank_mass_kg(A380FuelTankType::Trim) > trim_before);
+}
+
+#[test]
+fn trim_tank_is_emptied_forward_when_center_of_gravity_is_aft() {
+    let mut test_bed = test_bed_with()
+        .fuel_high()
+        .in_cruise()
+        .center_of_gravity_percent(45.)
+        .engines_running()
+        .and_run();
+    let trim_before = test_bed.tank_mass_kg(A380FuelTankType::Trim);
+
+    test_bed = test_bed.run_multiple_frames(Duration::from_secs(60));
+
+    assert_eq!(test_bed.trim_transfer_mode(), 2.);
+    assert!(test_bed.tank_mass_kg(A380FuelTankType::Trim) < trim_before);
+}
+
+#[test]
+fn trim_tank_fuel_is_trapped_when_trim_pumps_fail() {
+    let mut test_bed = test_bed_with()
+        .fuel_high()
+        .in_cruise()
+        .center_of_gravity_percent(45.)
+        .engines_running();
+    test_bed.fail(FailureType::FuelPump(15));
+    test_bed.fail(FailureType::FuelPump(16));
+    test_bed = test_bed.and_run();
+    let trim_before = test_bed.tank_mass_kg(A380FuelTankType::Trim);
+
+    test_bed = test_bed.run_multiple_frames(Duration::from_secs(60));
+
+    assert!((trim_before - test_bed.tank_mass_kg(A380FuelTankType::Trim)).abs() < 1e-3);
+}
+
+#[test]
+fn no_trim_transfer_below_minimum_altitude() {
+    let mut test_bed = test_bed_with()
+        .fuel_high()
+        .in_cruise()
+        .at_altitude(10000.)
+        .center_of_gravity_percent(30.)
+        .engines_running()
+        .and_run();
+
+    test_bed = test_bed.run_multiple_frames(Duration::from_secs(10));
+
+    assert_eq!(test_bed.trim_transfer_mode(), 0.);
+}
+
+#[test]
+fn jettison_removes_fuel_from_transfer_tanks_in_flight() {
+    let mut test_bed = test_bed_with()
+        .fuel_high()
+        .in_cruise()
+        .jettison_armed_and_active()
+        .and_run();
+    let total_before = test_bed.total_fuel_kg();
+    let feed_one_before = test_bed.tank_mass_kg(A380FuelTankType::FeedOne);
+
+    for _ in 0..10 {
+        test_bed.run_with_delta(Duration::from_secs(1));
+    }
+
+    assert!((total_before - test_bed.total_fuel_kg() - 400.).abs() < 5.);
+    assert!((feed_one_before - test_bed.tank_mass_kg(A380FuelTankType::FeedOne)).abs() < 1e-3);
+}
+
+#[test]
+fn jettison_is_inhibited_on_ground() {
+    let mut test_bed = test_bed_with().fuel_high().jettison_armed_and_active();
+    test_bed.set_on_ground(true);
+    test_bed = test_bed.and_run();
+    let total_before = test_bed.total_fuel_kg();
+
+    test_bed = test_bed.run_multiple_frames(Duration::from_secs(10));
+
+    assert!((total_before - test_bed.total_fuel_kg()).abs() < 1e-3);
+}
@@ -0,0 +1,589 @@
+use systems::{
+    accept_iterable,
+    electrical::CircuitBreaker,
+    fuel::{FuelPump, FuelSystem, FuelValve},
+    shared::{ElectricalBusType, EngineFuelFlow},
+    simulation::{
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+    },
+};
+use uom::si::{
+    electric_current::ampere,
+    f64::{ElectricCurrent, Length, Mass, Ratio},
+    length::foot,
+    mass::kilogram,
+    mass_rate::kilogram_per_second,
+    ratio::{percent, ratio},
+};
+
+use super::{A380FuelOverheadPanel, A380FuelTankType};
+
+/// Each fuel pump is protected by a circuit breaker identified by its pump number.
+const PUMP_CIRCUIT_BREAKERS: [&str; 16] = [
+    "FUEL_PUMP_1",
+    "FUEL_PUMP_2",
+    "FUEL_PUMP_3",
+    "FUEL_PUMP_4",
+    "FUEL_PUMP_5",
+    "FUEL_PUMP_6",
+    "FUEL_PUMP_7",
+    "FUEL_PUMP_8",
+    "FUEL_PUMP_9",
+    "FUEL_PUMP_10",
+    "FUEL_PUMP_11",
+    "FUEL_PUMP_12",
+    "FUEL_PUMP_13",
+    "FUEL_PUMP_14",
+    "FUEL_PUMP_15",
+    "FUEL_PUMP_16",
+];
+const PUMP_RATED_CURRENT_AMPERE: f64 = 15.;
+
+fn new_pump(
+    context: &mut InitContext,
+    number: usize,
+    powered_by: ElectricalBusType,
+) -> CircuitBreaker<FuelPump> {
+    let pump = FuelPump::new(context, number, powered_by);
+
+    CircuitBreaker::new(
+        context,
+        PUMP_CIRCUIT_BREAKERS[number - 1],
+        powered_by,
+        ElectricCurrent::new::<ampere>(PUMP_RATED_CURRENT_AMPERE),
+        pump,
+    )
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum TrimTransferMode {
+    Off,
+    Aft,
+    Forward,
+}
+
+/// A feed tank with its main and standby pumps and the crossfeed valve connecting
+/// it to the crossfeed gallery.
+struct FeedTank {
+    tank: A380FuelTankType,
+    main_pump: CircuitBreaker<FuelPump>,
+    standby_pump: CircuitBreaker<FuelPump>,
+    crossfeed_valve: FuelValve,
+}
+impl FeedTank {
+    fn new(
+        context: &mut InitContext,
+        number: usize,
+        tank: A380FuelTankType,
+        main_pump_powered_by: ElectricalBusType,
+        standby_pump_powered_by: ElectricalBusType,
+        crossfeed_valve_powered_by: ElectricalBusType,
+    ) -> Self {
+        Self {
+            tank,
+            main_pump: new_pump(context, 2 * number - 1, main_pump_powered_by),
+            standby_pump: new_pump(context, 2 * number, standby_pump_powered_by),
+            crossfeed_valve: FuelValve::new(context, number, crossfeed_valve_powered_by),
+        }
+    }
+
+    fn update(&mut self, main_pump_is_on: bool, standby_pump_is_on: bool, crossfeed_open: bool) {
+        self.main_pump.element_mut().update(main_pump_is_on);
+        // The standby pump takes over automatically when the main pump doesn't run.
+        self.standby_pump
+            .element_mut()
+            .update(standby_pump_is_on && !self.main_pump.element().is_running());
+        self.crossfeed_valve.update(crossfeed_open);
+    }
+
+    fn is_pressurised(&self, fuel_system: &FuelSystem<11>) -> bool {
+        (self.main_pump.element().is_running() || self.standby_pump.element().is_running())
+            && fuel_system.tank_has_fuel(self.tank as usize)
+    }
+
+    fn main_pump_is_running(&self) -> bool {
+        self.main_pump.element().is_running()
+    }
+
+    fn crossfeed_is_open(&self) -> bool {
+        self.crossfeed_valve.is_open()
+    }
+}
+impl SimulationElement for FeedTank {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.main_pump.accept(visitor);
+        self.standby_pump.accept(visitor);
+        self.crossfeed_valve.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
+/// A transfer tank with the pump which moves its fuel to other tanks.
+struct TransferTank {
+    tank: A380FuelTankType,
+    pump: CircuitBreaker<FuelPump>,
+}
+impl TransferTank {
+    fn new(
+        context: &mut InitContext,
+        pump_number: usize,
+        tank: A380FuelTankType,
+        powered_by: ElectricalBusType,
+    ) -> Self {
+        Self {
+            tank,
+            pump: new_pump(context, pump_number, powered_by),
+        }
+    }
+
+    fn update(&mut self, fuel_system: &FuelSystem<11>, transfer_is_auto: bool) {
+        self.pump
+            .element_mut()
+            .update(transfer_is_auto && fuel_system.tank_has_fuel(self.tank as usize));
+    }
+
+    fn can_transfer(&self) -> bool {
+        self.pump.element().is_running()
+    }
+}
+impl SimulationElement for TransferTank {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.pump.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
+/// Feeds the engines from the feed tanks and transfers fuel between the tanks in flight:
+/// inner, mid and trim tanks top up the feed tanks, the outer tanks are emptied into the
+/// inner tanks once those run low, the trim tank is used to keep the CG near its target and
+/// fuel can be jettisoned from the transfer tanks.
+pub struct TransferApplication {
+    engine_is_fed_ids: [VariableIdentifier; 4],
+    trim_transfer_mode_id: VariableIdentifier,
+    center_of_gravity_id: VariableIdentifier,
+
+    feed_tanks: [FeedTank; 4],
+    left_transfer_tanks: [TransferTank; 3],
+    right_transfer_tanks: [TransferTank; 3],
+    trim_tank_pumps: [CircuitBreaker<FuelPump>; 2],
+    jettison_valve: FuelValve,
+
+    engine_is_fed: [bool; 4],
+    feed_tank_is_refilling: [bool; 4],
+    outer_tank_transfer_is_active: [bool; 2],
+    trim_transfer_mode: TrimTransferMode,
+    center_of_gravity: Ratio,
+}
+impl TransferApplication {
+    const TRANSFER_RATE_KG_PER_SECOND: f64 = 8.;
+    const TRIM_TRANSFER_RATE_KG_PER_SECOND: f64 = 4.;
+    const JETTISON_RATE_KG_PER_SECOND: f64 = 40.;
+
+    const FEED_TANK_REFILL_START_RATIO: f64 = 0.85;
+    const FEED_TANK_REFILL_STOP_RATIO: f64 = 0.95;
+    const INNER_TANK_LOW_LEVEL_KG: f64 = 4000.;
+
+    const SUCTION_FEED_CEILING_FEET: f64 = 20000.;
+
+    const TRIM_TRANSFER_MIN_ALTITUDE_FEET: f64 = 25500.;
+    const TARGET_CENTER_OF_GRAVITY_PERCENT: f64 = 39.;
+    const AFT_TRANSFER_CENTER_OF_GRAVITY_MARGIN_PERCENT: f64 = 1.;
+    const FORWARD_TRANSFER_CENTER_OF_GRAVITY_MARGIN_PERCENT: f64 = 0.5;
+
+    pub fn new(context: &mut InitContext) -> Self {
+        Self {
+            engine_is_fed_ids: [1, 2, 3, 4]
+                .map(|n| context.get_identifier(format!("FUEL_ENGINE_{}_IS_FED", n))),
+            trim_transfer_mode_id: context
+                .get_identifier("FUEL_TRIM_TANK_TRANSFER_MODE".to_owned()),
+            center_of_gravity_id: context.get_identifier("CG PERCENT".to_owned()),
+
+            feed_tanks: [
+                FeedTank::new(
+                    context,
+                    1,
+                    A380FuelTankType::FeedOne,
+                    ElectricalBusType::AlternatingCurrent(1),
+                    ElectricalBusType::AlternatingCurrent(2),
+                    ElectricalBusType::DirectCurrent(1),
+                ),
+                FeedTank::new(
+                    context,
+                    2,
+                    A380FuelTankType::FeedTwo,
+                    ElectricalBusType::AlternatingCurrent(2),
+                    ElectricalBusType::AlternatingCurrent(1),
+                    ElectricalBusType::DirectCurrent(1),
+                ),
+                FeedTank::new(
+                    context,
+                    3,
+                    A380FuelTankType::FeedThree,
+                    ElectricalBusType::AlternatingCurrent(3),
+                    ElectricalBusType::AlternatingCurrent(4),
+                    ElectricalBusType::DirectCurrent(2),
+                ),
+                FeedTank::new(
+                    context,
+                    4,
+                    A380FuelTankType::FeedFour,
+                    ElectricalBusType::AlternatingCurrent(4),
+                    ElectricalBusType::AlternatingCurrent(3),
+                    ElectricalBusType::DirectCurrent(2),
+                ),
+            ],
+            left_transfer_tanks: [
+                TransferTank::new(
+                    context,
+                    9,
+                    A380FuelTankType::LeftOuter,
+                    ElectricalBusType::AlternatingCurrent(1),
+                ),
+                TransferTank::new(
+                    context,
+                    10,
+                    A380FuelTankType::LeftMid,
+                    ElectricalBusType::AlternatingCurrent(2),
+                ),
+                TransferTank::new(
+                    context,
+                    11,
+                    A380FuelTankType::LeftInner,
+                    ElectricalBusType::AlternatingCurrent(1),
+                ),
+            ],
+            right_transfer_tanks: [
+                TransferTank::new(
+                    context,
+                    14,
+                    A380FuelTankType::RightOuter,
+                    ElectricalBusType::AlternatingCurrent(4),
+                ),
+                TransferTank::new(
+                    context,
+                    13,
+                    A380FuelTankType::RightMid,
+                    ElectricalBusType::AlternatingCurrent(3),
+                ),
+                TransferTank::new(
+                    context,
+                    12,
+                    A380FuelTankType::RightInner,
+                    ElectricalBusType::AlternatingCurrent(4),
+                ),
+            ],
+            trim_tank_pumps: [
+                new_pump(context, 15, ElectricalBusType::AlternatingCurrent(2)),
+                new_pump(context, 16, ElectricalBusType::AlternatingCurrent(3)),
+            ],
+            jettison_valve: FuelValve::new(context, 5, ElectricalBusType::DirectCurrentEssential),
+
+            // Engines are updated before the first fuel update, so assume they are fed until then.
+            engine_is_fed: [true; 4],
+            feed_tank_is_refilling: [false; 4],
+            outer_tank_transfer_is_active: [false; 2],
+            trim_transfer_mode: TrimTransferMode::Off,
+            center_of_gravity: Ratio::default(),
+        }
+    }
+
+    pub fn update<T: EngineFuelFlow>(
+        &mut self,
+        context: &UpdateContext,
+        fuel_system: &mut FuelSystem<11>,
+        overhead: &A380FuelOverheadPanel,
+        engines: [&T; 4],
+        transfer_is_inhibited: bool,
+    ) {
+        self.update_pumps_and_valves(fuel_system, overhead);
+        self.feed_engines(context, fuel_system, engines);
+
+        let any_engine_is_running = engines
+            .iter()
+            .any(|engine| engine.fuel_flow().get::<kilogram_per_second>() > 0.);
+        if transfer_is_inhibited || !any_engine_is_running {
+            self.feed_tank_is_refilling = [false; 4];
+            self.outer_tank_transfer_is_active = [false; 2];
+            self.trim_transfer_mode = TrimTransferMode::Off;
+        } else {
+            self.refill_feed_tanks(context, fuel_system);
+            self.transfer_outer_tanks(context, fuel_system);
+            self.transfer_trim_tank(context, fuel_system, overhead);
+        }
+
+        if self.jettison_valve.is_open() && !context.is_on_ground() {
+            self.jettison(context, fuel_system);
+        }
+    }
+
+    fn update_pumps_and_valves(
+        &mut self,
+        fuel_system: &FuelSystem<11>,
+        overhead: &A380FuelOverheadPanel,
+    ) {
+        for (i, feed_tank) in self.feed_tanks.iter_mut().enumerate() {
+            feed_tank.update(
+                overhead.feed_main_pump_is_on(i + 1),
+                overhead.feed_standby_pump_is_on(i + 1),
+                overhead.crossfeed_is_on(i + 1),
+            );
+        }
+
+        for transfer_tanks in [
+            &mut self.left_transfer_tanks,
+            &mut self.right_transfer_tanks,
+        ] {
+            let [outer, mid, inner] = transfer_tanks;
+            outer.update(fuel_system, overhead.outer_tank_transfer_is_auto());
+            mid.update(fuel_system, overhead.mid_tank_transfer_is_auto());
+            inner.update(fuel_system, overhead.inner_tank_transfer_is_auto());
+        }
+
+        let trim_tank_has_fuel = fuel_system.tank_has_fuel(A380FuelTankType::Trim as usize);
+        self.trim_tank_pumps.iter_mut().for_each(|pump| {
+            pump.element_mut()
+                .update(overhead.trim_tank_transfer_is_auto() && trim_tank_has_fuel)
+        });
+
+        self.jettison_valve.update(overhead.jettison_is_active());
+    }
+
+    fn feed_engines<T: EngineFuelFlow>(
+        &mut self,
+        context: &UpdateContext,
+        fuel_system: &mut FuelSystem<11>,
+        engines: [&T; 4],
+    ) {
+        let is_pressurised: Vec<bool> = self
+            .feed_tanks
+            .iter()
+            .map(|feed_tank| feed_tank.is_pressurised(fuel_system))
+            .collect();
+        let suction_feed_is_possible =
+            context.pressure_altitude() < Length::new::<foot>(Self::SUCTION_FEED_CEILING_FEET);
+
+        for (i, engine) in engines.iter().enumerate() {
+            let source = if is_pressurised[i] {
+                Some(self.feed_tanks[i].tank)
+            } else if self.feed_tanks[i].crossfeed_is_open() {
+                self.feed_tanks
+                    .iter()
+                    .zip(&is_pressurised)
+                    .find(|(feed_tank, &pressurised)| pressurised && feed_tank.crossfeed_is_open())
+                    .map(|(feed_tank, _)| feed_tank.tank)
+            } else {
+                None
+            }
+            .or_else(|| {
+                let tank = self.feed_tanks[i].tank;
+                (suction_feed_is_possible && fuel_system.tank_has_fuel(tank as usize))
+                    .then_some(tank)
+            });
+
+            self.engine_is_fed[i] = source.is_some();
+            if let Some(tank) = source {
+                fuel_system.consume(tank as usize, engine.fuel_flow() * context.delta_as_time());
+            }
+        }
+    }
+
+    fn refill_feed_tanks(&mut self, context: &UpdateContext, fuel_system: &mut FuelSystem<11>) {
+        let transfer =
+            Mass::new::<kilogram>(Self::TRANSFER_RATE_KG_PER_SECOND * context.delta_as_secs_f64());
+
+        for (i, feed_tank) in self.feed_tanks.iter().enumerate() {
+            let tank = feed_tank.tank as usize;
+            let fill_ratio =
+                (fuel_system.tank_mass(tank) / fuel_system.tank_capacity(tank)).get::<ratio>();
+
+            if fill_ratio < Self::FEED_TANK_REFILL_START_RATIO {
+                self.feed_tank_is_refilling[i] = true;
+            } else if fill_ratio >= Self::FEED_TANK_REFILL_STOP_RATIO {
+                self.feed_tank_is_refilling[i] = false;
+            }
+
+            if !self.feed_tank_is_refilling[i] {
+                continue;
+            }
+
+            // Feed tanks 1 and 2 are refilled from the left wing, 3 and 4 from the right wing.
+            let [_, mid, inner] = if i < 2 {
+                &self.left_transfer_tanks
+            } else {
+                &self.right_transfer_tanks
+            };
+            let source = [inner, mid]
+                .iter()
+                .find(|transfer_tank| transfer_tank.can_transfer())
+                .map(|transfer_tank| transfer_tank.tank)
+                .or_else(|| {
+                    self.trim_tank_pumps
+                        .iter()
+                        .any(|pump| pump.element().is_running())
+                        .then_some(A380FuelTankType::Trim)
+                });
+
+            if let Some(source) = source {
+                fuel_system.transfer(source as usize, tank, transfer);
+            }
+        }
+    }
+
+    fn transfer_outer_tanks(&mut self, context: &UpdateContext, fuel_system: &mut FuelSystem<11>) {
+        if context.is_on_ground() {
+            return;
+        }
+
+        let transfer =
+            Mass::new::<kilogram>(Self::TRANSFER_RATE_KG_PER_SECOND * context.delta_as_secs_f64());
+
+        for (i, [outer, mid, inner]) in [&self.left_transfer_tanks, &self.right_transfer_tanks]
+            .into_iter()
+            .enumerate()
+        {
+            let inner_is_low = fuel_system.tank_mass(inner.tank as usize)
+                < Mass::new::<kilogram>(Self::INNER_TANK_LOW_LEVEL_KG);
+            if inner_is_low && !fuel_system.tank_has_fuel(mid.tank as usize) {
+                self.outer_tank_transfer_is_active[i] = true;
+            } else if !fuel_system.tank_has_fuel(outer.tank as usize) {
+                self.outer_tank_transfer_is_active[i] = false;
+            }
+
+            if self.outer_tank_transfer_is_active[i] && outer.can_transfer() {
+                fuel_system.transfer(outer.tank as usize, inner.tank as usize, transfer);
+            }
+        }
+    }
+
+    fn transfer_trim_tank(
+        &mut self,
+        context: &UpdateContext,
+        fuel_system: &mut FuelSystem<11>,
+        overhead: &A380FuelOverheadPanel,
+    ) {
+        let cg_percent = self.center_of_gravity.get::<percent>();
+        let trim_tank = A380FuelTankType::Trim as usize;
+
+        self.trim_transfer_mode = if context.is_on_ground()
+            || !overhead.trim_tank_transfer_is_auto()
+            || context.pressure_altitude()
+                < Length::new::<foot>(Self::TRIM_TRANSFER_MIN_ALTITUDE_FEET)
+        {
+            TrimTransferMode::Off
+        } else if cg_percent
+            < Self::TARGET_CENTER_OF_GRAVITY_PERCENT
+                - Self::AFT_TRANSFER_CENTER_OF_GRAVITY_MARGIN_PERCENT
+            && fuel_system.tank_mass(trim_tank) < fuel_system.tank_capacity(trim_tank)
+        {
+            TrimTransferMode::Aft
+        } else if cg_percent
+            > Self::TARGET_CENTER_OF_GRAVITY_PERCENT
+                + Self::FORWARD_TRANSFER_CENTER_OF_GRAVITY_MARGIN_PERCENT
+        {
+            TrimTransferMode::Forward
+        } else if self.trim_transfer_mode == TrimTransferMode::Aft
+            && cg_percent < Self::TARGET_CENTER_OF_GRAVITY_PERCENT
+        {
+            TrimTransferMode::Aft
+        } else if self.trim_transfer_mode == TrimTransferMode::Forward
+            && cg_percent > Self::TARGET_CENTER_OF_GRAVITY_PERCENT
+        {
+            TrimTransferMode::Forward
+        } else {
+            TrimTransferMode::Off
+        };
+
+        let transfer = Mass::new::<kilogram>(
+            Self::TRIM_TRANSFER_RATE_KG_PER_SECOND * context.delta_as_secs_f64(),
+        );
+        match self.trim_transfer_mode {
+            TrimTransferMode::Aft => {
+                for [_, _, inner] in [&self.left_transfer_tanks, &self.right_transfer_tanks] {
+                    if inner.can_transfer() {
+                        fuel_system.transfer(inner.tank as usize, trim_tank, transfer / 2.);
+                    }
+                }
+            }
+            TrimTransferMode::Forward => {
+                let running_pumps = self
+                    .trim_tank_pumps
+                    .iter()
+                    .filter(|pump| pump.element().is_running())
+                    .count() as f64;
+                let destinations = [A380FuelTankType::LeftInner, A380FuelTankType::RightInner];
+                for destination in destinations {
+                    fuel_system.transfer(
+                        trim_tank,
+                        destination as usize,
+                        transfer * running_pumps / destinations.len() as f64,
+                    );
+                }
+            }
+            TrimTransferMode::Off => {}
+        }
+    }
+
+    fn jettison(&mut self, context: &UpdateContext, fuel_system: &mut FuelSystem<11>) {
+        let mut sources: Vec<A380FuelTankType> = self
+            .left_transfer_tanks
+            .iter()
+            .chain(self.right_transfer_tanks.iter())
+            .filter(|transfer_tank| transfer_tank.can_transfer())
+            .map(|transfer_tank| transfer_tank.tank)
+            .collect();
+        if self
+            .trim_tank_pumps
+            .iter()
+            .any(|pump| pump.element().is_running())
+        {
+            sources.push(A380FuelTankType::Trim);
+        }
+
+        if sources.is_empty() {
+            return;
+        }
+
+        let jettisoned_per_tank =
+            Mass::new::<kilogram>(Self::JETTISON_RATE_KG_PER_SECOND * context.delta_as_secs_f64())
+                / sources.len() as f64;
+        for tank in sources {
+            fuel_system.consume(tank as usize, jettisoned_per_tank);
+        }
+    }
+
+    pub fn engine_is_fed(&self, engine_number: usize) -> bool {
+        self.engine_is_fed[engine_number - 1]
+    }
+
+    pub fn feed_main_pump_is_running(&self, feed_tank_number: usize) -> bool {
+        self.feed_tanks[feed_tank_number - 1].main_pump_is_running()
+    }
+}
+impl SimulationElement for TransferApplication {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.feed_tanks, visitor);
+        accept_iterable!(self.left_transfer_tanks, visitor);
+        accept_iterable!(self.right_transfer_tanks, visitor);
+        accept_iterable!(self.trim_tank_pumps, visitor);
+        self.jettison_valve.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.center_of_gravity = reader.read(&self.center_of_gravity_id);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        for (id, is_fed) in self.engine_is_fed_ids.iter().zip(self.engine_is_fed) {
+            writer.write(id, is_fed);
+        }
+        writer.write(
+            &self.trim_transfer_mode_id,
+            self.trim_transfer_mode as u8 as f64,
+        );
+    }
+}
@@ -731,6 +731,7 @@ mod tests {
         heading: Arinc429Word<Angle>,
         vertical_speed: Arinc429Word<Velocity>,
         altitude: Arinc429Word<Length>,
+        computed_airspeed: Arinc429Word<Velocity>,
         angle_of_attack: Arinc429Word<Angle>,
     }
     impl TestAdirs {
@@ -742,6 +743,10 @@ mod tests {
                 heading: Arinc429Word::new(Angle::default(), SignStatus::FailureWarning),
                 vertical_speed: Arinc429Word::new(Velocity::default(), SignStatus::FailureWarning),
                 altitude: Arinc429Word::new(Length::default(), SignStatus::FailureWarning),
+                computed_airspeed: Arinc429Word::new(
+                    Velocity::default(),
+                    SignStatus::FailureWarning,
+                ),
                 angle_of_attack: Arinc429Word::new(Angle::default(), SignStatus::NormalOperation),
             }
         }
@@ -779,6 +784,10 @@ mod tests {
             self.altitude
         }
 
+        fn computed_airspeed(&self, _adiru_number: usize) -> Arinc429Word<Velocity> {
+            self.computed_airspeed
+        }
+
         fn angle_of_attack(&self, _adiru_number: usize) -> Arinc429Word<Angle> {
             self.angle_of_attack
         }
@@ -17,10 +17,12 @@ use uom::si::{
 
 use systems::{
     accept_iterable,
+    aircraft_configuration::BrakeTemperatureModel,
     engine::Engine,
     hydraulic::{
         aerodynamic_model::AerodynamicModel,
         brake_circuit::{BrakeAccumulatorCharacteristics, BrakeCircuit, BrakeCircuitController},
+        brake_thermal::{BrakeThermalConstants, BrakeThermalModel},
         bypass_pin::BypassPin,
         cargo_doors::{CargoDoor, HydraulicDoorController},
         flap_slat::FlapSlatAssembly,
@@ -43,7 +45,10 @@ use systems::{
         HydraulicCircuitController, HydraulicPressureSensors, ManualPump, PressureSwitch,
         PressureSwitchType, PriorityValve, PumpController, Reservoir,
     },
-    landing_gear::{GearSystemSensors, LandingGearControlInterfaceUnitSet, TiltingGear},
+    landing_gear::{
+        tyre::{TyreConstants, TyreDrag, TyreModel, TyrePressureIndicatingSystem},
+        GearSystemSensors, LandingGearControlInterfaceUnitSet, TiltingGear,
+    },
     overhead::{AutoOffFaultPushButton, AutoOnFaultPushButton},
     shared::{
         interpolation, random_from_range, update_iterator::MaxStepLoop, AdirsDiscreteOutputs,
@@ -1601,6 +1606,10 @@ pub(super) struct A380Hydraulic {
     braking_circuit_norm: BrakeCircuit,
     braking_circuit_altn: BrakeCircuit,
     braking_force: A380BrakingForce,
+    brake_temperatures: BrakeThermalModel<A380BrakeThermalConstants, 16>,
+    tyres: TyreModel<A380TyreConstants, 22>,
+    tpis: TyrePressureIndicatingSystem<22>,
+    tyre_drag: TyreDrag<22>,
 
     flap_system: FlapSlatAssembly,
     slat_system: FlapSlatAssembly,
@@ -1671,11 +1680,28 @@ impl A380Hydraulic {
 
     const EDP_CONTROL_POWER_BUS1: ElectricalBusType = ElectricalBusType::DirectCurrentEssential;
 
+    const BRAKE_FAN_POWER_BUS: ElectricalBusType = ElectricalBusType::AlternatingCurrent(2);
+    const BRAKE_FAN_RATED_CURRENT_AMPERE: f64 = 10.;
+    const TPIS_POWER_BUS: ElectricalBusType = ElectricalBusType::DirectCurrent(1);
+
+    // Tyres 1 to 8 are on the wing gears, 9 to 20 on the body gears and 21 and 22 on the nose
+    // gear. Tyres 17 to 20 are on the steered rear axles of the body gears.
+    const TYRE_LATERAL_POSITIONS_METERS: [f64; 22] = [
+        -6.9, -5.3, 5.3, 6.9, -6.9, -5.3, 5.3, 6.9, -3.3, -1.9, 1.9, 3.3, -3.3, -1.9, 1.9, 3.3,
+        -3.3, -1.9, 1.9, 3.3, -0.3, 0.3,
+    ];
+    const LEFT_BODY_STEERING_TYRES: [usize; 2] = [16, 17];
+    const RIGHT_BODY_STEERING_TYRES: [usize; 2] = [18, 19];
+    const NOSE_TYRES: [usize; 2] = [20, 21];
+
     const ALTERNATE_BRAKE_ACCUMULATOR_GAS_PRE_CHARGE: f64 = 1000.0; // Nitrogen PSI
                                                                     // Refresh rate of core hydraulic simulation
     const HYDRAULIC_SIM_TIME_STEP: Duration = Duration::from_millis(10);
 
-    pub fn new(context: &mut InitContext) -> A380Hydraulic {
+    pub fn new(
+        context: &mut InitContext,
+        brake_temperature_model: BrakeTemperatureModel,
+    ) -> A380Hydraulic {
         let brake_accumulator_charac = BrakeAccumulatorCharacteristics::new(
             Volume::new::<gallon>(1.0),
             Pressure::new::<psi>(Self::ALTERNATE_BRAKE_ACCUMULATOR_GAS_PRE_CHARGE),
@@ -1894,6 +1920,18 @@ impl A380Hydraulic {
             ),
 
             braking_force: A380BrakingForce::new(context),
+            brake_temperatures: BrakeThermalModel::new(
+                context,
+                Self::BRAKE_FAN_POWER_BUS,
+                ElectricCurrent::new::<ampere>(Self::BRAKE_FAN_RATED_CURRENT_AMPERE),
+                brake_temperature_model == BrakeTemperatureModel::Native,
+            ),
+            tyres: TyreModel::new(context),
+            tpis: TyrePressureIndicatingSystem::new(context, Self::TPIS_POWER_BUS),
+            tyre_drag: TyreDrag::new(
+                context,
+                Self::TYRE_LATERAL_POSITIONS_METERS.map(Length::new::<meter>),
+            ),
 
             flap_system: FlapSlatAssembly::new(
                 context,
@@ -2100,6 +2138,11 @@ impl A380Hydraulic {
         self.bypass_pin.is_nose_wheel_steering_pin_inserted()
     }
 
+    /// Steering efficiency of the tyres of a steered axle.
+    fn steering_efficiency(&self, tyres: [usize; 2]) -> Ratio {
+        (self.tyres.steering_efficiency(tyres[0]) + self.tyres.steering_efficiency(tyres[1])) / 2.
+    }
+
     #[cfg(test)]
     fn is_green_pressure_switch_pressurised(&self) -> bool {
         self.green_circuit.system_section_pressure_switch() == PressureSwitchState::Pressurised
@@ -2291,6 +2334,13 @@ impl A380Hydraulic {
 
         self.tilting_gears.update(context);
 
+        self.nose_steering
+            .set_authority(self.steering_efficiency(Self::NOSE_TYRES));
+        self.body_wheel_steering_left
+            .set_authority(self.steering_efficiency(Self::LEFT_BODY_STEERING_TYRES));
+        self.body_wheel_steering_right
+            .set_authority(self.steering_efficiency(Self::RIGHT_BODY_STEERING_TYRES));
+
         self.nose_steering.update(
             context,
             self.yellow_circuit.system_section(),
@@ -2341,6 +2391,27 @@ impl A380Hydraulic {
             engine2,
             &self.bypass_pin,
         );
+        self.braking_force
+            .correct_with_brake_temperatures(&self.brake_temperatures);
+        self.braking_force.correct_with_tyres(&self.tyres);
+        self.brake_temperatures
+            .update(context, self.braking_force.wheel_braking_forces(), lgciu1);
+        self.tyres.update(
+            context,
+            std::array::from_fn(|wheel| {
+                if wheel < A380BrakingForce::BRAKED_WHEELS {
+                    self.brake_temperatures.temperature(wheel)
+                } else {
+                    context.ambient_temperature()
+                }
+            }),
+            std::array::from_fn(|wheel| {
+                wheel < A380BrakingForce::BRAKED_WHEELS
+                    && self.brake_temperatures.fuse_plug_melted(wheel)
+            }),
+        );
+        self.tpis.update(&self.tyres);
+        self.tyre_drag.update(context, &self.tyres);
 
         self.slats_flaps_complex
             .update(context, adirs, &self.flap_system, &self.slat_system);
@@ -3027,6 +3098,10 @@ impl SimulationElement for A380Hydraulic {
         self.braking_circuit_norm.accept(visitor);
         self.braking_circuit_altn.accept(visitor);
         self.braking_force.accept(visitor);
+        self.brake_temperatures.accept(visitor);
+        self.tyres.accept(visitor);
+        self.tpis.accept(visitor);
+        self.tyre_drag.accept(visitor);
 
         self.nose_steering.accept(visitor);
         self.body_wheel_steering_left.accept(visitor);
@@ -4452,6 +4527,34 @@ impl SteeringController for A380HydraulicBrakeSteerComputerUnit {
     }
 }
 
+struct A380BrakeThermalConstants;
+impl BrakeThermalConstants for A380BrakeThermalConstants {
+    const HEAT_SINK_MASS_KG: f64 = 95.;
+    const HEAT_SINK_SPECIFIC_HEAT_J_PER_KG_PER_KELVIN: f64 = 1420.;
+
+    const MAX_BRAKING_DECELERATION_METER_PER_SECOND_SQUARED: f64 = 5.;
+
+    const NATURAL_CONVECTION_WATT_PER_KELVIN: f64 = 25.;
+    const AIRFLOW_CONVECTION_WATT_PER_KELVIN_PER_METER_PER_SECOND: f64 = 2.;
+    const GEAR_BAY_CONVECTION_FACTOR: f64 = 0.5;
+    // No brake fans are installed, the push button is never pressed
+    const BRAKE_FAN_CONVECTION_WATT_PER_KELVIN: f64 = 0.;
+    const BRAKE_FAN_POWER_WATT: f64 = 0.;
+
+    const BRAKES_HOT_TEMPERATURE_DEGREE_CELSIUS: f64 = 300.;
+    const FUSE_PLUG_MELT_TEMPERATURE_DEGREE_CELSIUS: f64 = 650.;
+
+    const EFFICIENCY_TEMPERATURE_BREAKPOINTS_DEGREE_CELSIUS: &'static [f64] = &[600., 900., 1200.];
+    const EFFICIENCY_RATIO: &'static [f64] = &[1., 0.8, 0.5];
+}
+
+struct A380TyreConstants;
+impl TyreConstants for A380TyreConstants {
+    const NOMINAL_COLD_PRESSURE_PSI: f64 = 220.;
+    const TREAD_LIFE_METERS: f64 = 2_000_000.;
+    const LOW_PRESSURE_RATIO: f64 = 0.88;
+}
+
 struct A380BrakingForce {
     brake_left_force_factor_id: VariableIdentifier,
     brake_right_force_factor_id: VariableIdentifier,
@@ -4472,6 +4575,12 @@ struct A380BrakingForce {
 impl A380BrakingForce {
     const REFERENCE_PRESSURE_FOR_MAX_FORCE: f64 = 2538.;
 
+    // Wheels 1 to 16 of the wing and body gears are braked, the rear axles of the body gears
+    // (wheels 17 to 20) are not. Wheels are numbered by pairs alternating from the left to the
+    // right gear: wheels 1 and 2 are on the left wing gear, 3 and 4 on the right one, and so on.
+    const BRAKED_WHEELS: usize = 16;
+    const BRAKED_WHEELS_PER_SIDE: f64 = 8.;
+
     const FLAPS_BREAKPOINTS: [f64; 3] = [0., 50., 100.];
     const FLAPS_PENALTY_PERCENT: [f64; 3] = [5., 5., 0.];
 
@@ -4531,6 +4640,52 @@ impl A380BrakingForce {
         self.update_chocks_braking(context, engine1, engine2, bypass_pin);
     }
 
+    fn is_left_wheel(wheel_index: usize) -> bool {
+        wheel_index % 4 < 2
+    }
+
+    /// Braking force applied on each braked wheel.
+    fn wheel_braking_forces(&self) -> [Ratio; 16] {
+        std::array::from_fn(|wheel| {
+            Ratio::new::<ratio>(if Self::is_left_wheel(wheel) {
+                self.left_braking_force
+            } else {
+                self.right_braking_force
+            })
+        })
+    }
+
+    /// Hot brakes fade and deliver less force for the same brake pressure.
+    fn correct_with_brake_temperatures(
+        &mut self,
+        brake_temperatures: &BrakeThermalModel<A380BrakeThermalConstants, 16>,
+    ) {
+        let side_efficiency = |left: bool| {
+            (0..Self::BRAKED_WHEELS)
+                .filter(|&wheel| Self::is_left_wheel(wheel) == left)
+                .map(|wheel| brake_temperatures.braking_efficiency(wheel).get::<ratio>())
+                .sum::<f64>()
+                / Self::BRAKED_WHEELS_PER_SIDE
+        };
+
+        self.left_braking_force *= side_efficiency(true);
+        self.right_braking_force *= side_efficiency(false);
+    }
+
+    /// Deflated and worn tyres of braked wheels transmit less braking force.
+    fn correct_with_tyres(&mut self, tyres: &TyreModel<A380TyreConstants, 22>) {
+        let side_efficiency = |left: bool| {
+            (0..Self::BRAKED_WHEELS)
+                .filter(|&wheel| Self::is_left_wheel(wheel) == left)
+                .map(|wheel| tyres.braking_efficiency(wheel).get::<ratio>())
+                .sum::<f64>()
+                / Self::BRAKED_WHEELS_PER_SIDE
+        };
+
+        self.left_braking_force *= side_efficiency(true);
+        self.right_braking_force *= side_efficiency(false);
+    }
+
     fn correct_with_flaps_state(&mut self, context: &UpdateContext) {
         let flap_correction = Ratio::new::<percent>(interpolation(
             &Self::FLAPS_BREAKPOINTS,
@@ -6862,7 +7017,9 @@ mod tests {
             angle::degree,
             electric_potential::volt,
             length::foot,
+            mass::kilogram,
             ratio::{percent, ratio},
+            velocity::foot_per_second,
         };
 
         struct A380TestAdirus {
@@ -6925,6 +7082,9 @@ mod tests {
             fn altitude(&self, _adiru_number: usize) -> Arinc429Word<Length> {
                 Arinc429Word::new(Length::default(), SignStatus::NormalOperation)
             }
+            fn computed_airspeed(&self, _adiru_number: usize) -> Arinc429Word<Velocity> {
+                Arinc429Word::new(Velocity::default(), SignStatus::NormalOperation)
+            }
             fn angle_of_attack(&self, _adiru_number: usize) -> Arinc429Word<Angle> {
                 Arinc429Word::new(Angle::default(), SignStatus::NormalOperation)
             }
@@ -7044,7 +7204,7 @@ mod tests {
                     engine_2: TrentEngine::new(context, 2),
                     engine_3: TrentEngine::new(context, 3),
                     engine_4: TrentEngine::new(context, 4),
-                    hydraulics: A380Hydraulic::new(context),
+                    hydraulics: A380Hydraulic::new(context, BrakeTemperatureModel::Native),
                     overhead: A380HydraulicOverheadPanel::new(context),
                     autobrake_panel: A380AutobrakePanel::new(context),
                     engine_fire_overhead: EngineFireOverheadPanel::new(context),
@@ -7475,6 +7635,26 @@ mod tests {
                 .into()
             }
 
+            fn get_brake_left_force_factor(&mut self) -> f64 {
+                self.read_by_name("BRAKE LEFT FORCE FACTOR")
+            }
+
+            fn get_brake_right_force_factor(&mut self) -> f64 {
+                self.read_by_name("BRAKE RIGHT FORCE FACTOR")
+            }
+
+            fn get_tyre_drag_delta_speed(&mut self) -> f64 {
+                self.read_by_name("TYRE_DRAG_DELTA_SPEED")
+            }
+
+            fn get_tyre_drag_angular_acceleration(&mut self) -> f64 {
+                self.read_by_name("TYRE_DRAG_ANGULAR_ACCELERATION")
+            }
+
+            fn get_brake_temperature(&mut self, brake_number: usize) -> f64 {
+                self.read_by_name(&format!("BRAKE_TEMPERATURE_{}", brake_number))
+            }
+
             fn get_brake_left_green_pressure(&mut self) -> Pressure {
                 self.read_by_name("HYD_BRAKE_NORM_LEFT_PRESS")
             }
@@ -9582,6 +9762,76 @@ mod tests {
             assert!(!test_bed.is_yellow_epump_a_press_low());
         }
 
+        #[test]
+        fn braking_at_speed_heats_the_brakes() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .start_eng1(Ratio::new::<percent>(80.))
+                .start_eng2(Ratio::new::<percent>(80.))
+                .start_eng3(Ratio::new::<percent>(80.))
+                .start_eng4(Ratio::new::<percent>(80.))
+                .set_park_brake(false)
+                .run_waiting_for(Duration::from_secs(5));
+
+            test_bed.write_by_name("TOTAL WEIGHT", Mass::new::<kilogram>(400000.));
+            test_bed = test_bed
+                .set_ground_speed(Velocity::new::<knot>(100.))
+                .run_waiting_for(Duration::from_secs(1));
+            let initial_temperature = test_bed.get_brake_temperature(1);
+
+            test_bed = test_bed
+                .set_left_brake(Ratio::new::<percent>(100.))
+                .set_right_brake(Ratio::new::<percent>(100.))
+                .run_waiting_for(Duration::from_secs(5));
+
+            for brake_number in 1..=16 {
+                assert!(test_bed.get_brake_temperature(brake_number) > initial_temperature + 20.);
+            }
+        }
+
+        #[test]
+        fn burst_tyre_reduces_braking_and_drags_on_its_side() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .start_eng1(Ratio::new::<percent>(80.))
+                .start_eng2(Ratio::new::<percent>(80.))
+                .start_eng3(Ratio::new::<percent>(80.))
+                .start_eng4(Ratio::new::<percent>(80.))
+                .set_park_brake(false)
+                .set_ground_speed(Velocity::new::<knot>(100.))
+                .run_waiting_for(Duration::from_secs(5));
+
+            test_bed.write_by_name(
+                "VELOCITY BODY Z",
+                Velocity::new::<knot>(100.).get::<foot_per_second>(),
+            );
+            test_bed.write_by_name("TOTAL WEIGHT", Mass::new::<kilogram>(400000.));
+            test_bed.write_by_name("TOTAL WEIGHT YAW MOI", 50_000_000.);
+            test_bed.fail(FailureType::TyreBurst(3));
+            test_bed.fail(FailureType::TyreBurst(4));
+            test_bed = test_bed.run_waiting_for(Duration::from_secs(1));
+
+            // The drag of the tyres is not applied through the pedal brakes
+            assert!(test_bed.get_brake_left_force_factor() < 0.01);
+            assert!(test_bed.get_brake_right_force_factor() < 0.01);
+            assert!(test_bed.get_tyre_drag_delta_speed() < 0.);
+            assert!(test_bed.get_tyre_drag_angular_acceleration() > 0.);
+
+            test_bed = test_bed
+                .set_left_brake(Ratio::new::<percent>(100.))
+                .set_right_brake(Ratio::new::<percent>(100.))
+                .run_waiting_for(Duration::from_secs(1));
+
+            assert!(
+                test_bed.get_brake_right_force_factor()
+                    < test_bed.get_brake_left_force_factor() - 0.1
+            );
+        }
+
         #[test]
         fn auto_brake_at_gear_retraction() {
             let mut test_bed = test_bed_on_ground_with()
@@ -10585,6 +10835,28 @@ mod tests {
             assert!(test_bed.nose_steering_position().get::<degree>() >= 40.);
         }
 
+        #[test]
+        fn nose_steering_authority_reduced_by_burst_nose_tyre() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .set_yellow_e_pump_a(false)
+                .start_eng1(Ratio::new::<percent>(80.))
+                .start_eng2(Ratio::new::<percent>(80.))
+                .start_eng3(Ratio::new::<percent>(80.))
+                .start_eng4(Ratio::new::<percent>(80.))
+                .run_one_tick();
+
+            test_bed.fail(FailureType::TyreBurst(21));
+            test_bed = test_bed
+                .set_tiller_demand(Ratio::new::<ratio>(1.))
+                .run_waiting_for(Duration::from_secs_f64(10.));
+
+            assert!(test_bed.nose_steering_position().get::<degree>() >= 40.);
+            assert!(test_bed.nose_steering_position().get::<degree>() <= 53.);
+        }
+
         #[test]
         fn yellow_epump_has_cavitation_at_low_air_press() {
             let mut test_bed = test_bed_on_ground_with()
@@ -10996,6 +11268,44 @@ mod tests {
             );
         }
 
+        #[test]
+        fn body_steering_authority_reduced_by_burst_body_steering_tyre() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .run_one_tick();
+
+            test_bed.fail(FailureType::TyreBurst(17));
+            test_bed = test_bed
+                .set_pushback_state(true)
+                .set_pushback_angle(AngularVelocity::new::<degree_per_second>(5.))
+                .run_waiting_for(Duration::from_secs(15));
+
+            // Left BWS cannot reach its max right steer against the scrub of the deflated tyre
+            assert!(
+                test_bed
+                    .get_left_body_steering_steering_ratio()
+                    .get::<ratio>()
+                    <= 0.71
+            );
+            assert!(
+                test_bed
+                    .get_left_body_steering_steering_ratio()
+                    .get::<ratio>()
+                    >= 0.65
+            );
+
+            // Right BWS is not affected
+            let expected_right_angle = 11. / 15.;
+            assert!(
+                test_bed
+                    .get_right_body_steering_steering_ratio()
+                    .get::<ratio>()
+                    > expected_right_angle - 0.05
+            );
+        }
+
         #[test]
         fn pushback_steering_full_right_turns_body_steering() {
             let mut test_bed = test_bed_on_ground_with()
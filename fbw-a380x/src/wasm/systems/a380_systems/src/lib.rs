@@ -5,6 +5,7 @@ mod airframe;
 mod avionics_data_communication_network;
 mod control_display_system;
 mod electrical;
+pub mod failures;
 mod fire_and_smoke_protection;
 mod fuel;
 pub mod hydraulic;
@@ -20,7 +21,7 @@ use self::{
     air_conditioning::{A380AirConditioning, A380PressurizationOverheadPanel},
     avionics_data_communication_network::A380AvionicsDataCommunicationNetwork,
     control_display_system::A380ControlDisplaySystem,
-    fuel::A380Fuel,
+    fuel::{A380Fuel, A380FuelOverheadPanel},
     pneumatic::{A380Pneumatic, A380PneumaticOverheadPanel},
     structural_flex::A380StructuralFlex,
 };
@@ -34,31 +35,92 @@ use fire_and_smoke_protection::A380FireAndSmokeProtection;
 use fuel::FuelLevel;
 use hydraulic::{autobrakes::A380AutobrakePanel, A380Hydraulic, A380HydraulicOverheadPanel};
 use icing::Icing;
-use navigation::{A380AirDataInertialReferenceSystemBuilder, A380RadioAltimeters};
+use navigation::{
+    A380AirDataInertialReferenceSystemBuilder, A380MultiModeReceivers, A380RadioAltimeters,
+};
 use payload::A380Payload;
 use power_consumption::A380PowerConsumption;
 use reverser::{A380ReverserController, A380Reversers};
 use uom::si::{f64::Length, length::nautical_mile};
 
+use std::{path::Path, sync::OnceLock};
 use systems::{
     accept_iterable,
+    aircraft_configuration::{
+        AircraftConfiguration, AircraftConfigurationError, EngineModel, SystemModels,
+    },
     apu::{
         AuxiliaryPowerUnit, AuxiliaryPowerUnitFactory, AuxiliaryPowerUnitFireOverheadPanel,
         AuxiliaryPowerUnitOverheadPanel, Pw980ApuGenerator, Pw980Constants, Pw980StartMotor,
     },
     electrical::{Electricity, ElectricitySource, ExternalPowerSource},
-    engine::{reverser_thrust::ReverserForce, trent_engine::TrentEngine, EngineFireOverheadPanel},
+    engine::{
+        reverser_thrust::ReverserForce,
+        spool_engine::SpoolEngine,
+        trent_engine::{Trent972Constants, TrentEngine},
+        EngineFireOverheadPanel, SelectableEngine,
+    },
     enhanced_gpwc::EnhancedGroundProximityWarningComputer,
     landing_gear::{LandingGear, LandingGearControlInterfaceUnitSet},
     navigation::adirs::{
         AirDataInertialReferenceSystem, AirDataInertialReferenceSystemOverheadPanel,
     },
-    shared::ElectricalBusType,
+    shared::{ElectricalBusType, EngineStarterAirPressure},
     simulation::{
         Aircraft, InitContext, SimulationElement, SimulationElementVisitor, UpdateContext,
     },
 };
 
+type A380Engine = SelectableEngine<TrentEngine, Trent972Constants>;
+
+static AIRCRAFT_CONFIGURATION: OnceLock<(String, AircraftConfiguration)> = OnceLock::new();
+const EMBEDDED_AIRCRAFT_CONFIGURATION: &str = include_str!("aircraft_configuration.toml");
+
+/// Loads the aircraft configuration from the file at `path`, falling back to the embedded
+/// `aircraft_configuration.toml` when there is no such file. Has to be called before the aircraft
+/// is constructed. Once a configuration is in use, loading another one has no effect. An invalid
+/// file loads nothing, such that the aircraft is built from the embedded configuration.
+pub fn load_aircraft_configuration(
+    path: impl AsRef<Path>,
+) -> Result<(), AircraftConfigurationError> {
+    load_aircraft_configuration_toml(AircraftConfiguration::read_toml_file_or(
+        path,
+        EMBEDDED_AIRCRAFT_CONFIGURATION,
+    )?)
+}
+
+/// Loads the aircraft configuration from the given TOML text, such as the configuration stored in
+/// a recording. The same rules as for [`load_aircraft_configuration`] apply.
+pub fn load_aircraft_configuration_toml(
+    configuration: String,
+) -> Result<(), AircraftConfigurationError> {
+    let parsed = AircraftConfiguration::from_toml(&configuration)?;
+    let _ = AIRCRAFT_CONFIGURATION.set((configuration, parsed));
+
+    Ok(())
+}
+
+/// The TOML text of the configuration the aircraft is built from.
+pub fn aircraft_configuration_toml() -> &'static str {
+    &loaded_aircraft_configuration().0
+}
+
+/// Fuel tanks and payload stations of the aircraft, as loaded by [`load_aircraft_configuration`]
+/// or, when none was loaded, as described in the embedded `aircraft_configuration.toml`.
+pub(crate) fn aircraft_configuration() -> &'static AircraftConfiguration {
+    &loaded_aircraft_configuration().1
+}
+
+fn loaded_aircraft_configuration() -> &'static (String, AircraftConfiguration) {
+    AIRCRAFT_CONFIGURATION.get_or_init(|| {
+        (
+            EMBEDDED_AIRCRAFT_CONFIGURATION.to_owned(),
+            AircraftConfiguration::from_toml(EMBEDDED_AIRCRAFT_CONFIGURATION)
+                .unwrap_or_else(|e| panic!("Invalid A380 aircraft configuration: {}", e)),
+        )
+    })
+}
+
 pub struct A380 {
     adcn: A380AvionicsDataCommunicationNetwork,
     adcn_simvar_translation: A380AvionicsDataCommunicationNetworkSimvarTranslator,
@@ -76,10 +138,11 @@ pub struct A380 {
     airframe: A380Airframe,
     fire_and_smoke_protection: A380FireAndSmokeProtection,
     fuel: A380Fuel,
-    engine_1: TrentEngine,
-    engine_2: TrentEngine,
-    engine_3: TrentEngine,
-    engine_4: TrentEngine,
+    fuel_overhead: A380FuelOverheadPanel,
+    engine_1: A380Engine,
+    engine_2: A380Engine,
+    engine_3: A380Engine,
+    engine_4: A380Engine,
     engine_fire_overhead: EngineFireOverheadPanel<4>,
     electrical: A380Electrical,
     power_consumption: A380PowerConsumption,
@@ -91,6 +154,7 @@ pub struct A380 {
     landing_gear: LandingGear,
     pneumatic: A380Pneumatic,
     radio_altimeters: A380RadioAltimeters,
+    multi_mode_receivers: A380MultiModeReceivers,
     cds: A380ControlDisplaySystem,
     egpwc: EnhancedGroundProximityWarningComputer,
     icing_simulation: Icing,
@@ -102,6 +166,12 @@ pub struct A380 {
 }
 impl A380 {
     pub fn new(context: &mut InitContext) -> A380 {
+        A380::with_models(context, aircraft_configuration().models())
+    }
+
+    /// Creates the aircraft simulated with the given models instead of the ones selected by the
+    /// aircraft configuration.
+    pub fn with_models(context: &mut InitContext, models: SystemModels) -> A380 {
         let mut adcn = A380AvionicsDataCommunicationNetwork::new(context);
         let adcn_simvar_translation =
             A380AvionicsDataCommunicationNetworkSimvarTranslator::new(context, &mut adcn);
@@ -127,10 +197,11 @@ impl A380 {
             airframe: A380Airframe::new(context),
             fire_and_smoke_protection: A380FireAndSmokeProtection::new(context),
             fuel: A380Fuel::new(context),
-            engine_1: TrentEngine::new(context, 1),
-            engine_2: TrentEngine::new(context, 2),
-            engine_3: TrentEngine::new(context, 3),
-            engine_4: TrentEngine::new(context, 4),
+            fuel_overhead: A380FuelOverheadPanel::new(context),
+            engine_1: A380::new_engine(context, 1, models.engine),
+            engine_2: A380::new_engine(context, 2, models.engine),
+            engine_3: A380::new_engine(context, 3, models.engine),
+            engine_4: A380::new_engine(context, 4, models.engine),
             engine_fire_overhead: EngineFireOverheadPanel::new(context),
             electrical: A380Electrical::new(context),
             power_consumption: A380PowerConsumption::new(context),
@@ -140,12 +211,13 @@ impl A380 {
                 ElectricalBusType::DirectCurrentEssential,
                 ElectricalBusType::DirectCurrentGndFltService,
             ),
-            hydraulic: A380Hydraulic::new(context),
+            hydraulic: A380Hydraulic::new(context, models.brake_temperature),
             hydraulic_overhead: A380HydraulicOverheadPanel::new(context),
             autobrake_panel: A380AutobrakePanel::new(context),
             landing_gear: LandingGear::new(context, true),
             pneumatic: A380Pneumatic::new(context),
             radio_altimeters: A380RadioAltimeters::new(context),
+            multi_mode_receivers: A380MultiModeReceivers::new(context),
             cds: A380ControlDisplaySystem::new(context),
             egpwc: EnhancedGroundProximityWarningComputer::new(
                 context,
@@ -173,6 +245,17 @@ impl A380 {
             reverse_thrust: ReverserForce::new(context),
         }
     }
+
+    fn new_engine(context: &mut InitContext, number: usize, model: EngineModel) -> A380Engine {
+        match model {
+            EngineModel::Simulator => {
+                SelectableEngine::SimulatorVariables(TrentEngine::new(context, number))
+            }
+            EngineModel::Spool => {
+                SelectableEngine::Spool(Box::new(SpoolEngine::new(context, number)))
+            }
+        }
+    }
 }
 impl Aircraft for A380 {
     fn update_before_power_distribution(
@@ -180,6 +263,27 @@ impl Aircraft for A380 {
         context: &UpdateContext,
         electricity: &mut Electricity,
     ) {
+        self.engine_1.update(
+            context,
+            self.pneumatic.starter_air_pressure(1),
+            self.fuel.engine_is_fed(1),
+        );
+        self.engine_2.update(
+            context,
+            self.pneumatic.starter_air_pressure(2),
+            self.fuel.engine_is_fed(2),
+        );
+        self.engine_3.update(
+            context,
+            self.pneumatic.starter_air_pressure(3),
+            self.fuel.engine_is_fed(3),
+        );
+        self.engine_4.update(
+            context,
+            self.pneumatic.starter_air_pressure(4),
+            self.fuel.engine_is_fed(4),
+        );
+
         self.apu.update_before_electrical(
             context,
             &self.apu_overhead,
@@ -193,6 +297,7 @@ impl Aircraft for A380 {
                 || self.electrical_overhead.apu_generator_is_on(2))
                 && !(self.electrical_overhead.external_power_is_on(1)
                     && self.electrical_overhead.external_power_is_available(1)),
+            &self.electrical_overhead,
             self.pneumatic.apu_bleed_air_valve(),
             self.fuel.feed_one_tank_has_fuel(),
         );
@@ -236,7 +341,7 @@ impl Aircraft for A380 {
         );
         self.apu_overhead.update_after_apu(&self.apu);
 
-        self.adcn.update();
+        self.adcn.update(context);
         self.adcn_simvar_translation.update(&self.adcn);
         self.lgcius.update(
             context,
@@ -249,6 +354,7 @@ impl Aircraft for A380 {
             context,
             &self.engine_fire_overhead,
             [self.lgcius.lgciu1(), self.lgcius.lgciu2()],
+            &self.air_conditioning,
         );
 
         self.radio_altimeters.update(context);
@@ -276,7 +382,15 @@ impl Aircraft for A380 {
 
         self.hydraulic_overhead.update(&self.hydraulic);
 
-        self.adirs.update(context, &self.adirs_overhead);
+        self.multi_mode_receivers.update(context);
+        self.adirs.update(
+            context,
+            &self.adirs_overhead,
+            [
+                self.multi_mode_receivers.mmr_1(),
+                self.multi_mode_receivers.mmr_2(),
+            ],
+        );
         self.adirs_overhead.update(context, &self.adirs);
 
         self.power_consumption.update(context);
@@ -300,6 +414,7 @@ impl Aircraft for A380 {
             context,
             &self.adirs,
             &self.hydraulic,
+            &self.fire_and_smoke_protection,
             &self.adcn,
             [
                 &self.engine_1,
@@ -315,10 +430,6 @@ impl Aircraft for A380 {
             [self.lgcius.lgciu1(), self.lgcius.lgciu2()],
         );
 
-        self.cds.update();
-
-        self.egpwc.update(&self.adirs, self.lgcius.lgciu1());
-
         self.structural_flex.update(
             context,
             [
@@ -329,12 +440,31 @@ impl Aircraft for A380 {
             &self.hydraulic,
             &self.fuel,
         );
-        self.cds.update();
+        self.cds.update(context);
 
         self.icing_simulation.update(context);
 
-        self.egpwc.update(&self.adirs, self.lgcius.lgciu1());
-        self.fuel.update(context);
+        self.egpwc.update(
+            context,
+            &self.adirs,
+            self.multi_mode_receivers.mmr_1(),
+            [
+                self.radio_altimeters.radio_altimeter_1(),
+                self.radio_altimeters.radio_altimeter_2(),
+            ],
+            self.lgcius.lgciu1(),
+        );
+        self.fuel.update(
+            context,
+            &self.fuel_overhead,
+            [
+                &self.engine_1,
+                &self.engine_2,
+                &self.engine_3,
+                &self.engine_4,
+            ],
+        );
+        self.fuel_overhead.update(&self.fuel);
 
         self.engine_reverser_control[0].update(
             &self.engine_2,
@@ -371,6 +501,7 @@ impl SimulationElement for A380 {
         self.emergency_electrical_overhead.accept(visitor);
         self.fire_and_smoke_protection.accept(visitor);
         self.fuel.accept(visitor);
+        self.fuel_overhead.accept(visitor);
         self.payload.accept(visitor);
         self.airframe.accept(visitor);
         self.pneumatic_overhead.accept(visitor);
@@ -385,6 +516,7 @@ impl SimulationElement for A380 {
         accept_iterable!(self.ext_pwrs, visitor);
         self.lgcius.accept(visitor);
         self.radio_altimeters.accept(visitor);
+        self.multi_mode_receivers.accept(visitor);
         self.autobrake_panel.accept(visitor);
         self.hydraulic.accept(visitor);
         self.hydraulic_overhead.accept(visitor);
@@ -402,3 +534,144 @@ impl SimulationElement for A380 {
         visitor.visit(self);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{fs, path::Path, time::Duration};
+    use systems::{
+        failures::FailureType,
+        simulation::{
+            recording::replay,
+            scenario::{run_scenario, Scenario},
+            test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+        },
+    };
+
+    #[test]
+    fn recorded_flight_replays_identically() {
+        let failures = [(32_200, FailureType::TyreBurst(1))];
+        let mut test_bed =
+            SimulationTestBed::new_recorded(7, aircraft_configuration_toml(), &failures, A380::new);
+
+        test_bed.run_multiple_frames(Duration::from_secs(5));
+        test_bed.fail(FailureType::TyreBurst(1));
+        test_bed.run_multiple_frames(Duration::from_secs(5));
+
+        let recording = test_bed.finish_recording();
+        let report = replay(&recording, A380::new, &failures, 0.);
+
+        assert_eq!(recording.configuration(), aircraft_configuration_toml());
+        assert!(report.ticks() > 0);
+        assert!(report.is_identical(), "{:?}", report.divergences().first());
+    }
+
+    #[test]
+    fn scenarios_hold() {
+        let directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("scenarios");
+        for entry in fs::read_dir(directory).unwrap() {
+            let path = entry.unwrap().path();
+            if path
+                .extension()
+                .map_or(true, |extension| extension != "toml")
+            {
+                continue;
+            }
+
+            let scenario = Scenario::from_toml(&fs::read_to_string(&path).unwrap())
+                .unwrap_or_else(|e| panic!("{}: {}", path.display(), e));
+            let report = run_scenario(&scenario, A380::new, failures::FAILURES)
+                .unwrap_or_else(|e| panic!("{}: {}", path.display(), e));
+
+            assert!(report.is_successful(), "{}: {}", path.display(), report);
+        }
+    }
+
+    fn test_bed_with_engines_running_on(engine: EngineModel) -> SimulationTestBed<A380> {
+        let mut test_bed = SimulationTestBed::new(|context| {
+            A380::with_models(
+                context,
+                SystemModels {
+                    engine,
+                    ..Default::default()
+                },
+            )
+        });
+        for number in 1..=11 {
+            test_bed.write_by_name(&format!("FUEL_TANK_QUANTITY_{}", number), 1000.);
+        }
+        for number in 1..=4 {
+            test_bed.write_by_name(&format!("GENERAL ENG COMBUSTION:{}", number), true);
+            test_bed.write_by_name(&format!("GENERAL ENG FUEL VALVE:{}", number), true);
+        }
+        test_bed.run_multiple_frames(Duration::from_secs(10));
+
+        test_bed
+    }
+
+    #[test]
+    fn spool_engines_drive_the_aircraft_systems() {
+        let mut test_bed = test_bed_with_engines_running_on(EngineModel::Spool);
+
+        let n2: f64 = test_bed.read_by_name("SPOOL_ENGINE_1_N2");
+        let green_pressure: f64 = test_bed.read_by_name("HYD_GREEN_SYSTEM_1_SECTION_PRESSURE");
+        assert!(n2 > 50.);
+        assert!(green_pressure > 4000.);
+    }
+
+    #[test]
+    fn simulator_engine_model_takes_the_engines_from_the_simulator() {
+        let mut test_bed = test_bed_with_engines_running_on(EngineModel::Simulator);
+
+        let green_pressure: f64 = test_bed.read_by_name("HYD_GREEN_SYSTEM_1_SECTION_PRESSURE");
+        assert!(!test_bed.contains_variable_with_name("SPOOL_ENGINE_1_N2"));
+        assert!(green_pressure < 500.);
+    }
+
+    #[test]
+    fn spool_engine_spools_down_when_its_fuel_valve_closes() {
+        let mut test_bed = test_bed_with_engines_running_on(EngineModel::Spool);
+
+        test_bed.write_by_name("GENERAL ENG FUEL VALVE:1", false);
+        test_bed.run_multiple_frames(Duration::from_secs(60));
+
+        let n2: f64 = test_bed.read_by_name("SPOOL_ENGINE_1_N2");
+        assert!(n2 < 20.);
+    }
+
+    fn test_bed_with_external_power() -> SimulationTestBed<A380> {
+        let mut test_bed = SimulationTestBed::new(A380::new);
+        for number in 1..=4 {
+            test_bed.write_by_name(&format!("EXTERNAL POWER AVAILABLE:{}", number), true);
+            test_bed.write_by_name(&format!("OVHD_ELEC_EXT_PWR_{}_PB_IS_ON", number), true);
+        }
+        test_bed.run_multiple_frames(Duration::from_secs(10));
+
+        test_bed
+    }
+
+    fn fuel_pump_is_running(test_bed: &mut SimulationTestBed<A380>, number: usize) -> bool {
+        test_bed.read_by_name(&format!("FUEL_PUMP_{}_IS_RUNNING", number))
+    }
+
+    #[test]
+    fn seized_fuel_pump_trips_its_circuit_breaker_which_unpowers_the_pump_until_reset() {
+        let mut test_bed = test_bed_with_external_power();
+        assert!(fuel_pump_is_running(&mut test_bed, 1));
+
+        test_bed.fail(FailureType::FuelPump(1));
+        test_bed.run_multiple_frames(Duration::from_secs(2));
+        let is_tripped: bool = test_bed.read_by_name("ELEC_CB_FUEL_PUMP_1_IS_TRIPPED");
+        assert!(is_tripped);
+
+        test_bed.unfail(FailureType::FuelPump(1));
+        test_bed.run_multiple_frames(Duration::from_secs(1));
+        assert!(!fuel_pump_is_running(&mut test_bed, 1));
+
+        test_bed.write_by_name("ELEC_CB_FUEL_PUMP_1_IS_PULLED", true);
+        test_bed.run_multiple_frames(Duration::from_secs(15));
+        test_bed.write_by_name("ELEC_CB_FUEL_PUMP_1_IS_PULLED", false);
+        test_bed.run_multiple_frames(Duration::from_secs(1));
+        assert!(fuel_pump_is_running(&mut test_bed, 1));
+    }
+}
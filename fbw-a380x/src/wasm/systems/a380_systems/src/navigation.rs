@@ -5,8 +5,9 @@ use systems::navigation::adirs::{
 use systems::navigation::ala52b::{
     Ala52BAircraftInstallationDelay, Ala52BRadioAltimeter, Ala52BTransceiverPair,
 };
-use systems::navigation::radio_altimeter::AntennaInstallation;
-use systems::shared::{ElectricalBusType, MachNumber};
+use systems::navigation::mmr::MultiModeReceiver;
+use systems::navigation::radio_altimeter::{AntennaInstallation, RadioAltimeter};
+use systems::shared::{arinc429::Arinc429Word, ElectricalBusType, MachNumber};
 use systems::simulation::{
     InitContext, SimulationElement, SimulationElementVisitor, UpdateContext,
 };
@@ -44,6 +45,46 @@ impl A380AirDataInertialReferenceSystemBuilder {
     }
 }
 
+pub struct A380MultiModeReceivers {
+    mmr_1: MultiModeReceiver,
+    mmr_2: MultiModeReceiver,
+}
+
+impl A380MultiModeReceivers {
+    pub fn new(context: &mut InitContext) -> Self {
+        Self {
+            mmr_1: MultiModeReceiver::new(
+                context,
+                1,
+                ElectricalBusType::AlternatingCurrentEssential,
+            ),
+            mmr_2: MultiModeReceiver::new(context, 2, ElectricalBusType::AlternatingCurrent(2)),
+        }
+    }
+
+    pub fn mmr_1(&self) -> &MultiModeReceiver {
+        &self.mmr_1
+    }
+
+    pub fn mmr_2(&self) -> &MultiModeReceiver {
+        &self.mmr_2
+    }
+
+    pub fn update(&mut self, context: &UpdateContext) {
+        self.mmr_1.update(context);
+        self.mmr_2.update(context);
+    }
+}
+
+impl SimulationElement for A380MultiModeReceivers {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.mmr_1.accept(visitor);
+        self.mmr_2.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
 pub struct A380RadioAltimeters {
     radio_altimeter_1: A380RadioAltimeter,
     radio_altimeter_2: A380RadioAltimeter,
@@ -104,6 +145,14 @@ impl A380RadioAltimeters {
         }
     }
 
+    pub fn radio_altimeter_1(&self) -> &A380RadioAltimeter {
+        &self.radio_altimeter_1
+    }
+
+    pub fn radio_altimeter_2(&self) -> &A380RadioAltimeter {
+        &self.radio_altimeter_2
+    }
+
     pub fn update(&mut self, context: &UpdateContext) {
         self.radio_altimeter_1.update(context);
         self.radio_altimeter_2.update(context);
@@ -150,6 +199,12 @@ impl A380RadioAltimeter {
     }
 }
 
+impl RadioAltimeter for A380RadioAltimeter {
+    fn radio_altitude(&self) -> Arinc429Word<Length> {
+        self.radio_altimeter.radio_altitude()
+    }
+}
+
 impl SimulationElement for A380RadioAltimeter {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.transceivers.accept(visitor);
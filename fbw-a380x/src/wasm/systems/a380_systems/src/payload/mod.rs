@@ -4,11 +4,9 @@ use std::{cell::Cell, rc::Rc};
 
 use uom::si::{f64::Mass, mass::kilogram};
 
+use crate::aircraft_configuration;
 use systems::{
-    payload::{
-        BoardingAgent, BoardingSounds, Cargo, CargoDeck, CargoInfo, CargoPayload,
-        NumberOfPassengers, PassengerDeck, PassengerPayload, Pax, PaxInfo, PayloadManager,
-    },
+    payload::{BoardingSounds, CargoPayload, NumberOfPassengers, PassengerPayload, PayloadManager},
     simulation::{InitContext, SimulationElement, SimulationElementVisitor, UpdateContext},
 };
 
@@ -86,179 +84,19 @@ pub struct A380Payload {
 impl A380Payload {
     // Note: These constants reflect flight_model.cfg values and will have to be updated in sync with the configuration
     pub const DEFAULT_PER_PAX_WEIGHT_KG: f64 = 84.;
-    // TODO: Move into a toml cfg
-    const A380_PAX: [PaxInfo<'static>; 14] = [
-        PaxInfo {
-            max_pax: 28,
-            position: (75.7, 0., 7.1),
-            pax_id: "PAX_MAIN_FWD_A",
-            payload_id: "PAYLOAD_STATION_1_REQ",
-        },
-        PaxInfo {
-            max_pax: 28,
-            position: (75.7, 0., 7.1),
-            pax_id: "PAX_MAIN_FWD_B",
-            payload_id: "PAYLOAD_STATION_2_REQ",
-        },
-        // PAX MAIN FWD: 56
-        PaxInfo {
-            max_pax: 39,
-            position: (30.6, 0., 7.1),
-            pax_id: "PAX_MAIN_MID_1A",
-            payload_id: "PAYLOAD_STATION_3_REQ",
-        },
-        PaxInfo {
-            max_pax: 50,
-            position: (30.6, 0., 7.1),
-            pax_id: "PAX_MAIN_MID_1B",
-            payload_id: "PAYLOAD_STATION_4_REQ",
-        },
-        PaxInfo {
-            max_pax: 43,
-            position: (30.6, 0., 7.1),
-            pax_id: "PAX_MAIN_MID_1C",
-            payload_id: "PAYLOAD_STATION_5_REQ",
-        },
-        // PAX MAIN MID 1: 132
-        PaxInfo {
-            max_pax: 48,
-            position: (-11.1, 0., 7.1),
-            pax_id: "PAX_MAIN_MID_2A",
-            payload_id: "PAYLOAD_STATION_6_REQ",
-        },
-        PaxInfo {
-            max_pax: 40,
-            position: (-11.1, 0., 7.1),
-            pax_id: "PAX_MAIN_MID_2B",
-            payload_id: "PAYLOAD_STATION_7_REQ",
-        },
-        PaxInfo {
-            max_pax: 36,
-            position: (-11.1, 0., 7.1),
-            pax_id: "PAX_MAIN_MID_2C",
-            payload_id: "PAYLOAD_STATION_8_REQ",
-        },
-        // PAX MAIN MID 2: 124
-        PaxInfo {
-            max_pax: 42,
-            position: (-46.9, 0., 7.1),
-            pax_id: "PAX_MAIN_AFT_A",
-            payload_id: "PAYLOAD_STATION_9_REQ",
-        },
-        PaxInfo {
-            max_pax: 40,
-            position: (-46.9, 0., 7.1),
-            pax_id: "PAX_MAIN_AFT_B",
-            payload_id: "PAYLOAD_STATION_10_REQ",
-        },
-        // PAX MAIN AFT: 82
-        PaxInfo {
-            max_pax: 14,
-            position: (60.8, 0., 15.6),
-            pax_id: "PAX_UPPER_FWD",
-            payload_id: "PAYLOAD_STATION_11_REQ",
-        },
-        // PAX UPPER FWD: 14
-        PaxInfo {
-            max_pax: 30,
-            position: (11.7, 0., 15.6),
-            pax_id: "PAX_UPPER_MID_A",
-            payload_id: "PAYLOAD_STATION_12_REQ",
-        },
-        PaxInfo {
-            max_pax: 28,
-            position: (11.7, 0., 15.6),
-            pax_id: "PAX_UPPER_MID_B",
-            payload_id: "PAYLOAD_STATION_13_REQ",
-        },
-        // PAX UPPER MID: 58
-        PaxInfo {
-            max_pax: 18,
-            position: (-29.3, 0., 15.6),
-            pax_id: "PAX_UPPER_AFT",
-            payload_id: "PAYLOAD_STATION_14_REQ",
-        },
-        // PAX UPPER AFT: 18
-    ];
-    // TODO: Move into a toml cfg
-    const A380_CARGO: [CargoInfo<'static>; 3] = [
-        CargoInfo {
-            max_cargo_kg: 28577.,
-            position: (67.4, 0., -0.95),
-            cargo_id: "CARGO_FWD",
-            payload_id: "PAYLOAD_STATION_15_REQ",
-        },
-        CargoInfo {
-            max_cargo_kg: 20310.,
-            position: (-18.5, 0., -0.95),
-            cargo_id: "CARGO_AFT",
-            payload_id: "PAYLOAD_STATION_16_REQ",
-        },
-        CargoInfo {
-            max_cargo_kg: 2513.,
-            position: (-52.9, 0., -0.71),
-            cargo_id: "CARGO_BULK",
-            payload_id: "PAYLOAD_STATION_17_REQ",
-        },
-    ];
-
     pub fn new(context: &mut InitContext) -> Self {
         let per_pax_weight = Rc::new(Cell::new(Mass::new::<kilogram>(
             Self::DEFAULT_PER_PAX_WEIGHT_KG,
         )));
         let developer_state = Rc::new(Cell::new(0));
         let boarding_sounds = BoardingSounds::new(context);
-        let pax = Self::A380_PAX.map(|p| {
-            Pax::new(
-                context.get_identifier(p.pax_id.to_owned()),
-                context.get_identifier(format!("{}_DESIRED", p.pax_id)),
-                context.get_identifier(p.payload_id.to_owned()),
-                developer_state.clone(),
-                per_pax_weight.clone(),
-                Vector3::new(p.position.0, p.position.1, p.position.2),
-                p.max_pax,
-            )
-        });
-
-        let cargo = Self::A380_CARGO.map(|c| {
-            Cargo::new(
-                context.get_identifier(c.cargo_id.to_owned()),
-                context.get_identifier(format!("{}_DESIRED", c.cargo_id)),
-                context.get_identifier(c.payload_id.to_owned()),
-                Rc::clone(&developer_state),
-                Vector3::new(c.position.0, c.position.1, c.position.2),
-                Mass::new::<kilogram>(c.max_cargo_kg),
-            )
-        });
-        let default_boarding_agent =
-            BoardingAgent::new(None, [10, 13, 12, 11, 1, 0, 9, 8, 7, 6, 5, 4, 3, 2]);
-
-        // TODO: Move into a toml cfg
-        let boarding_agents = [
-            BoardingAgent::new(
-                Some(context.get_identifier("INTERACTIVE POINT OPEN:0".to_owned())),
-                [10, 13, 12, 11, 1, 0, 9, 8, 7, 6, 5, 4, 3, 2],
-            ), // M1L
-            BoardingAgent::new(
-                Some(context.get_identifier("INTERACTIVE POINT OPEN:2".to_owned())),
-                [1, 0, 9, 8, 7, 6, 5, 2, 3, 4, 10, 11, 12, 13],
-            ), // M2L
-            BoardingAgent::new(
-                Some(context.get_identifier("INTERACTIVE POINT OPEN:6".to_owned())),
-                [13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0],
-            ), // M4L
-            BoardingAgent::new(
-                Some(context.get_identifier("INTERACTIVE POINT OPEN:8".to_owned())),
-                [13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0],
-            ), // M5L
-            BoardingAgent::new(
-                Some(context.get_identifier("INTERACTIVE POINT OPEN:10".to_owned())),
-                [10, 13, 12, 11, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0],
-            ), // U1L
-        ];
-
-        let passenger_deck = PassengerDeck::new(pax, default_boarding_agent, boarding_agents);
-        let cargo_deck = CargoDeck::new(cargo);
+        let configuration = aircraft_configuration();
+        let passenger_deck = configuration
+            .passenger_deck(context, developer_state.clone(), per_pax_weight.clone())
+            .unwrap_or_else(|e| panic!("Invalid A380 passenger configuration: {}", e));
+        let cargo_deck = configuration
+            .cargo_deck(context, Rc::clone(&developer_state))
+            .unwrap_or_else(|e| panic!("Invalid A380 cargo configuration: {}", e));
 
         A380Payload {
             payload_manager: PayloadManager::new(
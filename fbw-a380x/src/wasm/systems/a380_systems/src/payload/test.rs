@@ -218,7 +218,7 @@ impl BoardingTestBed {
     fn board_gsx_pax_half(mut self) -> Self {
         let mut max_pax = 0;
 
-        for ps in 0..A380Payload::A380_PAX.len() {
+        for ps in 0..aircraft_configuration().pax().len() {
             max_pax += test_bed().query(|a| a.max_pax(ps)) as i32;
         }
         self.write_by_name("FSDT_GSX_NUMPASSENGERS_BOARDING_TOTAL", max_pax / 2);
@@ -227,7 +227,7 @@ impl BoardingTestBed {
 
     fn board_gsx_pax_full(mut self) -> Self {
         let mut max_pax = 0;
-        for ps in 0..A380Payload::A380_PAX.len() {
+        for ps in 0..aircraft_configuration().pax().len() {
             max_pax += test_bed().query(|a| a.max_pax(ps)) as i32;
         }
         self.write_by_name("FSDT_GSX_NUMPASSENGERS_BOARDING_TOTAL", max_pax);
@@ -281,8 +281,8 @@ impl BoardingTestBed {
 
         let payload = Mass::new::<pound>(pax_qty as f64 * per_pax_weight.get::<pound>());
 
-        self.write_by_name(A380Payload::A380_PAX[ps].pax_id, pax_flag);
-        self.write_by_name(A380Payload::A380_PAX[ps].payload_id, payload);
+        self.write_by_name(&aircraft_configuration().pax()[ps].pax_id, pax_flag);
+        self.write_by_name(&aircraft_configuration().pax()[ps].payload_id, payload);
     }
 
     fn target_pax(&mut self, ps: usize, pax_qty: i8) {
@@ -303,7 +303,7 @@ impl BoardingTestBed {
         }
 
         self.write_by_name(
-            &format!("{}_DESIRED", A380Payload::A380_PAX[ps].pax_id),
+            &format!("{}_DESIRED", aircraft_configuration().pax()[ps].pax_id),
             pax_flag,
         );
     }
@@ -312,11 +312,11 @@ impl BoardingTestBed {
         assert!(cargo_qty <= test_bed().query(|a| a.max_cargo(cs)));
 
         self.write_by_name(
-            A380Payload::A380_CARGO[cs].cargo_id,
+            &aircraft_configuration().cargo()[cs].cargo_id,
             cargo_qty.get::<kilogram>(),
         );
         self.write_by_name(
-            A380Payload::A380_CARGO[cs].payload_id,
+            &aircraft_configuration().cargo()[cs].payload_id,
             cargo_qty.get::<pound>(),
         );
     }
@@ -325,7 +325,7 @@ impl BoardingTestBed {
         assert!(cargo_qty <= test_bed().query(|a| a.max_cargo(cs)));
 
         self.write_by_name(
-            &format!("{}_DESIRED", A380Payload::A380_CARGO[cs].cargo_id),
+            &format!("{}_DESIRED", aircraft_configuration().cargo()[cs].cargo_id),
             cargo_qty.get::<kilogram>(),
         );
     }
@@ -419,35 +419,35 @@ impl BoardingTestBed {
     }
 
     fn with_no_pax(mut self) -> Self {
-        for ps in 0..A380Payload::A380_PAX.len() {
+        for ps in 0..aircraft_configuration().pax().len() {
             self.load_pax(ps, 0);
         }
         self
     }
 
     fn with_all_stations_half_pax(mut self) -> Self {
-        for ps in 0..A380Payload::A380_PAX.len() {
+        for ps in 0..aircraft_configuration().pax().len() {
             self.load_pax(ps, test_bed().query(|a| a.max_pax(ps)) / 2);
         }
         self
     }
 
     fn with_full_pax(mut self) -> Self {
-        for ps in 0..A380Payload::A380_PAX.len() {
+        for ps in 0..aircraft_configuration().pax().len() {
             self.load_pax(ps, test_bed().query(|a| a.max_pax(ps)));
         }
         self
     }
 
     fn with_all_stations_half_cargo(mut self) -> Self {
-        for cs in 0..A380Payload::A380_CARGO.len() {
+        for cs in 0..aircraft_configuration().cargo().len() {
             self.load_cargo(cs, test_bed().query(|a| a.max_cargo(cs)) / 2.);
         }
         self
     }
 
     fn with_full_cargo(mut self) -> Self {
-        for cs in 0..A380Payload::A380_CARGO.len() {
+        for cs in 0..aircraft_configuration().cargo().len() {
             self.load_cargo(cs, test_bed().query(|a| a.max_cargo(cs)));
         }
         self
@@ -459,21 +459,21 @@ impl BoardingTestBed {
     }
 
     fn target_half_pax(mut self) -> Self {
-        for ps in 0..A380Payload::A380_PAX.len() {
+        for ps in 0..aircraft_configuration().pax().len() {
             self.target_pax(ps, test_bed().query(|a| a.max_pax(ps)) / 2);
         }
         self
     }
 
     fn target_full_pax(mut self) -> Self {
-        for ps in 0..A380Payload::A380_PAX.len() {
+        for ps in 0..aircraft_configuration().pax().len() {
             self.target_pax(ps, test_bed().query(|a| a.max_pax(ps)));
         }
         self
     }
 
     fn target_no_pax(mut self) -> Self {
-        for ps in 0..A380Payload::A380_PAX.len() {
+        for ps in 0..aircraft_configuration().pax().len() {
             self.target_pax(ps, 0);
         }
         self
@@ -484,7 +484,7 @@ impl BoardingTestBed {
     }
 
     fn has_no_pax(&self) {
-        for ps in 0..A380Payload::A380_PAX.len() {
+        for ps in 0..aircraft_configuration().pax().len() {
             let pax_num = 0;
             let pax_payload = Mass::default();
             assert_eq!(
@@ -506,7 +506,7 @@ impl BoardingTestBed {
 
         let mut total_pax_payload = Mass::default();
         let mut total_expected_pax_payload = Mass::default();
-        for ps in 0..A380Payload::A380_PAX.len() {
+        for ps in 0..aircraft_configuration().pax().len() {
             let pax_num = test_bed().query(|a| a.max_pax(ps)) / 2;
             let pax_payload = Mass::new::<pound>(pax_num as f64 * per_pax_weight.get::<pound>());
             total_expected_pax_payload += pax_payload;
@@ -520,7 +520,7 @@ impl BoardingTestBed {
 
     fn has_all_stations_half_pax(&mut self) {
         let per_pax_weight: Mass = Mass::new::<kilogram>(self.read_by_name("WB_PER_PAX_WEIGHT"));
-        for ps in 0..A380Payload::A380_PAX.len() {
+        for ps in 0..aircraft_configuration().pax().len() {
             let pax_num = test_bed().query(|a| a.max_pax(ps)) / 2;
             let pax_payload = Mass::new::<pound>(pax_num as f64 * per_pax_weight.get::<pound>());
             assert_eq!(
@@ -533,7 +533,7 @@ impl BoardingTestBed {
     fn has_full_pax(&mut self) {
         let per_pax_weight: Mass = Mass::new::<kilogram>(self.read_by_name("WB_PER_PAX_WEIGHT"));
 
-        for ps in 0..A380Payload::A380_PAX.len() {
+        for ps in 0..aircraft_configuration().pax().len() {
             let pax_num = test_bed().query(|a| a.max_pax(ps));
             let pax_payload = Mass::new::<pound>(pax_num as f64 * per_pax_weight.get::<pound>());
             assert_eq!(self.pax_num(ps), pax_num);
@@ -545,21 +545,21 @@ impl BoardingTestBed {
     }
 
     fn load_half_cargo(mut self) -> Self {
-        for cs in 0..A380Payload::A380_CARGO.len() {
+        for cs in 0..aircraft_configuration().cargo().len() {
             self.load_cargo(cs, test_bed().query(|a| a.max_cargo(cs)) / 2.);
         }
         self
     }
 
     fn load_full_cargo(mut self) -> Self {
-        for cs in 0..A380Payload::A380_CARGO.len() {
+        for cs in 0..aircraft_configuration().cargo().len() {
             self.load_cargo(cs, test_bed().query(|a| a.max_cargo(cs)));
         }
         self
     }
 
     fn has_no_cargo(&self) {
-        for cs in 0..A380Payload::A380_CARGO.len() {
+        for cs in 0..aircraft_configuration().cargo().len() {
             let cargo = Mass::default();
             assert_eq!(
                 self.cargo(cs).get::<kilogram>().floor(),
@@ -573,7 +573,7 @@ impl BoardingTestBed {
     }
 
     fn has_all_stations_half_cargo(&mut self) {
-        for cs in 0..A380Payload::A380_CARGO.len() {
+        for cs in 0..aircraft_configuration().cargo().len() {
             let cargo = test_bed().query(|a| a.max_cargo(cs)) / 2.;
             assert_eq!(
                 self.cargo(cs).get::<kilogram>().floor(),
@@ -589,7 +589,7 @@ impl BoardingTestBed {
     fn has_half_cargo(&mut self) {
         let mut total_cargo_payload = Mass::default();
         let mut total_expected_cargo_payload = Mass::default();
-        for cs in 0..A380Payload::A380_CARGO.len() {
+        for cs in 0..aircraft_configuration().cargo().len() {
             total_cargo_payload += self.cargo(cs);
             total_expected_cargo_payload += test_bed().query(|a| a.max_cargo(cs)) / 2.;
         }
@@ -600,7 +600,7 @@ impl BoardingTestBed {
     }
 
     fn has_full_cargo(&mut self) {
-        for cs in 0..A380Payload::A380_CARGO.len() {
+        for cs in 0..aircraft_configuration().cargo().len() {
             let cargo = test_bed().query(|a| a.max_cargo(cs));
             assert_eq!(
                 self.cargo(cs).get::<kilogram>().floor(),
@@ -614,21 +614,21 @@ impl BoardingTestBed {
     }
 
     fn target_no_cargo(mut self) -> Self {
-        for cs in 0..A380Payload::A380_CARGO.len() {
+        for cs in 0..aircraft_configuration().cargo().len() {
             self.target_cargo(cs, Mass::default());
         }
         self
     }
 
     fn target_half_cargo(mut self) -> Self {
-        for cs in 0..A380Payload::A380_CARGO.len() {
+        for cs in 0..aircraft_configuration().cargo().len() {
             self.target_cargo(cs, test_bed().query(|a| a.max_cargo(cs)) / 2.);
         }
         self
     }
 
     fn target_full_cargo(mut self) -> Self {
-        for cs in 0..A380Payload::A380_CARGO.len() {
+        for cs in 0..aircraft_configuration().cargo().len() {
             self.target_cargo(cs, test_bed().query(|a| a.max_cargo(cs)));
         }
         self
@@ -732,55 +732,55 @@ fn boarding_init() {
     assert!(test_bed.contains_variable_with_name("BOARDING_RATE"));
     assert!(test_bed.contains_variable_with_name("WB_PER_PAX_WEIGHT"));
     assert!(test_bed.contains_variable_with_name(
-        A380Payload::A380_PAX[Into::<usize>::into(A380Pax::MainFwdA)].pax_id
+        &aircraft_configuration().pax()[Into::<usize>::into(A380Pax::MainFwdA)].pax_id
     ));
     assert!(test_bed.contains_variable_with_name(
-        A380Payload::A380_PAX[Into::<usize>::into(A380Pax::MainFwdB)].pax_id
+        &aircraft_configuration().pax()[Into::<usize>::into(A380Pax::MainFwdB)].pax_id
     ));
     assert!(test_bed.contains_variable_with_name(
-        A380Payload::A380_PAX[Into::<usize>::into(A380Pax::MainMid1A)].pax_id
+        &aircraft_configuration().pax()[Into::<usize>::into(A380Pax::MainMid1A)].pax_id
     ));
     assert!(test_bed.contains_variable_with_name(
-        A380Payload::A380_PAX[Into::<usize>::into(A380Pax::MainMid1B)].pax_id
+        &aircraft_configuration().pax()[Into::<usize>::into(A380Pax::MainMid1B)].pax_id
     ));
     assert!(test_bed.contains_variable_with_name(
-        A380Payload::A380_PAX[Into::<usize>::into(A380Pax::MainMid1C)].pax_id
+        &aircraft_configuration().pax()[Into::<usize>::into(A380Pax::MainMid1C)].pax_id
     ));
     assert!(test_bed.contains_variable_with_name(
-        A380Payload::A380_PAX[Into::<usize>::into(A380Pax::MainMid2A)].pax_id
+        &aircraft_configuration().pax()[Into::<usize>::into(A380Pax::MainMid2A)].pax_id
     ));
     assert!(test_bed.contains_variable_with_name(
-        A380Payload::A380_PAX[Into::<usize>::into(A380Pax::MainMid2B)].pax_id
+        &aircraft_configuration().pax()[Into::<usize>::into(A380Pax::MainMid2B)].pax_id
     ));
     assert!(test_bed.contains_variable_with_name(
-        A380Payload::A380_PAX[Into::<usize>::into(A380Pax::MainMid2C)].pax_id
+        &aircraft_configuration().pax()[Into::<usize>::into(A380Pax::MainMid2C)].pax_id
     ));
     assert!(test_bed.contains_variable_with_name(
-        A380Payload::A380_PAX[Into::<usize>::into(A380Pax::MainAftA)].pax_id
+        &aircraft_configuration().pax()[Into::<usize>::into(A380Pax::MainAftA)].pax_id
     ));
     assert!(test_bed.contains_variable_with_name(
-        A380Payload::A380_PAX[Into::<usize>::into(A380Pax::MainAftB)].pax_id
+        &aircraft_configuration().pax()[Into::<usize>::into(A380Pax::MainAftB)].pax_id
     ));
     assert!(test_bed.contains_variable_with_name(
-        A380Payload::A380_PAX[Into::<usize>::into(A380Pax::UpperFwd)].pax_id
+        &aircraft_configuration().pax()[Into::<usize>::into(A380Pax::UpperFwd)].pax_id
     ));
     assert!(test_bed.contains_variable_with_name(
-        A380Payload::A380_PAX[Into::<usize>::into(A380Pax::UpperMidA)].pax_id
+        &aircraft_configuration().pax()[Into::<usize>::into(A380Pax::UpperMidA)].pax_id
     ));
     assert!(test_bed.contains_variable_with_name(
-        A380Payload::A380_PAX[Into::<usize>::into(A380Pax::UpperMidB)].pax_id
+        &aircraft_configuration().pax()[Into::<usize>::into(A380Pax::UpperMidB)].pax_id
     ));
     assert!(test_bed.contains_variable_with_name(
-        A380Payload::A380_PAX[Into::<usize>::into(A380Pax::UpperAft)].pax_id
+        &aircraft_configuration().pax()[Into::<usize>::into(A380Pax::UpperAft)].pax_id
     ));
     assert!(test_bed.contains_variable_with_name(
-        A380Payload::A380_CARGO[Into::<usize>::into(A380Cargo::Fwd)].cargo_id
+        &aircraft_configuration().cargo()[Into::<usize>::into(A380Cargo::Fwd)].cargo_id
     ));
     assert!(test_bed.contains_variable_with_name(
-        A380Payload::A380_CARGO[Into::<usize>::into(A380Cargo::Aft)].cargo_id
+        &aircraft_configuration().cargo()[Into::<usize>::into(A380Cargo::Aft)].cargo_id
     ));
     assert!(test_bed.contains_variable_with_name(
-        A380Payload::A380_CARGO[Into::<usize>::into(A380Cargo::Bulk)].cargo_id
+        &aircraft_configuration().cargo()[Into::<usize>::into(A380Cargo::Bulk)].cargo_id
     ));
 }
 #[test]
@@ -12,6 +12,7 @@ use uom::si::{
 use systems::{
     accept_iterable,
     air_conditioning::PackFlowControllers,
+    failures::FailureType,
     overhead::{AutoOffFaultPushButton, OnOffFaultPushButton},
     pneumatic::{
         valve::*, BleedMonitoringComputerIsAliveSignal, CompressionChamber,
@@ -24,8 +25,8 @@ use systems::{
     shared::{
         pid::PidController, update_iterator::MaxStepLoop, ControllerSignal, ElectricalBusType,
         ElectricalBuses, EngineBleedPushbutton, EngineCorrectedN1, EngineCorrectedN2,
-        EngineFirePushButtons, EngineStartState, HydraulicColor, PackFlowValveState,
-        PneumaticBleed, PneumaticValve, ReservoirAirPressure,
+        EngineFirePushButtons, EngineStartState, EngineStarterAirPressure, HydraulicColor,
+        PackFlowValveState, PneumaticBleed, PneumaticValve, ReservoirAirPressure,
     },
     simulation::{
         InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
@@ -374,6 +375,11 @@ impl EngineStartState for A380Pneumatic {
         self.fadec.engine_mode_selector()
     }
 }
+impl EngineStarterAirPressure for A380Pneumatic {
+    fn starter_air_pressure(&self, engine_number: usize) -> Pressure {
+        self.engine_systems[engine_number - 1].starter_air_pressure()
+    }
+}
 impl PackFlowValveState for A380Pneumatic {
     // fcv_id: 1, 2, 3 or 4
     fn pack_flow_valve_is_open(&self, fcv_id: usize) -> bool {
@@ -722,6 +728,7 @@ struct EngineBleedAirSystem {
     engine_starter_valve: DefaultValve,
     fan_air_valve: ElectroPneumaticValve,
     precooler: Precooler,
+    bleed_leak: PneumaticExhaust,
 
     intermediate_pressure_transducer: PressureTransducer,
     transfer_pressure_transducer: PressureTransducer,
@@ -729,6 +736,8 @@ struct EngineBleedAirSystem {
     differential_pressure_transducer: DifferentialPressureTransducer,
 }
 impl EngineBleedAirSystem {
+    const BLEED_LEAK_EXHAUST_SPEED: f64 = 1.;
+
     fn new(context: &mut InitContext, number: usize, powered_by: ElectricalBusType) -> Self {
         Self {
             high_pressure_id: context.get_identifier(format!("PNEU_ENG_{}_HP_PRESSURE", number)),
@@ -782,7 +791,8 @@ impl EngineBleedAirSystem {
             )),
             intermediate_pressure_valve: PurelyPneumaticValve::new(),
             high_pressure_valve: ElectroPneumaticValve::new(powered_by),
-            pressure_regulating_valve: ElectroPneumaticValve::new(powered_by),
+            pressure_regulating_valve: ElectroPneumaticValve::new(powered_by)
+                .with_stuck_failure(FailureType::BleedValveStuck(number)),
             fan_air_valve: ElectroPneumaticValve::new(powered_by),
             transfer_pressure_pipe: PneumaticPipe::new(
                 Volume::new::<cubic_meter>(1.),
@@ -811,7 +821,13 @@ impl EngineBleedAirSystem {
             ),
             engine_starter_exhaust: PneumaticExhaust::new(3e-2, 3e-2, Pressure::new::<psi>(0.)),
             engine_starter_valve: DefaultValve::new_closed(),
-            precooler: Precooler::new(180. * 2.),
+            precooler: Precooler::new(180. * 2.).with_failure(FailureType::PrecoolerFault(number)),
+            bleed_leak: PneumaticExhaust::new(
+                0.,
+                Self::BLEED_LEAK_EXHAUST_SPEED,
+                Pressure::new::<psi>(0.),
+            )
+            .with_leak_failure(FailureType::BleedLeak(number)),
             intermediate_pressure_transducer: PressureTransducer::new(powered_by),
             transfer_pressure_transducer: PressureTransducer::new(powered_by),
             regulated_pressure_transducer: PressureTransducer::new(powered_by),
@@ -878,6 +894,8 @@ impl EngineBleedAirSystem {
             &mut self.precooler_supply_pipe,
             &mut self.precooler_outlet_pipe,
         );
+        self.bleed_leak
+            .update_move_fluid(context, &mut self.precooler_outlet_pipe);
         self.engine_starter_valve.update_move_fluid(
             context,
             &mut self.precooler_inlet_pipe,
@@ -942,6 +960,10 @@ impl EngineBleedAirSystem {
         self.engine_starter_valve.is_open()
     }
 
+    fn starter_air_pressure(&self) -> Pressure {
+        self.engine_starter_container.pressure()
+    }
+
     fn pressure_regulating_valve_is_open(&self) -> bool {
         self.pressure_regulating_valve.is_open()
     }
@@ -967,6 +989,8 @@ impl SimulationElement for EngineBleedAirSystem {
         self.high_pressure_valve.accept(visitor);
         self.pressure_regulating_valve.accept(visitor);
         self.fan_air_valve.accept(visitor);
+        self.precooler.accept(visitor);
+        self.bleed_leak.accept(visitor);
 
         self.intermediate_pressure_transducer.accept(visitor);
         self.transfer_pressure_transducer.accept(visitor);
@@ -1452,9 +1476,9 @@ mod tests {
         },
         shared::{
             arinc429::{Arinc429Word, SignStatus},
-            ApuBleedAirValveSignal, CargoDoorLocked, ControllerSignal, ElectricalBusType,
-            ElectricalBuses, EmergencyElectricalState, EngineBleedPushbutton, EngineCorrectedN1,
-            EngineFirePushButtons, EngineStartState, HydraulicColor,
+            ApuBleedAirValveSignal, CargoDoorLocked, CargoVentilationIsolation, ControllerSignal,
+            ElectricalBusType, ElectricalBuses, EmergencyElectricalState, EngineBleedPushbutton,
+            EngineCorrectedN1, EngineFirePushButtons, EngineStartState, HydraulicColor,
             InternationalStandardAtmosphere, LgciuWeightOnWheels, MachNumber, PackFlowValveState,
             PneumaticBleed, PneumaticValve, PotentialOrigin,
         },
@@ -1483,6 +1507,7 @@ mod tests {
         adcn: A380AvionicsDataCommunicationNetwork,
         adirs: TestAdirs,
         dsms: TestDsms,
+        fire_and_smoke_protection: TestFireAndSmokeProtection,
         payload: TestPayload,
         pressurization_overhead: A380PressurizationOverheadPanel,
     }
@@ -1493,6 +1518,7 @@ mod tests {
                 adcn: A380AvionicsDataCommunicationNetwork::new(context),
                 adirs: TestAdirs::new(),
                 dsms: TestDsms {},
+                fire_and_smoke_protection: TestFireAndSmokeProtection {},
                 payload: TestPayload {},
                 pressurization_overhead: A380PressurizationOverheadPanel::new(context),
             }
@@ -1510,6 +1536,7 @@ mod tests {
                 context,
                 &self.adirs,
                 &self.dsms,
+                &self.fire_and_smoke_protection,
                 &self.adcn,
                 engines,
                 engine_fire_push_buttons,
@@ -1574,6 +1601,17 @@ mod tests {
         }
     }
 
+    struct TestFireAndSmokeProtection {}
+
+    impl CargoVentilationIsolation for TestFireAndSmokeProtection {
+        fn fwd_cargo_isolation_requested(&self) -> bool {
+            false
+        }
+        fn bulk_cargo_isolation_requested(&self) -> bool {
+            false
+        }
+    }
+
     struct TestPayload;
     impl NumberOfPassengers for TestPayload {
         fn number_of_passengers(&self, _ps: usize) -> i8 {
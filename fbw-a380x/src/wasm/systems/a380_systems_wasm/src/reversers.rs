@@ -58,6 +58,8 @@ impl VariablesToObject for ReverserThrust {
             Variable::named("REVERSER_ANGULAR_ACCELERATION"),
             Variable::aspect("BRAKE LEFT FORCE FACTOR"),
             Variable::aspect("BRAKE RIGHT FORCE FACTOR"),
+            Variable::aspect("TYRE_DRAG_DELTA_SPEED"),
+            Variable::named("TYRE_DRAG_ANGULAR_ACCELERATION"),
         ]
     }
 
@@ -68,14 +70,19 @@ impl VariablesToObject for ReverserThrust {
             && values[0] > LOW_SPEED_MODE_SPEED_THRESHOLD_FOOT_PER_SEC
             && !brakes_in_use
         {
-            values[0] + LOW_SPEED_MODE_SPEED_FORCE_MULTIPLIER * values[1]
+            values[0] + LOW_SPEED_MODE_SPEED_FORCE_MULTIPLIER * values[1] + values[6]
         } else {
-            values[0] + values[1]
+            values[0] + values[1] + values[6]
         };
 
-        self.angular_acc_y = values[2] + ASYMETRY_EFFECT_MAGIC_MULTIPLIER * values[3];
+        self.angular_acc_y = values[2] + ASYMETRY_EFFECT_MAGIC_MULTIPLIER * values[3] + values[7];
 
-        ObjectWrite::on(values[1].abs() > 0. || values[3].abs() > 0.)
+        ObjectWrite::on(
+            values[1].abs() > 0.
+                || values[3].abs() > 0.
+                || values[6].abs() > 0.
+                || values[7].abs() > 0.,
+        )
     }
 
     set_data_on_sim_object!();
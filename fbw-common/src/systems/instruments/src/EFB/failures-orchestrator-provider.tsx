@@ -2,14 +2,26 @@
 // SPDX-License-Identifier: GPL-3.0
 
 import React, { PropsWithChildren, useState } from 'react';
-import { Failure, FailuresOrchestrator, useUpdate, FailureDefinition } from '@flybywiresim/fbw-sdk';
+import {
+  Failure,
+  FailuresOrchestrator,
+  useUpdate,
+  FailureDefinition,
+  FailureOrigin,
+  FailureTrigger,
+} from '@flybywiresim/fbw-sdk';
 
 interface FailuresOrchestratorContext {
   allFailures: Readonly<Readonly<Failure>[]>;
   activeFailures: Set<number>;
   changingFailures: Set<number>;
+  armedFailures: Set<number>;
   activate(identifier: number): Promise<void>;
   deactivate(identifier: number): Promise<void>;
+  arm(identifier: number, trigger: FailureTrigger): Promise<void>;
+  disarm(identifier: number): Promise<void>;
+  origin(identifier: number): Readonly<FailureOrigin> | undefined;
+  rootCause(identifier: number): number | undefined;
 }
 
 const createOrchestrator = (failures: FailureDefinition[]) => new FailuresOrchestrator('A32NX', failures);
@@ -18,8 +30,13 @@ const Context = React.createContext<FailuresOrchestratorContext>({
   allFailures: [],
   activeFailures: new Set<number>(),
   changingFailures: new Set<number>(),
+  armedFailures: new Set<number>(),
   activate: () => Promise.resolve(),
   deactivate: () => Promise.resolve(),
+  arm: () => Promise.resolve(),
+  disarm: () => Promise.resolve(),
+  origin: () => undefined,
+  rootCause: () => undefined,
 });
 
 export interface FailuresOrchestratorProviderProps {
@@ -35,6 +52,7 @@ export const FailuresOrchestratorProvider: React.FC<PropsWithChildren<FailuresOr
   const [allFailures] = useState(() => orchestrator.getAllFailures());
   const [activeFailures, setActiveFailures] = useState<Set<number>>(() => new Set<number>());
   const [changingFailures, setChangingFailures] = useState<Set<number>>(() => new Set<number>());
+  const [armedFailures, setArmedFailures] = useState<Set<number>>(() => new Set<number>());
 
   useUpdate(() => {
     orchestrator.update();
@@ -48,6 +66,11 @@ export const FailuresOrchestratorProvider: React.FC<PropsWithChildren<FailuresOr
     if (!areEqual(changingFailures, cf)) {
       setChangingFailures(cf);
     }
+
+    const armed = orchestrator.getArmedFailures();
+    if (!areEqual(armedFailures, armed)) {
+      setArmedFailures(armed);
+    }
   });
 
   return (
@@ -56,8 +79,13 @@ export const FailuresOrchestratorProvider: React.FC<PropsWithChildren<FailuresOr
         allFailures,
         activeFailures,
         changingFailures,
+        armedFailures,
         activate: (identifier) => orchestrator.activate(identifier),
         deactivate: (identifier) => orchestrator.deactivate(identifier),
+        arm: (identifier, trigger) => orchestrator.arm(identifier, trigger),
+        disarm: (identifier) => orchestrator.disarm(identifier),
+        origin: (identifier) => orchestrator.getOrigin(identifier),
+        rootCause: (identifier) => orchestrator.getRootCause(identifier),
       }}
     >
       {children}
@@ -0,0 +1,82 @@
+// Copyright (c) 2026 FlyByWire Simulations
+// SPDX-License-Identifier: GPL-3.0
+
+import { Reader, SimVarReaderWriter, Writer } from './communication';
+import {
+  getArmFailureFromSimVarName,
+  getArmFailureSimVarName,
+  getArmFailureToSimVarName,
+  getArmFailureTriggerSimVarName,
+} from './sim-vars';
+
+export enum FailureTriggerKind {
+  /** Activates after a random time with the given mean time between failures, `from` in hours. */
+  Mtbf = 1,
+  /** Activates at a random moment within the window, `from` and `to` in seconds after arming. */
+  TimeWindow = 2,
+  /** Activates when crossing a random altitude within the band, `from` and `to` in feet. */
+  AltitudeBand = 3,
+  /** Activates when crossing a random speed within the band, `from` and `to` in knots. */
+  SpeedBand = 4,
+  /** Activates once the FWC flight phase given by `from` is reached. */
+  FlightPhase = 5,
+}
+
+/**
+ * Condition under which the systems activate an armed failure.
+ */
+export interface FailureTrigger {
+  kind: FailureTriggerKind;
+  from: number;
+  to: number;
+}
+
+/**
+ * Writes the identifier of a failure to arm, after writing the trigger it was armed with.
+ *
+ * The systems read the trigger at the same time as the identifier, so the trigger is only written when the
+ * identifier is about to be written.
+ */
+export class FailureArmingSimVar implements Reader, Writer {
+  private identifierSimVar: SimVarReaderWriter;
+
+  private triggerSimVar: SimVarReaderWriter;
+
+  private fromSimVar: SimVarReaderWriter;
+
+  private toSimVar: SimVarReaderWriter;
+
+  private pendingTriggers: [number, FailureTrigger][] = [];
+
+  constructor(simVarPrefix: string) {
+    this.identifierSimVar = new SimVarReaderWriter(getArmFailureSimVarName(simVarPrefix));
+    this.triggerSimVar = new SimVarReaderWriter(getArmFailureTriggerSimVarName(simVarPrefix));
+    this.fromSimVar = new SimVarReaderWriter(getArmFailureFromSimVarName(simVarPrefix));
+    this.toSimVar = new SimVarReaderWriter(getArmFailureToSimVarName(simVarPrefix));
+  }
+
+  /**
+   * Sets the trigger to write with the next write of the given identifier.
+   */
+  setTrigger(identifier: number, trigger: FailureTrigger) {
+    this.pendingTriggers.push([identifier, trigger]);
+  }
+
+  read(): number {
+    return this.identifierSimVar.read();
+  }
+
+  async write(identifier: number): Promise<void> {
+    const index = this.pendingTriggers.findIndex(([pendingIdentifier]) => pendingIdentifier === identifier);
+    if (index !== -1) {
+      const [[, trigger]] = this.pendingTriggers.splice(index, 1);
+      await Promise.all([
+        this.triggerSimVar.write(trigger.kind),
+        this.fromSimVar.write(trigger.from),
+        this.toSimVar.write(trigger.to),
+      ]);
+    }
+
+    return this.identifierSimVar.write(identifier);
+  }
+}
@@ -1,5 +1,15 @@
-import { FailuresOrchestrator } from '.';
-import { getActivateFailureSimVarName, getDeactivateFailureSimVarName } from './sim-vars';
+import { FailureOriginKind, FailuresOrchestrator, FailureTriggerKind } from '.';
+import {
+  getActivateFailureSimVarName,
+  getArmFailureFromSimVarName,
+  getArmFailureSimVarName,
+  getArmFailureToSimVarName,
+  getArmFailureTriggerSimVarName,
+  getDeactivateFailureSimVarName,
+  getDisarmFailureSimVarName,
+  getInducedFailureCauseSimVarName,
+  getInducedFailureSimVarName,
+} from './sim-vars';
 import { flushPromises } from './test-functions';
 
 describe('FailuresOrchestrator', () => {
@@ -29,6 +39,26 @@ describe('FailuresOrchestrator', () => {
       expect(o.isActive(identifier)).toBe(true);
     });
 
+    test('active when induced by the systems', async () => {
+      const o = orchestrator();
+
+      await SimVar.SetSimVarValue(inducedSimVarName, 'number', identifier);
+      o.update();
+
+      expect(o.isActive(identifier)).toBe(true);
+      expect(SimVar.GetSimVarValue(inducedSimVarName, 'number')).toBe(0);
+    });
+
+    test('inactive when deactivated after being induced by the systems', async () => {
+      const o = orchestrator();
+      await SimVar.SetSimVarValue(inducedSimVarName, 'number', identifier);
+      o.update();
+
+      await deactivateFailure(o);
+
+      expect(o.isActive(identifier)).toBe(false);
+    });
+
     test('inactive when deactivated', async () => {
       const o = orchestrator();
       // First activate the failure to ensure we're not just observing
@@ -40,6 +70,44 @@ describe('FailuresOrchestrator', () => {
       expect(o.isActive(identifier)).toBe(false);
     });
 
+    describe('armed', () => {
+      test('when armed with its trigger', async () => {
+        const o = orchestrator();
+
+        await armFailure(o);
+
+        expect(o.isArmed(identifier)).toBe(true);
+        expect(o.isActive(identifier)).toBe(false);
+        expect(SimVar.GetSimVarValue(armTriggerSimVarName, 'number')).toBe(FailureTriggerKind.AltitudeBand);
+        expect(SimVar.GetSimVarValue(armFromSimVarName, 'number')).toBe(1000);
+        expect(SimVar.GetSimVarValue(armToSimVarName, 'number')).toBe(2000);
+      });
+
+      test('no longer when disarmed', async () => {
+        const o = orchestrator();
+        await armFailure(o);
+
+        const promise = o.disarm(identifier);
+        await flushPromises();
+        await SimVar.SetSimVarValue(disarmSimVarName, 'number', 0);
+        o.update();
+        await promise;
+
+        expect(o.isArmed(identifier)).toBe(false);
+      });
+
+      test('no longer when triggered by the systems', async () => {
+        const o = orchestrator();
+        await armFailure(o);
+
+        await SimVar.SetSimVarValue(inducedSimVarName, 'number', identifier);
+        o.update();
+
+        expect(o.isArmed(identifier)).toBe(false);
+        expect(o.isActive(identifier)).toBe(true);
+      });
+    });
+
     describe('changing', () => {
       test('while failure is activating', async () => {
         const o = orchestrator();
@@ -76,19 +144,80 @@ describe('FailuresOrchestrator', () => {
       });
     });
   });
+
+  describe('indicates the origin of a failure is', () => {
+    test('commanded when activated', async () => {
+      const o = orchestrator();
+
+      await activateFailure(o);
+
+      expect(o.getOrigin(identifier)).toEqual({ kind: FailureOriginKind.Commanded });
+      expect(o.getRootCause(identifier)).toBe(identifier);
+    });
+
+    test('scheduled when induced by the systems without a cause', async () => {
+      const o = orchestrator();
+
+      await induceFailure(o, identifier, 0);
+
+      expect(o.getOrigin(identifier)).toEqual({ kind: FailureOriginKind.Scheduled });
+      expect(o.getRootCause(identifier)).toBe(identifier);
+    });
+
+    test('cascaded when induced by the systems with a cause', async () => {
+      const o = cascadeOrchestrator();
+      await activateFailure(o);
+
+      await induceFailure(o, effectIdentifier, identifier);
+      await induceFailure(o, secondEffectIdentifier, effectIdentifier);
+
+      expect(o.getOrigin(secondEffectIdentifier)).toEqual({
+        kind: FailureOriginKind.Cascaded,
+        cause: effectIdentifier,
+      });
+      expect(o.getRootCause(secondEffectIdentifier)).toBe(identifier);
+    });
+
+    test('unknown when deactivated', async () => {
+      const o = orchestrator();
+      await activateFailure(o);
+
+      await deactivateFailure(o);
+
+      expect(o.getOrigin(identifier)).toBeUndefined();
+      expect(o.getRootCause(identifier)).toBeUndefined();
+    });
+  });
 });
 
 const prefix = 'PREFIX';
 const activateSimVarName = getActivateFailureSimVarName(prefix);
 const deactivateSimVarName = getDeactivateFailureSimVarName(prefix);
+const inducedSimVarName = getInducedFailureSimVarName(prefix);
+const inducedCauseSimVarName = getInducedFailureCauseSimVarName(prefix);
+const armSimVarName = getArmFailureSimVarName(prefix);
+const armTriggerSimVarName = getArmFailureTriggerSimVarName(prefix);
+const armFromSimVarName = getArmFailureFromSimVarName(prefix);
+const armToSimVarName = getArmFailureToSimVarName(prefix);
+const disarmSimVarName = getDisarmFailureSimVarName(prefix);
 
 const identifier = 123;
+const effectIdentifier = 456;
+const secondEffectIdentifier = 789;
 const name = 'test';
 
 function orchestrator() {
   return new FailuresOrchestrator(prefix, [[0, identifier, name]]);
 }
 
+function cascadeOrchestrator() {
+  return new FailuresOrchestrator(prefix, [
+    [0, identifier, name],
+    [0, effectIdentifier, name],
+    [0, secondEffectIdentifier, name],
+  ]);
+}
+
 function activateFailure(o: FailuresOrchestrator) {
   return activateOrDeactivateFailure(o, true);
 }
@@ -105,3 +234,18 @@ async function activateOrDeactivateFailure(o: FailuresOrchestrator, activate: bo
 
   await promise;
 }
+
+async function armFailure(o: FailuresOrchestrator) {
+  const promise = o.arm(identifier, { kind: FailureTriggerKind.AltitudeBand, from: 1000, to: 2000 });
+  await flushPromises();
+  await SimVar.SetSimVarValue(armSimVarName, 'number', 0);
+  o.update();
+
+  await promise;
+}
+
+async function induceFailure(o: FailuresOrchestrator, inducedIdentifier: number, cause: number) {
+  await SimVar.SetSimVarValue(inducedCauseSimVarName, 'number', cause);
+  await SimVar.SetSimVarValue(inducedSimVarName, 'number', inducedIdentifier);
+  o.update();
+}
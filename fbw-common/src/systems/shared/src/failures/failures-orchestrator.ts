@@ -3,8 +3,15 @@
 // SPDX-License-Identifier: GPL-3.0
 
 import { AtaChapterNumber } from '../ata';
-import { QueuedSimVarWriter, SimVarReaderWriter } from './communication';
-import { getActivateFailureSimVarName, getDeactivateFailureSimVarName } from './sim-vars';
+import { QueuedSimVarReader, QueuedSimVarWriter, SimVarReaderWriter } from './communication';
+import { FailureArmingSimVar, FailureTrigger } from './failure-trigger';
+import {
+  getActivateFailureSimVarName,
+  getDeactivateFailureSimVarName,
+  getDisarmFailureSimVarName,
+  getInducedFailureCauseSimVarName,
+  getInducedFailureSimVarName,
+} from './sim-vars';
 
 export interface Failure {
   ata: AtaChapterNumber;
@@ -14,9 +21,31 @@ export interface Failure {
 
 export type FailureDefinition = [AtaChapterNumber, number, string];
 
+export enum FailureOriginKind {
+  /** Activated through the orchestrator. */
+  Commanded,
+  /** Activated by the systems when the trigger it was armed with was met. */
+  Scheduled,
+  /** Activated by the systems as the effect of another failure. */
+  Cascaded,
+}
+
+/**
+ * Where an active failure originates from.
+ */
+export interface FailureOrigin {
+  kind: FailureOriginKind;
+  /** The identifier of the failure this failure is an effect of, when cascaded. */
+  cause?: number;
+}
+
 /**
  * Orchestrates the activation and deactivation of failures.
  *
+ * Failures can also be armed, to be activated by the systems once their trigger condition is met. Failures
+ * activated by the systems themselves, either when triggered or as the effect of another failure, are reported
+ * back with their origin and become active like any other failure.
+ *
  * Only a single instance of the orchestrator should exist within the whole application.
  */
 export class FailuresOrchestrator {
@@ -24,12 +53,26 @@ export class FailuresOrchestrator {
 
   private activeFailures = new Set<number>();
 
+  private origins = new Map<number, FailureOrigin>();
+
   private changingFailures = new Set<number>();
 
+  private armedFailures = new Set<number>();
+
   private activateFailureQueue: QueuedSimVarWriter;
 
   private deactivateFailureQueue: QueuedSimVarWriter;
 
+  private armFailureSimVar: FailureArmingSimVar;
+
+  private armFailureQueue: QueuedSimVarWriter;
+
+  private disarmFailureQueue: QueuedSimVarWriter;
+
+  private inducedFailureReader: QueuedSimVarReader;
+
+  private inducedFailureCauseSimVar: SimVarReaderWriter;
+
   constructor(simVarPrefix: string, failures: FailureDefinition[]) {
     this.activateFailureQueue = new QueuedSimVarWriter(
       new SimVarReaderWriter(getActivateFailureSimVarName(simVarPrefix)),
@@ -37,18 +80,29 @@ export class FailuresOrchestrator {
     this.deactivateFailureQueue = new QueuedSimVarWriter(
       new SimVarReaderWriter(getDeactivateFailureSimVarName(simVarPrefix)),
     );
+    this.armFailureSimVar = new FailureArmingSimVar(simVarPrefix);
+    this.armFailureQueue = new QueuedSimVarWriter(this.armFailureSimVar);
+    this.disarmFailureQueue = new QueuedSimVarWriter(new SimVarReaderWriter(getDisarmFailureSimVarName(simVarPrefix)));
+    this.inducedFailureReader = new QueuedSimVarReader(
+      new SimVarReaderWriter(getInducedFailureSimVarName(simVarPrefix)),
+    );
+    this.inducedFailureCauseSimVar = new SimVarReaderWriter(getInducedFailureCauseSimVarName(simVarPrefix));
     failures.forEach((failure) => {
       this.failures.push({
         ata: failure[0],
         identifier: failure[1],
         name: failure[2],
       });
+      this.inducedFailureReader.register(failure[1], () => this.onInduced(failure[1]));
     });
   }
 
   update() {
     this.activateFailureQueue.update();
     this.deactivateFailureQueue.update();
+    this.armFailureQueue.update();
+    this.disarmFailureQueue.update();
+    this.inducedFailureReader.update();
   }
 
   /**
@@ -58,7 +112,7 @@ export class FailuresOrchestrator {
     this.changingFailures.add(identifier);
     await this.activateFailureQueue.write(identifier);
     this.changingFailures.delete(identifier);
-    this.activeFailures.add(identifier);
+    this.setActive(identifier, { kind: FailureOriginKind.Commanded });
   }
 
   /**
@@ -69,6 +123,28 @@ export class FailuresOrchestrator {
     await this.deactivateFailureQueue.write(identifier);
     this.changingFailures.delete(identifier);
     this.activeFailures.delete(identifier);
+    this.origins.delete(identifier);
+  }
+
+  /**
+   * Arms the failure with the given identifier, to be activated by the systems once the trigger condition is met.
+   */
+  async arm(identifier: number, trigger: FailureTrigger): Promise<void> {
+    this.changingFailures.add(identifier);
+    this.armFailureSimVar.setTrigger(identifier, trigger);
+    await this.armFailureQueue.write(identifier);
+    this.changingFailures.delete(identifier);
+    this.armedFailures.add(identifier);
+  }
+
+  /**
+   * Disarms the failure with the given identifier, without changing whether or not it is active.
+   */
+  async disarm(identifier: number): Promise<void> {
+    this.changingFailures.add(identifier);
+    await this.disarmFailureQueue.write(identifier);
+    this.changingFailures.delete(identifier);
+    this.armedFailures.delete(identifier);
   }
 
   /**
@@ -86,6 +162,46 @@ export class FailuresOrchestrator {
     return this.changingFailures.has(identifier);
   }
 
+  /**
+   * Determines whether or not the failure with the given identifier is armed and waiting for its trigger.
+   */
+  isArmed(identifier: number): boolean {
+    return this.armedFailures.has(identifier);
+  }
+
+  /**
+   * Gets where the active failure with the given identifier originates from.
+   */
+  getOrigin(identifier: number): Readonly<FailureOrigin> | undefined {
+    return this.origins.get(identifier);
+  }
+
+  /**
+   * Follows the causes of the active failure with the given identifier back to the failure which started the
+   * cascade. When a cause was deactivated in the meantime, the chain ends at that cause.
+   */
+  getRootCause(identifier: number): number | undefined {
+    if (!this.isActive(identifier)) {
+      return undefined;
+    }
+
+    let rootCause = identifier;
+    // Bounded, as a cycle in the dependencies would otherwise never end.
+    for (let i = 0; i < this.activeFailures.size; i++) {
+      const origin = this.origins.get(rootCause);
+      if (origin?.kind !== FailureOriginKind.Cascaded) {
+        break;
+      }
+
+      rootCause = origin.cause;
+      if (!this.isActive(rootCause)) {
+        break;
+      }
+    }
+
+    return rootCause;
+  }
+
   getAllFailures(): Readonly<Readonly<Failure>[]> {
     return this.failures;
   }
@@ -97,4 +213,25 @@ export class FailuresOrchestrator {
   getChangingFailures(): Set<number> {
     return new Set(this.changingFailures);
   }
+
+  getArmedFailures(): Set<number> {
+    return new Set(this.armedFailures);
+  }
+
+  private onInduced(identifier: number) {
+    const cause = this.inducedFailureCauseSimVar.read();
+
+    this.armedFailures.delete(identifier);
+    this.setActive(
+      identifier,
+      cause !== 0 ? { kind: FailureOriginKind.Cascaded, cause } : { kind: FailureOriginKind.Scheduled },
+    );
+  }
+
+  private setActive(identifier: number, origin: FailureOrigin) {
+    if (!this.activeFailures.has(identifier)) {
+      this.activeFailures.add(identifier);
+      this.origins.set(identifier, origin);
+    }
+  }
 }
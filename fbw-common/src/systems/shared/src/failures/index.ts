@@ -2,5 +2,7 @@
 // SPDX-License-Identifier: GPL-3.0
 
 export { FailuresConsumer } from './failures-consumer';
-export { FailuresOrchestrator, FailureDefinition } from './failures-orchestrator';
-export type { Failure } from './failures-orchestrator';
+export { FailuresOrchestrator, FailureDefinition, FailureOriginKind } from './failures-orchestrator';
+export type { Failure, FailureOrigin } from './failures-orchestrator';
+export { FailureTriggerKind } from './failure-trigger';
+export type { FailureTrigger } from './failure-trigger';
@@ -5,3 +5,31 @@ export function getActivateFailureSimVarName(prefix: string) {
 export function getDeactivateFailureSimVarName(prefix: string) {
   return `L:${prefix}_FAILURE_DEACTIVATE`;
 }
+
+export function getInducedFailureSimVarName(prefix: string) {
+  return `L:${prefix}_FAILURE_INDUCED`;
+}
+
+export function getArmFailureSimVarName(prefix: string) {
+  return `L:${prefix}_FAILURE_ARM`;
+}
+
+export function getArmFailureTriggerSimVarName(prefix: string) {
+  return `L:${prefix}_FAILURE_ARM_TRIGGER`;
+}
+
+export function getArmFailureFromSimVarName(prefix: string) {
+  return `L:${prefix}_FAILURE_ARM_FROM`;
+}
+
+export function getArmFailureToSimVarName(prefix: string) {
+  return `L:${prefix}_FAILURE_ARM_TO`;
+}
+
+export function getDisarmFailureSimVarName(prefix: string) {
+  return `L:${prefix}_FAILURE_DISARM`;
+}
+
+export function getInducedFailureCauseSimVarName(prefix: string) {
+  return `L:${prefix}_FAILURE_INDUCED_CAUSE`;
+}
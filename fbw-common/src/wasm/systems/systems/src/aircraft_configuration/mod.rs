@@ -0,0 +1,803 @@
+//! Data driven description of an aircraft's fuel tanks, payload stations and the fuselage
+//! geometry used to validate them, as well as of the models its systems are simulated with.
+//!
+//! A configuration is parsed from TOML or JSON and validated before it is handed out, so
+//! mistakes in the description surface at initialisation instead of as odd weight and balance
+//! behaviour later on. Aircraft read their configuration from a file at initialisation and fall
+//! back to the one compiled into them when there is no such file.
+
+use std::{
+    cell::Cell,
+    collections::HashSet,
+    convert::TryInto,
+    fmt::{self, Display},
+    fs, io,
+    path::Path,
+    rc::Rc,
+};
+
+use nalgebra::Vector3;
+use serde::Deserialize;
+use uom::si::{f64::Mass, mass::kilogram};
+
+use crate::{
+    fuel::{FuelInfo, FuelSystem, FuelTank, FUEL_GALLONS_TO_KG},
+    payload::{
+        BoardingAgent, BoardingAgentInfo, Cargo, CargoDeck, CargoInfo, PassengerDeck, Pax, PaxInfo,
+    },
+    simulation::InitContext,
+};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum AircraftConfigurationError {
+    Io(String),
+    Parse(String),
+    DuplicateId(String),
+    NegativeCapacity {
+        id: String,
+        capacity: f64,
+    },
+    NonFinite {
+        id: String,
+        field: &'static str,
+    },
+    OutsideFuselage {
+        id: String,
+        position: (f64, f64, f64),
+    },
+    InvalidBoardingOrder {
+        door_id: String,
+        station: usize,
+    },
+    StationCount {
+        kind: &'static str,
+        expected: usize,
+        actual: usize,
+    },
+}
+impl Display for AircraftConfigurationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AircraftConfigurationError::Io(message) => {
+                write!(f, "failed to read configuration: {}", message)
+            }
+            AircraftConfigurationError::Parse(message) => {
+                write!(f, "failed to parse configuration: {}", message)
+            }
+            AircraftConfigurationError::DuplicateId(id) => {
+                write!(f, "the id \"{}\" is used more than once", id)
+            }
+            AircraftConfigurationError::NegativeCapacity { id, capacity } => {
+                write!(f, "\"{}\" has a negative capacity of {}", id, capacity)
+            }
+            AircraftConfigurationError::NonFinite { id, field } => {
+                write!(f, "\"{}\" has a {} which is not a finite number", id, field)
+            }
+            AircraftConfigurationError::OutsideFuselage { id, position } => write!(
+                f,
+                "\"{}\" at {:?} is located outside of the fuselage",
+                id, position
+            ),
+            AircraftConfigurationError::InvalidBoardingOrder { door_id, station } => write!(
+                f,
+                "the boarding order of \"{}\" refers to passenger station {} which does not exist",
+                door_id, station
+            ),
+            AircraftConfigurationError::StationCount {
+                kind,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "expected {} {} but the configuration contains {}",
+                expected, kind, actual
+            ),
+        }
+    }
+}
+impl std::error::Error for AircraftConfigurationError {}
+
+fn is_finite(position: (f64, f64, f64)) -> bool {
+    position.0.is_finite() && position.1.is_finite() && position.2.is_finite()
+}
+
+/// The extent of the fuselage in the aircraft's reference frame, in feet.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FuselageInfo {
+    pub forward_limit: f64,
+    pub aft_limit: f64,
+    pub half_width: f64,
+}
+impl FuselageInfo {
+    fn contains_longitudinally(&self, position: (f64, f64, f64)) -> bool {
+        (self.aft_limit..=self.forward_limit).contains(&position.0)
+    }
+
+    fn contains(&self, position: (f64, f64, f64)) -> bool {
+        self.contains_longitudinally(position) && position.1.abs() <= self.half_width
+    }
+}
+
+/// The model which provides the engine parameters.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum EngineModel {
+    /// The engine parameters are read from the simulator.
+    #[default]
+    Simulator,
+    /// The engine parameters are computed by the native spool model.
+    Spool,
+}
+
+/// The model which manages the fuel quantities.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum FuelModel {
+    /// The fuel quantities are managed by the simulator and the FADEC.
+    #[default]
+    Simulator,
+    /// The fuel quantities are managed by the native fuel model, which feeds the engines through
+    /// its pumps and valves.
+    Native,
+}
+
+/// The model which owns the brake temperatures.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum BrakeTemperatureModel {
+    /// The brake temperatures are computed by the instruments. They don't affect braking.
+    #[default]
+    Instruments,
+    /// The brake temperatures are computed by the native brake thermal model. Hot brakes fade
+    /// and melt the wheel fuse plugs.
+    Native,
+}
+
+/// Selects the models the aircraft's systems are simulated with. Systems which aren't listed in
+/// the configuration are left to the simulator.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(default, deny_unknown_fields)]
+pub struct SystemModels {
+    pub engine: EngineModel,
+    pub fuel: FuelModel,
+    pub brake_temperature: BrakeTemperatureModel,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AircraftConfiguration {
+    fuselage: FuselageInfo,
+    #[serde(default)]
+    models: SystemModels,
+    #[serde(default)]
+    fuel_tanks: Vec<FuelInfo>,
+    #[serde(default)]
+    pax: Vec<PaxInfo>,
+    #[serde(default)]
+    cargo: Vec<CargoInfo>,
+    #[serde(default)]
+    default_boarding_order: Vec<usize>,
+    #[serde(default)]
+    boarding_agents: Vec<BoardingAgentInfo>,
+}
+impl AircraftConfiguration {
+    pub fn from_toml(configuration: &str) -> Result<Self, AircraftConfigurationError> {
+        let configuration: Self = toml::from_str(configuration)
+            .map_err(|e| AircraftConfigurationError::Parse(e.to_string()))?;
+        configuration.validate()?;
+
+        Ok(configuration)
+    }
+
+    pub fn from_json(configuration: &str) -> Result<Self, AircraftConfigurationError> {
+        let configuration: Self = serde_json::from_str(configuration)
+            .map_err(|e| AircraftConfigurationError::Parse(e.to_string()))?;
+        configuration.validate()?;
+
+        Ok(configuration)
+    }
+
+    /// Reads the TOML configuration from the file at `path`. When there is no such file, the
+    /// `embedded` configuration is used instead.
+    pub fn from_toml_file_or(
+        path: impl AsRef<Path>,
+        embedded: &str,
+    ) -> Result<Self, AircraftConfigurationError> {
+        Self::from_toml(&Self::read_toml_file_or(path, embedded)?)
+    }
+
+    /// Reads the TOML text of the configuration at `path`, without parsing it. When there is no
+    /// such file, the `embedded` text is returned instead.
+    pub fn read_toml_file_or(
+        path: impl AsRef<Path>,
+        embedded: &str,
+    ) -> Result<String, AircraftConfigurationError> {
+        match fs::read_to_string(path) {
+            Ok(configuration) => Ok(configuration),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(embedded.to_owned()),
+            Err(e) => Err(AircraftConfigurationError::Io(e.to_string())),
+        }
+    }
+
+    fn validate(&self) -> Result<(), AircraftConfigurationError> {
+        let fuselage_limits = [
+            ("forward limit", self.fuselage.forward_limit),
+            ("aft limit", self.fuselage.aft_limit),
+            ("half width", self.fuselage.half_width),
+        ];
+        if let Some((field, _)) = fuselage_limits
+            .into_iter()
+            .find(|(_, value)| !value.is_finite())
+        {
+            return Err(AircraftConfigurationError::NonFinite {
+                id: "fuselage".to_owned(),
+                field,
+            });
+        }
+
+        let finite_values = self
+            .fuel_tanks
+            .iter()
+            .flat_map(|f| {
+                [
+                    (
+                        &f.fuel_tank_id,
+                        "capacity",
+                        f.total_capacity_gallons.is_finite(),
+                    ),
+                    (&f.fuel_tank_id, "position", is_finite(f.position)),
+                ]
+            })
+            .chain(
+                self.pax
+                    .iter()
+                    .map(|p| (&p.pax_id, "position", is_finite(p.position))),
+            )
+            .chain(self.cargo.iter().flat_map(|c| {
+                [
+                    (&c.cargo_id, "capacity", c.max_cargo_kg.is_finite()),
+                    (&c.cargo_id, "position", is_finite(c.position)),
+                ]
+            }));
+        for (id, field, finite) in finite_values {
+            if !finite {
+                return Err(AircraftConfigurationError::NonFinite {
+                    id: id.clone(),
+                    field,
+                });
+            }
+        }
+
+        let mut ids = HashSet::new();
+        let all_ids = self
+            .fuel_tanks
+            .iter()
+            .map(|f| &f.fuel_tank_id)
+            .chain(self.pax.iter().flat_map(|p| [&p.pax_id, &p.payload_id]))
+            .chain(self.cargo.iter().flat_map(|c| [&c.cargo_id, &c.payload_id]));
+        for id in all_ids {
+            if !ids.insert(id) {
+                return Err(AircraftConfigurationError::DuplicateId(id.clone()));
+            }
+        }
+
+        let capacities = self
+            .fuel_tanks
+            .iter()
+            .map(|f| (&f.fuel_tank_id, f.total_capacity_gallons))
+            .chain(self.pax.iter().map(|p| (&p.pax_id, p.max_pax as f64)))
+            .chain(self.cargo.iter().map(|c| (&c.cargo_id, c.max_cargo_kg)));
+        for (id, capacity) in capacities {
+            if capacity < 0. {
+                return Err(AircraftConfigurationError::NegativeCapacity {
+                    id: id.clone(),
+                    capacity,
+                });
+            }
+        }
+
+        // Wing tanks extend beyond the fuselage laterally, thus only their longitudinal position
+        // is checked. Payload stations have to be fully contained within the fuselage.
+        let outside_fuselage = self
+            .fuel_tanks
+            .iter()
+            .filter(|f| !self.fuselage.contains_longitudinally(f.position))
+            .map(|f| (&f.fuel_tank_id, f.position))
+            .chain(
+                self.pax
+                    .iter()
+                    .filter(|p| !self.fuselage.contains(p.position))
+                    .map(|p| (&p.pax_id, p.position)),
+            )
+            .chain(
+                self.cargo
+                    .iter()
+                    .filter(|c| !self.fuselage.contains(c.position))
+                    .map(|c| (&c.cargo_id, c.position)),
+            )
+            .next();
+        if let Some((id, position)) = outside_fuselage {
+            return Err(AircraftConfigurationError::OutsideFuselage {
+                id: id.clone(),
+                position,
+            });
+        }
+
+        let boarding_orders = std::iter::once(("default", &self.default_boarding_order)).chain(
+            self.boarding_agents
+                .iter()
+                .map(|agent| (agent.door_id.as_deref().unwrap_or("default"), &agent.order)),
+        );
+        for (door_id, order) in boarding_orders {
+            if let Some(&station) = order.iter().find(|&&ps| ps >= self.pax.len()) {
+                return Err(AircraftConfigurationError::InvalidBoardingOrder {
+                    door_id: door_id.to_owned(),
+                    station,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn models(&self) -> SystemModels {
+        self.models
+    }
+
+    pub fn fuel_tanks(&self) -> &[FuelInfo] {
+        &self.fuel_tanks
+    }
+
+    pub fn pax(&self) -> &[PaxInfo] {
+        &self.pax
+    }
+
+    pub fn cargo(&self) -> &[CargoInfo] {
+        &self.cargo
+    }
+
+    pub fn fuel_system<const N: usize>(
+        &self,
+        context: &mut InitContext,
+        write: bool,
+    ) -> Result<FuelSystem<N>, AircraftConfigurationError> {
+        let fuel_tanks: Vec<FuelTank> = self
+            .fuel_tanks
+            .iter()
+            .map(|f| {
+                FuelTank::new(
+                    context,
+                    &f.fuel_tank_id,
+                    Vector3::new(f.position.0, f.position.1, f.position.2),
+                    Mass::new::<kilogram>(f.total_capacity_gallons * FUEL_GALLONS_TO_KG),
+                    write,
+                )
+            })
+            .collect();
+
+        Ok(FuelSystem::new(
+            context,
+            Self::into_array("fuel tanks", fuel_tanks)?,
+        ))
+    }
+
+    pub fn passenger_deck<const N: usize, const G: usize>(
+        &self,
+        context: &mut InitContext,
+        developer_state: Rc<Cell<i8>>,
+        per_pax_weight: Rc<Cell<Mass>>,
+    ) -> Result<PassengerDeck<N, G>, AircraftConfigurationError> {
+        let pax: Vec<Pax> = self
+            .pax
+            .iter()
+            .map(|p| {
+                Pax::new(
+                    context.get_identifier(p.pax_id.to_owned()),
+                    context.get_identifier(format!("{}_DESIRED", p.pax_id)),
+                    context.get_identifier(p.payload_id.to_owned()),
+                    Rc::clone(&developer_state),
+                    Rc::clone(&per_pax_weight),
+                    Vector3::new(p.position.0, p.position.1, p.position.2),
+                    p.max_pax,
+                )
+            })
+            .collect();
+        let pax = Self::into_array("passenger stations", pax)?;
+
+        let default_boarding_agent = BoardingAgent::new(
+            None,
+            Self::into_array(
+                "stations in the default boarding order",
+                self.default_boarding_order.clone(),
+            )?,
+        );
+
+        let boarding_agents = self
+            .boarding_agents
+            .iter()
+            .map(|agent| {
+                Ok(BoardingAgent::new(
+                    agent
+                        .door_id
+                        .as_ref()
+                        .map(|door_id| context.get_identifier(door_id.to_owned())),
+                    Self::into_array("stations in the boarding order", agent.order.clone())?,
+                ))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(PassengerDeck::new(
+            pax,
+            default_boarding_agent,
+            Self::into_array("boarding agents", boarding_agents)?,
+        ))
+    }
+
+    pub fn cargo_deck<const N: usize>(
+        &self,
+        context: &mut InitContext,
+        developer_state: Rc<Cell<i8>>,
+    ) -> Result<CargoDeck<N>, AircraftConfigurationError> {
+        let cargo: Vec<Cargo> = self
+            .cargo
+            .iter()
+            .map(|c| {
+                Cargo::new(
+                    context.get_identifier(c.cargo_id.to_owned()),
+                    context.get_identifier(format!("{}_DESIRED", c.cargo_id)),
+                    context.get_identifier(c.payload_id.to_owned()),
+                    Rc::clone(&developer_state),
+                    Vector3::new(c.position.0, c.position.1, c.position.2),
+                    Mass::new::<kilogram>(c.max_cargo_kg),
+                )
+            })
+            .collect();
+
+        Ok(CargoDeck::new(Self::into_array("cargo stations", cargo)?))
+    }
+
+    fn into_array<T, const N: usize>(
+        kind: &'static str,
+        items: Vec<T>,
+    ) -> Result<[T; N], AircraftConfigurationError> {
+        let actual = items.len();
+        items
+            .try_into()
+            .map_err(|_| AircraftConfigurationError::StationCount {
+                kind,
+                expected: N,
+                actual,
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::{
+        test::{ElementCtorFn, SimulationTestBed, TestBed},
+        SimulationElement, SimulationElementVisitor,
+    };
+
+    const CONFIGURATION: &str = r#"
+        default_boarding_order = [0, 1]
+
+        [fuselage]
+        forward_limit = 40.0
+        aft_limit = -60.0
+        half_width = 6.0
+
+        [[fuel_tanks]]
+        fuel_tank_id = "FUEL TANK CENTER QUANTITY"
+        position = [-4.5, 0.0, 1.0]
+        total_capacity_gallons = 2179.0
+
+        [[fuel_tanks]]
+        fuel_tank_id = "FUEL TANK LEFT MAIN QUANTITY"
+        position = [-8.0, -13.0, 2.0]
+        total_capacity_gallons = 1816.0
+
+        [[pax]]
+        max_pax = 36
+        position = [20.5, 0.0, 5.0]
+        pax_id = "PAX_A"
+        payload_id = "PAYLOAD_STATION_1_REQ"
+
+        [[pax]]
+        max_pax = 42
+        position = [1.5, 0.0, 5.1]
+        pax_id = "PAX_B"
+        payload_id = "PAYLOAD_STATION_2_REQ"
+
+        [[cargo]]
+        max_cargo_kg = 3402.0
+        position = [17.3, 0.0, 0.0]
+        cargo_id = "CARGO_FWD_BAGGAGE_CONTAINER"
+        payload_id = "PAYLOAD_STATION_5_REQ"
+
+        [[boarding_agents]]
+        door_id = "INTERACTIVE POINT OPEN:0"
+        order = [1, 0]
+    "#;
+
+    fn error_for(configuration: &str) -> AircraftConfigurationError {
+        AircraftConfiguration::from_toml(configuration).unwrap_err()
+    }
+
+    struct ConfiguredElements {
+        fuel_system: FuelSystem<2>,
+        passenger_deck: PassengerDeck<2, 1>,
+        cargo_deck: CargoDeck<1>,
+    }
+    impl SimulationElement for ConfiguredElements {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.fuel_system.accept(visitor);
+            self.passenger_deck.accept(visitor);
+            self.cargo_deck.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    #[test]
+    fn parses_toml_configuration() {
+        let configuration = AircraftConfiguration::from_toml(CONFIGURATION).unwrap();
+
+        assert_eq!(configuration.fuel_tanks().len(), 2);
+        assert_eq!(configuration.pax().len(), 2);
+        assert_eq!(configuration.cargo().len(), 1);
+        assert_eq!(configuration.pax()[1].pax_id, "PAX_B");
+        assert_eq!(configuration.fuel_tanks()[1].position, (-8., -13., 2.));
+    }
+
+    #[test]
+    fn parses_json_configuration() {
+        let configuration = AircraftConfiguration::from_json(
+            r#"{
+                "fuselage": { "forward_limit": 40.0, "aft_limit": -60.0, "half_width": 6.0 },
+                "cargo": [{
+                    "max_cargo_kg": 2426.0,
+                    "position": [-24.1, 0.0, 1.0],
+                    "cargo_id": "CARGO_AFT_CONTAINER",
+                    "payload_id": "PAYLOAD_STATION_6_REQ"
+                }]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(configuration.cargo()[0].max_cargo_kg, 2426.);
+        assert!(configuration.pax().is_empty());
+    }
+
+    #[test]
+    fn models_default_to_the_simulator() {
+        let configuration = AircraftConfiguration::from_toml(CONFIGURATION).unwrap();
+
+        assert_eq!(configuration.models(), SystemModels::default());
+        assert_eq!(configuration.models().engine, EngineModel::Simulator);
+        assert_eq!(configuration.models().fuel, FuelModel::Simulator);
+        assert_eq!(
+            configuration.models().brake_temperature,
+            BrakeTemperatureModel::Instruments
+        );
+    }
+
+    #[test]
+    fn parses_models() {
+        let configuration = AircraftConfiguration::from_toml(&format!(
+            "{}\n[models]\nengine = \"spool\"\nfuel = \"native\"\nbrake_temperature = \"native\"",
+            CONFIGURATION
+        ))
+        .unwrap();
+
+        assert_eq!(configuration.models().engine, EngineModel::Spool);
+        assert_eq!(configuration.models().fuel, FuelModel::Native);
+        assert_eq!(
+            configuration.models().brake_temperature,
+            BrakeTemperatureModel::Native
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_models() {
+        assert!(matches!(
+            error_for(&format!("{}\n[models]\nengine = \"piston\"", CONFIGURATION)),
+            AircraftConfigurationError::Parse(_)
+        ));
+    }
+
+    #[test]
+    fn builds_fuel_system_and_decks() {
+        let configuration = AircraftConfiguration::from_toml(CONFIGURATION).unwrap();
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(|context| {
+            let developer_state = Rc::new(Cell::new(0));
+            let per_pax_weight = Rc::new(Cell::new(Mass::new::<kilogram>(84.)));
+
+            ConfiguredElements {
+                fuel_system: configuration.fuel_system(context, true).unwrap(),
+                passenger_deck: configuration
+                    .passenger_deck(context, Rc::clone(&developer_state), per_pax_weight)
+                    .unwrap(),
+                cargo_deck: configuration.cargo_deck(context, developer_state).unwrap(),
+            }
+        }));
+        test_bed.run();
+
+        assert!(test_bed.contains_variable_with_name("FUEL TANK LEFT MAIN QUANTITY"));
+        assert!(test_bed.contains_variable_with_name("PAX_B_DESIRED"));
+        assert!(test_bed.contains_variable_with_name("CARGO_FWD_BAGGAGE_CONTAINER"));
+        assert!(test_bed
+            .get_variable_identifier("INTERACTIVE POINT OPEN:0")
+            .is_some());
+    }
+
+    #[test]
+    fn reports_unexpected_number_of_stations() {
+        let configuration = AircraftConfiguration::from_toml(CONFIGURATION).unwrap();
+        let _ = SimulationTestBed::from(ElementCtorFn(|context| {
+            assert_eq!(
+                configuration.cargo_deck::<3>(context, Rc::default()).err(),
+                Some(AircraftConfigurationError::StationCount {
+                    kind: "cargo stations",
+                    expected: 3,
+                    actual: 1
+                })
+            );
+
+            configuration
+                .cargo_deck::<1>(context, Rc::default())
+                .unwrap()
+        }));
+    }
+
+    #[test]
+    fn rejects_malformed_configuration() {
+        assert!(matches!(
+            error_for("[fuselage]\nforward_limit = 40.0"),
+            AircraftConfigurationError::Parse(_)
+        ));
+    }
+
+    #[test]
+    fn rejects_duplicate_ids() {
+        let configuration = CONFIGURATION.replace("\"PAX_B\"", "\"PAX_A\"");
+
+        assert_eq!(
+            error_for(&configuration),
+            AircraftConfigurationError::DuplicateId("PAX_A".to_owned())
+        );
+    }
+
+    #[test]
+    fn rejects_negative_capacities() {
+        let configuration = CONFIGURATION.replace("max_cargo_kg = 3402.0", "max_cargo_kg = -1.0");
+
+        assert_eq!(
+            error_for(&configuration),
+            AircraftConfigurationError::NegativeCapacity {
+                id: "CARGO_FWD_BAGGAGE_CONTAINER".to_owned(),
+                capacity: -1.
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_non_finite_capacities() {
+        let configuration = CONFIGURATION.replace("max_cargo_kg = 3402.0", "max_cargo_kg = nan");
+
+        assert_eq!(
+            error_for(&configuration),
+            AircraftConfigurationError::NonFinite {
+                id: "CARGO_FWD_BAGGAGE_CONTAINER".to_owned(),
+                field: "capacity"
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_non_finite_positions() {
+        let configuration = CONFIGURATION.replace("[20.5, 0.0, 5.0]", "[20.5, 0.0, inf]");
+
+        assert_eq!(
+            error_for(&configuration),
+            AircraftConfigurationError::NonFinite {
+                id: "PAX_A".to_owned(),
+                field: "position"
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_non_finite_fuselage_limits() {
+        let configuration = CONFIGURATION.replace("half_width = 6.0", "half_width = -inf");
+
+        assert_eq!(
+            error_for(&configuration),
+            AircraftConfigurationError::NonFinite {
+                id: "fuselage".to_owned(),
+                field: "half width"
+            }
+        );
+    }
+
+    #[test]
+    fn reads_configuration_from_file() {
+        let path = std::env::temp_dir().join(format!(
+            "aircraft_configuration_{}.toml",
+            std::process::id()
+        ));
+        fs::write(&path, CONFIGURATION.replace("\"PAX_B\"", "\"PAX_C\"")).unwrap();
+
+        let configuration = AircraftConfiguration::from_toml_file_or(&path, CONFIGURATION);
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(configuration.unwrap().pax()[1].pax_id, "PAX_C");
+    }
+
+    #[test]
+    fn falls_back_to_embedded_configuration_when_file_does_not_exist() {
+        let configuration = AircraftConfiguration::from_toml_file_or(
+            std::env::temp_dir().join("does_not_exist_aircraft_configuration.toml"),
+            CONFIGURATION,
+        )
+        .unwrap();
+
+        assert_eq!(configuration.pax()[1].pax_id, "PAX_B");
+    }
+
+    #[test]
+    fn rejects_invalid_configuration_file() {
+        let path = std::env::temp_dir().join(format!(
+            "invalid_aircraft_configuration_{}.toml",
+            std::process::id()
+        ));
+        fs::write(&path, "[fuselage]").unwrap();
+
+        let error = AircraftConfiguration::from_toml_file_or(&path, CONFIGURATION).unwrap_err();
+        fs::remove_file(&path).unwrap();
+
+        assert!(matches!(error, AircraftConfigurationError::Parse(_)));
+    }
+
+    #[test]
+    fn rejects_stations_outside_the_fuselage() {
+        let configuration = CONFIGURATION.replace("[20.5, 0.0, 5.0]", "[20.5, 8.0, 5.0]");
+
+        assert_eq!(
+            error_for(&configuration),
+            AircraftConfigurationError::OutsideFuselage {
+                id: "PAX_A".to_owned(),
+                position: (20.5, 8., 5.)
+            }
+        );
+    }
+
+    #[test]
+    fn accepts_wing_tanks_outside_the_fuselage_width() {
+        assert!(AircraftConfiguration::from_toml(CONFIGURATION).is_ok());
+    }
+
+    #[test]
+    fn rejects_fuel_tanks_beyond_the_fuselage_length() {
+        let configuration = CONFIGURATION.replace("[-8.0, -13.0, 2.0]", "[-80.0, -13.0, 2.0]");
+
+        assert!(matches!(
+            error_for(&configuration),
+            AircraftConfigurationError::OutsideFuselage { .. }
+        ));
+    }
+
+    #[test]
+    fn rejects_boarding_order_referring_to_unknown_station() {
+        let configuration = CONFIGURATION.replace("order = [1, 0]", "order = [2, 0]");
+
+        assert_eq!(
+            error_for(&configuration),
+            AircraftConfigurationError::InvalidBoardingOrder {
+                door_id: "INTERACTIVE POINT OPEN:0".to_owned(),
+                station: 2
+            }
+        );
+    }
+}
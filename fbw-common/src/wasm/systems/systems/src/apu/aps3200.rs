@@ -8,8 +8,8 @@ use uom::si::{
 use crate::{
     electrical::{
         ElectricalElement, ElectricalElementIdentifier, ElectricalElementIdentifierProvider,
-        ElectricalStateWriter, ElectricitySource, Potential, ProvideFrequency, ProvideLoad,
-        ProvidePotential,
+        ElectricalStateWriter, ElectricitySource, MainGeneratorControlUnit, Potential,
+        ProvideFrequency, ProvideLoad, ProvidePotential,
     },
     failures::{Failure, FailureType},
     shared::{
@@ -590,6 +590,7 @@ pub struct Aps3200ApuGenerator {
     output_potential: ElectricPotential,
     load: Ratio,
     is_emergency_shutdown: bool,
+    gcu: MainGeneratorControlUnit,
     failure: Failure,
 }
 impl Aps3200ApuGenerator {
@@ -598,13 +599,20 @@ impl Aps3200ApuGenerator {
     pub fn new(context: &mut InitContext, number: usize) -> Aps3200ApuGenerator {
         Aps3200ApuGenerator {
             number,
-            identifier: context.next_electrical_identifier(),
+            identifier: context.next_named_electrical_identifier(&format!("APU_GEN_{}", number)),
             n: Ratio::new::<percent>(0.),
             writer: ElectricalStateWriter::new(context, &format!("APU_GEN_{}", number)),
             output_potential: ElectricPotential::new::<volt>(0.),
             output_frequency: Frequency::new::<hertz>(0.),
             load: Ratio::new::<percent>(0.),
             is_emergency_shutdown: false,
+            gcu: MainGeneratorControlUnit::new(
+                context,
+                &format!("APU_GEN_{}", number),
+                110.0..=120.0,
+                390.0..=410.0,
+                Duration::ZERO,
+            ),
             failure: Failure::new(FailureType::ApuGenerator(number)),
         }
     }
@@ -653,13 +661,27 @@ impl Aps3200ApuGenerator {
     fn should_provide_output(&self) -> bool {
         !self.failure.is_active()
             && !self.is_emergency_shutdown
+            && self.gcu.excites_generator()
             && self.n.get::<percent>() >= Aps3200ApuGenerator::APU_GEN_POWERED_N
     }
 }
 impl ApuGenerator for Aps3200ApuGenerator {
-    fn update(&mut self, n: Ratio, is_emergency_shutdown: bool) {
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        n: Ratio,
+        is_emergency_shutdown: bool,
+        push_button_is_on: bool,
+    ) {
         self.n = n;
         self.is_emergency_shutdown = is_emergency_shutdown;
+        self.gcu.update(
+            context,
+            push_button_is_on,
+            self.output_potential,
+            self.output_frequency,
+            self.load,
+        );
     }
 
     /// Indicates if the provided electricity's potential and frequency
@@ -669,7 +691,7 @@ impl ApuGenerator for Aps3200ApuGenerator {
     /// overtemperature which over time will trigger a mechanical
     /// disconnect of the generator.
     fn output_within_normal_parameters(&self) -> bool {
-        self.should_provide_output() && self.potential_normal() && self.frequency_normal()
+        self.should_provide_output() && self.gcu.closes_line_contactor()
     }
 }
 provide_potential!(Aps3200ApuGenerator, (110.0..=120.0));
@@ -702,6 +724,7 @@ impl ElectricitySource for Aps3200ApuGenerator {
 }
 impl SimulationElement for Aps3200ApuGenerator {
     fn accept<T: crate::simulation::SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.gcu.accept(visitor);
         self.failure.accept(visitor);
         visitor.visit(self);
     }
@@ -727,6 +750,9 @@ impl SimulationElement for Aps3200ApuGenerator {
             Frequency::new::<hertz>(0.)
         };
 
+        self.gcu
+            .sense_output(self.output_potential, self.output_frequency);
+
         let power_consumption = report
             .total_consumption_of(PotentialOrigin::ApuGenerator(self.number))
             .get::<watt>();
@@ -1012,16 +1038,16 @@ mod apu_generator_tests {
     }
 
     fn update_above_threshold(test_bed: &mut SimulationTestBed<TestAircraft<Aps3200ApuGenerator>>) {
-        test_bed.set_update_before_power_distribution(|generator, _, electricity| {
-            generator.update(Ratio::new::<percent>(100.), false);
+        test_bed.set_update_before_power_distribution(|generator, context, electricity| {
+            generator.update(context, Ratio::new::<percent>(100.), false, true);
             electricity.supplied_by(generator);
         });
         test_bed.run();
     }
 
     fn update_below_threshold(test_bed: &mut SimulationTestBed<TestAircraft<Aps3200ApuGenerator>>) {
-        test_bed.set_update_before_power_distribution(|generator, _, electricity| {
-            generator.update(Ratio::new::<percent>(0.), false);
+        test_bed.set_update_before_power_distribution(|generator, context, electricity| {
+            generator.update(context, Ratio::new::<percent>(0.), false, true);
             electricity.supplied_by(generator);
         });
         test_bed.run();
@@ -230,6 +230,12 @@ impl<C: ApuConstants> ElectronicControlBox<C> {
         }
     }
 
+    pub fn update_internal_failure_state(&mut self, has_failed: bool) {
+        if self.fault.is_none() && self.is_on() && has_failed {
+            self.fault = Some(ApuFault::Internal);
+        }
+    }
+
     fn update_fuel_used(&mut self, context: &UpdateContext) {
         self.fuel_used += self.fuel_flow
             * MassConcentration::new::<kilogram_per_liter>(Self::JET_A_1_DENSITY)
@@ -548,4 +554,5 @@ enum ApuFault {
     ApuFire,
     FuelLowPressure,
     DcPowerLoss,
+    Internal,
 }
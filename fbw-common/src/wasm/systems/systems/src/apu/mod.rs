@@ -3,7 +3,11 @@ use self::{
     electronic_control_box::ElectronicControlBox, pw980::ShutdownPw980Turbine,
 };
 use crate::{
-    electrical::{ElectricalElement, ElectricitySource, ProvideFrequency, ProvidePotential},
+    electrical::{
+        ApuGeneratorPushButtons, ElectricalElement, ElectricitySource, ProvideFrequency,
+        ProvidePotential,
+    },
+    failures::{Failure, FailureType},
     overhead::{FirePushButton, OnOffAvailablePushButton, OnOffFaultPushButton},
     pneumatic::{ControllablePneumaticValve, TargetPressureTemperatureSignal},
     shared::{
@@ -109,6 +113,7 @@ pub struct AuxiliaryPowerUnit<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants
     start_motor: U,
     air_intake_flap: AirIntakeFlap,
     fuel_pressure_switch: FuelPressureSwitch,
+    failure: Failure,
 }
 impl<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants, const N: usize>
     AuxiliaryPowerUnit<T, U, C, N>
@@ -131,6 +136,7 @@ impl<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants, const N: usize>
             start_motor,
             air_intake_flap: AirIntakeFlap::new(air_intake_flap_powered_by),
             fuel_pressure_switch: FuelPressureSwitch::new(),
+            failure: Failure::new(FailureType::ApuFault),
         }
     }
 
@@ -142,6 +148,7 @@ impl<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants, const N: usize>
         fire_overhead: &AuxiliaryPowerUnitFireOverheadPanel,
         apu_bleed_is_on: bool,
         apu_gen_is_used: bool,
+        generator_buttons: &impl ApuGeneratorPushButtons,
         bleed_air_valve: &mut impl ControllablePneumaticValve,
         has_fuel_remaining: bool,
     ) {
@@ -151,6 +158,8 @@ impl<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants, const N: usize>
         self.fuel_pressure_switch.update(has_fuel_remaining);
         self.ecb
             .update_fuel_pressure_switch_state(&self.fuel_pressure_switch);
+        self.ecb
+            .update_internal_failure_state(self.failure.is_active());
         bleed_air_valve.update_open_amount::<ApuBleedAirValveSignal, Self>(self);
         self.ecb
             .update_bleed_air_valve_state(context, bleed_air_valve);
@@ -171,8 +180,13 @@ impl<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants, const N: usize>
         }
 
         let emergency_shutdown = self.is_emergency_shutdown();
-        for gen in &mut self.generators {
-            gen.update(self.ecb.n(), emergency_shutdown);
+        for (index, gen) in self.generators.iter_mut().enumerate() {
+            gen.update(
+                context,
+                self.ecb.n(),
+                emergency_shutdown,
+                generator_buttons.apu_gen_push_button_is_on(index + 1),
+            );
         }
     }
 
@@ -267,6 +281,7 @@ impl<T: ApuGenerator, U: ApuStartMotor, C: ApuConstants, const N: usize> Simulat
         self.start_motor.accept(visitor);
         self.air_intake_flap.accept(visitor);
         self.ecb.accept(visitor);
+        self.failure.accept(visitor);
 
         visitor.visit(self);
     }
@@ -307,7 +322,13 @@ pub enum TurbineState {
 pub trait ApuGenerator:
     SimulationElement + ProvidePotential + ProvideFrequency + ElectricalElement + ElectricitySource
 {
-    fn update(&mut self, n: Ratio, is_emergency_shutdown: bool);
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        n: Ratio,
+        is_emergency_shutdown: bool,
+        push_button_is_on: bool,
+    );
     fn output_within_normal_parameters(&self) -> bool;
 }
 
@@ -330,7 +351,7 @@ impl AuxiliaryPowerUnitFireOverheadPanel {
         }
     }
 
-    fn fire_button_is_released(&self) -> bool {
+    pub fn fire_button_is_released(&self) -> bool {
         self.apu_fire_button.is_released()
     }
 }
@@ -550,6 +571,13 @@ mod tests {
         }
     }
 
+    struct ApuGeneratorPushButtonsOn;
+    impl ApuGeneratorPushButtons for ApuGeneratorPushButtonsOn {
+        fn apu_gen_push_button_is_on(&self, _: usize) -> bool {
+            true
+        }
+    }
+
     pub struct AuxiliaryPowerUnitTestAircraft<
         T: ApuGenerator,
         U: ApuStartMotor,
@@ -731,6 +759,7 @@ mod tests {
                 &self.apu_fire_overhead,
                 self.apu_bleed.is_on(),
                 self.apu_gen_is_used,
+                &ApuGeneratorPushButtonsOn,
                 self.pneumatic.bleed_air_valve(),
                 self.has_fuel_remaining,
             );
@@ -898,6 +927,11 @@ mod tests {
             self
         }
 
+        fn apu_fault(mut self) -> Self {
+            self.fail(FailureType::ApuFault);
+            self
+        }
+
         fn no_fuel_available(mut self) -> Self {
             self.command(|a| a.set_has_fuel_remaining(false));
             self
@@ -2594,6 +2628,30 @@ mod tests {
             assert!(!test_bed.start_is_on());
         }
 
+        #[rstest]
+        #[timeout(500)]
+        #[case::aps3200(test_bed_aps3200())]
+        #[case::pw980(test_bed_pw980())]
+        fn running_apu_shuts_down_with_fault_when_apu_fault_failure_occurs<
+            T: ApuGenerator,
+            U: ApuStartMotor,
+            C: ApuConstants,
+            const N: usize,
+        >(
+            #[case] bed_with: AuxiliaryPowerUnitTestBed<T, U, C, N>,
+        ) {
+            let mut test_bed = bed_with
+                .running_apu()
+                .and()
+                .apu_fault()
+                .run_until_n_decreases(Duration::from_millis(50));
+
+            assert!(!test_bed.apu_is_available());
+            assert!(test_bed.master_has_fault());
+            assert!(test_bed.is_auto_shutdown());
+            assert!(!test_bed.start_is_on());
+        }
+
         #[rstest]
         #[case::aps3200(test_bed_aps3200())]
         #[case::pw980(test_bed_pw980())]
@@ -11,8 +11,8 @@ use uom::{
 use crate::{
     electrical::{
         ElectricalElement, ElectricalElementIdentifier, ElectricalElementIdentifierProvider,
-        ElectricalStateWriter, ElectricitySource, Potential, ProvideFrequency, ProvideLoad,
-        ProvidePotential,
+        ElectricalStateWriter, ElectricitySource, MainGeneratorControlUnit, Potential,
+        ProvideFrequency, ProvideLoad, ProvidePotential,
     },
     failures::{Failure, FailureType},
     shared::{
@@ -813,6 +813,7 @@ pub struct Pw980ApuGenerator {
     output_potential: ElectricPotential,
     load: Ratio,
     is_emergency_shutdown: bool,
+    gcu: MainGeneratorControlUnit,
     failure: Failure,
 }
 impl Pw980ApuGenerator {
@@ -821,13 +822,20 @@ impl Pw980ApuGenerator {
     pub fn new(context: &mut InitContext, number: usize) -> Pw980ApuGenerator {
         Pw980ApuGenerator {
             number,
-            identifier: context.next_electrical_identifier(),
+            identifier: context.next_named_electrical_identifier(&format!("APU_GEN_{}", number)),
             n: Ratio::default(),
             writer: ElectricalStateWriter::new(context, &format!("APU_GEN_{}", number)),
             output_potential: ElectricPotential::default(),
             output_frequency: Frequency::default(),
             load: Ratio::default(),
             is_emergency_shutdown: false,
+            gcu: MainGeneratorControlUnit::new(
+                context,
+                &format!("APU_GEN_{}", number),
+                110.0..=120.0,
+                390.0..=410.0,
+                Duration::ZERO,
+            ),
             failure: Failure::new(FailureType::ApuGenerator(number)),
         }
     }
@@ -875,13 +883,27 @@ impl Pw980ApuGenerator {
     fn should_provide_output(&self) -> bool {
         !self.failure.is_active()
             && !self.is_emergency_shutdown
+            && self.gcu.excites_generator()
             && self.n.get::<percent>() >= Pw980ApuGenerator::APU_GEN_POWERED_N
     }
 }
 impl ApuGenerator for Pw980ApuGenerator {
-    fn update(&mut self, n: Ratio, is_emergency_shutdown: bool) {
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        n: Ratio,
+        is_emergency_shutdown: bool,
+        push_button_is_on: bool,
+    ) {
         self.n = n;
         self.is_emergency_shutdown = is_emergency_shutdown;
+        self.gcu.update(
+            context,
+            push_button_is_on,
+            self.output_potential,
+            self.output_frequency,
+            self.load,
+        );
     }
 
     /// Indicates if the provided electricity's potential and frequency
@@ -891,7 +913,7 @@ impl ApuGenerator for Pw980ApuGenerator {
     /// overtemperature which over time will trigger a mechanical
     /// disconnect of the generator.
     fn output_within_normal_parameters(&self) -> bool {
-        self.should_provide_output() && self.potential_normal() && self.frequency_normal()
+        self.should_provide_output() && self.gcu.closes_line_contactor()
     }
 }
 provide_potential!(Pw980ApuGenerator, (110.0..=120.0));
@@ -924,6 +946,7 @@ impl ElectricitySource for Pw980ApuGenerator {
 }
 impl SimulationElement for Pw980ApuGenerator {
     fn accept<T: crate::simulation::SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.gcu.accept(visitor);
         self.failure.accept(visitor);
         visitor.visit(self);
     }
@@ -949,6 +972,9 @@ impl SimulationElement for Pw980ApuGenerator {
             Frequency::default()
         };
 
+        self.gcu
+            .sense_output(self.output_potential, self.output_frequency);
+
         let power_consumption = report
             .total_consumption_of(PotentialOrigin::ApuGenerator(self.number))
             .get::<watt>();
@@ -1234,16 +1260,16 @@ mod apu_generator_tests {
     }
 
     fn update_above_threshold(test_bed: &mut SimulationTestBed<TestAircraft<Pw980ApuGenerator>>) {
-        test_bed.set_update_before_power_distribution(|generator, _, electricity| {
-            generator.update(Ratio::new::<percent>(100.), false);
+        test_bed.set_update_before_power_distribution(|generator, context, electricity| {
+            generator.update(context, Ratio::new::<percent>(100.), false, true);
             electricity.supplied_by(generator);
         });
         test_bed.run();
     }
 
     fn update_below_threshold(test_bed: &mut SimulationTestBed<TestAircraft<Pw980ApuGenerator>>) {
-        test_bed.set_update_before_power_distribution(|generator, _, electricity| {
-            generator.update(Ratio::default(), false);
+        test_bed.set_update_before_power_distribution(|generator, context, electricity| {
+            generator.update(context, Ratio::default(), false, true);
             electricity.supplied_by(generator);
         });
         test_bed.run();
@@ -1,11 +1,16 @@
 use uom::si::{
     electric_charge::ampere_hour, electric_current::ampere, electric_potential::volt,
-    electrical_resistance::ohm, f64::*, time::second,
+    electrical_resistance::ohm, f64::*, power::watt, thermodynamic_temperature::degree_celsius,
+    time::second,
 };
 
 use crate::{
+    failures::{Failure, FailureType},
     shared::{ConsumePower, PowerConsumptionReport},
-    simulation::{InitContext, SimulationElement, SimulatorWriter, UpdateContext},
+    simulation::{
+        InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
+        VariableIdentifier, Write,
+    },
 };
 
 use super::{
@@ -14,32 +19,129 @@ use super::{
     ProvidePotential,
 };
 
+/// Describes the electrochemical and thermal behaviour of a [`Battery`].
+#[derive(Clone, Copy)]
+pub struct BatteryChemistry {
+    /// Capacity when discharged in one hour.
+    rated_capacity: ElectricCharge,
+    /// Peukert's exponent, describing how much capacity is lost when discharging
+    /// faster than the rated discharge current.
+    peukert_exponent: f64,
+    internal_resistance: ElectricalResistance,
+    /// Relative increase of the internal resistance per kelvin below the reference temperature.
+    internal_resistance_temperature_coefficient: f64,
+    heat_capacity_joule_per_kelvin: f64,
+    cooling_watt_per_kelvin: f64,
+    /// Fraction of the charging power which is turned into heat instead of charge.
+    charging_heat_ratio: f64,
+    thermal_runaway_temperature: ThermodynamicTemperature,
+}
+impl BatteryChemistry {
+    const RATED_DISCHARGE_HOURS: f64 = 1.;
+    const REFERENCE_TEMPERATURE_DEGREE_CELSIUS: f64 = 20.;
+    const MIN_INTERNAL_RESISTANCE_FACTOR: f64 = 0.5;
+
+    /// A vented nickel-cadmium battery. The internal resistance and thermal
+    /// properties scale with the capacity, as a larger battery has more and larger cells.
+    pub fn nickel_cadmium(rated_capacity: ElectricCharge) -> Self {
+        let capacity_ampere_hours = rated_capacity.get::<ampere_hour>();
+
+        Self {
+            rated_capacity,
+            peukert_exponent: 1.1,
+            internal_resistance: ElectricalResistance::new::<ohm>(
+                0.011 * 23. / capacity_ampere_hours,
+            ),
+            internal_resistance_temperature_coefficient: 0.04,
+            heat_capacity_joule_per_kelvin: 1100. * capacity_ampere_hours,
+            cooling_watt_per_kelvin: 0.2 * capacity_ampere_hours,
+            charging_heat_ratio: 0.1,
+            thermal_runaway_temperature: ThermodynamicTemperature::new::<degree_celsius>(70.),
+        }
+    }
+
+    pub fn with_peukert_exponent(mut self, peukert_exponent: f64) -> Self {
+        self.peukert_exponent = peukert_exponent;
+        self
+    }
+
+    pub fn with_internal_resistance(mut self, internal_resistance: ElectricalResistance) -> Self {
+        self.internal_resistance = internal_resistance;
+        self
+    }
+
+    pub fn with_thermal_runaway_temperature(
+        mut self,
+        thermal_runaway_temperature: ThermodynamicTemperature,
+    ) -> Self {
+        self.thermal_runaway_temperature = thermal_runaway_temperature;
+        self
+    }
+
+    fn internal_resistance(&self, temperature: ThermodynamicTemperature) -> ElectricalResistance {
+        let below_reference =
+            Self::REFERENCE_TEMPERATURE_DEGREE_CELSIUS - temperature.get::<degree_celsius>();
+
+        self.internal_resistance
+            * (1. + self.internal_resistance_temperature_coefficient * below_reference)
+                .max(Self::MIN_INTERNAL_RESISTANCE_FACTOR)
+    }
+
+    /// The factor by which a discharge drains more charge than was delivered.
+    /// Below the rated discharge current the rated capacity is available.
+    fn peukert_factor(&self, discharge_current: ElectricCurrent) -> f64 {
+        let rated_current =
+            self.rated_capacity.get::<ampere_hour>() / BatteryChemistry::RATED_DISCHARGE_HOURS;
+
+        (discharge_current.get::<ampere>().abs() / rated_current)
+            .powf(self.peukert_exponent - 1.)
+            .max(1.)
+    }
+}
+impl Default for BatteryChemistry {
+    /// The 23 Ah nickel-cadmium battery as found on the A320.
+    fn default() -> Self {
+        Self::nickel_cadmium(ElectricCharge::new::<ampere_hour>(23.))
+    }
+}
+
 pub struct Battery {
     number: usize,
     identifier: ElectricalElementIdentifier,
     writer: ElectricalStateWriter,
+    temperature_id: VariableIdentifier,
+    thermal_runaway_id: VariableIdentifier,
+    chemistry: BatteryChemistry,
     charge: ElectricCharge,
     input_potential: ElectricPotential,
     output_potential: ElectricPotential,
     current: ElectricCurrent,
+    temperature: ThermodynamicTemperature,
+    temperature_is_initialised: bool,
+    is_in_thermal_runaway: bool,
+    thermal_runaway_failure: Failure,
 }
 impl Battery {
-    const RATED_CAPACITY_AMPERE_HOURS: f64 = 23.;
+    /// The time in which an internally short circuited battery in thermal runaway
+    /// dissipates its rated capacity.
+    const THERMAL_RUNAWAY_DISCHARGE_HOURS: f64 = 1. / 6.;
+    const WIRE_RESISTANCE_OHM: f64 = 0.139;
 
     pub fn full(context: &mut InitContext, number: usize) -> Battery {
-        Battery::new(
-            context,
-            number,
-            ElectricCharge::new::<ampere_hour>(Battery::RATED_CAPACITY_AMPERE_HOURS),
-        )
+        Battery::full_with_chemistry(context, number, BatteryChemistry::default())
+    }
+
+    pub fn full_with_chemistry(
+        context: &mut InitContext,
+        number: usize,
+        chemistry: BatteryChemistry,
+    ) -> Battery {
+        Battery::with_chemistry(context, number, chemistry, chemistry.rated_capacity)
     }
 
     pub fn half(context: &mut InitContext, number: usize) -> Battery {
-        Battery::new(
-            context,
-            number,
-            ElectricCharge::new::<ampere_hour>(Battery::RATED_CAPACITY_AMPERE_HOURS / 2.),
-        )
+        let chemistry = BatteryChemistry::default();
+        Battery::with_chemistry(context, number, chemistry, chemistry.rated_capacity / 2.)
     }
 
     pub fn empty(context: &mut InitContext, number: usize) -> Battery {
@@ -47,23 +149,54 @@ impl Battery {
     }
 
     pub fn new(context: &mut InitContext, number: usize, charge: ElectricCharge) -> Self {
+        Battery::with_chemistry(context, number, BatteryChemistry::default(), charge)
+    }
+
+    pub fn with_chemistry(
+        context: &mut InitContext,
+        number: usize,
+        chemistry: BatteryChemistry,
+        charge: ElectricCharge,
+    ) -> Self {
         Self {
             number,
-            identifier: context.next_electrical_identifier(),
+            identifier: context.next_named_electrical_identifier(&format!("BAT_{}", number)),
             writer: ElectricalStateWriter::new(context, &format!("BAT_{}", number)),
+            temperature_id: context.get_identifier(format!("ELEC_BAT_{}_TEMPERATURE", number)),
+            thermal_runaway_id: context
+                .get_identifier(format!("ELEC_BAT_{}_THERMAL_RUNAWAY", number)),
+            chemistry,
             charge,
             input_potential: ElectricPotential::new::<volt>(0.),
-            output_potential: Battery::calculate_output_potential_for_charge(charge),
+            output_potential: Battery::calculate_open_circuit_potential(&chemistry, charge),
             current: ElectricCurrent::new::<ampere>(0.),
+            temperature: ThermodynamicTemperature::new::<degree_celsius>(
+                BatteryChemistry::REFERENCE_TEMPERATURE_DEGREE_CELSIUS,
+            ),
+            temperature_is_initialised: false,
+            is_in_thermal_runaway: false,
+            thermal_runaway_failure: Failure::new(FailureType::BatteryThermalRunaway(number)),
         }
     }
 
     pub fn needs_charging(&self) -> bool {
-        self.charge <= ElectricCharge::new::<ampere_hour>(Battery::RATED_CAPACITY_AMPERE_HOURS - 3.)
+        self.charge <= self.chemistry.rated_capacity - ElectricCharge::new::<ampere_hour>(3.)
+    }
+
+    pub fn temperature(&self) -> ThermodynamicTemperature {
+        self.temperature
+    }
+
+    pub fn is_in_thermal_runaway(&self) -> bool {
+        self.is_in_thermal_runaway
     }
 
     fn is_powered_by_other_potential(&self) -> bool {
-        self.input_potential > self.output_potential
+        self.input_potential > self.open_circuit_potential()
+    }
+
+    fn open_circuit_potential(&self) -> ElectricPotential {
+        Battery::calculate_open_circuit_potential(&self.chemistry, self.charge)
     }
 
     #[cfg(test)]
@@ -75,14 +208,12 @@ impl Battery {
     fn set_charge(&mut self, charge: ElectricCharge) {
         self.charge = charge;
         self.input_potential = ElectricPotential::new::<volt>(0.);
-        self.output_potential = Battery::calculate_output_potential_for_charge(self.charge);
+        self.output_potential = self.open_circuit_potential();
     }
 
     #[cfg(test)]
     pub(crate) fn set_full_charge(&mut self) {
-        self.set_charge(ElectricCharge::new::<ampere_hour>(
-            Battery::RATED_CAPACITY_AMPERE_HOURS,
-        ))
+        self.set_charge(self.chemistry.rated_capacity)
     }
 
     #[cfg(test)]
@@ -95,6 +226,17 @@ impl Battery {
         self.set_charge(ElectricCharge::new::<ampere_hour>(0.))
     }
 
+    /// The open circuit potential is described for a 23 Ah battery. Batteries of
+    /// other capacities follow the same curve for the same state of charge.
+    fn calculate_open_circuit_potential(
+        chemistry: &BatteryChemistry,
+        charge: ElectricCharge,
+    ) -> ElectricPotential {
+        Battery::calculate_output_potential_for_charge(
+            charge * (23. / chemistry.rated_capacity.get::<ampere_hour>()),
+        )
+    }
+
     fn calculate_output_potential_for_charge(charge: ElectricCharge) -> ElectricPotential {
         // There are four distinct charges, being:
         // 1. No charge, giving no potential.
@@ -120,18 +262,81 @@ impl Battery {
         })
     }
 
-    fn calculate_charging_current(
-        input: ElectricPotential,
-        output: ElectricPotential,
-    ) -> ElectricCurrent {
-        // Internal resistance = 0.011 ohm. However that would make current go through the roof.
+    fn calculate_charging_current(&self) -> ElectricCurrent {
+        // The internal resistance alone would make current go through the roof.
         // Thus we add some fake wire resistance here too. If needed, later one can
         // add resistance of wires between buses to calculate correct values.
-        let resistance = ElectricalResistance::new::<ohm>(0.15);
-        ((input - output) / resistance)
+        let resistance = ElectricalResistance::new::<ohm>(Battery::WIRE_RESISTANCE_OHM)
+            + self.chemistry.internal_resistance(self.temperature);
+        ((self.input_potential - self.open_circuit_potential()) / resistance)
             .min(ElectricCurrent::new::<ampere>(10.))
             .max(ElectricCurrent::new::<ampere>(0.))
     }
+
+    fn update_temperature(&mut self, context: &UpdateContext) {
+        let ambient = context.ambient_temperature().get::<degree_celsius>();
+        if !self.temperature_is_initialised {
+            self.temperature = context.ambient_temperature();
+            self.temperature_is_initialised = true;
+        }
+
+        let resistance = self.chemistry.internal_resistance(self.temperature);
+        let mut heat = (self.current * self.current * resistance).get::<watt>();
+        if self.current > ElectricCurrent::new::<ampere>(0.) {
+            // When overcharging, all charging power is turned into heat.
+            let charging_heat_ratio = if self.charge < self.chemistry.rated_capacity {
+                self.chemistry.charging_heat_ratio
+            } else {
+                1.
+            };
+
+            heat += (self.input_potential * self.current).get::<watt>() * charging_heat_ratio;
+        }
+
+        if self.is_in_thermal_runaway && self.charge > ElectricCharge::new::<ampere_hour>(0.) {
+            let short_circuit_current = ElectricCurrent::new::<ampere>(
+                self.chemistry.rated_capacity.get::<ampere_hour>()
+                    / Battery::THERMAL_RUNAWAY_DISCHARGE_HOURS,
+            );
+
+            heat += (self.open_circuit_potential() * short_circuit_current).get::<watt>();
+
+            let time = Time::new::<second>(context.delta_as_secs_f64());
+            self.charge -= (short_circuit_current * time).min(self.charge);
+        }
+
+        // The cooling is integrated as an exponential decay towards ambient so that it remains
+        // stable with long frames.
+        let heat_capacity = self.chemistry.heat_capacity_joule_per_kelvin;
+        let cooling_decay = (-self.chemistry.cooling_watt_per_kelvin * context.delta_as_secs_f64()
+            / heat_capacity)
+            .exp();
+        let temperature = ambient
+            + (self.temperature.get::<degree_celsius>() - ambient) * cooling_decay
+            + heat * context.delta_as_secs_f64() / heat_capacity;
+        self.temperature = ThermodynamicTemperature::new::<degree_celsius>(temperature);
+
+        // Once the cells are short circuited internally the battery is lost.
+        if self.temperature >= self.chemistry.thermal_runaway_temperature {
+            self.is_in_thermal_runaway = true;
+        }
+    }
+
+    fn calculate_terminal_potential(&self) -> ElectricPotential {
+        if self.is_in_thermal_runaway {
+            return ElectricPotential::new::<volt>(0.);
+        }
+
+        let open_circuit_potential = self.open_circuit_potential();
+        let discharge_current = (-self.current).max(ElectricCurrent::new::<ampere>(0.));
+        let sag = discharge_current * self.chemistry.internal_resistance(self.temperature);
+
+        if open_circuit_potential > ElectricPotential::new::<volt>(0.) {
+            (open_circuit_potential - sag).max(ElectricPotential::new::<volt>(0.))
+        } else {
+            open_circuit_potential
+        }
+    }
 }
 impl ProvideCurrent for Battery {
     fn current(&self) -> ElectricCurrent {
@@ -176,16 +381,27 @@ impl ElectricitySource for Battery {
     }
 }
 impl SimulationElement for Battery {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.thermal_runaway_failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn write(&self, writer: &mut SimulatorWriter) {
         self.writer.write_direct(self, writer);
+        writer.write(&self.temperature_id, self.temperature);
+        writer.write(&self.thermal_runaway_id, self.is_in_thermal_runaway);
     }
 
     fn consume_power<T: ConsumePower>(&mut self, context: &UpdateContext, consumption: &mut T) {
+        if self.thermal_runaway_failure.is_active() {
+            self.is_in_thermal_runaway = true;
+        }
+
         self.input_potential = consumption.input_of(self).raw();
 
-        if self.is_powered_by_other_potential() {
-            self.current =
-                Battery::calculate_charging_current(self.input_potential, self.output_potential);
+        if self.is_powered_by_other_potential() && !self.is_in_thermal_runaway {
+            self.current = self.calculate_charging_current();
 
             let power = self.input_potential * self.current;
             consumption.consume_from_input(self, power);
@@ -200,7 +416,9 @@ impl SimulationElement for Battery {
         context: &UpdateContext,
         report: &T,
     ) {
-        if !self.is_powered_by_other_potential() {
+        if self.is_in_thermal_runaway {
+            self.current = ElectricCurrent::new::<ampere>(0.);
+        } else if !self.is_powered_by_other_potential() {
             let consumption = report.total_consumption_of(PotentialOrigin::Battery(self.number));
 
             self.current = if self.output_potential > ElectricPotential::new::<volt>(0.) {
@@ -211,11 +429,14 @@ impl SimulationElement for Battery {
 
             if self.output_potential > ElectricPotential::new::<volt>(0.) {
                 let time = Time::new::<second>(context.delta_as_secs_f64());
-                self.charge -= ((consumption * time) / self.output_potential).min(self.charge);
+                self.charge -= ((consumption * time) / self.output_potential
+                    * self.chemistry.peukert_factor(self.current))
+                .min(self.charge);
             }
         }
 
-        self.output_potential = Battery::calculate_output_potential_for_charge(self.charge);
+        self.update_temperature(context);
+        self.output_potential = self.calculate_terminal_potential();
     }
 }
 
@@ -305,6 +526,18 @@ mod tests {
                 }
             }
 
+            fn with_full_batteries_of_chemistry(chemistry: BatteryChemistry) -> Self {
+                Self {
+                    test_bed: SimulationTestBed::new(|context| {
+                        TestAircraft::new(
+                            Battery::full_with_chemistry(context, 1, chemistry),
+                            Battery::full_with_chemistry(context, 2, chemistry),
+                            context,
+                        )
+                    }),
+                }
+            }
+
             fn with_empty_batteries() -> Self {
                 Self {
                     test_bed: SimulationTestBed::new(|context| {
@@ -332,6 +565,14 @@ mod tests {
             fn potential(&mut self, number: usize) -> ElectricPotential {
                 self.read_by_name(&format!("ELEC_BAT_{}_POTENTIAL", number))
             }
+
+            fn temperature(&mut self, number: usize) -> ThermodynamicTemperature {
+                self.read_by_name(&format!("ELEC_BAT_{}_TEMPERATURE", number))
+            }
+
+            fn is_in_thermal_runaway(&mut self, number: usize) -> bool {
+                self.read_by_name(&format!("ELEC_BAT_{}_THERMAL_RUNAWAY", number))
+            }
         }
         impl TestBed for BatteryTestBed {
             type Aircraft = TestAircraft;
@@ -676,7 +917,8 @@ mod tests {
 
             test_bed.command(|a| a.close_battery_2_contactor());
 
-            for _ in 0..100 {
+            // The internal resistance of both batteries slows down the equalisation.
+            for _ in 0..150 {
                 test_bed.run_with_delta(Duration::from_secs(120));
             }
 
@@ -695,5 +937,203 @@ mod tests {
                     < ElectricCharge::new::<ampere_hour>(0.001)
             );
         }
+
+        #[test]
+        fn potential_sags_under_high_load() {
+            let mut test_bed = BatteryTestBed::with_full_batteries();
+            test_bed.run();
+            let unloaded_potential = test_bed.potential(1);
+
+            test_bed.command(|a| a.power_demand(Power::new::<watt>(5000.)));
+            test_bed.run_with_delta(Duration::from_millis(100));
+            test_bed.run_with_delta(Duration::from_millis(100));
+
+            assert!(
+                test_bed.potential(1) < unloaded_potential - ElectricPotential::new::<volt>(1.)
+            );
+        }
+
+        #[test]
+        fn potential_recovers_once_load_is_removed() {
+            let mut test_bed = BatteryTestBed::with_full_batteries();
+            test_bed.command(|a| a.power_demand(Power::new::<watt>(5000.)));
+            test_bed.run_with_delta(Duration::from_millis(100));
+            test_bed.run_with_delta(Duration::from_millis(100));
+            let loaded_potential = test_bed.potential(1);
+
+            test_bed.command(|a| a.power_demand(Power::new::<watt>(0.)));
+            test_bed.run_with_delta(Duration::from_millis(100));
+
+            assert!(test_bed.potential(1) > loaded_potential);
+        }
+
+        #[test]
+        fn cold_battery_sags_more_than_warm_battery() {
+            let sagged_potential = |ambient_temperature: f64| {
+                let mut test_bed = BatteryTestBed::with_full_batteries();
+                test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(
+                    ambient_temperature,
+                ));
+                test_bed.command(|a| a.power_demand(Power::new::<watt>(5000.)));
+                test_bed.run_with_delta(Duration::from_millis(100));
+                test_bed.run_with_delta(Duration::from_millis(100));
+
+                test_bed.potential(1)
+            };
+
+            assert!(sagged_potential(-30.) < sagged_potential(20.));
+        }
+
+        #[test]
+        fn discharging_faster_than_rated_current_drains_more_than_delivered_charge() {
+            let mut test_bed = BatteryTestBed::with_full_batteries();
+            test_bed.command(|a| a.power_demand(Power::new::<watt>(5000.)));
+            test_bed.run_with_delta(Duration::from_secs(1));
+
+            let charge_prior_to_run = test_bed.query(|a| a.battery_1_charge());
+            let potential = test_bed.potential(1);
+            test_bed.run_with_delta(Duration::from_secs(60));
+
+            let delivered_charge = Power::new::<watt>(5000.) * Time::new::<second>(60.) / potential;
+            assert!(
+                charge_prior_to_run - test_bed.query(|a| a.battery_1_charge()) > delivered_charge
+            );
+        }
+
+        #[test]
+        fn larger_battery_loses_less_charge_ratio_for_same_load() {
+            let remaining_ratio = |test_bed: &mut BatteryTestBed| {
+                let charge_prior_to_run = test_bed.query(|a| a.battery_1_charge());
+                test_bed.command(|a| a.power_demand(Power::new::<watt>(1000.)));
+                test_bed.run_with_delta(Duration::from_secs(600));
+
+                test_bed.query(|a| a.battery_1_charge()) / charge_prior_to_run
+            };
+
+            let mut small = BatteryTestBed::with_full_batteries();
+            let mut large = BatteryTestBed::with_full_batteries_of_chemistry(
+                BatteryChemistry::nickel_cadmium(ElectricCharge::new::<ampere_hour>(50.)),
+            );
+
+            assert!(remaining_ratio(&mut large) > remaining_ratio(&mut small));
+        }
+
+        #[test]
+        fn battery_starts_at_ambient_temperature() {
+            let mut test_bed = BatteryTestBed::with_full_batteries();
+            test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(-10.));
+
+            test_bed.run();
+
+            assert!(
+                (test_bed.temperature(1).get::<degree_celsius>() - -10.).abs() < 0.1,
+                "Temperature was {}",
+                test_bed.temperature(1).get::<degree_celsius>()
+            );
+        }
+
+        #[test]
+        fn charging_heats_battery() {
+            let mut test_bed = BatteryTestBed::with_empty_batteries();
+            test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(15.));
+
+            test_bed.command(|a| a.supply_input_potential(ElectricPotential::new::<volt>(28.)));
+            test_bed.run_with_delta(Duration::from_secs(600));
+
+            assert!(
+                test_bed.temperature(1) > ThermodynamicTemperature::new::<degree_celsius>(15.5)
+            );
+        }
+
+        #[test]
+        fn heated_battery_cools_down_to_ambient() {
+            let mut test_bed = BatteryTestBed::with_empty_batteries();
+            test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(15.));
+            test_bed.command(|a| a.supply_input_potential(ElectricPotential::new::<volt>(28.)));
+            test_bed.run_with_delta(Duration::from_secs(600));
+
+            test_bed.command(|a| a.supply_input_potential(ElectricPotential::new::<volt>(0.)));
+            for _ in 0..100 {
+                test_bed.run_with_delta(Duration::from_secs(600));
+            }
+
+            assert!(
+                (test_bed.temperature(1).get::<degree_celsius>() - 15.).abs() < 0.1,
+                "Temperature was {}",
+                test_bed.temperature(1).get::<degree_celsius>()
+            );
+        }
+
+        #[test]
+        fn battery_above_thermal_runaway_temperature_goes_into_thermal_runaway() {
+            let mut test_bed = BatteryTestBed::with_full_batteries();
+            test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(75.));
+
+            test_bed.run();
+
+            assert!(test_bed.is_in_thermal_runaway(1));
+        }
+
+        #[test]
+        fn battery_below_thermal_runaway_temperature_is_not_in_thermal_runaway() {
+            let mut test_bed = BatteryTestBed::with_full_batteries();
+            test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(45.));
+
+            test_bed.run();
+
+            assert!(!test_bed.is_in_thermal_runaway(1));
+        }
+
+        #[test]
+        fn battery_in_thermal_runaway_provides_no_potential() {
+            let mut test_bed = BatteryTestBed::with_full_batteries();
+            test_bed.fail(FailureType::BatteryThermalRunaway(1));
+
+            test_bed.run();
+            test_bed.run();
+
+            assert!(test_bed.is_in_thermal_runaway(1));
+            assert_eq!(test_bed.potential(1), ElectricPotential::new::<volt>(0.));
+        }
+
+        #[test]
+        fn battery_in_thermal_runaway_heats_up_and_loses_its_charge() {
+            let mut test_bed = BatteryTestBed::with_full_batteries();
+            test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(15.));
+            test_bed.fail(FailureType::BatteryThermalRunaway(1));
+
+            for _ in 0..10 {
+                test_bed.run_with_delta(Duration::from_secs(60));
+            }
+
+            assert!(test_bed.temperature(1) > ThermodynamicTemperature::new::<degree_celsius>(70.));
+            assert_eq!(
+                test_bed.query(|a| a.battery_1_charge()),
+                ElectricCharge::new::<ampere_hour>(0.)
+            );
+        }
+
+        #[test]
+        fn battery_in_thermal_runaway_does_not_charge() {
+            let mut test_bed = BatteryTestBed::with_half_charged_batteries();
+            test_bed.fail(FailureType::BatteryThermalRunaway(1));
+            test_bed.command(|a| a.supply_input_potential(ElectricPotential::new::<volt>(28.)));
+
+            test_bed.run();
+
+            assert!(test_bed.current(1) <= ElectricCurrent::new::<ampere>(0.));
+        }
+
+        #[test]
+        fn thermal_runaway_remains_once_failure_is_cleared() {
+            let mut test_bed = BatteryTestBed::with_full_batteries();
+            test_bed.fail(FailureType::BatteryThermalRunaway(1));
+            test_bed.run();
+
+            test_bed.unfail(FailureType::BatteryThermalRunaway(1));
+            test_bed.run();
+
+            assert!(test_bed.is_in_thermal_runaway(1));
+        }
     }
 }
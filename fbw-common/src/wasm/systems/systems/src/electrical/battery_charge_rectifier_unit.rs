@@ -45,8 +45,8 @@ impl BatteryChargeRectifierUnit {
     ) -> Self {
         Self {
             writer: ElectricalStateWriter::new(context, &format!("TR_{number}")),
-            input_identifier: context.next_electrical_identifier(),
-            output_identifier: context.next_electrical_identifier(),
+            input_identifier: context.next_named_electrical_identifier(&format!("TR_{number}")),
+            output_identifier: context.next_named_electrical_identifier(&format!("TR_{number}")),
             output_potential: ElectricPotential::default(),
             output_current: ElectricCurrent::default(),
             number,
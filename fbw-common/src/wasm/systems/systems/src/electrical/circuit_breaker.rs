@@ -0,0 +1,553 @@
+use std::cell::{Ref, RefCell};
+
+use uom::si::{
+    electric_current::ampere, electric_potential::volt, f64::*, power::watt, ratio::ratio,
+};
+
+use crate::{
+    failures::FailureType,
+    shared::{ConsumePower, ElectricalBusType, ElectricalBuses, PowerConsumptionReport},
+    simulation::{
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+    },
+};
+
+use super::{ElectricalElement, Potential, PotentialOrigin};
+
+/// A circuit breaker which sits between an electrical bus and the element it protects.
+///
+/// The protected element keeps receiving power from and consuming power from the bus
+/// as it normally would. The breaker intercepts this exchange: while it is open the bus
+/// appears unpowered to the element and the element's consumption from the bus is
+/// dropped. While closed, the current drawn by the element is measured and compared
+/// against the rated current of the breaker.
+///
+/// The breaker trips following an inverse time curve: the further the current exceeds
+/// the rated current, the sooner it trips. Currents well beyond the rated current trip
+/// the breaker instantly.
+///
+/// A tripped breaker pops out. It is reset by pulling it and pushing it back in.
+/// Breakers are identified by a stable ID (e.g. `"1PC1"`), which is used to derive
+/// the `ELEC_CB_{id}_IS_PULLED` and `ELEC_CB_{id}_IS_TRIPPED` variables.
+pub struct CircuitBreaker<T: SimulationElement> {
+    id: &'static str,
+    is_pulled_id: VariableIdentifier,
+    is_tripped_id: VariableIdentifier,
+
+    rated_current: ElectricCurrent,
+    is_pulled: bool,
+    is_tripped: bool,
+    heat: f64,
+    current: ElectricCurrent,
+
+    state: BreakerState,
+    element: T,
+}
+impl<T: SimulationElement> CircuitBreaker<T> {
+    /// The breaker trips after `TRIP_CURVE_CONSTANT / ((I/In)² - 1)` seconds of overcurrent.
+    /// At twice the rated current this amounts to a little over three seconds.
+    const TRIP_CURVE_CONSTANT: f64 = 10.;
+    const INSTANT_TRIP_CURRENT_FACTOR: f64 = 10.;
+
+    pub fn new(
+        context: &mut InitContext,
+        id: &'static str,
+        bus_type: ElectricalBusType,
+        rated_current: ElectricCurrent,
+        element: T,
+    ) -> Self {
+        Self {
+            id,
+            is_pulled_id: context.get_identifier(format!("ELEC_CB_{}_IS_PULLED", id)),
+            is_tripped_id: context.get_identifier(format!("ELEC_CB_{}_IS_TRIPPED", id)),
+
+            rated_current,
+            is_pulled: false,
+            is_tripped: false,
+            heat: 0.,
+            current: ElectricCurrent::new::<ampere>(0.),
+
+            state: BreakerState::new(bus_type),
+            element,
+        }
+    }
+
+    pub fn id(&self) -> &'static str {
+        self.id
+    }
+
+    pub fn is_closed(&self) -> bool {
+        !self.is_pulled && !self.is_tripped
+    }
+
+    pub fn is_tripped(&self) -> bool {
+        self.is_tripped
+    }
+
+    pub fn current(&self) -> ElectricCurrent {
+        self.current
+    }
+
+    pub fn element(&self) -> &T {
+        &self.element
+    }
+
+    pub fn element_mut(&mut self) -> &mut T {
+        &mut self.element
+    }
+
+    fn update(&mut self, context: &UpdateContext) {
+        self.current = if self.state.potential > ElectricPotential::new::<volt>(0.) {
+            self.state.load / self.state.potential
+        } else {
+            ElectricCurrent::new::<ampere>(0.)
+        };
+        self.state.load = Power::new::<watt>(0.);
+
+        let current_factor = (self.current / self.rated_current).get::<ratio>();
+        self.heat = (self.heat + (current_factor.powi(2) - 1.) * context.delta_as_secs_f64())
+            .clamp(0., Self::TRIP_CURVE_CONSTANT);
+
+        if self.is_closed()
+            && (self.heat >= Self::TRIP_CURVE_CONSTANT
+                || current_factor >= Self::INSTANT_TRIP_CURRENT_FACTOR)
+        {
+            self.is_tripped = true;
+        }
+
+        self.state.is_open = !self.is_closed();
+    }
+}
+impl<T: SimulationElement> SimulationElement for CircuitBreaker<T> {
+    fn accept<U: SimulationElementVisitor>(&mut self, visitor: &mut U) {
+        let mut breaker_visitor = CircuitBreakerVisitor {
+            visitor,
+            state: &mut self.state,
+        };
+        self.element.accept(&mut breaker_visitor);
+
+        visitor.visit(self);
+    }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.is_pulled = reader.read(&self.is_pulled_id);
+        if self.is_pulled {
+            self.is_tripped = false;
+        }
+
+        self.state.is_open = !self.is_closed();
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.is_tripped_id, self.is_tripped);
+    }
+
+    fn process_power_consumption_report<U: PowerConsumptionReport>(
+        &mut self,
+        context: &UpdateContext,
+        _: &U,
+    ) {
+        self.update(context);
+    }
+}
+
+struct BreakerState {
+    bus_type: ElectricalBusType,
+    is_open: bool,
+    potential: ElectricPotential,
+    load: Power,
+    none_potential: RefCell<Potential>,
+}
+impl BreakerState {
+    fn new(bus_type: ElectricalBusType) -> Self {
+        Self {
+            bus_type,
+            is_open: false,
+            potential: ElectricPotential::new::<volt>(0.),
+            load: Power::new::<watt>(0.),
+            none_potential: RefCell::new(Potential::none()),
+        }
+    }
+}
+
+/// Wraps every element within the protected element, such that the power
+/// it receives and consumes passes through the breaker.
+struct CircuitBreakerVisitor<'a, U: SimulationElementVisitor> {
+    visitor: &'a mut U,
+    state: &'a mut BreakerState,
+}
+impl<'a, U: SimulationElementVisitor> SimulationElementVisitor for CircuitBreakerVisitor<'a, U> {
+    fn visit<T: SimulationElement>(&mut self, visited: &mut T) {
+        self.visitor.visit(&mut ProtectedElement {
+            element: visited,
+            state: self.state,
+        });
+    }
+}
+
+struct ProtectedElement<'a, T: SimulationElement> {
+    element: &'a mut T,
+    state: &'a mut BreakerState,
+}
+impl<'a, T: SimulationElement> SimulationElement for ProtectedElement<'a, T> {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.element.read(reader);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        self.element.write(writer);
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.state.potential = buses.potential_of(self.state.bus_type).raw();
+        self.element.receive_power(&BreakerBuses {
+            buses,
+            state: self.state,
+        });
+    }
+
+    fn consume_power<U: ConsumePower>(&mut self, context: &UpdateContext, power: &mut U) {
+        self.element.consume_power(
+            context,
+            &mut BreakerConsumption {
+                consumption: power,
+                state: self.state,
+            },
+        );
+    }
+
+    fn consume_power_in_converters<U: ConsumePower>(
+        &mut self,
+        context: &UpdateContext,
+        power: &mut U,
+    ) {
+        self.element.consume_power_in_converters(
+            context,
+            &mut BreakerConsumption {
+                consumption: power,
+                state: self.state,
+            },
+        );
+    }
+
+    fn process_power_consumption_report<U: PowerConsumptionReport>(
+        &mut self,
+        context: &UpdateContext,
+        report: &U,
+    ) {
+        self.element
+            .process_power_consumption_report(context, report);
+    }
+
+    fn receive_failure(&mut self, failure_type: FailureType, is_active: bool) {
+        self.element.receive_failure(failure_type, is_active);
+    }
+}
+
+struct BreakerBuses<'a, T: ElectricalBuses> {
+    buses: &'a T,
+    state: &'a BreakerState,
+}
+impl<'a, T: ElectricalBuses> ElectricalBuses for BreakerBuses<'a, T> {
+    fn potential_of(&self, bus_type: ElectricalBusType) -> Ref<Potential> {
+        if self.state.is_open && bus_type == self.state.bus_type {
+            self.state.none_potential.borrow()
+        } else {
+            self.buses.potential_of(bus_type)
+        }
+    }
+
+    fn is_powered(&self, bus_type: ElectricalBusType) -> bool {
+        if self.state.is_open && bus_type == self.state.bus_type {
+            false
+        } else {
+            self.buses.is_powered(bus_type)
+        }
+    }
+
+    fn any_is_powered(&self, bus_types: &[ElectricalBusType]) -> bool {
+        bus_types.iter().any(|bus_type| self.is_powered(*bus_type))
+    }
+}
+
+struct BreakerConsumption<'a, T: ConsumePower> {
+    consumption: &'a mut T,
+    state: &'a mut BreakerState,
+}
+impl<'a, T: ConsumePower> PowerConsumptionReport for BreakerConsumption<'a, T> {
+    fn is_powered(&self, element: &impl ElectricalElement) -> bool {
+        self.consumption.is_powered(element)
+    }
+
+    fn total_consumption_of(&self, potential_origin: PotentialOrigin) -> Power {
+        self.consumption.total_consumption_of(potential_origin)
+    }
+}
+impl<'a, T: ConsumePower> ConsumePower for BreakerConsumption<'a, T> {
+    fn input_of(&self, element: &impl ElectricalElement) -> Ref<Potential> {
+        self.consumption.input_of(element)
+    }
+
+    fn consume_from_input(&mut self, element: &impl ElectricalElement, power: Power) {
+        self.consumption.consume_from_input(element, power);
+    }
+
+    fn consume_from_bus(&mut self, bus_type: ElectricalBusType, power: Power) {
+        if bus_type != self.state.bus_type {
+            self.consumption.consume_from_bus(bus_type, power);
+        } else if !self.state.is_open {
+            self.state.load += power;
+            self.consumption.consume_from_bus(bus_type, power);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
+        simulation::{
+            test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+            Aircraft,
+        },
+    };
+    use std::time::Duration;
+
+    struct TestConsumer {
+        bus_type: ElectricalBusType,
+        is_powered: bool,
+        demand: Power,
+    }
+    impl TestConsumer {
+        fn new(bus_type: ElectricalBusType) -> Self {
+            Self {
+                bus_type,
+                is_powered: false,
+                demand: Power::new::<watt>(0.),
+            }
+        }
+    }
+    impl SimulationElement for TestConsumer {
+        fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+            self.is_powered = buses.is_powered(self.bus_type);
+        }
+
+        fn consume_power<T: ConsumePower>(&mut self, _: &UpdateContext, consumption: &mut T) {
+            consumption.consume_from_bus(self.bus_type, self.demand);
+        }
+    }
+
+    struct TestAircraft {
+        electricity_source: TestElectricitySource,
+        bus: ElectricalBus,
+        circuit_breaker: CircuitBreaker<TestConsumer>,
+        consumption: Power,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            let bus_type = ElectricalBusType::DirectCurrent(1);
+            Self {
+                electricity_source: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::ApuGenerator(1),
+                ),
+                bus: ElectricalBus::new(context, bus_type),
+                circuit_breaker: CircuitBreaker::new(
+                    context,
+                    "TEST",
+                    bus_type,
+                    ElectricCurrent::new::<ampere>(5.),
+                    TestConsumer::new(bus_type),
+                ),
+                consumption: Power::new::<watt>(0.),
+            }
+        }
+
+        fn demand(&mut self, power: Power) {
+            self.circuit_breaker.element_mut().demand = power;
+        }
+
+        fn consumer_is_powered(&self) -> bool {
+            self.circuit_breaker.element().is_powered
+        }
+
+        fn circuit_breaker(&self) -> &CircuitBreaker<TestConsumer> {
+            &self.circuit_breaker
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            electricity.supplied_by(&self.electricity_source);
+            electricity.flow(&self.electricity_source, &self.bus);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.circuit_breaker.accept(visitor);
+
+            visitor.visit(self);
+        }
+
+        fn process_power_consumption_report<T: PowerConsumptionReport>(
+            &mut self,
+            _: &UpdateContext,
+            report: &T,
+        ) {
+            self.consumption = report.total_consumption_of(PotentialOrigin::ApuGenerator(1));
+        }
+    }
+
+    fn test_bed_with_demand(power: Power) -> SimulationTestBed<TestAircraft> {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.command(|a| a.demand(power));
+
+        test_bed
+    }
+
+    fn pull(test_bed: &mut SimulationTestBed<TestAircraft>) {
+        test_bed.write_by_name("ELEC_CB_TEST_IS_PULLED", true);
+    }
+
+    fn push(test_bed: &mut SimulationTestBed<TestAircraft>) {
+        test_bed.write_by_name("ELEC_CB_TEST_IS_PULLED", false);
+    }
+
+    #[test]
+    fn closed_breaker_passes_power_and_consumption() {
+        let mut test_bed = test_bed_with_demand(Power::new::<watt>(100.));
+
+        test_bed.run();
+
+        assert!(test_bed.query(|a| a.consumer_is_powered()));
+        assert_eq!(test_bed.query(|a| a.consumption), Power::new::<watt>(100.));
+    }
+
+    #[test]
+    fn breaker_measures_current_drawn_by_protected_element() {
+        let mut test_bed = test_bed_with_demand(Power::new::<watt>(56.));
+
+        test_bed.run();
+
+        assert!(
+            (test_bed.query(|a| a.circuit_breaker().current().get::<ampere>()) - 2.).abs() < 0.001
+        );
+    }
+
+    #[test]
+    fn breaker_does_not_trip_at_rated_current() {
+        let mut test_bed = test_bed_with_demand(Power::new::<watt>(140.));
+
+        test_bed.run_with_delta(Duration::from_secs(600));
+
+        assert!(test_bed.query(|a| a.circuit_breaker().is_closed()));
+    }
+
+    #[test]
+    fn breaker_trips_after_sustained_overcurrent() {
+        let mut test_bed = test_bed_with_demand(Power::new::<watt>(280.));
+
+        test_bed.run_with_delta(Duration::from_secs(2));
+        assert!(test_bed.query(|a| a.circuit_breaker().is_closed()));
+
+        test_bed.run_with_delta(Duration::from_secs(2));
+        assert!(test_bed.query(|a| a.circuit_breaker().is_tripped()));
+    }
+
+    #[test]
+    fn breaker_trips_instantly_at_high_overcurrent() {
+        let mut test_bed = test_bed_with_demand(Power::new::<watt>(1400.));
+
+        test_bed.run_with_delta(Duration::from_millis(1));
+
+        assert!(test_bed.query(|a| a.circuit_breaker().is_tripped()));
+    }
+
+    #[test]
+    fn tripped_breaker_unpowers_protected_element() {
+        let mut test_bed = test_bed_with_demand(Power::new::<watt>(1400.));
+
+        test_bed.run();
+        test_bed.run();
+
+        assert!(!test_bed.query(|a| a.consumer_is_powered()));
+        assert_eq!(test_bed.query(|a| a.consumption), Power::new::<watt>(0.));
+    }
+
+    #[test]
+    fn tripped_breaker_writes_tripped_state() {
+        let mut test_bed = test_bed_with_demand(Power::new::<watt>(1400.));
+
+        test_bed.run();
+
+        let is_tripped: bool = test_bed.read_by_name("ELEC_CB_TEST_IS_TRIPPED");
+        assert!(is_tripped);
+    }
+
+    #[test]
+    fn pulled_breaker_unpowers_protected_element() {
+        let mut test_bed = test_bed_with_demand(Power::new::<watt>(100.));
+        pull(&mut test_bed);
+
+        test_bed.run();
+
+        assert!(!test_bed.query(|a| a.circuit_breaker().is_closed()));
+        assert!(!test_bed.query(|a| a.consumer_is_powered()));
+        assert_eq!(test_bed.query(|a| a.consumption), Power::new::<watt>(0.));
+    }
+
+    #[test]
+    fn pushed_breaker_powers_protected_element_again() {
+        let mut test_bed = test_bed_with_demand(Power::new::<watt>(100.));
+        pull(&mut test_bed);
+        test_bed.run();
+
+        push(&mut test_bed);
+        test_bed.run();
+
+        assert!(test_bed.query(|a| a.consumer_is_powered()));
+    }
+
+    #[test]
+    fn tripped_breaker_is_reset_by_pulling_and_pushing_it_after_cooling_down() {
+        let mut test_bed = test_bed_with_demand(Power::new::<watt>(1400.));
+        test_bed.run();
+
+        test_bed.command(|a| a.demand(Power::new::<watt>(100.)));
+        pull(&mut test_bed);
+        test_bed.run_with_delta(Duration::from_secs(15));
+
+        push(&mut test_bed);
+        test_bed.run();
+        test_bed.run();
+
+        assert!(test_bed.query(|a| a.circuit_breaker().is_closed()));
+        assert!(test_bed.query(|a| a.consumer_is_powered()));
+    }
+
+    #[test]
+    fn breaker_reset_before_cooling_down_trips_again() {
+        let mut test_bed = test_bed_with_demand(Power::new::<watt>(280.));
+        test_bed.run_with_delta(Duration::from_secs(4));
+
+        test_bed.command(|a| a.demand(Power::new::<watt>(200.)));
+        pull(&mut test_bed);
+        test_bed.run();
+        push(&mut test_bed);
+        test_bed.run_with_delta(Duration::from_secs(2));
+
+        assert!(test_bed.query(|a| a.circuit_breaker().is_tripped()));
+    }
+
+    #[test]
+    fn breaker_is_addressable_by_its_id() {
+        let test_bed = test_bed_with_demand(Power::new::<watt>(0.));
+
+        assert_eq!(test_bed.query(|a| a.circuit_breaker().id()), "TEST");
+    }
+}
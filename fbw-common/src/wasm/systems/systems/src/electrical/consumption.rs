@@ -48,6 +48,10 @@ impl PowerConsumer {
     pub fn demand(&mut self, power: Power) {
         self.demand = power;
     }
+
+    pub fn bus_type(&self) -> ElectricalBusType {
+        self.powered_by_bus
+    }
 }
 impl SimulationElement for PowerConsumer {
     fn receive_power(&mut self, buses: &impl ElectricalBuses) {
@@ -28,7 +28,7 @@ impl EmergencyGenerator {
         min_rpm_to_supply_power: AngularVelocity,
     ) -> EmergencyGenerator {
         EmergencyGenerator {
-            identifier: context.next_electrical_identifier(),
+            identifier: context.next_named_electrical_identifier("EMER_GEN"),
             writer: ElectricalStateWriter::new(context, "EMER_GEN"),
             supplying: false,
             output_frequency: Frequency::new::<hertz>(0.),
@@ -1,7 +1,7 @@
 use super::{
     ElectricalElement, ElectricalElementIdentifier, ElectricalElementIdentifierProvider,
-    ElectricalStateWriter, ElectricitySource, EngineGeneratorPushButtons, Potential,
-    PotentialOrigin, ProvideFrequency, ProvideLoad, ProvidePotential,
+    ElectricalStateWriter, ElectricitySource, EngineGeneratorPushButtons, MainGeneratorControlUnit,
+    Potential, PotentialOrigin, ProvideFrequency, ProvideLoad, ProvidePotential,
 };
 use crate::{
     engine::Engine,
@@ -41,15 +41,21 @@ pub struct EngineGenerator<Drive: EngineGeneratorDrive> {
     max_true_power: Power,
     identifier: ElectricalElementIdentifier,
     drive: Drive,
-    activated: bool,
+    gcu: MainGeneratorControlUnit,
+    minimum_normal_frequency: Frequency,
     output_frequency: Frequency,
-    normal_frequency: RangeInclusive<f64>,
     output_potential: ElectricPotential,
     load: Ratio,
-    time_above_threshold: Duration,
     failure: Failure,
+    drive_disconnect_failure: Failure,
+    voltage_regulator_failure: Failure,
 }
 impl<Drive: EngineGeneratorDrive> EngineGenerator<Drive> {
+    const REGULATED_POTENTIAL_VOLTS: f64 = 115.;
+    /// The potential of an excited generator whose voltage regulator no longer limits the field.
+    const UNREGULATED_POTENTIAL_VOLTS: f64 = 150.;
+    const OVERLOAD_POTENTIAL_DROP_VOLTS_PER_PERCENT: f64 = 0.03;
+
     pub fn new(
         context: &mut InitContext,
         number: usize,
@@ -60,15 +66,22 @@ impl<Drive: EngineGeneratorDrive> EngineGenerator<Drive> {
             writer: ElectricalStateWriter::new(context, &format!("ENG_GEN_{}", number)),
             number,
             max_true_power,
-            identifier: context.next_electrical_identifier(),
+            identifier: context.next_named_electrical_identifier(&format!("ENG_GEN_{}", number)),
             drive: Drive::new_drive(context, number),
-            activated: true,
+            minimum_normal_frequency: Frequency::new::<hertz>(*normal_frequency.start()),
+            gcu: MainGeneratorControlUnit::new(
+                context,
+                &format!("ENG_GEN_{}", number),
+                110.0..=120.0,
+                normal_frequency,
+                INTEGRATED_DRIVE_GENERATOR_STABILIZATION_TIME,
+            ),
             output_frequency: Frequency::new::<hertz>(0.),
-            normal_frequency,
             output_potential: ElectricPotential::new::<volt>(0.),
             load: Ratio::new::<percent>(0.),
-            time_above_threshold: INTEGRATED_DRIVE_GENERATOR_STABILIZATION_TIME,
             failure: Failure::new(FailureType::Generator(number)),
+            drive_disconnect_failure: Failure::new(FailureType::GeneratorDriveDisconnect(number)),
+            voltage_regulator_failure: Failure::new(FailureType::GeneratorVoltageRegulator(number)),
         }
     }
 
@@ -79,44 +92,33 @@ impl<Drive: EngineGeneratorDrive> EngineGenerator<Drive> {
         generator_buttons: &impl EngineGeneratorPushButtons,
         fire_buttons: &impl EngineFirePushButtons,
     ) {
-        if generator_buttons.idg_push_button_is_released(self.number) {
-            // The drive cannot be reconnected.
+        if generator_buttons.idg_push_button_is_released(self.number)
+            || self.drive_disconnect_failure.is_active()
+        {
+            self.gcu.request_drive_disconnect();
+        }
+        if self.gcu.drive_disconnect_requested() {
+            // The drive cannot be reconnected, not even once the failure is cleared.
             self.drive.disconnect();
         }
-        self.activated = generator_buttons.engine_gen_push_button_is_on(self.number)
-            && !fire_buttons.is_released(self.number);
         self.drive.update_drive(context, engine);
-        self.output_frequency = if self.activated {
-            Frequency::new::<hertz>(
-                self.drive.output_speed().get::<revolution_per_minute>() * 4. / 120.,
-            )
-        } else {
-            Frequency::default()
-        };
-        self.update_stable_time(context);
-    }
-
-    // TODO: move to GCU when implemented
-    fn update_stable_time(&mut self, context: &UpdateContext) {
-        if !self.activated {
-            self.time_above_threshold = Duration::ZERO;
-            return;
-        }
 
-        let new_time = if self.frequency_normal() {
-            self.time_above_threshold + context.delta()
+        let frequency = Frequency::new::<hertz>(
+            self.drive.output_speed().get::<revolution_per_minute>() * 4. / 120.,
+        );
+        self.gcu.update(
+            context,
+            generator_buttons.engine_gen_push_button_is_on(self.number)
+                && !fire_buttons.is_released(self.number),
+            self.calculate_potential(frequency),
+            frequency,
+            self.load,
+        );
+        self.output_frequency = if self.gcu.excites_generator() {
+            frequency
         } else {
-            Duration::ZERO
+            Frequency::default()
         };
-
-        self.time_above_threshold = new_time.clamp(
-            Duration::ZERO,
-            INTEGRATED_DRIVE_GENERATOR_STABILIZATION_TIME,
-        );
-    }
-
-    fn provides_stable_power_output(&self) -> bool {
-        self.time_above_threshold == INTEGRATED_DRIVE_GENERATOR_STABILIZATION_TIME
     }
 
     /// Indicates if the provided electricity's potential and frequency
@@ -126,19 +128,39 @@ impl<Drive: EngineGeneratorDrive> EngineGenerator<Drive> {
     /// overtemperature which over time will trigger a mechanical
     /// disconnect of the generator.
     pub fn output_within_normal_parameters(&self) -> bool {
-        self.should_provide_output() && self.potential_normal()
+        self.gcu.closes_line_contactor() && !self.failure.is_active()
     }
 
     fn should_provide_output(&self) -> bool {
-        self.provides_stable_power_output()
-            && self.activated
+        self.gcu.provides_stable_power_output()
             && self.frequency_normal()
             && !self.failure.is_active()
     }
 
+    /// The potential the generator provides at the given frequency when excited. The voltage
+    /// regulator holds the potential until the generator turns too slowly or is overloaded.
+    fn calculate_potential(&self, frequency: Frequency) -> ElectricPotential {
+        let speed_ratio = (frequency / self.minimum_normal_frequency)
+            .get::<ratio>()
+            .min(1.);
+        if self.voltage_regulator_failure.is_active() {
+            return ElectricPotential::new::<volt>(Self::UNREGULATED_POTENTIAL_VOLTS * speed_ratio);
+        }
+
+        let overload_percent = (self.load.get::<percent>() - 100.).max(0.);
+        ElectricPotential::new::<volt>(
+            Self::REGULATED_POTENTIAL_VOLTS * speed_ratio
+                - overload_percent * Self::OVERLOAD_POTENTIAL_DROP_VOLTS_PER_PERCENT,
+        )
+    }
+
     pub fn is_drive_connected(&self) -> bool {
         self.drive.is_connected()
     }
+
+    pub fn gcu(&self) -> &MainGeneratorControlUnit {
+        &self.gcu
+    }
 }
 impl<Drive: EngineGeneratorDrive> ElectricitySource for EngineGenerator<Drive> {
     fn output_potential(&self) -> Potential {
@@ -152,33 +174,28 @@ impl<Drive: EngineGeneratorDrive> ElectricitySource for EngineGenerator<Drive> {
         }
     }
 }
-// TODO: Move to GCU
 impl<Drive: EngineGeneratorDrive> ProvidePotential for EngineGenerator<Drive> {
     fn potential(&self) -> ElectricPotential {
         self.output_potential
     }
     fn potential_normal(&self) -> bool {
-        let volts = self.output_potential.get::<volt>();
-        (110.0..=120.0).contains(&volts)
+        self.gcu.potential_normal(self.output_potential)
     }
 }
-// TODO: Move to GCU
 impl<Drive: EngineGeneratorDrive> ProvideFrequency for EngineGenerator<Drive> {
     fn frequency(&self) -> Frequency {
         self.output_frequency
     }
     fn frequency_normal(&self) -> bool {
-        let hz = self.output_frequency.get::<hertz>();
-        self.normal_frequency.contains(&hz)
+        self.gcu.frequency_normal(self.output_frequency)
     }
 }
-// TODO: Move to GCU
 impl<Drive: EngineGeneratorDrive> ProvideLoad for EngineGenerator<Drive> {
     fn load(&self) -> Ratio {
         self.load
     }
     fn load_normal(&self) -> bool {
-        self.load <= Ratio::new::<percent>(100.)
+        self.gcu.load_normal(self.load)
     }
 }
 impl<Drive: EngineGeneratorDrive> ElectricalElement for EngineGenerator<Drive> {
@@ -197,7 +214,10 @@ impl<Drive: EngineGeneratorDrive> ElectricalElement for EngineGenerator<Drive> {
 impl<Drive: EngineGeneratorDrive> SimulationElement for EngineGenerator<Drive> {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.drive.accept(visitor);
+        self.gcu.accept(visitor);
         self.failure.accept(visitor);
+        self.drive_disconnect_failure.accept(visitor);
+        self.voltage_regulator_failure.accept(visitor);
 
         visitor.visit(self);
     }
@@ -209,16 +229,19 @@ impl<Drive: EngineGeneratorDrive> SimulationElement for EngineGenerator<Drive> {
     ) {
         const POWERFACTOR: f64 = 0.8;
 
+        let power_consumption =
+            report.total_consumption_of(PotentialOrigin::EngineGenerator(self.number));
+        let power_factor_correction = Ratio::new::<ratio>(POWERFACTOR);
+        self.load = power_consumption * power_factor_correction / self.max_true_power;
+
         self.output_potential = if self.should_provide_output() {
-            ElectricPotential::new::<volt>(115.)
+            self.calculate_potential(self.output_frequency)
         } else {
             ElectricPotential::new::<volt>(0.)
         };
 
-        let power_consumption =
-            report.total_consumption_of(PotentialOrigin::EngineGenerator(self.number));
-        let power_factor_correction = Ratio::new::<ratio>(POWERFACTOR);
-        self.load = power_consumption * power_factor_correction / self.max_true_power;
+        self.gcu
+            .sense_output(self.output_potential, self.output_frequency);
     }
 
     fn write(&self, writer: &mut SimulatorWriter) {
@@ -232,6 +255,7 @@ pub struct ConstantSpeedDrive {
     is_connected_id: VariableIdentifier,
     connected: bool,
     output_speed: AngularVelocity,
+    governor_failure: Failure,
 }
 impl ConstantSpeedDrive {
     // Threshold to reach target output speed = 58% of 16645 RPM
@@ -251,16 +275,20 @@ impl ConstantSpeedDrive {
                 .get_identifier(format!("ELEC_ENG_GEN_{}_IDG_IS_CONNECTED", number)),
             connected: true,
             output_speed: AngularVelocity::default(),
+            governor_failure: Failure::new(FailureType::GeneratorDriveGovernor(number)),
         }
     }
 
     pub fn update(&mut self, context: &UpdateContext, engine: &impl Engine) {
-        self.output_speed = if self.connected {
+        self.output_speed = if !self.connected {
+            AngularVelocity::default()
+        } else if self.governor_failure.is_active() {
+            // Without its governor, the drive no longer limits the output speed.
+            Self::M * engine.gearbox_speed()
+        } else {
             (Self::M * engine.gearbox_speed()).min(AngularVelocity::new::<revolution_per_minute>(
                 Self::OUTPUT_SPEED_RPM,
             ))
-        } else {
-            AngularVelocity::default()
         };
         self.update_temperature(
             context,
@@ -326,6 +354,12 @@ impl EngineGeneratorDrive for ConstantSpeedDrive {
     }
 }
 impl SimulationElement for ConstantSpeedDrive {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.governor_failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn write(&self, writer: &mut SimulatorWriter) {
         writer.write(&self.oil_outlet_temperature_id, self.oil_outlet_temperature);
         writer.write(&self.is_connected_id, self.connected);
@@ -525,6 +559,7 @@ mod tests {
         use crate::{
             electrical::{
                 consumption::PowerConsumer, ElectricalBus, ElectricalBusType, Electricity,
+                GeneratorControlUnitFault,
             },
             simulation::{
                 test::{ReadByName, SimulationTestBed, TestBed},
@@ -533,10 +568,10 @@ mod tests {
         };
         use uom::si::power::{kilowatt, watt};
 
-        struct EngineGeneratorTestBed {
-            test_bed: SimulationTestBed<TestAircraft>,
+        struct EngineGeneratorTestBed<Drive: EngineGeneratorDrive> {
+            test_bed: SimulationTestBed<TestAircraft<Drive>>,
         }
-        impl EngineGeneratorTestBed {
+        impl EngineGeneratorTestBed<ConstantSpeedDrive> {
             fn with_running_engine() -> Self {
                 Self {
                     test_bed: SimulationTestBed::new(TestAircraft::with_running_engine),
@@ -548,7 +583,17 @@ mod tests {
                     test_bed: SimulationTestBed::new(TestAircraft::with_shutdown_engine),
                 }
             }
-
+        }
+        impl EngineGeneratorTestBed<DirectDrive> {
+            fn variable_frequency_with_running_engine() -> Self {
+                Self {
+                    test_bed: SimulationTestBed::new(
+                        TestAircraft::variable_frequency_with_running_engine,
+                    ),
+                }
+            }
+        }
+        impl<Drive: EngineGeneratorDrive> EngineGeneratorTestBed<Drive> {
             fn frequency_is_normal(&mut self) -> bool {
                 self.read_by_name("ELEC_ENG_GEN_1_FREQUENCY_NORMAL")
             }
@@ -573,22 +618,23 @@ mod tests {
                 self.query(|a| a.generator_output_within_normal_parameters())
             }
         }
-        impl TestBed for EngineGeneratorTestBed {
-            type Aircraft = TestAircraft;
+        impl<Drive: EngineGeneratorDrive> TestBed for EngineGeneratorTestBed<Drive> {
+            type Aircraft = TestAircraft<Drive>;
 
-            fn test_bed(&self) -> &SimulationTestBed<TestAircraft> {
+            fn test_bed(&self) -> &SimulationTestBed<TestAircraft<Drive>> {
                 &self.test_bed
             }
 
-            fn test_bed_mut(&mut self) -> &mut SimulationTestBed<TestAircraft> {
+            fn test_bed_mut(&mut self) -> &mut SimulationTestBed<TestAircraft<Drive>> {
                 &mut self.test_bed
             }
         }
 
-        struct TestAircraft {
-            engine_gen: IntegratedDriveGenerator,
+        struct TestAircraft<Drive: EngineGeneratorDrive> {
+            engine_gen: EngineGenerator<Drive>,
             bus: ElectricalBus,
             running: bool,
+            running_n2: Ratio,
             gen_push_button_on: bool,
             idg_push_button_released: bool,
             fire_push_button_released: bool,
@@ -596,12 +642,33 @@ mod tests {
             generator_output_within_normal_parameters_before_processing_power_consumption_report:
                 bool,
         }
-        impl TestAircraft {
-            fn new(running: bool, context: &mut InitContext) -> Self {
+        impl TestAircraft<ConstantSpeedDrive> {
+            fn with_shutdown_engine(context: &mut InitContext) -> Self {
+                TestAircraft::new(context, false, 390.0..=410.0, 80.)
+            }
+
+            fn with_running_engine(context: &mut InitContext) -> Self {
+                TestAircraft::new(context, true, 390.0..=410.0, 80.)
+            }
+        }
+        impl TestAircraft<DirectDrive> {
+            fn variable_frequency_with_running_engine(context: &mut InitContext) -> Self {
+                // 60% N2 drives the generator at 624 Hz.
+                TestAircraft::new(context, true, 360.0..=800.0, 60.)
+            }
+        }
+        impl<Drive: EngineGeneratorDrive> TestAircraft<Drive> {
+            fn new(
+                context: &mut InitContext,
+                running: bool,
+                normal_frequency: RangeInclusive<f64>,
+                running_n2_percent: f64,
+            ) -> Self {
                 Self {
-                    engine_gen: IntegratedDriveGenerator::new(context, 1, Power::new::<kilowatt>(90.), 390.0..=410.0),
+                    engine_gen: EngineGenerator::new(context, 1, Power::new::<kilowatt>(90.), normal_frequency),
                     bus: ElectricalBus::new(context, ElectricalBusType::AlternatingCurrent(1)),
                     running,
+                    running_n2: Ratio::new::<percent>(running_n2_percent),
                     gen_push_button_on: true,
                     idg_push_button_released: false,
                     fire_push_button_released: false,
@@ -610,14 +677,6 @@ mod tests {
                 }
             }
 
-            fn with_shutdown_engine(context: &mut InitContext) -> Self {
-                TestAircraft::new(false, context)
-            }
-
-            fn with_running_engine(context: &mut InitContext) -> Self {
-                TestAircraft::new(true, context)
-            }
-
             fn disconnect_idg(&mut self) {
                 self.idg_push_button_released = true;
             }
@@ -626,6 +685,10 @@ mod tests {
                 self.gen_push_button_on = false;
             }
 
+            fn gen_push_button_on(&mut self) {
+                self.gen_push_button_on = true;
+            }
+
             fn release_fire_push_button(&mut self) {
                 self.fire_push_button_released = true;
             }
@@ -652,6 +715,10 @@ mod tests {
                 self.running = true;
             }
 
+            fn set_running_n2(&mut self, n2: Ratio) {
+                self.running_n2 = n2;
+            }
+
             fn generator_output_within_normal_parameters_before_processing_power_consumption_report(
                 &self,
             ) -> bool {
@@ -662,7 +729,7 @@ mod tests {
                 self.engine_gen.output_within_normal_parameters()
             }
         }
-        impl Aircraft for TestAircraft {
+        impl<Drive: EngineGeneratorDrive> Aircraft for TestAircraft<Drive> {
             fn update_before_power_distribution(
                 &mut self,
                 context: &UpdateContext,
@@ -670,7 +737,11 @@ mod tests {
             ) {
                 self.engine_gen.update(
                     context,
-                    &TestEngine::new(Ratio::new::<percent>(if self.running { 80. } else { 0. })),
+                    &TestEngine::new(if self.running {
+                        self.running_n2
+                    } else {
+                        Ratio::default()
+                    }),
                     &TestOverhead::new(self.gen_push_button_on, self.idg_push_button_released),
                     &TestFireOverhead::new(self.fire_push_button_released),
                 );
@@ -680,7 +751,7 @@ mod tests {
                 self.generator_output_within_normal_parameters_before_processing_power_consumption_report = self.engine_gen.output_within_normal_parameters();
             }
         }
-        impl SimulationElement for TestAircraft {
+        impl<Drive: EngineGeneratorDrive> SimulationElement for TestAircraft<Drive> {
             fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
                 self.engine_gen.accept(visitor);
                 self.consumer.accept(visitor);
@@ -755,6 +826,29 @@ mod tests {
             assert!(!test_bed.generator_is_powered());
         }
 
+        #[test]
+        fn when_engine_running_but_drive_disconnect_failure_provides_no_output() {
+            let mut test_bed = EngineGeneratorTestBed::with_running_engine();
+
+            test_bed.fail(FailureType::GeneratorDriveDisconnect(1));
+            test_bed.run();
+
+            assert!(!test_bed.generator_is_powered());
+            assert!(!test_bed.query(|a| a.engine_gen.is_drive_connected()));
+        }
+
+        #[test]
+        fn drive_stays_disconnected_once_drive_disconnect_failure_cleared() {
+            let mut test_bed = EngineGeneratorTestBed::with_running_engine();
+
+            test_bed.fail(FailureType::GeneratorDriveDisconnect(1));
+            test_bed.run();
+            test_bed.unfail(FailureType::GeneratorDriveDisconnect(1));
+            test_bed.run();
+
+            assert!(!test_bed.generator_is_powered());
+        }
+
         #[test]
         fn when_engine_running_but_generator_off_provides_no_output() {
             let mut test_bed = EngineGeneratorTestBed::with_running_engine();
@@ -765,6 +859,148 @@ mod tests {
             assert!(!test_bed.generator_is_powered());
         }
 
+        #[test]
+        fn sustained_overload_trips_generator() {
+            let mut test_bed = EngineGeneratorTestBed::with_running_engine();
+            test_bed.command(|a| a.power_demand(Power::new::<watt>(2. * 90000. / 0.8)));
+            test_bed.run();
+
+            test_bed.run_with_delta(Duration::from_secs(6));
+
+            assert!(!test_bed.generator_is_powered());
+            assert_eq!(
+                test_bed.query(|a| a.engine_gen.gcu().fault()),
+                Some(GeneratorControlUnitFault::Overload)
+            );
+        }
+
+        #[test]
+        fn tripped_generator_is_reset_by_gen_push_button() {
+            let mut test_bed = EngineGeneratorTestBed::with_running_engine();
+            test_bed.command(|a| a.power_demand(Power::new::<watt>(2. * 90000. / 0.8)));
+            test_bed.run();
+            test_bed.run_with_delta(Duration::from_secs(6));
+
+            test_bed.command(|a| a.power_demand(Power::new::<watt>(0.)));
+            test_bed.command(|a| a.gen_push_button_off());
+            test_bed.run();
+            test_bed.command(|a| a.gen_push_button_on());
+            test_bed.run();
+
+            assert!(test_bed.generator_is_powered());
+        }
+
+        #[test]
+        fn voltage_regulator_failure_latches_an_over_voltage_fault() {
+            let mut test_bed = EngineGeneratorTestBed::with_running_engine();
+            test_bed.run();
+
+            test_bed.fail(FailureType::GeneratorVoltageRegulator(1));
+            test_bed.run_with_delta(Duration::from_millis(200));
+            test_bed.unfail(FailureType::GeneratorVoltageRegulator(1));
+            test_bed.run();
+
+            assert!(!test_bed.generator_is_powered());
+            assert_eq!(
+                test_bed.query(|a| a.engine_gen.gcu().fault()),
+                Some(GeneratorControlUnitFault::OverVoltage)
+            );
+
+            test_bed.command(|a| a.gen_push_button_off());
+            test_bed.run();
+            test_bed.command(|a| a.gen_push_button_on());
+            test_bed.run();
+
+            assert!(test_bed.generator_is_powered());
+            assert_eq!(test_bed.query(|a| a.engine_gen.gcu().fault()), None);
+        }
+
+        #[test]
+        fn drive_governor_failure_latches_an_over_frequency_fault() {
+            let mut test_bed = EngineGeneratorTestBed::with_running_engine();
+            test_bed.run();
+
+            test_bed.fail(FailureType::GeneratorDriveGovernor(1));
+            test_bed.run_with_delta(Duration::from_millis(200));
+            test_bed.unfail(FailureType::GeneratorDriveGovernor(1));
+            test_bed.run();
+
+            assert!(!test_bed.generator_is_powered());
+            assert_eq!(
+                test_bed.query(|a| a.engine_gen.gcu().fault()),
+                Some(GeneratorControlUnitFault::OverFrequency)
+            );
+
+            test_bed.command(|a| a.gen_push_button_off());
+            test_bed.run();
+            test_bed.command(|a| a.gen_push_button_on());
+            test_bed.run_with_delta(INTEGRATED_DRIVE_GENERATOR_STABILIZATION_TIME);
+
+            assert!(test_bed.generator_is_powered());
+            assert_eq!(test_bed.query(|a| a.engine_gen.gcu().fault()), None);
+        }
+
+        #[test]
+        fn variable_frequency_generator_overspeed_latches_an_over_frequency_fault() {
+            let mut test_bed = EngineGeneratorTestBed::variable_frequency_with_running_engine();
+            test_bed.run();
+            assert!(test_bed.generator_is_powered());
+
+            // 90% N2 drives the generator at 936 Hz.
+            test_bed.command(|a| a.set_running_n2(Ratio::new::<percent>(90.)));
+            test_bed.run_with_delta(Duration::from_millis(200));
+            test_bed.command(|a| a.set_running_n2(Ratio::new::<percent>(60.)));
+            test_bed.run();
+
+            assert!(!test_bed.generator_is_powered());
+            assert_eq!(
+                test_bed.query(|a| a.engine_gen.gcu().fault()),
+                Some(GeneratorControlUnitFault::OverFrequency)
+            );
+
+            test_bed.command(|a| a.gen_push_button_off());
+            test_bed.run();
+            test_bed.command(|a| a.gen_push_button_on());
+            test_bed.run_with_delta(INTEGRATED_DRIVE_GENERATOR_STABILIZATION_TIME);
+
+            assert!(test_bed.generator_is_powered());
+        }
+
+        #[test]
+        fn variable_frequency_generator_voltage_regulator_failure_latches_an_over_voltage_fault() {
+            let mut test_bed = EngineGeneratorTestBed::variable_frequency_with_running_engine();
+            test_bed.run();
+
+            test_bed.fail(FailureType::GeneratorVoltageRegulator(1));
+            test_bed.run_with_delta(Duration::from_millis(200));
+            test_bed.unfail(FailureType::GeneratorVoltageRegulator(1));
+            test_bed.run();
+
+            assert!(!test_bed.generator_is_powered());
+            assert_eq!(
+                test_bed.query(|a| a.engine_gen.gcu().fault()),
+                Some(GeneratorControlUnitFault::OverVoltage)
+            );
+
+            test_bed.command(|a| a.gen_push_button_off());
+            test_bed.run();
+            test_bed.command(|a| a.gen_push_button_on());
+            test_bed.run();
+
+            assert!(test_bed.generator_is_powered());
+        }
+
+        #[test]
+        fn overload_lowers_the_potential() {
+            let mut test_bed = EngineGeneratorTestBed::with_running_engine();
+            test_bed.command(|a| a.power_demand(Power::new::<watt>(2. * 90000. / 0.8)));
+            test_bed.run();
+
+            let potential: ElectricPotential = test_bed.read_by_name("ELEC_ENG_GEN_1_POTENTIAL");
+            assert!(potential < ElectricPotential::new::<volt>(115.));
+            assert!(test_bed.potential_is_normal());
+        }
+
         #[test]
         fn when_engine_running_but_fire_push_button_released_provides_no_output() {
             let mut test_bed = EngineGeneratorTestBed::with_running_engine();
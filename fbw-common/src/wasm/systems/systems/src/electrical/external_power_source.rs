@@ -28,7 +28,7 @@ impl ExternalPowerSource {
         ExternalPowerSource {
             external_power_available_id: context
                 .get_identifier(format!("EXTERNAL POWER AVAILABLE:{id}")),
-            identifier: context.next_electrical_identifier(),
+            identifier: context.next_named_electrical_identifier(&format!("EXT_PWR_{id}")),
             writer: ElectricalStateWriter::new(context, "EXT_PWR"),
             is_connected: false,
             output_frequency: Frequency::new::<hertz>(0.),
@@ -0,0 +1,516 @@
+use std::{ops::RangeInclusive, time::Duration};
+
+use uom::si::{electric_potential::volt, f64::*, frequency::hertz, ratio::percent};
+
+use crate::simulation::{
+    InitContext, SimulationElement, SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+};
+
+/// Faults detected by a [`MainGeneratorControlUnit`]. The discriminant is the fault
+/// code which is written to the simulator.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GeneratorControlUnitFault {
+    OverVoltage = 1,
+    UnderVoltage = 2,
+    OverFrequency = 3,
+    UnderFrequency = 4,
+    Overload = 5,
+}
+impl GeneratorControlUnitFault {
+    /// Latching faults de-excite the generator until the GCU is reset by
+    /// switching the GEN push button off. Non-latching faults clear by themselves
+    /// once the generator output recovers, e.g. when the engine is spooling up.
+    fn is_latching(self) -> bool {
+        matches!(
+            self,
+            GeneratorControlUnitFault::OverVoltage
+                | GeneratorControlUnitFault::OverFrequency
+                | GeneratorControlUnitFault::Overload
+        )
+    }
+}
+
+/// Monitors a fault condition and indicates when it has been present
+/// for longer than the protection's time delay.
+struct Protection {
+    fault: GeneratorControlUnitFault,
+    time_delay: Duration,
+    present_for: Duration,
+}
+impl Protection {
+    fn new(fault: GeneratorControlUnitFault, time_delay: Duration) -> Self {
+        Self {
+            fault,
+            time_delay,
+            present_for: Duration::ZERO,
+        }
+    }
+
+    fn update(&mut self, context: &UpdateContext, condition: bool) {
+        self.present_for = if condition {
+            self.present_for + context.delta()
+        } else {
+            Duration::ZERO
+        };
+    }
+
+    fn tripped_fault(&self) -> Option<GeneratorControlUnitFault> {
+        if self.present_for > Duration::ZERO && self.present_for >= self.time_delay {
+            Some(self.fault)
+        } else {
+            None
+        }
+    }
+
+    fn reset(&mut self) {
+        self.present_for = Duration::ZERO;
+    }
+}
+
+/// The generator control unit (GCU) of an engine or APU generator.
+///
+/// The GCU excites the generator when its GEN push button is on, and opens the
+/// generator line contactor when the output isn't within normal parameters.
+/// It protects the network against over and under voltage, over and under frequency
+/// and overload. Over voltage, over frequency and overload faults are latched and
+/// de-excite the generator until the GEN push button is switched off.
+pub struct MainGeneratorControlUnit {
+    fault_id: VariableIdentifier,
+
+    normal_potential: RangeInclusive<f64>,
+    normal_frequency: RangeInclusive<f64>,
+    stabilization_time: Duration,
+
+    push_button_is_on: bool,
+    potential: ElectricPotential,
+    frequency: Frequency,
+    time_above_threshold: Duration,
+    drive_disconnect_requested: bool,
+
+    protections: [Protection; 5],
+    latched_fault: Option<GeneratorControlUnitFault>,
+}
+impl MainGeneratorControlUnit {
+    const OVER_VOLTAGE_LIMIT_VOLTS: f64 = 130.;
+    const OVER_FREQUENCY_FACTOR: f64 = 1.1;
+    const OVERLOAD_LIMIT_PERCENT: f64 = 150.;
+
+    /// Creates a GCU for the generator with the given ID, e.g. `"ENG_GEN_1"`.
+    /// The line contactor only closes once the frequency has been normal
+    /// for the given stabilization time.
+    pub fn new(
+        context: &mut InitContext,
+        generator_id: &str,
+        normal_potential: RangeInclusive<f64>,
+        normal_frequency: RangeInclusive<f64>,
+        stabilization_time: Duration,
+    ) -> Self {
+        Self {
+            fault_id: context.get_identifier(format!("ELEC_{}_GCU_FAULT", generator_id)),
+
+            normal_potential,
+            normal_frequency,
+            stabilization_time,
+
+            push_button_is_on: true,
+            potential: ElectricPotential::new::<volt>(0.),
+            frequency: Frequency::new::<hertz>(0.),
+            time_above_threshold: stabilization_time,
+            drive_disconnect_requested: false,
+
+            protections: [
+                Protection::new(
+                    GeneratorControlUnitFault::OverVoltage,
+                    Duration::from_millis(100),
+                ),
+                Protection::new(
+                    GeneratorControlUnitFault::UnderVoltage,
+                    Duration::from_secs(1),
+                ),
+                Protection::new(
+                    GeneratorControlUnitFault::OverFrequency,
+                    Duration::from_millis(100),
+                ),
+                Protection::new(
+                    GeneratorControlUnitFault::UnderFrequency,
+                    Duration::from_secs(1),
+                ),
+                Protection::new(GeneratorControlUnitFault::Overload, Duration::from_secs(5)),
+            ],
+            latched_fault: None,
+        }
+    }
+
+    /// Updates the GCU with the output the generator would provide when excited.
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        push_button_is_on: bool,
+        potential: ElectricPotential,
+        frequency: Frequency,
+        load: Ratio,
+    ) {
+        self.push_button_is_on = push_button_is_on;
+        self.potential = potential;
+        self.frequency = frequency;
+
+        if !push_button_is_on {
+            self.reset();
+        }
+
+        self.update_protections(context, potential, frequency, load);
+        self.update_stable_time(context);
+    }
+
+    /// Senses the generator output once it is known at the end of a tick,
+    /// such that the line contactor decision reflects the latest output.
+    pub fn sense_output(&mut self, potential: ElectricPotential, frequency: Frequency) {
+        self.potential = potential;
+        self.frequency = frequency;
+    }
+
+    fn update_protections(
+        &mut self,
+        context: &UpdateContext,
+        potential: ElectricPotential,
+        frequency: Frequency,
+        load: Ratio,
+    ) {
+        let is_excited = self.excites_generator();
+        let is_stable = self.provides_stable_power_output();
+        let volts = potential.get::<volt>();
+        let hz = frequency.get::<hertz>();
+
+        let conditions = [
+            is_excited && volts > Self::OVER_VOLTAGE_LIMIT_VOLTS,
+            is_excited && is_stable && volts < *self.normal_potential.start(),
+            is_excited && hz > self.normal_frequency.end() * Self::OVER_FREQUENCY_FACTOR,
+            // Under frequency protection is inhibited while the generator isn't turning.
+            is_excited && hz > 0. && hz < *self.normal_frequency.start(),
+            is_excited && load.get::<percent>() > Self::OVERLOAD_LIMIT_PERCENT,
+        ];
+
+        for (protection, condition) in self.protections.iter_mut().zip(conditions) {
+            protection.update(context, condition);
+        }
+
+        if self.latched_fault.is_none() {
+            self.latched_fault = self
+                .protections
+                .iter()
+                .filter_map(|protection| protection.tripped_fault())
+                .find(|fault| fault.is_latching());
+        }
+    }
+
+    fn update_stable_time(&mut self, context: &UpdateContext) {
+        if !self.excites_generator() {
+            self.time_above_threshold = Duration::ZERO;
+            return;
+        }
+
+        let new_time = if self.frequency_normal(self.frequency) {
+            self.time_above_threshold + context.delta()
+        } else {
+            Duration::ZERO
+        };
+
+        self.time_above_threshold = new_time.clamp(Duration::ZERO, self.stabilization_time);
+    }
+
+    fn reset(&mut self) {
+        self.latched_fault = None;
+        self.protections
+            .iter_mut()
+            .for_each(|protection| protection.reset());
+    }
+
+    /// Requests the generator drive to disconnect. Once disconnected, the
+    /// drive can only be reconnected on the ground by maintenance.
+    pub fn request_drive_disconnect(&mut self) {
+        self.drive_disconnect_requested = true;
+    }
+
+    pub fn drive_disconnect_requested(&self) -> bool {
+        self.drive_disconnect_requested
+    }
+
+    /// Indicates if the GCU supplies the generator's field, which is the
+    /// case when the GEN push button is on and no latched fault is present.
+    pub fn excites_generator(&self) -> bool {
+        self.push_button_is_on && self.latched_fault.is_none()
+    }
+
+    pub fn provides_stable_power_output(&self) -> bool {
+        self.excites_generator() && self.time_above_threshold == self.stabilization_time
+    }
+
+    /// Indicates if the generator line contactor should be closed.
+    /// Load isn't taken into account, as overloading only trips the
+    /// generator after the overload protection's time delay.
+    pub fn closes_line_contactor(&self) -> bool {
+        self.provides_stable_power_output()
+            && self.potential_normal(self.potential)
+            && self.frequency_normal(self.frequency)
+    }
+
+    /// The latched fault or, when there is none, the currently active non-latching fault.
+    pub fn fault(&self) -> Option<GeneratorControlUnitFault> {
+        self.latched_fault.or_else(|| {
+            self.protections
+                .iter()
+                .find_map(|protection| protection.tripped_fault())
+        })
+    }
+
+    pub fn potential_normal(&self, potential: ElectricPotential) -> bool {
+        self.normal_potential.contains(&potential.get::<volt>())
+    }
+
+    pub fn frequency_normal(&self, frequency: Frequency) -> bool {
+        self.normal_frequency.contains(&frequency.get::<hertz>())
+    }
+
+    pub fn load_normal(&self, load: Ratio) -> bool {
+        load <= Ratio::new::<percent>(100.)
+    }
+}
+impl SimulationElement for MainGeneratorControlUnit {
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(
+            &self.fault_id,
+            self.fault().map_or(0., |fault| fault as u8 as f64),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::{
+        test::{ReadByName, SimulationTestBed, TestBed},
+        Aircraft, SimulationElementVisitor,
+    };
+
+    struct TestAircraft {
+        gcu: MainGeneratorControlUnit,
+        push_button_is_on: bool,
+        potential: ElectricPotential,
+        frequency: Frequency,
+        load: Ratio,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                gcu: MainGeneratorControlUnit::new(
+                    context,
+                    "ENG_GEN_1",
+                    110.0..=120.0,
+                    390.0..=410.0,
+                    Duration::from_millis(500),
+                ),
+                push_button_is_on: true,
+                potential: ElectricPotential::new::<volt>(115.),
+                frequency: Frequency::new::<hertz>(400.),
+                load: Ratio::new::<percent>(50.),
+            }
+        }
+
+        fn set_push_button_on(&mut self, is_on: bool) {
+            self.push_button_is_on = is_on;
+        }
+
+        fn set_potential(&mut self, volts: f64) {
+            self.potential = ElectricPotential::new::<volt>(volts);
+        }
+
+        fn set_frequency(&mut self, hz: f64) {
+            self.frequency = Frequency::new::<hertz>(hz);
+        }
+
+        fn set_load(&mut self, load_percent: f64) {
+            self.load = Ratio::new::<percent>(load_percent);
+        }
+
+        fn gcu(&self) -> &MainGeneratorControlUnit {
+            &self.gcu
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.gcu.update(
+                context,
+                self.push_button_is_on,
+                self.potential,
+                self.frequency,
+                self.load,
+            );
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.gcu.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed() -> SimulationTestBed<TestAircraft> {
+        SimulationTestBed::new(TestAircraft::new)
+    }
+
+    #[test]
+    fn closes_line_contactor_when_output_is_normal() {
+        let mut test_bed = test_bed();
+
+        test_bed.run();
+
+        assert!(test_bed.query(|a| a.gcu().closes_line_contactor()));
+        assert_eq!(test_bed.query(|a| a.gcu().fault()), None);
+    }
+
+    #[test]
+    fn does_not_excite_generator_when_push_button_off() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.set_push_button_on(false));
+
+        test_bed.run();
+
+        assert!(!test_bed.query(|a| a.gcu().excites_generator()));
+        assert!(!test_bed.query(|a| a.gcu().closes_line_contactor()));
+    }
+
+    #[test]
+    fn line_contactor_closes_after_stabilization_time() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.set_frequency(0.));
+        test_bed.run();
+
+        test_bed.command(|a| a.set_frequency(400.));
+        test_bed.run_with_delta(Duration::from_millis(250));
+        assert!(!test_bed.query(|a| a.gcu().closes_line_contactor()));
+
+        test_bed.run_with_delta(Duration::from_millis(250));
+        assert!(test_bed.query(|a| a.gcu().closes_line_contactor()));
+    }
+
+    #[test]
+    fn over_voltage_trips_and_latches() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.set_potential(140.));
+        test_bed.run();
+
+        test_bed.command(|a| a.set_potential(115.));
+        test_bed.run();
+
+        assert_eq!(
+            test_bed.query(|a| a.gcu().fault()),
+            Some(GeneratorControlUnitFault::OverVoltage)
+        );
+        assert!(!test_bed.query(|a| a.gcu().excites_generator()));
+        assert!(!test_bed.query(|a| a.gcu().closes_line_contactor()));
+    }
+
+    #[test]
+    fn over_frequency_trips_and_latches() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.set_frequency(460.));
+        test_bed.run();
+
+        test_bed.command(|a| a.set_frequency(400.));
+        test_bed.run();
+
+        assert_eq!(
+            test_bed.query(|a| a.gcu().fault()),
+            Some(GeneratorControlUnitFault::OverFrequency)
+        );
+        assert!(!test_bed.query(|a| a.gcu().excites_generator()));
+    }
+
+    #[test]
+    fn overload_trips_after_time_delay() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.set_load(160.));
+
+        test_bed.run_with_delta(Duration::from_secs(4));
+        assert!(test_bed.query(|a| a.gcu().excites_generator()));
+
+        test_bed.run_with_delta(Duration::from_secs(1));
+        assert_eq!(
+            test_bed.query(|a| a.gcu().fault()),
+            Some(GeneratorControlUnitFault::Overload)
+        );
+        assert!(!test_bed.query(|a| a.gcu().excites_generator()));
+    }
+
+    #[test]
+    fn latched_fault_is_reset_by_switching_push_button_off_and_on() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.set_potential(140.));
+        test_bed.run();
+
+        test_bed.command(|a| a.set_potential(115.));
+        test_bed.command(|a| a.set_push_button_on(false));
+        test_bed.run();
+        test_bed.command(|a| a.set_push_button_on(true));
+        test_bed.run();
+
+        assert_eq!(test_bed.query(|a| a.gcu().fault()), None);
+        assert!(test_bed.query(|a| a.gcu().closes_line_contactor()));
+    }
+
+    #[test]
+    fn under_voltage_does_not_latch() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.set_potential(100.));
+        test_bed.run_with_delta(Duration::from_secs(2));
+
+        assert_eq!(
+            test_bed.query(|a| a.gcu().fault()),
+            Some(GeneratorControlUnitFault::UnderVoltage)
+        );
+        assert!(test_bed.query(|a| a.gcu().excites_generator()));
+
+        test_bed.command(|a| a.set_potential(115.));
+        test_bed.run();
+
+        assert_eq!(test_bed.query(|a| a.gcu().fault()), None);
+    }
+
+    #[test]
+    fn under_frequency_is_reported_while_generator_is_turning() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.set_frequency(300.));
+
+        test_bed.run_with_delta(Duration::from_secs(2));
+
+        assert_eq!(
+            test_bed.query(|a| a.gcu().fault()),
+            Some(GeneratorControlUnitFault::UnderFrequency)
+        );
+    }
+
+    #[test]
+    fn under_frequency_is_inhibited_while_generator_is_not_turning() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.set_frequency(0.));
+
+        test_bed.run_with_delta(Duration::from_secs(2));
+
+        assert_eq!(test_bed.query(|a| a.gcu().fault()), None);
+    }
+
+    #[test]
+    fn writes_fault_code() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.set_frequency(460.));
+
+        test_bed.run();
+
+        let fault_code: f64 = test_bed.read_by_name("ELEC_ENG_GEN_1_GCU_FAULT");
+        assert_eq!(
+            fault_code,
+            GeneratorControlUnitFault::OverFrequency as u8 as f64
+        );
+    }
+}
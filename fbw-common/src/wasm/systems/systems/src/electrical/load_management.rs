@@ -0,0 +1,561 @@
+use std::time::Duration;
+
+use fxhash::FxHashSet;
+use uom::si::{f64::*, power::watt};
+
+use crate::{
+    shared::{ElectricalBusType, ElectricalBuses, PotentialOrigin, PowerConsumptionReport},
+    simulation::{
+        InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
+        VariableIdentifier, Write,
+    },
+};
+
+use super::{consumption::PowerConsumer, CircuitBreaker};
+
+/// A load which can be shed by the [`ElectricalLoadManagement`], such as a galley,
+/// the in-flight entertainment system or other commercial loads.
+///
+/// Loads are identified by a stable ID (e.g. `"MAIN_GALLEY"`), which is used to derive
+/// the `ELEC_{id}_IS_SHED` variable. Each load is protected by a [`CircuitBreaker`]
+/// with the same ID.
+pub struct SheddableLoad {
+    id: &'static str,
+    is_shed_id: VariableIdentifier,
+
+    consumer: CircuitBreaker<PowerConsumer>,
+    demand: Power,
+    is_switched_on: bool,
+    is_shed: bool,
+}
+impl SheddableLoad {
+    pub fn new(
+        context: &mut InitContext,
+        id: &'static str,
+        bus_type: ElectricalBusType,
+        demand: Power,
+        rated_current: ElectricCurrent,
+    ) -> Self {
+        Self {
+            id,
+            is_shed_id: context.get_identifier(format!("ELEC_{}_IS_SHED", id)),
+
+            consumer: CircuitBreaker::new(
+                context,
+                id,
+                bus_type,
+                rated_current,
+                PowerConsumer::from(bus_type),
+            ),
+            demand,
+            is_switched_on: true,
+            is_shed: false,
+        }
+    }
+
+    pub fn id(&self) -> &'static str {
+        self.id
+    }
+
+    pub fn is_shed(&self) -> bool {
+        self.is_shed
+    }
+
+    fn bus_type(&self) -> ElectricalBusType {
+        self.consumer.element().bus_type()
+    }
+
+    fn is_consuming(&self) -> bool {
+        self.is_switched_on && !self.is_shed
+    }
+
+    fn update_consumer(&mut self) {
+        let demand = if self.is_consuming() {
+            self.demand
+        } else {
+            Power::new::<watt>(0.)
+        };
+        self.consumer.element_mut().demand(demand);
+    }
+}
+impl SimulationElement for SheddableLoad {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.consumer.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.is_shed_id, self.is_shed);
+    }
+}
+
+/// Sheds loads when the power demanded from the generators exceeds their capacity.
+///
+/// The available capacity is the sum of the rated power of the sources which power
+/// the buses of the sheddable loads. The demand is the total consumption from those
+/// sources, as found in the power consumption report.
+///
+/// Loads are given in the order in which they are shed. When the demand exceeds the
+/// available capacity for some time, the next load is shed. Loads are restored in
+/// reverse order, once the demand including the restored load stays sufficiently
+/// below the available capacity for some time.
+pub struct ElectricalLoadManagement<const N: usize> {
+    sources: Vec<(PotentialOrigin, Power)>,
+    loads: [SheddableLoad; N],
+
+    online_sources: FxHashSet<PotentialOrigin>,
+    available_capacity: Power,
+    demand: Power,
+    overload_duration: Duration,
+    spare_capacity_duration: Duration,
+}
+impl<const N: usize> ElectricalLoadManagement<N> {
+    const SHED_DELAY: Duration = Duration::from_secs(1);
+    const RESTORE_DELAY: Duration = Duration::from_secs(10);
+    /// Loads are only restored when the demand including the restored load
+    /// remains below this ratio of the available capacity.
+    const RESTORE_CAPACITY_RATIO: f64 = 0.9;
+
+    pub fn new(sources: &[(PotentialOrigin, Power)], loads: [SheddableLoad; N]) -> Self {
+        Self {
+            sources: sources.to_vec(),
+            loads,
+
+            online_sources: FxHashSet::default(),
+            available_capacity: Power::new::<watt>(0.),
+            demand: Power::new::<watt>(0.),
+            overload_duration: Duration::ZERO,
+            spare_capacity_duration: Duration::ZERO,
+        }
+    }
+
+    /// Switches the loads on or off, in the order in which they were given. A load which
+    /// is switched off, e.g. by a push button or the bus topology, consumes no power.
+    pub fn update(&mut self, is_switched_on: [bool; N]) {
+        self.loads
+            .iter_mut()
+            .zip(is_switched_on)
+            .for_each(|(load, is_switched_on)| {
+                load.is_switched_on = is_switched_on;
+                load.update_consumer();
+            });
+    }
+
+    pub fn is_shed(&self, id: &str) -> bool {
+        self.loads
+            .iter()
+            .any(|load| load.id() == id && load.is_shed())
+    }
+
+    pub fn available_capacity(&self) -> Power {
+        self.available_capacity
+    }
+
+    pub fn demand(&self) -> Power {
+        self.demand
+    }
+
+    fn rated_power_of(&self, origin: PotentialOrigin) -> Power {
+        self.sources
+            .iter()
+            .find(|(source, _)| *source == origin)
+            .map_or(Power::new::<watt>(0.), |(_, rated_power)| *rated_power)
+    }
+
+    fn update_shedding(&mut self, context: &UpdateContext) {
+        if self.demand > self.available_capacity {
+            self.overload_duration += context.delta();
+        } else {
+            self.overload_duration = Duration::ZERO;
+        }
+
+        if self.overload_duration >= Self::SHED_DELAY {
+            if let Some(load) = self.loads.iter_mut().find(|load| load.is_consuming()) {
+                load.is_shed = true;
+                load.update_consumer();
+            }
+
+            self.overload_duration = Duration::ZERO;
+        }
+
+        let restore_demand = self
+            .loads
+            .iter()
+            .rev()
+            .find(|load| load.is_shed())
+            .map(|load| self.demand + load.demand);
+
+        match restore_demand {
+            Some(restore_demand)
+                if restore_demand <= self.available_capacity * Self::RESTORE_CAPACITY_RATIO =>
+            {
+                self.spare_capacity_duration += context.delta();
+            }
+            _ => self.spare_capacity_duration = Duration::ZERO,
+        }
+
+        if self.spare_capacity_duration >= Self::RESTORE_DELAY {
+            if let Some(load) = self.loads.iter_mut().rev().find(|load| load.is_shed()) {
+                load.is_shed = false;
+                load.update_consumer();
+            }
+
+            self.spare_capacity_duration = Duration::ZERO;
+        }
+    }
+}
+impl<const N: usize> SimulationElement for ElectricalLoadManagement<N> {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.loads, visitor);
+
+        visitor.visit(self);
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.online_sources.clear();
+        for load in &self.loads {
+            self.online_sources
+                .extend(buses.potential_of(load.bus_type()).origins());
+        }
+
+        self.available_capacity = self
+            .online_sources
+            .iter()
+            .map(|origin| self.rated_power_of(*origin))
+            .fold(Power::new::<watt>(0.), |sum, rated_power| sum + rated_power);
+    }
+
+    fn process_power_consumption_report<T: PowerConsumptionReport>(
+        &mut self,
+        context: &UpdateContext,
+        report: &T,
+    ) {
+        self.demand = self
+            .online_sources
+            .iter()
+            .map(|origin| report.total_consumption_of(*origin))
+            .fold(Power::new::<watt>(0.), |sum, consumption| sum + consumption);
+
+        self.update_shedding(context);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
+        simulation::{
+            test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+            Aircraft,
+        },
+    };
+    use uom::si::{electric_current::ampere, electric_potential::volt, f64::ElectricPotential};
+
+    struct TestAircraft {
+        generator_1: TestElectricitySource,
+        generator_2: TestElectricitySource,
+        generator_2_is_running: bool,
+        ac_bus_1: ElectricalBus,
+        ac_bus_2: ElectricalBus,
+        other_consumer: PowerConsumer,
+        load_management: ElectricalLoadManagement<3>,
+        loads_are_switched_on: [bool; 3],
+    }
+    impl TestAircraft {
+        const GENERATOR_RATED_POWER_WATT: f64 = 90_000.;
+        const GENERATOR_POTENTIAL_VOLT: f64 = 115.;
+
+        fn new(context: &mut InitContext) -> Self {
+            let rated_power = Power::new::<watt>(Self::GENERATOR_RATED_POWER_WATT);
+            let mut generator_1 =
+                TestElectricitySource::powered(context, PotentialOrigin::EngineGenerator(1));
+            generator_1.set_potential(Self::generator_potential());
+            let mut generator_2 =
+                TestElectricitySource::powered(context, PotentialOrigin::EngineGenerator(2));
+            generator_2.set_potential(Self::generator_potential());
+
+            Self {
+                generator_1,
+                generator_2,
+                generator_2_is_running: true,
+                ac_bus_1: ElectricalBus::new(context, ElectricalBusType::AlternatingCurrent(1)),
+                ac_bus_2: ElectricalBus::new(context, ElectricalBusType::AlternatingCurrent(2)),
+                other_consumer: PowerConsumer::from(ElectricalBusType::AlternatingCurrent(1)),
+                load_management: ElectricalLoadManagement::new(
+                    &[
+                        (PotentialOrigin::EngineGenerator(1), rated_power),
+                        (PotentialOrigin::EngineGenerator(2), rated_power),
+                    ],
+                    [
+                        SheddableLoad::new(
+                            context,
+                            "GALLEY",
+                            ElectricalBusType::AlternatingCurrent(1),
+                            Power::new::<watt>(20_000.),
+                            ElectricCurrent::new::<ampere>(200.),
+                        ),
+                        SheddableLoad::new(
+                            context,
+                            "IFE",
+                            ElectricalBusType::AlternatingCurrent(2),
+                            Power::new::<watt>(10_000.),
+                            ElectricCurrent::new::<ampere>(100.),
+                        ),
+                        SheddableLoad::new(
+                            context,
+                            "COMMERCIAL",
+                            ElectricalBusType::AlternatingCurrent(2),
+                            Power::new::<watt>(10_000.),
+                            ElectricCurrent::new::<ampere>(100.),
+                        ),
+                    ],
+                ),
+                loads_are_switched_on: [true; 3],
+            }
+        }
+
+        fn generator_potential() -> ElectricPotential {
+            ElectricPotential::new::<volt>(Self::GENERATOR_POTENTIAL_VOLT)
+        }
+
+        fn stop_generator_2(&mut self) {
+            self.generator_2.unpower();
+            self.generator_2_is_running = false;
+        }
+
+        fn start_generator_2(&mut self) {
+            self.generator_2
+                .power_with_potential(Self::generator_potential());
+            self.generator_2_is_running = true;
+        }
+
+        fn switch_off_galley(&mut self) {
+            self.loads_are_switched_on[0] = false;
+        }
+
+        fn demand(&mut self, power: Power) {
+            self.other_consumer.demand(power);
+        }
+
+        fn is_shed(&self, id: &str) -> bool {
+            self.load_management.is_shed(id)
+        }
+
+        fn available_capacity(&self) -> Power {
+            self.load_management.available_capacity()
+        }
+
+        fn load_demand(&self) -> Power {
+            self.load_management.demand()
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            electricity.supplied_by(&self.generator_1);
+            electricity.supplied_by(&self.generator_2);
+            electricity.flow(&self.generator_1, &self.ac_bus_1);
+
+            if self.generator_2_is_running {
+                electricity.flow(&self.generator_2, &self.ac_bus_2);
+            } else {
+                electricity.flow(&self.generator_1, &self.ac_bus_2);
+            }
+
+            self.load_management.update(self.loads_are_switched_on);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.ac_bus_1.accept(visitor);
+            self.ac_bus_2.accept(visitor);
+            self.other_consumer.accept(visitor);
+            self.load_management.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed() -> SimulationTestBed<TestAircraft> {
+        SimulationTestBed::new(TestAircraft::new)
+    }
+
+    fn run_for(test_bed: &mut SimulationTestBed<TestAircraft>, duration: Duration) {
+        let step = Duration::from_millis(500);
+        for _ in 0..(duration.as_millis() / step.as_millis()) {
+            test_bed.run_with_delta(step);
+        }
+    }
+
+    #[test]
+    fn available_capacity_is_sum_of_sources_powering_the_loads() {
+        let mut test_bed = test_bed();
+
+        test_bed.run();
+
+        assert_eq!(
+            test_bed.query(|a| a.available_capacity()),
+            Power::new::<watt>(2. * TestAircraft::GENERATOR_RATED_POWER_WATT)
+        );
+    }
+
+    #[test]
+    fn available_capacity_reduces_when_a_source_is_lost() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.stop_generator_2());
+
+        test_bed.run();
+
+        assert_eq!(
+            test_bed.query(|a| a.available_capacity()),
+            Power::new::<watt>(TestAircraft::GENERATOR_RATED_POWER_WATT)
+        );
+    }
+
+    #[test]
+    fn pulled_circuit_breaker_takes_its_load_off_the_demand() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("ELEC_CB_GALLEY_IS_PULLED", true);
+
+        test_bed.run();
+
+        assert_eq!(
+            test_bed.query(|a| a.load_demand()),
+            Power::new::<watt>(20_000.)
+        );
+        assert!(!test_bed.query(|a| a.is_shed("GALLEY")));
+    }
+
+    #[test]
+    fn nothing_is_shed_when_capacity_suffices() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.demand(Power::new::<watt>(60_000.)));
+
+        run_for(&mut test_bed, Duration::from_secs(30));
+
+        assert!(!test_bed.query(|a| a.is_shed("GALLEY")));
+        assert!(!test_bed.query(|a| a.is_shed("IFE")));
+        assert!(!test_bed.query(|a| a.is_shed("COMMERCIAL")));
+    }
+
+    #[test]
+    fn first_load_is_shed_when_single_source_is_overloaded() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.demand(Power::new::<watt>(60_000.)));
+        test_bed.command(|a| a.stop_generator_2());
+
+        run_for(&mut test_bed, Duration::from_secs(5));
+
+        assert!(test_bed.query(|a| a.is_shed("GALLEY")));
+        assert!(!test_bed.query(|a| a.is_shed("IFE")));
+        assert!(!test_bed.query(|a| a.is_shed("COMMERCIAL")));
+    }
+
+    #[test]
+    fn overload_is_not_shed_before_delay_expires() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.demand(Power::new::<watt>(60_000.)));
+        test_bed.command(|a| a.stop_generator_2());
+
+        test_bed.run_with_delta(Duration::from_millis(500));
+
+        assert!(!test_bed.query(|a| a.is_shed("GALLEY")));
+    }
+
+    #[test]
+    fn loads_are_shed_by_priority_until_demand_fits_capacity() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.demand(Power::new::<watt>(75_000.)));
+        test_bed.command(|a| a.stop_generator_2());
+
+        run_for(&mut test_bed, Duration::from_secs(10));
+
+        assert!(test_bed.query(|a| a.is_shed("GALLEY")));
+        assert!(test_bed.query(|a| a.is_shed("IFE")));
+        assert!(!test_bed.query(|a| a.is_shed("COMMERCIAL")));
+    }
+
+    #[test]
+    fn switched_off_load_is_skipped_when_shedding() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.switch_off_galley());
+        test_bed.command(|a| a.demand(Power::new::<watt>(85_000.)));
+        test_bed.command(|a| a.stop_generator_2());
+
+        run_for(&mut test_bed, Duration::from_secs(5));
+
+        assert!(!test_bed.query(|a| a.is_shed("GALLEY")));
+        assert!(test_bed.query(|a| a.is_shed("IFE")));
+    }
+
+    #[test]
+    fn shed_loads_are_restored_when_capacity_returns() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.demand(Power::new::<watt>(75_000.)));
+        test_bed.command(|a| a.stop_generator_2());
+        run_for(&mut test_bed, Duration::from_secs(10));
+
+        test_bed.command(|a| a.start_generator_2());
+        run_for(&mut test_bed, Duration::from_secs(30));
+
+        assert!(!test_bed.query(|a| a.is_shed("GALLEY")));
+        assert!(!test_bed.query(|a| a.is_shed("IFE")));
+    }
+
+    #[test]
+    fn shed_loads_are_restored_in_reverse_order() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.demand(Power::new::<watt>(75_000.)));
+        test_bed.command(|a| a.stop_generator_2());
+        run_for(&mut test_bed, Duration::from_secs(10));
+
+        test_bed.command(|a| a.start_generator_2());
+        run_for(&mut test_bed, Duration::from_secs(15));
+
+        assert!(test_bed.query(|a| a.is_shed("GALLEY")));
+        assert!(!test_bed.query(|a| a.is_shed("IFE")));
+    }
+
+    #[test]
+    fn shed_load_is_not_restored_before_delay_expires() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.demand(Power::new::<watt>(60_000.)));
+        test_bed.command(|a| a.stop_generator_2());
+        run_for(&mut test_bed, Duration::from_secs(5));
+
+        test_bed.command(|a| a.start_generator_2());
+        run_for(&mut test_bed, Duration::from_secs(5));
+
+        assert!(test_bed.query(|a| a.is_shed("GALLEY")));
+    }
+
+    #[test]
+    fn shed_load_is_not_restored_when_it_would_overload_the_source_again() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.demand(Power::new::<watt>(60_000.)));
+        test_bed.command(|a| a.stop_generator_2());
+        run_for(&mut test_bed, Duration::from_secs(5));
+
+        test_bed.command(|a| a.demand(Power::new::<watt>(55_000.)));
+        run_for(&mut test_bed, Duration::from_secs(60));
+
+        assert!(test_bed.query(|a| a.is_shed("GALLEY")));
+    }
+
+    #[test]
+    fn writes_shed_state_of_loads() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.demand(Power::new::<watt>(60_000.)));
+        test_bed.command(|a| a.stop_generator_2());
+
+        run_for(&mut test_bed, Duration::from_secs(5));
+
+        let is_shed: bool = test_bed.read_by_name("ELEC_GALLEY_IS_SHED");
+        assert!(is_shed);
+    }
+}
@@ -3,12 +3,16 @@
 mod battery;
 mod battery_charge_limiter;
 mod battery_charge_rectifier_unit;
+mod circuit_breaker;
 pub mod consumption;
 mod emergency_generator;
 mod engine_generator;
 mod external_power_source;
+mod generator_control_unit;
+mod load_management;
 mod ram_air_turbine;
 mod static_inverter;
+mod topology;
 mod transformer_rectifier;
 
 use std::{
@@ -30,9 +34,10 @@ use crate::{
         SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext, Write,
     },
 };
-pub use battery::Battery;
+pub use battery::{Battery, BatteryChemistry};
 pub use battery_charge_limiter::BatteryChargeLimiter;
 pub use battery_charge_rectifier_unit::BatteryChargeRectifierUnit;
+pub use circuit_breaker::CircuitBreaker;
 pub use emergency_generator::EmergencyGenerator;
 pub use engine_generator::{
     EngineGenerator, IntegratedDriveGenerator, VariableFrequencyGenerator,
@@ -40,7 +45,14 @@ pub use engine_generator::{
 };
 pub use external_power_source::ExternalPowerSource;
 use fxhash::{FxHashMap, FxHashSet};
+pub use generator_control_unit::{GeneratorControlUnitFault, MainGeneratorControlUnit};
+pub use load_management::{ElectricalLoadManagement, SheddableLoad};
 pub use static_inverter::StaticInverter;
+use topology::TopologyRecorder;
+pub use topology::{
+    ElectricalTopology, ElectricalTopologyConnection, ElectricalTopologyElement,
+    ElectricalTopologyElementKind,
+};
 pub use transformer_rectifier::TransformerRectifier;
 use uom::si::{electric_potential::volt, f64::*, power::watt, velocity::knot};
 
@@ -57,6 +69,10 @@ pub trait EngineGeneratorPushButtons {
     fn idg_push_button_is_released(&self, number: usize) -> bool;
 }
 
+pub trait ApuGeneratorPushButtons {
+    fn apu_gen_push_button_is_on(&self, number: usize) -> bool;
+}
+
 pub trait BatteryPushButtons {
     fn bat_is_auto(&self, number: usize) -> bool;
 }
@@ -72,7 +88,7 @@ pub struct Contactor {
 impl Contactor {
     pub fn new(context: &mut InitContext, id: &str) -> Contactor {
         Contactor {
-            identifier: context.next_electrical_identifier(),
+            identifier: context.next_electrical_identifier_for_contactor(id),
             closed_id: context.get_identifier(format!("ELEC_CONTACTOR_{}_IS_CLOSED", id)),
             closed: false,
         }
@@ -356,6 +372,12 @@ pub trait ElectricalElementIdentifierProvider {
         &mut self,
         bus_type: ElectricalBusType,
     ) -> ElectricalElementIdentifier;
+    fn next_electrical_identifier_for_contactor(&mut self, id: &str)
+        -> ElectricalElementIdentifier;
+
+    /// Provides an identifier for an element which is known by the given name
+    /// within the exported [ElectricalTopology].
+    fn next_named_electrical_identifier(&mut self, name: &str) -> ElectricalElementIdentifier;
 }
 
 #[derive(Debug)]
@@ -364,6 +386,7 @@ pub struct Electricity {
     buses: FxHashMap<ElectricalBusType, ElectricalElementIdentifier>,
     potential: PotentialCollection,
     none_potential: RefCell<Potential>,
+    topology: TopologyRecorder,
 }
 impl Electricity {
     pub fn new() -> Self {
@@ -372,11 +395,13 @@ impl Electricity {
             buses: Default::default(),
             potential: PotentialCollection::new(),
             none_potential: RefCell::new(Potential::none()),
+            topology: TopologyRecorder::default(),
         }
     }
 
     pub(super) fn pre_tick(&mut self) {
         self.potential.clear();
+        self.topology.clear();
     }
 
     /// Flows electricity from the given output element to the given input element as long
@@ -409,7 +434,14 @@ impl Electricity {
         from_output: &impl ElectricalElement,
         to_input: &impl ElectricalElement,
     ) {
-        if from_output.is_conductive() && to_input.is_conductive() {
+        let from_is_conductive = from_output.is_conductive();
+        let to_is_conductive = to_input.is_conductive();
+        self.topology.record_connection(
+            (from_output.output_identifier(), from_is_conductive),
+            (to_input.input_identifier(), to_is_conductive),
+        );
+
+        if from_is_conductive && to_is_conductive {
             self.potential
                 .flow(from_output.output_identifier(), to_input.input_identifier());
         }
@@ -438,9 +470,16 @@ impl Electricity {
     /// ```
     pub fn supplied_by(&mut self, source: &impl ElectricitySource) {
         let output_identifier = source.output_identifier();
+        let output_potential = source.output_potential();
+        self.topology.record_supply(
+            output_identifier,
+            source.is_conductive(),
+            output_potential.origins(),
+        );
+
         self.potential.supplied_by(
             output_identifier,
-            source.output_potential().include(output_identifier),
+            output_potential.include(output_identifier),
         )
     }
 
@@ -474,6 +513,12 @@ impl Electricity {
                 .include(output_identifier),
             None => Potential::none(),
         };
+        self.topology.record_transformation(
+            transformer.input_identifier(),
+            output_identifier,
+            transformer.is_conductive(),
+            transformed_potential.origins(),
+        );
 
         self.potential
             .supplied_by(output_identifier, transformed_potential);
@@ -505,6 +550,14 @@ impl Electricity {
             .unwrap_or_else(|| self.none_potential.borrow())
     }
 
+    /// Returns the network through which electricity flowed during the current tick,
+    /// including the potential origins and loads of its elements and the state of its contactors.
+    /// As consumption is only known at the end of a tick, loads are only complete once
+    /// the consumption has been [reported](Self::report_consumption_to).
+    pub fn topology(&self) -> ElectricalTopology {
+        self.topology.topology(&self.buses, &self.potential)
+    }
+
     pub fn distribute_to(&self, element: &mut impl SimulationElement, _: &UpdateContext) {
         let mut visitor = ReceivePowerVisitor::new(self);
         element.accept(&mut visitor);
@@ -549,6 +602,23 @@ impl ElectricalElementIdentifierProvider for Electricity {
 
         identifier
     }
+
+    fn next_electrical_identifier_for_contactor(
+        &mut self,
+        id: &str,
+    ) -> ElectricalElementIdentifier {
+        let identifier = self.next_electrical_identifier();
+        self.topology.name_contactor(identifier, id);
+
+        identifier
+    }
+
+    fn next_named_electrical_identifier(&mut self, name: &str) -> ElectricalElementIdentifier {
+        let identifier = self.next_electrical_identifier();
+        self.topology.name(identifier, name);
+
+        identifier
+    }
 }
 impl ElectricalBuses for Electricity {
     fn potential_of(&self, bus_type: ElectricalBusType) -> Ref<Potential> {
@@ -579,11 +649,14 @@ impl ConsumePower for Electricity {
     fn consume_from_input(&mut self, element: &impl ElectricalElement, power: Power) {
         self.potential
             .consume_from(element.input_identifier(), power);
+        self.topology
+            .record_consumption(element.input_identifier(), power);
     }
 
     fn consume_from_bus(&mut self, bus_type: ElectricalBusType, power: Power) {
         if let Some(identifier) = self.buses.get(&bus_type) {
             self.potential.consume_from(*identifier, power);
+            self.topology.record_consumption(*identifier, power);
         }
     }
 }
@@ -24,8 +24,8 @@ pub struct StaticInverter {
 impl StaticInverter {
     pub fn new(context: &mut InitContext) -> StaticInverter {
         StaticInverter {
-            input_identifier: context.next_electrical_identifier(),
-            output_identifier: context.next_electrical_identifier(),
+            input_identifier: context.next_named_electrical_identifier("STAT_INV"),
+            output_identifier: context.next_named_electrical_identifier("STAT_INV"),
             writer: ElectricalStateWriter::new(context, "STAT_INV"),
             output_potential: ElectricPotential::new::<volt>(0.),
             output_frequency: Frequency::new::<hertz>(0.),
@@ -0,0 +1,698 @@
+use std::fmt::{Display, Write};
+
+use fxhash::{FxHashMap, FxHashSet};
+use serde::{Serialize, Serializer};
+use uom::si::{electric_potential::volt, f64::*, power::watt};
+
+use super::{ElectricalElementIdentifier, PotentialCollection};
+use crate::shared::{ElectricalBusType, PotentialOrigin};
+
+/// Records how electricity flowed through the electrical elements during a simulation tick,
+/// such that the resulting network can be exported through an [ElectricalTopology].
+#[derive(Debug, Default)]
+pub(super) struct TopologyRecorder {
+    names: FxHashMap<ElectricalElementIdentifier, String>,
+    contactors: FxHashSet<ElectricalElementIdentifier>,
+    conductive: FxHashMap<ElectricalElementIdentifier, bool>,
+    supplies: FxHashMap<ElectricalElementIdentifier, Vec<PotentialOrigin>>,
+    transformers: FxHashMap<ElectricalElementIdentifier, ElectricalElementIdentifier>,
+    connections: Vec<(ElectricalElementIdentifier, ElectricalElementIdentifier)>,
+    consumption: FxHashMap<ElectricalElementIdentifier, Power>,
+}
+impl TopologyRecorder {
+    pub(super) fn name(&mut self, identifier: ElectricalElementIdentifier, name: &str) {
+        self.names.insert(identifier, name.to_owned());
+    }
+
+    pub(super) fn name_contactor(&mut self, identifier: ElectricalElementIdentifier, id: &str) {
+        self.name(identifier, id);
+        self.contactors.insert(identifier);
+    }
+
+    /// Forgets everything recorded during the previous tick. Names are retained,
+    /// as they are only provided when the elements are created.
+    pub(super) fn clear(&mut self) {
+        self.conductive.clear();
+        self.supplies.clear();
+        self.transformers.clear();
+        self.connections.clear();
+        self.consumption.clear();
+    }
+
+    pub(super) fn record_connection(
+        &mut self,
+        (from_output, from_is_conductive): (ElectricalElementIdentifier, bool),
+        (to_input, to_is_conductive): (ElectricalElementIdentifier, bool),
+    ) {
+        self.conductive.insert(from_output, from_is_conductive);
+        self.conductive.insert(to_input, to_is_conductive);
+        self.connections.push((from_output, to_input));
+    }
+
+    pub(super) fn record_supply<'a>(
+        &mut self,
+        output_identifier: ElectricalElementIdentifier,
+        is_conductive: bool,
+        origins: impl Iterator<Item = &'a PotentialOrigin>,
+    ) {
+        self.conductive.insert(output_identifier, is_conductive);
+        let mut origins: Vec<_> = origins.copied().collect();
+        origins.sort_by_key(|origin| origin.to_string());
+        self.supplies.insert(output_identifier, origins);
+    }
+
+    pub(super) fn record_transformation<'a>(
+        &mut self,
+        input_identifier: ElectricalElementIdentifier,
+        output_identifier: ElectricalElementIdentifier,
+        is_conductive: bool,
+        origins: impl Iterator<Item = &'a PotentialOrigin>,
+    ) {
+        self.transformers
+            .insert(input_identifier, output_identifier);
+        self.conductive.insert(input_identifier, is_conductive);
+        self.record_supply(output_identifier, is_conductive, origins);
+    }
+
+    pub(super) fn record_consumption(
+        &mut self,
+        identifier: ElectricalElementIdentifier,
+        power: Power,
+    ) {
+        *self.consumption.entry(identifier).or_default() += power;
+    }
+
+    pub(super) fn topology(
+        &self,
+        buses: &FxHashMap<ElectricalBusType, ElectricalElementIdentifier>,
+        potential: &PotentialCollection,
+    ) -> ElectricalTopology {
+        let bus_types: FxHashMap<_, _> = buses
+            .iter()
+            .map(|(bus_type, identifier)| (*identifier, *bus_type))
+            .collect();
+
+        // A transformer has separate input and output identifiers. Both are
+        // represented by a single element, which is identified by its output.
+        let node_of = |identifier: ElectricalElementIdentifier| {
+            self.transformers
+                .get(&identifier)
+                .copied()
+                .unwrap_or(identifier)
+        };
+
+        let mut identifiers: Vec<_> = self
+            .names
+            .keys()
+            .chain(bus_types.keys())
+            .chain(self.supplies.keys())
+            .chain(
+                self.connections
+                    .iter()
+                    .flat_map(|(from, to)| [from, to].into_iter()),
+            )
+            .map(|identifier| node_of(*identifier))
+            .collect::<FxHashSet<_>>()
+            .into_iter()
+            .collect();
+        identifiers.sort_by_key(|identifier| identifier.0);
+
+        let elements = identifiers
+            .into_iter()
+            .map(|identifier| {
+                let input_identifier = self
+                    .transformers
+                    .iter()
+                    .find(|(_, output)| **output == identifier)
+                    .map(|(input, _)| *input);
+
+                let kind = if bus_types.contains_key(&identifier) {
+                    ElectricalTopologyElementKind::Bus
+                } else if self.contactors.contains(&identifier) {
+                    ElectricalTopologyElementKind::Contactor
+                } else if input_identifier.is_some() {
+                    ElectricalTopologyElementKind::Transformer
+                } else if self.supplies.contains_key(&identifier) {
+                    ElectricalTopologyElementKind::Source
+                } else {
+                    ElectricalTopologyElementKind::Other
+                };
+
+                let name = match bus_types.get(&identifier) {
+                    Some(bus_type) => bus_type.to_string(),
+                    None => self
+                        .names
+                        .get(&identifier)
+                        .cloned()
+                        .unwrap_or_else(|| format!("{}_{}", kind, identifier.0)),
+                };
+
+                let mut origins: Vec<_> = potential
+                    .get(identifier)
+                    .map(|potential| potential.origins().copied().collect())
+                    .unwrap_or_default();
+                origins.sort_by_key(|origin| origin.to_string());
+
+                let consumption = [Some(identifier), input_identifier]
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|identifier| self.consumption.get(&identifier))
+                    .fold(Power::new::<watt>(0.), |total, power| total + *power);
+
+                ElectricalTopologyElement {
+                    id: identifier.0,
+                    kind,
+                    name,
+                    is_conductive: self
+                        .conductive
+                        .get(&identifier)
+                        .copied()
+                        .unwrap_or_default(),
+                    potential: potential
+                        .get(identifier)
+                        .map(|potential| potential.raw())
+                        .unwrap_or_default(),
+                    origins,
+                    consumption,
+                    supplied: self.supplies.get(&identifier).map(|origins| {
+                        origins
+                            .iter()
+                            .map(|origin| potential.total_consumption_of(*origin))
+                            .fold(Power::new::<watt>(0.), |total, power| total + power)
+                    }),
+                }
+            })
+            .collect();
+
+        let mut seen = FxHashSet::default();
+        let connections = self
+            .connections
+            .iter()
+            .map(|(from, to)| (node_of(*from), node_of(*to)))
+            .filter(|(from, to)| from != to && seen.insert((*from, *to)))
+            .map(|(from, to)| ElectricalTopologyConnection {
+                from: from.0,
+                to: to.0,
+                is_conductive: self.conductive.get(&from).copied().unwrap_or_default()
+                    && self.conductive.get(&to).copied().unwrap_or_default(),
+            })
+            .collect();
+
+        ElectricalTopology {
+            elements,
+            connections,
+        }
+    }
+}
+
+/// The kind of an element within the [ElectricalTopology].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ElectricalTopologyElementKind {
+    Source,
+    Transformer,
+    Bus,
+    Contactor,
+    Other,
+}
+impl Display for ElectricalTopologyElementKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ElectricalTopologyElementKind::Source => write!(f, "SOURCE"),
+            ElectricalTopologyElementKind::Transformer => write!(f, "TRANSFORMER"),
+            ElectricalTopologyElementKind::Bus => write!(f, "BUS"),
+            ElectricalTopologyElementKind::Contactor => write!(f, "CONTACTOR"),
+            ElectricalTopologyElementKind::Other => write!(f, "ELEMENT"),
+        }
+    }
+}
+
+/// An element within the [ElectricalTopology] together with the state it was in
+/// at the end of the simulation tick.
+#[derive(Debug, Serialize)]
+pub struct ElectricalTopologyElement {
+    id: u32,
+    kind: ElectricalTopologyElementKind,
+    name: String,
+    is_conductive: bool,
+    #[serde(rename = "potential_volts", serialize_with = "serialize_volts")]
+    potential: ElectricPotential,
+    #[serde(serialize_with = "serialize_origins")]
+    origins: Vec<PotentialOrigin>,
+    #[serde(rename = "consumption_watts", serialize_with = "serialize_watts")]
+    consumption: Power,
+    #[serde(rename = "supplied_watts", serialize_with = "serialize_optional_watts")]
+    supplied: Option<Power>,
+}
+impl ElectricalTopologyElement {
+    pub fn kind(&self) -> ElectricalTopologyElementKind {
+        self.kind
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns whether the element was conductive. For contactors this
+    /// indicates whether the contactor was closed.
+    pub fn is_conductive(&self) -> bool {
+        self.is_conductive
+    }
+
+    pub fn is_powered(&self) -> bool {
+        !self.origins.is_empty()
+    }
+
+    pub fn potential(&self) -> ElectricPotential {
+        self.potential
+    }
+
+    /// The origins of the potential which is present at the element.
+    pub fn origins(&self) -> &[PotentialOrigin] {
+        &self.origins
+    }
+
+    /// The power consumed directly from the element, such as by the power
+    /// consumers connected to a bus.
+    pub fn consumption(&self) -> Power {
+        self.consumption
+    }
+
+    /// The total power consumed from the potential supplied by the element. Only
+    /// sources and transformers supply potential.
+    pub fn supplied(&self) -> Option<Power> {
+        self.supplied
+    }
+
+    fn dot_label(&self) -> String {
+        let mut label = self.name.clone();
+        if self.kind == ElectricalTopologyElementKind::Contactor {
+            label += if self.is_conductive {
+                "\\nclosed"
+            } else {
+                "\\nopen"
+            };
+        }
+
+        if self.is_powered() {
+            let origins: Vec<_> = self
+                .origins
+                .iter()
+                .map(|origin| origin.to_string())
+                .collect();
+            let _ = write!(
+                label,
+                "\\n{}\\n{:.1} V",
+                origins.join(", "),
+                self.potential.get::<volt>()
+            );
+        } else {
+            label += "\\nunpowered";
+        }
+
+        if self.consumption > Power::new::<watt>(0.) {
+            let _ = write!(label, "\\nconsumes {:.0} W", self.consumption.get::<watt>());
+        }
+
+        if let Some(supplied) = self.supplied {
+            let _ = write!(label, "\\nsupplies {:.0} W", supplied.get::<watt>());
+        }
+
+        label
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ElectricalTopologyConnection {
+    from: u32,
+    to: u32,
+    is_conductive: bool,
+}
+impl ElectricalTopologyConnection {
+    /// Returns whether electricity could flow through the connection, which requires
+    /// the elements on both ends of it to be conductive.
+    pub fn is_conductive(&self) -> bool {
+        self.is_conductive
+    }
+}
+
+/// The network of sources, transformers, buses and contactors through which electricity
+/// flowed during a simulation tick, including the potential origins and loads of every element
+/// and the state of every contactor. Obtain it through [Electricity::topology](super::Electricity::topology)
+/// and export it using [to_dot](Self::to_dot) or [to_json](Self::to_json).
+#[derive(Debug, Serialize)]
+pub struct ElectricalTopology {
+    elements: Vec<ElectricalTopologyElement>,
+    connections: Vec<ElectricalTopologyConnection>,
+}
+impl ElectricalTopology {
+    pub fn elements(&self) -> &[ElectricalTopologyElement] {
+        &self.elements
+    }
+
+    pub fn element(&self, name: &str) -> Option<&ElectricalTopologyElement> {
+        self.elements.iter().find(|element| element.name == name)
+    }
+
+    pub fn connections(&self) -> &[ElectricalTopologyConnection] {
+        &self.connections
+    }
+
+    /// Returns the connection between the elements with the given names, regardless
+    /// of the direction in which electricity flowed through it.
+    pub fn connection(&self, left: &str, right: &str) -> Option<&ElectricalTopologyConnection> {
+        let left = self.element(left)?.id;
+        let right = self.element(right)?.id;
+
+        self.connections.iter().find(|connection| {
+            (connection.from == left && connection.to == right)
+                || (connection.from == right && connection.to == left)
+        })
+    }
+
+    /// Exports the topology in the Graphviz DOT language. Powered elements are filled green,
+    /// and connections which cannot conduct electricity are dashed.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph electrical {\n    rankdir=LR;\n");
+        for element in &self.elements {
+            let shape = match element.kind {
+                ElectricalTopologyElementKind::Source => "doublecircle",
+                ElectricalTopologyElementKind::Transformer => "trapezium",
+                ElectricalTopologyElementKind::Bus => "box",
+                ElectricalTopologyElementKind::Contactor => "circle",
+                ElectricalTopologyElementKind::Other => "ellipse",
+            };
+            let color = if element.is_powered() {
+                "palegreen"
+            } else {
+                "lightgrey"
+            };
+
+            let _ = writeln!(
+                dot,
+                "    e{} [label=\"{}\", shape={}, style=filled, fillcolor={}];",
+                element.id,
+                element.dot_label().replace('"', "\\\""),
+                shape,
+                color
+            );
+        }
+
+        for connection in &self.connections {
+            let style = if connection.is_conductive {
+                "solid"
+            } else {
+                "dashed"
+            };
+
+            let _ = writeln!(
+                dot,
+                "    e{} -> e{} [style={}];",
+                connection.from, connection.to, style
+            );
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Exports the topology as JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("electrical topology can always be serialized")
+    }
+}
+
+fn serialize_volts<S: Serializer>(
+    value: &ElectricPotential,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_f64(value.get::<volt>())
+}
+
+fn serialize_watts<S: Serializer>(value: &Power, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_f64(value.get::<watt>())
+}
+
+fn serialize_optional_watts<S: Serializer>(
+    value: &Option<Power>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match value {
+        Some(power) => serialize_watts(power, serializer),
+        None => serializer.serialize_none(),
+    }
+}
+
+fn serialize_origins<S: Serializer>(
+    origins: &[PotentialOrigin],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(origins.iter().map(|origin| origin.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        electrical::{
+            consumption::PowerConsumer, test::TestElectricitySource, Contactor, ElectricalBus,
+            Electricity, TransformerRectifier,
+        },
+        simulation::{
+            test::{SimulationTestBed, TestBed},
+            Aircraft, InitContext, SimulationElement, SimulationElementVisitor, UpdateContext,
+        },
+    };
+
+    struct TestAircraft {
+        external_power: TestElectricitySource,
+        contactor: Contactor,
+        ac_bus: ElectricalBus,
+        transformer_rectifier: TransformerRectifier,
+        dc_bus: ElectricalBus,
+        consumer: PowerConsumer,
+        contactor_is_closed: bool,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            let mut consumer = PowerConsumer::from(ElectricalBusType::DirectCurrent(1));
+            consumer.demand(Power::new::<watt>(1000.));
+
+            Self {
+                external_power: TestElectricitySource::powered(context, PotentialOrigin::External),
+                contactor: Contactor::new(context, "TEST"),
+                ac_bus: ElectricalBus::new(context, ElectricalBusType::AlternatingCurrent(1)),
+                transformer_rectifier: TransformerRectifier::new(context, 1),
+                dc_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrent(1)),
+                consumer,
+                contactor_is_closed: true,
+            }
+        }
+
+        fn open_contactor(&mut self) {
+            self.contactor_is_closed = false;
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            self.contactor.close_when(self.contactor_is_closed);
+
+            electricity.supplied_by(&self.external_power);
+            electricity.flow(&self.external_power, &self.contactor);
+            electricity.flow(&self.contactor, &self.ac_bus);
+            electricity.flow(&self.ac_bus, &self.transformer_rectifier);
+            electricity.transform_in(&self.transformer_rectifier);
+            electricity.flow(&self.transformer_rectifier, &self.dc_bus);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.contactor.accept(visitor);
+            self.ac_bus.accept(visitor);
+            self.transformer_rectifier.accept(visitor);
+            self.dc_bus.accept(visitor);
+            self.consumer.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed() -> SimulationTestBed<TestAircraft> {
+        SimulationTestBed::new(TestAircraft::new)
+    }
+
+    fn topology(test_bed: &SimulationTestBed<TestAircraft>) -> ElectricalTopology {
+        test_bed.query_elec(|_, electricity| electricity.topology())
+    }
+
+    #[test]
+    fn elements_are_named_after_their_identifiers() {
+        let mut test_bed = test_bed();
+        test_bed.run();
+
+        let topology = topology(&test_bed);
+
+        assert_eq!(
+            topology.element("TEST").unwrap().kind(),
+            ElectricalTopologyElementKind::Contactor
+        );
+        assert_eq!(
+            topology.element("AC_1").unwrap().kind(),
+            ElectricalTopologyElementKind::Bus
+        );
+        assert_eq!(
+            topology.element("TR_1").unwrap().kind(),
+            ElectricalTopologyElementKind::Transformer
+        );
+    }
+
+    #[test]
+    fn unnamed_source_is_named_after_its_kind() {
+        let mut test_bed = test_bed();
+        test_bed.run();
+
+        let topology = topology(&test_bed);
+        let source = topology
+            .elements()
+            .iter()
+            .find(|element| element.kind() == ElectricalTopologyElementKind::Source)
+            .unwrap();
+
+        assert!(source.name().starts_with("SOURCE_"));
+        assert_eq!(source.origins(), &[PotentialOrigin::External]);
+    }
+
+    #[test]
+    fn closed_contactor_is_conductive_and_powers_downstream_bus() {
+        let mut test_bed = test_bed();
+        test_bed.run();
+
+        let topology = topology(&test_bed);
+
+        assert!(topology.element("TEST").unwrap().is_conductive());
+        assert!(topology.connection("TEST", "AC_1").unwrap().is_conductive());
+        assert_eq!(
+            topology.element("AC_1").unwrap().origins(),
+            &[PotentialOrigin::External]
+        );
+    }
+
+    #[test]
+    fn open_contactor_is_not_conductive_and_leaves_downstream_bus_unpowered() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.open_contactor());
+        test_bed.run();
+
+        let topology = topology(&test_bed);
+
+        assert!(!topology.element("TEST").unwrap().is_conductive());
+        assert!(!topology.connection("TEST", "AC_1").unwrap().is_conductive());
+        assert!(!topology.element("AC_1").unwrap().is_powered());
+        assert!(!topology.element("DC_1").unwrap().is_powered());
+    }
+
+    #[test]
+    fn transformer_is_a_single_element_between_its_input_and_output_buses() {
+        let mut test_bed = test_bed();
+        test_bed.run();
+
+        let topology = topology(&test_bed);
+
+        assert_eq!(
+            topology
+                .elements()
+                .iter()
+                .filter(|element| element.name() == "TR_1")
+                .count(),
+            1
+        );
+        assert!(topology.connection("AC_1", "TR_1").is_some());
+        assert!(topology.connection("TR_1", "DC_1").is_some());
+        assert_eq!(
+            topology.element("DC_1").unwrap().origins(),
+            &[PotentialOrigin::TransformerRectifier(1)]
+        );
+    }
+
+    #[test]
+    fn bus_consumption_includes_consumers_on_the_bus() {
+        let mut test_bed = test_bed();
+        test_bed.run();
+
+        let topology = topology(&test_bed);
+
+        assert_eq!(
+            topology.element("DC_1").unwrap().consumption(),
+            Power::new::<watt>(1000.)
+        );
+        assert_eq!(
+            topology.element("AC_1").unwrap().consumption(),
+            Power::new::<watt>(0.)
+        );
+    }
+
+    #[test]
+    fn transformer_supplies_the_power_consumed_downstream() {
+        let mut test_bed = test_bed();
+        test_bed.run();
+
+        let topology = topology(&test_bed);
+        let transformer_rectifier = topology.element("TR_1").unwrap();
+
+        assert_eq!(
+            transformer_rectifier.supplied(),
+            Some(Power::new::<watt>(1000.))
+        );
+        assert!(transformer_rectifier.consumption() > Power::new::<watt>(1000.));
+    }
+
+    #[test]
+    fn buses_and_contactors_do_not_supply_power() {
+        let mut test_bed = test_bed();
+        test_bed.run();
+
+        let topology = topology(&test_bed);
+
+        assert_eq!(topology.element("AC_1").unwrap().supplied(), None);
+        assert_eq!(topology.element("TEST").unwrap().supplied(), None);
+    }
+
+    #[test]
+    fn dot_export_contains_elements_and_connections() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.open_contactor());
+        test_bed.run();
+
+        let topology = topology(&test_bed);
+        let dot = topology.to_dot();
+        let contactor = topology.element("TEST").unwrap().id;
+        let ac_bus = topology.element("AC_1").unwrap().id;
+
+        assert!(dot.starts_with("digraph electrical {"));
+        assert!(dot.contains("label=\"TEST\\nopen\\nunpowered\", shape=circle"));
+        assert!(dot.contains(&format!("e{} -> e{} [style=dashed];", contactor, ac_bus)));
+    }
+
+    #[test]
+    fn json_export_contains_elements_and_connections() {
+        let mut test_bed = test_bed();
+        test_bed.run();
+
+        let json: serde_json::Value = serde_json::from_str(&topology(&test_bed).to_json()).unwrap();
+        let ac_bus = json["elements"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|element| element["name"] == "AC_1")
+            .unwrap();
+
+        assert_eq!(ac_bus["kind"], "bus");
+        assert_eq!(ac_bus["origins"], serde_json::json!(["ExternalPower"]));
+        assert_eq!(ac_bus["supplied_watts"], serde_json::Value::Null);
+        assert!(!json["connections"].as_array().unwrap().is_empty());
+    }
+}
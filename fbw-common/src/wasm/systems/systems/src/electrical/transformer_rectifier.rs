@@ -34,8 +34,8 @@ impl TransformerRectifier {
         TransformerRectifier {
             writer: ElectricalStateWriter::new(context, &format!("TR_{}", number)),
             number,
-            input_identifier: context.next_electrical_identifier(),
-            output_identifier: context.next_electrical_identifier(),
+            input_identifier: context.next_named_electrical_identifier(&format!("TR_{}", number)),
+            output_identifier: context.next_named_electrical_identifier(&format!("TR_{}", number)),
             failure: Failure::new(FailureType::TransformerRectifier(number)),
             output_potential: ElectricPotential::new::<volt>(0.),
             output_current: ElectricCurrent::new::<ampere>(0.),
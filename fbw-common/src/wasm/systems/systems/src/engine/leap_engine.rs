@@ -1,17 +1,23 @@
-use uom::si::{angular_velocity::revolution_per_minute, f64::*, pressure::psi, ratio::percent};
+use std::time::Duration;
+
+use uom::si::{
+    angular_velocity::revolution_per_minute, f64::*, mass_rate::kilogram_per_hour, pressure::psi,
+    ratio::percent,
+};
 
 use crate::{
-    shared::{EngineCorrectedN1, EngineCorrectedN2, EngineUncorrectedN2},
+    shared::{EngineCorrectedN1, EngineCorrectedN2, EngineFuelFlow, EngineUncorrectedN2},
     simulation::{Read, SimulationElement, SimulatorReader, UpdateContext},
 };
 
-use super::Engine;
+use super::{spool_engine::SpoolEngineConstants, Engine};
 use crate::simulation::{InitContext, VariableIdentifier};
 
 pub struct LeapEngine {
     corrected_n1_id: VariableIdentifier,
     corrected_n2_id: VariableIdentifier,
     thrust_id: VariableIdentifier,
+    fuel_flow_id: VariableIdentifier,
 
     corrected_n1: Ratio,
     corrected_n2: Ratio,
@@ -24,6 +30,7 @@ pub struct LeapEngine {
     oil_pressure: Pressure,
 
     net_thrust: Mass,
+    fuel_flow: MassRate,
 }
 impl LeapEngine {
     // According to the Type Certificate Data Sheet of LEAP 1A26
@@ -43,6 +50,7 @@ impl LeapEngine {
             corrected_n2_id: context.get_identifier(format!("TURB ENG CORRECTED N2:{}", number)),
             uncorrected_n2_id: context.get_identifier(format!("ENGINE_N2:{}", number)),
             thrust_id: context.get_identifier(format!("TURB ENG JET THRUST:{}", number)),
+            fuel_flow_id: context.get_identifier(format!("ENGINE_FF:{}", number)),
 
             corrected_n1: Ratio::new::<percent>(0.),
             corrected_n2: Ratio::new::<percent>(0.),
@@ -53,6 +61,7 @@ impl LeapEngine {
             oil_pressure: Pressure::new::<psi>(0.),
 
             net_thrust: Mass::default(),
+            fuel_flow: MassRate::default(),
         }
     }
 
@@ -74,6 +83,9 @@ impl SimulationElement for LeapEngine {
         self.corrected_n2 = reader.read(&self.corrected_n2_id);
         self.uncorrected_n2 = reader.read(&self.uncorrected_n2_id);
         self.net_thrust = reader.read(&self.thrust_id);
+
+        let fuel_flow_kg_per_hour: f64 = reader.read(&self.fuel_flow_id);
+        self.fuel_flow = MassRate::new::<kilogram_per_hour>(fuel_flow_kg_per_hour);
         self.update_parameters();
     }
 }
@@ -92,6 +104,11 @@ impl EngineUncorrectedN2 for LeapEngine {
         self.uncorrected_n2
     }
 }
+impl EngineFuelFlow for LeapEngine {
+    fn fuel_flow(&self) -> MassRate {
+        self.fuel_flow
+    }
+}
 impl Engine for LeapEngine {
     fn hydraulic_pump_output_speed(&self) -> AngularVelocity {
         self.hydraulic_pump_output_speed
@@ -114,3 +131,43 @@ impl Engine for LeapEngine {
         self.n2_speed
     }
 }
+
+/// LEAP 1A26 values for the [`super::spool_engine::SpoolEngine`] model.
+pub struct Leap1A26Constants;
+impl SpoolEngineConstants for Leap1A26Constants {
+    const CORE_SPEED_100_PCT_RPM: f64 = LeapEngine::LEAP_1A26_MAX_N2_RPM;
+    const PUMP_GEAR_RATIO: f64 = LeapEngine::PUMP_N2_GEAR_RATIO;
+
+    const MIN_IDLE_N2_UNCORRECTED_THRESHOLD_PERCENT: f64 =
+        LeapEngine::MIN_IDLE_N2_UNCORRECTED_THRESHOLD_PERCENT;
+    const LOW_OIL_PRESSURE_THRESHOLD_PSI: f64 = LeapEngine::LOW_OIL_PRESSURE_THRESHOLD_PSI;
+
+    const LIGHT_OFF_CORE_SPEED_PERCENT: f64 = 16.;
+    const FLAME_OUT_CORE_SPEED_PERCENT: f64 = 10.;
+    const SELF_SUSTAINING_CORE_SPEED_PERCENT: f64 = 45.;
+    const STARTER_CUT_OUT_CORE_SPEED_PERCENT: f64 = 55.;
+    const STARTER_ACCELERATION_PERCENT_PER_SECOND: f64 = 2.5;
+    const STARTER_NOMINAL_PRESSURE_PSIG: f64 = 30.;
+    const COMBUSTION_ACCELERATION_PERCENT_PER_SECOND: f64 = 1.5;
+    const ROTOR_DRAG_PER_SECOND: f64 = 0.04;
+    const WINDMILLING_CORE_SPEED_PERCENT_PER_KNOT: f64 = 0.06;
+    const GOVERNED_SPOOL_TIME_CONSTANT: Duration = Duration::from_secs(3);
+
+    // Max reverse, reverse idle, forward idle, CL, FLX/MCT, TOGA
+    const THRUST_LEVER_ANGLE_BREAKPOINTS: &'static [f64] = &[-20., -6., 0., 25., 35., 45.];
+    const TARGET_CORE_SPEED_PERCENT: &'static [f64] = &[90., 59., 59., 92., 95., 98.];
+
+    const CORE_SPEED_BREAKPOINTS: &'static [f64] = &[0., 16., 45., 59., 100., 110.];
+    const N1_PERCENT: &'static [f64] = &[0., 3., 12., 19.5, 97., 104.];
+    const N2_PERCENT: &'static [f64] = &[0., 16., 45., 59., 100., 110.];
+    const EGT_RISE_DEGREE_CELSIUS: &'static [f64] = &[0., 300., 600., 500., 870., 920.];
+    const FUEL_FLOW_KG_PER_HOUR: &'static [f64] = &[0., 100., 220., 300., 3200., 3500.];
+    const OIL_PRESSURE_PSI: &'static [f64] = &[0., 12., 30., 38., 70., 72.];
+    const OIL_TEMPERATURE_RISE_DEGREE_CELSIUS: &'static [f64] = &[0., 5., 30., 50., 90., 95.];
+
+    // Rated takeoff thrust is 26,600 lbf at an N1 slightly below 100%
+    const MAX_THRUST_LBS: f64 = 27_100.;
+
+    const OIL_TANK_QUANTITY_QUARTS: f64 = 17.;
+    const OIL_GULPING_QUARTS: f64 = 4.;
+}
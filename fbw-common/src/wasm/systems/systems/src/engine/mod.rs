@@ -1,17 +1,23 @@
 use uom::si::f64::*;
 
-use crate::simulation::InitContext;
+use crate::simulation::{InitContext, UpdateContext};
 use crate::{
     overhead::FirePushButton,
-    shared::{EngineCorrectedN1, EngineCorrectedN2, EngineFirePushButtons, EngineUncorrectedN2},
+    shared::{
+        EngineCorrectedN1, EngineCorrectedN2, EngineFirePushButtons, EngineFuelFlow,
+        EngineUncorrectedN2,
+    },
     simulation::{SimulationElement, SimulationElementVisitor},
 };
 
 pub mod leap_engine;
 pub mod reverser;
 pub mod reverser_thrust;
+pub mod spool_engine;
 pub mod trent_engine;
 
+use spool_engine::{SpoolEngine, SpoolEngineConstants};
+
 pub trait Engine: EngineCorrectedN2 + EngineUncorrectedN2 + EngineCorrectedN1 {
     fn hydraulic_pump_output_speed(&self) -> AngularVelocity;
     fn oil_pressure_is_low(&self) -> bool;
@@ -20,6 +26,96 @@ pub trait Engine: EngineCorrectedN2 + EngineUncorrectedN2 + EngineCorrectedN1 {
     fn gearbox_speed(&self) -> AngularVelocity;
 }
 
+/// An engine whose parameters are either passed through from the simulator or computed by the
+/// native [`SpoolEngine`] model. Each aircraft selects the model when constructing its engines.
+pub enum SelectableEngine<T: Engine + SimulationElement, C: SpoolEngineConstants> {
+    SimulatorVariables(T),
+    Spool(Box<SpoolEngine<C>>),
+}
+impl<T: Engine + SimulationElement, C: SpoolEngineConstants> SelectableEngine<T, C> {
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        starter_air_pressure: Pressure,
+        fuel_is_available: bool,
+    ) {
+        if let SelectableEngine::Spool(engine) = self {
+            engine.update(context, starter_air_pressure, fuel_is_available);
+        }
+    }
+
+    fn engine(&self) -> &dyn Engine {
+        match self {
+            SelectableEngine::SimulatorVariables(engine) => engine,
+            SelectableEngine::Spool(engine) => engine.as_ref(),
+        }
+    }
+}
+impl<T: Engine + SimulationElement, C: SpoolEngineConstants> EngineCorrectedN1
+    for SelectableEngine<T, C>
+{
+    fn corrected_n1(&self) -> Ratio {
+        self.engine().corrected_n1()
+    }
+}
+impl<T: Engine + SimulationElement, C: SpoolEngineConstants> EngineCorrectedN2
+    for SelectableEngine<T, C>
+{
+    fn corrected_n2(&self) -> Ratio {
+        self.engine().corrected_n2()
+    }
+}
+impl<T: Engine + SimulationElement, C: SpoolEngineConstants> EngineUncorrectedN2
+    for SelectableEngine<T, C>
+{
+    fn uncorrected_n2(&self) -> Ratio {
+        self.engine().uncorrected_n2()
+    }
+}
+impl<T: Engine + SimulationElement, C: SpoolEngineConstants> Engine for SelectableEngine<T, C> {
+    fn hydraulic_pump_output_speed(&self) -> AngularVelocity {
+        self.engine().hydraulic_pump_output_speed()
+    }
+
+    fn oil_pressure_is_low(&self) -> bool {
+        self.engine().oil_pressure_is_low()
+    }
+
+    fn is_above_minimum_idle(&self) -> bool {
+        self.engine().is_above_minimum_idle()
+    }
+
+    fn net_thrust(&self) -> Mass {
+        self.engine().net_thrust()
+    }
+
+    fn gearbox_speed(&self) -> AngularVelocity {
+        self.engine().gearbox_speed()
+    }
+}
+impl<T: Engine + EngineFuelFlow + SimulationElement, C: SpoolEngineConstants> EngineFuelFlow
+    for SelectableEngine<T, C>
+{
+    fn fuel_flow(&self) -> MassRate {
+        match self {
+            SelectableEngine::SimulatorVariables(engine) => engine.fuel_flow(),
+            SelectableEngine::Spool(engine) => EngineFuelFlow::fuel_flow(engine.as_ref()),
+        }
+    }
+}
+impl<T: Engine + SimulationElement, C: SpoolEngineConstants> SimulationElement
+    for SelectableEngine<T, C>
+{
+    fn accept<U: SimulationElementVisitor>(&mut self, visitor: &mut U) {
+        match self {
+            SelectableEngine::SimulatorVariables(engine) => engine.accept(visitor),
+            SelectableEngine::Spool(engine) => engine.accept(visitor),
+        }
+
+        visitor.visit(self);
+    }
+}
+
 use std::convert::TryInto;
 pub struct EngineFireOverheadPanel<const N: usize> {
     engine_fire_push_buttons: [FirePushButton; N],
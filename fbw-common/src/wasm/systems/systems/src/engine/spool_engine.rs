@@ -0,0 +1,833 @@
+use std::time::Duration;
+
+use uom::si::{
+    angle::degree,
+    angular_velocity::revolution_per_minute,
+    f64::*,
+    mass::pound,
+    mass_rate::kilogram_per_hour,
+    pressure::{hectopascal, psi},
+    ratio::{percent, ratio},
+    thermodynamic_temperature::{degree_celsius, kelvin},
+    velocity::knot,
+    volume::quart_liquid,
+};
+
+use crate::{
+    shared::{
+        interpolation, low_pass_filter::LowPassFilter, update_iterator::MaxStepLoop,
+        EngineCorrectedN1, EngineCorrectedN2, EngineFuelFlow, EngineUncorrectedN2,
+    },
+    simulation::{
+        InitContext, Read, SimulationElement, SimulatorReader, SimulatorWriter, UpdateContext,
+        VariableIdentifier, Write,
+    },
+};
+
+use super::Engine;
+
+/// Engine type specific values used by the [`SpoolEngine`] model.
+///
+/// The model integrates the speed of the high pressure (core) spool, which drives the accessory
+/// gearbox. All other rotor speeds and engine parameters are derived from it using the
+/// breakpoint tables below, which are indexed by [`SpoolEngineConstants::CORE_SPEED_BREAKPOINTS`].
+pub trait SpoolEngineConstants {
+    /// Core spool speed at 100%.
+    const CORE_SPEED_100_PCT_RPM: f64;
+    /// Gear ratio from the accessory gearbox to the engine driven hydraulic pump.
+    const PUMP_GEAR_RATIO: f64;
+
+    const MIN_IDLE_N2_UNCORRECTED_THRESHOLD_PERCENT: f64;
+    const LOW_OIL_PRESSURE_THRESHOLD_PSI: f64;
+
+    /// Core speed at which fuel admitted to the combustor ignites.
+    const LIGHT_OFF_CORE_SPEED_PERCENT: f64;
+    /// Core speed below which combustion cannot be sustained.
+    const FLAME_OUT_CORE_SPEED_PERCENT: f64;
+    /// Core speed above which the engine accelerates to idle without starter assistance.
+    const SELF_SUSTAINING_CORE_SPEED_PERCENT: f64;
+    /// Core speed above which the air turbine starter no longer provides torque.
+    const STARTER_CUT_OUT_CORE_SPEED_PERCENT: f64;
+    /// Starter acceleration of a stationary core with nominal starter air pressure.
+    const STARTER_ACCELERATION_PERCENT_PER_SECOND: f64;
+    const STARTER_NOMINAL_PRESSURE_PSIG: f64;
+    /// Acceleration provided by combustion at self-sustaining speed.
+    const COMBUSTION_ACCELERATION_PERCENT_PER_SECOND: f64;
+    /// Deceleration of the core per percent of core speed above the windmilling speed.
+    const ROTOR_DRAG_PER_SECOND: f64;
+    const WINDMILLING_CORE_SPEED_PERCENT_PER_KNOT: f64;
+    /// Time constant of the FADEC governed acceleration above self-sustaining speed.
+    const GOVERNED_SPOOL_TIME_CONSTANT: Duration;
+
+    const THRUST_LEVER_ANGLE_BREAKPOINTS: &'static [f64];
+    const TARGET_CORE_SPEED_PERCENT: &'static [f64];
+
+    const CORE_SPEED_BREAKPOINTS: &'static [f64];
+    const N1_PERCENT: &'static [f64];
+    /// Speed of the spool reported as N2. For two spool engines this is the core itself.
+    const N2_PERCENT: &'static [f64];
+    const EGT_RISE_DEGREE_CELSIUS: &'static [f64];
+    const FUEL_FLOW_KG_PER_HOUR: &'static [f64];
+    const OIL_PRESSURE_PSI: &'static [f64];
+    const OIL_TEMPERATURE_RISE_DEGREE_CELSIUS: &'static [f64];
+
+    const MAX_THRUST_LBS: f64;
+
+    const OIL_TANK_QUANTITY_QUARTS: f64;
+    /// Oil held in the sumps and lines at 100% core speed, which is missing from the tank.
+    const OIL_GULPING_QUARTS: f64;
+}
+
+/// A native engine model which integrates the core spool speed from the starter, combustion and
+/// windmilling torques instead of reading the rotor speeds from the simulator.
+pub struct SpoolEngine<C: SpoolEngineConstants> {
+    combustion_id: VariableIdentifier,
+    fuel_valve_open_id: VariableIdentifier,
+    thrust_lever_angle_id: VariableIdentifier,
+
+    n1_id: VariableIdentifier,
+    n2_id: VariableIdentifier,
+    egt_id: VariableIdentifier,
+    fuel_flow_id: VariableIdentifier,
+    oil_pressure_id: VariableIdentifier,
+    oil_temperature_id: VariableIdentifier,
+    oil_quantity_id: VariableIdentifier,
+    is_burning_id: VariableIdentifier,
+
+    physics_updater: MaxStepLoop,
+    is_initialised: bool,
+    simulator_combustion: bool,
+    fuel_valve_is_open: bool,
+    thrust_lever_angle: Angle,
+
+    core_speed: Ratio,
+    is_burning: bool,
+
+    corrected_n1: Ratio,
+    corrected_n2: Ratio,
+    uncorrected_n1: Ratio,
+    uncorrected_n2: Ratio,
+
+    egt: LowPassFilter<f64>,
+    fuel_flow: MassRate,
+    oil_pressure: LowPassFilter<f64>,
+    oil_temperature: LowPassFilter<f64>,
+    oil_quantity: Volume,
+
+    net_thrust: Mass,
+
+    constants: std::marker::PhantomData<C>,
+}
+impl<C: SpoolEngineConstants> SpoolEngine<C> {
+    const MAX_PHYSICS_STEP: Duration = Duration::from_millis(50);
+
+    const EGT_TIME_CONSTANT: Duration = Duration::from_secs(4);
+    const OIL_PRESSURE_TIME_CONSTANT: Duration = Duration::from_secs(1);
+    const OIL_TEMPERATURE_TIME_CONSTANT: Duration = Duration::from_secs(180);
+
+    const STANDARD_TEMPERATURE_KELVIN: f64 = 288.15;
+    const STANDARD_PRESSURE_HPA: f64 = 1013.25;
+
+    pub fn new(context: &mut InitContext, number: usize) -> Self {
+        Self {
+            combustion_id: context.get_identifier(format!("GENERAL ENG COMBUSTION:{}", number)),
+            fuel_valve_open_id: context
+                .get_identifier(format!("GENERAL ENG FUEL VALVE:{}", number)),
+            thrust_lever_angle_id: context.get_identifier(format!("AUTOTHRUST_TLA:{}", number)),
+
+            n1_id: context.get_identifier(format!("SPOOL_ENGINE_{}_N1", number)),
+            n2_id: context.get_identifier(format!("SPOOL_ENGINE_{}_N2", number)),
+            egt_id: context.get_identifier(format!("SPOOL_ENGINE_{}_EGT", number)),
+            fuel_flow_id: context.get_identifier(format!("SPOOL_ENGINE_{}_FUEL_FLOW", number)),
+            oil_pressure_id: context
+                .get_identifier(format!("SPOOL_ENGINE_{}_OIL_PRESSURE", number)),
+            oil_temperature_id: context
+                .get_identifier(format!("SPOOL_ENGINE_{}_OIL_TEMPERATURE", number)),
+            oil_quantity_id: context
+                .get_identifier(format!("SPOOL_ENGINE_{}_OIL_QUANTITY", number)),
+            is_burning_id: context.get_identifier(format!("SPOOL_ENGINE_{}_IS_BURNING", number)),
+
+            physics_updater: MaxStepLoop::new(Self::MAX_PHYSICS_STEP),
+            is_initialised: false,
+            simulator_combustion: false,
+            fuel_valve_is_open: false,
+            thrust_lever_angle: Angle::default(),
+
+            core_speed: Ratio::default(),
+            is_burning: false,
+
+            corrected_n1: Ratio::default(),
+            corrected_n2: Ratio::default(),
+            uncorrected_n1: Ratio::default(),
+            uncorrected_n2: Ratio::default(),
+
+            egt: LowPassFilter::new(Self::EGT_TIME_CONSTANT),
+            fuel_flow: MassRate::default(),
+            oil_pressure: LowPassFilter::new(Self::OIL_PRESSURE_TIME_CONSTANT),
+            oil_temperature: LowPassFilter::new(Self::OIL_TEMPERATURE_TIME_CONSTANT),
+            oil_quantity: Volume::new::<quart_liquid>(C::OIL_TANK_QUANTITY_QUARTS),
+
+            net_thrust: Mass::default(),
+
+            constants: std::marker::PhantomData,
+        }
+    }
+
+    /// Updates the spool model.
+    ///
+    /// `starter_air_pressure` is the absolute pressure delivered to the air turbine starter and
+    /// `fuel_is_available` indicates whether the fuel system can feed the engine.
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        starter_air_pressure: Pressure,
+        fuel_is_available: bool,
+    ) {
+        if !self.is_initialised {
+            self.initialise(context);
+        }
+
+        self.physics_updater.update(context);
+        for cur_time_step in self.physics_updater {
+            self.update_spool(
+                &context.with_delta(cur_time_step),
+                starter_air_pressure,
+                fuel_is_available,
+            );
+        }
+
+        self.update_parameters(context);
+    }
+
+    /// When the simulation starts with the engines running, the model starts at the speed
+    /// commanded by the thrust lever instead of spooling up from standstill.
+    fn initialise(&mut self, context: &UpdateContext) {
+        self.is_initialised = true;
+
+        if self.simulator_combustion {
+            self.is_burning = true;
+            self.core_speed = self.target_core_speed();
+
+            let ambient = context.ambient_temperature().get::<degree_celsius>();
+            let core_speed = self.core_speed.get::<percent>();
+            self.egt
+                .reset(ambient + Self::table(C::EGT_RISE_DEGREE_CELSIUS, core_speed));
+            self.oil_pressure
+                .reset(Self::table(C::OIL_PRESSURE_PSI, core_speed));
+            self.oil_temperature
+                .reset(ambient + Self::table(C::OIL_TEMPERATURE_RISE_DEGREE_CELSIUS, core_speed));
+        } else {
+            let ambient = context.ambient_temperature().get::<degree_celsius>();
+            self.egt.reset(ambient);
+            self.oil_temperature.reset(ambient);
+        }
+    }
+
+    fn update_spool(
+        &mut self,
+        context: &UpdateContext,
+        starter_air_pressure: Pressure,
+        fuel_is_available: bool,
+    ) {
+        let core_speed = self.core_speed.get::<percent>();
+        let fuel_is_flowing = self.fuel_valve_is_open && fuel_is_available;
+
+        if !fuel_is_flowing || core_speed < C::FLAME_OUT_CORE_SPEED_PERCENT {
+            self.is_burning = false;
+        } else if core_speed >= C::LIGHT_OFF_CORE_SPEED_PERCENT {
+            self.is_burning = true;
+        }
+
+        let acceleration = if self.is_burning && core_speed >= C::SELF_SUSTAINING_CORE_SPEED_PERCENT
+        {
+            (self.target_core_speed().get::<percent>() - core_speed)
+                / C::GOVERNED_SPOOL_TIME_CONSTANT.as_secs_f64()
+        } else {
+            let combustion = if self.is_burning {
+                C::COMBUSTION_ACCELERATION_PERCENT_PER_SECOND * core_speed
+                    / C::SELF_SUSTAINING_CORE_SPEED_PERCENT
+            } else {
+                0.
+            };
+
+            self.starter_acceleration(context, starter_air_pressure) + combustion
+                - self.drag_deceleration(context)
+        };
+
+        self.core_speed = Ratio::new::<percent>(
+            (core_speed + acceleration * context.delta_as_secs_f64()).max(0.),
+        );
+    }
+
+    fn starter_acceleration(&self, context: &UpdateContext, starter_air_pressure: Pressure) -> f64 {
+        let starter_pressure_psig = (starter_air_pressure - context.ambient_pressure())
+            .get::<psi>()
+            .max(0.);
+        let torque_ratio =
+            (1. - self.core_speed.get::<percent>() / C::STARTER_CUT_OUT_CORE_SPEED_PERCENT).max(0.);
+
+        C::STARTER_ACCELERATION_PERCENT_PER_SECOND
+            * (starter_pressure_psig / C::STARTER_NOMINAL_PRESSURE_PSIG).min(1.5)
+            * torque_ratio
+    }
+
+    /// Aerodynamic and bearing drag slow the rotor down towards the speed at which the airflow
+    /// through the engine keeps it windmilling.
+    fn drag_deceleration(&self, context: &UpdateContext) -> f64 {
+        C::ROTOR_DRAG_PER_SECOND
+            * (self.core_speed.get::<percent>() - self.windmilling_core_speed(context))
+    }
+
+    fn windmilling_core_speed(&self, context: &UpdateContext) -> f64 {
+        context.true_airspeed().get::<knot>().max(0.) * C::WINDMILLING_CORE_SPEED_PERCENT_PER_KNOT
+    }
+
+    fn target_core_speed(&self) -> Ratio {
+        Ratio::new::<percent>(interpolation(
+            C::THRUST_LEVER_ANGLE_BREAKPOINTS,
+            C::TARGET_CORE_SPEED_PERCENT,
+            self.thrust_lever_angle.get::<degree>(),
+        ))
+    }
+
+    fn update_parameters(&mut self, context: &UpdateContext) {
+        let core_speed = self.core_speed.get::<percent>();
+        let ambient = context.ambient_temperature().get::<degree_celsius>();
+        let pressure_ratio =
+            context.ambient_pressure().get::<hectopascal>() / Self::STANDARD_PRESSURE_HPA;
+        let temperature_ratio =
+            context.ambient_temperature().get::<kelvin>() / Self::STANDARD_TEMPERATURE_KELVIN;
+
+        self.uncorrected_n1 = Ratio::new::<percent>(Self::table(C::N1_PERCENT, core_speed));
+        self.uncorrected_n2 = Ratio::new::<percent>(Self::table(C::N2_PERCENT, core_speed));
+        self.corrected_n1 = self.uncorrected_n1 / temperature_ratio.sqrt();
+        self.corrected_n2 = self.uncorrected_n2 / temperature_ratio.sqrt();
+
+        if self.is_burning {
+            self.egt.update(
+                context.delta(),
+                ambient + Self::table(C::EGT_RISE_DEGREE_CELSIUS, core_speed),
+            );
+            self.fuel_flow = MassRate::new::<kilogram_per_hour>(
+                Self::table(C::FUEL_FLOW_KG_PER_HOUR, core_speed) * pressure_ratio,
+            );
+            self.net_thrust = Mass::new::<pound>(
+                C::MAX_THRUST_LBS * self.uncorrected_n1.get::<ratio>().powi(2) * pressure_ratio,
+            );
+        } else {
+            self.egt.update(context.delta(), ambient);
+            self.fuel_flow = MassRate::default();
+            self.net_thrust = Mass::default();
+        }
+
+        self.oil_quantity = Volume::new::<quart_liquid>(
+            C::OIL_TANK_QUANTITY_QUARTS - C::OIL_GULPING_QUARTS * core_speed / 100.,
+        );
+        self.oil_pressure.update(
+            context.delta(),
+            Self::table(C::OIL_PRESSURE_PSI, core_speed),
+        );
+        self.oil_temperature.update(
+            context.delta(),
+            ambient + Self::table(C::OIL_TEMPERATURE_RISE_DEGREE_CELSIUS, core_speed),
+        );
+    }
+
+    fn table(values: &[f64], core_speed: f64) -> f64 {
+        interpolation(C::CORE_SPEED_BREAKPOINTS, values, core_speed)
+    }
+
+    fn core_rotational_speed(&self) -> AngularVelocity {
+        AngularVelocity::new::<revolution_per_minute>(
+            self.core_speed.get::<percent>() * C::CORE_SPEED_100_PCT_RPM / 100.,
+        )
+    }
+
+    pub fn is_burning(&self) -> bool {
+        self.is_burning
+    }
+
+    pub fn uncorrected_n1(&self) -> Ratio {
+        self.uncorrected_n1
+    }
+
+    pub fn exhaust_gas_temperature(&self) -> ThermodynamicTemperature {
+        ThermodynamicTemperature::new::<degree_celsius>(self.egt.output())
+    }
+
+    pub fn fuel_flow(&self) -> MassRate {
+        self.fuel_flow
+    }
+
+    pub fn oil_pressure(&self) -> Pressure {
+        Pressure::new::<psi>(self.oil_pressure.output())
+    }
+
+    pub fn oil_temperature(&self) -> ThermodynamicTemperature {
+        ThermodynamicTemperature::new::<degree_celsius>(self.oil_temperature.output())
+    }
+
+    pub fn oil_quantity(&self) -> Volume {
+        self.oil_quantity
+    }
+}
+impl<C: SpoolEngineConstants> SimulationElement for SpoolEngine<C> {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.simulator_combustion = reader.read(&self.combustion_id);
+        self.fuel_valve_is_open = reader.read(&self.fuel_valve_open_id);
+        self.thrust_lever_angle = reader.read(&self.thrust_lever_angle_id);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.n1_id, self.uncorrected_n1);
+        writer.write(&self.n2_id, self.uncorrected_n2);
+        writer.write(&self.egt_id, self.exhaust_gas_temperature());
+        writer.write(
+            &self.fuel_flow_id,
+            self.fuel_flow.get::<kilogram_per_hour>(),
+        );
+        writer.write(&self.oil_pressure_id, self.oil_pressure());
+        writer.write(&self.oil_temperature_id, self.oil_temperature());
+        writer.write(
+            &self.oil_quantity_id,
+            self.oil_quantity.get::<quart_liquid>(),
+        );
+        writer.write(&self.is_burning_id, self.is_burning);
+    }
+}
+impl<C: SpoolEngineConstants> EngineCorrectedN1 for SpoolEngine<C> {
+    fn corrected_n1(&self) -> Ratio {
+        self.corrected_n1
+    }
+}
+impl<C: SpoolEngineConstants> EngineCorrectedN2 for SpoolEngine<C> {
+    fn corrected_n2(&self) -> Ratio {
+        self.corrected_n2
+    }
+}
+impl<C: SpoolEngineConstants> EngineUncorrectedN2 for SpoolEngine<C> {
+    fn uncorrected_n2(&self) -> Ratio {
+        self.uncorrected_n2
+    }
+}
+impl<C: SpoolEngineConstants> EngineFuelFlow for SpoolEngine<C> {
+    fn fuel_flow(&self) -> MassRate {
+        self.fuel_flow
+    }
+}
+impl<C: SpoolEngineConstants> Engine for SpoolEngine<C> {
+    fn hydraulic_pump_output_speed(&self) -> AngularVelocity {
+        self.core_rotational_speed() * C::PUMP_GEAR_RATIO
+    }
+
+    fn oil_pressure_is_low(&self) -> bool {
+        self.oil_pressure.output() < C::LOW_OIL_PRESSURE_THRESHOLD_PSI
+    }
+
+    fn is_above_minimum_idle(&self) -> bool {
+        self.uncorrected_n2 >= Ratio::new::<percent>(C::MIN_IDLE_N2_UNCORRECTED_THRESHOLD_PERCENT)
+    }
+
+    fn net_thrust(&self) -> Mass {
+        self.net_thrust
+    }
+
+    fn gearbox_speed(&self) -> AngularVelocity {
+        self.core_rotational_speed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::electrical::Electricity;
+    use crate::engine::leap_engine::Leap1A26Constants;
+    use crate::simulation::test::{ReadByName, SimulationTestBed, TestBed, WriteByName};
+    use crate::simulation::{Aircraft, SimulationElementVisitor};
+    use uom::si::{pressure::psi, thermodynamic_temperature::degree_celsius};
+
+    struct TestAircraft {
+        engine: SpoolEngine<Leap1A26Constants>,
+        starter_air_pressure_psig: f64,
+        fuel_is_available: bool,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                engine: SpoolEngine::new(context, 1),
+                starter_air_pressure_psig: 0.,
+                fuel_is_available: true,
+            }
+        }
+
+        fn set_starter_air_pressure_psig(&mut self, pressure_psig: f64) {
+            self.starter_air_pressure_psig = pressure_psig;
+        }
+
+        fn set_fuel_is_available(&mut self, is_available: bool) {
+            self.fuel_is_available = is_available;
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            context: &UpdateContext,
+            _: &mut Electricity,
+        ) {
+            self.engine.update(
+                context,
+                context.ambient_pressure() + Pressure::new::<psi>(self.starter_air_pressure_psig),
+                self.fuel_is_available,
+            );
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.engine.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    struct SpoolEngineTestBed {
+        test_bed: SimulationTestBed<TestAircraft>,
+    }
+    impl SpoolEngineTestBed {
+        fn new() -> Self {
+            let mut test_bed = Self {
+                test_bed: SimulationTestBed::new(TestAircraft::new),
+            };
+            test_bed.set_on_ground(true);
+            test_bed.set_true_airspeed(Velocity::new::<knot>(0.));
+
+            test_bed
+        }
+
+        fn running() -> Self {
+            let mut test_bed = Self::new();
+            test_bed.write_by_name("GENERAL ENG COMBUSTION:1", true);
+            test_bed.write_by_name("GENERAL ENG FUEL VALVE:1", true);
+
+            test_bed
+        }
+
+        fn starter_air_psig(mut self, pressure_psig: f64) -> Self {
+            self.command(|a| a.set_starter_air_pressure_psig(pressure_psig));
+            self
+        }
+
+        fn fuel_valve_open(mut self, is_open: bool) -> Self {
+            self.write_by_name("GENERAL ENG FUEL VALVE:1", is_open);
+            self
+        }
+
+        fn fuel_available(mut self, is_available: bool) -> Self {
+            self.command(|a| a.set_fuel_is_available(is_available));
+            self
+        }
+
+        fn thrust_lever_angle(mut self, degrees: f64) -> Self {
+            self.write_by_name("AUTOTHRUST_TLA:1", Angle::new::<degree>(degrees));
+            self
+        }
+
+        fn true_airspeed(mut self, knots: f64) -> Self {
+            self.set_on_ground(false);
+            self.set_true_airspeed(Velocity::new::<knot>(knots));
+            self
+        }
+
+        fn run_for(mut self, duration: Duration) -> Self {
+            let seconds = duration.as_secs();
+            for _ in 0..seconds {
+                self.run_with_delta(Duration::from_secs(1));
+            }
+
+            self
+        }
+
+        fn n2(&self) -> Ratio {
+            self.query(|a| a.engine.uncorrected_n2())
+        }
+
+        fn n1(&self) -> Ratio {
+            self.query(|a| a.engine.uncorrected_n1())
+        }
+
+        fn is_burning(&self) -> bool {
+            self.query(|a| a.engine.is_burning())
+        }
+
+        fn is_above_minimum_idle(&self) -> bool {
+            self.query(|a| a.engine.is_above_minimum_idle())
+        }
+
+        fn oil_pressure_is_low(&self) -> bool {
+            self.query(|a| a.engine.oil_pressure_is_low())
+        }
+
+        fn egt(&self) -> ThermodynamicTemperature {
+            self.query(|a| a.engine.exhaust_gas_temperature())
+        }
+
+        fn fuel_flow(&self) -> MassRate {
+            self.query(|a| a.engine.fuel_flow())
+        }
+
+        fn oil_temperature(&self) -> ThermodynamicTemperature {
+            self.query(|a| a.engine.oil_temperature())
+        }
+
+        fn oil_quantity(&self) -> Volume {
+            self.query(|a| a.engine.oil_quantity())
+        }
+
+        fn net_thrust(&self) -> Mass {
+            self.query(|a| a.engine.net_thrust())
+        }
+
+        fn hydraulic_pump_output_speed(&self) -> AngularVelocity {
+            self.query(|a| a.engine.hydraulic_pump_output_speed())
+        }
+    }
+    impl TestBed for SpoolEngineTestBed {
+        type Aircraft = TestAircraft;
+
+        fn test_bed(&self) -> &SimulationTestBed<TestAircraft> {
+            &self.test_bed
+        }
+
+        fn test_bed_mut(&mut self) -> &mut SimulationTestBed<TestAircraft> {
+            &mut self.test_bed
+        }
+    }
+
+    fn test_bed() -> SpoolEngineTestBed {
+        SpoolEngineTestBed::new()
+    }
+
+    #[test]
+    fn engine_is_stopped_after_construction() {
+        let test_bed = test_bed().run_for(Duration::from_secs(1));
+
+        assert_eq!(test_bed.n2(), Ratio::default());
+        assert!(!test_bed.is_burning());
+        assert!(!test_bed.is_above_minimum_idle());
+        assert!(test_bed.oil_pressure_is_low());
+        assert_eq!(test_bed.fuel_flow(), MassRate::default());
+    }
+
+    #[test]
+    fn engine_starts_running_when_simulation_starts_with_engines_running() {
+        let mut test_bed = SpoolEngineTestBed::running();
+        test_bed.run_without_delta();
+
+        assert!(test_bed.is_burning());
+        assert!(test_bed.is_above_minimum_idle());
+        assert!(!test_bed.oil_pressure_is_low());
+    }
+
+    #[test]
+    fn starter_air_motors_the_engine_below_starter_cut_out() {
+        let test_bed = test_bed()
+            .starter_air_psig(30.)
+            .run_for(Duration::from_secs(60));
+
+        assert!(test_bed.n2().get::<percent>() > 20.);
+        assert!(
+            test_bed.n2().get::<percent>() < Leap1A26Constants::STARTER_CUT_OUT_CORE_SPEED_PERCENT
+        );
+        assert!(!test_bed.is_burning());
+    }
+
+    #[test]
+    fn dry_cranking_does_not_heat_the_exhaust() {
+        let test_bed = test_bed()
+            .starter_air_psig(30.)
+            .run_for(Duration::from_secs(60));
+
+        assert!(test_bed.egt().get::<degree_celsius>() < 1.);
+    }
+
+    #[test]
+    fn engine_lights_off_and_reaches_idle_with_starter_air_and_fuel() {
+        let test_bed = test_bed()
+            .starter_air_psig(30.)
+            .fuel_valve_open(true)
+            .run_for(Duration::from_secs(60));
+
+        assert!(test_bed.is_burning());
+        assert!(test_bed.is_above_minimum_idle());
+        assert!(!test_bed.oil_pressure_is_low());
+        assert!(test_bed.fuel_flow().get::<kilogram_per_hour>() > 200.);
+        assert!(test_bed.egt().get::<degree_celsius>() > 400.);
+    }
+
+    #[test]
+    fn engine_keeps_running_after_starter_air_is_removed() {
+        let test_bed = test_bed()
+            .starter_air_psig(30.)
+            .fuel_valve_open(true)
+            .run_for(Duration::from_secs(60))
+            .starter_air_psig(0.)
+            .run_for(Duration::from_secs(30));
+
+        assert!(test_bed.is_burning());
+        assert!(test_bed.is_above_minimum_idle());
+    }
+
+    #[test]
+    fn engine_does_not_light_off_without_starter_air() {
+        let test_bed = test_bed()
+            .fuel_valve_open(true)
+            .run_for(Duration::from_secs(60));
+
+        assert!(!test_bed.is_burning());
+        assert_eq!(test_bed.n2(), Ratio::default());
+    }
+
+    #[test]
+    fn engine_does_not_light_off_without_fuel_available() {
+        let test_bed = test_bed()
+            .starter_air_psig(30.)
+            .fuel_valve_open(true)
+            .fuel_available(false)
+            .run_for(Duration::from_secs(60));
+
+        assert!(!test_bed.is_burning());
+        assert!(!test_bed.is_above_minimum_idle());
+    }
+
+    #[test]
+    fn closing_the_fuel_valve_shuts_the_engine_down() {
+        let mut test_bed = SpoolEngineTestBed::running();
+        test_bed.run_without_delta();
+
+        let test_bed = test_bed
+            .fuel_valve_open(false)
+            .run_for(Duration::from_secs(60));
+
+        assert!(!test_bed.is_burning());
+        assert!(test_bed.n2().get::<percent>() < 10.);
+        assert!(test_bed.oil_pressure_is_low());
+        assert_eq!(test_bed.fuel_flow(), MassRate::default());
+    }
+
+    #[test]
+    fn engine_flames_out_when_fuel_runs_out() {
+        let mut test_bed = SpoolEngineTestBed::running();
+        test_bed.run_without_delta();
+
+        let test_bed = test_bed
+            .fuel_available(false)
+            .run_for(Duration::from_secs(1));
+
+        assert!(!test_bed.is_burning());
+    }
+
+    #[test]
+    fn egt_cools_down_after_shutdown() {
+        let mut test_bed = SpoolEngineTestBed::running();
+        test_bed.run_without_delta();
+
+        let test_bed = test_bed.run_for(Duration::from_secs(10));
+        let running_egt = test_bed.egt();
+        let test_bed = test_bed
+            .fuel_valve_open(false)
+            .run_for(Duration::from_secs(30));
+
+        assert!(test_bed.egt() < running_egt);
+    }
+
+    #[test]
+    fn engine_windmills_in_flight() {
+        let test_bed = test_bed()
+            .true_airspeed(250.)
+            .run_for(Duration::from_secs(120));
+
+        assert!(!test_bed.is_burning());
+        assert!(test_bed.n2().get::<percent>() > 10.);
+        assert!(test_bed.hydraulic_pump_output_speed() > AngularVelocity::default());
+    }
+
+    #[test]
+    fn windmilling_engine_relights_at_high_airspeed() {
+        let test_bed = test_bed()
+            .true_airspeed(320.)
+            .run_for(Duration::from_secs(120))
+            .fuel_valve_open(true)
+            .run_for(Duration::from_secs(60));
+
+        assert!(test_bed.is_burning());
+        assert!(test_bed.is_above_minimum_idle());
+    }
+
+    #[test]
+    fn windmilling_engine_does_not_relight_at_low_airspeed() {
+        let test_bed = test_bed()
+            .true_airspeed(180.)
+            .run_for(Duration::from_secs(120))
+            .fuel_valve_open(true)
+            .run_for(Duration::from_secs(60));
+
+        assert!(!test_bed.is_burning());
+    }
+
+    #[test]
+    fn advancing_the_thrust_lever_increases_n1_and_thrust() {
+        let mut test_bed = SpoolEngineTestBed::running();
+        test_bed.run_without_delta();
+
+        let test_bed = test_bed.run_for(Duration::from_secs(5));
+        let idle_n1 = test_bed.n1();
+        let idle_thrust = test_bed.net_thrust();
+
+        let test_bed = test_bed
+            .thrust_lever_angle(45.)
+            .run_for(Duration::from_secs(20));
+
+        assert!(test_bed.n1().get::<percent>() > 90.);
+        assert!(test_bed.n1() > idle_n1);
+        assert!(test_bed.net_thrust() > idle_thrust);
+    }
+
+    #[test]
+    fn oil_warms_up_while_running() {
+        let mut test_bed = SpoolEngineTestBed::new();
+        test_bed.run_without_delta();
+        let cold_oil_temperature = test_bed.oil_temperature();
+
+        let test_bed = test_bed
+            .starter_air_psig(30.)
+            .fuel_valve_open(true)
+            .run_for(Duration::from_secs(300));
+
+        assert!(
+            test_bed.oil_temperature().get::<degree_celsius>()
+                > cold_oil_temperature.get::<degree_celsius>() + 20.
+        );
+    }
+
+    #[test]
+    fn oil_quantity_decreases_while_running() {
+        let mut test_bed = SpoolEngineTestBed::running();
+        test_bed.run_without_delta();
+
+        assert!(
+            test_bed.oil_quantity()
+                < Volume::new::<quart_liquid>(Leap1A26Constants::OIL_TANK_QUANTITY_QUARTS)
+        );
+    }
+
+    #[test]
+    fn writes_its_state() {
+        let mut test_bed = SpoolEngineTestBed::running();
+        test_bed.run();
+
+        let n2: Ratio = test_bed.read_by_name("SPOOL_ENGINE_1_N2");
+        let is_burning: bool = test_bed.read_by_name("SPOOL_ENGINE_1_IS_BURNING");
+
+        assert!(n2.get::<percent>() > 50.);
+        assert!(is_burning);
+    }
+}
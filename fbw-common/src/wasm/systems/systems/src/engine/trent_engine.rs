@@ -1,15 +1,21 @@
-use uom::si::{angular_velocity::revolution_per_minute, f64::*, pressure::psi, ratio::percent};
+use std::time::Duration;
+
+use uom::si::{
+    angular_velocity::revolution_per_minute, f64::*, mass_rate::kilogram_per_hour, pressure::psi,
+    ratio::percent,
+};
 
 use crate::{
-    shared::{EngineCorrectedN1, EngineCorrectedN2, EngineUncorrectedN2},
+    shared::{EngineCorrectedN1, EngineCorrectedN2, EngineFuelFlow, EngineUncorrectedN2},
     simulation::{Read, SimulationElement, SimulatorReader, UpdateContext},
 };
 
-use super::Engine;
+use super::{spool_engine::SpoolEngineConstants, Engine};
 use crate::simulation::{InitContext, VariableIdentifier};
 
 pub struct TrentEngine {
     thrust_id: VariableIdentifier,
+    fuel_flow_id: VariableIdentifier,
 
     corrected_n1_id: VariableIdentifier,
     corrected_n1: Ratio,
@@ -26,6 +32,7 @@ pub struct TrentEngine {
     oil_pressure: Pressure,
 
     net_thrust: Mass,
+    fuel_flow: MassRate,
 }
 impl TrentEngine {
     // 100% N1 @ 2900 RPM
@@ -45,6 +52,7 @@ impl TrentEngine {
     pub fn new(context: &mut InitContext, number: usize) -> TrentEngine {
         TrentEngine {
             thrust_id: context.get_identifier(format!("TURB ENG JET THRUST:{}", number)),
+            fuel_flow_id: context.get_identifier(format!("ENGINE_FF:{}", number)),
 
             corrected_n1_id: context.get_identifier(format!("TURB ENG CORRECTED N1:{}", number)),
             corrected_n1: Ratio::new::<percent>(0.),
@@ -60,6 +68,7 @@ impl TrentEngine {
             oil_pressure: Pressure::new::<psi>(0.),
 
             net_thrust: Mass::default(),
+            fuel_flow: MassRate::default(),
         }
     }
 
@@ -83,6 +92,9 @@ impl SimulationElement for TrentEngine {
         self.uncorrected_n3 = reader.read(&self.uncorrected_n3_id);
         self.net_thrust = reader.read(&self.thrust_id);
 
+        let fuel_flow_kg_per_hour: f64 = reader.read(&self.fuel_flow_id);
+        self.fuel_flow = MassRate::new::<kilogram_per_hour>(fuel_flow_kg_per_hour);
+
         self.update_parameters();
     }
 }
@@ -101,6 +113,11 @@ impl EngineUncorrectedN2 for TrentEngine {
         self.uncorrected_n2
     }
 }
+impl EngineFuelFlow for TrentEngine {
+    fn fuel_flow(&self) -> MassRate {
+        self.fuel_flow
+    }
+}
 impl Engine for TrentEngine {
     fn hydraulic_pump_output_speed(&self) -> AngularVelocity {
         self.hydraulic_pump_output_speed
@@ -123,3 +140,44 @@ impl Engine for TrentEngine {
         self.n3_speed
     }
 }
+
+/// Trent 972 values for the [`super::spool_engine::SpoolEngine`] model. The modelled core is the
+/// high pressure spool (N3), the intermediate pressure spool is reported as N2.
+pub struct Trent972Constants;
+impl SpoolEngineConstants for Trent972Constants {
+    const CORE_SPEED_100_PCT_RPM: f64 = TrentEngine::TRENT_900_100_PCT_N3_RPM;
+    const PUMP_GEAR_RATIO: f64 = TrentEngine::PUMP_N3_GEAR_RATIO;
+
+    const MIN_IDLE_N2_UNCORRECTED_THRESHOLD_PERCENT: f64 =
+        TrentEngine::MIN_IDLE_N2_UNCORRECTED_THRESHOLD_PERCENT;
+    const LOW_OIL_PRESSURE_THRESHOLD_PSI: f64 = TrentEngine::LOW_OIL_PRESSURE_THRESHOLD_PSI;
+
+    const LIGHT_OFF_CORE_SPEED_PERCENT: f64 = 15.;
+    const FLAME_OUT_CORE_SPEED_PERCENT: f64 = 9.;
+    const SELF_SUSTAINING_CORE_SPEED_PERCENT: f64 = 45.;
+    const STARTER_CUT_OUT_CORE_SPEED_PERCENT: f64 = 55.;
+    const STARTER_ACCELERATION_PERCENT_PER_SECOND: f64 = 2.;
+    const STARTER_NOMINAL_PRESSURE_PSIG: f64 = 35.;
+    const COMBUSTION_ACCELERATION_PERCENT_PER_SECOND: f64 = 1.3;
+    const ROTOR_DRAG_PER_SECOND: f64 = 0.035;
+    const WINDMILLING_CORE_SPEED_PERCENT_PER_KNOT: f64 = 0.055;
+    const GOVERNED_SPOOL_TIME_CONSTANT: Duration = Duration::from_secs(4);
+
+    // Max reverse, reverse idle, forward idle, CL, FLX/MCT, TOGA
+    const THRUST_LEVER_ANGLE_BREAKPOINTS: &'static [f64] = &[-20., -6., 0., 25., 35., 45.];
+    const TARGET_CORE_SPEED_PERCENT: &'static [f64] = &[88., 66., 66., 92., 95., 97.];
+
+    const CORE_SPEED_BREAKPOINTS: &'static [f64] = &[0., 15., 45., 66., 100., 110.];
+    const N1_PERCENT: &'static [f64] = &[0., 2., 10., 20., 97., 104.];
+    const N2_PERCENT: &'static [f64] = &[0., 10., 38., 60., 96., 102.];
+    const EGT_RISE_DEGREE_CELSIUS: &'static [f64] = &[0., 300., 600., 550., 880., 930.];
+    const FUEL_FLOW_KG_PER_HOUR: &'static [f64] = &[0., 300., 600., 800., 11_500., 12_500.];
+    const OIL_PRESSURE_PSI: &'static [f64] = &[0., 15., 40., 50., 80., 82.];
+    const OIL_TEMPERATURE_RISE_DEGREE_CELSIUS: &'static [f64] = &[0., 5., 30., 55., 100., 105.];
+
+    // Rated takeoff thrust is 76,500 lbf at an N1 slightly below 100%
+    const MAX_THRUST_LBS: f64 = 78_000.;
+
+    const OIL_TANK_QUANTITY_QUARTS: f64 = 40.;
+    const OIL_GULPING_QUARTS: f64 = 8.;
+}
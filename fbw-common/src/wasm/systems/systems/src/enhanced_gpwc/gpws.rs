@@ -0,0 +1,600 @@
+use crate::{
+    shared::{arinc429::Arinc429Word, low_pass_filter::LowPassFilter, DelayedTrueLogicGate},
+    simulation::{
+        InitContext, Read, SimulationElement, SimulatorReader, SimulatorWriter, UpdateContext,
+        VariableIdentifier, Write,
+    },
+};
+use std::time::Duration;
+use uom::si::{
+    angle::degree,
+    f64::{Angle, Length, Velocity},
+    length::foot,
+    velocity::{foot_per_minute, knot},
+};
+
+/// The sensor inputs the basic GPWS modes are computed from.
+pub(super) struct GpwsInputs {
+    pub(super) radio_altitude: Arinc429Word<Length>,
+    pub(super) barometric_altitude: Arinc429Word<Length>,
+    pub(super) computed_airspeed: Arinc429Word<Velocity>,
+    pub(super) inertial_vertical_speed: Arinc429Word<Velocity>,
+    pub(super) gear_down_and_locked: bool,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum Mode1Alert {
+    #[default]
+    None,
+    SinkRate,
+    PullUp,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum Mode2Alert {
+    #[default]
+    None,
+    Terrain,
+    PullUp,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum Mode3Alert {
+    #[default]
+    None,
+    DontSink,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum Mode4Alert {
+    #[default]
+    None,
+    TooLowGear,
+    TooLowFlaps,
+    TooLowTerrain,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum Mode5Alert {
+    #[default]
+    None,
+    Glideslope,
+    GlideslopeHard,
+}
+
+/// The basic ground proximity warning modes 1 to 5 of the EGPWC.
+///
+/// - Mode 1: excessive descent rate
+/// - Mode 2: excessive terrain closure rate
+/// - Mode 3: altitude loss after takeoff
+/// - Mode 4: unsafe terrain clearance (gear and flap sub-modes)
+/// - Mode 5: excessive deviation below the glideslope
+pub(super) struct GroundProximityWarningSystem {
+    system_off_id: VariableIdentifier,
+    glideslope_mode_off_id: VariableIdentifier,
+    flap_mode_off_id: VariableIdentifier,
+    landing_flaps_3_id: VariableIdentifier,
+    flaps_handle_index_id: VariableIdentifier,
+    glideslope_is_valid_id: VariableIdentifier,
+    glideslope_deviation_id: VariableIdentifier,
+    system_off: bool,
+    glideslope_mode_off: bool,
+    flap_mode_off: bool,
+    landing_flaps_3: bool,
+    flaps_handle_index: u8,
+    /// Glideslope deviation, positive when the aircraft is above the glideslope.
+    /// None when no valid glideslope is received.
+    glideslope_deviation: Option<Angle>,
+
+    is_air_mode: bool,
+    airborne_confirmation: DelayedTrueLogicGate,
+    is_approach_mode: bool,
+
+    previous_radio_altitude: Option<Length>,
+    radio_altitude_rate: LowPassFilter<f64>,
+
+    mode_2_boundary_confirmation: DelayedTrueLogicGate,
+    mode_2_terrain_duration: Duration,
+    mode_2_boundary_leave_altitude: Option<Length>,
+    mode_3_max_barometric_altitude: Option<Length>,
+    mode_4_max_radio_altitude: Length,
+
+    mode_1: Mode1Alert,
+    mode_2: Mode2Alert,
+    mode_3: Mode3Alert,
+    mode_4: Mode4Alert,
+    mode_5: Mode5Alert,
+
+    sink_rate_id: VariableIdentifier,
+    pull_up_id: VariableIdentifier,
+    terrain_id: VariableIdentifier,
+    dont_sink_id: VariableIdentifier,
+    too_low_gear_id: VariableIdentifier,
+    too_low_flaps_id: VariableIdentifier,
+    too_low_terrain_id: VariableIdentifier,
+    glideslope_id: VariableIdentifier,
+    glideslope_hard_id: VariableIdentifier,
+    gpws_warning_id: VariableIdentifier,
+    glideslope_warning_id: VariableIdentifier,
+    air_mode_id: VariableIdentifier,
+    approach_mode_id: VariableIdentifier,
+}
+impl GroundProximityWarningSystem {
+    const MIN_ACTIVE_RADIO_ALTITUDE_FT: f64 = 10.;
+    const MAX_ACTIVE_RADIO_ALTITUDE_FT: f64 = 2450.;
+    const AIR_GROUND_RADIO_ALTITUDE_THRESHOLD_FT: f64 = 25.;
+    const AIRBORNE_MIN_COMPUTED_AIRSPEED_KNOTS: f64 = 90.;
+    const AIRBORNE_CONFIRMATION_TIME: Duration = Duration::from_secs(10);
+    const RADIO_ALTITUDE_RATE_TIME_CONSTANT: Duration = Duration::from_millis(500);
+    const MODE_2_BOUNDARY_CONFIRMATION_TIME: Duration = Duration::from_millis(600);
+    // Two "TERRAIN" call outs are given before the "PULL UP" with the gear retracted.
+    const MODE_2_TERRAIN_CALL_OUT_DURATION: Duration = Duration::from_millis(2200);
+    const MODE_2_ALTITUDE_GAIN_TO_CLEAR_FT: f64 = 300.;
+
+    const FLAPS_HANDLE_INDEX_FULL: u8 = 4;
+    const FLAPS_HANDLE_INDEX_3: u8 = 3;
+
+    pub(super) fn new(context: &mut InitContext) -> Self {
+        Self {
+            system_off_id: context.get_identifier("GPWS_SYS_OFF".to_owned()),
+            glideslope_mode_off_id: context.get_identifier("GPWS_GS_OFF".to_owned()),
+            flap_mode_off_id: context.get_identifier("GPWS_FLAP_OFF".to_owned()),
+            landing_flaps_3_id: context.get_identifier("GPWS_FLAPS3".to_owned()),
+            flaps_handle_index_id: context.get_identifier("FLAPS_HANDLE_INDEX".to_owned()),
+            glideslope_is_valid_id: context.get_identifier("RADIO_RECEIVER_GS_IS_VALID".to_owned()),
+            glideslope_deviation_id: context
+                .get_identifier("RADIO_RECEIVER_GS_DEVIATION".to_owned()),
+            system_off: false,
+            glideslope_mode_off: false,
+            flap_mode_off: false,
+            landing_flaps_3: false,
+            flaps_handle_index: 0,
+            glideslope_deviation: None,
+
+            is_air_mode: context.is_in_flight(),
+            airborne_confirmation: DelayedTrueLogicGate::new(Self::AIRBORNE_CONFIRMATION_TIME),
+            is_approach_mode: false,
+
+            previous_radio_altitude: None,
+            radio_altitude_rate: LowPassFilter::new(Self::RADIO_ALTITUDE_RATE_TIME_CONSTANT),
+
+            mode_2_boundary_confirmation: DelayedTrueLogicGate::new(
+                Self::MODE_2_BOUNDARY_CONFIRMATION_TIME,
+            ),
+            mode_2_terrain_duration: Duration::ZERO,
+            mode_2_boundary_leave_altitude: None,
+            mode_3_max_barometric_altitude: None,
+            mode_4_max_radio_altitude: Length::default(),
+
+            mode_1: Mode1Alert::None,
+            mode_2: Mode2Alert::None,
+            mode_3: Mode3Alert::None,
+            mode_4: Mode4Alert::None,
+            mode_5: Mode5Alert::None,
+
+            sink_rate_id: context.get_identifier("EGPWC_GPWS_SINK_RATE".to_owned()),
+            pull_up_id: context.get_identifier("EGPWC_GPWS_PULL_UP".to_owned()),
+            terrain_id: context.get_identifier("EGPWC_GPWS_TERRAIN".to_owned()),
+            dont_sink_id: context.get_identifier("EGPWC_GPWS_DONT_SINK".to_owned()),
+            too_low_gear_id: context.get_identifier("EGPWC_GPWS_TOO_LOW_GEAR".to_owned()),
+            too_low_flaps_id: context.get_identifier("EGPWC_GPWS_TOO_LOW_FLAPS".to_owned()),
+            too_low_terrain_id: context.get_identifier("EGPWC_GPWS_TOO_LOW_TERRAIN".to_owned()),
+            glideslope_id: context.get_identifier("EGPWC_GPWS_GLIDESLOPE".to_owned()),
+            glideslope_hard_id: context.get_identifier("EGPWC_GPWS_GLIDESLOPE_HARD".to_owned()),
+            gpws_warning_id: context.get_identifier("EGPWC_GPWS_WARNING".to_owned()),
+            glideslope_warning_id: context
+                .get_identifier("EGPWC_GPWS_GLIDESLOPE_WARNING".to_owned()),
+            air_mode_id: context.get_identifier("EGPWC_GPWS_AIR_MODE".to_owned()),
+            approach_mode_id: context.get_identifier("EGPWC_GPWS_APPROACH_MODE".to_owned()),
+        }
+    }
+
+    pub(super) fn update(&mut self, context: &UpdateContext, inputs: &GpwsInputs) {
+        let radio_altitude = inputs.radio_altitude.normal_value();
+
+        self.update_radio_altitude_rate(context, radio_altitude);
+        self.update_air_ground_mode(context, inputs);
+        self.update_mode_4_max_radio_altitude(radio_altitude);
+        self.update_approach_takeoff_mode(inputs);
+
+        match radio_altitude {
+            Some(radio_altitude)
+                if !self.system_off
+                    && (Self::MIN_ACTIVE_RADIO_ALTITUDE_FT
+                        ..=Self::MAX_ACTIVE_RADIO_ALTITUDE_FT)
+                        .contains(&radio_altitude.get::<foot>()) =>
+            {
+                self.update_mode_1(radio_altitude, inputs);
+                self.update_mode_2(context, radio_altitude, inputs);
+                self.update_mode_3(radio_altitude, inputs);
+                self.update_mode_4(radio_altitude, inputs);
+                self.update_mode_5(radio_altitude, inputs);
+            }
+            _ => self.reset_modes(),
+        }
+    }
+
+    pub(super) fn reset(&mut self) {
+        self.previous_radio_altitude = None;
+        self.radio_altitude_rate.reset(0.);
+        self.reset_modes();
+    }
+
+    fn reset_modes(&mut self) {
+        self.mode_2_boundary_confirmation =
+            DelayedTrueLogicGate::new(Self::MODE_2_BOUNDARY_CONFIRMATION_TIME);
+        self.mode_2_terrain_duration = Duration::ZERO;
+        self.mode_2_boundary_leave_altitude = None;
+        self.mode_3_max_barometric_altitude = None;
+
+        self.mode_1 = Mode1Alert::None;
+        self.mode_2 = Mode2Alert::None;
+        self.mode_3 = Mode3Alert::None;
+        self.mode_4 = Mode4Alert::None;
+        self.mode_5 = Mode5Alert::None;
+    }
+
+    fn update_radio_altitude_rate(
+        &mut self,
+        context: &UpdateContext,
+        radio_altitude: Option<Length>,
+    ) {
+        match (self.previous_radio_altitude, radio_altitude) {
+            (Some(previous), Some(current)) if context.delta_as_secs_f64() > 0. => {
+                let rate = (current - previous).get::<foot>() / context.delta_as_secs_f64() * 60.;
+                self.radio_altitude_rate.update(context.delta(), rate);
+            }
+            (_, None) => self.radio_altitude_rate.reset(0.),
+            _ => {}
+        }
+
+        self.previous_radio_altitude = radio_altitude;
+    }
+
+    fn update_air_ground_mode(&mut self, context: &UpdateContext, inputs: &GpwsInputs) {
+        let (Some(radio_altitude), Some(computed_airspeed)) = (
+            inputs.radio_altitude.normal_value(),
+            inputs.computed_airspeed.normal_value(),
+        ) else {
+            // Stay in the current mode.
+            return;
+        };
+
+        self.airborne_confirmation.update(
+            context,
+            computed_airspeed.get::<knot>() > Self::AIRBORNE_MIN_COMPUTED_AIRSPEED_KNOTS
+                && radio_altitude.get::<foot>() > Self::AIR_GROUND_RADIO_ALTITUDE_THRESHOLD_FT,
+        );
+
+        if self.is_air_mode {
+            if radio_altitude.get::<foot>() < Self::AIR_GROUND_RADIO_ALTITUDE_THRESHOLD_FT {
+                self.is_air_mode = false;
+            }
+        } else if self.airborne_confirmation.output() {
+            self.is_air_mode = true;
+        }
+    }
+
+    fn update_mode_4_max_radio_altitude(&mut self, radio_altitude: Option<Length>) {
+        if !self.is_air_mode || self.is_approach_mode {
+            self.mode_4_max_radio_altitude = Length::default();
+        } else if let Some(radio_altitude) = radio_altitude {
+            self.mode_4_max_radio_altitude =
+                self.mode_4_max_radio_altitude.max(radio_altitude * 0.75);
+        }
+    }
+
+    fn update_approach_takeoff_mode(&mut self, inputs: &GpwsInputs) {
+        let Some(computed_airspeed) = inputs.computed_airspeed.normal_value() else {
+            return;
+        };
+
+        if self.is_approach_mode {
+            // Passing below the mode 4B floor without an alert means we're landing or going
+            // around, so the next climb is considered a takeoff.
+            if inputs
+                .radio_altitude
+                .normal_value()
+                .is_some_and(|radio_altitude| radio_altitude.get::<foot>() < 245.)
+                && inputs.gear_down_and_locked
+                && self.flaps_in_landing_configuration()
+            {
+                self.is_approach_mode = false;
+            }
+        } else if self.mode_4_max_radio_altitude.get::<foot>()
+            > Self::mode_4a_upper_boundary(computed_airspeed, self.flaps_in_landing_configuration())
+        {
+            self.is_approach_mode = true;
+        }
+    }
+
+    fn altitude_rate(&self, inputs: &GpwsInputs) -> Velocity {
+        inputs
+            .inertial_vertical_speed
+            .normal_value()
+            .unwrap_or_else(|| Velocity::new::<foot_per_minute>(self.radio_altitude_rate.output()))
+    }
+
+    fn update_mode_1(&mut self, radio_altitude: Length, inputs: &GpwsInputs) {
+        let sink_rate = -self.altitude_rate(inputs).get::<foot_per_minute>();
+        let radio_altitude = radio_altitude.get::<foot>();
+
+        self.mode_1 = if sink_rate <= 1000. {
+            Mode1Alert::None
+        } else {
+            let sink_rate_boundary = 0.61 * sink_rate - 600.;
+            let pull_up_boundary = if sink_rate < 1700. {
+                1.3 * sink_rate - 1940.
+            } else {
+                0.4 * sink_rate - 410.
+            };
+
+            if radio_altitude <= pull_up_boundary {
+                Mode1Alert::PullUp
+            } else if radio_altitude <= sink_rate_boundary {
+                Mode1Alert::SinkRate
+            } else {
+                Mode1Alert::None
+            }
+        };
+    }
+
+    fn update_mode_2(
+        &mut self,
+        context: &UpdateContext,
+        radio_altitude: Length,
+        inputs: &GpwsInputs,
+    ) {
+        let Some(computed_airspeed) = inputs.computed_airspeed.normal_value() else {
+            self.mode_2 = Mode2Alert::None;
+            return;
+        };
+
+        let closure_rate = -self.radio_altitude_rate.output();
+        let radio_altitude_ft = radio_altitude.get::<foot>();
+
+        let rate_boundary = if closure_rate < 3500. {
+            0.7937 * closure_rate - 1557.5
+        } else {
+            0.19166 * closure_rate + 610.
+        };
+        let is_in_boundary = closure_rate > 2000.
+            && radio_altitude_ft < rate_boundary
+            && if self.flaps_in_landing_configuration() {
+                // Mode 2B
+                radio_altitude_ft < 775. && closure_rate < 10000.
+            } else {
+                // Mode 2A
+                radio_altitude_ft
+                    < (8.8888 * computed_airspeed.get::<knot>() - 305.555).clamp(1650., 2450.)
+            };
+
+        // The closure rate is derived from the radio altitude, and thus noisy. Confirm the
+        // penetration of the envelope before alerting.
+        self.mode_2_boundary_confirmation
+            .update(context, is_in_boundary);
+
+        if self.mode_2_boundary_confirmation.output() {
+            self.mode_2_boundary_leave_altitude = None;
+            self.mode_2_terrain_duration += context.delta();
+
+            self.mode_2 = if inputs.gear_down_and_locked
+                || self.mode_2_terrain_duration < Self::MODE_2_TERRAIN_CALL_OUT_DURATION
+            {
+                Mode2Alert::Terrain
+            } else {
+                Mode2Alert::PullUp
+            };
+        } else if self.mode_2 != Mode2Alert::None {
+            // After leaving the envelope, "TERRAIN" continues until 300 ft of altitude are gained.
+            let leave_altitude = *self
+                .mode_2_boundary_leave_altitude
+                .get_or_insert(radio_altitude);
+
+            if radio_altitude_ft
+                < leave_altitude.get::<foot>() + Self::MODE_2_ALTITUDE_GAIN_TO_CLEAR_FT
+            {
+                self.mode_2 = Mode2Alert::Terrain;
+            } else {
+                self.mode_2 = Mode2Alert::None;
+                self.mode_2_terrain_duration = Duration::ZERO;
+                self.mode_2_boundary_leave_altitude = None;
+            }
+        }
+    }
+
+    fn update_mode_3(&mut self, radio_altitude: Length, inputs: &GpwsInputs) {
+        let is_landing_configuration =
+            inputs.gear_down_and_locked && self.flaps_in_landing_configuration();
+        let barometric_altitude = match inputs.barometric_altitude.normal_value() {
+            Some(barometric_altitude)
+                if !self.is_approach_mode
+                    && !is_landing_configuration
+                    && radio_altitude.get::<foot>() <= 1500. =>
+            {
+                barometric_altitude
+            }
+            _ => {
+                self.mode_3_max_barometric_altitude = None;
+                self.mode_3 = Mode3Alert::None;
+                return;
+            }
+        };
+
+        let max_altitude_loss = 0.09 * radio_altitude.get::<foot>() + 7.1;
+
+        self.mode_3 = match self.mode_3_max_barometric_altitude {
+            Some(max_altitude) if barometric_altitude <= max_altitude => {
+                if self.altitude_rate(inputs).get::<foot_per_minute>() <= 0.
+                    && (max_altitude - barometric_altitude).get::<foot>() > max_altitude_loss
+                {
+                    Mode3Alert::DontSink
+                } else {
+                    Mode3Alert::None
+                }
+            }
+            _ => {
+                self.mode_3_max_barometric_altitude = Some(barometric_altitude);
+                Mode3Alert::None
+            }
+        };
+    }
+
+    fn update_mode_4(&mut self, radio_altitude: Length, inputs: &GpwsInputs) {
+        let radio_altitude = radio_altitude.get::<foot>();
+        self.mode_4 = Mode4Alert::None;
+
+        let Some(computed_airspeed) = inputs.computed_airspeed.normal_value() else {
+            return;
+        };
+        if !(30. ..=1000.).contains(&radio_altitude) || !self.is_air_mode {
+            return;
+        }
+
+        let airspeed_knots = computed_airspeed.get::<knot>();
+        let gear_down = inputs.gear_down_and_locked;
+        let flaps_landing = self.flaps_in_landing_configuration();
+
+        if self.is_approach_mode && !gear_down {
+            // Mode 4A
+            if airspeed_knots < 190. && radio_altitude < 500. {
+                self.mode_4 = Mode4Alert::TooLowGear;
+            } else if airspeed_knots >= 190.
+                && radio_altitude < Self::mode_4a_upper_boundary(computed_airspeed, flaps_landing)
+            {
+                self.mode_4 = if flaps_landing {
+                    Mode4Alert::TooLowGear
+                } else {
+                    Mode4Alert::TooLowTerrain
+                };
+            }
+        } else if self.is_approach_mode && !flaps_landing {
+            // Mode 4B
+            if airspeed_knots < 159. && radio_altitude < 245. {
+                self.mode_4 = Mode4Alert::TooLowFlaps;
+            } else if airspeed_knots >= 159.
+                && radio_altitude < Self::mode_4b_upper_boundary(computed_airspeed, flaps_landing)
+            {
+                self.mode_4 = Mode4Alert::TooLowTerrain;
+            }
+        } else if !self.is_approach_mode && (!flaps_landing || !gear_down) {
+            // Mode 4C
+            let floor = (8.3333 * airspeed_knots - 1083.33).clamp(500., 1000.);
+            if radio_altitude < floor.min(self.mode_4_max_radio_altitude.get::<foot>()) {
+                self.mode_4 = Mode4Alert::TooLowTerrain;
+            }
+        }
+    }
+
+    fn update_mode_5(&mut self, radio_altitude: Length, inputs: &GpwsInputs) {
+        let radio_altitude = radio_altitude.get::<foot>();
+
+        self.mode_5 = match self.glideslope_deviation {
+            Some(deviation)
+                if !self.glideslope_mode_off
+                    && inputs.gear_down_and_locked
+                    && (30. ..=1000.).contains(&radio_altitude) =>
+            {
+                // One dot is approximately 0.4°.
+                let dots_below = -deviation.get::<degree>() / 0.4;
+                let soft_alert_floor = if dots_below < 2.9 {
+                    -75. * dots_below + 247.5
+                } else {
+                    30.
+                };
+                let hard_alert_floor = if dots_below < 3.8 {
+                    -66.66 * dots_below + 283.33
+                } else {
+                    30.
+                };
+
+                if dots_below > 2. && radio_altitude > hard_alert_floor && radio_altitude < 350. {
+                    Mode5Alert::GlideslopeHard
+                } else if dots_below > 1.3 && radio_altitude > soft_alert_floor {
+                    Mode5Alert::Glideslope
+                } else {
+                    Mode5Alert::None
+                }
+            }
+            _ => Mode5Alert::None,
+        };
+    }
+
+    fn flaps_in_landing_configuration(&self) -> bool {
+        self.flap_mode_off
+            || self.flaps_handle_index
+                == if self.landing_flaps_3 {
+                    Self::FLAPS_HANDLE_INDEX_3
+                } else {
+                    Self::FLAPS_HANDLE_INDEX_FULL
+                }
+    }
+
+    fn mode_4a_upper_boundary(computed_airspeed: Velocity, flaps_landing: bool) -> f64 {
+        let expanded_boundary = if flaps_landing { 500. } else { 1000. };
+
+        (8.333 * computed_airspeed.get::<knot>() - 1083.33).clamp(500., expanded_boundary)
+    }
+
+    fn mode_4b_upper_boundary(computed_airspeed: Velocity, flaps_landing: bool) -> f64 {
+        let expanded_boundary = if flaps_landing { 245. } else { 1000. };
+
+        (8.333 * computed_airspeed.get::<knot>() - 1083.33).clamp(245., expanded_boundary)
+    }
+
+    fn pull_up(&self) -> bool {
+        self.mode_1 == Mode1Alert::PullUp || self.mode_2 == Mode2Alert::PullUp
+    }
+
+    /// Whether the GPWS warning lights on the glareshield shall be illuminated.
+    fn gpws_warning(&self) -> bool {
+        self.mode_1 != Mode1Alert::None
+            || self.mode_2 != Mode2Alert::None
+            || self.mode_3 != Mode3Alert::None
+            || self.mode_4 != Mode4Alert::None
+    }
+
+    /// Whether the glideslope lights on the glareshield shall be illuminated.
+    fn glideslope_warning(&self) -> bool {
+        self.mode_5 != Mode5Alert::None
+    }
+}
+impl SimulationElement for GroundProximityWarningSystem {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.system_off = reader.read(&self.system_off_id);
+        self.glideslope_mode_off = reader.read(&self.glideslope_mode_off_id);
+        self.flap_mode_off = reader.read(&self.flap_mode_off_id);
+        self.landing_flaps_3 = reader.read(&self.landing_flaps_3_id);
+        self.flaps_handle_index = reader.read(&self.flaps_handle_index_id);
+
+        let glideslope_is_valid: bool = reader.read(&self.glideslope_is_valid_id);
+        let glideslope_deviation: f64 = reader.read(&self.glideslope_deviation_id);
+        self.glideslope_deviation =
+            glideslope_is_valid.then(|| Angle::new::<degree>(glideslope_deviation));
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.sink_rate_id, self.mode_1 == Mode1Alert::SinkRate);
+        writer.write(&self.pull_up_id, self.pull_up());
+        writer.write(&self.terrain_id, self.mode_2 == Mode2Alert::Terrain);
+        writer.write(&self.dont_sink_id, self.mode_3 == Mode3Alert::DontSink);
+        writer.write(&self.too_low_gear_id, self.mode_4 == Mode4Alert::TooLowGear);
+        writer.write(
+            &self.too_low_flaps_id,
+            self.mode_4 == Mode4Alert::TooLowFlaps,
+        );
+        writer.write(
+            &self.too_low_terrain_id,
+            self.mode_4 == Mode4Alert::TooLowTerrain,
+        );
+        writer.write(&self.glideslope_id, self.mode_5 == Mode5Alert::Glideslope);
+        writer.write(
+            &self.glideslope_hard_id,
+            self.mode_5 == Mode5Alert::GlideslopeHard,
+        );
+        writer.write(&self.gpws_warning_id, self.gpws_warning());
+        writer.write(&self.glideslope_warning_id, self.glideslope_warning());
+        writer.write(&self.air_mode_id, self.is_air_mode);
+        writer.write(&self.approach_mode_id, self.is_approach_mode);
+    }
+}
@@ -1,33 +1,46 @@
 use crate::{
     accept_iterable,
-    enhanced_gpwc::navigation_display::NavigationDisplay,
+    enhanced_gpwc::{
+        gpws::{GpwsInputs, GroundProximityWarningSystem},
+        navigation_display::NavigationDisplay,
+    },
+    failures::{Failure, FailureType},
+    navigation::{
+        adirs::AirDataInertialReferenceSystem, mmr::GpsSensor, radio_altimeter::RadioAltimeter,
+    },
     shared::{
         arinc429::{Arinc429Word, SignStatus},
-        AdirsMeasurementOutputs, ElectricalBusType, ElectricalBuses, LgciuGearExtension,
+        arinc429_bus::Arinc429Receiver,
+        AdirsBusOutputs, ElectricalBusType, ElectricalBuses, LgciuGearExtension,
     },
     simulation::{
         InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
-        SimulatorWriter, VariableIdentifier, Write,
+        SimulatorWriter, UpdateContext, VariableIdentifier, Write,
     },
 };
 use std::vec::Vec;
 use uom::si::{
     angle::degree,
     f64::{Angle, Length, Velocity},
-    velocity::foot_per_minute,
+    length::foot,
+    velocity::{foot_per_minute, knot},
 };
 
+mod gpws;
 pub mod navigation_display;
 
 pub struct EnhancedGroundProximityWarningComputer {
     powered_by: ElectricalBusType,
     is_powered: bool,
+    failure: Failure,
     fm1_destination_longitude_ssm_id: VariableIdentifier,
     fm1_destination_longitude_id: VariableIdentifier,
     fm1_destination_latitude_ssm_id: VariableIdentifier,
     fm1_destination_latitude_id: VariableIdentifier,
     destination_longitude: Arinc429Word<Angle>,
     destination_latitude: Arinc429Word<Angle>,
+    adr_receiver: Arinc429Receiver,
+    ir_receiver: Arinc429Receiver,
     latitude: Arinc429Word<Angle>,
     longitude: Arinc429Word<Angle>,
     altitude: Arinc429Word<Length>,
@@ -35,6 +48,7 @@ pub struct EnhancedGroundProximityWarningComputer {
     vertical_speed: Arinc429Word<Velocity>,
     navigation_display_range_lookup: Vec<Length>,
     navigation_displays: [NavigationDisplay; 2],
+    gpws: GroundProximityWarningSystem,
     gear_is_down: bool,
     terronnd_rendering_mode: u8,
     // output variables of the EGPWC
@@ -50,6 +64,13 @@ pub struct EnhancedGroundProximityWarningComputer {
 }
 
 impl EnhancedGroundProximityWarningComputer {
+    const ADR_ALTITUDE_LABEL: u8 = 0o203;
+    const ADR_COMPUTED_AIRSPEED_LABEL: u8 = 0o206;
+    const IR_LATITUDE_LABEL: u8 = 0o310;
+    const IR_LONGITUDE_LABEL: u8 = 0o311;
+    const IR_TRUE_HEADING_LABEL: u8 = 0o314;
+    const IR_VERTICAL_SPEED_LABEL: u8 = 0o365;
+
     pub fn new(
         context: &mut InitContext,
         powered_by: ElectricalBusType,
@@ -59,6 +80,7 @@ impl EnhancedGroundProximityWarningComputer {
         EnhancedGroundProximityWarningComputer {
             powered_by,
             is_powered: false,
+            failure: Failure::new(FailureType::Egpwc),
             fm1_destination_longitude_ssm_id: context
                 .get_identifier("FM1_DEST_LONG_SSM".to_owned()),
             fm1_destination_longitude_id: context.get_identifier("FM1_DEST_LONG".to_owned()),
@@ -66,6 +88,8 @@ impl EnhancedGroundProximityWarningComputer {
             fm1_destination_latitude_id: context.get_identifier("FM1_DEST_LAT".to_owned()),
             destination_longitude: Arinc429Word::new(Angle::default(), SignStatus::FailureWarning),
             destination_latitude: Arinc429Word::new(Angle::default(), SignStatus::FailureWarning),
+            adr_receiver: Arinc429Receiver::new(&AirDataInertialReferenceSystem::ADR_BUS_LABELS),
+            ir_receiver: Arinc429Receiver::new(&AirDataInertialReferenceSystem::IR_BUS_LABELS),
             latitude: Arinc429Word::new(Angle::default(), SignStatus::FailureWarning),
             longitude: Arinc429Word::new(Angle::default(), SignStatus::FailureWarning),
             altitude: Arinc429Word::new(Length::default(), SignStatus::FailureWarning),
@@ -76,6 +100,7 @@ impl EnhancedGroundProximityWarningComputer {
                 NavigationDisplay::new(context, "L"),
                 NavigationDisplay::new(context, "R"),
             ],
+            gpws: GroundProximityWarningSystem::new(context),
             gear_is_down: true,
             terronnd_rendering_mode,
             egpwc_destination_longitude_id: context.get_identifier("EGPWC_DEST_LONG".to_owned()),
@@ -92,25 +117,129 @@ impl EnhancedGroundProximityWarningComputer {
         }
     }
 
-    fn update_position_data(&mut self, adirs_output: &impl AdirsMeasurementOutputs) {
-        // documentation hints:
-        //   - EGPWC has direct connection to GPS sensor && ADIRS_1
-        //   - uses direct GPS data if ADIRS_1 is unavailable
-        // TODO:
-        //   - implement logic as soon as GPS sensor is available
-        self.latitude = adirs_output.latitude(1);
-        self.longitude = adirs_output.longitude(1);
-        self.altitude = adirs_output.altitude(1);
-        self.heading = adirs_output.true_heading(1);
-        self.vertical_speed = adirs_output.vertical_speed(1);
+    fn update_position_data(&mut self, gps: &impl GpsSensor) {
+        // The EGPWC has a direct connection to the GPS sensor and to ADIRS 1. It uses the
+        // direct GPS data if ADIRS 1 is unavailable or its buses went silent.
+        let adiru_latitude = self.adiru_latitude();
+        let adiru_longitude = self.adiru_longitude();
+        let adiru_position_valid =
+            adiru_latitude.is_normal_operation() && adiru_longitude.is_normal_operation();
+
+        if adiru_position_valid {
+            self.latitude = adiru_latitude;
+            self.longitude = adiru_longitude;
+        } else {
+            self.latitude = gps.latitude();
+            self.longitude = gps.longitude();
+        }
+        self.altitude = Self::valid_or_fallback(self.adiru_altitude(), gps.altitude());
+        self.heading = Self::valid_or_fallback(self.adiru_true_heading(), gps.true_track());
+        self.vertical_speed =
+            Self::valid_or_fallback(self.adiru_vertical_speed(), gps.vertical_velocity());
+    }
+
+    fn adiru_latitude(&self) -> Arinc429Word<Angle> {
+        Self::received(
+            &self.ir_receiver,
+            Self::IR_LATITUDE_LABEL,
+            Angle::new::<degree>,
+        )
+    }
+
+    fn adiru_longitude(&self) -> Arinc429Word<Angle> {
+        Self::received(
+            &self.ir_receiver,
+            Self::IR_LONGITUDE_LABEL,
+            Angle::new::<degree>,
+        )
+    }
+
+    fn adiru_true_heading(&self) -> Arinc429Word<Angle> {
+        // The heading is transmitted in the range [-180, 180).
+        Self::received(&self.ir_receiver, Self::IR_TRUE_HEADING_LABEL, |value| {
+            Angle::new::<degree>(value.rem_euclid(360.))
+        })
+    }
+
+    fn adiru_vertical_speed(&self) -> Arinc429Word<Velocity> {
+        Self::received(
+            &self.ir_receiver,
+            Self::IR_VERTICAL_SPEED_LABEL,
+            Velocity::new::<foot_per_minute>,
+        )
+    }
+
+    fn adiru_altitude(&self) -> Arinc429Word<Length> {
+        Self::received(
+            &self.adr_receiver,
+            Self::ADR_ALTITUDE_LABEL,
+            Length::new::<foot>,
+        )
+    }
+
+    fn adiru_computed_airspeed(&self) -> Arinc429Word<Velocity> {
+        Self::received(
+            &self.adr_receiver,
+            Self::ADR_COMPUTED_AIRSPEED_LABEL,
+            Velocity::new::<knot>,
+        )
+    }
+
+    /// Converts the last received word of the label, a stale label is treated as failed.
+    fn received<T: Copy + Default>(
+        receiver: &Arinc429Receiver,
+        label_number: u8,
+        to_value: impl Fn(f64) -> T,
+    ) -> Arinc429Word<T> {
+        match receiver.word(label_number) {
+            Some(word) => Arinc429Word::new(to_value(word.value()), word.ssm()),
+            None => Arinc429Word::new(T::default(), SignStatus::FailureWarning),
+        }
+    }
+
+    fn valid_or_fallback<T: Copy>(
+        primary: Arinc429Word<T>,
+        fallback: Arinc429Word<T>,
+    ) -> Arinc429Word<T> {
+        if primary.is_normal_operation() {
+            primary
+        } else {
+            fallback
+        }
+    }
+
+    fn position_data_valid(&self) -> bool {
+        self.latitude.is_normal_operation()
+            && self.longitude.is_normal_operation()
+            && self.heading.is_normal_operation()
+    }
+
+    fn radio_altitude(radio_altimeters: [&impl RadioAltimeter; 2]) -> Arinc429Word<Length> {
+        let radio_altitude = radio_altimeters[0].radio_altitude();
+        if radio_altitude.is_failure_warning() || radio_altitude.is_no_computed_data() {
+            radio_altimeters[1].radio_altitude()
+        } else {
+            radio_altitude
+        }
+    }
+
+    fn is_operative(&self) -> bool {
+        self.is_powered && !self.failure.is_active()
     }
 
     pub fn update(
         &mut self,
-        adirs_output: &impl AdirsMeasurementOutputs,
+        context: &UpdateContext,
+        adirs: &impl AdirsBusOutputs,
+        gps: &impl GpsSensor,
+        radio_altimeters: [&impl RadioAltimeter; 2],
         lgcius: &impl LgciuGearExtension,
     ) {
-        if !self.is_powered {
+        self.adr_receiver.update(context, adirs.adr_bus(1));
+        self.ir_receiver.update(context, adirs.ir_bus(1));
+
+        let is_operative = self.is_operative();
+        if !is_operative {
             self.destination_longitude =
                 Arinc429Word::new(Angle::default(), SignStatus::FailureWarning);
             self.destination_latitude =
@@ -123,16 +252,28 @@ impl EnhancedGroundProximityWarningComputer {
                 Arinc429Word::new(Velocity::default(), SignStatus::FailureWarning);
 
             self.gear_is_down = false;
+            self.gpws.reset();
         } else {
-            self.update_position_data(adirs_output);
+            self.update_position_data(gps);
             self.gear_is_down = lgcius.main_down_and_locked();
+            self.gpws.update(
+                context,
+                &GpwsInputs {
+                    radio_altitude: Self::radio_altitude(radio_altimeters),
+                    barometric_altitude: self.adiru_altitude(),
+                    computed_airspeed: self.adiru_computed_airspeed(),
+                    inertial_vertical_speed: self.vertical_speed,
+                    gear_down_and_locked: self.gear_is_down,
+                },
+            );
         }
 
+        let position_data_valid = self.position_data_valid();
         self.navigation_displays.iter_mut().for_each(|display| {
             display.update(
-                self.is_powered,
+                is_operative,
                 &self.navigation_display_range_lookup,
-                adirs_output.is_fully_aligned(1),
+                position_data_valid,
             )
         });
     }
@@ -186,6 +327,8 @@ impl SimulationElement for EnhancedGroundProximityWarningComputer {
 
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         accept_iterable!(self.navigation_displays, visitor);
+        self.gpws.accept(visitor);
+        self.failure.accept(visitor);
         visitor.visit(self);
     }
 }
@@ -195,45 +338,56 @@ mod tests {
     use super::*;
     use crate::{
         electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
-        shared::PotentialOrigin,
+        shared::{arinc429_bus::Arinc429Transmitter, PotentialOrigin},
         simulation::{
             test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
             Aircraft, InitContext, SimulationElement, SimulationElementVisitor, UpdateContext,
         },
     };
     use ntest::assert_about_eq;
+    use std::time::Duration;
     use uom::si::{
         angle::degree,
         electric_potential::volt,
         f64::*,
         length::{foot, nautical_mile},
-        velocity::foot_per_minute,
+        velocity::{foot_per_minute, knot},
     };
 
     struct TestAdirs {
-        is_aligned: bool,
         latitude: Arinc429Word<Angle>,
         longitude: Arinc429Word<Angle>,
         heading: Arinc429Word<Angle>,
         vertical_speed: Arinc429Word<Velocity>,
         altitude: Arinc429Word<Length>,
-        angle_of_attack: Arinc429Word<Angle>,
+        computed_airspeed: Arinc429Word<Velocity>,
+        adr_is_transmitting: bool,
+        ir_is_transmitting: bool,
+        adr_bus: Arinc429Transmitter,
+        ir_bus: Arinc429Transmitter,
     }
     impl TestAdirs {
         fn new() -> Self {
             Self {
-                is_aligned: false,
                 latitude: Arinc429Word::new(Angle::default(), SignStatus::FailureWarning),
                 longitude: Arinc429Word::new(Angle::default(), SignStatus::FailureWarning),
                 heading: Arinc429Word::new(Angle::default(), SignStatus::FailureWarning),
                 vertical_speed: Arinc429Word::new(Velocity::default(), SignStatus::FailureWarning),
                 altitude: Arinc429Word::new(Length::default(), SignStatus::FailureWarning),
-                angle_of_attack: Arinc429Word::new(Angle::default(), SignStatus::FailureWarning),
+                computed_airspeed: Arinc429Word::new(
+                    Velocity::default(),
+                    SignStatus::FailureWarning,
+                ),
+                adr_is_transmitting: true,
+                ir_is_transmitting: true,
+                adr_bus: Arinc429Transmitter::new(&AirDataInertialReferenceSystem::ADR_BUS_LABELS)
+                    .with_sdi(1),
+                ir_bus: Arinc429Transmitter::new(&AirDataInertialReferenceSystem::IR_BUS_LABELS)
+                    .with_sdi(1),
             }
         }
 
         fn initialize(&mut self) {
-            self.is_aligned = true;
             self.latitude =
                 Arinc429Word::new(Angle::new::<degree>(20.3), SignStatus::NormalOperation);
             self.longitude =
@@ -246,41 +400,161 @@ mod tests {
             );
             self.altitude =
                 Arinc429Word::new(Length::new::<foot>(15000.0), SignStatus::NormalOperation);
-            self.angle_of_attack =
-                Arinc429Word::new(Angle::new::<degree>(0.0), SignStatus::NormalOperation);
+            self.computed_airspeed =
+                Arinc429Word::new(Velocity::new::<knot>(250.0), SignStatus::NormalOperation);
+        }
+
+        fn set_vertical_speed(&mut self, vertical_speed: Velocity) {
+            self.vertical_speed = Arinc429Word::new(vertical_speed, SignStatus::NormalOperation);
+        }
+
+        fn set_altitude(&mut self, altitude: Length) {
+            self.altitude = Arinc429Word::new(altitude, SignStatus::NormalOperation);
+        }
+
+        fn set_computed_airspeed(&mut self, computed_airspeed: Velocity) {
+            self.computed_airspeed =
+                Arinc429Word::new(computed_airspeed, SignStatus::NormalOperation);
+        }
+
+        fn set_adr_transmitting(&mut self, is_transmitting: bool) {
+            self.adr_is_transmitting = is_transmitting;
+        }
+
+        fn set_ir_transmitting(&mut self, is_transmitting: bool) {
+            self.ir_is_transmitting = is_transmitting;
+        }
+
+        fn update(&mut self, context: &UpdateContext) {
+            let degrees = |word: Arinc429Word<Angle>| {
+                Arinc429Word::new(word.value().get::<degree>(), word.ssm())
+            };
+
+            self.adr_bus.set(
+                0o203,
+                Arinc429Word::new(self.altitude.value().get::<foot>(), self.altitude.ssm()),
+            );
+            self.adr_bus.set(
+                0o206,
+                Arinc429Word::new(
+                    self.computed_airspeed.value().get::<knot>(),
+                    self.computed_airspeed.ssm(),
+                ),
+            );
+            self.adr_bus.update(context, self.adr_is_transmitting);
+
+            self.ir_bus.set(0o310, degrees(self.latitude));
+            self.ir_bus.set(0o311, degrees(self.longitude));
+            self.ir_bus.set(
+                0o314,
+                Arinc429Word::new(
+                    (self.heading.value().get::<degree>() + 180.).rem_euclid(360.) - 180.,
+                    self.heading.ssm(),
+                ),
+            );
+            self.ir_bus.set(
+                0o365,
+                Arinc429Word::new(
+                    self.vertical_speed.value().get::<foot_per_minute>(),
+                    self.vertical_speed.ssm(),
+                ),
+            );
+            self.ir_bus.update(context, self.ir_is_transmitting);
+        }
+    }
+    impl AdirsBusOutputs for TestAdirs {
+        fn adr_bus(&self, _adiru_number: usize) -> &Arinc429Transmitter {
+            &self.adr_bus
+        }
+
+        fn ir_bus(&self, _adiru_number: usize) -> &Arinc429Transmitter {
+            &self.ir_bus
         }
     }
-    impl AdirsMeasurementOutputs for TestAdirs {
-        fn is_fully_aligned(&self, _adiru_number: usize) -> bool {
-            self.is_aligned
+
+    struct TestGpsSensor {
+        latitude: Arinc429Word<Angle>,
+        longitude: Arinc429Word<Angle>,
+        altitude: Arinc429Word<Length>,
+        true_track: Arinc429Word<Angle>,
+        vertical_velocity: Arinc429Word<Velocity>,
+    }
+    impl TestGpsSensor {
+        fn new() -> Self {
+            Self {
+                latitude: Arinc429Word::new(Angle::default(), SignStatus::FailureWarning),
+                longitude: Arinc429Word::new(Angle::default(), SignStatus::FailureWarning),
+                altitude: Arinc429Word::new(Length::default(), SignStatus::FailureWarning),
+                true_track: Arinc429Word::new(Angle::default(), SignStatus::FailureWarning),
+                vertical_velocity: Arinc429Word::new(
+                    Velocity::default(),
+                    SignStatus::FailureWarning,
+                ),
+            }
         }
 
-        fn latitude(&self, _adiru_number: usize) -> Arinc429Word<Angle> {
+        fn acquire_position(&mut self) {
+            self.latitude =
+                Arinc429Word::new(Angle::new::<degree>(20.4), SignStatus::NormalOperation);
+            self.longitude =
+                Arinc429Word::new(Angle::new::<degree>(30.4), SignStatus::NormalOperation);
+            self.altitude =
+                Arinc429Word::new(Length::new::<foot>(14900.0), SignStatus::NormalOperation);
+            self.true_track =
+                Arinc429Word::new(Angle::new::<degree>(305.0), SignStatus::NormalOperation);
+            self.vertical_velocity = Arinc429Word::new(
+                Velocity::new::<foot_per_minute>(1200.0),
+                SignStatus::NormalOperation,
+            );
+        }
+    }
+    impl GpsSensor for TestGpsSensor {
+        fn latitude(&self) -> Arinc429Word<Angle> {
             self.latitude
         }
 
-        fn longitude(&self, _adiru_number: usize) -> Arinc429Word<Angle> {
+        fn longitude(&self) -> Arinc429Word<Angle> {
             self.longitude
         }
 
-        fn heading(&self, _adiru_number: usize) -> Arinc429Word<Angle> {
-            self.heading
+        fn altitude(&self) -> Arinc429Word<Length> {
+            self.altitude
         }
 
-        fn true_heading(&self, _adiru_number: usize) -> Arinc429Word<Angle> {
-            self.heading
+        fn horizontal_figure_of_merit(&self) -> Arinc429Word<Length> {
+            Arinc429Word::new(Length::new::<nautical_mile>(0.02), self.latitude.ssm())
         }
 
-        fn vertical_speed(&self, _adiru_number: usize) -> Arinc429Word<Velocity> {
-            self.vertical_speed
+        fn true_track(&self) -> Arinc429Word<Angle> {
+            self.true_track
         }
 
-        fn altitude(&self, _adiru_number: usize) -> Arinc429Word<Length> {
-            self.altitude
+        fn ground_speed(&self) -> Arinc429Word<Velocity> {
+            Arinc429Word::new(Velocity::default(), self.latitude.ssm())
+        }
+
+        fn vertical_velocity(&self) -> Arinc429Word<Velocity> {
+            self.vertical_velocity
         }
+    }
 
-        fn angle_of_attack(&self, _adiru_number: usize) -> Arinc429Word<Angle> {
-            self.angle_of_attack
+    struct TestRadioAltimeter {
+        radio_altitude: Arinc429Word<Length>,
+    }
+    impl TestRadioAltimeter {
+        fn new() -> Self {
+            Self {
+                radio_altitude: Arinc429Word::new(Length::default(), SignStatus::FailureWarning),
+            }
+        }
+
+        fn set_radio_altitude(&mut self, radio_altitude: Length) {
+            self.radio_altitude = Arinc429Word::new(radio_altitude, SignStatus::NormalOperation);
+        }
+    }
+    impl RadioAltimeter for TestRadioAltimeter {
+        fn radio_altitude(&self) -> Arinc429Word<Length> {
+            self.radio_altitude
         }
     }
 
@@ -325,6 +599,8 @@ mod tests {
 
     struct EgpwcTestAircraft {
         adirs: TestAdirs,
+        gps: TestGpsSensor,
+        radio_altimeters: [TestRadioAltimeter; 2],
         lgciu: TestLgciu,
         egpwc: EnhancedGroundProximityWarningComputer,
         powered_source_dc: TestElectricitySource,
@@ -335,6 +611,8 @@ mod tests {
         fn new(context: &mut InitContext) -> Self {
             Self {
                 adirs: TestAdirs::new(),
+                gps: TestGpsSensor::new(),
+                radio_altimeters: [TestRadioAltimeter::new(), TestRadioAltimeter::new()],
                 lgciu: TestLgciu::new(),
                 egpwc: EnhancedGroundProximityWarningComputer::new(
                     context,
@@ -358,18 +636,59 @@ mod tests {
             }
         }
 
-        fn update(&mut self) {
-            self.egpwc.update(&self.adirs, &self.lgciu);
+        fn update(&mut self, context: &UpdateContext) {
+            self.adirs.update(context);
+            self.egpwc.update(
+                context,
+                &self.adirs,
+                &self.gps,
+                [&self.radio_altimeters[0], &self.radio_altimeters[1]],
+                &self.lgciu,
+            );
         }
 
         fn initialize_adiru(&mut self) {
             self.adirs.initialize();
         }
 
+        fn acquire_gps_position(&mut self) {
+            self.gps.acquire_position();
+        }
+
         fn gear_down(&mut self) {
             self.lgciu.set_gear_down(true);
         }
 
+        fn gear_up(&mut self) {
+            self.lgciu.set_gear_down(false);
+        }
+
+        fn set_radio_altitude(&mut self, radio_altitude: Length) {
+            self.radio_altimeters
+                .iter_mut()
+                .for_each(|radio_altimeter| radio_altimeter.set_radio_altitude(radio_altitude));
+        }
+
+        fn set_vertical_speed(&mut self, vertical_speed: Velocity) {
+            self.adirs.set_vertical_speed(vertical_speed);
+        }
+
+        fn set_barometric_altitude(&mut self, altitude: Length) {
+            self.adirs.set_altitude(altitude);
+        }
+
+        fn set_computed_airspeed(&mut self, computed_airspeed: Velocity) {
+            self.adirs.set_computed_airspeed(computed_airspeed);
+        }
+
+        fn set_adr_transmitting(&mut self, is_transmitting: bool) {
+            self.adirs.set_adr_transmitting(is_transmitting);
+        }
+
+        fn set_ir_transmitting(&mut self, is_transmitting: bool) {
+            self.adirs.set_ir_transmitting(is_transmitting);
+        }
+
         fn set_elec_powered(&mut self, is_powered: bool) {
             self.is_elec_powered = is_powered;
         }
@@ -389,8 +708,8 @@ mod tests {
             }
         }
 
-        fn update_after_power_distribution(&mut self, _: &UpdateContext) {
-            self.update();
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.update(context);
         }
     }
     impl SimulationElement for EgpwcTestAircraft {
@@ -400,6 +719,14 @@ mod tests {
         }
     }
 
+    /// Smallest angle the label transmitting it on the IR bus can tell apart.
+    fn ir_resolution(label: u8) -> f64 {
+        AirDataInertialReferenceSystem::IR_BUS_LABELS
+            .iter()
+            .find(|ir_label| ir_label.number() == label)
+            .map_or(0., |ir_label| ir_label.resolution())
+    }
+
     #[test]
     fn not_power_up() {
         let mut test_bed = SimulationTestBed::new(EgpwcTestAircraft::new);
@@ -531,15 +858,15 @@ mod tests {
         assert!(destination_long.is_failure_warning());
         let present_lat: Arinc429Word<Angle> = test_bed.read_arinc429_by_name("EGPWC_PRESENT_LAT");
         assert!(present_lat.is_normal_operation());
-        assert_about_eq!(present_lat.value().get::<degree>(), 20.3);
+        assert_about_eq!(present_lat.value().get::<degree>(), 20.3, ir_resolution(0o310));
         let present_long: Arinc429Word<Angle> =
             test_bed.read_arinc429_by_name("EGPWC_PRESENT_LONG");
         assert!(present_long.is_normal_operation());
-        assert_about_eq!(present_long.value().get::<degree>(), 30.3);
+        assert_about_eq!(present_long.value().get::<degree>(), 30.3, ir_resolution(0o311));
         let present_heading: Arinc429Word<Angle> =
             test_bed.read_arinc429_by_name("EGPWC_PRESENT_HEADING");
         assert!(present_heading.is_normal_operation());
-        assert_about_eq!(present_heading.value().get::<degree>(), 310.0);
+        assert_about_eq!(present_heading.value().get::<degree>(), 310.0, ir_resolution(0o314));
         let present_altitude: Arinc429Word<Length> =
             test_bed.read_arinc429_by_name("EGPWC_PRESENT_ALTITUDE");
         assert!(present_altitude.is_normal_operation());
@@ -594,15 +921,15 @@ mod tests {
         assert_about_eq!(destination_long.value().get::<degree>(), 25.3);
         let present_lat: Arinc429Word<Angle> = test_bed.read_arinc429_by_name("EGPWC_PRESENT_LAT");
         assert!(present_lat.is_normal_operation());
-        assert_about_eq!(present_lat.value().get::<degree>(), 20.3);
+        assert_about_eq!(present_lat.value().get::<degree>(), 20.3, ir_resolution(0o310));
         let present_long: Arinc429Word<Angle> =
             test_bed.read_arinc429_by_name("EGPWC_PRESENT_LONG");
         assert!(present_long.is_normal_operation());
-        assert_about_eq!(present_long.value().get::<degree>(), 30.3);
+        assert_about_eq!(present_long.value().get::<degree>(), 30.3, ir_resolution(0o311));
         let present_heading: Arinc429Word<Angle> =
             test_bed.read_arinc429_by_name("EGPWC_PRESENT_HEADING");
         assert!(present_heading.is_normal_operation());
-        assert_about_eq!(present_heading.value().get::<degree>(), 310.0);
+        assert_about_eq!(present_heading.value().get::<degree>(), 310.0, ir_resolution(0o314));
         let present_altitude: Arinc429Word<Length> =
             test_bed.read_arinc429_by_name("EGPWC_PRESENT_ALTITUDE");
         assert!(present_altitude.is_normal_operation());
@@ -625,4 +952,330 @@ mod tests {
         let nd_terr_activate_fo: bool = test_bed.read_by_name("EGPWC_ND_R_TERRAIN_ACTIVE");
         assert!(!nd_terr_activate_fo);
     }
+
+    #[test]
+    fn powered_up_uses_gps_when_adiru_is_unavailable() {
+        let mut test_bed = SimulationTestBed::new(EgpwcTestAircraft::new);
+
+        test_bed.write_by_name("EFIS_L_ND_RANGE", 1);
+        test_bed.write_by_name("EFIS_L_ND_MODE", 3);
+        test_bed.write_by_name("EFIS_TERR_L_ACTIVE", 1);
+
+        test_bed.command(|a| a.set_elec_powered(true));
+        test_bed.command(|a| a.acquire_gps_position());
+        test_bed.run();
+
+        let present_lat: Arinc429Word<Angle> = test_bed.read_arinc429_by_name("EGPWC_PRESENT_LAT");
+        assert!(present_lat.is_normal_operation());
+        assert_about_eq!(present_lat.value().get::<degree>(), 20.4);
+        let present_long: Arinc429Word<Angle> =
+            test_bed.read_arinc429_by_name("EGPWC_PRESENT_LONG");
+        assert!(present_long.is_normal_operation());
+        assert_about_eq!(present_long.value().get::<degree>(), 30.4);
+        let present_heading: Arinc429Word<Angle> =
+            test_bed.read_arinc429_by_name("EGPWC_PRESENT_HEADING");
+        assert!(present_heading.is_normal_operation());
+        assert_about_eq!(present_heading.value().get::<degree>(), 305.0);
+        let present_altitude: Arinc429Word<Length> =
+            test_bed.read_arinc429_by_name("EGPWC_PRESENT_ALTITUDE");
+        assert!(present_altitude.is_normal_operation());
+        assert_about_eq!(present_altitude.value().get::<foot>(), 14900.0);
+
+        let nd_terr_activate_capt: bool = test_bed.read_by_name("EGPWC_ND_L_TERRAIN_ACTIVE");
+        assert!(nd_terr_activate_capt);
+    }
+
+    #[test]
+    fn powered_up_prefers_adiru_over_gps() {
+        let mut test_bed = SimulationTestBed::new(EgpwcTestAircraft::new);
+
+        test_bed.command(|a| a.set_elec_powered(true));
+        test_bed.command(|a| a.initialize_adiru());
+        test_bed.command(|a| a.acquire_gps_position());
+        test_bed.run();
+
+        let present_lat: Arinc429Word<Angle> = test_bed.read_arinc429_by_name("EGPWC_PRESENT_LAT");
+        assert_about_eq!(present_lat.value().get::<degree>(), 20.3, ir_resolution(0o310));
+        let present_long: Arinc429Word<Angle> =
+            test_bed.read_arinc429_by_name("EGPWC_PRESENT_LONG");
+        assert_about_eq!(present_long.value().get::<degree>(), 30.3, ir_resolution(0o311));
+        let present_heading: Arinc429Word<Angle> =
+            test_bed.read_arinc429_by_name("EGPWC_PRESENT_HEADING");
+        assert_about_eq!(present_heading.value().get::<degree>(), 310.0, ir_resolution(0o314));
+    }
+
+    #[test]
+    fn silent_adr_bus_falls_back_to_gps_altitude() {
+        let mut test_bed = SimulationTestBed::new(EgpwcTestAircraft::new);
+
+        test_bed.command(|a| a.set_elec_powered(true));
+        test_bed.command(|a| a.initialize_adiru());
+        test_bed.command(|a| a.acquire_gps_position());
+        test_bed.run();
+
+        let present_altitude: Arinc429Word<Length> =
+            test_bed.read_arinc429_by_name("EGPWC_PRESENT_ALTITUDE");
+        assert_about_eq!(present_altitude.value().get::<foot>(), 15000.0);
+
+        test_bed.command(|a| a.set_adr_transmitting(false));
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        let present_altitude: Arinc429Word<Length> =
+            test_bed.read_arinc429_by_name("EGPWC_PRESENT_ALTITUDE");
+        assert!(present_altitude.is_normal_operation());
+        assert_about_eq!(present_altitude.value().get::<foot>(), 14900.0);
+        let present_lat: Arinc429Word<Angle> = test_bed.read_arinc429_by_name("EGPWC_PRESENT_LAT");
+        assert_about_eq!(present_lat.value().get::<degree>(), 20.3, ir_resolution(0o310));
+    }
+
+    #[test]
+    fn silent_ir_bus_falls_back_to_gps_position() {
+        let mut test_bed = SimulationTestBed::new(EgpwcTestAircraft::new);
+
+        test_bed.command(|a| a.set_elec_powered(true));
+        test_bed.command(|a| a.initialize_adiru());
+        test_bed.command(|a| a.acquire_gps_position());
+        test_bed.run();
+        test_bed.command(|a| a.set_ir_transmitting(false));
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        let present_lat: Arinc429Word<Angle> = test_bed.read_arinc429_by_name("EGPWC_PRESENT_LAT");
+        assert!(present_lat.is_normal_operation());
+        assert_about_eq!(present_lat.value().get::<degree>(), 20.4);
+        let present_heading: Arinc429Word<Angle> =
+            test_bed.read_arinc429_by_name("EGPWC_PRESENT_HEADING");
+        assert_about_eq!(present_heading.value().get::<degree>(), 305.0);
+    }
+
+    #[test]
+    fn silent_adr_bus_without_gps_fails_the_altitude() {
+        let mut test_bed = SimulationTestBed::new(EgpwcTestAircraft::new);
+
+        test_bed.command(|a| a.set_elec_powered(true));
+        test_bed.command(|a| a.initialize_adiru());
+        test_bed.run();
+        test_bed.command(|a| a.set_adr_transmitting(false));
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        let present_altitude: Arinc429Word<Length> =
+            test_bed.read_arinc429_by_name("EGPWC_PRESENT_ALTITUDE");
+        assert!(!present_altitude.is_normal_operation());
+    }
+
+    fn gpws_test_bed() -> SimulationTestBed<EgpwcTestAircraft> {
+        let mut test_bed = SimulationTestBed::new(EgpwcTestAircraft::new);
+
+        test_bed.command(|a| a.set_elec_powered(true));
+        test_bed.command(|a| a.initialize_adiru());
+        test_bed.command(|a| a.gear_up());
+        test_bed.command(|a| a.set_computed_airspeed(Velocity::new::<knot>(250.)));
+        test_bed.command(|a| a.set_vertical_speed(Velocity::new::<foot_per_minute>(0.)));
+
+        test_bed
+    }
+
+    fn establish_approach_mode(test_bed: &mut SimulationTestBed<EgpwcTestAircraft>) {
+        test_bed.command(|a| a.set_computed_airspeed(Velocity::new::<knot>(150.)));
+        test_bed.command(|a| a.set_radio_altitude(Length::new::<foot>(1000.)));
+        test_bed.run();
+
+        let approach_mode: bool = test_bed.read_by_name("EGPWC_GPWS_APPROACH_MODE");
+        assert!(approach_mode);
+    }
+
+    #[test]
+    fn gpws_mode_1_sink_rate() {
+        let mut test_bed = gpws_test_bed();
+
+        test_bed.command(|a| a.set_radio_altitude(Length::new::<foot>(1000.)));
+        test_bed.command(|a| a.set_vertical_speed(Velocity::new::<foot_per_minute>(-3000.)));
+        test_bed.run();
+
+        let sink_rate: bool = test_bed.read_by_name("EGPWC_GPWS_SINK_RATE");
+        assert!(sink_rate);
+        let pull_up: bool = test_bed.read_by_name("EGPWC_GPWS_PULL_UP");
+        assert!(!pull_up);
+        let gpws_warning: bool = test_bed.read_by_name("EGPWC_GPWS_WARNING");
+        assert!(gpws_warning);
+    }
+
+    #[test]
+    fn gpws_mode_1_pull_up() {
+        let mut test_bed = gpws_test_bed();
+
+        test_bed.command(|a| a.set_radio_altitude(Length::new::<foot>(500.)));
+        test_bed.command(|a| a.set_vertical_speed(Velocity::new::<foot_per_minute>(-3000.)));
+        test_bed.run();
+
+        let sink_rate: bool = test_bed.read_by_name("EGPWC_GPWS_SINK_RATE");
+        assert!(!sink_rate);
+        let pull_up: bool = test_bed.read_by_name("EGPWC_GPWS_PULL_UP");
+        assert!(pull_up);
+    }
+
+    #[test]
+    fn gpws_no_alert_when_system_off() {
+        let mut test_bed = gpws_test_bed();
+
+        test_bed.write_by_name("GPWS_SYS_OFF", true);
+        test_bed.command(|a| a.set_radio_altitude(Length::new::<foot>(500.)));
+        test_bed.command(|a| a.set_vertical_speed(Velocity::new::<foot_per_minute>(-3000.)));
+        test_bed.run();
+
+        let pull_up: bool = test_bed.read_by_name("EGPWC_GPWS_PULL_UP");
+        assert!(!pull_up);
+        let gpws_warning: bool = test_bed.read_by_name("EGPWC_GPWS_WARNING");
+        assert!(!gpws_warning);
+    }
+
+    #[test]
+    fn gpws_no_alert_when_unpowered() {
+        let mut test_bed = gpws_test_bed();
+
+        test_bed.command(|a| a.set_elec_powered(false));
+        test_bed.command(|a| a.set_radio_altitude(Length::new::<foot>(500.)));
+        test_bed.command(|a| a.set_vertical_speed(Velocity::new::<foot_per_minute>(-3000.)));
+        test_bed.run();
+
+        let pull_up: bool = test_bed.read_by_name("EGPWC_GPWS_PULL_UP");
+        assert!(!pull_up);
+        let gpws_warning: bool = test_bed.read_by_name("EGPWC_GPWS_WARNING");
+        assert!(!gpws_warning);
+    }
+
+    #[test]
+    fn gpws_no_alert_when_failed() {
+        let mut test_bed = gpws_test_bed();
+
+        test_bed.fail(FailureType::Egpwc);
+        test_bed.command(|a| a.set_radio_altitude(Length::new::<foot>(500.)));
+        test_bed.command(|a| a.set_vertical_speed(Velocity::new::<foot_per_minute>(-3000.)));
+        test_bed.run();
+
+        let pull_up: bool = test_bed.read_by_name("EGPWC_GPWS_PULL_UP");
+        assert!(!pull_up);
+        let gpws_warning: bool = test_bed.read_by_name("EGPWC_GPWS_WARNING");
+        assert!(!gpws_warning);
+        let present_lat: Arinc429Word<Angle> = test_bed.read_arinc429_by_name("EGPWC_PRESENT_LAT");
+        assert!(present_lat.is_failure_warning());
+    }
+
+    #[test]
+    fn gpws_mode_2_terrain_then_pull_up() {
+        let mut test_bed = gpws_test_bed();
+
+        let mut terrain_time = None;
+        let mut pull_up_time = None;
+        let mut radio_altitude = 1700.;
+        let mut time = Duration::ZERO;
+        while radio_altitude > 1000. {
+            test_bed.command(|a| a.set_radio_altitude(Length::new::<foot>(radio_altitude)));
+            test_bed.run_with_delta(Duration::from_millis(100));
+
+            let terrain: bool = test_bed.read_by_name("EGPWC_GPWS_TERRAIN");
+            let pull_up: bool = test_bed.read_by_name("EGPWC_GPWS_PULL_UP");
+            if terrain && terrain_time.is_none() {
+                terrain_time = Some(time);
+            }
+            if pull_up && pull_up_time.is_none() {
+                pull_up_time = Some(time);
+            }
+
+            // Descend towards the terrain at 5000 ft/min.
+            radio_altitude -= 5000. / 600.;
+            time += Duration::from_millis(100);
+        }
+
+        let terrain_time = terrain_time.expect("TERRAIN alert not triggered");
+        let pull_up_time = pull_up_time.expect("PULL UP alert not triggered");
+        assert!(pull_up_time > terrain_time);
+    }
+
+    #[test]
+    fn gpws_mode_3_dont_sink_after_takeoff() {
+        let mut test_bed = gpws_test_bed();
+
+        test_bed.command(|a| a.set_computed_airspeed(Velocity::new::<knot>(150.)));
+        test_bed.command(|a| a.set_radio_altitude(Length::new::<foot>(300.)));
+        test_bed.command(|a| a.set_barometric_altitude(Length::new::<foot>(1000.)));
+        test_bed.command(|a| a.set_vertical_speed(Velocity::new::<foot_per_minute>(500.)));
+        test_bed.run();
+
+        let dont_sink: bool = test_bed.read_by_name("EGPWC_GPWS_DONT_SINK");
+        assert!(!dont_sink);
+
+        test_bed.command(|a| a.set_barometric_altitude(Length::new::<foot>(950.)));
+        test_bed.command(|a| a.set_vertical_speed(Velocity::new::<foot_per_minute>(-500.)));
+        test_bed.run();
+
+        let dont_sink: bool = test_bed.read_by_name("EGPWC_GPWS_DONT_SINK");
+        assert!(dont_sink);
+    }
+
+    #[test]
+    fn gpws_mode_4_too_low_gear() {
+        let mut test_bed = gpws_test_bed();
+        establish_approach_mode(&mut test_bed);
+
+        test_bed.command(|a| a.set_radio_altitude(Length::new::<foot>(400.)));
+        test_bed.run();
+
+        let too_low_gear: bool = test_bed.read_by_name("EGPWC_GPWS_TOO_LOW_GEAR");
+        assert!(too_low_gear);
+
+        test_bed.command(|a| a.gear_down());
+        test_bed.run();
+
+        let too_low_gear: bool = test_bed.read_by_name("EGPWC_GPWS_TOO_LOW_GEAR");
+        assert!(!too_low_gear);
+    }
+
+    #[test]
+    fn gpws_mode_4_too_low_flaps() {
+        let mut test_bed = gpws_test_bed();
+        establish_approach_mode(&mut test_bed);
+
+        test_bed.write_by_name("FLAPS_HANDLE_INDEX", 2);
+        test_bed.command(|a| a.gear_down());
+        test_bed.command(|a| a.set_radio_altitude(Length::new::<foot>(200.)));
+        test_bed.run();
+
+        let too_low_flaps: bool = test_bed.read_by_name("EGPWC_GPWS_TOO_LOW_FLAPS");
+        assert!(too_low_flaps);
+
+        test_bed.write_by_name("GPWS_FLAP_OFF", true);
+        test_bed.run();
+
+        let too_low_flaps: bool = test_bed.read_by_name("EGPWC_GPWS_TOO_LOW_FLAPS");
+        assert!(!too_low_flaps);
+    }
+
+    #[test]
+    fn gpws_mode_5_glideslope() {
+        let mut test_bed = gpws_test_bed();
+
+        test_bed.write_by_name("RADIO_RECEIVER_GS_IS_VALID", true);
+        test_bed.write_by_name("RADIO_RECEIVER_GS_DEVIATION", -0.6);
+        test_bed.command(|a| a.gear_down());
+        test_bed.command(|a| a.set_radio_altitude(Length::new::<foot>(500.)));
+        test_bed.run();
+
+        let glideslope: bool = test_bed.read_by_name("EGPWC_GPWS_GLIDESLOPE");
+        assert!(glideslope);
+        let glideslope_warning: bool = test_bed.read_by_name("EGPWC_GPWS_GLIDESLOPE_WARNING");
+        assert!(glideslope_warning);
+
+        test_bed.write_by_name("RADIO_RECEIVER_GS_DEVIATION", -1.0);
+        test_bed.command(|a| a.set_radio_altitude(Length::new::<foot>(300.)));
+        test_bed.run();
+
+        let glideslope_hard: bool = test_bed.read_by_name("EGPWC_GPWS_GLIDESLOPE_HARD");
+        assert!(glideslope_hard);
+
+        test_bed.write_by_name("GPWS_GS_OFF", true);
+        test_bed.run();
+
+        let glideslope_warning: bool = test_bed.read_by_name("EGPWC_GPWS_GLIDESLOPE_WARNING");
+        assert!(!glideslope_warning);
+    }
 }
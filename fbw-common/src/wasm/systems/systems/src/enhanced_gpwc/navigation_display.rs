@@ -44,9 +44,10 @@ impl NavigationDisplay {
         }
     }
 
-    pub fn update(&mut self, is_powered: bool, range_lookup: &[Length], adiru_data_valid: bool) {
+    pub fn update(&mut self, is_powered: bool, range_lookup: &[Length], position_data_valid: bool) {
         self.range = range_lookup[self.range_knob_position];
-        self.terrain_on_nd_active = adiru_data_valid && self.terrain_on_nd_pb_active && is_powered;
+        self.terrain_on_nd_active =
+            position_data_valid && self.terrain_on_nd_pb_active && is_powered;
     }
 }
 
@@ -0,0 +1,315 @@
+use std::time::Duration;
+
+use crate::shared::random_from_range;
+
+use super::FailureType;
+
+/// Declares that the activation of a cause can lead to the activation of an effect, e.g. an
+/// engine fire burning through a fire detection loop. Dependencies are usually declared in a
+/// static list per aircraft, which forms the graph through which failures cascade.
+#[derive(Clone, Copy)]
+pub struct FailureDependency {
+    cause: FailureType,
+    effect: FailureType,
+    delay: Duration,
+    probability: f64,
+}
+impl FailureDependency {
+    /// The effect activates the given delay after the cause, with the given probability
+    /// between 0 and 1. Whether it activates is decided when the cause activates.
+    pub const fn new(
+        cause: FailureType,
+        effect: FailureType,
+        delay: Duration,
+        probability: f64,
+    ) -> Self {
+        Self {
+            cause,
+            effect,
+            delay,
+            probability,
+        }
+    }
+
+    pub fn cause(&self) -> FailureType {
+        self.cause
+    }
+
+    pub fn effect(&self) -> FailureType {
+        self.effect
+    }
+}
+
+/// Where an active failure originates from.
+#[derive(Clone, Copy, PartialEq)]
+pub enum FailureOrigin {
+    /// Activated from outside of the simulation, e.g. by the failures UI or a test.
+    Commanded,
+    /// Activated by the failure scheduler.
+    Scheduled,
+    /// Activated as the effect of another failure.
+    Cascaded(FailureType),
+}
+
+#[derive(Clone, Copy)]
+pub struct ActiveFailure {
+    failure_type: FailureType,
+    origin: FailureOrigin,
+}
+impl ActiveFailure {
+    pub(crate) fn new(failure_type: FailureType, origin: FailureOrigin) -> Self {
+        Self {
+            failure_type,
+            origin,
+        }
+    }
+
+    pub fn failure_type(&self) -> FailureType {
+        self.failure_type
+    }
+
+    pub fn origin(&self) -> FailureOrigin {
+        self.origin
+    }
+}
+
+struct PendingEffect {
+    cause: FailureType,
+    effect: FailureType,
+    remaining: Duration,
+}
+
+/// Keeps track of the effects of active failures which are yet to activate.
+/// An effect which didn't activate yet is cancelled when its cause deactivates. Effects which
+/// did activate stay active, as the damage is done.
+#[derive(Default)]
+pub struct FailureCascade {
+    dependencies: Vec<FailureDependency>,
+    pending: Vec<PendingEffect>,
+}
+impl FailureCascade {
+    pub fn new(dependencies: Vec<FailureDependency>) -> Self {
+        Self {
+            dependencies,
+            pending: Vec::new(),
+        }
+    }
+
+    /// Decides which effects of the cause will activate. Returns those without delay, which
+    /// activate immediately.
+    pub fn cause_activated(&mut self, cause: FailureType) -> Vec<FailureType> {
+        let mut immediate = Vec::new();
+        for dependency in self
+            .dependencies
+            .iter()
+            .filter(|dependency| dependency.cause == cause)
+        {
+            if random_from_range(0., 1.) >= dependency.probability {
+                continue;
+            }
+
+            if dependency.delay.is_zero() {
+                immediate.push(dependency.effect);
+            } else {
+                self.pending.push(PendingEffect {
+                    cause,
+                    effect: dependency.effect,
+                    remaining: dependency.delay,
+                });
+            }
+        }
+
+        immediate
+    }
+
+    pub fn cause_deactivated(&mut self, cause: FailureType) {
+        self.pending.retain(|pending| pending.cause != cause);
+    }
+
+    /// Advances the pending effects by the given delta. Returns the cause and effect of every
+    /// effect which is due.
+    pub fn update(&mut self, delta: Duration) -> Vec<(FailureType, FailureType)> {
+        let mut due = Vec::new();
+        self.pending.retain_mut(|pending| {
+            pending.remaining = pending.remaining.saturating_sub(delta);
+            let is_due = pending.remaining.is_zero();
+            if is_due {
+                due.push((pending.cause, pending.effect));
+            }
+
+            !is_due
+        });
+
+        due
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        failures::{scheduler::FailureTrigger, Failure},
+        simulation::{
+            test::{SimulationTestBed, TestBed},
+            Aircraft, InitContext, SimulationElement, SimulationElementVisitor,
+        },
+    };
+
+    const ROOT: FailureType = FailureType::Generator(1);
+    const SECONDARY: FailureType = FailureType::Generator(2);
+    const TERTIARY: FailureType = FailureType::StaticInverter;
+
+    struct TestAircraft {
+        failures: [Failure; 3],
+    }
+    impl TestAircraft {
+        fn new(_: &mut InitContext) -> Self {
+            Self {
+                failures: [
+                    Failure::new(ROOT),
+                    Failure::new(SECONDARY),
+                    Failure::new(TERTIARY),
+                ],
+            }
+        }
+
+        fn is_active(&self, failure_type: FailureType) -> bool {
+            self.failures
+                .iter()
+                .any(|failure| failure.failure_type() == failure_type && failure.is_active())
+        }
+    }
+    impl Aircraft for TestAircraft {}
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            for failure in &mut self.failures {
+                failure.accept(visitor);
+            }
+
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed(dependencies: &[FailureDependency]) -> SimulationTestBed<TestAircraft> {
+        SimulationTestBed::new(TestAircraft::new).with_failure_dependencies(dependencies)
+    }
+
+    fn origin_of(
+        test_bed: &SimulationTestBed<TestAircraft>,
+        failure_type: FailureType,
+    ) -> Option<FailureOrigin> {
+        test_bed
+            .active_failures()
+            .iter()
+            .find(|active| active.failure_type() == failure_type)
+            .map(|active| active.origin())
+    }
+
+    #[test]
+    fn effect_activates_after_the_delay() {
+        let mut test_bed = test_bed(&[FailureDependency::new(
+            ROOT,
+            SECONDARY,
+            Duration::from_secs(10),
+            1.,
+        )]);
+
+        test_bed.fail(ROOT);
+        test_bed.run_with_delta(Duration::from_secs(9));
+        assert!(!test_bed.query(|a| a.is_active(SECONDARY)));
+
+        test_bed.run_with_delta(Duration::from_secs(1));
+        assert!(test_bed.query(|a| a.is_active(SECONDARY)));
+        assert!(origin_of(&test_bed, SECONDARY) == Some(FailureOrigin::Cascaded(ROOT)));
+    }
+
+    #[test]
+    fn effects_without_delay_cascade_immediately() {
+        let mut test_bed = test_bed(&[
+            FailureDependency::new(ROOT, SECONDARY, Duration::ZERO, 1.),
+            FailureDependency::new(SECONDARY, TERTIARY, Duration::ZERO, 1.),
+        ]);
+
+        test_bed.fail(ROOT);
+
+        assert!(test_bed.query(|a| a.is_active(TERTIARY)));
+        assert!(test_bed.root_cause_of(TERTIARY) == Some(ROOT));
+    }
+
+    #[test]
+    fn effect_with_zero_probability_never_activates() {
+        let mut test_bed = test_bed(&[FailureDependency::new(
+            ROOT,
+            SECONDARY,
+            Duration::from_secs(1),
+            0.,
+        )]);
+
+        test_bed.fail(ROOT);
+        test_bed.run_with_delta(Duration::from_secs(5));
+
+        assert!(!test_bed.query(|a| a.is_active(SECONDARY)));
+    }
+
+    #[test]
+    fn deactivating_the_cause_cancels_pending_effects() {
+        let mut test_bed = test_bed(&[FailureDependency::new(
+            ROOT,
+            SECONDARY,
+            Duration::from_secs(10),
+            1.,
+        )]);
+
+        test_bed.fail(ROOT);
+        test_bed.run_with_delta(Duration::from_secs(5));
+        test_bed.unfail(ROOT);
+        test_bed.run_with_delta(Duration::from_secs(10));
+
+        assert!(!test_bed.query(|a| a.is_active(SECONDARY)));
+        assert!(test_bed.active_failures().is_empty());
+    }
+
+    #[test]
+    fn activated_effect_stays_active_when_the_cause_deactivates() {
+        let mut test_bed = test_bed(&[FailureDependency::new(ROOT, SECONDARY, Duration::ZERO, 1.)]);
+
+        test_bed.fail(ROOT);
+        test_bed.unfail(ROOT);
+
+        assert!(test_bed.query(|a| a.is_active(SECONDARY)));
+        assert!(test_bed.root_cause_of(SECONDARY) == Some(ROOT));
+    }
+
+    #[test]
+    fn reports_the_origin_of_active_failures() {
+        let mut test_bed = test_bed(&[]);
+        test_bed.arm_failure(
+            SECONDARY,
+            FailureTrigger::TimeWindow {
+                from: Duration::ZERO,
+                to: Duration::ZERO,
+            },
+        );
+
+        test_bed.fail(ROOT);
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert!(origin_of(&test_bed, ROOT) == Some(FailureOrigin::Commanded));
+        assert!(origin_of(&test_bed, SECONDARY) == Some(FailureOrigin::Scheduled));
+        assert!(origin_of(&test_bed, TERTIARY).is_none());
+        assert!(test_bed.root_cause_of(TERTIARY).is_none());
+    }
+
+    #[test]
+    fn cyclic_dependencies_end() {
+        let mut test_bed = test_bed(&[
+            FailureDependency::new(ROOT, SECONDARY, Duration::ZERO, 1.),
+            FailureDependency::new(SECONDARY, ROOT, Duration::ZERO, 1.),
+        ]);
+
+        test_bed.fail(SECONDARY);
+
+        assert!(test_bed.query(|a| a.is_active(ROOT)));
+        assert!(test_bed.root_cause_of(ROOT) == Some(SECONDARY));
+    }
+}
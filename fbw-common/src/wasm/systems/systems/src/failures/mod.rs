@@ -1,3 +1,6 @@
+pub mod cascade;
+pub mod scheduler;
+
 use crate::air_conditioning::{
     acs_controller::AcscId, cabin_pressure_controller::CpcId, Channel, VcmId, ZoneType,
 };
@@ -5,7 +8,8 @@ use crate::air_conditioning::{FdacId, OcsmId};
 use crate::integrated_modular_avionics::core_processing_input_output_module::CpiomId;
 use crate::shared::{
     AirbusElectricPumpId, AirbusEngineDrivenPumpId, ElectricalBusType, FireDetectionLoopID,
-    FireDetectionZone, GearActuatorId, HydraulicColor, LgciuId, ProximityDetectorId,
+    FireDetectionZone, FlightControlSurface, GearActuatorId, HydraulicColor, LgciuId,
+    ProximityDetectorId, SmokeDetectionZone,
 };
 use crate::simulation::SimulationElement;
 
@@ -39,19 +43,33 @@ pub enum FailureType {
     CargoHeater,
     // ATA24
     Generator(usize),
+    GeneratorDriveDisconnect(usize),
+    GeneratorDriveGovernor(usize),
+    GeneratorVoltageRegulator(usize),
     ApuGenerator(usize),
+    BatteryThermalRunaway(usize),
     TransformerRectifier(usize),
     StaticInverter,
     ElectricalBus(ElectricalBusType),
     // ATA26
     SetOnFire(FireDetectionZone),
     FireDetectionLoop(FireDetectionLoopID, FireDetectionZone),
+    Smoke(SmokeDetectionZone),
+    SmokeDetectionLoop(FireDetectionLoopID, SmokeDetectionZone),
+    // ATA27
+    FlightControlActuatorJam(FlightControlSurface, usize),
+    FlightControlActuatorRunaway(FlightControlSurface, usize),
+    // ATA28
+    FuelPump(usize),
     // ATA29
     ReservoirLeak(HydraulicColor),
     ReservoirAirLeak(HydraulicColor),
     ReservoirReturnLeak(HydraulicColor),
     EnginePumpOverheat(AirbusEngineDrivenPumpId),
     ElecPumpOverheat(AirbusElectricPumpId),
+    // ATA31
+    CanBusDisturbance(usize),
+    CanBusNodeFault(usize, u8),
     // ATA32
     LgciuPowerSupply(LgciuId),
     LgciuInternalError(LgciuId),
@@ -59,10 +77,24 @@ pub enum FailureType {
     GearActuatorJammed(GearActuatorId),
     BrakeHydraulicLeak(HydraulicColor),
     BrakeAccumulatorGasLeak,
+    TyreBurst(usize),
     // ATA34
     RadioAltimeter(usize),
+    GpsReceiver(usize),
+    AdiruBusParityError(usize),
+    Adiru(usize),
+    Adr(usize),
+    Ir(usize),
+    Egpwc,
+    // ATA36
+    BleedValveStuck(usize),
+    PrecoolerFault(usize),
+    BleedLeak(usize),
+    // ATA49
+    ApuFault,
 }
 
+#[derive(Clone, Copy, PartialEq)]
 pub struct Failure {
     failure_type: FailureType,
     is_active: bool,
@@ -0,0 +1,427 @@
+use std::time::Duration;
+
+use num_traits::FromPrimitive;
+use uom::si::{f64::*, length::foot, velocity::knot};
+
+use crate::{
+    shared::{random_from_range, FwcFlightPhase},
+    simulation::{
+        InitContext, Read, SimulationElement, SimulatorReader, UpdateContext, VariableIdentifier,
+    },
+};
+
+use super::FailureType;
+
+/// The condition under which an armed failure activates.
+///
+/// The random moment, altitude or speed is drawn using [`random_from_range`] when the failure
+/// is armed. Seeding the random number generator therefore makes the schedule reproducible.
+///
+/// [`random_from_range`]: ../../shared/fn.random_from_range.html
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FailureTrigger {
+    /// Activates after an exponentially distributed time with the given mean time between
+    /// failures.
+    Mtbf(Duration),
+    /// Activates at a random moment within the window, which starts when the failure is armed.
+    TimeWindow { from: Duration, to: Duration },
+    /// Activates when the indicated altitude crosses a random altitude within the band.
+    AltitudeBand { lower: Length, upper: Length },
+    /// Activates when the indicated airspeed crosses a random speed within the band.
+    SpeedBand { lower: Velocity, upper: Velocity },
+    /// Activates once the FWC flight phase is the given phase.
+    FlightPhase(FwcFlightPhase),
+}
+impl FailureTrigger {
+    const MTBF: u8 = 1;
+    const TIME_WINDOW: u8 = 2;
+    const ALTITUDE_BAND: u8 = 3;
+    const SPEED_BAND: u8 = 4;
+    const FLIGHT_PHASE: u8 = 5;
+
+    /// Decodes a trigger as it is written by the simulator. The kind selects the trigger,
+    /// the meaning of the two values depends on it:
+    /// 1. MTBF: the mean time between failures in hours.
+    /// 2. Time window: the start and end of the window in seconds.
+    /// 3. Altitude band: the lower and upper altitude in feet.
+    /// 4. Speed band: the lower and upper speed in knots.
+    /// 5. Flight phase: the FWC flight phase number.
+    pub fn decode(kind: f64, from: f64, to: f64) -> Option<Self> {
+        match kind as u8 {
+            Self::MTBF if from > 0. => Some(Self::Mtbf(Duration::from_secs_f64(from * 3600.))),
+            Self::TIME_WINDOW if from >= 0. && to >= from => Some(Self::TimeWindow {
+                from: Duration::from_secs_f64(from),
+                to: Duration::from_secs_f64(to),
+            }),
+            Self::ALTITUDE_BAND if to >= from => Some(Self::AltitudeBand {
+                lower: Length::new::<foot>(from),
+                upper: Length::new::<foot>(to),
+            }),
+            Self::SPEED_BAND if to >= from => Some(Self::SpeedBand {
+                lower: Velocity::new::<knot>(from),
+                upper: Velocity::new::<knot>(to),
+            }),
+            Self::FLIGHT_PHASE => FwcFlightPhase::from_f64(from).map(Self::FlightPhase),
+            _ => None,
+        }
+    }
+}
+
+struct ArmedFailure {
+    failure_type: FailureType,
+    trigger: FailureTrigger,
+    /// Seconds since arming, altitude in feet or speed in knots, depending on the trigger.
+    threshold: f64,
+    armed_duration: Duration,
+    previous_value: Option<f64>,
+}
+impl ArmedFailure {
+    fn new(failure_type: FailureType, trigger: FailureTrigger) -> Self {
+        let threshold = match trigger {
+            FailureTrigger::Mtbf(_) | FailureTrigger::FlightPhase(_) => 0.,
+            FailureTrigger::TimeWindow { from, to } => {
+                random_between(from.as_secs_f64(), to.as_secs_f64())
+            }
+            FailureTrigger::AltitudeBand { lower, upper } => {
+                random_between(lower.get::<foot>(), upper.get::<foot>())
+            }
+            FailureTrigger::SpeedBand { lower, upper } => {
+                random_between(lower.get::<knot>(), upper.get::<knot>())
+            }
+        };
+
+        Self {
+            failure_type,
+            trigger,
+            threshold,
+            armed_duration: Duration::ZERO,
+            previous_value: None,
+        }
+    }
+
+    fn is_triggered(
+        &mut self,
+        context: &UpdateContext,
+        flight_phase: Option<FwcFlightPhase>,
+    ) -> bool {
+        self.armed_duration += context.delta();
+
+        match self.trigger {
+            FailureTrigger::Mtbf(mtbf) => {
+                let probability = 1. - (-context.delta_as_secs_f64() / mtbf.as_secs_f64()).exp();
+                random_from_range(0., 1.) < probability
+            }
+            FailureTrigger::TimeWindow { .. } => {
+                self.armed_duration.as_secs_f64() >= self.threshold
+            }
+            FailureTrigger::AltitudeBand { .. } => {
+                self.has_crossed_threshold(context.indicated_altitude().get::<foot>())
+            }
+            FailureTrigger::SpeedBand { .. } => {
+                self.has_crossed_threshold(context.indicated_airspeed().get::<knot>())
+            }
+            FailureTrigger::FlightPhase(phase) => flight_phase == Some(phase),
+        }
+    }
+
+    fn has_crossed_threshold(&mut self, value: f64) -> bool {
+        let has_crossed = self
+            .previous_value
+            .is_some_and(|previous| (previous - self.threshold) * (value - self.threshold) <= 0.);
+        self.previous_value = Some(value);
+
+        has_crossed
+    }
+}
+
+/// Activates armed failures without any further interaction, e.g. to surprise the crew
+/// during a training session. Each armed failure is activated once, after which it is no
+/// longer armed.
+pub struct FailureScheduler {
+    fwc_flight_phase_id: VariableIdentifier,
+
+    fwc_flight_phase: Option<FwcFlightPhase>,
+    armed: Vec<ArmedFailure>,
+}
+impl FailureScheduler {
+    pub fn new(context: &mut InitContext) -> Self {
+        Self {
+            fwc_flight_phase_id: context.get_identifier("FWC_FLIGHT_PHASE".to_owned()),
+            fwc_flight_phase: None,
+            armed: Vec::new(),
+        }
+    }
+
+    /// Arms the failure with the given trigger, replacing any trigger it was armed with before.
+    pub fn arm(&mut self, failure_type: FailureType, trigger: FailureTrigger) {
+        self.disarm(failure_type);
+        self.armed.push(ArmedFailure::new(failure_type, trigger));
+    }
+
+    pub fn disarm(&mut self, failure_type: FailureType) {
+        self.armed
+            .retain(|armed| armed.failure_type != failure_type);
+    }
+
+    pub fn is_armed(&self, failure_type: FailureType) -> bool {
+        self.armed
+            .iter()
+            .any(|armed| armed.failure_type == failure_type)
+    }
+
+    /// Disarms and returns the failures whose trigger condition is met.
+    pub fn update(&mut self, context: &UpdateContext) -> Vec<FailureType> {
+        let flight_phase = self.fwc_flight_phase;
+        let mut triggered = Vec::new();
+        self.armed.retain_mut(|armed| {
+            let is_triggered = armed.is_triggered(context, flight_phase);
+            if is_triggered {
+                triggered.push(armed.failure_type);
+            }
+
+            !is_triggered
+        });
+
+        triggered
+    }
+}
+impl SimulationElement for FailureScheduler {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        let flight_phase: f64 = reader.read(&self.fwc_flight_phase_id);
+        self.fwc_flight_phase = FromPrimitive::from_f64(flight_phase);
+    }
+}
+
+fn random_between(from: f64, to: f64) -> f64 {
+    if to > from {
+        random_from_range(from, to)
+    } else {
+        from
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        failures::Failure,
+        shared::seed_random,
+        simulation::{
+            test::{SimulationTestBed, TestBed, WriteByName},
+            Aircraft, SimulationElementVisitor,
+        },
+    };
+
+    const FAILURE: FailureType = FailureType::Generator(1);
+
+    struct TestAircraft {
+        failure: Failure,
+    }
+    impl TestAircraft {
+        fn new(_: &mut InitContext) -> Self {
+            Self {
+                failure: Failure::new(FAILURE),
+            }
+        }
+
+        fn failure_is_active(&self) -> bool {
+            self.failure.is_active()
+        }
+    }
+    impl Aircraft for TestAircraft {}
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.failure.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed() -> SimulationTestBed<TestAircraft> {
+        SimulationTestBed::new(TestAircraft::new)
+    }
+
+    #[test]
+    fn time_window_failure_activates_within_the_window() {
+        let mut test_bed = test_bed();
+        test_bed.arm_failure(
+            FAILURE,
+            FailureTrigger::TimeWindow {
+                from: Duration::from_secs(10),
+                to: Duration::from_secs(20),
+            },
+        );
+
+        for _ in 0..9 {
+            test_bed.run_with_delta(Duration::from_secs(1));
+        }
+        assert!(!test_bed.query(|a| a.failure_is_active()));
+
+        for _ in 0..12 {
+            test_bed.run_with_delta(Duration::from_secs(1));
+        }
+        assert!(test_bed.query(|a| a.failure_is_active()));
+    }
+
+    #[test]
+    fn failure_is_disarmed_once_triggered() {
+        let mut test_bed = test_bed();
+        test_bed.arm_failure(
+            FAILURE,
+            FailureTrigger::TimeWindow {
+                from: Duration::ZERO,
+                to: Duration::ZERO,
+            },
+        );
+
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert!(test_bed.query(|a| a.failure_is_active()));
+        assert!(!test_bed.is_failure_armed(FAILURE));
+    }
+
+    #[test]
+    fn disarmed_failure_does_not_activate() {
+        let mut test_bed = test_bed();
+        test_bed.arm_failure(
+            FAILURE,
+            FailureTrigger::TimeWindow {
+                from: Duration::from_secs(1),
+                to: Duration::from_secs(1),
+            },
+        );
+        test_bed.disarm_failure(FAILURE);
+
+        test_bed.run_with_delta(Duration::from_secs(5));
+
+        assert!(!test_bed.query(|a| a.failure_is_active()));
+    }
+
+    #[test]
+    fn altitude_band_failure_activates_when_climbing_through_the_band() {
+        let mut test_bed = test_bed();
+        test_bed.set_indicated_altitude(Length::new::<foot>(5000.));
+        test_bed.arm_failure(
+            FAILURE,
+            FailureTrigger::AltitudeBand {
+                lower: Length::new::<foot>(10000.),
+                upper: Length::new::<foot>(12000.),
+            },
+        );
+
+        test_bed.run_with_delta(Duration::from_secs(1));
+        test_bed.set_indicated_altitude(Length::new::<foot>(9900.));
+        test_bed.run_with_delta(Duration::from_secs(1));
+        assert!(!test_bed.query(|a| a.failure_is_active()));
+
+        test_bed.set_indicated_altitude(Length::new::<foot>(12100.));
+        test_bed.run_with_delta(Duration::from_secs(1));
+        test_bed.run_with_delta(Duration::from_secs(1));
+        assert!(test_bed.query(|a| a.failure_is_active()));
+    }
+
+    #[test]
+    fn speed_band_failure_does_not_activate_outside_the_band() {
+        let mut test_bed = test_bed();
+        test_bed.set_indicated_airspeed(Velocity::new::<knot>(250.));
+        test_bed.arm_failure(
+            FAILURE,
+            FailureTrigger::SpeedBand {
+                lower: Velocity::new::<knot>(100.),
+                upper: Velocity::new::<knot>(150.),
+            },
+        );
+
+        for _ in 0..10 {
+            test_bed.run_with_delta(Duration::from_secs(1));
+        }
+
+        assert!(!test_bed.query(|a| a.failure_is_active()));
+    }
+
+    #[test]
+    fn flight_phase_failure_activates_in_the_flight_phase() {
+        let mut test_bed = test_bed();
+        test_bed.write_by_name("FWC_FLIGHT_PHASE", FwcFlightPhase::ElecPwr as i32 as f64);
+        test_bed.arm_failure(
+            FAILURE,
+            FailureTrigger::FlightPhase(FwcFlightPhase::LiftOff),
+        );
+
+        test_bed.run_with_delta(Duration::from_secs(1));
+        test_bed.run_with_delta(Duration::from_secs(1));
+        assert!(!test_bed.query(|a| a.failure_is_active()));
+
+        test_bed.write_by_name("FWC_FLIGHT_PHASE", FwcFlightPhase::LiftOff as i32 as f64);
+        test_bed.run_with_delta(Duration::from_secs(1));
+        test_bed.run_with_delta(Duration::from_secs(1));
+        assert!(test_bed.query(|a| a.failure_is_active()));
+    }
+
+    #[test]
+    fn mtbf_failure_activates_around_the_mean_time_between_failures() {
+        seed_random(42);
+
+        let mut activation_times = Vec::new();
+        for _ in 0..100 {
+            let mut test_bed = test_bed();
+            test_bed.arm_failure(FAILURE, FailureTrigger::Mtbf(Duration::from_secs(60)));
+
+            let mut seconds = 0;
+            while !test_bed.query(|a| a.failure_is_active()) && seconds < 1000 {
+                test_bed.run_with_delta(Duration::from_secs(1));
+                seconds += 1;
+            }
+            activation_times.push(seconds as f64);
+        }
+
+        let mean = activation_times.iter().sum::<f64>() / activation_times.len() as f64;
+        assert!((45. ..75.).contains(&mean));
+    }
+
+    #[test]
+    fn seeded_schedule_is_reproducible() {
+        let activation_second = || {
+            seed_random(7);
+            let mut test_bed = test_bed();
+            test_bed.arm_failure(
+                FAILURE,
+                FailureTrigger::TimeWindow {
+                    from: Duration::from_secs(0),
+                    to: Duration::from_secs(100),
+                },
+            );
+
+            let mut seconds = 0;
+            while !test_bed.query(|a| a.failure_is_active()) {
+                test_bed.run_with_delta(Duration::from_secs(1));
+                seconds += 1;
+            }
+
+            seconds
+        };
+
+        assert_eq!(activation_second(), activation_second());
+    }
+
+    #[test]
+    fn decodes_simulator_triggers() {
+        assert_eq!(
+            FailureTrigger::decode(1., 2., 0.),
+            Some(FailureTrigger::Mtbf(Duration::from_secs(7200)))
+        );
+        assert_eq!(
+            FailureTrigger::decode(3., 1000., 2000.),
+            Some(FailureTrigger::AltitudeBand {
+                lower: Length::new::<foot>(1000.),
+                upper: Length::new::<foot>(2000.),
+            })
+        );
+        assert_eq!(
+            FailureTrigger::decode(5., 5., 0.),
+            Some(FailureTrigger::FlightPhase(FwcFlightPhase::LiftOff))
+        );
+        assert_eq!(FailureTrigger::decode(2., 20., 10.), None);
+        assert_eq!(FailureTrigger::decode(0., 0., 0.), None);
+    }
+}
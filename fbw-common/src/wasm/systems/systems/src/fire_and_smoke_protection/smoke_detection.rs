@@ -0,0 +1,226 @@
+use std::time::Duration;
+
+use crate::{
+    accept_iterable,
+    failures::{Failure, FailureType},
+    shared::{
+        DelayedTrueLogicGate, ElectricalBusType, ElectricalBuses, FireDetectionLoopID,
+        SmokeDetectionZone,
+    },
+    simulation::{
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+    },
+};
+
+/// The Smoke Detection Control Unit monitors the smoke detectors of the aircraft.
+/// Each detector is connected to both SDCU channels (loops).
+pub struct SmokeDetectionControlUnit<const N: usize> {
+    smoke_detection_loop: [SmokeDetectionLoop<N>; 2],
+    smoke_detectors: [SmokeDetector; N],
+
+    smoke_detected_id: [VariableIdentifier; N],
+    smoke_detection_fault_id: [VariableIdentifier; N],
+    cargo_smoke_test_pushbutton_id: VariableIdentifier,
+
+    smoke_detection_zones: [SmokeDetectionZone; N],
+    smoke_detected: [bool; N],
+    smoke_confirmation: [DelayedTrueLogicGate; N],
+    cargo_smoke_test_pushbutton_is_pressed: bool,
+}
+
+impl<const N: usize> SmokeDetectionControlUnit<N> {
+    // Fixme: The confirmation time is assumed as no references
+    const SMOKE_CONFIRMATION_DELAY: Duration = Duration::from_secs(3);
+
+    pub fn new(
+        context: &mut InitContext,
+        smoke_detection_zones: [SmokeDetectionZone; N],
+        powered_by: [ElectricalBusType; 2],
+    ) -> Self {
+        Self {
+            smoke_detection_loop: [
+                SmokeDetectionLoop::new(
+                    FireDetectionLoopID::A,
+                    &smoke_detection_zones,
+                    powered_by[0],
+                ),
+                SmokeDetectionLoop::new(
+                    FireDetectionLoopID::B,
+                    &smoke_detection_zones,
+                    powered_by[1],
+                ),
+            ],
+            smoke_detectors: smoke_detection_zones.map(SmokeDetector::new),
+
+            smoke_detected_id: smoke_detection_zones
+                .map(|zone| context.get_identifier(format!("SMOKE_DETECTED_{}", zone))),
+            smoke_detection_fault_id: smoke_detection_zones
+                .map(|zone| context.get_identifier(format!("SMOKE_DETECTION_FAULT_{}", zone))),
+            cargo_smoke_test_pushbutton_id: context.get_identifier("FIRE_TEST_CARGO".to_owned()),
+
+            smoke_detection_zones,
+            smoke_detected: [false; N],
+            smoke_confirmation: [(); N]
+                .map(|_| DelayedTrueLogicGate::new(Self::SMOKE_CONFIRMATION_DELAY)),
+            cargo_smoke_test_pushbutton_is_pressed: false,
+        }
+    }
+
+    /// The suppression state is given per zone, in the order the zones were provided on creation.
+    /// A suppressed zone no longer produces smoke.
+    pub fn update(&mut self, context: &UpdateContext, fire_is_suppressed: [bool; N]) {
+        self.smoke_detectors
+            .iter_mut()
+            .zip(fire_is_suppressed)
+            .for_each(|(detector, suppressed)| detector.update(suppressed));
+
+        for (index, confirmation) in self.smoke_confirmation.iter_mut().enumerate() {
+            let smoke_test = self.cargo_smoke_test_pushbutton_is_pressed
+                && matches!(
+                    self.smoke_detection_zones[index],
+                    SmokeDetectionZone::FwdCargo | SmokeDetectionZone::BulkCargo
+                );
+            let smoke_present = self.smoke_detectors[index].smoke_present();
+
+            let detected_in_loop = |l: &SmokeDetectionLoop<N>| {
+                l.smoke_detected_in_loop(index, smoke_present, smoke_test)
+            };
+
+            // Smoke is detected when both loops agree, or when one loop detects smoke while the other one is failed
+            confirmation.update(
+                context,
+                self.smoke_detection_loop.iter().all(detected_in_loop)
+                    || (self.smoke_detection_loop.iter().any(detected_in_loop)
+                        && self
+                            .smoke_detection_loop
+                            .iter()
+                            .any(|l| l.loop_has_failed(index))),
+            );
+            self.smoke_detected[index] = confirmation.output();
+        }
+
+        self.smoke_detection_loop
+            .iter_mut()
+            .for_each(|l| l.update_has_been_powered());
+    }
+
+    fn loop_has_failed(&self, index: usize) -> bool {
+        self.smoke_detection_loop
+            .iter()
+            .any(|l| l.loop_has_failed(index))
+    }
+
+    pub fn smoke_detected(&self, zone: SmokeDetectionZone) -> bool {
+        self.smoke_detection_zones
+            .iter()
+            .zip(self.smoke_detected)
+            .any(|(&z, smoke_detected)| z == zone && smoke_detected)
+    }
+}
+
+impl<const N: usize> SimulationElement for SmokeDetectionControlUnit<N> {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.cargo_smoke_test_pushbutton_is_pressed =
+            reader.read(&self.cargo_smoke_test_pushbutton_id);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        for (index, (detected_id, fault_id)) in self
+            .smoke_detected_id
+            .iter()
+            .zip(&self.smoke_detection_fault_id)
+            .enumerate()
+        {
+            writer.write(detected_id, self.smoke_detected[index]);
+            writer.write(fault_id, self.loop_has_failed(index));
+        }
+    }
+
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.smoke_detection_loop, visitor);
+        accept_iterable!(self.smoke_detectors, visitor);
+
+        visitor.visit(self);
+    }
+}
+
+struct SmokeDetectionLoop<const N: usize> {
+    powered_by: ElectricalBusType,
+    is_powered: bool,
+    has_been_powered: bool,
+    failures: [Failure; N],
+}
+
+impl<const N: usize> SmokeDetectionLoop<N> {
+    fn new(
+        loop_id: FireDetectionLoopID,
+        smoke_detection_zones: &[SmokeDetectionZone; N],
+        powered_by: ElectricalBusType,
+    ) -> Self {
+        Self {
+            powered_by,
+            is_powered: false,
+            has_been_powered: false,
+            failures: smoke_detection_zones
+                .map(|zone| Failure::new(FailureType::SmokeDetectionLoop(loop_id, zone))),
+        }
+    }
+
+    fn smoke_detected_in_loop(&self, index: usize, smoke_present: bool, smoke_test: bool) -> bool {
+        !self.failures[index].is_active() && self.is_powered && (smoke_present || smoke_test)
+    }
+
+    fn loop_has_failed(&self, index: usize) -> bool {
+        self.failures[index].is_active() || (!self.is_powered && self.has_been_powered)
+    }
+
+    /// This is to avoid a loop fault on initial load
+    fn update_has_been_powered(&mut self) {
+        self.has_been_powered |= self.is_powered
+    }
+}
+
+impl<const N: usize> SimulationElement for SmokeDetectionLoop<N> {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.failures, visitor);
+
+        visitor.visit(self);
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.powered_by);
+    }
+}
+
+/// Optical smoke detector. Smoke is generated in a zone by triggering the corresponding failure.
+/// In the cargo compartments the smoke clears once the extinguishing agent has suppressed the fire.
+struct SmokeDetector {
+    smoke: Failure,
+    smoke_present: bool,
+}
+
+impl SmokeDetector {
+    fn new(zone_id: SmokeDetectionZone) -> Self {
+        Self {
+            smoke: Failure::new(FailureType::Smoke(zone_id)),
+            smoke_present: false,
+        }
+    }
+
+    fn update(&mut self, fire_is_suppressed: bool) {
+        self.smoke_present = self.smoke.is_active() && !fire_is_suppressed;
+    }
+
+    fn smoke_present(&self) -> bool {
+        self.smoke_present
+    }
+}
+
+impl SimulationElement for SmokeDetector {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.smoke.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
@@ -1,10 +1,19 @@
-use crate::simulation::{
-    InitContext, Read, Reader, SimulationElement, SimulationElementVisitor, SimulatorReader,
-    SimulatorWriter, VariableIdentifier, Write, Writer,
+use crate::{
+    failures::{Failure, FailureType},
+    shared::{ConsumePower, ElectricalBusType, ElectricalBuses},
+    simulation::{
+        InitContext, Read, Reader, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        SimulatorWriter, UpdateContext, VariableIdentifier, Write, Writer,
+    },
 };
 use nalgebra::Vector3;
 use num_traits::Zero;
-use uom::si::{f64::Mass, mass::kilogram};
+use serde::Deserialize;
+use uom::si::{
+    f64::{Mass, Power},
+    mass::kilogram,
+    power::watt,
+};
 
 pub const FUEL_GALLONS_TO_KG: f64 = 3.039075693483925;
 
@@ -37,9 +46,10 @@ pub trait FuelPayload {
 pub trait FuelCG {
     fn center_of_gravity(&self) -> Vector3<f64>;
 }
-#[derive(Debug)]
-pub struct FuelInfo<'a> {
-    pub fuel_tank_id: &'a str,
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FuelInfo {
+    pub fuel_tank_id: String,
     pub position: (f64, f64, f64),
     pub total_capacity_gallons: f64,
 }
@@ -48,14 +58,22 @@ pub struct FuelInfo<'a> {
 pub struct FuelTank {
     fuel_id: VariableIdentifier,
     location: Vector3<f64>,
+    capacity: Mass,
     quantity: Mass,
     write: bool,
 }
 impl FuelTank {
-    pub fn new(context: &mut InitContext, id: &str, location: Vector3<f64>, write: bool) -> Self {
+    pub fn new(
+        context: &mut InitContext,
+        id: &str,
+        location: Vector3<f64>,
+        capacity: Mass,
+        write: bool,
+    ) -> Self {
         FuelTank {
             fuel_id: context.get_identifier(id.to_owned()),
             location,
+            capacity,
             quantity: Mass::default(),
             write,
         }
@@ -65,6 +83,10 @@ impl FuelTank {
         self.location
     }
 
+    pub fn capacity(&self) -> Mass {
+        self.capacity
+    }
+
     pub fn quantity(&self) -> Mass {
         self.quantity
     }
@@ -128,6 +150,43 @@ impl<const N: usize> FuelSystem<N> {
         self.fuel_tanks[t].set_quantity(quantity);
     }
 
+    pub fn tank_capacity(&self, t: usize) -> Mass {
+        self.fuel_tanks[t].capacity()
+    }
+
+    pub fn is_unlimited_fuel(&self) -> bool {
+        self.unlimited_fuel
+    }
+
+    /// Moves up to the given mass of fuel from one tank to another. The transfer is limited by
+    /// the fuel left in the source tank and the space left in the destination tank.
+    /// Returns the mass which was actually moved.
+    pub fn transfer(&mut self, from: usize, to: usize, mass: Mass) -> Mass {
+        let space_left = (self.tank_capacity(to) - self.tank_mass(to)).max(Mass::default());
+        let transferred = mass.min(self.tank_mass(from)).min(space_left);
+
+        if transferred > Mass::default() {
+            self.fuel_tanks[from].set_quantity(self.tank_mass(from) - transferred);
+            self.fuel_tanks[to].set_quantity(self.tank_mass(to) + transferred);
+        }
+
+        transferred
+    }
+
+    /// Removes up to the given mass of fuel from the tank, e.g. when it is burnt by an engine
+    /// or jettisoned. Returns the mass which was removed. With unlimited fuel the tank
+    /// quantity remains unchanged.
+    pub fn consume(&mut self, t: usize, mass: Mass) -> Mass {
+        if self.unlimited_fuel {
+            return mass;
+        }
+
+        let consumed = mass.min(self.tank_mass(t)).max(Mass::default());
+        self.fuel_tanks[t].set_quantity(self.tank_mass(t) - consumed);
+
+        consumed
+    }
+
     pub fn center_of_gravity(&self) -> Vector3<f64> {
         let positions = self.fuel_tanks.iter().map(|t| t.location());
         let masses = self.fuel_tanks.iter().map(|t| t.quantity());
@@ -160,3 +219,331 @@ impl<const N: usize> SimulationElement for FuelSystem<N> {
         self.fuel_total_weight = reader.read(&self.fuel_total_weight_id);
     }
 }
+
+/// An electrically driven fuel pump. The pump only delivers pressure when it is
+/// switched on, its bus is powered and it hasn't failed. A failed pump is seized and
+/// draws its locked rotor current for as long as it is switched on and powered.
+pub struct FuelPump {
+    is_running_id: VariableIdentifier,
+
+    powered_by: ElectricalBusType,
+    is_powered: bool,
+    is_on: bool,
+    failure: Failure,
+}
+impl FuelPump {
+    const POWER_CONSUMPTION_WATT: f64 = 1200.;
+    const SEIZED_POWER_CONSUMPTION_WATT: f64 = 7200.;
+
+    pub fn new(context: &mut InitContext, number: usize, powered_by: ElectricalBusType) -> Self {
+        Self {
+            is_running_id: context.get_identifier(format!("FUEL_PUMP_{}_IS_RUNNING", number)),
+
+            powered_by,
+            is_powered: false,
+            is_on: false,
+            failure: Failure::new(FailureType::FuelPump(number)),
+        }
+    }
+
+    pub fn update(&mut self, is_on: bool) {
+        self.is_on = is_on;
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.is_on && self.is_powered && !self.failure.is_active()
+    }
+}
+impl SimulationElement for FuelPump {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.is_running_id, self.is_running());
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.powered_by);
+    }
+
+    fn consume_power<T: ConsumePower>(&mut self, _: &UpdateContext, consumption: &mut T) {
+        if self.is_running() {
+            consumption.consume_from_bus(
+                self.powered_by,
+                Power::new::<watt>(Self::POWER_CONSUMPTION_WATT),
+            );
+        } else if self.is_on && self.is_powered {
+            consumption.consume_from_bus(
+                self.powered_by,
+                Power::new::<watt>(Self::SEIZED_POWER_CONSUMPTION_WATT),
+            );
+        }
+    }
+}
+
+/// A motor driven fuel valve. The valve only moves when its motor bus is powered and
+/// otherwise remains in its last position.
+pub struct FuelValve {
+    is_open_id: VariableIdentifier,
+
+    powered_by: ElectricalBusType,
+    is_powered: bool,
+    is_open: bool,
+}
+impl FuelValve {
+    pub fn new(context: &mut InitContext, number: usize, powered_by: ElectricalBusType) -> Self {
+        Self {
+            is_open_id: context.get_identifier(format!("FUEL_VALVE_{}_IS_OPEN", number)),
+
+            powered_by,
+            is_powered: false,
+            is_open: false,
+        }
+    }
+
+    pub fn update(&mut self, should_open: bool) {
+        if self.is_powered {
+            self.is_open = should_open;
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.is_open
+    }
+}
+impl SimulationElement for FuelValve {
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.is_open_id, self.is_open);
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.powered_by);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::electrical::test::TestElectricitySource;
+    use crate::electrical::{ElectricalBus, Electricity};
+    use crate::shared::PotentialOrigin;
+    use crate::simulation::test::{ReadByName, SimulationTestBed, TestBed, WriteByName};
+    use crate::simulation::Aircraft;
+    use uom::si::{electric_potential::volt, f64::ElectricPotential};
+
+    struct TestAircraft {
+        electricity_source: TestElectricitySource,
+        ac_1_bus: ElectricalBus,
+        is_ac_1_powered: bool,
+
+        fuel_system: FuelSystem<2>,
+        pump: FuelPump,
+        valve: FuelValve,
+
+        pump_is_on: bool,
+        valve_should_open: bool,
+        transfer: Mass,
+        consumption: Mass,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            let fuel_tanks = [
+                FuelTank::new(
+                    context,
+                    "FUEL_TANK_QUANTITY_1",
+                    Vector3::zeros(),
+                    Mass::new::<kilogram>(1000.),
+                    true,
+                ),
+                FuelTank::new(
+                    context,
+                    "FUEL_TANK_QUANTITY_2",
+                    Vector3::zeros(),
+                    Mass::new::<kilogram>(500.),
+                    true,
+                ),
+            ];
+
+            Self {
+                electricity_source: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::EngineGenerator(1),
+                ),
+                ac_1_bus: ElectricalBus::new(context, ElectricalBusType::AlternatingCurrent(1)),
+                is_ac_1_powered: true,
+
+                fuel_system: FuelSystem::new(context, fuel_tanks),
+                pump: FuelPump::new(context, 1, ElectricalBusType::AlternatingCurrent(1)),
+                valve: FuelValve::new(context, 1, ElectricalBusType::AlternatingCurrent(1)),
+
+                pump_is_on: false,
+                valve_should_open: false,
+                transfer: Mass::default(),
+                consumption: Mass::default(),
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            self.electricity_source
+                .power_with_potential(ElectricPotential::new::<volt>(115.));
+            electricity.supplied_by(&self.electricity_source);
+
+            if self.is_ac_1_powered {
+                electricity.flow(&self.electricity_source, &self.ac_1_bus);
+            }
+        }
+
+        fn update_after_power_distribution(&mut self, _: &UpdateContext) {
+            self.pump.update(self.pump_is_on);
+            self.valve.update(self.valve_should_open);
+
+            self.fuel_system.transfer(0, 1, self.transfer);
+            self.fuel_system.consume(0, self.consumption);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.fuel_system.accept(visitor);
+            self.pump.accept(visitor);
+            self.valve.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed_with_fuel(tank_1_kg: f64, tank_2_kg: f64) -> SimulationTestBed<TestAircraft> {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.write_by_name("FUEL_TANK_QUANTITY_1", tank_1_kg / FUEL_GALLONS_TO_KG);
+        test_bed.write_by_name("FUEL_TANK_QUANTITY_2", tank_2_kg / FUEL_GALLONS_TO_KG);
+
+        test_bed
+    }
+
+    fn tank_mass_kg(test_bed: &SimulationTestBed<TestAircraft>, t: usize) -> f64 {
+        test_bed.query(|a| a.fuel_system.tank_mass(t).get::<kilogram>())
+    }
+
+    #[test]
+    fn transfer_moves_fuel_between_tanks() {
+        let mut test_bed = test_bed_with_fuel(800., 100.);
+        test_bed.command(|a| a.transfer = Mass::new::<kilogram>(200.));
+        test_bed.run();
+
+        assert!((tank_mass_kg(&test_bed, 0) - 600.).abs() < 1e-6);
+        assert!((tank_mass_kg(&test_bed, 1) - 300.).abs() < 1e-6);
+    }
+
+    #[test]
+    fn transfer_is_limited_by_space_left_in_destination() {
+        let mut test_bed = test_bed_with_fuel(800., 400.);
+        test_bed.command(|a| a.transfer = Mass::new::<kilogram>(300.));
+        test_bed.run();
+
+        assert!((tank_mass_kg(&test_bed, 0) - 700.).abs() < 1e-6);
+        assert!((tank_mass_kg(&test_bed, 1) - 500.).abs() < 1e-6);
+    }
+
+    #[test]
+    fn transfer_is_limited_by_fuel_left_in_source() {
+        let mut test_bed = test_bed_with_fuel(50., 0.);
+        test_bed.command(|a| a.transfer = Mass::new::<kilogram>(300.));
+        test_bed.run();
+
+        assert!(tank_mass_kg(&test_bed, 0).abs() < 1e-6);
+        assert!((tank_mass_kg(&test_bed, 1) - 50.).abs() < 1e-6);
+    }
+
+    #[test]
+    fn consume_cannot_remove_more_than_the_tank_contains() {
+        let mut test_bed = test_bed_with_fuel(50., 0.);
+        test_bed.command(|a| a.consumption = Mass::new::<kilogram>(80.));
+        test_bed.run();
+
+        assert!(tank_mass_kg(&test_bed, 0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn consume_does_not_remove_fuel_with_unlimited_fuel() {
+        let mut test_bed = test_bed_with_fuel(500., 0.);
+        test_bed.write_by_name("UNLIMITED FUEL", true);
+        test_bed.command(|a| a.consumption = Mass::new::<kilogram>(80.));
+        test_bed.run();
+
+        assert!((tank_mass_kg(&test_bed, 0) - 500.).abs() < 1e-6);
+    }
+
+    #[test]
+    fn pump_runs_when_on_and_powered() {
+        let mut test_bed = test_bed_with_fuel(0., 0.);
+        test_bed.command(|a| a.pump_is_on = true);
+        test_bed.run();
+
+        assert!(test_bed.query(|a| a.pump.is_running()));
+        let is_running: bool = test_bed.read_by_name("FUEL_PUMP_1_IS_RUNNING");
+        assert!(is_running);
+    }
+
+    #[test]
+    fn pump_does_not_run_when_off() {
+        let mut test_bed = test_bed_with_fuel(0., 0.);
+        test_bed.run();
+
+        assert!(!test_bed.query(|a| a.pump.is_running()));
+    }
+
+    #[test]
+    fn pump_does_not_run_when_unpowered() {
+        let mut test_bed = test_bed_with_fuel(0., 0.);
+        test_bed.command(|a| {
+            a.pump_is_on = true;
+            a.is_ac_1_powered = false;
+        });
+        test_bed.run();
+
+        assert!(!test_bed.query(|a| a.pump.is_running()));
+    }
+
+    #[test]
+    fn pump_does_not_run_when_failed() {
+        let mut test_bed = test_bed_with_fuel(0., 0.);
+        test_bed.command(|a| a.pump_is_on = true);
+        test_bed.fail(FailureType::FuelPump(1));
+        test_bed.run();
+
+        assert!(!test_bed.query(|a| a.pump.is_running()));
+    }
+
+    #[test]
+    fn valve_opens_when_powered() {
+        let mut test_bed = test_bed_with_fuel(0., 0.);
+        test_bed.command(|a| a.valve_should_open = true);
+        test_bed.run();
+
+        assert!(test_bed.query(|a| a.valve.is_open()));
+        let is_open: bool = test_bed.read_by_name("FUEL_VALVE_1_IS_OPEN");
+        assert!(is_open);
+    }
+
+    #[test]
+    fn valve_remains_in_position_when_unpowered() {
+        let mut test_bed = test_bed_with_fuel(0., 0.);
+        test_bed.command(|a| a.valve_should_open = true);
+        test_bed.run();
+
+        test_bed.command(|a| {
+            a.valve_should_open = false;
+            a.is_ac_1_powered = false;
+        });
+        test_bed.run();
+
+        assert!(test_bed.query(|a| a.valve.is_open()));
+    }
+}
@@ -0,0 +1,786 @@
+use crate::{
+    electrical::CircuitBreaker,
+    shared::{
+        interpolation, low_pass_filter::LowPassFilter, ConsumePower, ElectricalBusType,
+        ElectricalBuses, LgciuGearExtension,
+    },
+    simulation::{
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+    },
+};
+
+use std::{marker::PhantomData, time::Duration};
+
+use uom::si::{
+    f64::*, mass::kilogram, power::watt, ratio::ratio, thermodynamic_temperature::degree_celsius,
+    velocity::meter_per_second,
+};
+
+/// Aircraft specific values used by the [`BrakeThermalModel`].
+///
+/// All values are given per braked wheel.
+pub trait BrakeThermalConstants {
+    /// Mass of the carbon heat sink absorbing the braking energy.
+    const HEAT_SINK_MASS_KG: f64;
+    const HEAT_SINK_SPECIFIC_HEAT_J_PER_KG_PER_KELVIN: f64;
+
+    /// Deceleration produced by all braked wheels at full braking force.
+    const MAX_BRAKING_DECELERATION_METER_PER_SECOND_SQUARED: f64;
+
+    /// Heat transfer to the ambient air of a stationary brake with the gear extended.
+    const NATURAL_CONVECTION_WATT_PER_KELVIN: f64;
+    /// Additional heat transfer per m/s of true airspeed when the gear is extended.
+    const AIRFLOW_CONVECTION_WATT_PER_KELVIN_PER_METER_PER_SECOND: f64;
+    /// Fraction of the natural convection remaining when the gear is stowed in its bay.
+    const GEAR_BAY_CONVECTION_FACTOR: f64;
+    /// Additional heat transfer when the brake fan is running.
+    const BRAKE_FAN_CONVECTION_WATT_PER_KELVIN: f64;
+    /// Electrical power drawn by the brake fan while it is running.
+    const BRAKE_FAN_POWER_WATT: f64;
+
+    const BRAKES_HOT_TEMPERATURE_DEGREE_CELSIUS: f64;
+    /// Temperature at which the wheel fuse plugs melt and deflate the tyre.
+    const FUSE_PLUG_MELT_TEMPERATURE_DEGREE_CELSIUS: f64;
+
+    /// Breakpoints of the braking efficiency, which fades as the heat sink gets hot.
+    const EFFICIENCY_TEMPERATURE_BREAKPOINTS_DEGREE_CELSIUS: &'static [f64];
+    const EFFICIENCY_RATIO: &'static [f64];
+}
+
+struct WheelBrake {
+    temperature_id: VariableIdentifier,
+    reported_temperature_id: VariableIdentifier,
+    fuse_plug_melted_id: VariableIdentifier,
+
+    temperature: ThermodynamicTemperature,
+    is_initialised: bool,
+    reported_temperature: LowPassFilter<f64>,
+    fuse_plug_melted: bool,
+}
+impl WheelBrake {
+    // Time constant of the temperature probe following the heat sink temperature
+    const PROBE_TIME_CONSTANT: Duration = Duration::from_secs(60);
+
+    fn new(context: &mut InitContext, number: usize) -> Self {
+        Self {
+            temperature_id: context.get_identifier(format!("BRAKE_TEMPERATURE_{}", number)),
+            reported_temperature_id: context
+                .get_identifier(format!("REPORTED_BRAKE_TEMPERATURE_{}", number)),
+            fuse_plug_melted_id: context
+                .get_identifier(format!("BRAKE_FUSE_PLUG_MELTED_{}", number)),
+
+            temperature: ThermodynamicTemperature::default(),
+            is_initialised: false,
+            reported_temperature: LowPassFilter::new(Self::PROBE_TIME_CONSTANT),
+            fuse_plug_melted: false,
+        }
+    }
+
+    fn update<C: BrakeThermalConstants>(
+        &mut self,
+        context: &UpdateContext,
+        braking_power: Power,
+        gear_extended: bool,
+        fan_running: bool,
+    ) {
+        let ambient = context.ambient_temperature().get::<degree_celsius>();
+        if !self.is_initialised {
+            self.temperature = context.ambient_temperature();
+            self.reported_temperature.reset(ambient);
+            self.is_initialised = true;
+        }
+
+        let heat_capacity = C::HEAT_SINK_MASS_KG * C::HEAT_SINK_SPECIFIC_HEAT_J_PER_KG_PER_KELVIN;
+        let mut temperature = self.temperature.get::<degree_celsius>();
+        let delta_temperature_to_ambient = temperature - ambient;
+
+        let cooling_watt_per_kelvin = Self::convection_watt_per_kelvin::<C>(
+            context.true_airspeed(),
+            gear_extended,
+            fan_running,
+        );
+
+        // The cooling is integrated as an exponential decay towards ambient so that it remains
+        // stable with long frames.
+        let cooling_decay =
+            (-cooling_watt_per_kelvin * context.delta_as_secs_f64() / heat_capacity).exp();
+        temperature = ambient
+            + delta_temperature_to_ambient * cooling_decay
+            + braking_power.get::<watt>() * context.delta_as_secs_f64() / heat_capacity;
+        self.temperature = ThermodynamicTemperature::new::<degree_celsius>(temperature);
+
+        // A running brake fan cools the temperature probe faster than the heat sink, so
+        // the reported temperature is lower than the actual one.
+        let probe_target = if fan_running {
+            ambient + (temperature - ambient) / 2.
+        } else {
+            temperature
+        };
+        self.reported_temperature
+            .update(context.delta(), probe_target);
+
+        if temperature > C::FUSE_PLUG_MELT_TEMPERATURE_DEGREE_CELSIUS {
+            self.fuse_plug_melted = true;
+        }
+    }
+
+    fn convection_watt_per_kelvin<C: BrakeThermalConstants>(
+        true_airspeed: Velocity,
+        gear_extended: bool,
+        fan_running: bool,
+    ) -> f64 {
+        let fan = if fan_running {
+            C::BRAKE_FAN_CONVECTION_WATT_PER_KELVIN
+        } else {
+            0.
+        };
+
+        if gear_extended {
+            C::NATURAL_CONVECTION_WATT_PER_KELVIN
+                + C::AIRFLOW_CONVECTION_WATT_PER_KELVIN_PER_METER_PER_SECOND
+                    * true_airspeed.get::<meter_per_second>().abs()
+                + fan
+        } else {
+            C::NATURAL_CONVECTION_WATT_PER_KELVIN * C::GEAR_BAY_CONVECTION_FACTOR
+        }
+    }
+
+    fn efficiency<C: BrakeThermalConstants>(&self) -> Ratio {
+        Ratio::new::<ratio>(interpolation(
+            C::EFFICIENCY_TEMPERATURE_BREAKPOINTS_DEGREE_CELSIUS,
+            C::EFFICIENCY_RATIO,
+            self.temperature.get::<degree_celsius>(),
+        ))
+    }
+
+    fn temperature(&self) -> ThermodynamicTemperature {
+        self.temperature
+    }
+
+    fn reported_temperature(&self) -> ThermodynamicTemperature {
+        ThermodynamicTemperature::new::<degree_celsius>(self.reported_temperature.output())
+    }
+
+    fn fuse_plug_melted(&self) -> bool {
+        self.fuse_plug_melted
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.temperature_id, self.temperature);
+        writer.write(&self.reported_temperature_id, self.reported_temperature());
+        writer.write(&self.fuse_plug_melted_id, self.fuse_plug_melted);
+    }
+}
+
+/// The brake fans of all braked wheels, selected by the BRAKE FAN push button.
+///
+/// The fans are the only electrical consumer of the thermal model, hence they alone sit behind
+/// the `BRAKE_FANS` circuit breaker.
+struct BrakeFans {
+    pb_pressed_id: VariableIdentifier,
+
+    powered_by: ElectricalBusType,
+    power_consumption: Power,
+    pb_pressed: bool,
+    is_powered: bool,
+    is_running: bool,
+}
+impl BrakeFans {
+    const CIRCUIT_BREAKER_ID: &'static str = "BRAKE_FANS";
+
+    fn new(
+        context: &mut InitContext,
+        powered_by: ElectricalBusType,
+        power_consumption: Power,
+    ) -> Self {
+        Self {
+            pb_pressed_id: context.get_identifier("BRAKE_FAN_BTN_PRESSED".to_owned()),
+
+            powered_by,
+            power_consumption,
+            pb_pressed: false,
+            is_powered: false,
+            is_running: false,
+        }
+    }
+
+    fn update(&mut self, gear_extended: bool) {
+        self.is_running = self.pb_pressed && self.is_powered && gear_extended;
+    }
+
+    fn is_running(&self) -> bool {
+        self.is_running
+    }
+}
+impl SimulationElement for BrakeFans {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.pb_pressed = reader.read(&self.pb_pressed_id);
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.powered_by);
+    }
+
+    fn consume_power<T: ConsumePower>(&mut self, _: &UpdateContext, consumption: &mut T) {
+        if self.is_running {
+            consumption.consume_from_bus(self.powered_by, self.power_consumption);
+        }
+    }
+}
+
+/// Temperatures of the braked wheels.
+///
+/// Each heat sink absorbs the kinetic energy dissipated by its brake, computed from the braking
+/// force demanded from the simulator, the aircraft weight and the ground speed. It cools
+/// convectively towards ambient depending on gear position, airspeed and brake fans. Hot brakes
+/// fade and deliver less braking force, and fuse plugs melt above their threshold.
+///
+/// Unless `owns_temperatures` is set, the temperatures are computed by the instruments. The model
+/// then keeps running alongside them, but writes nothing to the simulator and neither fades the
+/// brakes nor melts the fuse plugs, as these must follow the temperatures shown in the cockpit.
+pub struct BrakeThermalModel<C: BrakeThermalConstants, const N: usize> {
+    brake_fan_id: VariableIdentifier,
+    brakes_hot_id: VariableIdentifier,
+
+    brakes: [WheelBrake; N],
+    brake_fans: CircuitBreaker<BrakeFans>,
+    owns_temperatures: bool,
+
+    constants: PhantomData<C>,
+}
+impl<C: BrakeThermalConstants, const N: usize> BrakeThermalModel<C, N> {
+    pub fn new(
+        context: &mut InitContext,
+        brake_fan_powered_by: ElectricalBusType,
+        brake_fan_rated_current: ElectricCurrent,
+        owns_temperatures: bool,
+    ) -> Self {
+        let mut brakes = vec![];
+        for idx in 0..N {
+            brakes.push(WheelBrake::new(context, idx + 1));
+        }
+
+        let brake_fans = BrakeFans::new(
+            context,
+            brake_fan_powered_by,
+            Power::new::<watt>(C::BRAKE_FAN_POWER_WATT),
+        );
+
+        Self {
+            brake_fan_id: context.get_identifier("BRAKE_FAN".to_owned()),
+            brakes_hot_id: context.get_identifier("BRAKES_HOT".to_owned()),
+
+            brakes: brakes.try_into().unwrap_or_else(|v: Vec<WheelBrake>| {
+                panic!("Expected a Vec of length {} but it was {}", N, v.len())
+            }),
+            brake_fans: CircuitBreaker::new(
+                context,
+                BrakeFans::CIRCUIT_BREAKER_ID,
+                brake_fan_powered_by,
+                brake_fan_rated_current,
+                brake_fans,
+            ),
+            owns_temperatures,
+
+            constants: PhantomData,
+        }
+    }
+
+    /// Updates the brake temperatures from the braking force ratio (0 to 1) applied on each
+    /// wheel.
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        braking_forces: [Ratio; N],
+        lgciu: &impl LgciuGearExtension,
+    ) {
+        let gear_extended = lgciu.main_down_and_locked();
+        self.brake_fans.element_mut().update(gear_extended);
+        let brake_fan_running = self.brake_fan_running();
+
+        let max_force_per_wheel = context.total_weight().get::<kilogram>()
+            * C::MAX_BRAKING_DECELERATION_METER_PER_SECOND_SQUARED
+            / N as f64;
+        let ground_speed = if context.is_on_ground() {
+            context.ground_speed().get::<meter_per_second>().abs()
+        } else {
+            0.
+        };
+
+        for (brake, force) in self.brakes.iter_mut().zip(braking_forces) {
+            let braking_power = Power::new::<watt>(
+                force.get::<ratio>().clamp(0., 1.) * max_force_per_wheel * ground_speed,
+            );
+            brake.update::<C>(context, braking_power, gear_extended, brake_fan_running);
+        }
+    }
+
+    pub fn temperature(&self, wheel_index: usize) -> ThermodynamicTemperature {
+        self.brakes[wheel_index].temperature()
+    }
+
+    pub fn reported_temperature(&self, wheel_index: usize) -> ThermodynamicTemperature {
+        self.brakes[wheel_index].reported_temperature()
+    }
+
+    /// Ratio of the nominal braking force the brake can still deliver at its temperature.
+    pub fn braking_efficiency(&self, wheel_index: usize) -> Ratio {
+        if self.owns_temperatures {
+            self.brakes[wheel_index].efficiency::<C>()
+        } else {
+            Ratio::new::<ratio>(1.)
+        }
+    }
+
+    pub fn fuse_plug_melted(&self, wheel_index: usize) -> bool {
+        self.owns_temperatures && self.brakes[wheel_index].fuse_plug_melted()
+    }
+
+    pub fn brakes_hot(&self) -> bool {
+        self.brakes.iter().any(|brake| {
+            brake.temperature().get::<degree_celsius>() > C::BRAKES_HOT_TEMPERATURE_DEGREE_CELSIUS
+        })
+    }
+
+    pub fn brake_fan_running(&self) -> bool {
+        self.brake_fans.element().is_running()
+    }
+}
+impl<C: BrakeThermalConstants, const N: usize> SimulationElement for BrakeThermalModel<C, N> {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.brake_fans.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        if self.owns_temperatures {
+            for brake in &self.brakes {
+                brake.write(writer);
+            }
+
+            writer.write(&self.brakes_hot_id, self.brakes_hot());
+            writer.write(&self.brake_fan_id, self.brake_fan_running());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::electrical::test::TestElectricitySource;
+    use crate::electrical::{ElectricalBus, Electricity};
+    use crate::shared::PotentialOrigin;
+    use crate::simulation::test::{ReadByName, SimulationTestBed, TestBed, WriteByName};
+    use crate::simulation::Aircraft;
+    use ntest::assert_about_eq;
+    use std::time::Duration;
+    use uom::si::{
+        electric_current::ampere, electric_potential::volt, f64::ElectricPotential, velocity::knot,
+    };
+
+    struct TestBrakeThermalConstants;
+    impl BrakeThermalConstants for TestBrakeThermalConstants {
+        const HEAT_SINK_MASS_KG: f64 = 70.;
+        const HEAT_SINK_SPECIFIC_HEAT_J_PER_KG_PER_KELVIN: f64 = 1420.;
+        const MAX_BRAKING_DECELERATION_METER_PER_SECOND_SQUARED: f64 = 5.;
+        const NATURAL_CONVECTION_WATT_PER_KELVIN: f64 = 21.;
+        const AIRFLOW_CONVECTION_WATT_PER_KELVIN_PER_METER_PER_SECOND: f64 = 2.;
+        const GEAR_BAY_CONVECTION_FACTOR: f64 = 0.5;
+        const BRAKE_FAN_CONVECTION_WATT_PER_KELVIN: f64 = 45.;
+        const BRAKE_FAN_POWER_WATT: f64 = 800.;
+        const BRAKES_HOT_TEMPERATURE_DEGREE_CELSIUS: f64 = 300.;
+        const FUSE_PLUG_MELT_TEMPERATURE_DEGREE_CELSIUS: f64 = 650.;
+        const EFFICIENCY_TEMPERATURE_BREAKPOINTS_DEGREE_CELSIUS: &'static [f64] =
+            &[600., 900., 1200.];
+        const EFFICIENCY_RATIO: &'static [f64] = &[1., 0.8, 0.5];
+    }
+
+    struct TestLgciu {
+        main_down_and_locked: bool,
+    }
+    impl LgciuGearExtension for TestLgciu {
+        fn all_down_and_locked(&self) -> bool {
+            self.main_down_and_locked
+        }
+
+        fn all_up_and_locked(&self) -> bool {
+            !self.main_down_and_locked
+        }
+
+        fn main_down_and_locked(&self) -> bool {
+            self.main_down_and_locked
+        }
+
+        fn main_up_and_locked(&self) -> bool {
+            !self.main_down_and_locked
+        }
+
+        fn nose_down_and_locked(&self) -> bool {
+            self.main_down_and_locked
+        }
+
+        fn nose_up_and_locked(&self) -> bool {
+            !self.main_down_and_locked
+        }
+    }
+
+    struct TestAircraft {
+        electricity_source: TestElectricitySource,
+        ac_2_bus: ElectricalBus,
+        is_ac_2_powered: bool,
+
+        brakes: BrakeThermalModel<TestBrakeThermalConstants, 4>,
+        lgciu: TestLgciu,
+        braking_force: Ratio,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext, owns_temperatures: bool) -> Self {
+            Self {
+                electricity_source: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::EngineGenerator(1),
+                ),
+                ac_2_bus: ElectricalBus::new(context, ElectricalBusType::AlternatingCurrent(2)),
+                is_ac_2_powered: true,
+
+                brakes: BrakeThermalModel::new(
+                    context,
+                    ElectricalBusType::AlternatingCurrent(2),
+                    ElectricCurrent::new::<ampere>(10.),
+                    owns_temperatures,
+                ),
+                lgciu: TestLgciu {
+                    main_down_and_locked: true,
+                },
+                braking_force: Ratio::default(),
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            self.electricity_source
+                .power_with_potential(ElectricPotential::new::<volt>(115.));
+            electricity.supplied_by(&self.electricity_source);
+
+            if self.is_ac_2_powered {
+                electricity.flow(&self.electricity_source, &self.ac_2_bus);
+            }
+        }
+
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.brakes
+                .update(context, [self.braking_force; 4], &self.lgciu);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.brakes.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    struct BrakeThermalTestBed {
+        test_bed: SimulationTestBed<TestAircraft>,
+    }
+    impl BrakeThermalTestBed {
+        fn new(owns_temperatures: bool) -> Self {
+            let mut test_bed = Self {
+                test_bed: SimulationTestBed::new(|context| {
+                    TestAircraft::new(context, owns_temperatures)
+                }),
+            };
+            test_bed.set_on_ground(true);
+            test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(15.));
+            test_bed.write_by_name("TOTAL WEIGHT", Mass::new::<kilogram>(64000.));
+
+            test_bed
+        }
+
+        fn ground_speed(mut self, speed: Velocity) -> Self {
+            self.write_by_name("GPS GROUND SPEED", speed);
+            self
+        }
+
+        fn braking_force(mut self, force: f64) -> Self {
+            self.command(|a| a.braking_force = Ratio::new::<ratio>(force));
+            self
+        }
+
+        fn brake_fan_pb_pressed(mut self, pressed: bool) -> Self {
+            self.write_by_name("BRAKE_FAN_BTN_PRESSED", pressed);
+            self
+        }
+
+        fn brake_fan_circuit_breaker_pulled(mut self, pulled: bool) -> Self {
+            self.write_by_name("ELEC_CB_BRAKE_FANS_IS_PULLED", pulled);
+            self
+        }
+
+        fn gear_retracted(mut self) -> Self {
+            self.command(|a| a.lgciu.main_down_and_locked = false);
+            self
+        }
+
+        fn ac_2_unpowered(mut self) -> Self {
+            self.command(|a| a.is_ac_2_powered = false);
+            self
+        }
+
+        fn run_for(mut self, duration: Duration) -> Self {
+            self.run_with_delta(duration);
+            self
+        }
+
+        fn temperature(&self, wheel_index: usize) -> f64 {
+            self.query(|a| a.brakes.temperature(wheel_index))
+                .get::<degree_celsius>()
+        }
+
+        fn reported_temperature(&self, wheel_index: usize) -> f64 {
+            self.query(|a| a.brakes.reported_temperature(wheel_index))
+                .get::<degree_celsius>()
+        }
+
+        fn braking_efficiency(&self, wheel_index: usize) -> f64 {
+            self.query(|a| a.brakes.braking_efficiency(wheel_index))
+                .get::<ratio>()
+        }
+
+        fn fuse_plug_melted(&self, wheel_index: usize) -> bool {
+            self.query(|a| a.brakes.fuse_plug_melted(wheel_index))
+        }
+
+        fn brake_fan_running(&mut self) -> bool {
+            self.read_by_name("BRAKE_FAN")
+        }
+
+        fn brakes_hot(&mut self) -> bool {
+            self.read_by_name("BRAKES_HOT")
+        }
+
+        /// Heats the brakes with a high energy stop and then releases them.
+        fn hot_brakes(self) -> Self {
+            self.ground_speed(Velocity::new::<knot>(100.))
+                .braking_force(1.)
+                .run_for(Duration::from_secs(10))
+                .braking_force(0.)
+                .ground_speed(Velocity::new::<knot>(0.))
+        }
+    }
+    impl TestBed for BrakeThermalTestBed {
+        type Aircraft = TestAircraft;
+
+        fn test_bed(&self) -> &SimulationTestBed<TestAircraft> {
+            &self.test_bed
+        }
+
+        fn test_bed_mut(&mut self) -> &mut SimulationTestBed<TestAircraft> {
+            &mut self.test_bed
+        }
+    }
+
+    fn test_bed() -> BrakeThermalTestBed {
+        BrakeThermalTestBed::new(true)
+    }
+
+    fn test_bed_with_temperatures_from_the_instruments() -> BrakeThermalTestBed {
+        BrakeThermalTestBed::new(false)
+    }
+
+    #[test]
+    fn brakes_start_at_ambient_temperature() {
+        let test_bed = test_bed().run_for(Duration::from_secs(1));
+
+        for wheel in 0..4 {
+            assert_about_eq!(test_bed.temperature(wheel), 15.);
+            assert_about_eq!(test_bed.reported_temperature(wheel), 15.);
+        }
+    }
+
+    #[test]
+    fn braking_while_rolling_heats_the_brakes() {
+        let test_bed = test_bed()
+            .ground_speed(Velocity::new::<knot>(60.))
+            .braking_force(0.3)
+            .run_for(Duration::from_secs(1));
+
+        assert!(test_bed.temperature(0) > 20.);
+    }
+
+    #[test]
+    fn braking_while_stationary_does_not_heat_the_brakes() {
+        let test_bed = test_bed()
+            .braking_force(1.)
+            .run_for(Duration::from_secs(10));
+
+        assert_about_eq!(test_bed.temperature(0), 15.);
+    }
+
+    #[test]
+    fn braking_energy_scales_with_aircraft_weight() {
+        let light = test_bed()
+            .ground_speed(Velocity::new::<knot>(60.))
+            .braking_force(0.5);
+        let mut heavy = test_bed()
+            .ground_speed(Velocity::new::<knot>(60.))
+            .braking_force(0.5);
+        heavy.write_by_name("TOTAL WEIGHT", Mass::new::<kilogram>(76000.));
+
+        let light = light.run_for(Duration::from_secs(1));
+        let heavy = heavy.run_for(Duration::from_secs(1));
+
+        assert!(heavy.temperature(0) > light.temperature(0));
+    }
+
+    #[test]
+    fn hot_brakes_cool_down_towards_ambient() {
+        let test_bed = test_bed().hot_brakes();
+        let hot_temperature = test_bed.temperature(0);
+
+        let test_bed = test_bed.run_for(Duration::from_secs(600));
+
+        assert!(test_bed.temperature(0) < hot_temperature);
+        assert!(test_bed.temperature(0) > 15.);
+    }
+
+    #[test]
+    fn brake_fans_cool_the_brakes_faster() {
+        let without_fans = test_bed().hot_brakes().run_for(Duration::from_secs(600));
+        let mut with_fans = test_bed()
+            .hot_brakes()
+            .brake_fan_pb_pressed(true)
+            .run_for(Duration::from_secs(600));
+
+        assert!(with_fans.brake_fan_running());
+        assert!(with_fans.temperature(0) < without_fans.temperature(0) - 50.);
+    }
+
+    #[test]
+    fn brake_fans_do_not_run_without_power() {
+        let mut test_bed = test_bed()
+            .ac_2_unpowered()
+            .brake_fan_pb_pressed(true)
+            .run_for(Duration::from_secs(1));
+
+        assert!(!test_bed.brake_fan_running());
+    }
+
+    #[test]
+    fn pulled_circuit_breaker_stops_the_brake_fans_but_not_the_thermal_model() {
+        let mut test_bed = test_bed()
+            .brake_fan_circuit_breaker_pulled(true)
+            .brake_fan_pb_pressed(true)
+            .hot_brakes()
+            .run_for(Duration::from_secs(1));
+
+        assert!(!test_bed.brake_fan_running());
+        assert!(test_bed.brakes_hot());
+    }
+
+    #[test]
+    fn brake_fans_do_not_run_with_gear_retracted() {
+        let mut test_bed = test_bed()
+            .gear_retracted()
+            .brake_fan_pb_pressed(true)
+            .run_for(Duration::from_secs(1));
+
+        assert!(!test_bed.brake_fan_running());
+    }
+
+    #[test]
+    fn brakes_cool_faster_with_gear_extended_in_flight() {
+        let mut gear_extended = test_bed().hot_brakes();
+        gear_extended.set_on_ground(false);
+        gear_extended.set_true_airspeed(Velocity::new::<knot>(200.));
+
+        let mut gear_retracted = test_bed().hot_brakes().gear_retracted();
+        gear_retracted.set_on_ground(false);
+        gear_retracted.set_true_airspeed(Velocity::new::<knot>(200.));
+
+        let gear_extended = gear_extended.run_for(Duration::from_secs(300));
+        let gear_retracted = gear_retracted.run_for(Duration::from_secs(300));
+
+        assert!(gear_extended.temperature(0) < gear_retracted.temperature(0) - 50.);
+    }
+
+    #[test]
+    fn brake_fans_make_the_probe_read_below_brake_temperature() {
+        let test_bed = test_bed()
+            .hot_brakes()
+            .brake_fan_pb_pressed(true)
+            .run_for(Duration::from_secs(300));
+
+        assert!(test_bed.reported_temperature(0) < test_bed.temperature(0) - 20.);
+    }
+
+    #[test]
+    fn probe_follows_brake_temperature_without_fans() {
+        let test_bed = test_bed().hot_brakes().run_for(Duration::from_secs(600));
+
+        assert!((test_bed.reported_temperature(0) - test_bed.temperature(0)).abs() < 10.);
+    }
+
+    #[test]
+    fn brakes_hot_is_reported_above_threshold() {
+        let mut cold = test_bed().run_for(Duration::from_secs(1));
+        let mut hot = test_bed().hot_brakes().run_for(Duration::from_secs(1));
+
+        assert!(!cold.brakes_hot());
+        assert!(hot.brakes_hot());
+    }
+
+    #[test]
+    fn fuse_plugs_melt_above_threshold_and_stay_melted() {
+        let test_bed = test_bed()
+            .ground_speed(Velocity::new::<knot>(150.))
+            .braking_force(1.)
+            .run_for(Duration::from_secs(15));
+
+        assert!(test_bed.temperature(0) > 650.);
+        assert!(test_bed.fuse_plug_melted(0));
+
+        let test_bed = test_bed
+            .braking_force(0.)
+            .brake_fan_pb_pressed(true)
+            .run_for(Duration::from_secs(3600));
+
+        assert!(test_bed.temperature(0) < 650.);
+        assert!(test_bed.fuse_plug_melted(0));
+    }
+
+    #[test]
+    fn braking_efficiency_fades_when_brakes_are_hot() {
+        let cold = test_bed().hot_brakes();
+
+        assert_about_eq!(cold.braking_efficiency(0), 1.);
+
+        let hot = test_bed()
+            .ground_speed(Velocity::new::<knot>(150.))
+            .braking_force(1.)
+            .run_for(Duration::from_secs(15));
+
+        assert!(hot.temperature(0) > 600.);
+        assert!(hot.braking_efficiency(0) < 1.);
+    }
+
+    #[test]
+    fn brakes_neither_fade_nor_melt_fuse_plugs_when_the_instruments_own_the_temperatures() {
+        let test_bed = test_bed_with_temperatures_from_the_instruments()
+            .ground_speed(Velocity::new::<knot>(150.))
+            .braking_force(1.)
+            .run_for(Duration::from_secs(15));
+
+        assert!(test_bed.temperature(0) > 650.);
+        assert_about_eq!(test_bed.braking_efficiency(0), 1.);
+        assert!(!test_bed.fuse_plug_melted(0));
+        assert!(!test_bed.contains_variable_with_name("BRAKE_TEMPERATURE_1"));
+        assert!(!test_bed.contains_variable_with_name("BRAKE_FUSE_PLUG_MELTED_1"));
+    }
+}
@@ -19,6 +19,7 @@ use uom::si::{
 };
 
 use crate::{
+    failures::{Failure, FailureType},
     shared::{
         interpolation, low_pass_filter::LowPassFilter, pid::PidController,
         random_from_normal_distribution, random_from_range, ConsumePower, ElectricalBusType,
@@ -933,6 +934,9 @@ impl LinearActuatorCharacteristics {
 ///
 /// It can behave it two main ways: its control valves are either closed, and it can't move, or valves are opened and
 /// hydraulic power can move it with enough pressure.
+///
+/// When failures are attached, a jammed actuator stays at its current position whatever the demand, and a runaway
+/// actuator drives to full extension with whatever pressure is available.
 #[derive(PartialEq, Clone, Copy)]
 pub struct LinearActuator {
     number_of_actuators: u8,
@@ -967,6 +971,9 @@ pub struct LinearActuator {
     core_hydraulics: CoreHydraulicForce,
 
     electro_hydrostatic_backup: Option<ElectroHydrostaticBackup>,
+
+    jam_failure: Option<Failure>,
+    runaway_failure: Option<Failure>,
 }
 impl LinearActuator {
     pub fn new(
@@ -1085,6 +1092,38 @@ impl LinearActuator {
                 max_working_pressure,
             ),
             electro_hydrostatic_backup,
+
+            jam_failure: None,
+            runaway_failure: None,
+        }
+    }
+
+    pub fn with_failures(mut self, jam: FailureType, runaway: FailureType) -> Self {
+        self.jam_failure = Some(Failure::new(jam));
+        self.runaway_failure = Some(Failure::new(runaway));
+        self
+    }
+
+    fn is_jammed(&self) -> bool {
+        self.jam_failure.is_some_and(|failure| failure.is_active())
+    }
+
+    fn is_running_away(&self) -> bool {
+        self.runaway_failure
+            .is_some_and(|failure| failure.is_active())
+    }
+
+    /// A jam takes precedence over a runaway, as the actuator can't move anymore.
+    fn effective_mode_and_position(
+        &self,
+        requested_mode: LinearActuatorMode,
+    ) -> (LinearActuatorMode, Ratio) {
+        if self.is_jammed() {
+            (LinearActuatorMode::ClosedValves, self.requested_position)
+        } else if self.is_running_away() {
+            (LinearActuatorMode::PositionControl, Ratio::new::<ratio>(1.))
+        } else {
+            (requested_mode, self.requested_position)
         }
     }
 
@@ -1124,10 +1163,12 @@ impl LinearActuator {
             Pressure::default()
         };
 
+        let (mode, position) = self.effective_mode_and_position(controller.requested_mode());
+
         self.core_hydraulics.update_force(
             context,
-            self.requested_position,
-            controller.requested_mode(),
+            position,
+            mode,
             self.position_normalized,
             internal_actuator_pressure,
             self.signed_flow,
@@ -1265,6 +1306,12 @@ impl SimulationElement for LinearActuator {
         if let Some(eha) = self.electro_hydrostatic_backup.as_mut() {
             eha.accept(visitor);
         };
+        if let Some(failure) = self.jam_failure.as_mut() {
+            failure.accept(visitor);
+        }
+        if let Some(failure) = self.runaway_failure.as_mut() {
+            failure.accept(visitor);
+        }
 
         visitor.visit(self);
     }
@@ -1990,8 +2037,8 @@ mod tests {
     use crate::electrical::ElectricalBus;
     use crate::electrical::Electricity;
 
-    use crate::shared::PowerConsumptionReport;
     use crate::shared::{update_iterator::MaxStepLoop, PotentialOrigin};
+    use crate::shared::{FlightControlSurface, PowerConsumptionReport};
     use crate::simulation::test::{ElementCtorFn, SimulationTestBed, TestBed, WriteByName};
     use crate::simulation::{Aircraft, InitContext, SimulationElement};
     use std::time::Duration;
@@ -3335,6 +3382,67 @@ mod tests {
         }
     }
 
+    #[test]
+    fn jammed_aileron_actuator_holds_its_position() {
+        let mut test_bed = SimulationTestBed::new(|context| {
+            let tested_object = aileron_assembly_with_failures(context);
+            TestAircraft::new(context, tested_object)
+        });
+
+        test_bed.command(|a| a.command_unlock());
+        test_bed.command(|a| {
+            a.set_pressures([Pressure::new::<psi>(3000.), Pressure::new::<psi>(3000.)])
+        });
+        test_bed.command(|a| a.command_active_damping_mode(0));
+        test_bed.command(|a| a.command_position_control(Ratio::new::<ratio>(0.2), 1));
+
+        for _ in 0..5 {
+            test_bed.run_with_delta(Duration::from_secs_f64(0.5));
+        }
+        assert!(test_bed.query(|a| a.body_position()) < Ratio::new::<ratio>(0.25));
+
+        test_bed.fail(FailureType::FlightControlActuatorJam(
+            FlightControlSurface::LeftAileron,
+            1,
+        ));
+        test_bed.command(|a| a.command_position_control(Ratio::new::<ratio>(0.8), 1));
+
+        for _ in 0..5 {
+            test_bed.run_with_delta(Duration::from_secs_f64(0.5));
+            assert!(test_bed.query(|a| a.body_position()) < Ratio::new::<ratio>(0.25));
+        }
+    }
+
+    #[test]
+    fn runaway_aileron_actuator_drives_to_full_extension() {
+        let mut test_bed = SimulationTestBed::new(|context| {
+            let tested_object = aileron_assembly_with_failures(context);
+            TestAircraft::new(context, tested_object)
+        });
+
+        test_bed.command(|a| a.command_unlock());
+        test_bed.command(|a| {
+            a.set_pressures([Pressure::new::<psi>(3000.), Pressure::new::<psi>(3000.)])
+        });
+        test_bed.command(|a| a.command_active_damping_mode(0));
+        test_bed.command(|a| a.command_position_control(Ratio::new::<ratio>(0.2), 1));
+
+        for _ in 0..5 {
+            test_bed.run_with_delta(Duration::from_secs_f64(0.5));
+        }
+        assert!(test_bed.query(|a| a.body_position()) < Ratio::new::<ratio>(0.25));
+
+        test_bed.fail(FailureType::FlightControlActuatorRunaway(
+            FlightControlSurface::LeftAileron,
+            1,
+        ));
+
+        for _ in 0..5 {
+            test_bed.run_with_delta(Duration::from_secs_f64(0.5));
+        }
+        assert!(test_bed.query(|a| a.body_position()) > Ratio::new::<ratio>(0.9));
+    }
+
     #[test]
     fn aileron_position_control_from_down_to_up_less_0_5s() {
         let mut test_bed = SimulationTestBed::new(|context| {
@@ -4491,6 +4599,18 @@ mod tests {
         HydraulicLinearActuatorAssembly::new([actuator, actuator], rigid_body)
     }
 
+    fn aileron_assembly_with_failures(
+        context: &mut InitContext,
+    ) -> HydraulicLinearActuatorAssembly<2> {
+        let rigid_body = aileron_body(false);
+        let actuator = aileron_actuator(context, &rigid_body).with_failures(
+            FailureType::FlightControlActuatorJam(FlightControlSurface::LeftAileron, 1),
+            FailureType::FlightControlActuatorRunaway(FlightControlSurface::LeftAileron, 1),
+        );
+
+        HydraulicLinearActuatorAssembly::new([actuator, actuator], rigid_body)
+    }
+
     fn aileron_actuator(
         context: &mut InitContext,
         bounded_linear_length: &impl BoundedLinearLength,
@@ -34,6 +34,7 @@ use uom::si::{
 
 pub mod aerodynamic_model;
 pub mod brake_circuit;
+pub mod brake_thermal;
 pub mod bypass_pin;
 pub mod cargo_doors;
 pub mod electrical_generator;
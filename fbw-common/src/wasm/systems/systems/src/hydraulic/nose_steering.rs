@@ -101,6 +101,7 @@ pub struct SteeringActuator {
     current_position: Angle,
 
     max_half_angle: Angle,
+    authority: Ratio,
 
     nominal_speed: AngularVelocity,
 
@@ -145,6 +146,7 @@ impl SteeringActuator {
             current_position: Angle::new::<radian>(0.),
 
             max_half_angle,
+            authority: Ratio::new::<ratio>(1.),
 
             nominal_speed,
             angular_to_linear_ratio,
@@ -171,9 +173,10 @@ impl SteeringActuator {
         bypass_pin: &BypassPin,
     ) {
         if !bypass_pin.is_nose_wheel_steering_pin_inserted() || !self.is_steered_by_tug {
+            let max_angle = self.max_half_angle * self.authority.get::<ratio>();
             let limited_requested_angle = steering_controller
                 .requested_position()
-                .clamp(-self.max_half_angle, self.max_half_angle);
+                .clamp(-max_angle, max_angle);
 
             self.update_current_speed(context, section_pressure, limited_requested_angle);
 
@@ -185,6 +188,13 @@ impl SteeringActuator {
         self.update_flow(context, bypass_pin);
     }
 
+    /// Limits the steering angle the actuator can reach against the scrubbing torque of the
+    /// wheels, as a ratio of its maximum angle. A deflated tyre scrubs much more than an
+    /// inflated one.
+    pub fn set_authority(&mut self, authority: Ratio) {
+        self.authority = authority.clamp(Ratio::new::<ratio>(0.), Ratio::new::<ratio>(1.));
+    }
+
     fn update_final_speed_position(&mut self, context: &UpdateContext) {
         self.current_position += Angle::new::<radian>(
             self.current_speed.output().get::<radian_per_second>() * context.delta_as_secs_f64(),
@@ -427,6 +437,10 @@ mod tests {
         fn set_pushback(&mut self) {
             self.pushback.set_pin_inserted();
         }
+
+        fn set_authority(&mut self, authority: Ratio) {
+            self.steering_actuator.set_authority(authority);
+        }
     }
     impl Aircraft for TestAircraft {
         fn update_after_power_distribution(&mut self, context: &UpdateContext) {
@@ -511,6 +525,31 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn steering_angle_limited_by_reduced_authority() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+
+        test_bed.command(|a| a.set_pressure(Pressure::new::<psi>(3000.)));
+        test_bed.command(|a| a.set_authority(Ratio::new::<ratio>(0.4)));
+        test_bed.command(|a| a.command_steer_angle(Angle::new::<degree>(90.)));
+
+        test_bed.run_multiple_frames(Duration::from_secs(10));
+
+        assert!(is_equal_angle(
+            test_bed.query(|a| a.steering_actuator.position_feedback()),
+            Angle::new::<degree>(30.)
+        ));
+
+        test_bed.command(|a| a.set_authority(Ratio::new::<ratio>(1.)));
+
+        test_bed.run_multiple_frames(Duration::from_secs(10));
+
+        assert!(is_equal_angle(
+            test_bed.query(|a| a.steering_actuator.position_feedback()),
+            Angle::new::<degree>(75.)
+        ));
+    }
+
     #[test]
     fn steering_not_moving_with_pushback_pin_inserted() {
         let mut test_bed = SimulationTestBed::new(TestAircraft::new);
@@ -1,5 +1,5 @@
 use super::{
-    avionics_full_duplex_switch::AvionicsFullDuplexSwitch,
+    avionics_full_duplex_switch::AvionicsFullDuplexSwitch, virtual_link::AfdxEndSystem,
     AvionicsDataCommunicationNetworkEndpoint, AvionicsDataCommunicationNetworkMessage,
     AvionicsDataCommunicationNetworkMessageIdentifier,
 };
@@ -38,6 +38,7 @@ pub struct CoreProcessingInputOutputModule<MessageData: Clone + Eq + PartialEq>
     failure_indication_id: VariableIdentifier,
     failure_indication: bool,
     connected_switches: Vec<Rc<RefCell<AvionicsFullDuplexSwitch<MessageData>>>>,
+    end_system: AfdxEndSystem<MessageData>,
 }
 
 impl<MessageData: Clone + Eq + PartialEq> CoreProcessingInputOutputModule<MessageData> {
@@ -54,12 +55,22 @@ impl<MessageData: Clone + Eq + PartialEq> CoreProcessingInputOutputModule<Messag
             failure_indication_id: context.get_identifier(format!("CPIOM_{}_FAILURE", name)),
             failure_indication: false,
             connected_switches,
+            end_system: AfdxEndSystem::new(),
         }
     }
 
     pub fn is_available(&self) -> bool {
         self.is_powered & !self.failure_indication
     }
+
+    /// Returns the AFDX end system which sends and receives messages on virtual links.
+    pub fn end_system(&self) -> &AfdxEndSystem<MessageData> {
+        &self.end_system
+    }
+
+    pub fn end_system_mut(&mut self) -> &mut AfdxEndSystem<MessageData> {
+        &mut self.end_system
+    }
 }
 
 impl<MessageData: Clone + Eq + PartialEq> AvionicsDataCommunicationNetworkEndpoint
@@ -71,7 +82,8 @@ impl<MessageData: Clone + Eq + PartialEq> AvionicsDataCommunicationNetworkEndpoi
         &self,
         id: &AvionicsDataCommunicationNetworkMessageIdentifier,
     ) -> Option<AvionicsDataCommunicationNetworkMessage<Self::MessageData>> {
-        // TODO: check if there is a newer message on the other networks
+        // Messages which need to be checked for the newest copy on the redundant networks are
+        // sent over virtual links, see [`Self::end_system`].
         self.connected_switches
             .iter()
             .find_map(|switch| switch.borrow().recv_value(id))
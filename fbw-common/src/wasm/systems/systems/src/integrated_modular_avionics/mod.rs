@@ -1,12 +1,14 @@
 use self::{
     core_processing_input_output_module::CoreProcessingInputOutputModule,
     input_output_module::InputOutputModule,
+    virtual_link::{VirtualLinkConfiguration, VirtualLinkIdentifier},
 };
 use std::ops::Deref;
 
 pub mod avionics_full_duplex_switch;
 pub mod core_processing_input_output_module;
 pub mod input_output_module;
+pub mod virtual_link;
 
 /// Represents an identifier for messages in the Avionics Data Communication Network.
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
@@ -18,6 +20,14 @@ impl AvionicsDataCommunicationNetworkMessageIdentifier {
     }
 }
 
+/// Identifies a module which is connected to the Avionics Data Communication Network by an
+/// AFDX end system.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum AvionicsDataCommunicationNetworkModule {
+    Cpiom(&'static str),
+    Iom(&'static str),
+}
+
 pub trait AvionicsDataCommunicationNetworkEndpoint {
     type MessageData: Clone + Eq + PartialEq;
 
@@ -68,6 +78,16 @@ pub trait AvionicsDataCommunicationNetwork<'a, MessageData: Clone + Eq + Partial
 
     /// Returns a reference to the IOM with the specified name.
     fn get_iom(&self, name: &str) -> &InputOutputModule<MessageData>;
+
+    /// Adds a virtual link from the source module to the destination modules and returns its
+    /// identifier. Messages on the virtual link are sent and received with the end systems of
+    /// the modules.
+    fn add_virtual_link(
+        &mut self,
+        source: AvionicsDataCommunicationNetworkModule,
+        destinations: Vec<AvionicsDataCommunicationNetworkModule>,
+        configuration: VirtualLinkConfiguration,
+    ) -> VirtualLinkIdentifier;
 }
 
 /// This type represents a message on the ACDN
@@ -0,0 +1,677 @@
+//! AFDX virtual links as defined by ARINC 664 part 7.
+//!
+//! A virtual link is a unidirectional logical connection from one end system to one or more end
+//! systems. The transmitting end system regulates the traffic of each virtual link to at most
+//! one frame per bandwidth allocation gap (BAG) and numbers the frames with a sequence number.
+//! Every frame is sent on both redundant networks.
+//!
+//! The receiving end system first checks the integrity of the frames per network, which discards
+//! frames whose sequence number doesn't follow the previous one received on the same network.
+//! Afterwards the redundancy management passes the first valid copy of a frame to the
+//! application and discards the copy arriving on the other network.
+
+use super::AvionicsDataCommunicationNetworkMessage;
+use fxhash::FxHashMap;
+use std::{cell::RefCell, time::Duration};
+
+/// Represents an identifier for virtual links in the Avionics Data Communication Network.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub struct VirtualLinkIdentifier(usize);
+impl VirtualLinkIdentifier {
+    /// Returns the next identifier in the sequence.
+    pub fn next(&self) -> Self {
+        Self(self.0 + 1)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AfdxNetwork {
+    A = 0,
+    B = 1,
+}
+impl AfdxNetwork {
+    pub const ALL: [AfdxNetwork; 2] = [AfdxNetwork::A, AfdxNetwork::B];
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct VirtualLinkConfiguration {
+    bag: Duration,
+    max_frame_size: u16,
+}
+impl VirtualLinkConfiguration {
+    const MIN_FRAME_SIZE: u16 = 64;
+    const MAX_FRAME_SIZE: u16 = 1518;
+
+    /// Creates a virtual link configuration. The BAG has to be a power of two milliseconds
+    /// between 1 ms and 128 ms, the maximum frame size between 64 and 1518 bytes.
+    pub fn new(bag: Duration, max_frame_size: u16) -> Self {
+        let bag_millis = bag.as_millis();
+        assert!(
+            bag.subsec_nanos() % 1_000_000 == 0
+                && (1..=128).contains(&bag_millis)
+                && bag_millis.is_power_of_two(),
+            "The BAG of a virtual link has to be a power of two milliseconds in [1, 128]."
+        );
+        assert!(
+            (Self::MIN_FRAME_SIZE..=Self::MAX_FRAME_SIZE).contains(&max_frame_size),
+            "The maximum frame size of a virtual link has to be in [64, 1518] bytes."
+        );
+
+        Self {
+            bag,
+            max_frame_size,
+        }
+    }
+
+    pub fn bag(&self) -> Duration {
+        self.bag
+    }
+
+    pub fn max_frame_size(&self) -> u16 {
+        self.max_frame_size
+    }
+}
+
+/// A copy of a frame as received on one of the networks.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AfdxFrame<MessageData: Clone + Eq + PartialEq> {
+    virtual_link: VirtualLinkIdentifier,
+    network: AfdxNetwork,
+    sequence_number: u8,
+    latency: Duration,
+    message: AvionicsDataCommunicationNetworkMessage<MessageData>,
+}
+impl<MessageData: Clone + Eq + PartialEq> AfdxFrame<MessageData> {
+    pub fn new(
+        virtual_link: VirtualLinkIdentifier,
+        network: AfdxNetwork,
+        sequence_number: u8,
+        latency: Duration,
+        message: AvionicsDataCommunicationNetworkMessage<MessageData>,
+    ) -> Self {
+        Self {
+            virtual_link,
+            network,
+            sequence_number,
+            latency,
+            message,
+        }
+    }
+
+    pub fn virtual_link(&self) -> VirtualLinkIdentifier {
+        self.virtual_link
+    }
+
+    pub fn network(&self) -> AfdxNetwork {
+        self.network
+    }
+
+    pub fn sequence_number(&self) -> u8 {
+        self.sequence_number
+    }
+
+    /// The time the frame took from the transmitting to the receiving end system.
+    pub fn latency(&self) -> Duration {
+        self.latency
+    }
+
+    pub fn message(&self) -> &AvionicsDataCommunicationNetworkMessage<MessageData> {
+        &self.message
+    }
+}
+
+/// The sequence number following the given one. Zero is only used for the first frame after
+/// the end system started, afterwards the sequence numbers wrap from 255 to 1.
+fn next_sequence_number(sequence_number: u8) -> u8 {
+    if sequence_number == u8::MAX {
+        1
+    } else {
+        sequence_number + 1
+    }
+}
+
+/// The distance from one sequence number to another, skipping zero when wrapping.
+fn sequence_number_distance(from: u8, to: u8) -> u8 {
+    if from == 0 {
+        to
+    } else if to >= from {
+        to - from
+    } else {
+        (u8::MAX - from) + to
+    }
+}
+
+/// The transmitting side of a virtual link. It works like a sampling port, only the latest
+/// message which wasn't transmitted yet is sent once the BAG expired.
+struct VirtualLinkTransmitter<MessageData: Clone + Eq + PartialEq> {
+    configuration: VirtualLinkConfiguration,
+    pending: Option<AvionicsDataCommunicationNetworkMessage<MessageData>>,
+    time_since_last_frame: Option<Duration>,
+    next_sequence_number: u8,
+}
+impl<MessageData: Clone + Eq + PartialEq> VirtualLinkTransmitter<MessageData> {
+    fn new(configuration: VirtualLinkConfiguration) -> Self {
+        Self {
+            configuration,
+            pending: None,
+            time_since_last_frame: None,
+            next_sequence_number: 0,
+        }
+    }
+
+    fn send(&mut self, message: AvionicsDataCommunicationNetworkMessage<MessageData>) {
+        self.pending = Some(message);
+    }
+
+    fn transmit(
+        &mut self,
+        delta: Duration,
+    ) -> Option<(u8, AvionicsDataCommunicationNetworkMessage<MessageData>)> {
+        if let Some(time_since_last_frame) = &mut self.time_since_last_frame {
+            *time_since_last_frame += delta;
+        }
+
+        let bag_expired = self
+            .time_since_last_frame
+            .map_or(true, |time| time >= self.configuration.bag());
+        if !bag_expired {
+            return None;
+        }
+
+        let message = self.pending.take()?;
+        let sequence_number = self.next_sequence_number;
+        self.next_sequence_number = next_sequence_number(sequence_number);
+        self.time_since_last_frame = Some(Duration::ZERO);
+
+        Some((sequence_number, message))
+    }
+
+    fn reset(&mut self) {
+        self.pending = None;
+        self.time_since_last_frame = None;
+        self.next_sequence_number = 0;
+    }
+}
+
+/// The receiving side of a virtual link, including the integrity checking and the redundancy
+/// management.
+pub struct VirtualLinkReceiver<MessageData: Clone + Eq + PartialEq> {
+    previous_sequence_numbers: [Option<u8>; 2],
+    last_accepted_sequence_number: Option<u8>,
+    message: Option<AvionicsDataCommunicationNetworkMessage<MessageData>>,
+    age: Duration,
+    latency: Duration,
+    accepted_frames: u64,
+    duplicate_frames: u64,
+    integrity_errors: u64,
+}
+impl<MessageData: Clone + Eq + PartialEq> VirtualLinkReceiver<MessageData> {
+    /// Frames which are more than this many sequence numbers ahead of the last accepted frame
+    /// are considered to be older than it.
+    const REDUNDANCY_WINDOW: u8 = 127;
+
+    fn new() -> Self {
+        Self {
+            previous_sequence_numbers: [None; 2],
+            last_accepted_sequence_number: None,
+            message: None,
+            age: Duration::ZERO,
+            latency: Duration::ZERO,
+            accepted_frames: 0,
+            duplicate_frames: 0,
+            integrity_errors: 0,
+        }
+    }
+
+    /// Passes a received frame through integrity checking and redundancy management. Returns
+    /// true when the frame was accepted.
+    pub fn receive(&mut self, frame: AfdxFrame<MessageData>) -> bool {
+        let sequence_number = frame.sequence_number();
+        let previous = &mut self.previous_sequence_numbers[frame.network() as usize];
+
+        // A frame may be lost, so the sequence number may skip one.
+        let is_valid = sequence_number == 0
+            || previous.map_or(true, |previous| {
+                (1..=2).contains(&sequence_number_distance(previous, sequence_number))
+            });
+        *previous = Some(sequence_number);
+
+        if !is_valid {
+            self.integrity_errors += 1;
+            return false;
+        }
+
+        // Zero restarts the sequence, unless its copy from the other network was accepted already.
+        let is_newer =
+            self.last_accepted_sequence_number
+                .map_or(true, |last| match sequence_number {
+                    0 => last != 0,
+                    _ => (1..=Self::REDUNDANCY_WINDOW)
+                        .contains(&sequence_number_distance(last, sequence_number)),
+                });
+        if !is_newer {
+            self.duplicate_frames += 1;
+            return false;
+        }
+
+        self.last_accepted_sequence_number = Some(sequence_number);
+        self.latency = frame.latency();
+        self.message = Some(frame.message);
+        self.age = Duration::ZERO;
+        self.accepted_frames += 1;
+
+        true
+    }
+
+    fn update(&mut self, delta: Duration) {
+        if self.message.is_some() {
+            self.age += delta;
+        }
+    }
+
+    fn reset(&mut self) {
+        *self = Self::new();
+    }
+
+    /// The last message passed to the application.
+    pub fn message(&self) -> Option<&AvionicsDataCommunicationNetworkMessage<MessageData>> {
+        self.message.as_ref()
+    }
+
+    /// The time since the last message was accepted, `None` when nothing was received yet.
+    pub fn age(&self) -> Option<Duration> {
+        self.message.as_ref().map(|_| self.age)
+    }
+
+    /// Indicates that no message was accepted within the given time.
+    pub fn is_stale(&self, max_age: Duration) -> bool {
+        self.age().map_or(true, |age| age > max_age)
+    }
+
+    /// The latency of the last accepted frame.
+    pub fn latency(&self) -> Duration {
+        self.latency
+    }
+
+    pub fn accepted_frames(&self) -> u64 {
+        self.accepted_frames
+    }
+
+    /// The number of valid frames discarded by the redundancy management, which normally are
+    /// the copies received on the slower network.
+    pub fn duplicate_frames(&self) -> u64 {
+        self.duplicate_frames
+    }
+
+    /// The number of frames discarded by the integrity checking.
+    pub fn integrity_errors(&self) -> u64 {
+        self.integrity_errors
+    }
+}
+
+/// The AFDX end system of a module connected to the network, which holds the transmit and
+/// receive ports of its virtual links.
+pub struct AfdxEndSystem<MessageData: Clone + Eq + PartialEq> {
+    transmit_ports: RefCell<FxHashMap<VirtualLinkIdentifier, VirtualLinkTransmitter<MessageData>>>,
+    receive_ports: FxHashMap<VirtualLinkIdentifier, VirtualLinkReceiver<MessageData>>,
+}
+impl<MessageData: Clone + Eq + PartialEq> AfdxEndSystem<MessageData> {
+    pub fn new() -> Self {
+        Self {
+            transmit_ports: RefCell::new(FxHashMap::default()),
+            receive_ports: FxHashMap::default(),
+        }
+    }
+
+    pub fn add_transmit_port(
+        &mut self,
+        virtual_link: VirtualLinkIdentifier,
+        configuration: VirtualLinkConfiguration,
+    ) {
+        self.transmit_ports
+            .get_mut()
+            .insert(virtual_link, VirtualLinkTransmitter::new(configuration));
+    }
+
+    pub fn add_receive_port(&mut self, virtual_link: VirtualLinkIdentifier) {
+        self.receive_ports
+            .insert(virtual_link, VirtualLinkReceiver::new());
+    }
+
+    /// Sends the message on the virtual link once its BAG allows for it.
+    ///
+    /// # Panics
+    /// Panics when the end system isn't the source of the virtual link.
+    pub fn send(
+        &self,
+        virtual_link: &VirtualLinkIdentifier,
+        message: AvionicsDataCommunicationNetworkMessage<MessageData>,
+    ) {
+        self.transmit_ports
+            .borrow_mut()
+            .get_mut(virtual_link)
+            .expect("The end system is not the source of the virtual link.")
+            .send(message);
+    }
+
+    /// Returns the receive port of the virtual link.
+    ///
+    /// # Panics
+    /// Panics when the end system isn't a destination of the virtual link.
+    pub fn receive(
+        &self,
+        virtual_link: &VirtualLinkIdentifier,
+    ) -> &VirtualLinkReceiver<MessageData> {
+        self.receive_ports
+            .get(virtual_link)
+            .expect("The end system is not a destination of the virtual link.")
+    }
+
+    /// Returns the frame to transmit on the virtual link during this tick, if any.
+    pub fn transmit(
+        &mut self,
+        virtual_link: &VirtualLinkIdentifier,
+        delta: Duration,
+    ) -> Option<(u8, AvionicsDataCommunicationNetworkMessage<MessageData>)> {
+        self.transmit_ports
+            .get_mut()
+            .get_mut(virtual_link)
+            .and_then(|port| port.transmit(delta))
+    }
+
+    pub fn deliver(&mut self, frame: AfdxFrame<MessageData>) {
+        if let Some(port) = self.receive_ports.get_mut(&frame.virtual_link()) {
+            port.receive(frame);
+        }
+    }
+
+    pub fn update(&mut self, delta: Duration) {
+        self.receive_ports
+            .values_mut()
+            .for_each(|port| port.update(delta));
+    }
+
+    /// Restarts the end system, e.g. after a loss of power. Pending and received messages are
+    /// lost and the sequence numbers start over.
+    pub fn reset(&mut self) {
+        self.transmit_ports
+            .get_mut()
+            .values_mut()
+            .for_each(|port| port.reset());
+        self.receive_ports
+            .values_mut()
+            .for_each(|port| port.reset());
+    }
+}
+impl<MessageData: Clone + Eq + PartialEq> Default for AfdxEndSystem<MessageData> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::integrated_modular_avionics::AvionicsDataCommunicationNetworkMessageFunctionalDataSetStatus;
+
+    type Message = AvionicsDataCommunicationNetworkMessage<u32>;
+
+    fn message(value: u32) -> Message {
+        Message::new(
+            AvionicsDataCommunicationNetworkMessageFunctionalDataSetStatus::NormalOperation,
+            value,
+        )
+    }
+
+    fn frame(network: AfdxNetwork, sequence_number: u8, value: u32) -> AfdxFrame<u32> {
+        AfdxFrame::new(
+            VirtualLinkIdentifier::default(),
+            network,
+            sequence_number,
+            Duration::from_micros(200),
+            message(value),
+        )
+    }
+
+    fn configuration(bag_millis: u64) -> VirtualLinkConfiguration {
+        VirtualLinkConfiguration::new(Duration::from_millis(bag_millis), 128)
+    }
+
+    #[test]
+    fn sequence_numbers_wrap_to_one() {
+        assert_eq!(next_sequence_number(0), 1);
+        assert_eq!(next_sequence_number(254), 255);
+        assert_eq!(next_sequence_number(255), 1);
+    }
+
+    #[test]
+    fn sequence_number_distance_skips_zero() {
+        assert_eq!(sequence_number_distance(1, 2), 1);
+        assert_eq!(sequence_number_distance(255, 1), 1);
+        assert_eq!(sequence_number_distance(254, 1), 2);
+        assert_eq!(sequence_number_distance(0, 1), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn bag_has_to_be_a_power_of_two() {
+        configuration(3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn bag_cannot_exceed_128_ms() {
+        configuration(256);
+    }
+
+    #[test]
+    fn transmitter_sends_first_message_immediately() {
+        let mut transmitter = VirtualLinkTransmitter::new(configuration(32));
+        transmitter.send(message(1));
+
+        assert_eq!(
+            transmitter.transmit(Duration::from_millis(10)),
+            Some((0, message(1)))
+        );
+    }
+
+    #[test]
+    fn transmitter_sends_nothing_without_a_message() {
+        let mut transmitter = VirtualLinkTransmitter::<u32>::new(configuration(32));
+
+        assert_eq!(transmitter.transmit(Duration::from_millis(10)), None);
+    }
+
+    #[test]
+    fn transmitter_respects_the_bag() {
+        let mut transmitter = VirtualLinkTransmitter::new(configuration(32));
+        transmitter.send(message(1));
+        transmitter.transmit(Duration::from_millis(10));
+
+        transmitter.send(message(2));
+        assert_eq!(transmitter.transmit(Duration::from_millis(16)), None);
+
+        transmitter.send(message(3));
+        assert_eq!(
+            transmitter.transmit(Duration::from_millis(16)),
+            Some((1, message(3)))
+        );
+    }
+
+    #[test]
+    fn transmitter_restarts_sequence_numbers_after_reset() {
+        let mut transmitter = VirtualLinkTransmitter::new(configuration(1));
+        for value in 0..3 {
+            transmitter.send(message(value));
+            transmitter.transmit(Duration::from_millis(1));
+        }
+
+        transmitter.reset();
+        transmitter.send(message(4));
+
+        assert_eq!(
+            transmitter.transmit(Duration::from_millis(1)),
+            Some((0, message(4)))
+        );
+    }
+
+    #[test]
+    fn first_valid_copy_wins() {
+        let mut receiver = VirtualLinkReceiver::new();
+
+        assert!(receiver.receive(frame(AfdxNetwork::B, 0, 1)));
+        assert!(!receiver.receive(frame(AfdxNetwork::A, 0, 1)));
+
+        assert_eq!(receiver.message(), Some(&message(1)));
+        assert_eq!(receiver.accepted_frames(), 1);
+        assert_eq!(receiver.duplicate_frames(), 1);
+    }
+
+    #[test]
+    fn frames_of_a_single_network_are_accepted() {
+        let mut receiver = VirtualLinkReceiver::new();
+
+        for sequence_number in 0..10 {
+            assert!(receiver.receive(frame(AfdxNetwork::A, sequence_number, 1)));
+        }
+
+        assert_eq!(receiver.accepted_frames(), 10);
+        assert_eq!(receiver.duplicate_frames(), 0);
+    }
+
+    #[test]
+    fn a_single_lost_frame_passes_integrity_checking() {
+        let mut receiver = VirtualLinkReceiver::new();
+        receiver.receive(frame(AfdxNetwork::A, 1, 1));
+
+        assert!(receiver.receive(frame(AfdxNetwork::A, 3, 3)));
+        assert_eq!(receiver.integrity_errors(), 0);
+    }
+
+    #[test]
+    fn integrity_checking_discards_out_of_sequence_frames() {
+        let mut receiver = VirtualLinkReceiver::new();
+        receiver.receive(frame(AfdxNetwork::A, 1, 1));
+
+        assert!(!receiver.receive(frame(AfdxNetwork::A, 5, 5)));
+        assert_eq!(receiver.integrity_errors(), 1);
+        assert_eq!(receiver.message(), Some(&message(1)));
+
+        // The integrity checking resynchronises on the discarded frame.
+        assert!(receiver.receive(frame(AfdxNetwork::A, 6, 6)));
+    }
+
+    #[test]
+    fn integrity_checking_is_done_per_network() {
+        let mut receiver = VirtualLinkReceiver::new();
+        receiver.receive(frame(AfdxNetwork::A, 1, 1));
+        receiver.receive(frame(AfdxNetwork::A, 2, 2));
+        receiver.receive(frame(AfdxNetwork::A, 3, 3));
+
+        // Network B wasn't heard from before, so its first frame is valid.
+        assert!(receiver.receive(frame(AfdxNetwork::B, 4, 4)));
+        assert_eq!(receiver.integrity_errors(), 0);
+    }
+
+    #[test]
+    fn older_frames_are_discarded_as_duplicates() {
+        let mut receiver = VirtualLinkReceiver::new();
+        receiver.receive(frame(AfdxNetwork::A, 1, 1));
+        receiver.receive(frame(AfdxNetwork::A, 2, 2));
+        receiver.receive(frame(AfdxNetwork::B, 1, 1));
+
+        assert!(!receiver.receive(frame(AfdxNetwork::B, 2, 2)));
+        assert_eq!(receiver.message(), Some(&message(2)));
+        assert_eq!(receiver.duplicate_frames(), 2);
+    }
+
+    #[test]
+    fn sequence_number_wrap_is_not_a_duplicate() {
+        let mut receiver = VirtualLinkReceiver::new();
+        receiver.receive(frame(AfdxNetwork::A, 255, 1));
+
+        assert!(receiver.receive(frame(AfdxNetwork::A, 1, 2)));
+    }
+
+    #[test]
+    fn transmitter_reset_is_accepted() {
+        let mut receiver = VirtualLinkReceiver::new();
+        receiver.receive(frame(AfdxNetwork::A, 100, 1));
+
+        assert!(receiver.receive(frame(AfdxNetwork::A, 0, 2)));
+        assert!(receiver.receive(frame(AfdxNetwork::A, 1, 3)));
+        assert_eq!(receiver.integrity_errors(), 0);
+    }
+
+    #[test]
+    fn redundant_copy_of_a_transmitter_reset_is_a_duplicate() {
+        let mut receiver = VirtualLinkReceiver::new();
+        receiver.receive(frame(AfdxNetwork::A, 100, 1));
+        receiver.receive(frame(AfdxNetwork::A, 0, 2));
+
+        assert!(!receiver.receive(frame(AfdxNetwork::B, 0, 2)));
+        assert_eq!(receiver.message(), Some(&message(2)));
+        assert_eq!(receiver.duplicate_frames(), 1);
+    }
+
+    #[test]
+    fn message_ages_until_the_next_frame() {
+        let mut receiver = VirtualLinkReceiver::new();
+        assert!(receiver.is_stale(Duration::from_secs(1)));
+        assert_eq!(receiver.age(), None);
+
+        receiver.receive(frame(AfdxNetwork::A, 0, 1));
+        receiver.update(Duration::from_millis(600));
+        assert!(!receiver.is_stale(Duration::from_secs(1)));
+
+        receiver.update(Duration::from_millis(600));
+        assert!(receiver.is_stale(Duration::from_secs(1)));
+
+        receiver.receive(frame(AfdxNetwork::A, 1, 2));
+        assert_eq!(receiver.age(), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn end_system_delivers_frames_to_the_receive_port() {
+        let virtual_link = VirtualLinkIdentifier::default();
+        let mut end_system = AfdxEndSystem::new();
+        end_system.add_receive_port(virtual_link);
+
+        end_system.deliver(frame(AfdxNetwork::A, 0, 42));
+
+        assert_eq!(
+            end_system.receive(&virtual_link).message(),
+            Some(&message(42))
+        );
+        assert_eq!(
+            end_system.receive(&virtual_link).latency(),
+            Duration::from_micros(200)
+        );
+    }
+
+    #[test]
+    fn end_system_loses_received_messages_on_reset() {
+        let virtual_link = VirtualLinkIdentifier::default();
+        let mut end_system = AfdxEndSystem::new();
+        end_system.add_receive_port(virtual_link);
+        end_system.deliver(frame(AfdxNetwork::A, 0, 42));
+
+        end_system.reset();
+
+        assert!(end_system.receive(&virtual_link).message().is_none());
+        assert!(end_system
+            .receive(&virtual_link)
+            .is_stale(Duration::from_secs(1)));
+    }
+
+    #[test]
+    fn end_system_ignores_frames_of_unknown_virtual_links() {
+        let virtual_link = VirtualLinkIdentifier::default();
+        let mut end_system = AfdxEndSystem::new();
+        end_system.add_receive_port(virtual_link.next());
+
+        end_system.deliver(frame(AfdxNetwork::A, 0, 42));
+
+        assert!(end_system.receive(&virtual_link.next()).message().is_none());
+    }
+}
@@ -1,3 +1,5 @@
+pub mod tyre;
+
 use std::time::Duration;
 
 use crate::{
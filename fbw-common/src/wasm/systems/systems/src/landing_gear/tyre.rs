@@ -0,0 +1,856 @@
+use crate::{
+    failures::{Failure, FailureType},
+    shared::{
+        arinc429::{Arinc429Word, SignStatus},
+        ElectricalBusType, ElectricalBuses,
+    },
+    simulation::{
+        InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
+        VariableIdentifier, Write,
+    },
+};
+
+use std::{marker::PhantomData, time::Duration};
+
+use uom::si::{
+    angular_acceleration::radian_per_second_squared,
+    f64::*,
+    length::meter,
+    mass::kilogram,
+    pressure::psi,
+    ratio::ratio,
+    thermodynamic_temperature::kelvin,
+    velocity::{foot_per_second, meter_per_second},
+};
+
+/// Aircraft specific values used by the [`TyreModel`].
+pub trait TyreConstants {
+    /// Inflation pressure of a cold tyre, given at the reference temperature of 15°C.
+    const NOMINAL_COLD_PRESSURE_PSI: f64;
+    /// Rolling distance after which the tread is completely worn.
+    const TREAD_LIFE_METERS: f64;
+    /// Pressure ratio to the nominal pressure below which the TPIS reports a low pressure.
+    const LOW_PRESSURE_RATIO: f64;
+}
+
+struct Tyre {
+    pressure_id: VariableIdentifier,
+    temperature_id: VariableIdentifier,
+    wear_id: VariableIdentifier,
+
+    burst_failure: Failure,
+
+    temperature: ThermodynamicTemperature,
+    is_initialised: bool,
+    /// Ratio of the gas mass remaining in the tyre, 1 being a nominally inflated tyre.
+    inflation: f64,
+    wear: f64,
+    is_rolling: bool,
+}
+impl Tyre {
+    const REFERENCE_TEMPERATURE_KELVIN: f64 = 288.15;
+    const STANDARD_ATMOSPHERE_PSI: f64 = 14.696;
+
+    // Heat exchange rates of the tyre with the brake heat sink and with the ambient air
+    const BRAKE_CONDUCTION_PER_SECOND: f64 = 1. / 3600.;
+    const AMBIENT_CONVECTION_PER_SECOND: f64 = 1. / 1800.;
+    // Heating of the flexing carcass when rolling
+    const ROLLING_HEATING_KELVIN_PER_SECOND_PER_METER_PER_SECOND: f64 = 0.005;
+
+    // A burst tyre empties almost instantly, a melted fuse plug vents the tyre progressively
+    const BURST_DEFLATION_TIME_CONSTANT: Duration = Duration::from_millis(500);
+    const FUSE_PLUG_DEFLATION_TIME_CONSTANT: Duration = Duration::from_secs(30);
+
+    const DEFLATED_INFLATION_RATIO: f64 = 0.5;
+    const DEFLATED_BRAKING_EFFICIENCY: f64 = 0.3;
+    const WORN_TREAD_BRAKING_EFFICIENCY: f64 = 0.85;
+    const DEFLATED_STEERING_EFFICIENCY: f64 = 0.4;
+    const DEFLATED_ROLLING_DRAG: f64 = 0.1;
+
+    fn new(context: &mut InitContext, number: usize) -> Self {
+        Self {
+            pressure_id: context.get_identifier(format!("TYRE_PRESSURE_{}", number)),
+            temperature_id: context.get_identifier(format!("TYRE_TEMPERATURE_{}", number)),
+            wear_id: context.get_identifier(format!("TYRE_WEAR_{}", number)),
+
+            burst_failure: Failure::new(FailureType::TyreBurst(number)),
+
+            temperature: ThermodynamicTemperature::default(),
+            is_initialised: false,
+            inflation: 1.,
+            wear: 0.,
+            is_rolling: false,
+        }
+    }
+
+    fn update<C: TyreConstants>(
+        &mut self,
+        context: &UpdateContext,
+        brake_temperature: ThermodynamicTemperature,
+        fuse_plug_melted: bool,
+    ) {
+        let ambient = context.ambient_temperature().get::<kelvin>();
+        if !self.is_initialised {
+            self.temperature = context.ambient_temperature();
+            self.is_initialised = true;
+        }
+
+        let ground_speed = if context.is_on_ground() {
+            context.ground_speed().get::<meter_per_second>().abs()
+        } else {
+            0.
+        };
+        self.is_rolling = ground_speed > 0.;
+
+        self.update_temperature(context, ambient, brake_temperature, ground_speed);
+        self.update_inflation(context, fuse_plug_melted);
+
+        self.wear =
+            (self.wear + ground_speed * context.delta_as_secs_f64() / C::TREAD_LIFE_METERS).min(1.);
+    }
+
+    fn update_temperature(
+        &mut self,
+        context: &UpdateContext,
+        ambient: f64,
+        brake_temperature: ThermodynamicTemperature,
+        ground_speed: f64,
+    ) {
+        let exchange_rate = Self::BRAKE_CONDUCTION_PER_SECOND + Self::AMBIENT_CONVECTION_PER_SECOND;
+        let equilibrium = (Self::BRAKE_CONDUCTION_PER_SECOND * brake_temperature.get::<kelvin>()
+            + Self::AMBIENT_CONVECTION_PER_SECOND * ambient
+            + Self::ROLLING_HEATING_KELVIN_PER_SECOND_PER_METER_PER_SECOND * ground_speed)
+            / exchange_rate;
+
+        // Integrated as an exponential decay towards the equilibrium so that it remains stable
+        // with long frames.
+        let temperature = equilibrium
+            + (self.temperature.get::<kelvin>() - equilibrium)
+                * (-exchange_rate * context.delta_as_secs_f64()).exp();
+        self.temperature = ThermodynamicTemperature::new::<kelvin>(temperature);
+    }
+
+    fn update_inflation(&mut self, context: &UpdateContext, fuse_plug_melted: bool) {
+        let deflation_time_constant = if self.burst_failure.is_active() {
+            Some(Self::BURST_DEFLATION_TIME_CONSTANT)
+        } else if fuse_plug_melted {
+            Some(Self::FUSE_PLUG_DEFLATION_TIME_CONSTANT)
+        } else {
+            None
+        };
+
+        self.inflation = match deflation_time_constant {
+            Some(time_constant) => {
+                self.inflation * (-context.delta_as_secs_f64() / time_constant.as_secs_f64()).exp()
+            }
+            // Without any failure the tyre is considered serviced and inflated.
+            None => 1.,
+        };
+    }
+
+    /// Gauge pressure, following the ideal gas law for the constant volume of the tyre.
+    fn pressure<C: TyreConstants>(&self, ambient_pressure: Pressure) -> Pressure {
+        let absolute_cold_pressure = C::NOMINAL_COLD_PRESSURE_PSI + Self::STANDARD_ATMOSPHERE_PSI;
+        let absolute_pressure =
+            absolute_cold_pressure * self.inflation * self.temperature.get::<kelvin>()
+                / Self::REFERENCE_TEMPERATURE_KELVIN;
+
+        Pressure::new::<psi>((absolute_pressure - ambient_pressure.get::<psi>()).max(0.))
+    }
+
+    fn temperature(&self) -> ThermodynamicTemperature {
+        self.temperature
+    }
+
+    fn wear(&self) -> Ratio {
+        Ratio::new::<ratio>(self.wear)
+    }
+
+    fn is_deflated(&self) -> bool {
+        self.inflation < Self::DEFLATED_INFLATION_RATIO
+    }
+
+    fn braking_efficiency(&self) -> Ratio {
+        Ratio::new::<ratio>(if self.is_deflated() {
+            Self::DEFLATED_BRAKING_EFFICIENCY
+        } else {
+            1. - (1. - Self::WORN_TREAD_BRAKING_EFFICIENCY) * self.wear
+        })
+    }
+
+    fn steering_efficiency(&self) -> Ratio {
+        Ratio::new::<ratio>(if self.is_deflated() {
+            Self::DEFLATED_STEERING_EFFICIENCY
+        } else {
+            1.
+        })
+    }
+
+    fn rolling_drag(&self) -> Ratio {
+        Ratio::new::<ratio>(if self.is_deflated() && self.is_rolling {
+            Self::DEFLATED_ROLLING_DRAG
+        } else {
+            0.
+        })
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter, pressure: Pressure) {
+        writer.write(&self.pressure_id, pressure);
+        writer.write(&self.temperature_id, self.temperature);
+        writer.write(&self.wear_id, self.wear());
+    }
+}
+impl SimulationElement for Tyre {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.burst_failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
+/// Tyres of the landing gear wheels.
+///
+/// The tyre temperature follows the heat conducted from the brake heat sink and the heat of the
+/// flexing carcass when rolling, and the inflation pressure follows the temperature at constant
+/// volume. The tread wears with the distance rolled. A tyre deflates when it bursts or when the
+/// fuse plug of its wheel melts. A deflated tyre brakes poorly, gives less grip to the steering
+/// and drags while rolling, see [`TyreDrag`].
+pub struct TyreModel<C: TyreConstants, const N: usize> {
+    tyres: [Tyre; N],
+    ambient_pressure: Pressure,
+
+    constants: PhantomData<C>,
+}
+impl<C: TyreConstants, const N: usize> TyreModel<C, N> {
+    pub fn new(context: &mut InitContext) -> Self {
+        let mut tyres = vec![];
+        for idx in 0..N {
+            tyres.push(Tyre::new(context, idx + 1));
+        }
+
+        Self {
+            tyres: tyres.try_into().unwrap_or_else(|v: Vec<Tyre>| {
+                panic!("Expected a Vec of length {} but it was {}", N, v.len())
+            }),
+            ambient_pressure: Pressure::new::<psi>(Tyre::STANDARD_ATMOSPHERE_PSI),
+
+            constants: PhantomData,
+        }
+    }
+
+    /// Updates the tyres from the temperature and fuse plug state of the brake of each wheel.
+    /// Unbraked wheels are given the ambient temperature.
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        brake_temperatures: [ThermodynamicTemperature; N],
+        fuse_plugs_melted: [bool; N],
+    ) {
+        self.ambient_pressure = context.ambient_pressure();
+
+        for ((tyre, brake_temperature), fuse_plug_melted) in self
+            .tyres
+            .iter_mut()
+            .zip(brake_temperatures)
+            .zip(fuse_plugs_melted)
+        {
+            tyre.update::<C>(context, brake_temperature, fuse_plug_melted);
+        }
+    }
+
+    pub fn pressure(&self, tyre_index: usize) -> Pressure {
+        self.tyres[tyre_index].pressure::<C>(self.ambient_pressure)
+    }
+
+    pub fn temperature(&self, tyre_index: usize) -> ThermodynamicTemperature {
+        self.tyres[tyre_index].temperature()
+    }
+
+    pub fn wear(&self, tyre_index: usize) -> Ratio {
+        self.tyres[tyre_index].wear()
+    }
+
+    pub fn is_deflated(&self, tyre_index: usize) -> bool {
+        self.tyres[tyre_index].is_deflated()
+    }
+
+    /// Ratio of the nominal braking force the wheel can still transmit to the runway.
+    pub fn braking_efficiency(&self, tyre_index: usize) -> Ratio {
+        self.tyres[tyre_index].braking_efficiency()
+    }
+
+    /// Ratio of the nominal side force the wheel can still transmit to the runway when steered.
+    pub fn steering_efficiency(&self, tyre_index: usize) -> Ratio {
+        self.tyres[tyre_index].steering_efficiency()
+    }
+
+    /// Additional rolling resistance of the tyre, as a ratio of the load carried by the wheel.
+    pub fn rolling_drag(&self, tyre_index: usize) -> Ratio {
+        self.tyres[tyre_index].rolling_drag()
+    }
+
+    pub fn nominal_pressure(&self) -> Pressure {
+        Pressure::new::<psi>(C::NOMINAL_COLD_PRESSURE_PSI)
+    }
+}
+impl<C: TyreConstants, const N: usize> SimulationElement for TyreModel<C, N> {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        for tyre in &mut self.tyres {
+            tyre.accept(visitor);
+        }
+
+        visitor.visit(self);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        for tyre in &self.tyres {
+            tyre.write(writer, tyre.pressure::<C>(self.ambient_pressure));
+        }
+    }
+}
+
+/// Drag of the deflated tyres.
+///
+/// The rolling resistance of the tyres slows the aircraft down and, as the failed tyres are rarely
+/// symmetrical, yaws it towards the side of the failed tyres. This is applied as a change of the
+/// aircraft velocity, separately from the brakes so that it does not appear as a pedal demand.
+pub struct TyreDrag<const N: usize> {
+    delta_speed_id: VariableIdentifier,
+    angular_acceleration_id: VariableIdentifier,
+
+    /// Lateral position of each wheel from the aircraft centreline, positive to the right.
+    lateral_positions: [Length; N],
+
+    delta_speed: Velocity,
+    angular_acceleration: AngularAcceleration,
+}
+impl<const N: usize> TyreDrag<N> {
+    const GRAVITY_ACCELERATION: f64 = 9.807;
+
+    pub fn new(context: &mut InitContext, lateral_positions: [Length; N]) -> Self {
+        Self {
+            delta_speed_id: context.get_identifier("TYRE_DRAG_DELTA_SPEED".to_owned()),
+            angular_acceleration_id: context
+                .get_identifier("TYRE_DRAG_ANGULAR_ACCELERATION".to_owned()),
+
+            lateral_positions,
+
+            delta_speed: Velocity::default(),
+            angular_acceleration: AngularAcceleration::default(),
+        }
+    }
+
+    pub fn update<C: TyreConstants>(&mut self, context: &UpdateContext, tyres: &TyreModel<C, N>) {
+        let longitudinal_speed = context.local_velocity().to_ms_vector()[2];
+
+        // The load is considered evenly shared between the wheels
+        let wheel_load =
+            context.total_weight().get::<kilogram>() * Self::GRAVITY_ACCELERATION / N as f64;
+
+        let mut total_force = 0.;
+        let mut total_torque = 0.;
+        for (idx, lateral_position) in self.lateral_positions.iter().enumerate() {
+            let force = tyres.rolling_drag(idx).get::<ratio>() * wheel_load;
+            total_force += force;
+            total_torque += force * lateral_position.get::<meter>();
+        }
+
+        let deceleration = if context.total_weight().get::<kilogram>() > 0. {
+            total_force / context.total_weight().get::<kilogram>()
+        } else {
+            0.
+        };
+
+        // Drag opposes the motion and cannot reverse it
+        self.delta_speed = Velocity::new::<meter_per_second>(
+            -longitudinal_speed.signum()
+                * (deceleration * context.delta_as_secs_f64()).min(longitudinal_speed.abs()),
+        );
+
+        self.angular_acceleration = if context.total_yaw_inertia_kg_m2().abs() > 0. {
+            AngularAcceleration::new::<radian_per_second_squared>(
+                longitudinal_speed.signum() * total_torque / context.total_yaw_inertia_kg_m2(),
+            )
+        } else {
+            AngularAcceleration::default()
+        };
+    }
+
+    pub fn delta_speed(&self) -> Velocity {
+        self.delta_speed
+    }
+
+    pub fn angular_acceleration(&self) -> AngularAcceleration {
+        self.angular_acceleration
+    }
+}
+impl<const N: usize> SimulationElement for TyreDrag<N> {
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(
+            &self.delta_speed_id,
+            self.delta_speed.get::<foot_per_second>(),
+        );
+        writer.write(
+            &self.angular_acceleration_id,
+            self.angular_acceleration.get::<radian_per_second_squared>(),
+        );
+    }
+}
+
+/// Tyre pressure indicating system (TPIS).
+///
+/// Measures the pressure of each tyre and sends it to the displays. A tyre is reported with a low
+/// pressure when it falls below a ratio of the nominal pressure. When unpowered the outputs are FW.
+pub struct TyrePressureIndicatingSystem<const N: usize> {
+    tyre_pressure_ids: [VariableIdentifier; N],
+    low_pressure_id: VariableIdentifier,
+
+    powered_by: ElectricalBusType,
+    is_powered: bool,
+
+    pressures: [Pressure; N],
+    low_pressures: [bool; N],
+}
+impl<const N: usize> TyrePressureIndicatingSystem<N> {
+    pub fn new(context: &mut InitContext, powered_by: ElectricalBusType) -> Self {
+        let mut tyre_pressure_ids = vec![];
+        for idx in 0..N {
+            tyre_pressure_ids
+                .push(context.get_identifier(format!("TPIS_TYRE_{}_PRESSURE", idx + 1)));
+        }
+
+        Self {
+            tyre_pressure_ids: tyre_pressure_ids.try_into().unwrap_or_else(
+                |v: Vec<VariableIdentifier>| {
+                    panic!("Expected a Vec of length {} but it was {}", N, v.len())
+                },
+            ),
+            low_pressure_id: context.get_identifier("TPIS_TYRE_LO_PR".to_owned()),
+
+            powered_by,
+            is_powered: false,
+
+            pressures: [Pressure::default(); N],
+            low_pressures: [false; N],
+        }
+    }
+
+    pub fn update<C: TyreConstants>(&mut self, tyres: &TyreModel<C, N>) {
+        let low_pressure_threshold = tyres.nominal_pressure() * C::LOW_PRESSURE_RATIO;
+
+        for (idx, (pressure, low_pressure)) in self
+            .pressures
+            .iter_mut()
+            .zip(self.low_pressures.iter_mut())
+            .enumerate()
+        {
+            *pressure = tyres.pressure(idx);
+            *low_pressure = self.is_powered && *pressure < low_pressure_threshold;
+        }
+    }
+
+    pub fn tyre_pressure(&self, tyre_index: usize) -> Arinc429Word<Pressure> {
+        if self.is_powered {
+            Arinc429Word::new(self.pressures[tyre_index], SignStatus::NormalOperation)
+        } else {
+            Arinc429Word::new(Pressure::default(), SignStatus::FailureWarning)
+        }
+    }
+
+    pub fn tyre_low_pressure(&self) -> bool {
+        self.low_pressures.iter().any(|&low_pressure| low_pressure)
+    }
+}
+impl<const N: usize> SimulationElement for TyrePressureIndicatingSystem<N> {
+    fn write(&self, writer: &mut SimulatorWriter) {
+        for (idx, id) in self.tyre_pressure_ids.iter().enumerate() {
+            let pressure = self.tyre_pressure(idx);
+            writer.write_arinc429(id, pressure.value().get::<psi>(), pressure.ssm());
+        }
+        writer.write(&self.low_pressure_id, self.tyre_low_pressure());
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.powered_by);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::electrical::test::TestElectricitySource;
+    use crate::electrical::{ElectricalBus, Electricity};
+    use crate::shared::PotentialOrigin;
+    use crate::simulation::test::{ReadByName, SimulationTestBed, TestBed, WriteByName};
+    use crate::simulation::Aircraft;
+    use ntest::assert_about_eq;
+    use uom::si::{
+        electric_potential::volt, f64::ElectricPotential,
+        thermodynamic_temperature::degree_celsius, velocity::knot,
+    };
+
+    struct TestTyreConstants;
+    impl TyreConstants for TestTyreConstants {
+        const NOMINAL_COLD_PRESSURE_PSI: f64 = 200.;
+        const TREAD_LIFE_METERS: f64 = 1_000_000.;
+        const LOW_PRESSURE_RATIO: f64 = 0.88;
+    }
+
+    struct TestAircraft {
+        electricity_source: TestElectricitySource,
+        dc_1_bus: ElectricalBus,
+        is_dc_1_powered: bool,
+
+        tyres: TyreModel<TestTyreConstants, 2>,
+        tpis: TyrePressureIndicatingSystem<2>,
+        drag: TyreDrag<2>,
+        brake_temperature: ThermodynamicTemperature,
+        fuse_plug_melted: bool,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                electricity_source: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::EngineGenerator(1),
+                ),
+                dc_1_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrent(1)),
+                is_dc_1_powered: true,
+
+                tyres: TyreModel::new(context),
+                tpis: TyrePressureIndicatingSystem::new(
+                    context,
+                    ElectricalBusType::DirectCurrent(1),
+                ),
+                drag: TyreDrag::new(
+                    context,
+                    [Length::new::<meter>(-2.), Length::new::<meter>(2.)],
+                ),
+                brake_temperature: ThermodynamicTemperature::new::<degree_celsius>(15.),
+                fuse_plug_melted: false,
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            self.electricity_source
+                .power_with_potential(ElectricPotential::new::<volt>(28.));
+            electricity.supplied_by(&self.electricity_source);
+
+            if self.is_dc_1_powered {
+                electricity.flow(&self.electricity_source, &self.dc_1_bus);
+            }
+        }
+
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.tyres.update(
+                context,
+                [self.brake_temperature, context.ambient_temperature()],
+                [self.fuse_plug_melted, false],
+            );
+            self.tpis.update(&self.tyres);
+            self.drag.update(context, &self.tyres);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.tyres.accept(visitor);
+            self.tpis.accept(visitor);
+            self.drag.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    struct TyreTestBed {
+        test_bed: SimulationTestBed<TestAircraft>,
+    }
+    impl TyreTestBed {
+        fn new() -> Self {
+            let mut test_bed = Self {
+                test_bed: SimulationTestBed::new(TestAircraft::new),
+            };
+            test_bed.set_on_ground(true);
+            test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(15.));
+            test_bed.set_ambient_pressure(Pressure::new::<psi>(Tyre::STANDARD_ATMOSPHERE_PSI));
+
+            test_bed
+        }
+
+        fn ground_speed(mut self, speed: Velocity) -> Self {
+            self.write_by_name("GPS GROUND SPEED", speed);
+            self
+        }
+
+        fn rolling_forward(mut self, speed: Velocity) -> Self {
+            self.write_by_name("VELOCITY BODY Z", speed.get::<foot_per_second>());
+            self.write_by_name("TOTAL WEIGHT", Mass::new::<kilogram>(60000.));
+            self.write_by_name("TOTAL WEIGHT YAW MOI", 3_000_000.);
+            self.ground_speed(speed)
+        }
+
+        fn brake_temperature(mut self, temperature: f64) -> Self {
+            self.command(|a| {
+                a.brake_temperature = ThermodynamicTemperature::new::<degree_celsius>(temperature)
+            });
+            self
+        }
+
+        fn fuse_plug_melted(mut self) -> Self {
+            self.command(|a| a.fuse_plug_melted = true);
+            self
+        }
+
+        fn burst(mut self, number: usize) -> Self {
+            self.fail(FailureType::TyreBurst(number));
+            self
+        }
+
+        fn dc_1_unpowered(mut self) -> Self {
+            self.command(|a| a.is_dc_1_powered = false);
+            self
+        }
+
+        fn run_for(mut self, duration: Duration) -> Self {
+            self.run_with_delta(duration);
+            self
+        }
+
+        fn pressure(&self, tyre_index: usize) -> f64 {
+            self.query(|a| a.tyres.pressure(tyre_index)).get::<psi>()
+        }
+
+        fn temperature(&self, tyre_index: usize) -> f64 {
+            self.query(|a| a.tyres.temperature(tyre_index))
+                .get::<degree_celsius>()
+        }
+
+        fn wear(&self, tyre_index: usize) -> f64 {
+            self.query(|a| a.tyres.wear(tyre_index)).get::<ratio>()
+        }
+
+        fn is_deflated(&self, tyre_index: usize) -> bool {
+            self.query(|a| a.tyres.is_deflated(tyre_index))
+        }
+
+        fn braking_efficiency(&self, tyre_index: usize) -> f64 {
+            self.query(|a| a.tyres.braking_efficiency(tyre_index))
+                .get::<ratio>()
+        }
+
+        fn steering_efficiency(&self, tyre_index: usize) -> f64 {
+            self.query(|a| a.tyres.steering_efficiency(tyre_index))
+                .get::<ratio>()
+        }
+
+        fn rolling_drag(&self, tyre_index: usize) -> f64 {
+            self.query(|a| a.tyres.rolling_drag(tyre_index))
+                .get::<ratio>()
+        }
+
+        fn drag_delta_speed(&mut self) -> f64 {
+            self.read_by_name("TYRE_DRAG_DELTA_SPEED")
+        }
+
+        fn drag_angular_acceleration(&mut self) -> f64 {
+            self.read_by_name("TYRE_DRAG_ANGULAR_ACCELERATION")
+        }
+
+        fn tpis_pressure(&mut self, number: usize) -> Arinc429Word<f64> {
+            self.read_arinc429_by_name(&format!("TPIS_TYRE_{}_PRESSURE", number))
+        }
+
+        fn tpis_low_pressure(&mut self) -> bool {
+            self.read_by_name("TPIS_TYRE_LO_PR")
+        }
+    }
+    impl TestBed for TyreTestBed {
+        type Aircraft = TestAircraft;
+
+        fn test_bed(&self) -> &SimulationTestBed<TestAircraft> {
+            &self.test_bed
+        }
+
+        fn test_bed_mut(&mut self) -> &mut SimulationTestBed<TestAircraft> {
+            &mut self.test_bed
+        }
+    }
+
+    fn test_bed() -> TyreTestBed {
+        TyreTestBed::new()
+    }
+
+    #[test]
+    fn cold_tyres_are_at_nominal_pressure() {
+        let test_bed = test_bed().run_for(Duration::from_secs(1));
+
+        for tyre in 0..2 {
+            assert_about_eq!(test_bed.temperature(tyre), 15.);
+            assert_about_eq!(test_bed.pressure(tyre), 200., 0.01);
+            assert!(!test_bed.is_deflated(tyre));
+        }
+    }
+
+    #[test]
+    fn hot_brakes_heat_the_tyre_and_raise_its_pressure() {
+        let test_bed = test_bed()
+            .brake_temperature(300.)
+            .run_for(Duration::from_secs(1800));
+
+        assert!(test_bed.temperature(0) > 60.);
+        assert!(test_bed.pressure(0) > 220.);
+        assert_about_eq!(test_bed.temperature(1), 15.);
+    }
+
+    #[test]
+    fn rolling_heats_the_tyres() {
+        let test_bed = test_bed()
+            .ground_speed(Velocity::new::<knot>(20.))
+            .run_for(Duration::from_secs(600));
+
+        assert!(test_bed.temperature(0) > 20.);
+        assert!(test_bed.pressure(0) > 200.);
+    }
+
+    #[test]
+    fn tyres_do_not_heat_from_rolling_in_flight() {
+        let mut test_bed = test_bed().ground_speed(Velocity::new::<knot>(250.));
+        test_bed.set_on_ground(false);
+
+        let test_bed = test_bed.run_for(Duration::from_secs(600));
+
+        assert_about_eq!(test_bed.temperature(0), 15.);
+    }
+
+    #[test]
+    fn gauge_pressure_rises_when_ambient_pressure_drops() {
+        let mut test_bed = test_bed();
+        test_bed.set_ambient_pressure(Pressure::new::<psi>(4.));
+
+        let test_bed = test_bed.run_for(Duration::from_secs(1));
+
+        assert!(test_bed.pressure(0) > 210.);
+    }
+
+    #[test]
+    fn tread_wears_with_rolled_distance() {
+        let test_bed = test_bed()
+            .ground_speed(Velocity::new::<meter_per_second>(50.))
+            .run_for(Duration::from_secs(200));
+
+        assert_about_eq!(test_bed.wear(0), 0.01, 0.0001);
+        assert!(test_bed.braking_efficiency(0) < 1.);
+    }
+
+    #[test]
+    fn burst_tyre_deflates_immediately() {
+        let test_bed = test_bed()
+            .ground_speed(Velocity::new::<knot>(100.))
+            .burst(1)
+            .run_for(Duration::from_secs(1));
+
+        assert!(test_bed.is_deflated(0));
+        assert!(test_bed.pressure(0) < 50.);
+        assert!(!test_bed.is_deflated(1));
+    }
+
+    #[test]
+    fn deflated_tyre_brakes_poorly_and_drags_when_rolling() {
+        let test_bed = test_bed()
+            .ground_speed(Velocity::new::<knot>(100.))
+            .burst(1)
+            .run_for(Duration::from_secs(1));
+
+        assert!(test_bed.braking_efficiency(0) < 0.5);
+        assert!(test_bed.rolling_drag(0) > 0.);
+        assert_about_eq!(test_bed.braking_efficiency(1), 1., 0.01);
+        assert_about_eq!(test_bed.rolling_drag(1), 0.);
+
+        let test_bed = test_bed
+            .ground_speed(Velocity::new::<knot>(0.))
+            .run_for(Duration::from_secs(1));
+
+        assert_about_eq!(test_bed.rolling_drag(0), 0.);
+    }
+
+    #[test]
+    fn inflated_tyres_do_not_drag() {
+        let mut test_bed = test_bed()
+            .rolling_forward(Velocity::new::<knot>(100.))
+            .run_for(Duration::from_secs(1));
+
+        assert_about_eq!(test_bed.drag_delta_speed(), 0.);
+        assert_about_eq!(test_bed.drag_angular_acceleration(), 0.);
+    }
+
+    #[test]
+    fn deflated_tyre_drag_slows_and_yaws_the_aircraft_towards_its_side() {
+        let mut test_bed = test_bed()
+            .rolling_forward(Velocity::new::<knot>(100.))
+            .burst(1)
+            .run_for(Duration::from_secs(1));
+
+        assert!(test_bed.drag_delta_speed() < 0.);
+        assert!(test_bed.drag_angular_acceleration() < 0.);
+
+        let mut test_bed = test_bed.burst(2).run_for(Duration::from_secs(1));
+
+        assert!(test_bed.drag_delta_speed() < 0.);
+        assert_about_eq!(test_bed.drag_angular_acceleration(), 0.);
+    }
+
+    #[test]
+    fn deflated_tyre_steers_poorly() {
+        let test_bed = test_bed().burst(1).run_for(Duration::from_secs(1));
+
+        assert!(test_bed.steering_efficiency(0) < 0.5);
+        assert_about_eq!(test_bed.steering_efficiency(1), 1.);
+    }
+
+    #[test]
+    fn melted_fuse_plug_deflates_the_tyre_progressively() {
+        let test_bed = test_bed()
+            .fuse_plug_melted()
+            .run_for(Duration::from_secs(5));
+
+        assert!(!test_bed.is_deflated(0));
+        assert!(test_bed.pressure(0) < 200.);
+
+        let test_bed = test_bed.run_for(Duration::from_secs(60));
+
+        assert!(test_bed.is_deflated(0));
+    }
+
+    #[test]
+    fn tpis_reports_tyre_pressures() {
+        let mut test_bed = test_bed().run_for(Duration::from_secs(1));
+
+        let pressure = test_bed.tpis_pressure(1);
+        assert!(pressure.is_normal_operation());
+        assert_about_eq!(pressure.value(), 200., 0.01);
+        assert!(!test_bed.tpis_low_pressure());
+    }
+
+    #[test]
+    fn tpis_reports_low_pressure_of_burst_tyre() {
+        let mut test_bed = test_bed().burst(2).run_for(Duration::from_secs(1));
+
+        assert!(test_bed.tpis_pressure(2).value() < 50.);
+        assert!(test_bed.tpis_low_pressure());
+    }
+
+    #[test]
+    fn unpowered_tpis_outputs_failure_warning() {
+        let mut test_bed = test_bed()
+            .dc_1_unpowered()
+            .burst(1)
+            .run_for(Duration::from_secs(1));
+
+        assert!(test_bed.tpis_pressure(1).is_failure_warning());
+        assert!(!test_bed.tpis_low_pressure());
+    }
+}
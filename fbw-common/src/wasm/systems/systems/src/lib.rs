@@ -9,12 +9,14 @@ pub mod macros;
 
 pub mod air_conditioning;
 pub mod air_starter_unit;
+pub mod aircraft_configuration;
 pub mod airframe;
 pub mod apu;
 pub mod electrical;
 pub mod engine;
 pub mod enhanced_gpwc;
 pub mod failures;
+pub mod fire_and_smoke_protection;
 pub mod fuel;
 pub mod hydraulic;
 pub mod icing_state;
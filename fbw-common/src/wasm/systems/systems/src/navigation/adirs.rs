@@ -1,11 +1,14 @@
 use crate::air_conditioning::AdirsToAirCondInterface;
 use crate::simulation::{InitContext, VariableIdentifier};
 use crate::{
+    failures::{Failure, FailureType},
+    navigation::mmr::GpsSensor,
     overhead::{IndicationLight, OnOffFaultPushButton},
     shared::{
         arinc429::{Arinc429Word, SignStatus},
+        arinc429_bus::{Arinc429Label, Arinc429Transmitter},
         low_pass_filter::LowPassFilter,
-        AdirsDiscreteOutputs, AdirsMeasurementOutputs, MachNumber,
+        session_seed, AdirsBusOutputs, AdirsDiscreteOutputs, AdirsMeasurementOutputs, MachNumber,
     },
     simulation::{
         Read, Reader, SimulationElement, SimulationElementVisitor, SimulatorReader,
@@ -14,6 +17,8 @@ use crate::{
 };
 use bitflags::bitflags;
 use nalgebra::{Rotation2, Vector2};
+use rand::{rngs::SmallRng, SeedableRng};
+use rand_distr::{Distribution, Normal};
 use std::{fmt::Display, time::Duration};
 use uom::si::acceleration::meter_per_second_squared;
 use uom::si::pressure::inch_of_mercury;
@@ -22,9 +27,10 @@ use uom::si::{
     angle::radian,
     angular_velocity::degree_per_second,
     f64::*,
-    length::foot,
+    length::{foot, nautical_mile},
     pressure::hectopascal,
     ratio::ratio,
+    thermodynamic_temperature::degree_celsius,
     velocity::{foot_per_minute, knot},
 };
 
@@ -81,7 +87,11 @@ impl AirDataInertialReferenceSystemOverheadPanel {
         self.ir
             .iter_mut()
             .enumerate()
-            .for_each(|(index, ir)| ir.set_fault(adirs.ir_has_fault(index + 1)))
+            .for_each(|(index, ir)| ir.set_fault(adirs.ir_has_fault(index + 1)));
+        self.adr
+            .iter_mut()
+            .enumerate()
+            .for_each(|(index, adr)| adr.set_fault(adirs.adr_has_fault(index + 1)));
     }
 
     fn mode_of(&self, number: usize) -> InertialReferenceMode {
@@ -402,6 +412,11 @@ pub struct AirDataInertialReferenceSystem {
     simulator_data: AdirsSimulatorData,
 }
 impl AirDataInertialReferenceSystem {
+    /// The labels transmitted on the ADR bus of each ADIRU.
+    pub const ADR_BUS_LABELS: [Arinc429Label; 14] = AirDataReference::BUS_LABELS;
+    /// The labels transmitted on the IR bus of each ADIRU.
+    pub const IR_BUS_LABELS: [Arinc429Label; 16] = InertialReference::BUS_LABELS;
+
     const REMAINING_ALIGNMENT_TIME_KEY: &'static str = "ADIRS_REMAINING_IR_ALIGNMENT_TIME";
     const CONFIGURED_ALIGN_TIME_KEY: &'static str = "CONFIG_ADIRS_IR_ALIGN_TIME";
     // A32NX_AIRCRAFT_PRESET_QUICK_MODE LVar is set by the Aircraft Presets to allow expedited presets without
@@ -436,6 +451,7 @@ impl AirDataInertialReferenceSystem {
         &mut self,
         context: &UpdateContext,
         overhead: &AirDataInertialReferenceSystemOverheadPanel,
+        gps_sensors: [&impl GpsSensor; 2],
     ) {
         // adirs_quick_mode is set by the Aircraft Presets to allow expedited presets without
         // changing the alignment time setting
@@ -445,15 +461,35 @@ impl AirDataInertialReferenceSystem {
             self.configured_align_time
         };
         let simulator_data = self.simulator_data;
-        self.adirus.iter_mut().for_each(|adiru| {
-            adiru.update(
-                context,
-                overhead,
-                align_time,
-                self.aircraft_preset_quick_mode,
-                simulator_data,
-            )
-        });
+        self.adirus
+            .iter_mut()
+            .enumerate()
+            .for_each(|(index, adiru)| {
+                adiru.update(
+                    context,
+                    overhead,
+                    align_time,
+                    self.aircraft_preset_quick_mode,
+                    simulator_data,
+                    Self::gps_sensor_of(index + 1, gps_sensors),
+                )
+            });
+    }
+
+    /// Each ADIRU receives the GPS data of both MMRs. It uses the on-side MMR, unless that one
+    /// doesn't provide a valid position. ADIRU 3 uses MMR 1 as its on-side MMR.
+    fn gps_sensor_of<T: GpsSensor>(adiru_number: usize, gps_sensors: [&T; 2]) -> &T {
+        let (on_side, off_side) = if adiru_number == 2 {
+            (gps_sensors[1], gps_sensors[0])
+        } else {
+            (gps_sensors[0], gps_sensors[1])
+        };
+
+        if on_side.latitude().is_normal_operation() && on_side.longitude().is_normal_operation() {
+            on_side
+        } else {
+            off_side
+        }
     }
 
     fn remaining_align_duration(&self) -> Duration {
@@ -477,6 +513,10 @@ impl AirDataInertialReferenceSystem {
     fn ir_has_fault(&self, number: usize) -> bool {
         self.adirus[number - 1].ir_has_fault()
     }
+
+    fn adr_has_fault(&self, number: usize) -> bool {
+        self.adirus[number - 1].adr_has_fault()
+    }
 }
 impl SimulationElement for AirDataInertialReferenceSystem {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
@@ -516,6 +556,15 @@ impl AdirsToAirCondInterface for AirDataInertialReferenceSystem {
         self.adirus[adiru_number - 1].ambient_static_pressure()
     }
 }
+impl AdirsBusOutputs for AirDataInertialReferenceSystem {
+    fn adr_bus(&self, adiru_number: usize) -> &Arinc429Transmitter {
+        self.adirus[adiru_number - 1].adr_bus()
+    }
+
+    fn ir_bus(&self, adiru_number: usize) -> &Arinc429Transmitter {
+        self.adirus[adiru_number - 1].ir_bus()
+    }
+}
 impl AdirsDiscreteOutputs for AirDataInertialReferenceSystem {
     fn low_speed_warning_1(&self, adiru_number: usize) -> bool {
         self.adirus[adiru_number - 1].low_speed_warning_1()
@@ -562,6 +611,10 @@ impl AdirsMeasurementOutputs for AirDataInertialReferenceSystem {
         self.adirus[adiru_number - 1].altitude()
     }
 
+    fn computed_airspeed(&self, adiru_number: usize) -> Arinc429Word<Velocity> {
+        self.adirus[adiru_number - 1].computed_airspeed()
+    }
+
     fn angle_of_attack(&self, adiru_number: usize) -> Arinc429Word<Angle> {
         self.adirus[adiru_number - 1].angle_of_attack()
     }
@@ -601,6 +654,7 @@ impl AirDataInertialReferenceUnit {
         // TODO: this should be refactored (abstracted) away from the AirInertialReferenceUnit struct
         aircraft_preset_quick_mode: bool,
         simulator_data: AdirsSimulatorData,
+        gps: &impl GpsSensor,
     ) {
         self.adr.update(
             context,
@@ -615,6 +669,7 @@ impl AirDataInertialReferenceUnit {
             align_time,
             aircraft_preset_quick_mode,
             simulator_data,
+            gps,
         );
 
         self.update_discrete_outputs();
@@ -650,6 +705,18 @@ impl AirDataInertialReferenceUnit {
         self.ir.has_fault()
     }
 
+    fn adr_has_fault(&self) -> bool {
+        self.adr.is_failed()
+    }
+
+    fn adr_bus(&self) -> &Arinc429Transmitter {
+        &self.adr.bus
+    }
+
+    fn ir_bus(&self) -> &Arinc429Transmitter {
+        &self.ir.bus
+    }
+
     // When the ADR is unpowered (i.e. knob is set to OFF), all discretes go to open circuit (false).
     // Discrete #4 is inverted, so ground when below 260kts, OC otherwise.
     // When CAS is invalid but the ADR is otherwise powered (currently only possible when ADR p/b is off),
@@ -723,6 +790,10 @@ impl AirDataInertialReferenceUnit {
         self.adr.altitude()
     }
 
+    fn computed_airspeed(&self) -> Arinc429Word<Velocity> {
+        self.adr.computed_airspeed()
+    }
+
     fn ground_speed(&self) -> Arinc429Word<Velocity> {
         self.ir.ground_speed()
     }
@@ -839,6 +910,10 @@ impl<T: Copy + Default + PartialOrd> AdirsData<T> {
     ) {
         writer.write_arinc429(&self.id, convert(self.value), self.ssm);
     }
+
+    fn transmit_on<U: Fn(T) -> f64>(&self, bus: &mut Arinc429Transmitter, label: u8, convert: U) {
+        bus.set(label, Arinc429Word::new(convert(self.value), self.ssm));
+    }
 }
 
 #[derive(Clone, Copy)]
@@ -922,8 +997,11 @@ struct AirDataReference {
     total_air_temperature: AdirsData<ThermodynamicTemperature>,
     angle_of_attack: AdirsData<Angle>,
     discrete_word_1: AdirsData<u32>,
+    bus: Arinc429Transmitter,
 
     remaining_initialisation_duration: Option<Duration>,
+    failure: Failure,
+    adiru_failure: Failure,
 }
 impl AirDataReference {
     const INITIALISATION_DURATION: Duration = Duration::from_secs(18);
@@ -951,6 +1029,23 @@ impl AirDataReference {
     const MINIMUM_MACH: f64 = 0.1;
     const MINIMUM_CAS_FOR_AOA: f64 = 60.;
 
+    const BUS_LABELS: [Arinc429Label; 14] = [
+        Arinc429Label::bnr(0o203, 17, 131072., Duration::from_micros(62500)),
+        Arinc429Label::bnr(0o204, 17, 131072., Duration::from_micros(62500)),
+        Arinc429Label::bnr(0o205, 16, 4.096, Duration::from_millis(125)),
+        Arinc429Label::bnr(0o206, 14, 1024., Duration::from_millis(125)),
+        Arinc429Label::bnr(0o207, 12, 1024., Duration::from_millis(250)),
+        Arinc429Label::bnr(0o210, 15, 2048., Duration::from_millis(125)),
+        Arinc429Label::bnr(0o211, 11, 512., Duration::from_millis(500)),
+        Arinc429Label::bnr(0o212, 11, 32768., Duration::from_micros(62500)),
+        Arinc429Label::bnr(0o213, 11, 512., Duration::from_millis(500)),
+        Arinc429Label::bcd(0o234, 5, 0.1, Duration::from_millis(250)),
+        Arinc429Label::bcd(0o235, 5, 0.001, Duration::from_millis(250)),
+        Arinc429Label::bnr(0o241, 12, 180., Duration::from_micros(31250)),
+        Arinc429Label::bnr(0o246, 16, 2048., Duration::from_micros(62500)),
+        Arinc429Label::discrete(0o270, Duration::from_millis(250)),
+    ];
+
     fn new(context: &mut InitContext, number: usize, vmo: Velocity, mmo: MachNumber) -> Self {
         Self {
             number,
@@ -1004,9 +1099,14 @@ impl AirDataReference {
             total_air_temperature: AdirsData::new_adr(context, number, Self::TOTAL_AIR_TEMPERATURE),
             angle_of_attack: AdirsData::new_adr(context, number, Self::ANGLE_OF_ATTACK),
             discrete_word_1: AdirsData::new_adr(context, number, Self::DISCRETE_WORD_1),
+            bus: Arinc429Transmitter::new(&Self::BUS_LABELS)
+                .with_sdi(number as u8)
+                .with_parity_error_failure(FailureType::AdiruBusParityError(number)),
 
             // Start fully initialised.
             remaining_initialisation_duration: Some(Duration::from_secs(0)),
+            failure: Failure::new(FailureType::Adr(number)),
+            adiru_failure: Failure::new(FailureType::Adiru(number)),
         }
     }
 
@@ -1025,6 +1125,42 @@ impl AirDataReference {
         );
         self.update_values(context, simulator_data);
         self.update_discrete_word_1();
+        self.update_bus(context);
+    }
+
+    /// The ADR doesn't transmit anything while it is switched off, which is how the receiving
+    /// systems detect its loss.
+    fn update_bus(&mut self, context: &UpdateContext) {
+        let bus = &mut self.bus;
+        self.altitude
+            .transmit_on(bus, 0o203, |value| value.get::<foot>());
+        self.baro_corrected_altitude_1
+            .transmit_on(bus, 0o204, |value| value.get::<foot>());
+        self.mach.transmit_on(bus, 0o205, f64::from);
+        self.computed_airspeed
+            .transmit_on(bus, 0o206, |value| value.get::<knot>());
+        self.max_airspeed
+            .transmit_on(bus, 0o207, |value| value.get::<knot>());
+        self.true_airspeed
+            .transmit_on(bus, 0o210, |value| value.get::<knot>());
+        self.total_air_temperature
+            .transmit_on(bus, 0o211, |value| value.get::<degree_celsius>());
+        self.barometric_vertical_speed
+            .transmit_on(bus, 0o212, |value| value);
+        self.static_air_temperature
+            .transmit_on(bus, 0o213, |value| value.get::<degree_celsius>());
+        self.baro_correction_1_hpa
+            .transmit_on(bus, 0o234, |value| value.get::<hectopascal>());
+        self.baro_correction_1_inhg
+            .transmit_on(bus, 0o235, |value| value.get::<inch_of_mercury>());
+        self.angle_of_attack
+            .transmit_on(bus, 0o241, |value| value.normalised_180().get::<degree>());
+        self.corrected_average_static_pressure
+            .transmit_on(bus, 0o246, |value| value.get::<hectopascal>());
+        self.discrete_word_1
+            .transmit_on(bus, 0o270, |value| value as f64);
+
+        self.bus.update(context, self.is_on);
     }
 
     fn update_remaining_initialisation_duration(
@@ -1188,13 +1324,22 @@ impl AirDataReference {
     }
 
     fn is_valid(&self) -> bool {
-        self.is_on && self.is_initialised()
+        self.is_on && self.is_initialised() && !self.is_failed()
+    }
+
+    /// A failed ADR keeps transmitting, but all its data is flagged as failed.
+    fn is_failed(&self) -> bool {
+        self.failure.is_active() || self.adiru_failure.is_active()
     }
 
     fn computed_airspeed_raw(&self) -> Velocity {
         self.computed_airspeed.value()
     }
 
+    fn computed_airspeed(&self) -> Arinc429Word<Velocity> {
+        Arinc429Word::new(self.computed_airspeed.value(), self.computed_airspeed.ssm())
+    }
+
     fn altitude(&self) -> Arinc429Word<Length> {
         Arinc429Word::new(self.altitude.value(), self.altitude.ssm())
     }
@@ -1231,6 +1376,14 @@ impl TrueAirspeedSource for AirDataReference {
     }
 }
 impl SimulationElement for AirDataReference {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.bus.accept(visitor);
+        self.failure.accept(visitor);
+        self.adiru_failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn write(&self, writer: &mut SimulatorWriter) {
         self.baro_correction_1_hpa
             .write_to_converted(writer, |value| value.get::<hectopascal>());
@@ -1305,16 +1458,119 @@ bitflags! {
     }
 }
 
+/// The operating state of an inertial reference, as driven by its mode selector.
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum InertialReferenceState {
+    Off,
+    /// Gyrocompassing and levelling in preparation of the navigation mode. The aircraft has to
+    /// remain stationary during the alignment.
+    Aligning,
+    Navigation,
+    /// The reversionary attitude mode. Only attitude and, after its manual entry, heading are
+    /// provided.
+    Attitude,
+}
+
+/// Models the error growth of the pure inertial solution. The position error consists of a
+/// linearly growing part, caused by the residual gyro drift, and a Schuler oscillation, caused by
+/// the residual levelling error. As the heading isn't gyrocompassed in the attitude mode, it drifts
+/// freely in that mode. The error characteristics are drawn anew for every alignment, such that
+/// every IR drifts differently. Each IR draws them from its own generator, seeded from the
+/// session seed and the ADIRU number, so a session's drift is reproducible.
+struct InertialDrift {
+    rng: SmallRng,
+    duration: Duration,
+    /// North (x) and east (y) position drift rate in nautical miles per hour.
+    position_drift_rate: Vector2<f64>,
+    /// North (x) and east (y) Schuler oscillation amplitude in nautical miles.
+    schuler_amplitude: Vector2<f64>,
+    attitude_heading_drift_rate_degrees_per_hour: f64,
+}
+impl InertialDrift {
+    const SCHULER_PERIOD: Duration = Duration::from_secs(5064);
+    const POSITION_DRIFT_RATE_STANDARD_DEVIATION_NM_PER_HOUR: f64 = 0.6;
+    const SCHULER_AMPLITUDE_STANDARD_DEVIATION_NM: f64 = 0.3;
+    const ATTITUDE_HEADING_DRIFT_RATE_STANDARD_DEVIATION_DEGREES_PER_HOUR: f64 = 2.;
+    /// Draws are limited to this many standard deviations.
+    const SAMPLE_LIMIT_STANDARD_DEVIATIONS: f64 = 4.;
+
+    fn new(adiru_number: usize) -> Self {
+        let mut drift = Self {
+            rng: SmallRng::seed_from_u64(session_seed().wrapping_add(adiru_number as u64)),
+            duration: Duration::default(),
+            position_drift_rate: Vector2::default(),
+            schuler_amplitude: Vector2::default(),
+            attitude_heading_drift_rate_degrees_per_hour: 0.,
+        };
+        drift.restart();
+
+        drift
+    }
+
+    fn restart(&mut self) {
+        self.duration = Duration::default();
+        self.position_drift_rate = Vector2::new(
+            self.sample(Self::POSITION_DRIFT_RATE_STANDARD_DEVIATION_NM_PER_HOUR),
+            self.sample(Self::POSITION_DRIFT_RATE_STANDARD_DEVIATION_NM_PER_HOUR),
+        );
+        self.schuler_amplitude = Vector2::new(
+            self.sample(Self::SCHULER_AMPLITUDE_STANDARD_DEVIATION_NM),
+            self.sample(Self::SCHULER_AMPLITUDE_STANDARD_DEVIATION_NM),
+        );
+        self.attitude_heading_drift_rate_degrees_per_hour =
+            self.sample(Self::ATTITUDE_HEADING_DRIFT_RATE_STANDARD_DEVIATION_DEGREES_PER_HOUR);
+    }
+
+    /// Draws from a zero mean normal distribution with the given standard deviation.
+    fn sample(&mut self, standard_deviation: f64) -> f64 {
+        let limit = Self::SAMPLE_LIMIT_STANDARD_DEVIATIONS * standard_deviation;
+
+        Normal::new(0., standard_deviation)
+            .unwrap()
+            .sample(&mut self.rng)
+            .clamp(-limit, limit)
+    }
+
+    fn update(&mut self, context: &UpdateContext) {
+        self.duration += context.delta();
+    }
+
+    /// The north (x) and east (y) position error in nautical miles.
+    fn position_error(&self) -> Vector2<f64> {
+        let hours = self.duration.as_secs_f64() / 3600.;
+        let schuler_phase = 2. * std::f64::consts::PI * self.duration.as_secs_f64()
+            / Self::SCHULER_PERIOD.as_secs_f64();
+
+        self.position_drift_rate * hours + self.schuler_amplitude * schuler_phase.sin()
+    }
+
+    fn attitude_heading_drift(&self, context: &UpdateContext) -> Angle {
+        Angle::new::<degree>(
+            self.attitude_heading_drift_rate_degrees_per_hour * context.delta_as_secs_f64() / 3600.,
+        )
+    }
+}
+
 struct InertialReference {
     number: usize,
     is_on: bool,
+    state: InertialReferenceState,
     /// The remaining time to align, where 0 indicates the IR system is aligned.
     /// None indicates the IR system isn't aligning nor aligned.
     remaining_align_duration: Option<Duration>,
     ir_fault_flash_duration: Option<Duration>,
     remaining_attitude_initialisation_duration: Option<Duration>,
+    /// Set when the aircraft moved during the alignment. Cleared once the alignment completes.
+    has_align_fault: bool,
     wind_velocity: LowPassFilter<Vector2<f64>>,
     extreme_latitude: bool,
+    inertial_drift: InertialDrift,
+
+    set_heading_id: VariableIdentifier,
+    set_heading: Option<Angle>,
+    /// The difference between the heading entered for the attitude mode and the actual heading.
+    /// None until a heading has been entered.
+    attitude_heading_offset: Option<Angle>,
 
     pitch: AdirsData<Angle>,
     roll: AdirsData<Angle>,
@@ -1347,6 +1603,23 @@ struct InertialReference {
     longitude: AdirsData<Angle>,
     /// label 270
     maint_word: AdirsData<u32>,
+
+    /// The low pass filtered difference between the GPS and the inertial position in degrees of
+    /// latitude (x) and longitude (y). It is held constant when the GPS position is lost, such
+    /// that the hybrid position continues in coasting mode.
+    gpirs_position_error: LowPassFilter<Vector2<f64>>,
+    has_gpirs_reference: bool,
+    gpirs_coasting_duration: Duration,
+    gpirs_reference_horizontal_figure_of_merit: Length,
+    /// label 254, hybrid latitude
+    gpirs_latitude: AdirsData<Angle>,
+    /// label 255, hybrid longitude
+    gpirs_longitude: AdirsData<Angle>,
+    /// label 247, hybrid horizontal figure of merit
+    gpirs_horizontal_figure_of_merit: AdirsData<Length>,
+    bus: Arinc429Transmitter,
+    failure: Failure,
+    adiru_failure: Failure,
 }
 impl InertialReference {
     const FAST_ALIGNMENT_TIME_IN_SECS: f64 = 90.;
@@ -1379,10 +1652,36 @@ impl InertialReference {
     const LATITUDE: &'static str = "LATITUDE";
     const LONGITUDE: &'static str = "LONGITUDE";
     const MAINT_WORD: &'static str = "MAINT_WORD";
+    const GPIRS_LATITUDE: &'static str = "GPIRS_LATITUDE";
+    const GPIRS_LONGITUDE: &'static str = "GPIRS_LONGITUDE";
+    const GPIRS_HORIZONTAL_FIGURE_OF_MERIT: &'static str = "GPIRS_HFOM";
+
+    const BUS_LABELS: [Arinc429Label; 16] = [
+        Arinc429Label::bcd(0o015, 3, 1., Duration::from_millis(500)),
+        Arinc429Label::bcd(0o016, 3, 1., Duration::from_millis(500)),
+        Arinc429Label::discrete(0o270, Duration::from_millis(200)),
+        Arinc429Label::bnr(0o310, 18, 180., Duration::from_millis(200)),
+        Arinc429Label::bnr(0o311, 18, 180., Duration::from_millis(200)),
+        Arinc429Label::bnr(0o312, 15, 4096., Duration::from_millis(50)),
+        Arinc429Label::bnr(0o313, 15, 180., Duration::from_millis(50)),
+        Arinc429Label::bnr(0o314, 15, 180., Duration::from_millis(50)),
+        Arinc429Label::bnr(0o315, 9, 180., Duration::from_millis(100)),
+        Arinc429Label::bnr(0o316, 8, 256., Duration::from_millis(100)),
+        Arinc429Label::bnr(0o317, 15, 180., Duration::from_millis(50)),
+        Arinc429Label::bnr(0o320, 15, 180., Duration::from_millis(50)),
+        Arinc429Label::bnr(0o321, 15, 180., Duration::from_millis(50)),
+        Arinc429Label::bnr(0o324, 14, 180., Duration::from_millis(20)),
+        Arinc429Label::bnr(0o325, 14, 180., Duration::from_millis(20)),
+        Arinc429Label::bnr(0o365, 15, 32768., Duration::from_millis(50)),
+    ];
     const MINIMUM_TRUE_AIRSPEED_FOR_WIND_DETERMINATION_KNOTS: f64 = 100.;
     const MINIMUM_GROUND_SPEED_FOR_TRACK_KNOTS: f64 = 50.;
+    const EXCESS_MOTION_GROUND_SPEED_KNOTS: f64 = 1.;
 
     const WIND_VELOCITY_TIME_CONSTANT: Duration = Duration::from_millis(100);
+    const GPIRS_POSITION_ERROR_TIME_CONSTANT: Duration = Duration::from_secs(10);
+    /// The rate at which the figure of merit of the hybrid position grows while coasting.
+    const GPIRS_COASTING_DRIFT_RATE_NM_PER_HOUR: f64 = 2.;
 
     fn new(context: &mut InitContext, number: usize) -> Self {
         Self {
@@ -1390,12 +1689,19 @@ impl InertialReference {
             is_on: true,
             // We start in an aligned state to support starting on the
             // runway or in the air.
+            state: InertialReferenceState::Navigation,
             remaining_align_duration: Some(Duration::from_secs(0)),
             ir_fault_flash_duration: None,
             // Start fully initialised.
             remaining_attitude_initialisation_duration: Some(Duration::from_secs(0)),
+            has_align_fault: false,
             wind_velocity: LowPassFilter::new(Self::WIND_VELOCITY_TIME_CONSTANT),
             extreme_latitude: false,
+            inertial_drift: InertialDrift::new(number),
+
+            set_heading_id: context.get_identifier(Self::set_heading_id(number)),
+            set_heading: None,
+            attitude_heading_offset: None,
 
             pitch: AdirsData::new_ir(context, number, Self::PITCH),
             roll: AdirsData::new_ir(context, number, Self::ROLL),
@@ -1423,6 +1729,23 @@ impl InertialReference {
             latitude: AdirsData::new_ir(context, number, Self::LATITUDE),
             longitude: AdirsData::new_ir(context, number, Self::LONGITUDE),
             maint_word: AdirsData::new_ir(context, number, Self::MAINT_WORD),
+
+            gpirs_position_error: LowPassFilter::new(Self::GPIRS_POSITION_ERROR_TIME_CONSTANT),
+            has_gpirs_reference: false,
+            gpirs_coasting_duration: Duration::default(),
+            gpirs_reference_horizontal_figure_of_merit: Length::default(),
+            gpirs_latitude: AdirsData::new_ir(context, number, Self::GPIRS_LATITUDE),
+            gpirs_longitude: AdirsData::new_ir(context, number, Self::GPIRS_LONGITUDE),
+            gpirs_horizontal_figure_of_merit: AdirsData::new_ir(
+                context,
+                number,
+                Self::GPIRS_HORIZONTAL_FIGURE_OF_MERIT,
+            ),
+            bus: Arinc429Transmitter::new(&Self::BUS_LABELS)
+                .with_sdi(number as u8)
+                .with_parity_error_failure(FailureType::AdiruBusParityError(number)),
+            failure: Failure::new(FailureType::Ir(number)),
+            adiru_failure: Failure::new(FailureType::Adiru(number)),
         }
     }
 
@@ -1434,6 +1757,7 @@ impl InertialReference {
         configured_align_time: AlignTime,
         aircraft_preset_quick_mode: bool,
         simulator_data: AdirsSimulatorData,
+        gps: &impl GpsSensor,
     ) {
         self.is_on = overhead.ir_is_on(self.number);
 
@@ -1443,18 +1767,64 @@ impl InertialReference {
             overhead,
             aircraft_preset_quick_mode,
         );
-        self.update_remaining_align_duration(
-            context,
-            overhead,
-            configured_align_time,
-            simulator_data,
-        );
+        self.update_state(context, overhead, configured_align_time, simulator_data);
 
         self.update_latitude(simulator_data);
         self.update_attitude_values(context, simulator_data);
-        self.update_heading_values(overhead, simulator_data);
-        self.update_non_attitude_values(context, true_airspeed_source, overhead, simulator_data);
-        self.update_maint_word(overhead);
+        self.update_attitude_heading(context, simulator_data);
+        self.update_heading_values(simulator_data);
+        self.update_non_attitude_values(context, true_airspeed_source, simulator_data);
+        self.update_gpirs_position(context, gps);
+        self.update_maint_word();
+        self.update_bus(context);
+    }
+
+    /// The IR transmits as long as its mode selector isn't off. The wind labels aren't
+    /// transmitted in ATT mode.
+    fn update_bus(&mut self, context: &UpdateContext) {
+        let wind_is_transmitted = self.wind_is_transmitted();
+        let bus = &mut self.bus;
+        if wind_is_transmitted {
+            self.wind_direction
+                .transmit_on(bus, 0o015, |value| value.get::<degree>());
+            self.wind_speed
+                .transmit_on(bus, 0o016, |value| value.get::<knot>());
+            self.wind_direction_bnr
+                .transmit_on(bus, 0o315, |value| value.get::<degree>());
+            self.wind_speed_bnr
+                .transmit_on(bus, 0o316, |value| value.get::<knot>());
+        } else {
+            [0o015, 0o016, 0o315, 0o316]
+                .into_iter()
+                .for_each(|label| bus.set_no_transmission(label));
+        }
+
+        self.maint_word
+            .transmit_on(bus, 0o270, |value| value as f64);
+        self.latitude
+            .transmit_on(bus, 0o310, |value| value.get::<degree>());
+        self.longitude
+            .transmit_on(bus, 0o311, |value| value.get::<degree>());
+        self.ground_speed
+            .transmit_on(bus, 0o312, |value| value.get::<knot>());
+        self.true_track
+            .transmit_on(bus, 0o313, |value| value.normalised_180().get::<degree>());
+        self.true_heading
+            .transmit_on(bus, 0o314, |value| value.normalised_180().get::<degree>());
+        self.track
+            .transmit_on(bus, 0o317, |value| value.normalised_180().get::<degree>());
+        self.heading
+            .transmit_on(bus, 0o320, |value| value.normalised_180().get::<degree>());
+        self.drift_angle
+            .transmit_on(bus, 0o321, |value| value.normalised_180().get::<degree>());
+        self.pitch
+            .transmit_on(bus, 0o324, |value| value.normalised_180().get::<degree>());
+        self.roll
+            .transmit_on(bus, 0o325, |value| value.normalised_180().get::<degree>());
+        self.vertical_speed.transmit_on(bus, 0o365, |value| value);
+
+        self.bus
+            .update(context, self.state != InertialReferenceState::Off);
     }
 
     fn update_fault_flash_duration(
@@ -1462,7 +1832,7 @@ impl InertialReference {
         context: &UpdateContext,
         overhead: &AirDataInertialReferenceSystemOverheadPanel,
     ) {
-        if self.alignment_starting(overhead.mode_of(self.number)) {
+        if self.alignment_starting(self.selected_mode(overhead)) {
             self.ir_fault_flash_duration = Some(Self::IR_FAULT_FLASH_DURATION);
         } else if let Some(flash_duration) = self.ir_fault_flash_duration {
             let remaining = subtract_delta_from_duration(context, flash_duration);
@@ -1488,17 +1858,87 @@ impl InertialReference {
         self.remaining_attitude_initialisation_duration = remaining_initialisation_duration(
             context,
             initialisation_duration,
-            overhead.mode_of(self.number),
+            self.selected_mode(overhead),
             self.remaining_attitude_initialisation_duration,
         );
     }
 
-    fn update_remaining_align_duration(
+    fn set_heading_id(number: usize) -> String {
+        format!("ADIRS_IR_{}_SET_HEADING", number)
+    }
+
+    /// A failed IR loses its attitude and alignment as if it was switched off. It has to align
+    /// again once the failure is cleared.
+    fn selected_mode(
+        &self,
+        overhead: &AirDataInertialReferenceSystemOverheadPanel,
+    ) -> InertialReferenceMode {
+        if self.is_failed() {
+            InertialReferenceMode::Off
+        } else {
+            overhead.mode_of(self.number)
+        }
+    }
+
+    fn is_failed(&self) -> bool {
+        self.failure.is_active() || self.adiru_failure.is_active()
+    }
+
+    fn update_state(
         &mut self,
         context: &UpdateContext,
         overhead: &AirDataInertialReferenceSystemOverheadPanel,
         configured_align_time: AlignTime,
         simulator_data: AdirsSimulatorData,
+    ) {
+        let previous_state = self.state;
+        self.state = match self.selected_mode(overhead) {
+            InertialReferenceMode::Off => InertialReferenceState::Off,
+            InertialReferenceMode::Attitude => InertialReferenceState::Attitude,
+            InertialReferenceMode::Navigation => {
+                self.update_remaining_align_duration(
+                    context,
+                    configured_align_time,
+                    simulator_data,
+                );
+
+                if self.remaining_align_duration == Some(Duration::from_secs(0)) {
+                    InertialReferenceState::Navigation
+                } else {
+                    InertialReferenceState::Aligning
+                }
+            }
+        };
+
+        if !matches!(
+            self.state,
+            InertialReferenceState::Aligning | InertialReferenceState::Navigation
+        ) {
+            self.remaining_align_duration = None;
+        }
+
+        if self.state != InertialReferenceState::Aligning {
+            self.has_align_fault = false;
+        }
+
+        if self.state != InertialReferenceState::Attitude {
+            self.attitude_heading_offset = None;
+        }
+
+        if self.state == InertialReferenceState::Navigation {
+            if previous_state == InertialReferenceState::Navigation {
+                self.inertial_drift.update(context);
+            } else {
+                self.inertial_drift.restart();
+            }
+        }
+    }
+
+    fn update_remaining_align_duration(
+        &mut self,
+        context: &UpdateContext,
+        configured_align_time: AlignTime,
+        simulator_data: AdirsSimulatorData,
     ) {
         // If the  align time setting has been changed to instant during alignment,
         // then set remaining time to 0. This allows to implement a "Instant Align" button in the EFB
@@ -1508,15 +1948,23 @@ impl InertialReference {
             self.remaining_align_duration = Some(Duration::from_secs_f64(0.));
         }
 
-        self.remaining_align_duration = match overhead.mode_of(self.number) {
-            InertialReferenceMode::Navigation => match self.remaining_align_duration {
-                Some(remaining) => Some(subtract_delta_from_duration(context, remaining)),
-                None => Some(Self::total_alignment_duration(
-                    configured_align_time,
-                    simulator_data.latitude,
-                )),
-            },
-            InertialReferenceMode::Off | InertialReferenceMode::Attitude => None,
+        // Moving the aircraft during the alignment invalidates the gyrocompassing performed so
+        // far. The alignment restarts and the fault is annunciated until the alignment completes.
+        let excess_motion = self.state == InertialReferenceState::Aligning
+            && simulator_data.ground_speed
+                >= Velocity::new::<knot>(Self::EXCESS_MOTION_GROUND_SPEED_KNOTS);
+        if excess_motion {
+            self.has_align_fault = true;
+        }
+
+        self.remaining_align_duration = match self.remaining_align_duration {
+            Some(remaining) if !excess_motion => {
+                Some(subtract_delta_from_duration(context, remaining))
+            }
+            _ => Some(Self::total_alignment_duration(
+                configured_align_time,
+                simulator_data.latitude,
+            )),
         };
     }
 
@@ -1581,21 +2029,40 @@ impl InertialReference {
             .set_value(context.vert_accel() / g + pitch.cos() * roll.cos(), ssm);
     }
 
-    fn update_heading_values(
+    /// In the attitude mode the heading isn't gyrocompassed. It has to be entered manually, after
+    /// which it follows the aircraft's heading changes while slowly drifting away.
+    fn update_attitude_heading(
         &mut self,
-        overhead: &AirDataInertialReferenceSystemOverheadPanel,
+        context: &UpdateContext,
         simulator_data: AdirsSimulatorData,
     ) {
+        let set_heading = self.set_heading.take();
+        if self.state != InertialReferenceState::Attitude || !self.is_attitude_aligned() {
+            return;
+        }
+
+        if let Some(set_heading) = set_heading {
+            self.attitude_heading_offset =
+                Some((set_heading - self.unaligned_heading(simulator_data)).normalised_180());
+        }
+
+        if let Some(offset) = self.attitude_heading_offset {
+            self.attitude_heading_offset =
+                Some(offset + self.inertial_drift.attitude_heading_drift(context));
+        }
+    }
+
+    fn update_heading_values(&mut self, simulator_data: AdirsSimulatorData) {
         // TODO BNR labels (that most things use) are actually +/- 180
 
         // TODO tests for when should be mag or true in mag labels
 
         let heading_available = self.is_on
             && (self.is_fully_aligned()
-                || (overhead.mode_of(self.number) == InertialReferenceMode::Navigation
+                || (self.is_aligning()
                     && self
                         .remaining_align_duration
-                        .map_or(false, |duration| duration.as_secs() < 120)));
+                        .is_some_and(|duration| duration.as_secs() < 120)));
 
         let true_heading_ssm = if heading_available {
             SignStatus::NormalOperation
@@ -1605,40 +2072,46 @@ impl InertialReference {
         self.true_heading
             .set_value(simulator_data.true_heading, true_heading_ssm);
 
-        // TODO in ATT mode NCD until heading initialised on MCDU
-        let magnetic_heading_ssm = if self.is_on
-            && (heading_available
-                || (overhead.mode_of(self.number) == InertialReferenceMode::Attitude
-                    && self.is_attitude_aligned()))
-        {
-            SignStatus::NormalOperation
+        let attitude_heading_available = self.is_on
+            && self.state == InertialReferenceState::Attitude
+            && self.is_attitude_aligned()
+            && self.attitude_heading_offset.is_some();
+
+        if attitude_heading_available {
+            self.heading.set_normal_operation_value(
+                (self.unaligned_heading(simulator_data)
+                    + self.attitude_heading_offset.unwrap_or_default())
+                .normalised(),
+            );
         } else {
-            SignStatus::NoComputedData
-        };
-        self.heading.set_value(
-            if self.has_magnetic_data() {
-                simulator_data.heading
-            } else {
-                simulator_data.true_heading
-            },
-            magnetic_heading_ssm,
-        );
+            self.heading.set_value(
+                self.unaligned_heading(simulator_data),
+                if heading_available {
+                    SignStatus::NormalOperation
+                } else {
+                    SignStatus::NoComputedData
+                },
+            );
+        }
+    }
+
+    fn unaligned_heading(&self, simulator_data: AdirsSimulatorData) -> Angle {
+        if self.has_magnetic_data() {
+            simulator_data.heading
+        } else {
+            simulator_data.true_heading
+        }
     }
 
     fn update_wind_velocity(
         &mut self,
         context: &UpdateContext,
         true_airspeed_source: &impl TrueAirspeedSource,
-        overhead: &AirDataInertialReferenceSystemOverheadPanel,
         simulator_data: AdirsSimulatorData,
     ) {
         // In ATT mode these labels are not even transmitted
         // In Align, NCD prior to NAV
-
-        let no_transmission = match overhead.mode_of(self.number) {
-            InertialReferenceMode::Navigation => false,
-            InertialReferenceMode::Off | InertialReferenceMode::Attitude => true,
-        } || !self.is_on;
+        let no_transmission = !self.wind_is_transmitted();
 
         // The IR does not compute the wind if the TAS is less than 100 knots or NCD
         let true_airspeed_above_minimum_threshold = true_airspeed_source
@@ -1647,8 +2120,9 @@ impl InertialReference {
             && true_airspeed_source.true_airspeed().value()
                 >= Velocity::new::<knot>(Self::MINIMUM_TRUE_AIRSPEED_FOR_WIND_DETERMINATION_KNOTS);
 
+        // The simvars can't express the absence of a label, the bus stops transmitting it instead.
         let ssm = if no_transmission {
-            SignStatus::FailureWarning // TODO should be no transmission
+            SignStatus::FailureWarning
         } else if true_airspeed_above_minimum_threshold && self.is_fully_aligned() {
             SignStatus::NormalOperation
         } else {
@@ -1699,8 +2173,6 @@ impl InertialReference {
             self.wind_velocity.reset(Vector2::default());
         }
 
-        // set all the labels...
-        // TODO build out bus implementation for correct period, bnr/bcd encoding and no transmission state
         self.wind_direction
             .set_value(Angle::new::<degree>(wind_direction.round()), ssm);
 
@@ -1724,11 +2196,18 @@ impl InertialReference {
             .set_value(Velocity::new::<knot>(wind_speed), ssm);
     }
 
+    fn wind_is_transmitted(&self) -> bool {
+        self.is_on
+            && matches!(
+                self.state,
+                InertialReferenceState::Aligning | InertialReferenceState::Navigation
+            )
+    }
+
     fn update_non_attitude_values(
         &mut self,
         context: &UpdateContext,
         true_airspeed_source: &impl TrueAirspeedSource,
-        overhead: &AirDataInertialReferenceSystemOverheadPanel,
         simulator_data: AdirsSimulatorData,
     ) {
         let ssm = if self.is_on && self.is_fully_aligned() {
@@ -1798,14 +2277,91 @@ impl InertialReference {
         self.ground_speed
             .set_value(simulator_data.ground_speed, ssm);
 
-        self.latitude.set_value(simulator_data.latitude, ssm);
-        self.longitude.set_value(simulator_data.longitude, ssm);
+        let (latitude, longitude) = self.inertial_position(simulator_data);
+        self.latitude.set_value(latitude, ssm);
+        self.longitude.set_value(longitude, ssm);
+
+        self.update_wind_velocity(context, true_airspeed_source, simulator_data);
+    }
+
+    /// The pure inertial position, which includes the error accumulated since the alignment.
+    fn inertial_position(&self, simulator_data: AdirsSimulatorData) -> (Angle, Angle) {
+        let position_error = self.inertial_drift.position_error();
+        let latitude = simulator_data.latitude + Angle::new::<degree>(position_error.x / 60.);
+        let longitude = simulator_data.longitude
+            + Angle::new::<degree>(
+                position_error.y
+                    / (60. * simulator_data.latitude.cos().get::<ratio>().abs().max(0.01)),
+            );
+
+        (latitude, longitude.normalised_180())
+    }
+
+    /// Computes the hybrid GPS/inertial (GPIRS) position. While the GPS position is available, the
+    /// inertial position is continuously corrected towards it. When it is lost, the last
+    /// correction is kept and the hybrid position coasts on the inertial position.
+    fn update_gpirs_position(&mut self, context: &UpdateContext, gps: &impl GpsSensor) {
+        if self.latitude.ssm() != SignStatus::NormalOperation
+            || self.longitude.ssm() != SignStatus::NormalOperation
+        {
+            self.has_gpirs_reference = false;
+            self.gpirs_coasting_duration = Duration::default();
+            self.gpirs_latitude.set_no_computed_data();
+            self.gpirs_longitude.set_no_computed_data();
+            self.gpirs_horizontal_figure_of_merit.set_no_computed_data();
+            return;
+        }
+
+        if let (Some(latitude), Some(longitude), Some(horizontal_figure_of_merit)) = (
+            gps.latitude().normal_value(),
+            gps.longitude().normal_value(),
+            gps.horizontal_figure_of_merit().normal_value(),
+        ) {
+            let position_error = Vector2::new(
+                (latitude - self.latitude.value()).get::<degree>(),
+                (longitude - self.longitude.value())
+                    .normalised_180()
+                    .get::<degree>(),
+            );
+            if self.has_gpirs_reference {
+                self.gpirs_position_error
+                    .update(context.delta(), position_error);
+            } else {
+                self.gpirs_position_error.reset(position_error);
+            }
+
+            self.has_gpirs_reference = true;
+            self.gpirs_coasting_duration = Duration::default();
+            self.gpirs_reference_horizontal_figure_of_merit = horizontal_figure_of_merit;
+        } else if self.has_gpirs_reference {
+            self.gpirs_coasting_duration += context.delta();
+        }
 
-        self.update_wind_velocity(context, true_airspeed_source, overhead, simulator_data);
+        if self.has_gpirs_reference {
+            let position_error = self.gpirs_position_error.output();
+            self.gpirs_latitude.set_normal_operation_value(
+                self.latitude.value() + Angle::new::<degree>(position_error.x),
+            );
+            self.gpirs_longitude.set_normal_operation_value(
+                (self.longitude.value() + Angle::new::<degree>(position_error.y)).normalised_180(),
+            );
+            self.gpirs_horizontal_figure_of_merit
+                .set_normal_operation_value(
+                    self.gpirs_reference_horizontal_figure_of_merit
+                        + Length::new::<nautical_mile>(
+                            Self::GPIRS_COASTING_DRIFT_RATE_NM_PER_HOUR
+                                * self.gpirs_coasting_duration.as_secs_f64()
+                                / 3600.,
+                        ),
+                );
+        } else {
+            self.gpirs_latitude.set_no_computed_data();
+            self.gpirs_longitude.set_no_computed_data();
+            self.gpirs_horizontal_figure_of_merit.set_no_computed_data();
+        }
     }
 
-    fn update_maint_word(&mut self, overhead: &AirDataInertialReferenceSystemOverheadPanel) {
-        // TODO check status of these during mode transitions (first need to implement mode FSM)
+    fn update_maint_word(&mut self) {
         let mut maint_word: IrMaintFlags = IrMaintFlags::default();
 
         if !self.is_on {
@@ -1819,16 +2375,19 @@ impl InertialReference {
             maint_word |= IrMaintFlags::ALIGNMENT_NOT_READY;
         }
 
-        if overhead.mode_of(self.number) == InertialReferenceMode::Attitude {
+        if self.state == InertialReferenceState::Attitude {
             maint_word |= IrMaintFlags::REV_ATT_MODE;
+
+            // Request the entry of a heading.
+            if self.is_attitude_aligned() && self.attitude_heading_offset.is_none() {
+                maint_word |= IrMaintFlags::VALID_SET_HEADING;
+            }
         }
 
         if self.is_fully_aligned() {
             maint_word |= IrMaintFlags::NAV_MODE;
         }
 
-        // TODO request heading setting in att mode if not set
-
         // TODO attitude invalid fault
 
         // TODO dc < 18 V
@@ -1841,12 +2400,12 @@ impl InertialReference {
 
         // TODO DC fault during DC operation last power up
 
-        // TODO align fault
+        if self.has_align_fault {
+            maint_word |= IrMaintFlags::ALIGN_FAULT | IrMaintFlags::EXCESS_MOTION_ERROR;
+        }
 
         // TODO No IRS initial pos
 
-        // TODO excess motion during align
-
         // TODO ADR data not received or parity error
 
         if self.extreme_latitude {
@@ -1889,7 +2448,7 @@ impl InertialReference {
     }
 
     fn is_fully_aligned(&self) -> bool {
-        self.remaining_align_duration == Some(Duration::from_secs(0))
+        self.state == InertialReferenceState::Navigation
     }
 
     fn is_on(&self) -> bool {
@@ -1897,10 +2456,7 @@ impl InertialReference {
     }
 
     fn is_aligning(&self) -> bool {
-        match self.remaining_align_duration.as_ref() {
-            Some(remaining) => *remaining > Duration::from_secs(0),
-            None => false,
-        }
+        self.state == InertialReferenceState::Aligning
     }
 
     fn remaining_align_duration(&self) -> Option<Duration> {
@@ -1912,7 +2468,7 @@ impl InertialReference {
     }
 
     fn has_fault(&self) -> bool {
-        self.ir_fault_flash_duration.is_some()
+        self.ir_fault_flash_duration.is_some() || self.has_align_fault || self.is_failed()
     }
 
     fn has_magnetic_data(&self) -> bool {
@@ -1947,7 +2503,28 @@ impl InertialReference {
     }
 }
 impl SimulationElement for InertialReference {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.bus.accept(visitor);
+        self.failure.accept(visitor);
+        self.adiru_failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        // A negative value indicates that no heading has been entered.
+        let set_heading: f64 = reader.read(&self.set_heading_id);
+        self.set_heading = if set_heading >= 0. {
+            Some(Angle::new::<degree>(set_heading))
+        } else {
+            None
+        };
+    }
+
     fn write(&self, writer: &mut SimulatorWriter) {
+        // The entered heading has been consumed.
+        writer.write(&self.set_heading_id, -1.);
+
         self.pitch.write_to(writer);
         self.roll.write_to(writer);
 
@@ -1984,6 +2561,10 @@ impl SimulationElement for InertialReference {
         self.latitude.write_to(writer);
         self.longitude.write_to(writer);
         self.maint_word.write_to(writer);
+        self.gpirs_latitude.write_to(writer);
+        self.gpirs_longitude.write_to(writer);
+        self.gpirs_horizontal_figure_of_merit
+            .write_to_converted(writer, |value| value.get::<nautical_mile>());
     }
 }
 
@@ -2050,6 +2631,7 @@ impl NormaliseAngleExt for Angle {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::shared::arinc429_bus::{label_of, sdi_of, Arinc429Receiver};
     use crate::simulation::test::{ReadByName, WriteByName};
     use crate::{
         shared::arinc429::Arinc429Word,
@@ -2070,9 +2652,68 @@ mod tests {
         velocity::{foot_per_minute, knot},
     };
 
+    struct TestGpsSensor {
+        latitude: Arinc429Word<Angle>,
+        longitude: Arinc429Word<Angle>,
+    }
+    impl TestGpsSensor {
+        fn new() -> Self {
+            Self {
+                latitude: Arinc429Word::new(Angle::default(), SignStatus::NoComputedData),
+                longitude: Arinc429Word::new(Angle::default(), SignStatus::NoComputedData),
+            }
+        }
+
+        fn set_position(&mut self, latitude: Angle, longitude: Angle) {
+            self.latitude = Arinc429Word::new(latitude, SignStatus::NormalOperation);
+            self.longitude = Arinc429Word::new(longitude, SignStatus::NormalOperation);
+        }
+
+        fn set_unavailable(&mut self) {
+            self.latitude = Arinc429Word::new(Angle::default(), SignStatus::NoComputedData);
+            self.longitude = Arinc429Word::new(Angle::default(), SignStatus::NoComputedData);
+        }
+
+        fn ssm(&self) -> SignStatus {
+            self.latitude.ssm()
+        }
+    }
+    impl GpsSensor for TestGpsSensor {
+        fn latitude(&self) -> Arinc429Word<Angle> {
+            self.latitude
+        }
+
+        fn longitude(&self) -> Arinc429Word<Angle> {
+            self.longitude
+        }
+
+        fn altitude(&self) -> Arinc429Word<Length> {
+            Arinc429Word::new(Length::default(), self.ssm())
+        }
+
+        fn horizontal_figure_of_merit(&self) -> Arinc429Word<Length> {
+            Arinc429Word::new(Length::new::<nautical_mile>(0.02), self.ssm())
+        }
+
+        fn true_track(&self) -> Arinc429Word<Angle> {
+            Arinc429Word::new(Angle::default(), self.ssm())
+        }
+
+        fn ground_speed(&self) -> Arinc429Word<Velocity> {
+            Arinc429Word::new(Velocity::default(), self.ssm())
+        }
+
+        fn vertical_velocity(&self) -> Arinc429Word<Velocity> {
+            Arinc429Word::new(Velocity::default(), self.ssm())
+        }
+    }
+
     struct TestAircraft {
         adirs: AirDataInertialReferenceSystem,
         overhead: AirDataInertialReferenceSystemOverheadPanel,
+        gps_sensors: [TestGpsSensor; 2],
+        adr_receivers: [Arinc429Receiver; 3],
+        ir_receivers: [Arinc429Receiver; 3],
     }
     impl TestAircraft {
         fn new(context: &mut InitContext) -> Self {
@@ -2101,13 +2742,29 @@ mod tests {
             Self {
                 adirs: AirDataInertialReferenceSystem::new(context, adirs_programming),
                 overhead: AirDataInertialReferenceSystemOverheadPanel::new(context),
+                gps_sensors: [TestGpsSensor::new(), TestGpsSensor::new()],
+                adr_receivers: [1, 2, 3]
+                    .map(|_| Arinc429Receiver::new(&AirDataReference::BUS_LABELS)),
+                ir_receivers: [1, 2, 3]
+                    .map(|_| Arinc429Receiver::new(&InertialReference::BUS_LABELS)),
             }
         }
     }
     impl Aircraft for TestAircraft {
         fn update_after_power_distribution(&mut self, context: &UpdateContext) {
-            self.adirs.update(context, &self.overhead);
+            self.adirs.update(
+                context,
+                &self.overhead,
+                [&self.gps_sensors[0], &self.gps_sensors[1]],
+            );
             self.overhead.update(context, &self.adirs);
+
+            for (index, receiver) in self.adr_receivers.iter_mut().enumerate() {
+                receiver.update(context, self.adirs.adr_bus(index + 1));
+            }
+            for (index, receiver) in self.ir_receivers.iter_mut().enumerate() {
+                receiver.update(context, self.adirs.ir_bus(index + 1));
+            }
         }
     }
     impl SimulationElement for TestAircraft {
@@ -2162,6 +2819,16 @@ mod tests {
             self
         }
 
+        fn gps_position_of(mut self, number: usize, latitude: Angle, longitude: Angle) -> Self {
+            self.command(|a| a.gps_sensors[number - 1].set_position(latitude, longitude));
+            self
+        }
+
+        fn gps_unavailable(mut self, number: usize) -> Self {
+            self.command(|a| a.gps_sensors[number - 1].set_unavailable());
+            self
+        }
+
         fn mach_of(mut self, mach: MachNumber) -> Self {
             self.write_by_name(AdirsSimulatorData::MACH, mach);
             self
@@ -2314,6 +2981,13 @@ mod tests {
             self
         }
 
+        fn adr_fault_light_illuminated(&mut self, number: usize) -> bool {
+            self.read_by_name(&OnOffFaultPushButton::has_fault_id(&format!(
+                "ADIRS_ADR_{}",
+                number
+            )))
+        }
+
         fn ir_fault_light_illuminated(&mut self, number: usize) -> bool {
             self.read_by_name(&OnOffFaultPushButton::has_fault_id(&format!(
                 "ADIRS_IR_{}",
@@ -2337,6 +3011,14 @@ mod tests {
             self.read_by_name(AirDataInertialReferenceSystem::REMAINING_ALIGNMENT_TIME_KEY)
         }
 
+        fn heading_set_to(mut self, number: usize, angle: Angle) -> Self {
+            self.write_by_name(
+                &InertialReference::set_heading_id(number),
+                angle.get::<degree>(),
+            );
+            self
+        }
+
         fn all_mode_selectors_off(mut self) -> Self {
             self.move_all_mode_selectors_to(InertialReferenceMode::Off);
             self.run_without_delta();
@@ -2708,7 +3390,31 @@ mod tests {
             ))
         }
 
-        fn maint_word(&mut self, adiru_number: usize) -> Arinc429Word<u32> {
+        fn gpirs_latitude(&mut self, adiru_number: usize) -> Arinc429Word<Angle> {
+            self.read_arinc429_by_name(&output_data_id(
+                OutputDataType::Ir,
+                adiru_number,
+                InertialReference::GPIRS_LATITUDE,
+            ))
+        }
+
+        fn gpirs_longitude(&mut self, adiru_number: usize) -> Arinc429Word<Angle> {
+            self.read_arinc429_by_name(&output_data_id(
+                OutputDataType::Ir,
+                adiru_number,
+                InertialReference::GPIRS_LONGITUDE,
+            ))
+        }
+
+        fn gpirs_horizontal_figure_of_merit(&mut self, adiru_number: usize) -> Arinc429Word<f64> {
+            self.read_arinc429_by_name(&output_data_id(
+                OutputDataType::Ir,
+                adiru_number,
+                InertialReference::GPIRS_HORIZONTAL_FIGURE_OF_MERIT,
+            ))
+        }
+
+        fn maint_word(&mut self, adiru_number: usize) -> Arinc429Word<u32> {
             self.read_arinc429_by_name(&output_data_id(
                 OutputDataType::Ir,
                 adiru_number,
@@ -3096,6 +3802,56 @@ mod tests {
         assert!(!test_bed.ir_fault_light_illuminated(1));
     }
 
+    #[rstest]
+    #[case(1)]
+    #[case(2)]
+    #[case(3)]
+    fn moving_the_aircraft_during_alignment_restarts_the_alignment_with_an_align_fault(
+        #[case] adiru_number: usize,
+    ) {
+        let mut test_bed = test_bed_with()
+            .align_time_configured_as(AlignTime::Fast)
+            .and()
+            .ir_mode_selector_set_to(adiru_number, InertialReferenceMode::Navigation);
+        test_bed.run_without_delta();
+        test_bed.run_with_delta(Duration::from_secs(60));
+
+        test_bed = test_bed
+            .then_continue_with()
+            .ground_speed_of(Velocity::new::<knot>(5.));
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert!(test_bed.is_aligning(adiru_number));
+        assert!(test_bed.ir_fault_light_illuminated(adiru_number));
+        let maint_word_flags =
+            IrMaintFlags::from_bits(test_bed.maint_word(adiru_number).value()).unwrap();
+        assert!(maint_word_flags.contains(IrMaintFlags::ALIGN_FAULT));
+        assert!(maint_word_flags.contains(IrMaintFlags::EXCESS_MOTION_ERROR));
+
+        test_bed = test_bed
+            .then_continue_with()
+            .ground_speed_of(Velocity::new::<knot>(0.));
+        test_bed.run_with_delta(Duration::from_secs(60));
+        assert!(test_bed.is_aligning(adiru_number));
+
+        test_bed.run_with_delta(Duration::from_secs(30));
+        assert!(test_bed.is_aligned(adiru_number));
+        assert!(!test_bed.ir_fault_light_illuminated(adiru_number));
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(2)]
+    #[case(3)]
+    fn moving_the_aircraft_once_aligned_doesnt_cause_an_align_fault(#[case] adiru_number: usize) {
+        let mut test_bed =
+            all_adirus_aligned_test_bed_with().ground_speed_of(Velocity::new::<knot>(250.));
+        test_bed.run();
+
+        assert!(test_bed.is_aligned(adiru_number));
+        assert!(!test_bed.ir_fault_light_illuminated(adiru_number));
+    }
+
     #[rstest]
     #[case(InertialReferenceMode::Navigation)]
     #[case(InertialReferenceMode::Attitude)]
@@ -3292,6 +4048,45 @@ mod tests {
             test_bed.assert_adr_data_valid(false, adiru_number);
         }
 
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn when_adr_failed_data_is_not_valid_and_fault_light_illuminated(
+            #[case] adiru_number: usize,
+        ) {
+            let mut test_bed = all_adirus_aligned_test_bed();
+            test_bed.fail(FailureType::Adr(adiru_number));
+            test_bed.run();
+
+            test_bed.assert_adr_data_valid(false, adiru_number);
+            assert!(test_bed.adr_fault_light_illuminated(adiru_number));
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn when_adiru_failed_adr_data_is_not_valid(#[case] adiru_number: usize) {
+            let mut test_bed = all_adirus_aligned_test_bed();
+            test_bed.fail(FailureType::Adiru(adiru_number));
+            test_bed.run();
+
+            test_bed.assert_adr_data_valid(false, adiru_number);
+        }
+
+        #[test]
+        fn adr_data_is_valid_again_when_failure_cleared() {
+            let mut test_bed = all_adirus_aligned_test_bed();
+            test_bed.fail(FailureType::Adr(1));
+            test_bed.run();
+            test_bed.unfail(FailureType::Adr(1));
+            test_bed.run();
+
+            test_bed.assert_adr_data_valid(true, 1);
+            assert!(!test_bed.adr_fault_light_illuminated(1));
+        }
+
         #[rstest]
         #[case(1)]
         #[case(2)]
@@ -3734,6 +4529,46 @@ mod tests {
         use super::*;
         use uom::si::angular_velocity::revolution_per_minute;
 
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn when_ir_failed_data_is_not_available_and_fault_light_illuminated(
+            #[case] adiru_number: usize,
+        ) {
+            let mut test_bed = all_adirus_aligned_test_bed();
+            test_bed.fail(FailureType::Ir(adiru_number));
+            test_bed.run();
+
+            test_bed.assert_all_ir_data_available(false, adiru_number);
+            assert!(test_bed.ir_fault_light_illuminated(adiru_number));
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn when_adiru_failed_ir_data_is_not_available(#[case] adiru_number: usize) {
+            let mut test_bed = all_adirus_aligned_test_bed();
+            test_bed.fail(FailureType::Adiru(adiru_number));
+            test_bed.run();
+
+            test_bed.assert_all_ir_data_available(false, adiru_number);
+        }
+
+        #[test]
+        fn ir_realigns_once_failure_cleared() {
+            let mut test_bed = all_adirus_aligned_test_bed();
+            test_bed.fail(FailureType::Ir(1));
+            test_bed.run();
+            assert!(!test_bed.is_aligned(1));
+
+            test_bed.unfail(FailureType::Ir(1));
+            test_bed.run();
+
+            assert!(test_bed.is_aligning(1));
+        }
+
         #[rstest]
         #[case(1)]
         #[case(2)]
@@ -3788,6 +4623,11 @@ mod tests {
                 .ir_mode_selector_set_to(adiru_number, InertialReferenceMode::Attitude);
             test_bed.run();
 
+            test_bed = test_bed
+                .then_continue_with()
+                .heading_set_to(adiru_number, Angle::new::<degree>(90.));
+            test_bed.run();
+
             test_bed.assert_ir_attitude_data_available(true, adiru_number);
             test_bed.assert_ir_heading_data_available(true, adiru_number);
             test_bed.assert_ir_non_attitude_data_available(false, adiru_number);
@@ -3819,11 +4659,9 @@ mod tests {
         #[case(1)]
         #[case(2)]
         #[case(3)]
-        fn in_att_mode_attitude_and_heading_are_available_28_seconds_after_alignment_began(
+        fn in_att_mode_attitude_is_available_28_seconds_after_alignment_began_and_heading_once_entered(
             #[case] adiru_number: usize,
         ) {
-            // Note that in reality the HDG part needs HDG entry through the MCDU. As we haven't implemented
-            // that feature yet, for now we'll just make it available after 28 seconds in ATT mode.
             let mut test_bed = test_bed_with()
                 .ir_mode_selector_set_to(adiru_number, InertialReferenceMode::Attitude);
             test_bed.run_without_delta();
@@ -3836,16 +4674,133 @@ mod tests {
 
             test_bed.run_with_delta(Duration::from_millis(1));
             test_bed.assert_ir_attitude_data_available(true, adiru_number);
+            test_bed.assert_ir_heading_data_available(false, adiru_number);
+
+            let maint_word_flags =
+                IrMaintFlags::from_bits(test_bed.maint_word(adiru_number).value()).unwrap();
+            assert!(maint_word_flags.contains(IrMaintFlags::REV_ATT_MODE));
+            assert!(maint_word_flags.contains(IrMaintFlags::VALID_SET_HEADING));
+
+            test_bed = test_bed
+                .then_continue_with()
+                .heading_set_to(adiru_number, Angle::new::<degree>(90.));
+            test_bed.run_without_delta();
             test_bed.assert_ir_heading_data_available(true, adiru_number);
 
             let maint_word_flags =
-                IrMaintFlags::from_bits(test_bed.maint_word(adiru_number).value());
+                IrMaintFlags::from_bits(test_bed.maint_word(adiru_number).value()).unwrap();
+            assert!(maint_word_flags.contains(IrMaintFlags::REV_ATT_MODE));
+            assert!(!maint_word_flags.contains(IrMaintFlags::VALID_SET_HEADING));
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn in_att_mode_heading_follows_the_aircraft_heading_from_the_entered_heading(
+            #[case] adiru_number: usize,
+        ) {
+            let mut test_bed = all_adirus_aligned_test_bed_with()
+                .heading_of(Angle::new::<degree>(50.))
+                .ir_mode_selector_set_to(adiru_number, InertialReferenceMode::Attitude);
+            test_bed.run();
+
+            test_bed = test_bed
+                .then_continue_with()
+                .heading_set_to(adiru_number, Angle::new::<degree>(100.));
+            test_bed.run_without_delta();
+            assert_about_eq!(
+                test_bed.heading(adiru_number).value().get::<degree>(),
+                100.,
+                1e-6
+            );
+
+            test_bed = test_bed
+                .then_continue_with()
+                .heading_of(Angle::new::<degree>(80.));
+            test_bed.run_without_delta();
+            assert_about_eq!(
+                test_bed.heading(adiru_number).value().get::<degree>(),
+                130.,
+                1e-6
+            );
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn in_att_mode_heading_drifts(#[case] adiru_number: usize) {
+            let mut test_bed = all_adirus_aligned_test_bed_with()
+                .heading_of(Angle::new::<degree>(50.))
+                .ir_mode_selector_set_to(adiru_number, InertialReferenceMode::Attitude);
+            test_bed.run();
+
+            test_bed = test_bed
+                .then_continue_with()
+                .heading_set_to(adiru_number, Angle::new::<degree>(50.));
+            test_bed.run_without_delta();
+
+            for _ in 0..5 {
+                test_bed.run_with_delta(Duration::from_secs(3600));
+            }
+
+            let heading_error = (test_bed.heading(adiru_number).value()
+                - Angle::new::<degree>(50.))
+            .normalised_180()
+            .get::<degree>()
+            .abs();
+            assert!(heading_error > 0.);
+            assert!(heading_error < 5. * 4. * 2.);
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn heading_entry_is_ignored_in_nav_mode(#[case] adiru_number: usize) {
+            let mut test_bed = all_adirus_aligned_test_bed_with()
+                .heading_of(Angle::new::<degree>(50.))
+                .heading_set_to(adiru_number, Angle::new::<degree>(100.));
+            test_bed.run();
+
             assert_eq!(
-                maint_word_flags.unwrap() & IrMaintFlags::REV_ATT_MODE,
-                IrMaintFlags::REV_ATT_MODE
+                test_bed.heading(adiru_number).normal_value().unwrap(),
+                Angle::new::<degree>(50.)
             );
         }
 
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn att_mode_heading_needs_to_be_entered_again_after_leaving_att_mode(
+            #[case] adiru_number: usize,
+        ) {
+            let mut test_bed = all_adirus_aligned_test_bed_with()
+                .ir_mode_selector_set_to(adiru_number, InertialReferenceMode::Attitude);
+            test_bed.run();
+
+            test_bed = test_bed
+                .then_continue_with()
+                .heading_set_to(adiru_number, Angle::new::<degree>(100.));
+            test_bed.run();
+
+            test_bed = test_bed
+                .then_continue_with()
+                .ir_mode_selector_set_to(adiru_number, InertialReferenceMode::Off);
+            test_bed.run();
+
+            test_bed = test_bed
+                .then_continue_with()
+                .ir_mode_selector_set_to(adiru_number, InertialReferenceMode::Attitude);
+            test_bed.run_without_delta();
+            test_bed.run_with_delta(InertialReference::ATTITUDE_INITIALISATION_DURATION);
+
+            test_bed.assert_ir_attitude_data_available(true, adiru_number);
+            test_bed.assert_ir_heading_data_available(false, adiru_number);
+        }
+
         #[rstest]
         #[case(1)]
         #[case(2)]
@@ -4530,9 +5485,15 @@ mod tests {
             let mut test_bed = all_adirus_aligned_test_bed_with().latitude_of(latitude);
             test_bed.run();
 
-            assert_eq!(
-                test_bed.latitude(adiru_number).normal_value().unwrap(),
-                latitude
+            // Shortly after the alignment, the inertial drift is negligible.
+            assert_about_eq!(
+                test_bed
+                    .latitude(adiru_number)
+                    .normal_value()
+                    .unwrap()
+                    .get::<degree>(),
+                latitude.get::<degree>(),
+                1e-4
             );
         }
 
@@ -4545,9 +5506,169 @@ mod tests {
             let mut test_bed = all_adirus_aligned_test_bed_with().longitude_of(longitude);
             test_bed.run();
 
+            // Shortly after the alignment, the inertial drift is negligible.
+            assert_about_eq!(
+                test_bed
+                    .longitude(adiru_number)
+                    .normal_value()
+                    .unwrap()
+                    .get::<degree>(),
+                longitude.get::<degree>(),
+                1e-4
+            );
+        }
+    }
+
+    mod inertial_drift {
+        use super::*;
+        use crate::shared::seed_random;
+
+        fn position_error_in_nautical_miles(
+            test_bed: &mut AdirsTestBed,
+            adiru_number: usize,
+            latitude: Angle,
+            longitude: Angle,
+        ) -> f64 {
+            let north = (test_bed.latitude(adiru_number).value() - latitude).get::<degree>() * 60.;
+            let east = (test_bed.longitude(adiru_number).value() - longitude)
+                .normalised_180()
+                .get::<degree>()
+                * 60.
+                * latitude.cos().get::<ratio>();
+
+            (north.powi(2) + east.powi(2)).sqrt()
+        }
+
+        fn fly_for_hours(test_bed: &mut AdirsTestBed, hours: u64) {
+            for _ in 0..hours {
+                test_bed.run_with_delta(Duration::from_secs(3600));
+            }
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn inertial_position_diverges_over_a_long_flight(#[case] adiru_number: usize) {
+            let latitude = Angle::new::<degree>(45.);
+            let longitude = Angle::new::<degree>(10.);
+            let mut test_bed = all_adirus_aligned_test_bed_with()
+                .latitude_of(latitude)
+                .longitude_of(longitude);
+            fly_for_hours(&mut test_bed, 10);
+
+            let position_error =
+                position_error_in_nautical_miles(&mut test_bed, adiru_number, latitude, longitude);
+            assert!(test_bed.latitude(adiru_number).is_normal_operation());
+            assert!(position_error > 0.);
+            // Drift rates and Schuler amplitudes are limited to four standard deviations.
+            let maximum_axis_error = 4.
+                * (10. * InertialDrift::POSITION_DRIFT_RATE_STANDARD_DEVIATION_NM_PER_HOUR
+                    + InertialDrift::SCHULER_AMPLITUDE_STANDARD_DEVIATION_NM);
+            assert!(position_error < maximum_axis_error * 2_f64.sqrt());
+        }
+
+        #[test]
+        fn each_adiru_drifts_differently() {
+            let mut test_bed = all_adirus_aligned_test_bed_with()
+                .latitude_of(Angle::new::<degree>(45.))
+                .longitude_of(Angle::new::<degree>(10.));
+            fly_for_hours(&mut test_bed, 10);
+
+            assert_ne!(test_bed.latitude(1).value(), test_bed.latitude(2).value());
+            assert_ne!(test_bed.latitude(2).value(), test_bed.latitude(3).value());
+        }
+
+        fn latitudes_after_flying_with_seed(seed: u64) -> Vec<Angle> {
+            seed_random(seed);
+            let mut test_bed = all_adirus_aligned_test_bed_with()
+                .latitude_of(Angle::new::<degree>(45.))
+                .longitude_of(Angle::new::<degree>(10.));
+            fly_for_hours(&mut test_bed, 10);
+
+            (1..=3)
+                .map(|adiru_number| test_bed.latitude(adiru_number).value())
+                .collect()
+        }
+
+        #[test]
+        fn the_same_session_seed_reproduces_the_drift() {
             assert_eq!(
-                test_bed.longitude(adiru_number).normal_value().unwrap(),
-                longitude
+                latitudes_after_flying_with_seed(42),
+                latitudes_after_flying_with_seed(42)
+            );
+        }
+
+        #[test]
+        fn a_different_session_seed_results_in_a_different_drift() {
+            assert_ne!(
+                latitudes_after_flying_with_seed(42),
+                latitudes_after_flying_with_seed(43)
+            );
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn realignment_removes_the_accumulated_position_error(#[case] adiru_number: usize) {
+            let latitude = Angle::new::<degree>(45.);
+            let longitude = Angle::new::<degree>(10.);
+            let mut test_bed = all_adirus_aligned_test_bed_with()
+                .latitude_of(latitude)
+                .longitude_of(longitude);
+            fly_for_hours(&mut test_bed, 10);
+
+            test_bed = test_bed
+                .then_continue_with()
+                .ir_mode_selector_set_to(adiru_number, InertialReferenceMode::Off);
+            test_bed.run();
+
+            test_bed = test_bed
+                .then_continue_with()
+                .align_time_configured_as(AlignTime::Instant)
+                .ir_mode_selector_set_to(adiru_number, InertialReferenceMode::Navigation);
+            test_bed.run_without_delta();
+
+            assert!(test_bed.latitude(adiru_number).is_normal_operation());
+            assert_about_eq!(
+                position_error_in_nautical_miles(&mut test_bed, adiru_number, latitude, longitude),
+                0.
+            );
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn gpirs_position_remains_on_gps_position_while_inertial_position_drifts(
+            #[case] adiru_number: usize,
+        ) {
+            let latitude = Angle::new::<degree>(45.);
+            let longitude = Angle::new::<degree>(10.);
+            let mut test_bed = all_adirus_aligned_test_bed_with()
+                .latitude_of(latitude)
+                .longitude_of(longitude)
+                .gps_position_of(1, latitude, longitude)
+                .and()
+                .gps_position_of(2, latitude, longitude);
+            fly_for_hours(&mut test_bed, 10);
+
+            assert_about_eq!(
+                test_bed
+                    .gpirs_latitude(adiru_number)
+                    .value()
+                    .get::<degree>(),
+                latitude.get::<degree>(),
+                1e-6
+            );
+            assert_about_eq!(
+                test_bed
+                    .gpirs_longitude(adiru_number)
+                    .value()
+                    .get::<degree>(),
+                longitude.get::<degree>(),
+                1e-6
             );
         }
     }
@@ -4569,6 +5690,127 @@ mod tests {
             assert!(test_bed.uses_gps_as_primary());
         }
 
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn gpirs_position_is_ncd_without_gps(#[case] adiru_number: usize) {
+            let mut test_bed = all_adirus_aligned_test_bed();
+            test_bed.run();
+
+            assert!(test_bed.gpirs_latitude(adiru_number).is_no_computed_data());
+            assert!(test_bed.gpirs_longitude(adiru_number).is_no_computed_data());
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn gpirs_position_is_corrected_to_gps_position(#[case] adiru_number: usize) {
+            let mut test_bed = all_adirus_aligned_test_bed_with()
+                .latitude_of(Angle::new::<degree>(10.))
+                .longitude_of(Angle::new::<degree>(20.))
+                .gps_position_of(1, Angle::new::<degree>(10.01), Angle::new::<degree>(20.02))
+                .and()
+                .gps_position_of(2, Angle::new::<degree>(10.01), Angle::new::<degree>(20.02));
+            test_bed.run();
+
+            let latitude = test_bed.gpirs_latitude(adiru_number);
+            assert!(latitude.is_normal_operation());
+            assert_about_eq!(latitude.value().get::<degree>(), 10.01);
+            let longitude = test_bed.gpirs_longitude(adiru_number);
+            assert!(longitude.is_normal_operation());
+            assert_about_eq!(longitude.value().get::<degree>(), 20.02);
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn gpirs_position_coasts_when_gps_is_lost(#[case] adiru_number: usize) {
+            let mut test_bed = all_adirus_aligned_test_bed_with()
+                .latitude_of(Angle::new::<degree>(10.))
+                .longitude_of(Angle::new::<degree>(20.))
+                .gps_position_of(1, Angle::new::<degree>(10.01), Angle::new::<degree>(20.02))
+                .and()
+                .gps_position_of(2, Angle::new::<degree>(10.01), Angle::new::<degree>(20.02));
+            test_bed.run();
+
+            let coasting_start_hfom = test_bed
+                .gpirs_horizontal_figure_of_merit(adiru_number)
+                .value();
+
+            test_bed = test_bed
+                .then_continue_with()
+                .gps_unavailable(1)
+                .gps_unavailable(2)
+                .latitude_of(Angle::new::<degree>(11.))
+                .longitude_of(Angle::new::<degree>(21.));
+            test_bed.run_with_delta(Duration::from_secs(3600));
+
+            // The hybrid position keeps the last correction to the drifting inertial position.
+            let inertial_latitude = test_bed.latitude(adiru_number).value();
+            let inertial_longitude = test_bed.longitude(adiru_number).value();
+            let latitude = test_bed.gpirs_latitude(adiru_number);
+            assert!(latitude.is_normal_operation());
+            assert_about_eq!(
+                latitude.value().get::<degree>(),
+                inertial_latitude.get::<degree>() + 0.01,
+                1e-4
+            );
+            let longitude = test_bed.gpirs_longitude(adiru_number);
+            assert!(longitude.is_normal_operation());
+            assert_about_eq!(
+                longitude.value().get::<degree>(),
+                inertial_longitude.get::<degree>() + 0.02,
+                1e-4
+            );
+            let hfom = test_bed.gpirs_horizontal_figure_of_merit(adiru_number);
+            assert!(hfom.is_normal_operation());
+            assert_about_eq!(
+                hfom.value(),
+                coasting_start_hfom + InertialReference::GPIRS_COASTING_DRIFT_RATE_NM_PER_HOUR
+            );
+        }
+
+        #[rstest]
+        #[case(1, 2)]
+        #[case(2, 1)]
+        #[case(3, 2)]
+        fn gpirs_uses_off_side_gps_when_on_side_gps_is_unavailable(
+            #[case] adiru_number: usize,
+            #[case] available_gps_number: usize,
+        ) {
+            let mut test_bed = all_adirus_aligned_test_bed_with()
+                .latitude_of(Angle::new::<degree>(10.))
+                .longitude_of(Angle::new::<degree>(20.))
+                .gps_position_of(
+                    available_gps_number,
+                    Angle::new::<degree>(10.01),
+                    Angle::new::<degree>(20.02),
+                );
+            test_bed.run();
+
+            let latitude = test_bed.gpirs_latitude(adiru_number);
+            assert!(latitude.is_normal_operation());
+            assert_about_eq!(latitude.value().get::<degree>(), 10.01);
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn gpirs_position_is_ncd_when_ir_is_not_aligned(#[case] adiru_number: usize) {
+            let mut test_bed = test_bed_with()
+                .gps_position_of(1, Angle::new::<degree>(10.01), Angle::new::<degree>(20.02))
+                .and()
+                .gps_position_of(2, Angle::new::<degree>(10.01), Angle::new::<degree>(20.02));
+            test_bed.run();
+
+            assert!(test_bed.gpirs_latitude(adiru_number).is_no_computed_data());
+            assert!(test_bed.gpirs_longitude(adiru_number).is_no_computed_data());
+        }
+
         #[test]
         fn does_not_use_gps_as_primary_when_no_adiru_is_aligned() {
             let mut test_bed = test_bed();
@@ -4672,4 +5914,127 @@ mod tests {
             assert!(!test_bed.query(|a| a.adirs.adirus[adiru_number - 1].low_speed_warning_4()));
         }
     }
+
+    mod bus {
+        use super::*;
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn adr_and_ir_transmit_with_their_adiru_number_as_sdi(#[case] adiru_number: usize) {
+            let mut test_bed = all_adirus_aligned_test_bed();
+            test_bed.run();
+
+            test_bed.query(|a| {
+                let words = a.adirs.adr_bus(adiru_number).transmitted_words();
+                assert!(!words.is_empty());
+                assert!(words.iter().all(|&raw| sdi_of(raw) == adiru_number as u8));
+
+                let words = a.adirs.ir_bus(adiru_number).transmitted_words();
+                assert!(!words.is_empty());
+                assert!(words.iter().all(|&raw| sdi_of(raw) == adiru_number as u8));
+            });
+        }
+
+        #[test]
+        fn received_altitude_matches_the_altitude_output() {
+            let mut test_bed =
+                all_adirus_aligned_test_bed()
+                    .altimeter_setting_of(Pressure::new::<hectopascal>(1013.));
+            test_bed.set_pressure_altitude(Length::new::<foot>(12345.));
+            test_bed.run();
+
+            let altitude = test_bed.query(|a| a.adr_receivers[0].word(0o203)).unwrap();
+            assert!(altitude.is_normal_operation());
+            assert_about_eq!(altitude.value(), 12345.);
+
+            let baro_correction = test_bed.query(|a| a.adr_receivers[0].word(0o234)).unwrap();
+            assert_about_eq!(baro_correction.value(), 1013., 1e-9);
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn switched_off_adr_is_detected_by_its_receivers(#[case] adiru_number: usize) {
+            let mut test_bed = all_adirus_aligned_test_bed();
+            test_bed.run();
+            assert!(!test_bed.query(|a| a.adr_receivers[adiru_number - 1].all_stale()));
+
+            test_bed = test_bed
+                .then_continue_with()
+                .adr_push_button_off(adiru_number);
+            test_bed.run_with_delta(Duration::from_secs(2));
+
+            test_bed.query(|a| {
+                assert!(!a.adirs.adr_bus(adiru_number).is_transmitting());
+                assert!(a.adr_receivers[adiru_number - 1].all_stale());
+                assert!(a.adr_receivers[adiru_number % 3].word(0o203).is_some());
+            });
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn ir_with_mode_selector_off_is_detected_by_its_receivers(#[case] adiru_number: usize) {
+            let mut test_bed = all_adirus_aligned_test_bed();
+            test_bed.run();
+            assert!(test_bed
+                .query(|a| a.ir_receivers[adiru_number - 1].word(0o310))
+                .is_some());
+
+            test_bed = test_bed
+                .then_continue_with()
+                .ir_mode_selector_set_to(adiru_number, InertialReferenceMode::Off);
+            test_bed.run_with_delta(Duration::from_secs(2));
+
+            assert!(test_bed.query(|a| a.ir_receivers[adiru_number - 1].all_stale()));
+        }
+
+        #[test]
+        fn wind_labels_are_not_transmitted_in_attitude_mode() {
+            let mut test_bed = all_adirus_aligned_test_bed()
+                .ir_mode_selector_set_to(1, InertialReferenceMode::Attitude);
+            test_bed.run();
+            test_bed.run();
+
+            test_bed.query(|a| {
+                for label in [0o015, 0o016, 0o315, 0o316] {
+                    assert!(a.ir_receivers[0].is_stale(label));
+                    assert!(a.ir_receivers[1].word(label).is_some());
+                }
+
+                assert!(a.ir_receivers[0].word(0o324).is_some());
+            });
+        }
+
+        #[test]
+        fn labels_are_transmitted_at_most_once_per_tick() {
+            let mut test_bed = all_adirus_aligned_test_bed();
+            test_bed.run();
+
+            let words = test_bed.query(|a| a.adirs.adr_bus(1).transmitted_words().to_vec());
+            let mut labels: Vec<_> = words.iter().map(|&raw| label_of(raw)).collect();
+            labels.sort_unstable();
+            labels.dedup();
+            assert_eq!(labels.len(), words.len());
+            assert_eq!(labels.len(), AirDataReference::BUS_LABELS.len());
+        }
+
+        #[test]
+        fn parity_error_is_detected_by_the_receivers() {
+            let mut test_bed = all_adirus_aligned_test_bed();
+            test_bed.fail(FailureType::AdiruBusParityError(2));
+            test_bed.run();
+
+            test_bed.query(|a| {
+                assert!(a.adr_receivers[1].all_stale());
+                assert!(a.ir_receivers[1].all_stale());
+                assert!(a.adr_receivers[1].parity_errors() > 0);
+                assert!(!a.adr_receivers[0].all_stale());
+            });
+        }
+    }
 }
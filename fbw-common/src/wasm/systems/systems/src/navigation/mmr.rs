@@ -0,0 +1,422 @@
+use crate::failures::{Failure, FailureType};
+use crate::shared::arinc429::{Arinc429Word, SignStatus};
+use crate::shared::{ConsumePower, ElectricalBusType, ElectricalBuses};
+use crate::simulation::{
+    InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+    SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+};
+use std::time::Duration;
+use uom::si::f64::{Angle, Length, Power, Velocity};
+use uom::si::length::{meter, nautical_mile};
+use uom::si::power::watt;
+use uom::si::velocity::foot_per_minute;
+
+/// This trait describes the outputs of a GPS sensor, such as the GPS function of a multi-mode
+/// receiver.
+pub trait GpsSensor {
+    fn latitude(&self) -> Arinc429Word<Angle>;
+    fn longitude(&self) -> Arinc429Word<Angle>;
+    fn altitude(&self) -> Arinc429Word<Length>;
+    /// The horizontal figure of merit, i.e. the radius of the circle centered on the reported
+    /// position in which the actual position lies with a probability of 95%.
+    fn horizontal_figure_of_merit(&self) -> Arinc429Word<Length>;
+    fn true_track(&self) -> Arinc429Word<Angle>;
+    fn ground_speed(&self) -> Arinc429Word<Velocity>;
+    fn vertical_velocity(&self) -> Arinc429Word<Velocity>;
+}
+
+/// This struct represents the GPS function of a multi-mode receiver (MMR). The other functions of
+/// the MMR (ILS, GLS) are not modelled here.
+///
+/// After power up the receiver needs to acquire the satellites before it can compute a position.
+/// During the acquisition, or when no satellites are available, the outputs are NCD. When the
+/// receiver is unpowered or has failed, the outputs are FW.
+pub struct MultiModeReceiver {
+    failure: Failure,
+
+    powered_by: ElectricalBusType,
+    is_powered: bool,
+
+    /// The time remaining until a position fix is obtained. [None] when the receiver isn't
+    /// running, i.e. it is either unpowered or failed.
+    remaining_acquisition_duration: Option<Duration>,
+
+    satellite_outage_id: VariableIdentifier,
+    satellite_outage: bool,
+
+    latitude_id: VariableIdentifier,
+    latitude: Angle,
+    longitude_id: VariableIdentifier,
+    longitude: Angle,
+    altitude_id: VariableIdentifier,
+    altitude: Length,
+    true_track_id: VariableIdentifier,
+    true_track: Angle,
+    ground_speed_id: VariableIdentifier,
+    ground_speed: Velocity,
+    vertical_velocity_id: VariableIdentifier,
+    vertical_velocity: Velocity,
+
+    gps_latitude_id: VariableIdentifier,
+    gps_longitude_id: VariableIdentifier,
+    gps_altitude_id: VariableIdentifier,
+    gps_horizontal_figure_of_merit_id: VariableIdentifier,
+    gps_true_track_id: VariableIdentifier,
+    gps_ground_speed_id: VariableIdentifier,
+    gps_vertical_velocity_id: VariableIdentifier,
+}
+
+impl MultiModeReceiver {
+    const SATELLITE_OUTAGE: &'static str = "GPS_SATELLITE_OUTAGE";
+    const LATITUDE: &'static str = "PLANE LATITUDE";
+    const LONGITUDE: &'static str = "PLANE LONGITUDE";
+    const ALTITUDE: &'static str = "PLANE ALTITUDE";
+    const TRUE_TRACK: &'static str = "GPS GROUND TRUE TRACK";
+    const GROUND_SPEED: &'static str = "GPS GROUND SPEED";
+    const VERTICAL_VELOCITY: &'static str = "VELOCITY WORLD Y";
+
+    /// The time to first fix after power up.
+    const ACQUISITION_DURATION: Duration = Duration::from_secs(90);
+    /// The time to regain the fix when the satellites become available again.
+    const REACQUISITION_DURATION: Duration = Duration::from_secs(10);
+    const NOMINAL_HORIZONTAL_FIGURE_OF_MERIT_METERS: f64 = 30.;
+
+    pub fn new(context: &mut InitContext, number: usize, powered_by: ElectricalBusType) -> Self {
+        Self {
+            failure: Failure::new(FailureType::GpsReceiver(number)),
+            powered_by,
+            is_powered: false,
+            remaining_acquisition_duration: if context.has_engines_running() {
+                Some(Duration::ZERO)
+            } else {
+                None
+            },
+
+            satellite_outage_id: context.get_identifier(Self::SATELLITE_OUTAGE.to_owned()),
+            satellite_outage: false,
+
+            latitude_id: context.get_identifier(Self::LATITUDE.to_owned()),
+            latitude: Angle::default(),
+            longitude_id: context.get_identifier(Self::LONGITUDE.to_owned()),
+            longitude: Angle::default(),
+            altitude_id: context.get_identifier(Self::ALTITUDE.to_owned()),
+            altitude: Length::default(),
+            true_track_id: context.get_identifier(Self::TRUE_TRACK.to_owned()),
+            true_track: Angle::default(),
+            ground_speed_id: context.get_identifier(Self::GROUND_SPEED.to_owned()),
+            ground_speed: Velocity::default(),
+            vertical_velocity_id: context.get_identifier(Self::VERTICAL_VELOCITY.to_owned()),
+            vertical_velocity: Velocity::default(),
+
+            gps_latitude_id: context.get_identifier(Self::output_id(number, "LATITUDE")),
+            gps_longitude_id: context.get_identifier(Self::output_id(number, "LONGITUDE")),
+            gps_altitude_id: context.get_identifier(Self::output_id(number, "ALTITUDE")),
+            gps_horizontal_figure_of_merit_id: context
+                .get_identifier(Self::output_id(number, "HFOM")),
+            gps_true_track_id: context.get_identifier(Self::output_id(number, "TRUE_TRACK")),
+            gps_ground_speed_id: context.get_identifier(Self::output_id(number, "GROUND_SPEED")),
+            gps_vertical_velocity_id: context
+                .get_identifier(Self::output_id(number, "VERTICAL_VELOCITY")),
+        }
+    }
+
+    fn output_id(number: usize, name: &str) -> String {
+        format!("MMR_{}_GPS_{}", number, name)
+    }
+
+    pub fn update(&mut self, context: &UpdateContext) {
+        // Without power, or when failed, the receiver loses its satellite tracking and has to
+        // start the acquisition from scratch.
+        if !self.is_powered || self.failure.is_active() {
+            self.remaining_acquisition_duration = None;
+            return;
+        }
+
+        let remaining = self
+            .remaining_acquisition_duration
+            .unwrap_or(Self::ACQUISITION_DURATION);
+        self.remaining_acquisition_duration = Some(if self.satellite_outage {
+            remaining.max(Self::REACQUISITION_DURATION)
+        } else {
+            remaining.saturating_sub(context.delta())
+        });
+    }
+
+    pub fn has_failed(&self) -> bool {
+        self.failure.is_active()
+    }
+
+    pub fn has_position_fix(&self) -> bool {
+        self.remaining_acquisition_duration == Some(Duration::ZERO) && !self.satellite_outage
+    }
+
+    fn ssm(&self) -> SignStatus {
+        if !self.is_powered || self.remaining_acquisition_duration.is_none() {
+            SignStatus::FailureWarning
+        } else if self.has_position_fix() {
+            SignStatus::NormalOperation
+        } else {
+            SignStatus::NoComputedData
+        }
+    }
+
+    fn output<T: Copy + Default>(&self, value: T) -> Arinc429Word<T> {
+        let ssm = self.ssm();
+        if ssm == SignStatus::NormalOperation {
+            Arinc429Word::new(value, ssm)
+        } else {
+            Arinc429Word::new(T::default(), ssm)
+        }
+    }
+}
+
+impl GpsSensor for MultiModeReceiver {
+    fn latitude(&self) -> Arinc429Word<Angle> {
+        self.output(self.latitude)
+    }
+
+    fn longitude(&self) -> Arinc429Word<Angle> {
+        self.output(self.longitude)
+    }
+
+    fn altitude(&self) -> Arinc429Word<Length> {
+        self.output(self.altitude)
+    }
+
+    fn horizontal_figure_of_merit(&self) -> Arinc429Word<Length> {
+        self.output(Length::new::<meter>(
+            Self::NOMINAL_HORIZONTAL_FIGURE_OF_MERIT_METERS,
+        ))
+    }
+
+    fn true_track(&self) -> Arinc429Word<Angle> {
+        self.output(self.true_track)
+    }
+
+    fn ground_speed(&self) -> Arinc429Word<Velocity> {
+        self.output(self.ground_speed)
+    }
+
+    fn vertical_velocity(&self) -> Arinc429Word<Velocity> {
+        self.output(self.vertical_velocity)
+    }
+}
+
+impl SimulationElement for MultiModeReceiver {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.satellite_outage = reader.read(&self.satellite_outage_id);
+        self.latitude = reader.read(&self.latitude_id);
+        self.longitude = reader.read(&self.longitude_id);
+        self.altitude = reader.read(&self.altitude_id);
+        self.true_track = reader.read(&self.true_track_id);
+        self.ground_speed = reader.read(&self.ground_speed_id);
+        let vertical_velocity: f64 = reader.read(&self.vertical_velocity_id);
+        self.vertical_velocity = Velocity::new::<foot_per_minute>(vertical_velocity);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write_arinc429(&self.gps_latitude_id, self.latitude().value(), self.ssm());
+        writer.write_arinc429(&self.gps_longitude_id, self.longitude().value(), self.ssm());
+        writer.write_arinc429(&self.gps_altitude_id, self.altitude().value(), self.ssm());
+        writer.write_arinc429(
+            &self.gps_horizontal_figure_of_merit_id,
+            self.horizontal_figure_of_merit()
+                .value()
+                .get::<nautical_mile>(),
+            self.ssm(),
+        );
+        writer.write_arinc429(
+            &self.gps_true_track_id,
+            self.true_track().value(),
+            self.ssm(),
+        );
+        writer.write_arinc429(
+            &self.gps_ground_speed_id,
+            self.ground_speed().value(),
+            self.ssm(),
+        );
+        writer.write_arinc429(
+            &self.gps_vertical_velocity_id,
+            self.vertical_velocity().value().get::<foot_per_minute>(),
+            self.ssm(),
+        );
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.powered_by);
+    }
+
+    fn consume_power<T: ConsumePower>(&mut self, _: &UpdateContext, consumption: &mut T) {
+        if !self.has_failed() {
+            consumption.consume_from_bus(self.powered_by, Power::new::<watt>(35.))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::electrical::test::TestElectricitySource;
+    use crate::electrical::{ElectricalBus, Electricity};
+    use crate::shared::PotentialOrigin;
+    use crate::simulation::test::{ReadByName, SimulationTestBed, TestBed, WriteByName};
+    use crate::simulation::{Aircraft, InitContext, SimulationElementVisitor, StartState};
+    use ntest::assert_about_eq;
+    use uom::si::angle::degree;
+    use uom::si::electric_potential::volt;
+    use uom::si::f64::ElectricPotential;
+    use uom::si::length::foot;
+
+    struct TestAircraft {
+        electricity_source: TestElectricitySource,
+        ac_1_bus: ElectricalBus,
+        mmr: MultiModeReceiver,
+        is_ac_1_powered: bool,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                electricity_source: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::EngineGenerator(1),
+                ),
+                ac_1_bus: ElectricalBus::new(context, ElectricalBusType::AlternatingCurrent(1)),
+                mmr: MultiModeReceiver::new(context, 1, ElectricalBusType::AlternatingCurrent(1)),
+                is_ac_1_powered: true,
+            }
+        }
+
+        fn set_ac_1_power(&mut self, is_powered: bool) {
+            self.is_ac_1_powered = is_powered;
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            self.electricity_source
+                .power_with_potential(ElectricPotential::new::<volt>(115.));
+            electricity.supplied_by(&self.electricity_source);
+
+            if self.is_ac_1_powered {
+                electricity.flow(&self.electricity_source, &self.ac_1_bus);
+            }
+        }
+
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.mmr.update(context);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.mmr.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed_with_position() -> SimulationTestBed<TestAircraft> {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.write_by_name(MultiModeReceiver::LATITUDE, Angle::new::<degree>(47.26));
+        test_bed.write_by_name(MultiModeReceiver::LONGITUDE, Angle::new::<degree>(11.34));
+        test_bed.write_by_name(MultiModeReceiver::ALTITUDE, Length::new::<foot>(1906.));
+
+        test_bed
+    }
+
+    #[test]
+    fn provides_position_when_started_with_engines_running() {
+        let mut test_bed = test_bed_with_position();
+        test_bed.run_with_delta(Duration::from_millis(1));
+
+        let latitude: Arinc429Word<Angle> = test_bed.read_arinc429_by_name("MMR_1_GPS_LATITUDE");
+        assert!(latitude.is_normal_operation());
+        assert_about_eq!(latitude.value().get::<degree>(), 47.26, 1e-4);
+        let longitude: Arinc429Word<Angle> = test_bed.read_arinc429_by_name("MMR_1_GPS_LONGITUDE");
+        assert!(longitude.is_normal_operation());
+        assert_about_eq!(longitude.value().get::<degree>(), 11.34, 1e-4);
+        let altitude: Arinc429Word<Length> = test_bed.read_arinc429_by_name("MMR_1_GPS_ALTITUDE");
+        assert!(altitude.is_normal_operation());
+        assert_about_eq!(altitude.value().get::<foot>(), 1906., 1e-2);
+        let hfom: Arinc429Word<f64> = test_bed.read_arinc429_by_name("MMR_1_GPS_HFOM");
+        assert!(hfom.is_normal_operation());
+        assert!(hfom.value() < 0.1);
+    }
+
+    #[test]
+    fn acquires_satellites_after_cold_start() {
+        let mut test_bed = SimulationTestBed::new_with_start_state(StartState::Apron, |context| {
+            TestAircraft::new(context)
+        });
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        let latitude: Arinc429Word<Angle> = test_bed.read_arinc429_by_name("MMR_1_GPS_LATITUDE");
+        assert!(latitude.is_no_computed_data());
+
+        test_bed.run_with_delta(MultiModeReceiver::ACQUISITION_DURATION);
+
+        let latitude: Arinc429Word<Angle> = test_bed.read_arinc429_by_name("MMR_1_GPS_LATITUDE");
+        assert!(latitude.is_normal_operation());
+    }
+
+    #[test]
+    fn failure_warning_when_unpowered() {
+        let mut test_bed = test_bed_with_position();
+        test_bed.command(|a| a.set_ac_1_power(false));
+        test_bed.run_with_delta(Duration::from_millis(1));
+
+        let latitude: Arinc429Word<Angle> = test_bed.read_arinc429_by_name("MMR_1_GPS_LATITUDE");
+        assert!(latitude.is_failure_warning());
+    }
+
+    #[test]
+    fn power_loss_requires_a_new_acquisition() {
+        let mut test_bed = test_bed_with_position();
+        test_bed.command(|a| a.set_ac_1_power(false));
+        test_bed.run_with_delta(Duration::from_millis(1));
+        test_bed.command(|a| a.set_ac_1_power(true));
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        let latitude: Arinc429Word<Angle> = test_bed.read_arinc429_by_name("MMR_1_GPS_LATITUDE");
+        assert!(latitude.is_no_computed_data());
+    }
+
+    #[test]
+    fn failure_warning_when_failed() {
+        let mut test_bed = test_bed_with_position();
+        test_bed.fail(FailureType::GpsReceiver(1));
+        test_bed.run_with_delta(Duration::from_millis(1));
+
+        let latitude: Arinc429Word<Angle> = test_bed.read_arinc429_by_name("MMR_1_GPS_LATITUDE");
+        assert!(latitude.is_failure_warning());
+    }
+
+    #[test]
+    fn no_computed_data_during_satellite_outage() {
+        let mut test_bed = test_bed_with_position();
+        test_bed.write_by_name(MultiModeReceiver::SATELLITE_OUTAGE, true);
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        let latitude: Arinc429Word<Angle> = test_bed.read_arinc429_by_name("MMR_1_GPS_LATITUDE");
+        assert!(latitude.is_no_computed_data());
+
+        test_bed.write_by_name(MultiModeReceiver::SATELLITE_OUTAGE, false);
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        let latitude: Arinc429Word<Angle> = test_bed.read_arinc429_by_name("MMR_1_GPS_LATITUDE");
+        assert!(latitude.is_no_computed_data());
+
+        test_bed.run_with_delta(MultiModeReceiver::REACQUISITION_DURATION);
+
+        let latitude: Arinc429Word<Angle> = test_bed.read_arinc429_by_name("MMR_1_GPS_LATITUDE");
+        assert!(latitude.is_normal_operation());
+    }
+}
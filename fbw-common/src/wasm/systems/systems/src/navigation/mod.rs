@@ -1,3 +1,4 @@
 pub mod adirs;
 pub mod ala52b;
+pub mod mmr;
 pub mod radio_altimeter;
@@ -9,6 +9,7 @@ use crate::{
     },
 };
 use nalgebra::Vector3;
+use serde::Deserialize;
 use uom::si::{f64::Mass, mass::kilogram, mass::pound};
 
 pub struct LoadsheetInfo {
@@ -19,17 +20,27 @@ pub struct LoadsheetInfo {
     pub leading_edge_mean_aerodynamic_chord: f64,
 }
 
-pub struct PaxInfo<'a> {
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PaxInfo {
     pub max_pax: i8,
     pub position: (f64, f64, f64),
-    pub pax_id: &'a str,
-    pub payload_id: &'a str,
+    pub pax_id: String,
+    pub payload_id: String,
 }
-pub struct CargoInfo<'a> {
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CargoInfo {
     pub max_cargo_kg: f64,
     pub position: (f64, f64, f64),
-    pub cargo_id: &'a str,
-    pub payload_id: &'a str,
+    pub cargo_id: String,
+    pub payload_id: String,
+}
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BoardingAgentInfo {
+    pub door_id: Option<String>,
+    pub order: Vec<usize>,
 }
 
 pub trait NumberOfPassengers {
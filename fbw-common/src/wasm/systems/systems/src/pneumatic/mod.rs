@@ -531,9 +531,12 @@ pub struct Precooler {
     heat_transfer_coefficient: f64,
     internal_connector: PneumaticContainerConnector,
     exhaust: PneumaticExhaust,
+    failure: Option<Failure>,
 }
 impl Precooler {
     const HEAT_CAPACITY_CONSTANT_PRESSURE: f64 = 1.005e3;
+    /// Fraction of the heat transfer left when the precooler is faulty (e.g. a clogged core).
+    const FAULTY_HEAT_TRANSFER_FACTOR: f64 = 0.1;
 
     /// The `heat_transfer_coefficient` contains both the heat transfer coefficient and the area of exchange.
     /// Typical values of the heat transfer coefficient for air to air coolers are 60-180 W/(m^2*K).
@@ -542,6 +545,20 @@ impl Precooler {
             heat_transfer_coefficient,
             internal_connector: PneumaticContainerConnector::new(),
             exhaust: PneumaticExhaust::new(3., 3., Pressure::new::<psi>(0.)),
+            failure: None,
+        }
+    }
+
+    pub fn with_failure(mut self, failure_type: FailureType) -> Self {
+        self.failure = Some(Failure::new(failure_type));
+        self
+    }
+
+    fn heat_transfer_coefficient(&self) -> f64 {
+        if self.failure.is_some_and(|failure| failure.is_active()) {
+            self.heat_transfer_coefficient * Self::FAULTY_HEAT_TRANSFER_FACTOR
+        } else {
+            self.heat_transfer_coefficient
         }
     }
 
@@ -558,7 +575,7 @@ impl Precooler {
         );
 
         let mass_energy_change = temperature_gradient / Self::HEAT_CAPACITY_CONSTANT_PRESSURE
-            * (self.heat_transfer_coefficient * context.delta_as_secs_f64());
+            * (self.heat_transfer_coefficient() * context.delta_as_secs_f64());
 
         supply.update_temperature(-mass_energy_change / supply.mass().get::<kilogram>());
         container_one
@@ -569,6 +586,15 @@ impl Precooler {
             .update_move_fluid(context, container_one, container_two);
     }
 }
+impl SimulationElement for Precooler {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        if let Some(failure) = &mut self.failure {
+            failure.accept(visitor);
+        }
+
+        visitor.visit(self);
+    }
+}
 
 pub struct VariableVolumeContainer {
     pipe: PneumaticPipe,
@@ -1241,6 +1267,49 @@ mod tests {
         assert!(supply.temperature() > ThermodynamicTemperature::new::<degree_celsius>(15.));
     }
 
+    #[test]
+    fn faulty_precooler_cools_less() {
+        fn bleed_temperature_after_precooler(
+            precooler: &mut Precooler,
+        ) -> ThermodynamicTemperature {
+            let context = context(Duration::from_secs(1), Length::new::<foot>(0.));
+
+            let mut from = PneumaticPipe::new(
+                Volume::new::<cubic_meter>(1.),
+                Pressure::new::<psi>(29.4),
+                ThermodynamicTemperature::new::<degree_celsius>(200.),
+            );
+            let mut supply = PneumaticPipe::new(
+                Volume::new::<cubic_meter>(1.),
+                Pressure::new::<psi>(14.7),
+                ThermodynamicTemperature::new::<degree_celsius>(15.),
+            );
+            let mut to = PneumaticPipe::new(
+                Volume::new::<cubic_meter>(1.),
+                Pressure::new::<psi>(14.7),
+                ThermodynamicTemperature::new::<degree_celsius>(200.),
+            );
+
+            precooler.update(&context, &mut from, &mut supply, &mut to);
+
+            from.temperature()
+        }
+
+        let mut healthy_precooler = Precooler::new(100.);
+        let mut faulty_precooler =
+            Precooler::new(100.).with_failure(FailureType::PrecoolerFault(1));
+        faulty_precooler
+            .failure
+            .as_mut()
+            .unwrap()
+            .receive_failure(FailureType::PrecoolerFault(1), true);
+
+        assert!(
+            bleed_temperature_after_precooler(&mut faulty_precooler)
+                > bleed_temperature_after_precooler(&mut healthy_precooler)
+        );
+    }
+
     #[test]
     fn pressure_increases_for_temperature_increase() {
         let mut pipe = PneumaticPipe::new(
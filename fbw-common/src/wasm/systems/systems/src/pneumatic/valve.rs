@@ -1,6 +1,7 @@
 use std::f64::consts::PI;
 
 use crate::{
+    failures::{Failure, FailureType},
     pneumatic::{Solenoid, SolenoidSignal},
     shared::{interpolation, ControllerSignal, ElectricalBusType, ElectricalBuses, PneumaticValve},
     simulation::{SimulationElement, SimulationElementVisitor, UpdateContext},
@@ -189,6 +190,7 @@ pub struct ElectroPneumaticValve {
     connector: PneumaticContainerConnector,
     is_powered: bool,
     powered_by: ElectricalBusType,
+    stuck_failure: Option<Failure>,
 }
 impl ElectroPneumaticValve {
     const SPRING_CHARACTERISTIC: f64 = 1.;
@@ -199,16 +201,30 @@ impl ElectroPneumaticValve {
             connector: PneumaticContainerConnector::new(),
             is_powered: false,
             powered_by,
+            stuck_failure: None,
         }
     }
 
+    /// A stuck valve keeps its current open amount, regardless of
+    /// controller signals, power or pressure difference.
+    pub fn with_stuck_failure(mut self, failure_type: FailureType) -> Self {
+        self.stuck_failure = Some(Failure::new(failure_type));
+        self
+    }
+
+    fn is_stuck(&self) -> bool {
+        self.stuck_failure
+            .as_ref()
+            .is_some_and(|failure| failure.is_active())
+    }
+
     pub fn update_move_fluid(
         &mut self,
         context: &UpdateContext,
         container_one: &mut impl PneumaticContainer,
         container_two: &mut impl PneumaticContainer,
     ) {
-        if !self.is_powered {
+        if !self.is_powered && !self.is_stuck() {
             self.set_open_amount_from_pressure_difference(
                 container_one.pressure() - container_two.pressure(),
             )
@@ -250,7 +266,7 @@ impl ControllablePneumaticValve for ElectroPneumaticValve {
         &mut self,
         controller: &U,
     ) {
-        if self.is_powered {
+        if self.is_powered && !self.is_stuck() {
             if let Some(signal) = controller.signal() {
                 self.open_amount = signal.target_open_amount();
             }
@@ -259,6 +275,10 @@ impl ControllablePneumaticValve for ElectroPneumaticValve {
 }
 impl SimulationElement for ElectroPneumaticValve {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        if let Some(failure) = &mut self.stuck_failure {
+            failure.accept(visitor);
+        }
+
         visitor.visit(self);
     }
 
@@ -534,6 +554,8 @@ pub struct PneumaticExhaust {
 
     pressure_preload: Pressure,
     nominal_preload: Pressure,
+
+    leak_failure: Option<Failure>,
 }
 impl PneumaticExhaust {
     const HEAT_TRANSFER_SPEED: f64 = 2.5;
@@ -551,15 +573,27 @@ impl PneumaticExhaust {
             fluid_flow: MassRate::default(),
             pressure_preload,
             nominal_preload: pressure_preload,
+            leak_failure: None,
         }
     }
 
+    /// The exhaust leaks for as long as the given failure is active.
+    pub fn with_leak_failure(mut self, failure_type: FailureType) -> Self {
+        self.leak_failure = Some(Failure::new(failure_type));
+        self
+    }
+
     pub fn update_move_fluid(
         &mut self,
         context: &UpdateContext,
         from: &mut impl PneumaticContainer,
     ) {
-        let mass_flow = if from.pressure() > self.pressure_preload {
+        if let Some(is_leaking) = self.leak_failure.map(|failure| failure.is_active()) {
+            self.set_leaking(is_leaking);
+        }
+
+        // A closed exhaust (e.g. a duct which only leaks when failed) neither vents nor cools.
+        let mass_flow = if self.exhaust_speed > 0. && from.pressure() > self.pressure_preload {
             Self::heat_conduction(context, from);
             from.get_mass_flow_for_target_pressure(
                 context.ambient_pressure(),
@@ -604,6 +638,15 @@ impl PneumaticExhaust {
         }
     }
 }
+impl SimulationElement for PneumaticExhaust {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        if let Some(failure) = &mut self.leak_failure {
+            failure.accept(visitor);
+        }
+
+        visitor.visit(self);
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -864,6 +907,33 @@ mod tests {
         assert_eq!(valve.open_amount(), Ratio::new::<percent>(0.));
     }
 
+    #[test]
+    fn stuck_electropneumatic_valve_keeps_its_open_amount() {
+        let mut container_one = quick_container(1., 14., 15.);
+        let mut container_two = quick_container(1., 14., 15.);
+
+        let controller = TestValveController::new(Ratio::new::<percent>(100.));
+
+        let mut valve = ElectroPneumaticValve::new(ElectricalBusType::DirectCurrent(2))
+            .with_stuck_failure(FailureType::BleedValveStuck(1));
+        valve.is_powered = true;
+        valve.open_amount = Ratio::new::<ratio>(0.5);
+        valve
+            .stuck_failure
+            .as_mut()
+            .unwrap()
+            .receive_failure(FailureType::BleedValveStuck(1), true);
+
+        valve.update_open_amount(&controller);
+        assert_eq!(valve.open_amount(), Ratio::new::<ratio>(0.5));
+
+        valve.is_powered = false;
+        let context = context(Duration::from_secs(1), Length::new::<foot>(0.));
+        valve.update_move_fluid(&context, &mut container_one, &mut container_two);
+
+        assert_eq!(valve.open_amount(), Ratio::new::<ratio>(0.5));
+    }
+
     #[test]
     fn pneumatic_valve_falls_closed_without_pressure() {
         let mut container_one = quick_container(1., 14., 15.);
@@ -950,6 +1020,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn preloaded_exhaust_with_leak_failure_vents_below_preload() {
+        let mut container = quick_container(1., 60., 15.);
+        let mut exhaust = PneumaticExhaust::new(0., 1., Pressure::new::<psi>(70.))
+            .with_leak_failure(FailureType::BleedLeak(1));
+
+        let context = context(Duration::from_millis(16), Length::new::<foot>(0.));
+
+        exhaust.update_move_fluid(&context, &mut container);
+        assert!(exhaust.fluid_flow().get::<kilogram_per_second>() == 0.);
+
+        exhaust
+            .leak_failure
+            .as_mut()
+            .unwrap()
+            .receive_failure(FailureType::BleedLeak(1), true);
+        exhaust.update_move_fluid(&context, &mut container);
+
+        assert!(exhaust.fluid_flow().get::<kilogram_per_second>() > 0.);
+        assert!(container.pressure() < Pressure::new::<psi>(60.));
+    }
+
     #[test]
     fn preloaded_exhaust_makes_pressure_go_to_preload() {
         let mut container = quick_container(1., 20., 15.);
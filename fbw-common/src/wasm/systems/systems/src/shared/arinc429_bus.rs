@@ -0,0 +1,816 @@
+//! ARINC 429 transport layer between a transmitting system and its receivers.
+//!
+//! A transmitter owns a set of labels, each with its own encoding and transmit interval. Every
+//! tick it emits the 32 bit words of the labels which are due. Labels without a value, BCD labels
+//! in failure warning and all labels of a transmitter which is switched off are not transmitted.
+//!
+//! A receiver monitors a set of labels and decodes the words it receives. Words with a parity
+//! error are discarded. A label which hasn't been received within its timeout is stale, which is
+//! how real equipment detects a dead transmitter: it simply stops hearing from it.
+//!
+//! The word layout follows ARINC 429: the label occupies bits 1-8 with the most significant bit
+//! first, followed by the SDI in bits 9-10, data in bits 11-29, the SSM in bits 30-31 and odd
+//! parity in bit 32.
+
+use crate::{
+    failures::{Failure, FailureType},
+    shared::arinc429::{Arinc429Word, SignStatus},
+    simulation::{SimulationElement, SimulationElementVisitor, UpdateContext},
+};
+use std::time::Duration;
+
+const PARITY_BIT: u32 = 1 << 31;
+const DATA_BITS: u32 = 19;
+const DATA_MASK: u32 = (1 << DATA_BITS) - 1;
+const MAX_BNR_SIGNIFICANT_BITS: u8 = 18;
+const MAX_BCD_DIGITS: u8 = 5;
+
+/// A label is considered stale when it wasn't received for this many of its transmit intervals.
+const STALE_AFTER_TRANSMIT_INTERVALS: u32 = 3;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Arinc429Encoding {
+    /// Two's complement binary with the sign in bit 29 and the given number of significant bits
+    /// below it. The range is the (exclusive) largest magnitude which can be represented.
+    Bnr { significant_bits: u8, range: f64 },
+    /// Binary coded decimal, the most significant digit occupies bits 27-29 and can thus not
+    /// exceed 7. The sign is encoded in the SSM.
+    Bcd { digits: u8, resolution: f64 },
+    /// The data bits are transmitted as is.
+    Discrete,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Arinc429Label {
+    number: u8,
+    encoding: Arinc429Encoding,
+    transmit_interval: Duration,
+}
+impl Arinc429Label {
+    /// Creates a BNR label. The number is the label's octal number, e.g. `0o206`.
+    pub const fn bnr(
+        number: u8,
+        significant_bits: u8,
+        range: f64,
+        transmit_interval: Duration,
+    ) -> Self {
+        assert!(significant_bits > 0 && significant_bits <= MAX_BNR_SIGNIFICANT_BITS);
+
+        Self {
+            number,
+            encoding: Arinc429Encoding::Bnr {
+                significant_bits,
+                range,
+            },
+            transmit_interval,
+        }
+    }
+
+    pub const fn bcd(number: u8, digits: u8, resolution: f64, transmit_interval: Duration) -> Self {
+        assert!(digits > 0 && digits <= MAX_BCD_DIGITS);
+
+        Self {
+            number,
+            encoding: Arinc429Encoding::Bcd { digits, resolution },
+            transmit_interval,
+        }
+    }
+
+    pub const fn discrete(number: u8, transmit_interval: Duration) -> Self {
+        Self {
+            number,
+            encoding: Arinc429Encoding::Discrete,
+            transmit_interval,
+        }
+    }
+
+    pub fn number(&self) -> u8 {
+        self.number
+    }
+
+    pub fn encoding(&self) -> Arinc429Encoding {
+        self.encoding
+    }
+
+    pub fn transmit_interval(&self) -> Duration {
+        self.transmit_interval
+    }
+
+    /// The value of the least significant data bit.
+    pub fn resolution(&self) -> f64 {
+        match self.encoding {
+            Arinc429Encoding::Bnr {
+                significant_bits,
+                range,
+            } => range / (1u32 << significant_bits) as f64,
+            Arinc429Encoding::Bcd { resolution, .. } => resolution,
+            Arinc429Encoding::Discrete => 1.,
+        }
+    }
+
+    /// Encodes the word into its 32 bit representation, including parity. Returns `None` when
+    /// the word cannot be transmitted, which is the case for BCD words in failure warning.
+    pub fn encode(&self, sdi: u8, word: Arinc429Word<f64>) -> Option<u32> {
+        let (data, ssm) = match self.encoding {
+            Arinc429Encoding::Bnr {
+                significant_bits, ..
+            } => (
+                self.encode_bnr(significant_bits, word.value()),
+                u64::from(word.ssm()) as u32,
+            ),
+            Arinc429Encoding::Bcd { digits, resolution } => {
+                let ssm = match word.ssm() {
+                    SignStatus::NormalOperation if word.value() < 0. => 0b11,
+                    SignStatus::NormalOperation => 0b00,
+                    SignStatus::NoComputedData => 0b01,
+                    SignStatus::FunctionalTest => 0b10,
+                    SignStatus::FailureWarning => return None,
+                };
+
+                (Self::encode_bcd(digits, resolution, word.value()), ssm)
+            }
+            Arinc429Encoding::Discrete => (
+                word.value() as u32 & DATA_MASK,
+                match word.ssm() {
+                    SignStatus::NormalOperation => 0b00,
+                    SignStatus::NoComputedData => 0b01,
+                    SignStatus::FunctionalTest => 0b10,
+                    SignStatus::FailureWarning => 0b11,
+                },
+            ),
+        };
+
+        let raw =
+            self.number.reverse_bits() as u32 | (sdi as u32 & 0b11) << 8 | data << 10 | ssm << 29;
+
+        Some(with_odd_parity(raw))
+    }
+
+    /// Decodes the data and SSM of a word carrying this label. The parity isn't checked.
+    pub fn decode(&self, raw: u32) -> Arinc429Word<f64> {
+        debug_assert!(label_of(raw) == self.number);
+
+        let data = (raw >> 10) & DATA_MASK;
+        let ssm = (raw >> 29) & 0b11;
+
+        match self.encoding {
+            Arinc429Encoding::Bnr {
+                significant_bits, ..
+            } => Arinc429Word::new(self.decode_bnr(significant_bits, data), ssm.into()),
+            Arinc429Encoding::Bcd { digits, resolution } => {
+                let magnitude = Self::decode_bcd(digits, data) as f64 * resolution;
+                match ssm {
+                    0b00 => Arinc429Word::new(magnitude, SignStatus::NormalOperation),
+                    0b11 => Arinc429Word::new(-magnitude, SignStatus::NormalOperation),
+                    0b01 => Arinc429Word::new(magnitude, SignStatus::NoComputedData),
+                    _ => Arinc429Word::new(magnitude, SignStatus::FunctionalTest),
+                }
+            }
+            Arinc429Encoding::Discrete => Arinc429Word::new(
+                data as f64,
+                match ssm {
+                    0b00 => SignStatus::NormalOperation,
+                    0b01 => SignStatus::NoComputedData,
+                    0b10 => SignStatus::FunctionalTest,
+                    _ => SignStatus::FailureWarning,
+                },
+            ),
+        }
+    }
+
+    fn encode_bnr(&self, significant_bits: u8, value: f64) -> u32 {
+        let max_count = (1i64 << significant_bits) - 1;
+        let count = ((value / self.resolution()).round() as i64).clamp(-max_count - 1, max_count);
+        let field_mask = (1u32 << (significant_bits + 1)) - 1;
+
+        ((count as u32) & field_mask) << (MAX_BNR_SIGNIFICANT_BITS - significant_bits)
+    }
+
+    fn decode_bnr(&self, significant_bits: u8, data: u32) -> f64 {
+        let field = data >> (MAX_BNR_SIGNIFICANT_BITS - significant_bits);
+        let unused_bits = 31 - significant_bits as u32;
+        let count = ((field << unused_bits) as i32) >> unused_bits;
+
+        count as f64 * self.resolution()
+    }
+
+    fn encode_bcd(digits: u8, resolution: f64, value: f64) -> u32 {
+        let max_value = Self::max_bcd_value(digits);
+        let mut remainder = ((value.abs() / resolution).round() as u32).min(max_value);
+
+        let mut data = 0;
+        for digit in 0..digits as u32 {
+            data |= (remainder % 10) << Self::bcd_digit_shift(digits, digit);
+            remainder /= 10;
+        }
+
+        data
+    }
+
+    fn decode_bcd(digits: u8, data: u32) -> u32 {
+        (0..digits as u32).rev().fold(0, |value, digit| {
+            value * 10 + ((data >> Self::bcd_digit_shift(digits, digit)) & 0xf).min(9)
+        })
+    }
+
+    /// The shift of the given digit within the data field, where digit 0 is the least
+    /// significant digit. The most significant digit is aligned with bit 29.
+    fn bcd_digit_shift(digits: u8, digit: u32) -> u32 {
+        DATA_BITS - 3 - (digits as u32 - 1 - digit) * 4
+    }
+
+    fn max_bcd_value(digits: u8) -> u32 {
+        (0..digits as u32 - 1).fold(7, |value, _| value * 10 + 9)
+    }
+}
+
+/// Returns the octal label number of the word.
+pub fn label_of(raw: u32) -> u8 {
+    (raw as u8).reverse_bits()
+}
+
+/// Returns the source/destination identifier of the word.
+pub fn sdi_of(raw: u32) -> u8 {
+    ((raw >> 8) & 0b11) as u8
+}
+
+pub fn has_valid_parity(raw: u32) -> bool {
+    raw.count_ones() & 1 == 1
+}
+
+fn with_odd_parity(raw: u32) -> u32 {
+    let raw = raw & !PARITY_BIT;
+    if raw.count_ones() & 1 == 0 {
+        raw | PARITY_BIT
+    } else {
+        raw
+    }
+}
+
+struct TransmittedLabel {
+    label: Arinc429Label,
+    word: Option<Arinc429Word<f64>>,
+    time_since_transmission: Duration,
+}
+impl TransmittedLabel {
+    fn new(label: Arinc429Label) -> Self {
+        Self {
+            label,
+            word: None,
+            time_since_transmission: label.transmit_interval(),
+        }
+    }
+
+    /// Returns true when the label is due for transmission.
+    fn update(&mut self, delta: Duration) -> bool {
+        self.time_since_transmission += delta;
+
+        let interval = self.label.transmit_interval();
+        if self.time_since_transmission >= interval {
+            // Only a single word is transmitted per tick, no matter how many intervals passed.
+            self.time_since_transmission = Duration::from_secs_f64(
+                self.time_since_transmission.as_secs_f64() % interval.as_secs_f64(),
+            );
+
+            true
+        } else {
+            false
+        }
+    }
+}
+
+pub struct Arinc429Transmitter {
+    sdi: u8,
+    labels: Vec<TransmittedLabel>,
+    is_transmitting: bool,
+    transmitted_words: Vec<u32>,
+    parity_error: Option<Failure>,
+}
+impl Arinc429Transmitter {
+    pub fn new(labels: &[Arinc429Label]) -> Self {
+        Self {
+            sdi: 0,
+            labels: labels.iter().copied().map(TransmittedLabel::new).collect(),
+            is_transmitting: false,
+            transmitted_words: Vec::with_capacity(labels.len()),
+            parity_error: None,
+        }
+    }
+
+    pub fn with_sdi(mut self, sdi: u8) -> Self {
+        self.sdi = sdi;
+        self
+    }
+
+    /// When the given failure is active, all transmitted words have an invalid parity.
+    pub fn with_parity_error_failure(mut self, failure_type: FailureType) -> Self {
+        self.parity_error = Some(Failure::new(failure_type));
+        self
+    }
+
+    /// Sets the word which is transmitted for the given label from now on.
+    pub fn set(&mut self, label_number: u8, word: Arinc429Word<f64>) {
+        self.label_mut(label_number).word = Some(word);
+    }
+
+    /// Stops the transmission of the given label until a new word is set.
+    pub fn set_no_transmission(&mut self, label_number: u8) {
+        self.label_mut(label_number).word = None;
+    }
+
+    pub fn update(&mut self, context: &UpdateContext, is_transmitting: bool) {
+        self.is_transmitting = is_transmitting;
+        self.transmitted_words.clear();
+
+        if !is_transmitting {
+            self.labels
+                .iter_mut()
+                .for_each(|label| label.time_since_transmission = label.label.transmit_interval());
+            return;
+        }
+
+        let parity_error = self.parity_error.as_ref().is_some_and(|f| f.is_active());
+        for label in &mut self.labels {
+            if !label.update(context.delta()) {
+                continue;
+            }
+
+            if let Some(raw) = label
+                .word
+                .and_then(|word| label.label.encode(self.sdi, word))
+            {
+                self.transmitted_words
+                    .push(if parity_error { raw ^ PARITY_BIT } else { raw });
+            }
+        }
+    }
+
+    pub fn is_transmitting(&self) -> bool {
+        self.is_transmitting
+    }
+
+    /// The words transmitted during the last update, in order of transmission.
+    pub fn transmitted_words(&self) -> &[u32] {
+        &self.transmitted_words
+    }
+
+    fn label_mut(&mut self, label_number: u8) -> &mut TransmittedLabel {
+        self.labels
+            .iter_mut()
+            .find(|label| label.label.number() == label_number)
+            .unwrap_or_else(|| panic!("Label {:o} is not transmitted on this bus.", label_number))
+    }
+}
+impl SimulationElement for Arinc429Transmitter {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        if let Some(parity_error) = &mut self.parity_error {
+            parity_error.accept(visitor);
+        }
+
+        visitor.visit(self);
+    }
+}
+
+struct ReceivedLabel {
+    label: Arinc429Label,
+    timeout: Duration,
+    word: Option<Arinc429Word<f64>>,
+    time_since_reception: Duration,
+}
+impl ReceivedLabel {
+    fn new(label: Arinc429Label) -> Self {
+        Self {
+            label,
+            timeout: label.transmit_interval() * STALE_AFTER_TRANSMIT_INTERVALS,
+            word: None,
+            time_since_reception: Duration::ZERO,
+        }
+    }
+
+    fn is_stale(&self) -> bool {
+        self.word.is_none() || self.time_since_reception > self.timeout
+    }
+}
+
+pub struct Arinc429Receiver {
+    labels: Vec<ReceivedLabel>,
+    parity_errors: u64,
+}
+impl Arinc429Receiver {
+    /// Creates a receiver for the given labels. A label becomes stale when it wasn't received
+    /// for three of its transmit intervals.
+    pub fn new(labels: &[Arinc429Label]) -> Self {
+        Self {
+            labels: labels.iter().copied().map(ReceivedLabel::new).collect(),
+            parity_errors: 0,
+        }
+    }
+
+    pub fn with_timeout(mut self, label_number: u8, timeout: Duration) -> Self {
+        self.label_mut(label_number).timeout = timeout;
+        self
+    }
+
+    pub fn update(&mut self, context: &UpdateContext, transmitter: &Arinc429Transmitter) {
+        self.labels
+            .iter_mut()
+            .for_each(|label| label.time_since_reception += context.delta());
+
+        for &raw in transmitter.transmitted_words() {
+            if !has_valid_parity(raw) {
+                self.parity_errors += 1;
+                continue;
+            }
+
+            let number = label_of(raw);
+            if let Some(label) = self
+                .labels
+                .iter_mut()
+                .find(|label| label.label.number() == number)
+            {
+                label.word = Some(label.label.decode(raw));
+                label.time_since_reception = Duration::ZERO;
+            }
+        }
+    }
+
+    /// Returns the last received word of the label, or `None` when the label is stale.
+    pub fn word(&self, label_number: u8) -> Option<Arinc429Word<f64>> {
+        let label = self.label(label_number);
+        if label.is_stale() {
+            None
+        } else {
+            label.word
+        }
+    }
+
+    pub fn is_stale(&self, label_number: u8) -> bool {
+        self.label(label_number).is_stale()
+    }
+
+    /// Indicates that none of the monitored labels is received, i.e. the transmitter is dead.
+    pub fn all_stale(&self) -> bool {
+        self.labels.iter().all(|label| label.is_stale())
+    }
+
+    pub fn parity_errors(&self) -> u64 {
+        self.parity_errors
+    }
+
+    fn label(&self, label_number: u8) -> &ReceivedLabel {
+        self.labels
+            .iter()
+            .find(|label| label.label.number() == label_number)
+            .unwrap_or_else(|| panic!("Label {:o} is not received.", label_number))
+    }
+
+    fn label_mut(&mut self, label_number: u8) -> &mut ReceivedLabel {
+        self.labels
+            .iter_mut()
+            .find(|label| label.label.number() == label_number)
+            .unwrap_or_else(|| panic!("Label {:o} is not received.", label_number))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::{
+        test::{SimulationTestBed, TestBed},
+        Aircraft, InitContext,
+    };
+    use ntest::assert_about_eq;
+
+    const ALTITUDE: Arinc429Label =
+        Arinc429Label::bnr(0o203, 17, 131072., Duration::from_millis(62));
+    const BARO_CORRECTION: Arinc429Label =
+        Arinc429Label::bcd(0o234, 5, 0.1, Duration::from_millis(250));
+    const DISCRETE_WORD: Arinc429Label = Arinc429Label::discrete(0o270, Duration::from_secs(1));
+
+    fn normal(value: f64) -> Arinc429Word<f64> {
+        Arinc429Word::new(value, SignStatus::NormalOperation)
+    }
+
+    #[test]
+    fn label_is_transmitted_most_significant_bit_first() {
+        let raw = ALTITUDE.encode(0, normal(0.)).unwrap();
+
+        // 203 octal is 10 000 011, transmitted as 11 000 001 in bits 1-8
+        assert_eq!(raw & 0xff, 0b1100_0001);
+        assert_eq!(label_of(raw), 0o203);
+    }
+
+    #[test]
+    fn sdi_is_encoded_in_bits_9_and_10() {
+        let raw = ALTITUDE.encode(2, normal(0.)).unwrap();
+
+        assert_eq!(sdi_of(raw), 2);
+        assert_eq!((raw >> 8) & 0b11, 0b10);
+    }
+
+    #[test]
+    fn words_have_odd_parity() {
+        for value in [0., 1., 100., -2500.5, 39000.] {
+            assert!(has_valid_parity(ALTITUDE.encode(1, normal(value)).unwrap()));
+        }
+    }
+
+    #[test]
+    fn bnr_resolution_follows_from_range_and_significant_bits() {
+        assert_about_eq!(ALTITUDE.resolution(), 1.);
+        assert_about_eq!(
+            Arinc429Label::bnr(0o310, 18, 180., Duration::from_millis(200)).resolution(),
+            180. / 262144.
+        );
+    }
+
+    #[test]
+    fn bnr_round_trips_within_resolution() {
+        for value in [0., 1., 15000., -1500., 131071.] {
+            let word = ALTITUDE.decode(ALTITUDE.encode(0, normal(value)).unwrap());
+
+            assert!(word.is_normal_operation());
+            assert_about_eq!(word.value(), value);
+        }
+
+        let word = ALTITUDE.decode(ALTITUDE.encode(0, normal(15000.3)).unwrap());
+        assert_about_eq!(word.value(), 15000.);
+    }
+
+    #[test]
+    fn bnr_sign_is_in_bit_29() {
+        let raw = ALTITUDE.encode(0, normal(-1.)).unwrap();
+
+        assert_eq!((raw >> 28) & 1, 1);
+    }
+
+    #[test]
+    fn bnr_saturates_at_its_range() {
+        let word = ALTITUDE.decode(ALTITUDE.encode(0, normal(200000.)).unwrap());
+        assert_about_eq!(word.value(), 131071.);
+
+        let word = ALTITUDE.decode(ALTITUDE.encode(0, normal(-200000.)).unwrap());
+        assert_about_eq!(word.value(), -131072.);
+    }
+
+    #[test]
+    fn bnr_ssm_is_preserved() {
+        for ssm in [
+            SignStatus::FailureWarning,
+            SignStatus::NoComputedData,
+            SignStatus::FunctionalTest,
+            SignStatus::NormalOperation,
+        ] {
+            let word = ALTITUDE.decode(ALTITUDE.encode(0, Arinc429Word::new(10., ssm)).unwrap());
+
+            assert_eq!(word.ssm(), ssm);
+        }
+    }
+
+    #[test]
+    fn bcd_digits_are_encoded_from_bit_29_downwards() {
+        let raw = BARO_CORRECTION.encode(0, normal(1013.2)).unwrap();
+
+        let data = (raw >> 10) & DATA_MASK;
+        assert_eq!(data >> 16, 1);
+        assert_eq!((data >> 12) & 0xf, 0);
+        assert_eq!((data >> 8) & 0xf, 1);
+        assert_eq!((data >> 4) & 0xf, 3);
+        assert_eq!(data & 0xf, 2);
+    }
+
+    #[test]
+    fn bcd_round_trips_within_resolution() {
+        let word = BARO_CORRECTION.decode(BARO_CORRECTION.encode(0, normal(1013.25)).unwrap());
+
+        assert!(word.is_normal_operation());
+        assert_about_eq!(word.value(), 1013.3, 1e-9);
+    }
+
+    #[test]
+    fn bcd_sign_is_encoded_in_the_ssm() {
+        let raw = BARO_CORRECTION.encode(0, normal(-12.5)).unwrap();
+        assert_eq!(raw >> 29 & 0b11, 0b11);
+
+        let word = BARO_CORRECTION.decode(raw);
+        assert!(word.is_normal_operation());
+        assert_about_eq!(word.value(), -12.5, 1e-9);
+    }
+
+    #[test]
+    fn bcd_failure_warning_is_not_transmitted() {
+        assert!(BARO_CORRECTION
+            .encode(0, Arinc429Word::new(1013., SignStatus::FailureWarning))
+            .is_none());
+    }
+
+    #[test]
+    fn bcd_saturates_at_the_largest_representable_value() {
+        let word = BARO_CORRECTION.decode(BARO_CORRECTION.encode(0, normal(9999.)).unwrap());
+
+        assert_about_eq!(word.value(), 7999.9, 1e-9);
+    }
+
+    #[test]
+    fn discrete_data_bits_are_transmitted_as_is() {
+        let raw = DISCRETE_WORD
+            .encode(0, normal(0b101_0000_0000_0000_0011 as f64))
+            .unwrap();
+        let word = DISCRETE_WORD.decode(raw);
+
+        assert!(word.is_normal_operation());
+        assert_eq!(word.value() as u32, 0b101_0000_0000_0000_0011);
+        assert_eq!(
+            DISCRETE_WORD
+                .decode(
+                    DISCRETE_WORD
+                        .encode(0, Arinc429Word::new(0., SignStatus::FailureWarning))
+                        .unwrap()
+                )
+                .ssm(),
+            SignStatus::FailureWarning
+        );
+    }
+
+    struct BusTestAircraft {
+        transmitter: Arinc429Transmitter,
+        receiver: Arinc429Receiver,
+        is_transmitting: bool,
+        altitude_words_transmitted: usize,
+    }
+    impl BusTestAircraft {
+        fn new(_: &mut InitContext) -> Self {
+            let labels = [ALTITUDE, BARO_CORRECTION, DISCRETE_WORD];
+            Self {
+                transmitter: Arinc429Transmitter::new(&labels)
+                    .with_sdi(1)
+                    .with_parity_error_failure(FailureType::AdiruBusParityError(1)),
+                receiver: Arinc429Receiver::new(&labels),
+                is_transmitting: true,
+                altitude_words_transmitted: 0,
+            }
+        }
+
+        fn set(&mut self, label_number: u8, word: Arinc429Word<f64>) {
+            self.transmitter.set(label_number, word);
+        }
+    }
+    impl Aircraft for BusTestAircraft {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.transmitter.update(context, self.is_transmitting);
+            self.altitude_words_transmitted += self
+                .transmitter
+                .transmitted_words()
+                .iter()
+                .filter(|&&raw| label_of(raw) == ALTITUDE.number())
+                .count();
+            self.receiver.update(context, &self.transmitter);
+        }
+    }
+    impl SimulationElement for BusTestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.transmitter.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed() -> SimulationTestBed<BusTestAircraft> {
+        let mut test_bed = SimulationTestBed::new(BusTestAircraft::new);
+        test_bed.command(|a| {
+            a.set(0o203, normal(15000.));
+            a.set(0o234, normal(1013.));
+            a.set(0o270, normal(5.));
+        });
+
+        test_bed
+    }
+
+    #[test]
+    fn receiver_decodes_transmitted_labels() {
+        let mut test_bed = test_bed();
+        test_bed.run_with_delta(Duration::from_millis(50));
+
+        test_bed.query(|a| {
+            assert_about_eq!(a.receiver.word(0o203).unwrap().value(), 15000.);
+            assert_about_eq!(a.receiver.word(0o234).unwrap().value(), 1013., 1e-9);
+            assert_about_eq!(a.receiver.word(0o270).unwrap().value(), 5.);
+        });
+    }
+
+    #[test]
+    fn labels_are_transmitted_at_their_interval() {
+        let mut test_bed = test_bed();
+
+        for _ in 0..100 {
+            test_bed.run_with_delta(Duration::from_millis(10));
+        }
+
+        // 1 s at a 62 ms interval, plus the initial transmission
+        let transmitted = test_bed.query(|a| a.altitude_words_transmitted);
+        assert!(
+            (16..=17).contains(&transmitted),
+            "{} words transmitted",
+            transmitted
+        );
+    }
+
+    #[test]
+    fn label_without_value_is_not_transmitted() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.transmitter.set_no_transmission(0o203));
+        test_bed.run_with_delta(Duration::from_millis(50));
+
+        test_bed.query(|a| {
+            assert!(a.receiver.is_stale(0o203));
+            assert!(a.receiver.word(0o203).is_none());
+            assert!(!a.receiver.is_stale(0o234));
+        });
+    }
+
+    #[test]
+    fn labels_become_stale_when_the_transmitter_stops() {
+        let mut test_bed = test_bed();
+        test_bed.run_with_delta(Duration::from_millis(50));
+        test_bed.command(|a| a.is_transmitting = false);
+
+        test_bed.run_with_delta(Duration::from_millis(200));
+        test_bed.query(|a| {
+            assert!(a.receiver.is_stale(0o203));
+            assert!(!a.receiver.is_stale(0o234));
+            assert!(!a.receiver.all_stale());
+        });
+
+        test_bed.run_with_delta(Duration::from_secs(3));
+        test_bed.query(|a| assert!(a.receiver.all_stale()));
+    }
+
+    #[test]
+    fn stale_labels_recover_when_the_transmitter_resumes() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.is_transmitting = false);
+        test_bed.run_with_delta(Duration::from_secs(5));
+        test_bed.query(|a| assert!(a.receiver.all_stale()));
+
+        test_bed.command(|a| a.is_transmitting = true);
+        test_bed.run_with_delta(Duration::from_millis(20));
+
+        test_bed.query(|a| {
+            assert!(!a.receiver.is_stale(0o203));
+            assert!(!a.receiver.is_stale(0o270));
+        });
+    }
+
+    #[test]
+    fn bcd_label_in_failure_warning_becomes_stale() {
+        let mut test_bed = test_bed();
+        test_bed.run_with_delta(Duration::from_millis(50));
+        test_bed.command(|a| a.set(0o234, Arinc429Word::new(0., SignStatus::FailureWarning)));
+
+        test_bed.run_with_delta(Duration::from_millis(800));
+
+        test_bed.query(|a| assert!(a.receiver.is_stale(0o234)));
+    }
+
+    #[test]
+    fn custom_timeout_delays_staleness() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| {
+            a.receiver =
+                Arinc429Receiver::new(&[ALTITUDE]).with_timeout(0o203, Duration::from_secs(2))
+        });
+        test_bed.run_with_delta(Duration::from_millis(50));
+        test_bed.command(|a| a.is_transmitting = false);
+
+        test_bed.run_with_delta(Duration::from_secs(1));
+        test_bed.query(|a| assert!(!a.receiver.is_stale(0o203)));
+
+        test_bed.run_with_delta(Duration::from_millis(1500));
+        test_bed.query(|a| assert!(a.receiver.is_stale(0o203)));
+    }
+
+    #[test]
+    fn parity_error_failure_corrupts_all_words() {
+        let mut test_bed = test_bed();
+        test_bed.fail(FailureType::AdiruBusParityError(1));
+
+        test_bed.run_with_delta(Duration::from_millis(50));
+
+        test_bed.query(|a| {
+            assert!(a.receiver.all_stale());
+            assert_eq!(a.receiver.parity_errors(), 3);
+        });
+    }
+
+    #[test]
+    fn words_are_received_again_once_parity_error_clears() {
+        let mut test_bed = test_bed();
+        test_bed.fail(FailureType::AdiruBusParityError(1));
+        test_bed.run_with_delta(Duration::from_millis(50));
+
+        test_bed.unfail(FailureType::AdiruBusParityError(1));
+        test_bed.run_with_delta(Duration::from_millis(1000));
+
+        test_bed.query(|a| assert!(!a.receiver.all_stale()));
+    }
+}
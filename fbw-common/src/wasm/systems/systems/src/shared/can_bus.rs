@@ -1,186 +1,418 @@
+//! ARINC 825 CAN bus shared between a fixed set of systems, identified by their function id.
+//!
+//! Every tick the bus gets a budget of bits based on its bit rate and the tick's duration.
+//! Pending frames are arbitrated by their 29 bit identifier, the lowest identifier wins and is
+//! transmitted when the remaining budget covers its worst case frame length. Frames on
+//! broadcast channels are delivered to all other systems, frames on peer-to-peer channels only
+//! to the addressed server function.
+//!
+//! Each node implements the CAN fault confinement with a transmit and receive error counter.
+//! A node becomes error passive when either counter exceeds 127 and goes bus off once its
+//! transmit error counter exceeds 255. A node which is bus off recovers after it observed 128
+//! occurrences of 11 recessive bits.
+
 use crate::{
-    shared::arinc825::Arinc825Word,
+    failures::{Failure, FailureType},
+    shared::arinc825::{Arinc825Word, LogicalCommunicationChannel},
     simulation::{
-        InitContext, Read, SimulationElement, SimulatorReader, SimulatorWriter, VariableIdentifier,
-        Write,
+        InitContext, Read, Reader, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        SimulatorWriter, UpdateContext, VariableIdentifier, Write, Writer,
     },
 };
 use std::collections::VecDeque;
-use std::vec::Vec;
 
 const TRANSMISSION_BUFFER_SIZE: usize = 12;
+const RECEPTION_BUFFER_SIZE: usize = 12;
+
+const DEFAULT_BIT_RATE: u32 = 500_000;
+
+// extended frame with four data bytes: 67 bits of framing and interframe space, 32 data bits
+// and up to 21 stuff bits
+const FRAME_BITS: f64 = 120.;
+// error flag, error delimiter and interframe space
+const ERROR_FRAME_BITS: f64 = 20.;
+// an error passive transmitter has to wait for the suspend transmission time
+const SUSPEND_TRANSMISSION_BITS: f64 = 8.;
+const BUS_OFF_RECOVERY_BITS: f64 = 128. * 11.;
+
+const ERROR_PASSIVE_THRESHOLD: u16 = 128;
+const BUS_OFF_THRESHOLD: u16 = 256;
+const TRANSMIT_ERROR_INCREMENT: u16 = 8;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CanErrorState {
+    ErrorActive = 0,
+    ErrorPassive = 1,
+    BusOff = 2,
+}
+
+read_write_enum!(CanErrorState);
+
+impl From<f64> for CanErrorState {
+    fn from(value: f64) -> Self {
+        match value as u8 {
+            0 => CanErrorState::ErrorActive,
+            1 => CanErrorState::ErrorPassive,
+            2 => CanErrorState::BusOff,
+            _ => panic!("CanErrorState value does not correspond to any enum member"),
+        }
+    }
+}
+
+struct CanNode {
+    function_id: u8,
+    received_id: VariableIdentifier,
+    error_state_id: VariableIdentifier,
+
+    transmission_buffer: VecDeque<Arinc825Word<f64>>,
+    reception_buffer: VecDeque<Arinc825Word<f64>>,
+    transmit_error_counter: u16,
+    receive_error_counter: u16,
+    bus_off: bool,
+    recessive_bits_observed: f64,
+
+    fault: Failure,
+}
+impl CanNode {
+    fn new(context: &mut InitContext, bus_name: &str, bus_number: usize, function_id: u8) -> Self {
+        Self {
+            function_id,
+            received_id: context.get_identifier(format!("{}_{}_RECEIVED", bus_name, function_id)),
+            error_state_id: context
+                .get_identifier(format!("{}_{}_ERROR_STATE", bus_name, function_id)),
+            transmission_buffer: VecDeque::new(),
+            reception_buffer: VecDeque::new(),
+            transmit_error_counter: 0,
+            receive_error_counter: 0,
+            bus_off: false,
+            recessive_bits_observed: 0.,
+            fault: Failure::new(FailureType::CanBusNodeFault(bus_number, function_id)),
+        }
+    }
+
+    fn error_state(&self) -> CanErrorState {
+        if self.bus_off {
+            CanErrorState::BusOff
+        } else if self.transmit_error_counter >= ERROR_PASSIVE_THRESHOLD
+            || self.receive_error_counter >= ERROR_PASSIVE_THRESHOLD
+        {
+            CanErrorState::ErrorPassive
+        } else {
+            CanErrorState::ErrorActive
+        }
+    }
+
+    fn is_on_bus(&self) -> bool {
+        !self.bus_off
+    }
+
+    /// The frame with the lowest identifier is transmitted first. Frames sharing an
+    /// identifier keep the order in which they were queued.
+    fn next_frame(&self) -> Option<(usize, u32)> {
+        if self.bus_off {
+            return None;
+        }
+
+        self.transmission_buffer
+            .iter()
+            .enumerate()
+            .map(|(position, frame)| (position, frame_identifier(frame)))
+            .min_by_key(|(position, identifier)| (*identifier, *position))
+    }
+
+    fn transmission_succeeded(&mut self) {
+        self.transmit_error_counter = self.transmit_error_counter.saturating_sub(1);
+    }
+
+    fn transmission_failed(&mut self) {
+        self.transmit_error_counter += TRANSMIT_ERROR_INCREMENT;
+        if self.transmit_error_counter >= BUS_OFF_THRESHOLD {
+            self.bus_off = true;
+            self.recessive_bits_observed = 0.;
+        }
+    }
+
+    fn receive(&mut self, frame: Arinc825Word<f64>) {
+        self.receive_error_counter = self.receive_error_counter.saturating_sub(1);
+
+        if self.reception_buffer.len() >= RECEPTION_BUFFER_SIZE {
+            // overrun, the oldest frame is overwritten
+            self.reception_buffer.pop_front();
+        }
+        self.reception_buffer.push_back(frame);
+    }
+
+    fn reception_failed(&mut self) {
+        self.receive_error_counter = (self.receive_error_counter + 1).min(BUS_OFF_THRESHOLD - 1);
+    }
+
+    fn observe_bus(&mut self, bits: f64) {
+        if self.bus_off {
+            self.recessive_bits_observed += bits;
+            if self.recessive_bits_observed >= BUS_OFF_RECOVERY_BITS {
+                self.bus_off = false;
+                self.transmit_error_counter = 0;
+                self.receive_error_counter = 0;
+            }
+        }
+    }
+}
+impl SimulationElement for CanNode {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.fault.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        // The flag is written as false while frames are waiting. A consumer outside of the
+        // simulation acknowledges the oldest frame by setting it.
+        let received: bool = reader.read(&self.received_id);
+        if received {
+            self.reception_buffer.pop_front();
+        }
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.received_id, self.reception_buffer.is_empty());
+        writer.write(&self.error_state_id, self.error_state());
+    }
+}
+
+fn frame_identifier(frame: &Arinc825Word<f64>) -> u32 {
+    frame.status() & 0x1fff_ffff
+}
+
+/// Frames on the one-to-many channels are received by every node, the others are peer-to-peer
+/// and only received by the addressed server function.
+fn is_broadcast(frame: &Arinc825Word<f64>) -> bool {
+    matches!(
+        frame.logical_communication_channel(),
+        LogicalCommunicationChannel::ExceptionEventChannel
+            | LogicalCommunicationChannel::NormalOperationChannel
+            | LogicalCommunicationChannel::CanBaseFrameMigrationChannel
+    )
+}
 
 pub struct CanBus<const N: usize> {
-    attached_systems: [u8; N],
-    next_transmitting_system: usize,
-    transmission_buffers: Vec<VecDeque<Arinc825Word<f64>>>,
-    message_received_by_systems_ids: Vec<VariableIdentifier>,
-    // first bool is the received-state and the second bool describes a dirty flag to skip read-calls, if needed
-    message_received_by_systems: Vec<[bool; 2]>,
+    nodes: [CanNode; N],
+    bit_rate: u32,
+    bit_budget: f64,
     availability_id: VariableIdentifier,
-    available: bool,
     failure_indication_id: VariableIdentifier,
     failure_indication: bool,
+    disturbance: Failure,
     databus_id: VariableIdentifier,
-    received_message: Arinc825Word<f64>,
-    next_output_message: Arinc825Word<f64>,
-    next_output_message_valid: bool,
+    last_transmitted_message: Option<Arinc825Word<f64>>,
+    transmitted_frames: usize,
 }
 
 impl<const N: usize> CanBus<N> {
-    pub fn new(context: &mut InitContext, bus_name: &str, systems: [u8; N]) -> Self {
+    pub fn new(
+        context: &mut InitContext,
+        bus_name: &str,
+        bus_number: usize,
+        systems: [u8; N],
+    ) -> Self {
         Self {
-            attached_systems: systems,
-            next_transmitting_system: 0,
-            transmission_buffers: (1..=N).map(|_| VecDeque::new()).collect(),
-            message_received_by_systems_ids: (0..=N - 1)
-                .map(|id| context.get_identifier(format!("{}_{}_RECEIVED", bus_name, systems[id])))
-                .collect(),
-            message_received_by_systems: (1..=N).map(|_| [true, true]).collect(),
+            nodes: systems
+                .map(|function_id| CanNode::new(context, bus_name, bus_number, function_id)),
+            bit_rate: DEFAULT_BIT_RATE,
+            bit_budget: 0.,
             availability_id: context.get_identifier(format!("{}_AVAIL", bus_name)),
-            available: false,
             failure_indication_id: context.get_identifier(format!("{}_FAILURE", bus_name)),
             failure_indication: false,
+            disturbance: Failure::new(FailureType::CanBusDisturbance(bus_number)),
             databus_id: context.get_identifier(bus_name.to_owned()),
-            received_message: Arinc825Word::new_with_status(0.0, 0x04000000),
-            next_output_message: Arinc825Word::new_with_status(0.0, 0x04000000),
-            next_output_message_valid: true,
+            last_transmitted_message: None,
+            transmitted_frames: 0,
         }
     }
 
-    pub fn update(&mut self) {
-        self.next_output_message_valid = false;
+    /// Sets the bit rate in bits per second, ARINC 825 buses commonly use 83.3, 125, 250, 500
+    /// or 1000 kbit/s.
+    pub fn with_bit_rate(mut self, bit_rate: u32) -> Self {
+        self.bit_rate = bit_rate;
+        self
+    }
+
+    pub fn update(&mut self, context: &UpdateContext) {
+        self.transmitted_frames = 0;
+        if self.failure_indication {
+            self.bit_budget = 0.;
+            return;
+        }
 
-        self.message_received_by_systems
+        let tick_bits = self.bit_rate as f64 * context.delta_as_secs_f64();
+        self.nodes
             .iter_mut()
-            .for_each(|received| received[1] = false);
-
-        if self.available && !self.failure_indication {
-            let mut bus_busy = false;
-
-            // check if all stations received the last message
-            self.message_received_by_systems
-                .iter()
-                .for_each(|received| {
-                    if !received[0] {
-                        bus_busy = true;
-                    }
-                });
-
-            if !bus_busy {
-                // search the next sendable message
-                for x in 0..N {
-                    let idx = (self.next_transmitting_system + x) % N;
-                    let message = self.transmission_buffers[idx].get(0);
-                    if message.is_some() {
-                        // reset the received flags to release the bus for the next transmission
-                        self.message_received_by_systems
-                            .iter_mut()
-                            .for_each(|received| *received = [false, true]);
-                        self.message_received_by_systems[idx][0] = true;
-
-                        self.next_output_message =
-                            self.transmission_buffers[idx].pop_front().unwrap();
-                        self.next_output_message_valid = true;
-
-                        break;
-                    }
-                }
+            .for_each(|node| node.observe_bus(tick_bits));
+
+        self.bit_budget += tick_bits;
+        while let Some((node_index, position)) = self.arbitrate() {
+            let error_passive = self.nodes[node_index].error_state() == CanErrorState::ErrorPassive;
+            let frame_bits = FRAME_BITS
+                + if error_passive {
+                    SUSPEND_TRANSMISSION_BITS
+                } else {
+                    0.
+                };
+            if self.bit_budget < frame_bits {
+                break;
+            }
 
-                self.next_transmitting_system = (self.next_transmitting_system + 1) % N;
+            if self.disturbance.is_active() || self.nodes[node_index].fault.is_active() {
+                self.bit_budget -= frame_bits + ERROR_FRAME_BITS;
+                self.transmission_failed(node_index);
+            } else {
+                self.bit_budget -= frame_bits;
+                self.transmit(node_index, position);
             }
-        } else {
-            self.message_received_by_systems
-                .iter_mut()
-                .for_each(|received| *received = [true, true]);
+        }
+
+        // an idle bus cannot save up bits for later
+        if self.arbitrate().is_none() {
+            self.bit_budget = 0.;
         }
     }
 
-    pub fn new_message_received(&self, function_id: u8) -> bool {
-        for (i, id) in self.attached_systems.iter().enumerate() {
-            if *id == function_id {
-                return !self.message_received_by_systems[i][0];
+    fn arbitrate(&self) -> Option<(usize, usize)> {
+        self.nodes
+            .iter()
+            .enumerate()
+            .filter_map(|(node_index, node)| {
+                node.next_frame()
+                    .map(|(position, identifier)| (identifier, node_index, position))
+            })
+            .min()
+            .map(|(_, node_index, position)| (node_index, position))
+    }
+
+    fn transmit(&mut self, node_index: usize, position: usize) {
+        let frame = self.nodes[node_index]
+            .transmission_buffer
+            .remove(position)
+            .unwrap();
+        self.nodes[node_index].transmission_succeeded();
+
+        let broadcast = is_broadcast(&frame);
+        for (index, node) in self.nodes.iter_mut().enumerate() {
+            if index == node_index || !node.is_on_bus() {
+                continue;
+            }
+
+            if node.fault.is_active() {
+                node.reception_failed();
+            } else if broadcast || node.function_id == frame.server_function_id() {
+                node.receive(frame);
             }
         }
 
-        false
+        self.last_transmitted_message = Some(frame);
+        self.transmitted_frames += 1;
     }
 
-    pub fn received_message(&mut self, function_id: u8) -> Arinc825Word<f64> {
-        let mut system_idx = N;
-        for (i, id) in self.attached_systems.iter().enumerate() {
-            if *id == function_id {
-                system_idx = i;
-                break;
+    fn transmission_failed(&mut self, node_index: usize) {
+        self.nodes[node_index].transmission_failed();
+
+        for (index, node) in self.nodes.iter_mut().enumerate() {
+            if index != node_index && node.is_on_bus() {
+                node.reception_failed();
             }
         }
+    }
 
-        if system_idx < N {
-            self.message_received_by_systems[system_idx] = [true, true];
-        }
+    fn node_index(&self, function_id: u8) -> Option<usize> {
+        self.nodes
+            .iter()
+            .position(|node| node.function_id == function_id)
+    }
+
+    pub fn new_message_received(&self, function_id: u8) -> bool {
+        self.node_index(function_id)
+            .is_some_and(|index| !self.nodes[index].reception_buffer.is_empty())
+    }
+
+    /// Takes the oldest frame received by the given system. When nothing is waiting, the frame
+    /// which was last transmitted on the bus is returned.
+    pub fn received_message(&mut self, function_id: u8) -> Arinc825Word<f64> {
+        let received = self
+            .node_index(function_id)
+            .and_then(|index| self.nodes[index].reception_buffer.pop_front());
 
-        self.received_message
+        received.unwrap_or_else(|| self.last_message())
     }
 
     pub fn send_message(&mut self, message: Arinc825Word<f64>) -> bool {
-        for (i, id) in self.attached_systems.iter().enumerate() {
-            if *id == message.client_function_id() {
+        match self.node_index(message.source_function_id()) {
+            Some(index) if !self.nodes[index].bus_off => {
+                let buffer = &mut self.nodes[index].transmission_buffer;
+
                 // detected an buffer overrun
-                if self.transmission_buffers[i].len() >= TRANSMISSION_BUFFER_SIZE {
+                if buffer.len() >= TRANSMISSION_BUFFER_SIZE {
                     return false;
                 }
 
-                self.transmission_buffers[i].push_back(message);
-                return true;
+                buffer.push_back(message);
+                true
             }
+            _ => false,
         }
-
-        false
     }
 
     pub fn reset_buffer(&mut self, function_id: u8) {
-        for (i, id) in self.attached_systems.iter().enumerate() {
-            if *id == function_id {
-                self.transmission_buffers[i] = VecDeque::new();
-                break;
-            }
+        if let Some(index) = self.node_index(function_id) {
+            self.nodes[index].transmission_buffer.clear();
         }
     }
+
+    pub fn error_state(&self, function_id: u8) -> Option<CanErrorState> {
+        self.node_index(function_id)
+            .map(|index| self.nodes[index].error_state())
+    }
+
+    pub fn pending_messages(&self, function_id: u8) -> usize {
+        self.node_index(function_id)
+            .map_or(0, |index| self.nodes[index].transmission_buffer.len())
+    }
+
+    /// The number of frames which were successfully transmitted during the last update.
+    pub fn transmitted_frames(&self) -> usize {
+        self.transmitted_frames
+    }
+
+    /// The bus is available when it is not failed and at least two nodes are able to
+    /// communicate over it.
+    pub fn is_available(&self) -> bool {
+        !self.failure_indication
+            && !self.disturbance.is_active()
+            && self.nodes.iter().filter(|node| node.is_on_bus()).count() >= 2
+    }
+
+    fn last_message(&self) -> Arinc825Word<f64> {
+        self.last_transmitted_message
+            .unwrap_or_else(|| Arinc825Word::new_with_status(0.0, 0x04000000))
+    }
 }
 
 impl<const N: usize> SimulationElement for CanBus<N> {
-    fn read(&mut self, reader: &mut SimulatorReader) {
-        self.received_message = reader.read_arinc825(&self.databus_id);
-
-        let availability: f64 = reader.read(&self.availability_id);
-        self.available = availability != 0.0;
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.disturbance.accept(visitor);
+        self.nodes.iter_mut().for_each(|node| node.accept(visitor));
 
-        for (i, id) in self.message_received_by_systems_ids.iter().enumerate() {
-            if !self.message_received_by_systems[i][1] {
-                let message_received: f64 = reader.read(id);
-                self.message_received_by_systems[i][0] = message_received != 0.0;
-            }
-        }
+        visitor.visit(self);
+    }
 
-        let failure: f64 = reader.read(&self.failure_indication_id);
-        self.failure_indication = failure != 0.0;
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.failure_indication = reader.read(&self.failure_indication_id);
     }
 
     fn write(&self, writer: &mut SimulatorWriter) {
-        // set the availability flag
-        writer.write(&self.availability_id, !self.failure_indication);
-
-        if self.next_output_message_valid {
-            writer.write_arinc825(
-                &self.databus_id,
-                self.next_output_message.value(),
-                self.next_output_message.status(),
-            );
-        }
+        writer.write(&self.availability_id, self.is_available());
 
-        // write the current status of the message received state per system
-        for (i, id) in self.message_received_by_systems_ids.iter().enumerate() {
-            writer.write(id, self.message_received_by_systems[i][0]);
+        if self.transmitted_frames > 0 {
+            let message = self.last_message();
+            writer.write_arinc825(&self.databus_id, message.value(), message.status());
         }
     }
 }
@@ -189,23 +421,40 @@ impl<const N: usize> SimulationElement for CanBus<N> {
 mod tests {
     use super::*;
     use crate::simulation::{
-        test::{ReadByName, SimulationTestBed, TestBed},
+        test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
         Aircraft, InitContext, SimulationElement, SimulationElementVisitor, UpdateContext,
     };
     use ntest::assert_about_eq;
+    use std::time::Duration;
+
+    // transmits a single frame per one second tick
+    const ONE_FRAME_PER_SECOND_BIT_RATE: u32 = 150;
 
     struct CanBusTestAircraft {
         can_bus: CanBus<5>,
     }
     impl CanBusTestAircraft {
         fn new(context: &mut InitContext) -> Self {
+            Self::new_with_bit_rate(context, ONE_FRAME_PER_SECOND_BIT_RATE)
+        }
+
+        fn new_with_bit_rate(context: &mut InitContext, bit_rate: u32) -> Self {
             Self {
-                can_bus: CanBus::new(context, "TEST_CAN_BUS", [0, 1, 2, 3, 4]),
+                can_bus: CanBus::new(context, "TEST_CAN_BUS", 1, [0, 1, 2, 3, 4])
+                    .with_bit_rate(bit_rate),
             }
         }
 
-        fn update(&mut self, _: &UpdateContext) {
-            self.can_bus.update();
+        fn new_fast(context: &mut InitContext) -> Self {
+            Self::new_with_bit_rate(context, 500_000)
+        }
+
+        fn update(&mut self, context: &UpdateContext) {
+            self.can_bus.update(context);
+        }
+
+        fn error_state(&self, id: u8) -> CanErrorState {
+            self.can_bus.error_state(id).unwrap()
         }
 
         fn send_message(&mut self, message: Arinc825Word<f64>) {
@@ -503,4 +752,209 @@ mod tests {
         assert!(value.status() == second_message.status());
         assert_about_eq!(value.value(), second_message.value());
     }
+
+    fn message(value: f64, lcc: LogicalCommunicationChannel, source: u8) -> Arinc825Word<f64> {
+        let mut word = Arinc825Word::new(value, lcc);
+        word.set_source_function_id(source);
+        word
+    }
+
+    fn received_value(test_bed: &mut SimulationTestBed<CanBusTestAircraft>, id: u8) -> f64 {
+        let mut value = 0.;
+        test_bed.command(|a| value = a.received_message(id).value());
+        value
+    }
+
+    fn bus_message(test_bed: &mut SimulationTestBed<CanBusTestAircraft>) -> Arinc825Word<f64> {
+        let message: f64 = test_bed.read_by_name("TEST_CAN_BUS");
+        Arinc825Word::from(message)
+    }
+
+    #[test]
+    fn lowest_identifier_wins_arbitration() {
+        let mut test_bed = SimulationTestBed::new(CanBusTestAircraft::new);
+
+        let normal = message(1.0, LogicalCommunicationChannel::NormalOperationChannel, 1);
+        let exception = message(2.0, LogicalCommunicationChannel::ExceptionEventChannel, 4);
+        test_bed.command(|a| a.send_message(normal));
+        test_bed.command(|a| a.send_message(exception));
+        test_bed.run();
+
+        assert_about_eq!(bus_message(&mut test_bed).value(), 2.0);
+
+        test_bed.run();
+
+        assert_about_eq!(bus_message(&mut test_bed).value(), 1.0);
+    }
+
+    #[test]
+    fn frames_of_one_system_with_the_same_identifier_keep_their_order() {
+        let mut test_bed = SimulationTestBed::new(CanBusTestAircraft::new_fast);
+
+        for value in [1.0, 2.0, 3.0] {
+            let word = message(
+                value,
+                LogicalCommunicationChannel::NormalOperationChannel,
+                1,
+            );
+            test_bed.command(|a| a.send_message(word));
+        }
+        test_bed.run_with_delta(Duration::from_millis(10));
+
+        for value in [1.0, 2.0, 3.0] {
+            assert_about_eq!(received_value(&mut test_bed, 0), value);
+        }
+    }
+
+    #[test]
+    fn bit_rate_limits_the_frames_per_tick() {
+        let mut test_bed = SimulationTestBed::new(CanBusTestAircraft::new_fast);
+
+        for source in 0..5 {
+            for _ in 0..TRANSMISSION_BUFFER_SIZE {
+                let word = message(
+                    1.0,
+                    LogicalCommunicationChannel::NormalOperationChannel,
+                    source,
+                );
+                test_bed.command(|a| a.send_message(word));
+            }
+        }
+        // 5000 bits fit 41 frames of 120 bits
+        test_bed.run_with_delta(Duration::from_millis(10));
+
+        assert_eq!(test_bed.query(|a| a.can_bus.transmitted_frames()), 41);
+        assert_eq!(
+            test_bed.query(|a| (0..5)
+                .map(|id| a.can_bus.pending_messages(id))
+                .sum::<usize>()),
+            5 * TRANSMISSION_BUFFER_SIZE - 41
+        );
+    }
+
+    #[test]
+    fn peer_to_peer_frames_are_only_received_by_the_server() {
+        let mut test_bed = SimulationTestBed::new(CanBusTestAircraft::new);
+
+        let mut word = message(1.0, LogicalCommunicationChannel::NodeServiceChannel, 1);
+        word.set_server_function_id(3);
+        test_bed.command(|a| a.send_message(word));
+        test_bed.run();
+
+        for id in [0, 1, 2, 4] {
+            assert!(!test_bed.query(|a| a.message_available(id)));
+        }
+        assert!(test_bed.query(|a| a.message_available(3)));
+    }
+
+    #[test]
+    fn disturbed_bus_drives_the_transmitter_error_passive_and_bus_off() {
+        let mut test_bed = SimulationTestBed::new(CanBusTestAircraft::new_fast);
+        test_bed.fail(FailureType::CanBusDisturbance(1));
+
+        let word = message(1.0, LogicalCommunicationChannel::NormalOperationChannel, 1);
+        test_bed.command(|a| a.send_message(word));
+        // every failed attempt takes the frame and the error frame, 140 bits in total
+        test_bed.run_with_delta(Duration::from_millis(5));
+
+        assert_eq!(
+            test_bed.query(|a| a.error_state(1)),
+            CanErrorState::ErrorPassive
+        );
+        assert_eq!(
+            test_bed.query(|a| a.error_state(0)),
+            CanErrorState::ErrorActive
+        );
+
+        test_bed.run_with_delta(Duration::from_millis(10));
+
+        assert_eq!(test_bed.query(|a| a.error_state(1)), CanErrorState::BusOff);
+        let state: f64 = test_bed.read_by_name("TEST_CAN_BUS_1_ERROR_STATE");
+        assert_about_eq!(state, 2.);
+        let available: f64 = test_bed.read_by_name("TEST_CAN_BUS_AVAIL");
+        assert_about_eq!(available, 0.);
+        assert!(!test_bed.query(|a| a.message_available(0)));
+    }
+
+    #[test]
+    fn bus_off_system_recovers_once_the_disturbance_is_gone() {
+        let mut test_bed = SimulationTestBed::new(CanBusTestAircraft::new_fast);
+        test_bed.fail(FailureType::CanBusDisturbance(1));
+
+        let word = message(1.0, LogicalCommunicationChannel::NormalOperationChannel, 1);
+        test_bed.command(|a| a.send_message(word));
+        test_bed.run_with_delta(Duration::from_millis(20));
+        assert_eq!(test_bed.query(|a| a.error_state(1)), CanErrorState::BusOff);
+
+        test_bed.unfail(FailureType::CanBusDisturbance(1));
+        test_bed.run_with_delta(Duration::from_millis(5));
+
+        assert_eq!(
+            test_bed.query(|a| a.error_state(1)),
+            CanErrorState::ErrorActive
+        );
+        assert!(test_bed.query(|a| a.message_available(0)));
+        let available: f64 = test_bed.read_by_name("TEST_CAN_BUS_AVAIL");
+        assert_about_eq!(available, 1.);
+    }
+
+    #[test]
+    fn faulty_system_goes_bus_off_while_the_others_keep_communicating() {
+        let mut test_bed = SimulationTestBed::new(CanBusTestAircraft::new_fast);
+        test_bed.fail(FailureType::CanBusNodeFault(1, 2));
+
+        let faulty = message(1.0, LogicalCommunicationChannel::NormalOperationChannel, 2);
+        test_bed.command(|a| a.send_message(faulty));
+        test_bed.run_with_delta(Duration::from_millis(20));
+
+        let healthy = message(2.0, LogicalCommunicationChannel::NormalOperationChannel, 3);
+        test_bed.command(|a| a.send_message(healthy));
+        test_bed.run_with_delta(Duration::from_millis(1));
+
+        assert_eq!(test_bed.query(|a| a.error_state(2)), CanErrorState::BusOff);
+        assert_eq!(
+            test_bed.query(|a| a.error_state(3)),
+            CanErrorState::ErrorActive
+        );
+        assert_about_eq!(received_value(&mut test_bed, 0), 2.0);
+        assert!(!test_bed.query(|a| a.message_available(2)));
+        let available: f64 = test_bed.read_by_name("TEST_CAN_BUS_AVAIL");
+        assert_about_eq!(available, 1.);
+    }
+
+    #[test]
+    fn failed_bus_does_not_transmit() {
+        let mut test_bed = SimulationTestBed::new(CanBusTestAircraft::new);
+        test_bed.write_by_name("TEST_CAN_BUS_FAILURE", true);
+
+        let word = message(1.0, LogicalCommunicationChannel::NormalOperationChannel, 1);
+        test_bed.command(|a| a.send_message(word));
+        test_bed.run();
+
+        assert!(!test_bed.query(|a| a.message_available(0)));
+        assert_eq!(test_bed.query(|a| a.can_bus.pending_messages(1)), 1);
+        let available: f64 = test_bed.read_by_name("TEST_CAN_BUS_AVAIL");
+        assert_about_eq!(available, 0.);
+    }
+
+    #[test]
+    fn external_consumer_acknowledges_the_oldest_frame() {
+        let mut test_bed = SimulationTestBed::new(CanBusTestAircraft::new_fast);
+
+        for value in [1.0, 2.0] {
+            let word = message(
+                value,
+                LogicalCommunicationChannel::NormalOperationChannel,
+                1,
+            );
+            test_bed.command(|a| a.send_message(word));
+        }
+        test_bed.run_with_delta(Duration::from_millis(10));
+
+        test_bed.write_by_name("TEST_CAN_BUS_0_RECEIVED", true);
+        test_bed.run_with_delta(Duration::from_millis(10));
+
+        assert_about_eq!(received_value(&mut test_bed, 0), 2.0);
+        assert!(!test_bed.query(|a| a.message_available(0)));
+    }
 }
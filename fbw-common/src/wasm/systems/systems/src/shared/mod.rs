@@ -6,6 +6,7 @@ use crate::{
 };
 
 use arinc429::Arinc429Word;
+use arinc429_bus::Arinc429Transmitter;
 use nalgebra::Vector3;
 use ntest::MaxDifference;
 use num_derive::FromPrimitive;
@@ -30,6 +31,7 @@ mod random;
 pub use random::*;
 
 pub mod arinc429;
+pub mod arinc429_bus;
 pub mod arinc825;
 pub mod can_bus;
 pub mod power_supply_relay;
@@ -186,6 +188,15 @@ pub enum ProximityDetectorId {
     DownlockDoorRight2,
 }
 
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FlightControlSurface {
+    LeftAileron,
+    RightAileron,
+    LeftElevator,
+    RightElevator,
+    Rudder,
+}
+
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum GearActuatorId {
     GearNose,
@@ -232,6 +243,16 @@ pub trait EngineStartState {
     fn engine_mode_selector(&self) -> EngineModeSelector;
 }
 
+pub trait EngineStarterAirPressure {
+    /// Absolute pressure of the air supplied to the starter of the given engine.
+    fn starter_air_pressure(&self, engine_number: usize) -> Pressure;
+}
+
+pub trait EngineFuelFlow {
+    /// Fuel mass flow currently burnt by the engine.
+    fn fuel_flow(&self) -> MassRate;
+}
+
 pub trait EngineBleedPushbutton<const N: usize> {
     fn engine_bleed_pushbuttons_are_auto(&self) -> [bool; N];
 }
@@ -251,6 +272,7 @@ pub trait AdirsMeasurementOutputs {
     fn true_heading(&self, adiru_number: usize) -> Arinc429Word<Angle>;
     fn vertical_speed(&self, adiru_number: usize) -> Arinc429Word<Velocity>;
     fn altitude(&self, adiru_number: usize) -> Arinc429Word<Length>;
+    fn computed_airspeed(&self, adiru_number: usize) -> Arinc429Word<Velocity>;
     fn angle_of_attack(&self, adiru_number: usize) -> Arinc429Word<Angle>;
 }
 
@@ -261,6 +283,13 @@ pub trait AdirsDiscreteOutputs {
     fn low_speed_warning_4(&self, adiru_number: usize) -> bool;
 }
 
+/// The ARINC 429 output buses of the ADIRUs, for consumers which need to detect that an ADIRU
+/// stopped transmitting.
+pub trait AdirsBusOutputs {
+    fn adr_bus(&self, adiru_number: usize) -> &Arinc429Transmitter;
+    fn ir_bus(&self, adiru_number: usize) -> &Arinc429Transmitter;
+}
+
 pub enum GearWheel {
     NOSE = 0,
     LEFT = 1,
@@ -505,8 +534,8 @@ pub enum ContactorSignal {
     Close,
 }
 
-#[derive(FromPrimitive)]
-pub(crate) enum FwcFlightPhase {
+#[derive(Clone, Copy, Debug, PartialEq, Eq, FromPrimitive)]
+pub enum FwcFlightPhase {
     ElecPwr = 1,
     FirstEngineStarted = 2,
     FirstEngineTakeOffPower = 3,
@@ -1085,6 +1114,35 @@ pub enum FireDetectionLoopID {
     B,
 }
 
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SmokeDetectionZone {
+    FwdCargo,
+    BulkCargo,
+    AvionicsBay,
+    Lavatory(usize),
+    CrewRest(usize),
+}
+
+impl Display for SmokeDetectionZone {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SmokeDetectionZone::FwdCargo => write!(f, "FWD_CARGO"),
+            SmokeDetectionZone::BulkCargo => write!(f, "BULK_CARGO"),
+            SmokeDetectionZone::AvionicsBay => write!(f, "AVNCS"),
+            SmokeDetectionZone::Lavatory(number) => write!(f, "LAV_{}", number),
+            SmokeDetectionZone::CrewRest(number) => write!(f, "CREW_REST_{}", number),
+        }
+    }
+}
+
+pub trait CargoVentilationIsolation {
+    /// Indicates that the ventilation of the forward cargo compartment has to be isolated,
+    /// e.g. because smoke was detected or extinguishing agent was discharged into it.
+    fn fwd_cargo_isolation_requested(&self) -> bool;
+    /// Indicates that the ventilation of the bulk cargo compartment has to be isolated.
+    fn bulk_cargo_isolation_requested(&self) -> bool;
+}
+
 pub trait Clamp {
     /// Restrict a value to a certain interval unless it is NaN.
     ///
@@ -11,22 +11,54 @@ mod wasm {
 
     static RAND_INIT: Once = Once::new();
     static mut RAND: MaybeUninit<SmallRng> = MaybeUninit::uninit();
+    static mut SEED: u64 = 0;
+
+    fn initialize_from_entropy() {
+        // SAFETY: WASM is single-threaded, and we're not passing references to `RAND` around.
+        unsafe {
+            SEED = SmallRng::from_entropy().gen();
+            RAND = MaybeUninit::new(SmallRng::seed_from_u64(SEED));
+        }
+    }
+
+    /// Replaces the generator with one created from the given seed, so that all random numbers
+    /// which follow are reproducible.
+    pub fn seed_random(seed: u64) {
+        RAND_INIT.call_once(|| {});
 
-    pub fn random_number() -> u8 {
         // SAFETY: WASM is single-threaded, and we're not passing references to `RAND` around.
-        RAND_INIT.call_once(|| unsafe {
-            RAND = MaybeUninit::new(SmallRng::from_entropy());
-        });
+        unsafe {
+            SEED = seed;
+            RAND = MaybeUninit::new(SmallRng::seed_from_u64(seed));
+        }
+    }
+
+    /// The seed the generator was last created from. Systems which keep their own generator
+    /// derive its seed from this one, such that they are reproducible together with the rest.
+    pub fn session_seed() -> u64 {
+        RAND_INIT.call_once(initialize_from_entropy);
+
+        // SAFETY: WASM is single-threaded and `SEED` was initialized above.
+        unsafe { SEED }
+    }
+
+    /// Seeds the generator from entropy and returns the seed used.
+    pub fn reseed_random() -> u64 {
+        let seed = SmallRng::from_entropy().gen();
+        seed_random(seed);
+
+        seed
+    }
+
+    pub fn random_number() -> u8 {
+        RAND_INIT.call_once(initialize_from_entropy);
 
         // SAFETY: `RAND` was initialized above.
         unsafe { (*RAND.as_mut_ptr()).gen() }
     }
 
     pub fn random_from_range(from: f64, to: f64) -> f64 {
-        // SAFETY: WASM is single-threaded, and we're not passing references to `RAND` around.
-        RAND_INIT.call_once(|| unsafe {
-            RAND = MaybeUninit::new(SmallRng::from_entropy());
-        });
+        RAND_INIT.call_once(initialize_from_entropy);
 
         // SAFETY: `RAND` was initialized above.
         unsafe { (*RAND.as_mut_ptr()).gen_range(from..to) }
@@ -34,10 +66,7 @@ mod wasm {
 
     // Generates a random number based on normal distribution
     pub fn random_from_normal_distribution(mean: f64, std_dev: f64) -> f64 {
-        // SAFETY: WASM is single-threaded, and we're not passing references to `RAND` around.
-        RAND_INIT.call_once(|| unsafe {
-            RAND = MaybeUninit::new(SmallRng::from_entropy());
-        });
+        RAND_INIT.call_once(initialize_from_entropy);
 
         let normal = Normal::new(mean, std_dev).unwrap();
         let limit_offset = 4. * std_dev;
@@ -57,23 +86,51 @@ pub use not_wasm::*;
 
 #[cfg(not(any(target_arch = "wasm32", doc)))]
 mod not_wasm {
-    use rand::Rng;
+    use rand::rngs::SmallRng;
+    use rand::{Rng, SeedableRng};
     use rand_distr::{Distribution, Normal};
+    use std::cell::{Cell, RefCell};
+
+    thread_local! {
+        static SEED: Cell<u64> = Cell::new(SmallRng::from_entropy().gen());
+        static RAND: RefCell<SmallRng> = RefCell::new(SmallRng::seed_from_u64(session_seed()));
+    }
+
+    /// Replaces the generator of the current thread with one created from the given seed, so
+    /// that all random numbers which follow are reproducible.
+    pub fn seed_random(seed: u64) {
+        SEED.with(|current| current.set(seed));
+        RAND.with(|rand| *rand.borrow_mut() = SmallRng::seed_from_u64(seed));
+    }
+
+    /// The seed the generator of the current thread was last created from. Systems which keep
+    /// their own generator derive its seed from this one, such that they are reproducible
+    /// together with the rest.
+    pub fn session_seed() -> u64 {
+        SEED.with(|seed| seed.get())
+    }
+
+    /// Seeds the generator of the current thread from entropy and returns the seed used.
+    pub fn reseed_random() -> u64 {
+        let seed = SmallRng::from_entropy().gen();
+        seed_random(seed);
+
+        seed
+    }
 
     pub fn random_number() -> u8 {
-        rand::thread_rng().gen()
+        RAND.with(|rand| rand.borrow_mut().gen())
     }
 
     pub fn random_from_range(from: f64, to: f64) -> f64 {
-        rand::thread_rng().gen_range(from..to)
+        RAND.with(|rand| rand.borrow_mut().gen_range(from..to))
     }
 
     /// Random value from normal distribution. Output limited to -4 / +4 sigma
     pub fn random_from_normal_distribution(mean: f64, std_dev: f64) -> f64 {
         let normal = Normal::new(mean, std_dev).unwrap();
         let limit_offset = 4. * std_dev;
-        normal
-            .sample(&mut rand::thread_rng())
+        RAND.with(|rand| normal.sample(&mut *rand.borrow_mut()))
             .max(mean - limit_offset)
             .min(mean + limit_offset)
     }
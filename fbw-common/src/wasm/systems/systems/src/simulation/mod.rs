@@ -4,8 +4,12 @@ mod update_context;
 use crate::electrical::{ElectricalElementIdentifier, ElectricalElementIdentifierProvider};
 use crate::shared::{from_bool, ElectricalBusType};
 use crate::{
-    electrical::Electricity,
-    failures::FailureType,
+    electrical::{ElectricalTopology, Electricity},
+    failures::{
+        cascade::{ActiveFailure, FailureCascade, FailureDependency, FailureOrigin},
+        scheduler::{FailureScheduler, FailureTrigger},
+        FailureType,
+    },
     shared::arinc429::{from_arinc429, to_arinc429, Arinc429Word, SignStatus},
     shared::arinc825::{from_arinc825, to_arinc825, Arinc825Word},
     shared::{to_bool, ConsumePower, ElectricalBuses, MachNumber, PowerConsumptionReport},
@@ -21,6 +25,9 @@ use uom::si::{
 };
 pub use update_context::*;
 
+pub mod probe;
+pub mod recording;
+pub mod scenario;
 pub mod test;
 
 /// Trait for a type which can read and write simulator data.
@@ -179,6 +186,19 @@ impl<'a> ElectricalElementIdentifierProvider for InitContext<'a> {
         self.electrical_identifier_provider
             .next_electrical_identifier_for_bus(bus_type)
     }
+
+    fn next_electrical_identifier_for_contactor(
+        &mut self,
+        id: &str,
+    ) -> ElectricalElementIdentifier {
+        self.electrical_identifier_provider
+            .next_electrical_identifier_for_contactor(id)
+    }
+
+    fn next_named_electrical_identifier(&mut self, name: &str) -> ElectricalElementIdentifier {
+        self.electrical_identifier_provider
+            .next_named_electrical_identifier(name)
+    }
 }
 
 /// An [`Aircraft`] that can be simulated by the [`Simulation`].
@@ -360,6 +380,10 @@ pub struct Simulation<T: Aircraft> {
     aircraft: T,
     electricity: Electricity,
     update_context: UpdateContext,
+    failure_scheduler: FailureScheduler,
+    failure_cascade: FailureCascade,
+    active_failures: Vec<ActiveFailure>,
+    induced_failures: Vec<ActiveFailure>,
 }
 impl<T: Aircraft> Simulation<T> {
     pub fn new<U: FnOnce(&mut InitContext) -> T>(
@@ -370,10 +394,16 @@ impl<T: Aircraft> Simulation<T> {
         let mut electricity = Electricity::new();
         let mut context = InitContext::new(start_state, &mut electricity, registry);
         let update_context = UpdateContext::new_for_simulation(&mut context);
+        let aircraft = (aircraft_ctor_fn)(&mut context);
+        let failure_scheduler = FailureScheduler::new(&mut context);
         Self {
-            aircraft: (aircraft_ctor_fn)(&mut context),
+            aircraft,
             electricity,
             update_context,
+            failure_scheduler,
+            failure_cascade: FailureCascade::default(),
+            active_failures: Vec::new(),
+            induced_failures: Vec::new(),
         }
     }
 
@@ -435,6 +465,7 @@ impl<T: Aircraft> Simulation<T> {
         reader_writer: &mut impl SimulatorReaderWriter,
     ) {
         self.electricity.pre_tick();
+        self.induced_failures.clear();
 
         let mut reader = SimulatorReader::new(reader_writer);
         self.update_context
@@ -442,6 +473,7 @@ impl<T: Aircraft> Simulation<T> {
 
         let mut visitor = SimulatorToSimulationVisitor::new(&mut reader);
         self.aircraft.accept(&mut visitor);
+        self.failure_scheduler.accept(&mut visitor);
 
         self.aircraft
             .update_before_power_distribution(&self.update_context, &mut self.electricity);
@@ -459,13 +491,101 @@ impl<T: Aircraft> Simulation<T> {
         let mut writer = SimulatorWriter::new(reader_writer);
         let mut visitor = SimulationToSimulatorVisitor::new(&mut writer);
         self.aircraft.accept(&mut visitor);
+
+        for (cause, effect) in self.failure_cascade.update(delta) {
+            self.activate_failure_with_origin(effect, FailureOrigin::Cascaded(cause));
+        }
+
+        for failure_type in self.failure_scheduler.update(&self.update_context) {
+            self.activate_failure_with_origin(failure_type, FailureOrigin::Scheduled);
+        }
+    }
+
+    /// Sets the dependencies through which the activation of one failure can lead to others.
+    pub fn set_failure_dependencies(&mut self, dependencies: &[FailureDependency]) {
+        self.failure_cascade = FailureCascade::new(dependencies.to_vec());
+    }
+
+    /// Arms the failure to be activated by the simulation once the trigger condition is met.
+    /// A failure triggered during a tick is active from the next tick on.
+    pub fn arm_failure(&mut self, failure_type: FailureType, trigger: FailureTrigger) {
+        self.failure_scheduler.arm(failure_type, trigger);
+    }
+
+    pub fn disarm_failure(&mut self, failure_type: FailureType) {
+        self.failure_scheduler.disarm(failure_type);
+    }
+
+    pub fn is_failure_armed(&self, failure_type: FailureType) -> bool {
+        self.failure_scheduler.is_armed(failure_type)
+    }
+
+    /// Takes the failures which were activated by the simulation itself, either scheduled or
+    /// cascaded, together with their origin, since the last tick started or since they were
+    /// last taken. A failure activated at the end of a tick is active from the next tick on.
+    pub fn take_induced_failures(&mut self) -> Vec<ActiveFailure> {
+        std::mem::take(&mut self.induced_failures)
+    }
+
+    pub fn active_failures(&self) -> &[ActiveFailure] {
+        &self.active_failures
+    }
+
+    pub fn is_failure_active(&self, failure_type: FailureType) -> bool {
+        self.active_failure(failure_type).is_some()
+    }
+
+    /// Follows the origins of the given active failure back to the failure which started the
+    /// cascade. When a cause was deactivated in the meantime, the chain ends at that cause.
+    pub fn root_cause_of(&self, failure_type: FailureType) -> Option<FailureType> {
+        let mut root_cause = self.active_failure(failure_type)?;
+        // Bounded, as a cycle in the dependencies would otherwise never end.
+        for _ in 0..self.active_failures.len() {
+            match root_cause.origin() {
+                FailureOrigin::Cascaded(cause) => match self.active_failure(cause) {
+                    Some(active_cause) => root_cause = active_cause,
+                    None => return Some(cause),
+                },
+                FailureOrigin::Commanded | FailureOrigin::Scheduled => break,
+            }
+        }
+
+        Some(root_cause.failure_type())
+    }
+
+    fn active_failure(&self, failure_type: FailureType) -> Option<&ActiveFailure> {
+        self.active_failures
+            .iter()
+            .find(|active| active.failure_type() == failure_type)
     }
 
     pub fn activate_failure(&mut self, failure_type: FailureType) {
+        self.activate_failure_with_origin(failure_type, FailureOrigin::Commanded);
+    }
+
+    fn activate_failure_with_origin(&mut self, failure_type: FailureType, origin: FailureOrigin) {
+        if self.is_failure_active(failure_type) {
+            return;
+        }
+
+        let active_failure = ActiveFailure::new(failure_type, origin);
+        self.active_failures.push(active_failure);
+        if origin != FailureOrigin::Commanded {
+            self.induced_failures.push(active_failure);
+        }
         self.handle_failure(failure_type, true);
+
+        for effect in self.failure_cascade.cause_activated(failure_type) {
+            self.activate_failure_with_origin(effect, FailureOrigin::Cascaded(failure_type));
+        }
     }
 
+    /// Deactivates the failure and cancels its effects which did not activate yet.
+    /// Effects which already activated are not deactivated.
     pub fn deactivate_failure(&mut self, failure_type: FailureType) {
+        self.active_failures
+            .retain(|active| active.failure_type() != failure_type);
+        self.failure_cascade.cause_deactivated(failure_type);
         self.handle_failure(failure_type, false);
     }
 
@@ -477,6 +597,12 @@ impl<T: Aircraft> Simulation<T> {
             ));
     }
 
+    /// Returns the electrical network as it was resolved during the last tick.
+    /// See [Electricity::topology] for details.
+    pub fn electrical_topology(&self) -> ElectricalTopology {
+        self.electricity.topology()
+    }
+
     fn electricity(&self) -> &Electricity {
         &self.electricity
     }
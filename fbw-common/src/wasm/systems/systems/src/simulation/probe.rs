@@ -0,0 +1,434 @@
+//! Sampling of simulation values into time series, for tuning graphs.
+//!
+//! Probes are added to a [`SimulationTestBed`] before it runs. A probe either reads a named
+//! variable, like a simulator would, or queries the aircraft for a value which isn't written
+//! to any variable. The test bed samples the initial state before its first tick and all
+//! probes again after every tick, so each time series has one value per sampled time.
+//!
+//! The samples can be exported as CSV for further processing, or as an SVG image with one
+//! line chart per probe sharing the time axis.
+//!
+//! [`SimulationTestBed`]: ../test/struct.SimulationTestBed.html
+
+use std::{
+    fmt::Write as _,
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::Path,
+    time::Duration,
+};
+
+use super::VariableIdentifier;
+
+type QueryFn<T> = dyn Fn(&T) -> f64 + 'static;
+
+enum ProbeSource<T> {
+    Variable(VariableIdentifier),
+    Query(Box<QueryFn<T>>),
+}
+
+struct Probe<T> {
+    name: String,
+    source: ProbeSource<T>,
+    values: Vec<f64>,
+}
+
+pub struct Probes<T> {
+    probes: Vec<Probe<T>>,
+    elapsed: Duration,
+    time: Vec<f64>,
+}
+impl<T> Probes<T> {
+    const SVG_WIDTH: f64 = 800.;
+    const SVG_TITLE_HEIGHT: f64 = 40.;
+    const SVG_PLOT_HEIGHT: f64 = 150.;
+    const SVG_PLOT_SPACING: f64 = 50.;
+    const SVG_LEFT_MARGIN: f64 = 80.;
+    const SVG_RIGHT_MARGIN: f64 = 20.;
+
+    pub(super) fn new() -> Self {
+        Self {
+            probes: Vec::new(),
+            elapsed: Duration::ZERO,
+            time: Vec::new(),
+        }
+    }
+
+    pub(super) fn add_variable(&mut self, name: &str, identifier: VariableIdentifier) {
+        self.add(name, ProbeSource::Variable(identifier));
+    }
+
+    pub(super) fn add_query<U: Fn(&T) -> f64 + 'static>(&mut self, name: &str, func: U) {
+        self.add(name, ProbeSource::Query(Box::new(func)));
+    }
+
+    fn add(&mut self, name: &str, source: ProbeSource<T>) {
+        assert!(
+            self.time.is_empty(),
+            "Probes must be added before the test bed runs"
+        );
+        assert!(
+            self.series(name).is_none(),
+            "A probe named \"{}\" already exists",
+            name
+        );
+
+        self.probes.push(Probe {
+            name: name.to_owned(),
+            source,
+            values: Vec::new(),
+        });
+    }
+
+    /// Indicates the initial state has to be sampled before the next tick.
+    pub(super) fn awaits_initial_sample(&self) -> bool {
+        !self.probes.is_empty() && self.time.is_empty()
+    }
+
+    pub(super) fn sample(
+        &mut self,
+        delta: Duration,
+        aircraft: &T,
+        mut read_variable: impl FnMut(&VariableIdentifier) -> f64,
+    ) {
+        if self.probes.is_empty() {
+            return;
+        }
+
+        self.elapsed += delta;
+        self.time.push(self.elapsed.as_secs_f64());
+
+        for probe in &mut self.probes {
+            let value = match &probe.source {
+                ProbeSource::Variable(identifier) => read_variable(identifier),
+                ProbeSource::Query(func) => (func)(aircraft),
+            };
+            probe.values.push(value);
+        }
+    }
+
+    /// The time in seconds of every sample, starting at zero.
+    pub fn time(&self) -> &[f64] {
+        &self.time
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.probes.iter().map(|probe| probe.name.as_str())
+    }
+
+    pub fn series(&self, name: &str) -> Option<&[f64]> {
+        self.probes
+            .iter()
+            .find(|probe| probe.name == name)
+            .map(|probe| probe.values.as_slice())
+    }
+
+    /// Writes a header row followed by one row per sample, with the time in the first column.
+    pub fn write_csv(&self, mut writer: impl Write) -> io::Result<()> {
+        write!(writer, "time")?;
+        for name in self.names() {
+            write!(writer, ",{}", csv_field(name))?;
+        }
+        writeln!(writer)?;
+
+        for (index, time) in self.time.iter().enumerate() {
+            write!(writer, "{}", time)?;
+            for probe in &self.probes {
+                write!(writer, ",{}", probe.values[index])?;
+            }
+            writeln!(writer)?;
+        }
+
+        writer.flush()
+    }
+
+    /// Writes an SVG image with a line chart per probe, stacked in the order they were added.
+    pub fn write_svg(&self, mut writer: impl Write, title: &str) -> io::Result<()> {
+        let plot_width = Self::SVG_WIDTH - Self::SVG_LEFT_MARGIN - Self::SVG_RIGHT_MARGIN;
+        let height = Self::SVG_TITLE_HEIGHT
+            + self.probes.len() as f64 * (Self::SVG_PLOT_HEIGHT + Self::SVG_PLOT_SPACING);
+        let end_time = self.time.last().copied().unwrap_or_default();
+
+        writeln!(
+            writer,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}" font-family="sans-serif" font-size="12">"#,
+            w = Self::SVG_WIDTH,
+            h = height
+        )?;
+        writeln!(
+            writer,
+            r#"<rect width="100%" height="100%" fill="white"/><text x="{}" y="25" font-size="16" text-anchor="middle">{}</text>"#,
+            Self::SVG_WIDTH / 2.,
+            xml_escape(title)
+        )?;
+
+        for (index, probe) in self.probes.iter().enumerate() {
+            let top = Self::SVG_TITLE_HEIGHT
+                + index as f64 * (Self::SVG_PLOT_HEIGHT + Self::SVG_PLOT_SPACING);
+            let bottom = top + Self::SVG_PLOT_HEIGHT;
+            let (min, max) = value_range(&probe.values);
+
+            let mut points = String::new();
+            for (time, value) in self
+                .time
+                .iter()
+                .zip(&probe.values)
+                .filter(|(_, value)| value.is_finite())
+            {
+                let x = Self::SVG_LEFT_MARGIN
+                    + if end_time > 0. {
+                        time / end_time * plot_width
+                    } else {
+                        0.
+                    };
+                let y = bottom - (value - min) / (max - min) * Self::SVG_PLOT_HEIGHT;
+                // Writing to a String cannot fail.
+                let _ = write!(points, "{:.2},{:.2} ", x, y);
+            }
+
+            writeln!(
+                writer,
+                r#"<rect x="{}" y="{}" width="{}" height="{}" fill="none" stroke="gray"/>"#,
+                Self::SVG_LEFT_MARGIN,
+                top,
+                plot_width,
+                Self::SVG_PLOT_HEIGHT
+            )?;
+            writeln!(
+                writer,
+                r#"<text x="{}" y="{}">{}</text>"#,
+                Self::SVG_LEFT_MARGIN,
+                top - 5.,
+                xml_escape(&probe.name)
+            )?;
+            writeln!(
+                writer,
+                r#"<text x="{x}" y="{}" text-anchor="end">{}</text><text x="{x}" y="{}" text-anchor="end">{}</text>"#,
+                top + 12.,
+                format_value(max),
+                bottom,
+                format_value(min),
+                x = Self::SVG_LEFT_MARGIN - 5.
+            )?;
+            writeln!(
+                writer,
+                r#"<text x="{}" y="{y}">0 s</text><text x="{}" y="{y}" text-anchor="end">{} s</text>"#,
+                Self::SVG_LEFT_MARGIN,
+                Self::SVG_LEFT_MARGIN + plot_width,
+                format_value(end_time),
+                y = bottom + 15.
+            )?;
+            writeln!(
+                writer,
+                r#"<polyline points="{}" fill="none" stroke="steelblue" stroke-width="1.5"/>"#,
+                points.trim_end()
+            )?;
+        }
+
+        writeln!(writer, "</svg>")?;
+
+        writer.flush()
+    }
+
+    pub fn save_csv(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        self.write_csv(BufWriter::new(File::create(path)?))
+    }
+
+    pub fn save_svg(&self, path: impl AsRef<Path>, title: &str) -> io::Result<()> {
+        self.write_svg(BufWriter::new(File::create(path)?), title)
+    }
+}
+
+/// The range of the finite values, widened when all values are equal so they can be scaled.
+fn value_range(values: &[f64]) -> (f64, f64) {
+    let (min, max) = values
+        .iter()
+        .filter(|value| value.is_finite())
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &value| {
+            (min.min(value), max.max(value))
+        });
+
+    if min > max {
+        (0., 1.)
+    } else if min == max {
+        (min - 0.5, max + 0.5)
+    } else {
+        (min, max)
+    }
+}
+
+fn format_value(value: f64) -> String {
+    let formatted = format!("{:.3}", value);
+    formatted
+        .trim_end_matches('0')
+        .trim_end_matches('.')
+        .to_owned()
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_owned()
+    }
+}
+
+fn xml_escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::{
+        test::{SimulationTestBed, TestBed, WriteByName},
+        Aircraft, InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        SimulatorWriter, UpdateContext, Write as _,
+    };
+
+    struct Integrator {
+        input_id: VariableIdentifier,
+        output_id: VariableIdentifier,
+
+        input: f64,
+        output: f64,
+    }
+    impl Integrator {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                input_id: context.get_identifier("INPUT".to_owned()),
+                output_id: context.get_identifier("OUTPUT".to_owned()),
+                input: 0.,
+                output: 0.,
+            }
+        }
+
+        fn doubled_output(&self) -> f64 {
+            self.output * 2.
+        }
+    }
+    impl Aircraft for Integrator {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.output += self.input * context.delta_as_secs_f64();
+        }
+    }
+    impl SimulationElement for Integrator {
+        fn read(&mut self, reader: &mut SimulatorReader) {
+            self.input = reader.read(&self.input_id);
+        }
+
+        fn write(&self, writer: &mut SimulatorWriter) {
+            writer.write(&self.output_id, self.output);
+        }
+
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed() -> SimulationTestBed<Integrator> {
+        let mut test_bed = SimulationTestBed::new(Integrator::new);
+        test_bed.probe_variable("OUTPUT");
+        test_bed.probe("Doubled output", |a: &Integrator| a.doubled_output());
+        test_bed.write_by_name("INPUT", 2.);
+
+        test_bed
+    }
+
+    #[test]
+    fn samples_initial_state_and_every_tick() {
+        let mut test_bed = test_bed();
+
+        test_bed.run_with_delta(Duration::from_millis(500));
+        test_bed.run_with_delta(Duration::from_millis(500));
+
+        assert_eq!(test_bed.probes().time(), [0., 0.5, 1.]);
+        assert_eq!(test_bed.probes().series("OUTPUT").unwrap(), [0., 1., 2.]);
+        assert_eq!(
+            test_bed.probes().series("Doubled output").unwrap(),
+            [0., 2., 4.]
+        );
+    }
+
+    #[test]
+    fn unknown_series_is_none() {
+        let test_bed = test_bed();
+
+        assert!(test_bed.probes().series("UNKNOWN").is_none());
+    }
+
+    #[test]
+    fn without_probes_nothing_is_sampled() {
+        let mut test_bed = SimulationTestBed::new(Integrator::new);
+
+        test_bed.run_with_delta(Duration::from_millis(500));
+
+        assert!(test_bed.probes().time().is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn probe_of_unknown_variable_panics() {
+        let mut test_bed = SimulationTestBed::new(Integrator::new);
+
+        test_bed.probe_variable("UNKNOWN");
+    }
+
+    #[test]
+    #[should_panic]
+    fn probe_added_after_running_panics() {
+        let mut test_bed = test_bed();
+        test_bed.run_with_delta(Duration::from_millis(500));
+
+        test_bed.probe("Output", |a: &Integrator| a.output);
+    }
+
+    #[test]
+    fn writes_csv() {
+        let mut test_bed = test_bed();
+        test_bed.run_with_delta(Duration::from_millis(500));
+
+        let mut csv = Vec::new();
+        test_bed.probes().write_csv(&mut csv).unwrap();
+
+        assert_eq!(
+            String::from_utf8(csv).unwrap(),
+            "time,OUTPUT,Doubled output\n0,0,0\n0.5,1,2\n"
+        );
+    }
+
+    #[test]
+    fn csv_fields_with_separators_are_quoted() {
+        assert_eq!(csv_field("Pressure, blue"), "\"Pressure, blue\"");
+        assert_eq!(csv_field("6\" pipe"), "\"6\"\" pipe\"");
+    }
+
+    #[test]
+    fn writes_svg_with_a_chart_per_probe() {
+        let mut test_bed = test_bed();
+        test_bed.run_with_delta(Duration::from_millis(500));
+
+        let mut svg = Vec::new();
+        test_bed
+            .probes()
+            .write_svg(&mut svg, "Integrator <test>")
+            .unwrap();
+        let svg = String::from_utf8(svg).unwrap();
+
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.trim_end().ends_with("</svg>"));
+        assert!(svg.contains("Integrator &lt;test&gt;"));
+        assert_eq!(svg.matches("<polyline").count(), 2);
+    }
+
+    #[test]
+    fn constant_values_get_a_range() {
+        assert_eq!(value_range(&[3., 3.]), (2.5, 3.5));
+        assert_eq!(value_range(&[]), (0., 1.));
+        assert_eq!(value_range(&[1., f64::NAN, 4.]), (1., 4.));
+    }
+}
@@ -0,0 +1,862 @@
+//! Deterministic record and replay of a [`Simulation`].
+//!
+//! A [`Recorder`] sits between the simulation and the simulator and stores every value the
+//! simulation reads and writes during a tick, together with the tick's delta, simulation time
+//! and any failures which were (de)activated before it. Only values which changed since the
+//! previous tick are stored, which keeps a recording of a full flight small.
+//!
+//! The random number generator is seeded with the seed found in the recording before the
+//! aircraft is constructed, so [`replay`] is able to run the same aircraft outside of the
+//! simulator and report every written value that differs from what was recorded. The recording
+//! also holds the aircraft configuration the aircraft was built from, which has to be loaded
+//! before replaying it.
+//!
+//! All numbers are stored in little endian byte order. The file starts with a header:
+//! - magic `FBWREC` followed by a `u16` format version
+//! - `u64` random seed and `f64` start state
+//! - `u32` length followed by the UTF-8 bytes of the aircraft configuration
+//! - `u32` number of variables, followed by a `u32` length and the UTF-8 bytes of each name
+//!
+//! Followed by one frame per tick:
+//! - `u8` frame marker, `u64` delta in nanoseconds and `f64` simulation time
+//! - `u32` number of failures, followed by a `u64` failure identifier and `u8` active flag each
+//! - `u32` number of reads and `u32` number of writes, followed by a `u32` variable index and
+//!   `f64` value each
+//!
+//! A recording which ends in the middle of a frame, e.g. because the simulator was closed
+//! before the file was flushed, is read up to the last complete frame.
+
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+    fs::File,
+    io::{self, BufReader, Read, Write},
+    path::Path,
+    time::Duration,
+};
+
+use fxhash::FxHashMap;
+
+use crate::{failures::FailureType, shared::seed_random};
+
+use super::{
+    Aircraft, InitContext, Simulation, SimulatorReaderWriter, StartState, VariableIdentifier,
+    VariableRegistry,
+};
+
+const MAGIC: &[u8; 6] = b"FBWREC";
+const VERSION: u16 = 2;
+const TICK_FRAME: u8 = 1;
+
+#[derive(Debug)]
+pub enum RecordingError {
+    Io(io::Error),
+    InvalidMagic,
+    UnsupportedVersion(u16),
+    InvalidConfiguration,
+    InvalidVariableName(u32),
+    UnknownFrame(u8),
+    UnknownVariable(u32),
+}
+impl Display for RecordingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RecordingError::Io(error) => write!(f, "failed to read recording: {}", error),
+            RecordingError::InvalidMagic => write!(f, "the file is not a simulation recording"),
+            RecordingError::UnsupportedVersion(version) => {
+                write!(f, "recording version {} is not supported", version)
+            }
+            RecordingError::InvalidConfiguration => {
+                write!(f, "the aircraft configuration is not valid UTF-8")
+            }
+            RecordingError::InvalidVariableName(index) => {
+                write!(f, "the name of variable {} is not valid UTF-8", index)
+            }
+            RecordingError::UnknownFrame(marker) => {
+                write!(f, "encountered unknown frame marker {}", marker)
+            }
+            RecordingError::UnknownVariable(index) => write!(
+                f,
+                "a frame refers to variable {} which is not in the variable table",
+                index
+            ),
+        }
+    }
+}
+impl std::error::Error for RecordingError {}
+impl From<io::Error> for RecordingError {
+    fn from(error: io::Error) -> Self {
+        RecordingError::Io(error)
+    }
+}
+
+/// Keeps the last value seen per variable, so only changes end up in the recording.
+struct ChangeTracker {
+    last: Vec<Option<u64>>,
+    changes: BTreeMap<u32, f64>,
+}
+impl ChangeTracker {
+    fn new(variable_count: usize) -> Self {
+        Self {
+            last: vec![None; variable_count],
+            changes: BTreeMap::new(),
+        }
+    }
+
+    fn observe(&mut self, index: u32, value: f64) {
+        // Bits are compared so NaN values are recorded once instead of on every tick.
+        let bits = value.to_bits();
+        if self.last[index as usize] != Some(bits) {
+            self.last[index as usize] = Some(bits);
+            self.changes.insert(index, value);
+        }
+    }
+
+    fn take_changes(&mut self) -> BTreeMap<u32, f64> {
+        std::mem::take(&mut self.changes)
+    }
+}
+
+/// Records the traffic between a [`Simulation`] and the simulator into the given writer.
+pub struct Recorder<W: Write> {
+    writer: W,
+    indices: FxHashMap<VariableIdentifier, u32>,
+    reads: ChangeTracker,
+    writes: ChangeTracker,
+    failures: Vec<(u64, bool)>,
+}
+impl<W: Write> Recorder<W> {
+    /// Creates a recorder and writes the header of the recording.
+    ///
+    /// The `seed` must be the seed the random number generator was seeded with before the
+    /// aircraft was constructed and the `configuration` the aircraft configuration it was built
+    /// from. The `variables` contain every variable the aircraft registered.
+    pub fn new(
+        mut writer: W,
+        seed: u64,
+        start_state: StartState,
+        configuration: &str,
+        variables: impl IntoIterator<Item = (String, VariableIdentifier)>,
+    ) -> io::Result<Self> {
+        let mut variables: Vec<_> = variables.into_iter().collect();
+        variables.sort_by(|a, b| a.0.cmp(&b.0));
+
+        writer.write_all(MAGIC)?;
+        writer.write_all(&VERSION.to_le_bytes())?;
+        writer.write_all(&seed.to_le_bytes())?;
+        writer.write_all(&f64::from(start_state).to_le_bytes())?;
+        writer.write_all(&(configuration.len() as u32).to_le_bytes())?;
+        writer.write_all(configuration.as_bytes())?;
+        writer.write_all(&(variables.len() as u32).to_le_bytes())?;
+        for (name, _) in &variables {
+            writer.write_all(&(name.len() as u32).to_le_bytes())?;
+            writer.write_all(name.as_bytes())?;
+        }
+
+        Ok(Self {
+            writer,
+            indices: variables
+                .iter()
+                .enumerate()
+                .map(|(index, (_, identifier))| (*identifier, index as u32))
+                .collect(),
+            reads: ChangeTracker::new(variables.len()),
+            writes: ChangeTracker::new(variables.len()),
+            failures: Vec::new(),
+        })
+    }
+
+    /// Records a failure (de)activation which will be applied before the next tick.
+    pub fn record_failure(&mut self, identifier: u64, is_active: bool) {
+        self.failures.push((identifier, is_active));
+    }
+
+    /// Wraps the given reader writer, recording all values passing through it.
+    /// Pass the returned instance to [`Simulation::tick`] and call [`finish_tick`] afterwards.
+    ///
+    /// [`finish_tick`]: #method.finish_tick
+    pub fn reader_writer<'a, T: SimulatorReaderWriter>(
+        &'a mut self,
+        inner: &'a mut T,
+    ) -> RecordingReaderWriter<'a, W, T> {
+        RecordingReaderWriter {
+            recorder: self,
+            inner,
+        }
+    }
+
+    /// Writes a frame containing everything recorded since the previous tick.
+    pub fn finish_tick(&mut self, delta: Duration, simulation_time: f64) -> io::Result<()> {
+        let reads = self.reads.take_changes();
+        let writes = self.writes.take_changes();
+
+        self.writer.write_all(&[TICK_FRAME])?;
+        self.writer
+            .write_all(&(delta.as_nanos() as u64).to_le_bytes())?;
+        self.writer.write_all(&simulation_time.to_le_bytes())?;
+
+        self.writer
+            .write_all(&(self.failures.len() as u32).to_le_bytes())?;
+        for (identifier, is_active) in self.failures.drain(..) {
+            self.writer.write_all(&identifier.to_le_bytes())?;
+            self.writer.write_all(&[is_active as u8])?;
+        }
+
+        self.writer.write_all(&(reads.len() as u32).to_le_bytes())?;
+        self.writer
+            .write_all(&(writes.len() as u32).to_le_bytes())?;
+        for (index, value) in reads.iter().chain(writes.iter()) {
+            self.writer.write_all(&index.to_le_bytes())?;
+            self.writer.write_all(&value.to_le_bytes())?;
+        }
+
+        Ok(())
+    }
+
+    /// Flushes the recording and returns the underlying writer.
+    pub fn into_inner(mut self) -> io::Result<W> {
+        self.writer.flush()?;
+
+        Ok(self.writer)
+    }
+}
+
+pub struct RecordingReaderWriter<'a, W: Write, T: SimulatorReaderWriter> {
+    recorder: &'a mut Recorder<W>,
+    inner: &'a mut T,
+}
+impl<'a, W: Write, T: SimulatorReaderWriter> SimulatorReaderWriter
+    for RecordingReaderWriter<'a, W, T>
+{
+    fn read(&mut self, identifier: &VariableIdentifier) -> f64 {
+        let value = self.inner.read(identifier);
+        if let Some(&index) = self.recorder.indices.get(identifier) {
+            self.recorder.reads.observe(index, value);
+        }
+
+        value
+    }
+
+    fn write(&mut self, identifier: &VariableIdentifier, value: f64) {
+        if let Some(&index) = self.recorder.indices.get(identifier) {
+            self.recorder.writes.observe(index, value);
+        }
+
+        self.inner.write(identifier, value);
+    }
+}
+
+struct RecordedTick {
+    delta: Duration,
+    simulation_time: f64,
+    failures: Vec<(u64, bool)>,
+    reads: Vec<(u32, f64)>,
+    writes: Vec<(u32, f64)>,
+}
+
+/// A recording read back from a file written by a [`Recorder`].
+pub struct Recording {
+    seed: u64,
+    start_state: StartState,
+    configuration: String,
+    variables: Vec<String>,
+    ticks: Vec<RecordedTick>,
+}
+impl Recording {
+    /// Reads the recording at `path`, such as the `systems.rec` written to the work folder of
+    /// the aircraft.
+    pub fn read_file(path: impl AsRef<Path>) -> Result<Self, RecordingError> {
+        Self::read_from(BufReader::new(File::open(path)?))
+    }
+
+    pub fn read_from(mut reader: impl Read) -> Result<Self, RecordingError> {
+        let mut magic = [0; 6];
+        reader.read_exact(&mut magic)?;
+        if &magic != MAGIC {
+            return Err(RecordingError::InvalidMagic);
+        }
+
+        let version = u16::from_le_bytes(read_bytes(&mut reader)?);
+        if version != VERSION {
+            return Err(RecordingError::UnsupportedVersion(version));
+        }
+
+        let seed = u64::from_le_bytes(read_bytes(&mut reader)?);
+        let start_state = f64::from_le_bytes(read_bytes(&mut reader)?).into();
+
+        let configuration_length = u32::from_le_bytes(read_bytes(&mut reader)?);
+        let mut configuration = vec![0; configuration_length as usize];
+        reader.read_exact(&mut configuration)?;
+        let configuration =
+            String::from_utf8(configuration).map_err(|_| RecordingError::InvalidConfiguration)?;
+
+        let variable_count = u32::from_le_bytes(read_bytes(&mut reader)?);
+        let mut variables = Vec::with_capacity(variable_count as usize);
+        for index in 0..variable_count {
+            let length = u32::from_le_bytes(read_bytes(&mut reader)?);
+            let mut name = vec![0; length as usize];
+            reader.read_exact(&mut name)?;
+            variables.push(
+                String::from_utf8(name).map_err(|_| RecordingError::InvalidVariableName(index))?,
+            );
+        }
+
+        let mut ticks = Vec::new();
+        loop {
+            match Self::read_tick(&mut reader, variables.len()) {
+                Ok(Some(tick)) => ticks.push(tick),
+                Ok(None) => break,
+                Err(RecordingError::Io(error)) if error.kind() == io::ErrorKind::UnexpectedEof => {
+                    break
+                }
+                Err(error) => return Err(error),
+            }
+        }
+
+        Ok(Self {
+            seed,
+            start_state,
+            configuration,
+            variables,
+            ticks,
+        })
+    }
+
+    fn read_tick(
+        reader: &mut impl Read,
+        variable_count: usize,
+    ) -> Result<Option<RecordedTick>, RecordingError> {
+        let mut marker = [0; 1];
+        if reader.read(&mut marker)? == 0 {
+            return Ok(None);
+        }
+        if marker[0] != TICK_FRAME {
+            return Err(RecordingError::UnknownFrame(marker[0]));
+        }
+
+        let delta = Duration::from_nanos(u64::from_le_bytes(read_bytes(reader)?));
+        let simulation_time = f64::from_le_bytes(read_bytes(reader)?);
+
+        let failure_count = u32::from_le_bytes(read_bytes(reader)?);
+        let mut failures = Vec::with_capacity(failure_count as usize);
+        for _ in 0..failure_count {
+            let identifier = u64::from_le_bytes(read_bytes(reader)?);
+            let [is_active] = read_bytes(reader)?;
+            failures.push((identifier, is_active != 0));
+        }
+
+        let read_count = u32::from_le_bytes(read_bytes(reader)?);
+        let write_count = u32::from_le_bytes(read_bytes(reader)?);
+        let mut values = Vec::with_capacity((read_count + write_count) as usize);
+        for _ in 0..read_count + write_count {
+            let index = u32::from_le_bytes(read_bytes(reader)?);
+            if index as usize >= variable_count {
+                return Err(RecordingError::UnknownVariable(index));
+            }
+
+            values.push((index, f64::from_le_bytes(read_bytes(reader)?)));
+        }
+        let writes = values.split_off(read_count as usize);
+
+        Ok(Some(RecordedTick {
+            delta,
+            simulation_time,
+            failures,
+            reads: values,
+            writes,
+        }))
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn start_state(&self) -> StartState {
+        self.start_state
+    }
+
+    /// The aircraft configuration the recorded aircraft was built from.
+    pub fn configuration(&self) -> &str {
+        &self.configuration
+    }
+
+    pub fn tick_count(&self) -> usize {
+        self.ticks.len()
+    }
+}
+
+fn read_bytes<const N: usize>(reader: &mut impl Read) -> io::Result<[u8; N]> {
+    let mut bytes = [0; N];
+    reader.read_exact(&mut bytes)?;
+
+    Ok(bytes)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Divergence {
+    pub tick: usize,
+    pub variable: String,
+    pub recorded: f64,
+    pub replayed: f64,
+}
+impl Display for Divergence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "tick {}: {} was {} but replayed as {}",
+            self.tick, self.variable, self.recorded, self.replayed
+        )
+    }
+}
+
+/// The outcome of a [`replay`].
+#[derive(Debug, Default)]
+pub struct ReplayReport {
+    ticks: usize,
+    divergences: Vec<Divergence>,
+    unknown_failures: Vec<u64>,
+}
+impl ReplayReport {
+    pub fn ticks(&self) -> usize {
+        self.ticks
+    }
+
+    pub fn divergences(&self) -> &[Divergence] {
+        &self.divergences
+    }
+
+    /// Failure identifiers found in the recording which could not be mapped to a [`FailureType`].
+    pub fn unknown_failures(&self) -> &[u64] {
+        &self.unknown_failures
+    }
+
+    pub fn is_identical(&self) -> bool {
+        self.divergences.is_empty() && self.unknown_failures.is_empty()
+    }
+}
+impl Display for ReplayReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} tick(s) replayed: {} divergence(s), {} unknown failure(s)",
+            self.ticks,
+            self.divergences.len(),
+            self.unknown_failures.len()
+        )?;
+        for divergence in &self.divergences {
+            write!(f, "\n  {}", divergence)?;
+        }
+        for identifier in &self.unknown_failures {
+            write!(f, "\n  unknown failure {}", identifier)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct ReplayVariableRegistry {
+    name_to_identifier: FxHashMap<String, VariableIdentifier>,
+    next_identifier: VariableIdentifier,
+}
+impl VariableRegistry for ReplayVariableRegistry {
+    fn get(&mut self, name: String) -> VariableIdentifier {
+        *self.name_to_identifier.entry(name).or_insert_with(|| {
+            let identifier = self.next_identifier;
+            self.next_identifier = identifier.next();
+
+            identifier
+        })
+    }
+}
+
+/// Feeds the recorded reads to the simulation and keeps the values it writes.
+struct ReplayReaderWriter {
+    indices: FxHashMap<VariableIdentifier, u32>,
+    reads: Vec<f64>,
+    written: BTreeMap<u32, f64>,
+}
+impl SimulatorReaderWriter for ReplayReaderWriter {
+    fn read(&mut self, identifier: &VariableIdentifier) -> f64 {
+        self.indices
+            .get(identifier)
+            .map_or(0., |&index| self.reads[index as usize])
+    }
+
+    fn write(&mut self, identifier: &VariableIdentifier, value: f64) {
+        if let Some(&index) = self.indices.get(identifier) {
+            self.written.insert(index, value);
+        }
+    }
+}
+
+/// Runs the aircraft created by `aircraft_ctor_fn` through the given recording and compares the
+/// values it writes with the recorded values. Values within `tolerance` of each other are
+/// considered equal, as are two NaN values.
+///
+/// Failures are mapped from the recorded identifiers using `failures`, which should be the
+/// same list the simulator was configured with. The [`Recording::configuration`] has to be
+/// loaded before calling this function, as the aircraft is constructed by it.
+pub fn replay<T: Aircraft, U: FnOnce(&mut InitContext) -> T>(
+    recording: &Recording,
+    aircraft_ctor_fn: U,
+    failures: &[(u64, FailureType)],
+    tolerance: f64,
+) -> ReplayReport {
+    seed_random(recording.seed);
+
+    let mut registry = ReplayVariableRegistry::default();
+    let mut simulation = Simulation::new(recording.start_state, aircraft_ctor_fn, &mut registry);
+
+    let mut reader_writer = ReplayReaderWriter {
+        indices: recording
+            .variables
+            .iter()
+            .enumerate()
+            .filter_map(|(index, name)| {
+                registry
+                    .name_to_identifier
+                    .get(name)
+                    .map(|identifier| (*identifier, index as u32))
+            })
+            .collect(),
+        reads: vec![0.; recording.variables.len()],
+        written: BTreeMap::new(),
+    };
+
+    let mut recorded_writes = vec![None; recording.variables.len()];
+    let mut report = ReplayReport::default();
+    for (tick_index, tick) in recording.ticks.iter().enumerate() {
+        for &(identifier, is_active) in &tick.failures {
+            match failures.iter().find(|(id, _)| *id == identifier) {
+                Some((_, failure_type)) if is_active => simulation.activate_failure(*failure_type),
+                Some((_, failure_type)) => simulation.deactivate_failure(*failure_type),
+                None => report.unknown_failures.push(identifier),
+            }
+        }
+
+        for &(index, value) in &tick.reads {
+            reader_writer.reads[index as usize] = value;
+        }
+        for &(index, value) in &tick.writes {
+            recorded_writes[index as usize] = Some(value);
+        }
+
+        simulation.tick(tick.delta, tick.simulation_time, &mut reader_writer);
+
+        for (&index, &replayed) in &reader_writer.written {
+            let recorded = recorded_writes[index as usize].unwrap_or(f64::NAN);
+            let is_equal = (recorded.is_nan() && replayed.is_nan())
+                || (recorded - replayed).abs() <= tolerance;
+            if !is_equal {
+                report.divergences.push(Divergence {
+                    tick: tick_index,
+                    variable: recording.variables[index as usize].clone(),
+                    recorded,
+                    replayed,
+                });
+            }
+        }
+
+        report.ticks += 1;
+    }
+
+    report.unknown_failures.sort_unstable();
+    report.unknown_failures.dedup();
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        failures::Failure,
+        shared::random_from_range,
+        simulation::{
+            test::{SimulationTestBed, TestBed, WriteByName},
+            Read as _, SimulationElement, SimulationElementVisitor, SimulatorReader,
+            SimulatorWriter, UpdateContext, Write as _,
+        },
+    };
+    use std::{env, fs, process};
+
+    const FAILURES: [(u64, FailureType); 1] = [(24_000, FailureType::Generator(1))];
+    const CONFIGURATION: &str = "gain = \"configured\"";
+
+    struct NoisyIntegrator {
+        input_id: VariableIdentifier,
+        output_id: VariableIdentifier,
+
+        gain: f64,
+        input: f64,
+        output: f64,
+        failure: Failure,
+    }
+    impl NoisyIntegrator {
+        fn new(context: &mut InitContext, gain: f64) -> Self {
+            Self {
+                input_id: context.get_identifier("INPUT".to_owned()),
+                output_id: context.get_identifier("OUTPUT".to_owned()),
+                gain,
+                input: 0.,
+                output: random_from_range(0., 1.),
+                failure: Failure::new(FailureType::Generator(1)),
+            }
+        }
+    }
+    impl Aircraft for NoisyIntegrator {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.output = if self.failure.is_active() {
+                0.
+            } else {
+                self.output
+                    + self.gain * self.input * context.delta_as_secs_f64()
+                    + random_from_range(-0.1, 0.1)
+            };
+        }
+    }
+    impl SimulationElement for NoisyIntegrator {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.failure.accept(visitor);
+
+            visitor.visit(self);
+        }
+
+        fn read(&mut self, reader: &mut SimulatorReader) {
+            self.input = reader.read(&self.input_id);
+        }
+
+        fn write(&self, writer: &mut SimulatorWriter) {
+            writer.write(&self.output_id, self.output);
+        }
+    }
+
+    fn record(gain: f64, fail_after: Option<usize>) -> Recording {
+        let mut test_bed =
+            SimulationTestBed::new_recorded(42, CONFIGURATION, &FAILURES, |context| {
+                NoisyIntegrator::new(context, gain)
+            });
+
+        for tick in 0..20 {
+            if Some(tick) == fail_after {
+                test_bed.fail(FailureType::Generator(1));
+            }
+
+            test_bed.write_by_name("INPUT", (tick % 5) as f64);
+            test_bed.run_with_delta(Duration::from_millis(50 + tick as u64));
+        }
+
+        test_bed.finish_recording()
+    }
+
+    #[test]
+    fn recording_contains_header_and_every_tick() {
+        let recording = record(1., None);
+
+        assert_eq!(recording.seed(), 42);
+        assert_eq!(recording.start_state(), StartState::default());
+        assert_eq!(recording.configuration(), CONFIGURATION);
+        assert_eq!(recording.tick_count(), 20);
+    }
+
+    #[test]
+    fn replaying_the_same_aircraft_is_identical() {
+        let recording = record(1., None);
+
+        let report = replay(
+            &recording,
+            |context| NoisyIntegrator::new(context, 1.),
+            &FAILURES,
+            0.,
+        );
+
+        assert_eq!(report.ticks(), 20);
+        assert!(report.is_identical());
+    }
+
+    #[test]
+    fn replaying_a_changed_aircraft_reports_divergences() {
+        let recording = record(1., None);
+
+        let report = replay(
+            &recording,
+            |context| NoisyIntegrator::new(context, 2.),
+            &FAILURES,
+            0.,
+        );
+
+        assert!(!report.is_identical());
+        let divergence = &report.divergences()[0];
+        assert_eq!(divergence.variable, "OUTPUT");
+        assert!((divergence.recorded - divergence.replayed).abs() > 0.);
+    }
+
+    #[test]
+    fn divergences_within_tolerance_are_ignored() {
+        let recording = record(1., None);
+
+        let report = replay(
+            &recording,
+            |context| NoisyIntegrator::new(context, 1.0001),
+            &FAILURES,
+            0.1,
+        );
+
+        assert!(report.is_identical());
+    }
+
+    #[test]
+    fn failures_are_replayed() {
+        let recording = record(1., Some(10));
+
+        let report = replay(
+            &recording,
+            |context| NoisyIntegrator::new(context, 1.),
+            &FAILURES,
+            0.,
+        );
+
+        assert!(report.is_identical());
+    }
+
+    #[test]
+    fn unknown_failures_are_reported() {
+        let recording = record(1., Some(10));
+
+        let report = replay(
+            &recording,
+            |context| NoisyIntegrator::new(context, 1.),
+            &[],
+            0.,
+        );
+
+        assert_eq!(report.unknown_failures(), &[24_000]);
+        assert!(!report.divergences().is_empty());
+    }
+
+    #[test]
+    fn report_lists_divergences_and_unknown_failures() {
+        let recording = record(1., Some(10));
+
+        let report = replay(
+            &recording,
+            |context| NoisyIntegrator::new(context, 2.),
+            &[],
+            0.,
+        )
+        .to_string();
+
+        assert!(report.starts_with("20 tick(s) replayed: "));
+        assert!(report.contains(": OUTPUT was "));
+        assert!(report.ends_with("\n  unknown failure 24000"));
+    }
+
+    #[test]
+    fn recording_is_replayed_from_a_file() {
+        let path = env::temp_dir().join(format!("systems-{}.rec", process::id()));
+        let mut recorder = Recorder::new(
+            File::create(&path).unwrap(),
+            1,
+            StartState::Apron,
+            CONFIGURATION,
+            vec![("INPUT".to_owned(), VariableIdentifier::default())],
+        )
+        .unwrap();
+        recorder.finish_tick(Duration::from_millis(10), 1.).unwrap();
+        recorder.finish_tick(Duration::from_millis(10), 2.).unwrap();
+        recorder.into_inner().unwrap();
+
+        let recording = Recording::read_file(&path);
+        fs::remove_file(&path).unwrap();
+        let recording = recording.unwrap();
+        let report = replay(
+            &recording,
+            |context| NoisyIntegrator::new(context, 1.),
+            &[],
+            0.,
+        );
+
+        assert_eq!(recording.configuration(), CONFIGURATION);
+        assert_eq!(report.ticks(), 2);
+    }
+
+    #[test]
+    fn reading_a_missing_file_fails() {
+        let recording = Recording::read_file(env::temp_dir().join("missing-systems.rec"));
+
+        assert!(matches!(recording, Err(RecordingError::Io(_))));
+    }
+
+    #[test]
+    fn truncated_recording_is_read_up_to_the_last_complete_tick() {
+        let mut recorder = Recorder::new(
+            Vec::new(),
+            1,
+            StartState::Apron,
+            CONFIGURATION,
+            vec![("A".to_owned(), VariableIdentifier::default())],
+        )
+        .unwrap();
+        recorder.finish_tick(Duration::from_millis(10), 1.).unwrap();
+        recorder.finish_tick(Duration::from_millis(10), 2.).unwrap();
+        let mut bytes = recorder.into_inner().unwrap();
+        bytes.truncate(bytes.len() - 3);
+
+        let recording = Recording::read_from(bytes.as_slice()).unwrap();
+
+        assert_eq!(recording.start_state(), StartState::Apron);
+        assert_eq!(recording.tick_count(), 1);
+    }
+
+    #[test]
+    fn only_changed_values_are_recorded() {
+        struct Constant(f64);
+        impl SimulatorReaderWriter for Constant {
+            fn read(&mut self, _: &VariableIdentifier) -> f64 {
+                self.0
+            }
+
+            fn write(&mut self, _: &VariableIdentifier, _: f64) {}
+        }
+
+        let identifier = VariableIdentifier::default();
+        let mut recorder = Recorder::new(
+            Vec::new(),
+            1,
+            StartState::Apron,
+            CONFIGURATION,
+            vec![("A".to_owned(), identifier)],
+        )
+        .unwrap();
+        let mut inner = Constant(3.);
+        for _ in 0..10 {
+            recorder.reader_writer(&mut inner).read(&identifier);
+            recorder.finish_tick(Duration::from_millis(10), 1.).unwrap();
+        }
+
+        let recording = Recording::read_from(recorder.into_inner().unwrap().as_slice()).unwrap();
+
+        assert_eq!(recording.tick_count(), 10);
+        assert_eq!(recording.ticks[0].reads, vec![(0, 3.)]);
+        assert!(recording.ticks[1..]
+            .iter()
+            .all(|tick| tick.reads.is_empty()));
+    }
+
+    #[test]
+    fn other_files_are_rejected() {
+        assert!(matches!(
+            Recording::read_from(b"NOTREC\x01\x00".as_slice()),
+            Err(RecordingError::InvalidMagic)
+        ));
+    }
+
+    #[test]
+    fn recordings_without_aircraft_configuration_are_rejected() {
+        assert!(matches!(
+            Recording::read_from(b"FBWREC\x01\x00".as_slice()),
+            Err(RecordingError::UnsupportedVersion(1))
+        ));
+    }
+}
@@ -0,0 +1,588 @@
+//! Declarative scenarios which drive an [`Aircraft`] through a [`SimulationTestBed`].
+//!
+//! A scenario is written in TOML and consists of steps which are executed in order:
+//!
+//! ```toml
+//! name = "Engine 1 fire"
+//! on_ground = true
+//!
+//! [[step]]
+//! fail = 26001
+//!
+//! [[step]]
+//! run = 1.5
+//!
+//! [[step]]
+//! set = { variable = "FIRE_BUTTON_ENG1", value = true }
+//!
+//! [[step]]
+//! run = 1
+//!
+//! [[step]]
+//! assert = { variable = "FIRE_SQUIB_1_ENG_1_IS_ARMED", value = true }
+//! ```
+//!
+//! - `set` writes a value to a variable read by the aircraft.
+//! - `fail` and `unfail` (de)activate a failure using the identifier shown in the failures UI.
+//! - `run` advances the simulation by the given number of seconds, in frames of
+//!   `frame_duration` seconds (0.1 by default).
+//! - `assert` checks that a variable is within `tolerance` (0 by default) of the given value.
+//!
+//! Values are either numbers or booleans, where a boolean is written as 1 or 0.
+//! A failed assertion doesn't stop the scenario, all failed assertions are found in the
+//! [`ScenarioReport`].
+
+use std::{
+    fmt::{self, Display},
+    time::Duration,
+};
+
+use serde::Deserialize;
+
+use crate::{failures::FailureType, shared::from_bool};
+
+use super::{
+    test::{SimulationTestBed, TestBed},
+    Aircraft, InitContext, Reader, VariableIdentifier, Writer,
+};
+
+#[derive(Debug, PartialEq)]
+pub enum ScenarioError {
+    Parse(String),
+    InvalidFrameDuration(f64),
+    InvalidRunDuration { step: usize, seconds: f64 },
+    UnknownFailure { step: usize, identifier: u64 },
+    UnknownVariable { step: usize, name: String },
+}
+impl Display for ScenarioError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScenarioError::Parse(error) => write!(f, "failed to parse scenario: {}", error),
+            ScenarioError::InvalidFrameDuration(seconds) => {
+                write!(f, "frame duration of {} seconds is not valid", seconds)
+            }
+            ScenarioError::InvalidRunDuration { step, seconds } => write!(
+                f,
+                "step {} runs for {} seconds, which is not valid",
+                step, seconds
+            ),
+            ScenarioError::UnknownFailure { step, identifier } => {
+                write!(f, "step {} refers to unknown failure {}", step, identifier)
+            }
+            ScenarioError::UnknownVariable { step, name } => write!(
+                f,
+                "step {} refers to variable {} which the aircraft doesn't use",
+                step, name
+            ),
+        }
+    }
+}
+impl std::error::Error for ScenarioError {}
+
+#[derive(Clone, Copy, Debug, Deserialize)]
+#[serde(untagged)]
+enum ScenarioValue {
+    Bool(bool),
+    Number(f64),
+}
+impl From<ScenarioValue> for f64 {
+    fn from(value: ScenarioValue) -> Self {
+        match value {
+            ScenarioValue::Bool(value) => from_bool(value),
+            ScenarioValue::Number(value) => value,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SetVariable {
+    variable: String,
+    value: ScenarioValue,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Assertion {
+    variable: String,
+    value: ScenarioValue,
+    #[serde(default)]
+    tolerance: f64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum Step {
+    Set(SetVariable),
+    Fail(u64),
+    Unfail(u64),
+    Run(f64),
+    Assert(Assertion),
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Scenario {
+    name: String,
+    #[serde(default)]
+    on_ground: bool,
+    #[serde(default = "Scenario::default_frame_duration")]
+    frame_duration: f64,
+    #[serde(default, rename = "step")]
+    steps: Vec<Step>,
+}
+impl Scenario {
+    fn default_frame_duration() -> f64 {
+        0.1
+    }
+
+    pub fn from_toml(scenario: &str) -> Result<Self, ScenarioError> {
+        let scenario: Self =
+            toml::from_str(scenario).map_err(|e| ScenarioError::Parse(e.to_string()))?;
+        scenario.validate()?;
+
+        Ok(scenario)
+    }
+
+    fn validate(&self) -> Result<(), ScenarioError> {
+        if !self.frame_duration.is_finite() || self.frame_duration <= 0. {
+            return Err(ScenarioError::InvalidFrameDuration(self.frame_duration));
+        }
+
+        for (step, seconds) in self
+            .steps
+            .iter()
+            .enumerate()
+            .filter_map(|(index, s)| match s {
+                Step::Run(seconds) => Some((index + 1, *seconds)),
+                _ => None,
+            })
+        {
+            if !seconds.is_finite() || seconds < 0. {
+                return Err(ScenarioError::InvalidRunDuration { step, seconds });
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+/// An assertion which didn't hold. Steps are numbered from 1 in the order they are written.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FailedAssertion {
+    step: usize,
+    time: Duration,
+    variable: String,
+    expected: f64,
+    actual: f64,
+    tolerance: f64,
+}
+impl FailedAssertion {
+    pub fn step(&self) -> usize {
+        self.step
+    }
+
+    pub fn variable(&self) -> &str {
+        &self.variable
+    }
+
+    pub fn actual(&self) -> f64 {
+        self.actual
+    }
+}
+impl Display for FailedAssertion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "step {} at {:.2}s: expected {} to be {} ± {}, but was {}",
+            self.step,
+            self.time.as_secs_f64(),
+            self.variable,
+            self.expected,
+            self.tolerance,
+            self.actual
+        )
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct ScenarioReport {
+    name: String,
+    elapsed: Duration,
+    failed_assertions: Vec<FailedAssertion>,
+}
+impl ScenarioReport {
+    pub fn is_successful(&self) -> bool {
+        self.failed_assertions.is_empty()
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    pub fn failed_assertions(&self) -> &[FailedAssertion] {
+        &self.failed_assertions
+    }
+}
+impl Display for ScenarioReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "scenario \"{}\": {} failed assertion(s)",
+            self.name,
+            self.failed_assertions.len()
+        )?;
+        for assertion in &self.failed_assertions {
+            write!(f, "\n  {}", assertion)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A step with its variables and failures resolved against the aircraft under test.
+enum ResolvedStep<'a> {
+    Set(VariableIdentifier, f64),
+    Fail(FailureType),
+    Unfail(FailureType),
+    Run(Duration),
+    Assert(VariableIdentifier, &'a Assertion),
+}
+
+/// Runs the given scenario against the aircraft created by `aircraft_ctor_fn`.
+///
+/// Failures are mapped from their identifiers using `failures`, which should be the same list
+/// the simulator is configured with. Every step is resolved before the scenario starts, so a
+/// scenario which refers to an unknown failure or variable doesn't run at all.
+pub fn run_scenario<T: Aircraft, U: FnOnce(&mut InitContext) -> T>(
+    scenario: &Scenario,
+    aircraft_ctor_fn: U,
+    failures: &[(u64, FailureType)],
+) -> Result<ScenarioReport, ScenarioError> {
+    let mut test_bed = SimulationTestBed::new(aircraft_ctor_fn);
+    let steps = resolve(&scenario.steps, &mut test_bed, failures)?;
+
+    if scenario.on_ground {
+        test_bed.set_on_ground(true);
+    }
+
+    let frame_duration = Duration::from_secs_f64(scenario.frame_duration);
+    let mut report = ScenarioReport {
+        name: scenario.name.clone(),
+        ..Default::default()
+    };
+    for (index, step) in steps.into_iter().enumerate() {
+        match step {
+            ResolvedStep::Set(identifier, value) => test_bed.write_f64(&identifier, value),
+            ResolvedStep::Fail(failure_type) => test_bed.fail(failure_type),
+            ResolvedStep::Unfail(failure_type) => test_bed.unfail(failure_type),
+            ResolvedStep::Run(duration) => {
+                let mut remaining = duration;
+                while !remaining.is_zero() {
+                    let delta = remaining.min(frame_duration);
+                    test_bed.run_with_delta(delta);
+                    remaining -= delta;
+                    report.elapsed += delta;
+                }
+            }
+            ResolvedStep::Assert(identifier, assertion) => {
+                let expected = f64::from(assertion.value);
+                let actual = test_bed.read_f64(&identifier);
+                if (actual - expected).abs() > assertion.tolerance || actual.is_nan() {
+                    report.failed_assertions.push(FailedAssertion {
+                        step: index + 1,
+                        time: report.elapsed,
+                        variable: assertion.variable.clone(),
+                        expected,
+                        actual,
+                        tolerance: assertion.tolerance,
+                    });
+                }
+            }
+        }
+    }
+
+    Ok(report)
+}
+
+fn resolve<'a, T: Aircraft>(
+    steps: &'a [Step],
+    test_bed: &mut SimulationTestBed<T>,
+    failures: &[(u64, FailureType)],
+) -> Result<Vec<ResolvedStep<'a>>, ScenarioError> {
+    let mut identifier = |step: usize, name: &str| {
+        test_bed
+            .get_variable_identifier(name)
+            .copied()
+            .ok_or_else(|| ScenarioError::UnknownVariable {
+                step,
+                name: name.to_owned(),
+            })
+    };
+    let failure = |step: usize, identifier: u64| {
+        failures
+            .iter()
+            .find(|(id, _)| *id == identifier)
+            .map(|(_, failure_type)| *failure_type)
+            .ok_or(ScenarioError::UnknownFailure { step, identifier })
+    };
+
+    steps
+        .iter()
+        .enumerate()
+        .map(|(index, step)| {
+            let step_number = index + 1;
+            Ok(match step {
+                Step::Set(set) => ResolvedStep::Set(
+                    identifier(step_number, &set.variable)?,
+                    f64::from(set.value),
+                ),
+                Step::Fail(id) => ResolvedStep::Fail(failure(step_number, *id)?),
+                Step::Unfail(id) => ResolvedStep::Unfail(failure(step_number, *id)?),
+                Step::Run(seconds) => ResolvedStep::Run(Duration::from_secs_f64(*seconds)),
+                Step::Assert(assertion) => {
+                    ResolvedStep::Assert(identifier(step_number, &assertion.variable)?, assertion)
+                }
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        failures::Failure,
+        simulation::{
+            Read as _, SimulationElement, SimulationElementVisitor, SimulatorReader,
+            SimulatorWriter, UpdateContext, Write as _,
+        },
+    };
+
+    const FAILURES: [(u64, FailureType); 1] = [(24_000, FailureType::Generator(1))];
+
+    struct Integrator {
+        input_id: VariableIdentifier,
+        output_id: VariableIdentifier,
+
+        input: f64,
+        output: f64,
+        failure: Failure,
+    }
+    impl Integrator {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                input_id: context.get_identifier("INPUT".to_owned()),
+                output_id: context.get_identifier("OUTPUT".to_owned()),
+                input: 0.,
+                output: 0.,
+                failure: Failure::new(FailureType::Generator(1)),
+            }
+        }
+    }
+    impl Aircraft for Integrator {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.output = if self.failure.is_active() {
+                0.
+            } else {
+                self.output + self.input * context.delta_as_secs_f64()
+            };
+        }
+    }
+    impl SimulationElement for Integrator {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.failure.accept(visitor);
+
+            visitor.visit(self);
+        }
+
+        fn read(&mut self, reader: &mut SimulatorReader) {
+            self.input = reader.read(&self.input_id);
+        }
+
+        fn write(&self, writer: &mut SimulatorWriter) {
+            writer.write(&self.output_id, self.output);
+        }
+    }
+
+    fn run(scenario: &str) -> Result<ScenarioReport, ScenarioError> {
+        run_scenario(&Scenario::from_toml(scenario)?, Integrator::new, &FAILURES)
+    }
+
+    #[test]
+    fn scenario_which_holds_is_successful() {
+        let report = run(r#"
+            name = "Integrates input"
+
+            [[step]]
+            set = { variable = "INPUT", value = 2 }
+
+            [[step]]
+            run = 1.5
+
+            [[step]]
+            assert = { variable = "OUTPUT", value = 3, tolerance = 0.001 }
+        "#)
+        .unwrap();
+
+        assert!(report.is_successful(), "{}", report);
+        assert_eq!(report.elapsed(), Duration::from_millis(1500));
+    }
+
+    #[test]
+    fn failed_assertions_are_reported_and_the_scenario_continues() {
+        let report = run(r#"
+            name = "Wrong expectations"
+
+            [[step]]
+            set = { variable = "INPUT", value = true }
+
+            [[step]]
+            run = 1
+
+            [[step]]
+            assert = { variable = "OUTPUT", value = 5 }
+
+            [[step]]
+            run = 1
+
+            [[step]]
+            assert = { variable = "OUTPUT", value = 2, tolerance = 0.001 }
+
+            [[step]]
+            assert = { variable = "OUTPUT", value = false }
+        "#)
+        .unwrap();
+
+        assert!(!report.is_successful());
+        assert_eq!(report.failed_assertions().len(), 2);
+        assert_eq!(report.failed_assertions()[0].step(), 3);
+        assert_eq!(report.failed_assertions()[1].step(), 6);
+        assert!((report.failed_assertions()[0].actual() - 1.).abs() < 0.001);
+    }
+
+    #[test]
+    fn failures_are_activated_and_deactivated_by_identifier() {
+        let report = run(r#"
+            name = "Failure resets output"
+
+            [[step]]
+            set = { variable = "INPUT", value = 1 }
+
+            [[step]]
+            run = 1
+
+            [[step]]
+            fail = 24000
+
+            [[step]]
+            run = 0.1
+
+            [[step]]
+            assert = { variable = "OUTPUT", value = 0 }
+
+            [[step]]
+            unfail = 24000
+
+            [[step]]
+            run = 1
+
+            [[step]]
+            assert = { variable = "OUTPUT", value = 1, tolerance = 0.001 }
+        "#)
+        .unwrap();
+
+        assert!(report.is_successful(), "{}", report);
+    }
+
+    #[test]
+    fn run_is_split_into_frames() {
+        let report = run(r#"
+            name = "Frames"
+            frame_duration = 0.25
+
+            [[step]]
+            run = 0.625
+        "#)
+        .unwrap();
+
+        assert_eq!(report.elapsed(), Duration::from_millis(625));
+    }
+
+    #[test]
+    fn unknown_failure_is_an_error() {
+        let error = run(r#"
+            name = "Unknown failure"
+
+            [[step]]
+            run = 1
+
+            [[step]]
+            fail = 99999
+        "#)
+        .unwrap_err();
+
+        assert_eq!(
+            error,
+            ScenarioError::UnknownFailure {
+                step: 2,
+                identifier: 99999
+            }
+        );
+    }
+
+    #[test]
+    fn unknown_variable_is_an_error() {
+        let error = run(r#"
+            name = "Unknown variable"
+
+            [[step]]
+            assert = { variable = "DOES_NOT_EXIST", value = 0 }
+        "#)
+        .unwrap_err();
+
+        assert_eq!(
+            error,
+            ScenarioError::UnknownVariable {
+                step: 1,
+                name: "DOES_NOT_EXIST".to_owned()
+            }
+        );
+    }
+
+    #[test]
+    fn unknown_step_is_a_parse_error() {
+        let error = run(r#"
+            name = "Unknown step"
+
+            [[step]]
+            wait = 1
+        "#)
+        .unwrap_err();
+
+        assert!(matches!(error, ScenarioError::Parse(_)));
+    }
+
+    #[test]
+    fn negative_run_duration_is_an_error() {
+        let error = run(r#"
+            name = "Negative run"
+
+            [[step]]
+            run = -1
+        "#)
+        .unwrap_err();
+
+        assert_eq!(
+            error,
+            ScenarioError::InvalidRunDuration {
+                step: 1,
+                seconds: -1.
+            }
+        );
+    }
+}
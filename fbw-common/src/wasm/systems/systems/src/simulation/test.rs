@@ -14,7 +14,11 @@ use uom::si::{
 
 use crate::{
     electrical::{Electricity, Potential},
-    failures::FailureType,
+    failures::{
+        cascade::{ActiveFailure, FailureDependency},
+        scheduler::FailureTrigger,
+        FailureType,
+    },
 };
 
 use super::{
@@ -24,6 +28,9 @@ use super::{
 };
 use crate::landing_gear::LandingGear;
 use crate::shared::arinc429::{from_arinc429, to_arinc429, Arinc429Word, SignStatus};
+use crate::shared::seed_random;
+use crate::simulation::probe::Probes;
+use crate::simulation::recording::{Recorder, Recording};
 use crate::simulation::update_context::Delta;
 use crate::simulation::{
     DeltaContext, InitContext, StartState, VariableIdentifier, VariableRegistry,
@@ -55,6 +62,26 @@ pub trait TestBed {
         self.test_bed_mut().unfail(failure_type);
     }
 
+    fn arm_failure(&mut self, failure_type: FailureType, trigger: FailureTrigger) {
+        self.test_bed_mut().arm_failure(failure_type, trigger);
+    }
+
+    fn disarm_failure(&mut self, failure_type: FailureType) {
+        self.test_bed_mut().disarm_failure(failure_type);
+    }
+
+    fn is_failure_armed(&self, failure_type: FailureType) -> bool {
+        self.test_bed().is_failure_armed(failure_type)
+    }
+
+    fn active_failures(&self) -> &[ActiveFailure] {
+        self.test_bed().active_failures()
+    }
+
+    fn root_cause_of(&self, failure_type: FailureType) -> Option<FailureType> {
+        self.test_bed().root_cause_of(failure_type)
+    }
+
     fn command<V: FnOnce(&mut Self::Aircraft)>(&mut self, func: V) {
         self.test_bed_mut().command(func);
     }
@@ -148,6 +175,18 @@ pub trait TestBed {
     fn get_variable_identifier(&mut self, name: &str) -> Option<&VariableIdentifier> {
         self.test_bed_mut().get_variable_identifier(name)
     }
+
+    fn probe_variable(&mut self, name: &str) {
+        self.test_bed_mut().probe_variable(name);
+    }
+
+    fn probe<V: Fn(&Self::Aircraft) -> f64 + 'static>(&mut self, name: &str, func: V) {
+        self.test_bed_mut().probe(name, func);
+    }
+
+    fn probes(&self) -> &Probes<Self::Aircraft> {
+        self.test_bed().probes()
+    }
 }
 impl<T: TestBed> Writer for T {
     fn write_f64(&mut self, identifier: &VariableIdentifier, value: f64) {
@@ -237,6 +276,9 @@ pub struct SimulationTestBed<T: Aircraft> {
     reader_writer: TestReaderWriter,
     simulation: Simulation<T>,
     variable_registry: TestVariableRegistry,
+    recorder: Option<Recorder<Vec<u8>>>,
+    failure_identifiers: Vec<(u64, FailureType)>,
+    probes: Probes<T>,
 }
 impl<T: Aircraft> SimulationTestBed<T> {
     pub fn new<U: FnOnce(&mut InitContext) -> T>(aircraft_ctor_fn: U) -> Self {
@@ -252,6 +294,9 @@ impl<T: Aircraft> SimulationTestBed<T> {
             reader_writer: TestReaderWriter::new(),
             simulation: Simulation::new(start_state, aircraft_ctor_fn, &mut variable_registry),
             variable_registry,
+            recorder: None,
+            failure_identifiers: Vec::new(),
+            probes: Probes::new(),
         };
 
         test_bed.set_indicated_airspeed(Velocity::new::<knot>(250.));
@@ -266,6 +311,54 @@ impl<T: Aircraft> SimulationTestBed<T> {
         test_bed
     }
 
+    /// Creates an instance which records every tick, so the run can be replayed with
+    /// [`replay`]. The `configuration` is stored as the aircraft configuration the aircraft is
+    /// built from. Failures are recorded using the identifiers in `failures`.
+    ///
+    /// [`replay`]: ../recording/fn.replay.html
+    pub fn new_recorded<U: FnOnce(&mut InitContext) -> T>(
+        seed: u64,
+        configuration: &str,
+        failures: &[(u64, FailureType)],
+        aircraft_ctor_fn: U,
+    ) -> Self {
+        seed_random(seed);
+
+        let start_state = StartState::default();
+        let mut test_bed = Self::new_with_start_state(start_state, aircraft_ctor_fn);
+        test_bed.recorder = Some(
+            Recorder::new(
+                Vec::new(),
+                seed,
+                start_state,
+                configuration,
+                test_bed
+                    .variable_registry
+                    .name_to_identifier
+                    .iter()
+                    .map(|(name, identifier)| (name.clone(), *identifier)),
+            )
+            .unwrap(),
+        );
+        test_bed.failure_identifiers = failures.to_vec();
+
+        test_bed
+    }
+
+    /// Sets the dependencies through which the activation of one failure can lead to others.
+    pub fn with_failure_dependencies(mut self, dependencies: &[FailureDependency]) -> Self {
+        self.simulation.set_failure_dependencies(dependencies);
+
+        self
+    }
+
+    /// Stops recording and returns everything recorded so far.
+    pub fn finish_recording(&mut self) -> Recording {
+        let recorder = self.recorder.take().expect("The test bed is not recording");
+
+        Recording::read_from(recorder.into_inner().unwrap().as_slice()).unwrap()
+    }
+
     /// Creates an instance seeded with the starting state found in the given element.
     ///
     /// By default an unseeded simulation will return 0.0 or false for any requested
@@ -288,7 +381,61 @@ impl<T: Aircraft> SimulationTestBed<T> {
     }
 
     pub fn run_with_delta(&mut self, delta: Duration) {
-        self.simulation.tick(delta, 100., &mut self.reader_writer);
+        self.tick(delta, 100.);
+    }
+
+    fn tick(&mut self, delta: Duration, simulation_time: f64) {
+        if self.probes.awaits_initial_sample() {
+            self.sample_probes(Duration::ZERO);
+        }
+
+        match self.recorder.as_mut() {
+            Some(recorder) => {
+                self.simulation.tick(
+                    delta,
+                    simulation_time,
+                    &mut recorder.reader_writer(&mut self.reader_writer),
+                );
+                recorder.finish_tick(delta, simulation_time).unwrap();
+            }
+            None => self
+                .simulation
+                .tick(delta, simulation_time, &mut self.reader_writer),
+        }
+
+        self.record_induced_failures();
+        self.sample_probes(delta);
+    }
+
+    fn sample_probes(&mut self, delta: Duration) {
+        let reader_writer = &mut self.reader_writer;
+        self.probes
+            .sample(delta, self.simulation.aircraft(), |identifier| {
+                reader_writer.read_f64(identifier)
+            });
+    }
+
+    /// Samples the named variable after every tick. See [`probes`].
+    ///
+    /// [`probes`]: #method.probes
+    fn probe_variable(&mut self, name: &str) {
+        let identifier = *self
+            .variable_registry
+            .find(name)
+            .unwrap_or_else(|| panic!("No variable named \"{}\" is registered", name));
+        self.probes.add_variable(name, identifier);
+    }
+
+    /// Samples the value returned by the given function after every tick. See [`probes`].
+    ///
+    /// [`probes`]: #method.probes
+    fn probe<U: Fn(&T) -> f64 + 'static>(&mut self, name: &str, func: U) {
+        self.probes.add_query(name, func);
+    }
+
+    /// The time series sampled by all probes, which can be exported as CSV or SVG.
+    fn probes(&self) -> &Probes<T> {
+        &self.probes
     }
 
     /// Runs a multiple [Simulation] ticks by subdividing given delta on the contained [Aircraft].
@@ -304,31 +451,68 @@ impl<T: Aircraft> SimulationTestBed<T> {
             let current_delta = Duration::from_millis(rng.gen_range(12..200));
 
             if executed_duration + current_delta > delta {
-                self.simulation.tick(
+                self.tick(
                     (executed_duration + current_delta) - delta,
                     10. + executed_duration.as_secs_f64(),
-                    &mut self.reader_writer,
                 );
                 break;
             } else {
-                self.simulation.tick(
-                    current_delta,
-                    10. + executed_duration.as_secs_f64(),
-                    &mut self.reader_writer,
-                );
+                self.tick(current_delta, 10. + executed_duration.as_secs_f64());
             }
             executed_duration += current_delta;
         }
     }
 
     fn fail(&mut self, failure_type: FailureType) {
+        self.record_failure(failure_type, true);
         self.simulation.activate_failure(failure_type);
+        self.record_induced_failures();
     }
 
     fn unfail(&mut self, failure_type: FailureType) {
+        self.record_failure(failure_type, false);
         self.simulation.deactivate_failure(failure_type);
     }
 
+    fn arm_failure(&mut self, failure_type: FailureType, trigger: FailureTrigger) {
+        self.simulation.arm_failure(failure_type, trigger);
+    }
+
+    fn disarm_failure(&mut self, failure_type: FailureType) {
+        self.simulation.disarm_failure(failure_type);
+    }
+
+    fn is_failure_armed(&self, failure_type: FailureType) -> bool {
+        self.simulation.is_failure_armed(failure_type)
+    }
+
+    fn active_failures(&self) -> &[ActiveFailure] {
+        self.simulation.active_failures()
+    }
+
+    fn root_cause_of(&self, failure_type: FailureType) -> Option<FailureType> {
+        self.simulation.root_cause_of(failure_type)
+    }
+
+    /// Failures activated by the simulation itself are recorded as if they were activated
+    /// before the next tick, which is when they take effect.
+    fn record_induced_failures(&mut self) {
+        for induced_failure in self.simulation.take_induced_failures() {
+            self.record_failure(induced_failure.failure_type(), true);
+        }
+    }
+
+    fn record_failure(&mut self, failure_type: FailureType, is_active: bool) {
+        if let Some(recorder) = self.recorder.as_mut() {
+            let (identifier, _) = self
+                .failure_identifiers
+                .iter()
+                .find(|(_, failure)| *failure == failure_type)
+                .expect("The failure has no identifier to record it with");
+            recorder.record_failure(*identifier, is_active);
+        }
+    }
+
     fn aircraft(&self) -> &T {
         self.simulation.aircraft()
     }
@@ -488,6 +672,10 @@ impl<T: SimulationElement> SimulationTestBed<TestAircraft<T>> {
         (func)(self.aircraft().element(), self.simulation.electricity())
     }
 
+    pub fn probe_element<V: Fn(&T) -> f64 + 'static>(&mut self, name: &str, func: V) {
+        self.probe(name, move |aircraft| (func)(aircraft.element()));
+    }
+
     pub fn with_update_before_power_distribution<
         U: Fn(&mut T, &UpdateContext, &mut Electricity) + 'static,
     >(
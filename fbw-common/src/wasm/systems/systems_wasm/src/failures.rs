@@ -4,20 +4,39 @@ use crate::msfs::legacy::NamedVariable;
 use msfs::legacy::NamedVariable;
 
 use fxhash::FxHashMap;
+use std::collections::VecDeque;
 
-use systems::failures::FailureType;
+use systems::failures::{scheduler::FailureTrigger, FailureType};
 
 pub(super) struct Failures {
     activate_sim_var: NamedVariable,
     deactivate_sim_var: NamedVariable,
+    arm_sim_var: NamedVariable,
+    arm_trigger_sim_var: NamedVariable,
+    arm_from_sim_var: NamedVariable,
+    arm_to_sim_var: NamedVariable,
+    disarm_sim_var: NamedVariable,
+    induced_sim_var: NamedVariable,
+    induced_cause_sim_var: NamedVariable,
     identifier_to_failure_type: FxHashMap<u64, FailureType>,
+    induced_failures: VecDeque<(u64, u64)>,
 }
 impl Failures {
-    pub(super) fn new(activate_sim_var: NamedVariable, deactivate_sim_var: NamedVariable) -> Self {
+    pub(super) fn new(key_prefix: &str) -> Self {
+        let variable = |name: &str| NamedVariable::from(&format!("{}{}", key_prefix, name));
+
         Self {
-            activate_sim_var,
-            deactivate_sim_var,
+            activate_sim_var: variable("FAILURE_ACTIVATE"),
+            deactivate_sim_var: variable("FAILURE_DEACTIVATE"),
+            arm_sim_var: variable("FAILURE_ARM"),
+            arm_trigger_sim_var: variable("FAILURE_ARM_TRIGGER"),
+            arm_from_sim_var: variable("FAILURE_ARM_FROM"),
+            arm_to_sim_var: variable("FAILURE_ARM_TO"),
+            disarm_sim_var: variable("FAILURE_DISARM"),
+            induced_sim_var: variable("FAILURE_INDUCED"),
+            induced_cause_sim_var: variable("FAILURE_INDUCED_CAUSE"),
             identifier_to_failure_type: FxHashMap::default(),
+            induced_failures: VecDeque::new(),
         }
     }
 
@@ -26,19 +45,66 @@ impl Failures {
             .insert(identifier, failure_type);
     }
 
-    pub(super) fn read_failure_activate(&self) -> Option<FailureType> {
+    pub(super) fn identifier_of(&self, failure_type: FailureType) -> Option<u64> {
+        self.identifier_to_failure_type
+            .iter()
+            .find(|(_, &candidate)| candidate == failure_type)
+            .map(|(&identifier, _)| identifier)
+    }
+
+    pub(super) fn read_failure_activate(&self) -> Option<(u64, FailureType)> {
         self.read_failure(&self.activate_sim_var)
     }
 
-    pub(super) fn read_failure_deactivate(&self) -> Option<FailureType> {
+    pub(super) fn read_failure_deactivate(&self) -> Option<(u64, FailureType)> {
         self.read_failure(&self.deactivate_sim_var)
     }
 
-    fn read_failure(&self, from: &NamedVariable) -> Option<FailureType> {
+    /// Reads a failure to arm, together with the trigger described by the trigger variables.
+    /// See [`FailureTrigger::decode`] for their meaning. A failure with an invalid trigger is
+    /// not armed.
+    pub(super) fn read_failure_arm(&self) -> Option<(FailureType, FailureTrigger)> {
+        let (_, failure_type) = self.read_failure(&self.arm_sim_var)?;
+
+        FailureTrigger::decode(
+            self.arm_trigger_sim_var.get_value(),
+            self.arm_from_sim_var.get_value(),
+            self.arm_to_sim_var.get_value(),
+        )
+        .map(|trigger| (failure_type, trigger))
+    }
+
+    pub(super) fn read_failure_disarm(&self) -> Option<FailureType> {
+        self.read_failure(&self.disarm_sim_var)
+            .map(|(_, failure_type)| failure_type)
+    }
+
+    /// Queues a failure activated by the simulation itself, to be written for the failures
+    /// orchestrator. The cause is the identifier of the failure it cascaded from, or 0 when it
+    /// was activated by the failure scheduler.
+    pub(super) fn queue_induced_failure(&mut self, identifier: u64, cause: u64) {
+        self.induced_failures.push_back((identifier, cause));
+    }
+
+    /// Writes the next induced failure once the orchestrator consumed the previous one, which it
+    /// signals by resetting the variable to 0. The cause is written first, as the orchestrator
+    /// reads it when it reads the failure.
+    pub(super) fn write_induced_failure(&mut self) {
+        let previous: f64 = self.induced_sim_var.get_value();
+        if previous == 0. {
+            if let Some((identifier, cause)) = self.induced_failures.pop_front() {
+                self.induced_cause_sim_var.set_value(cause as f64);
+                self.induced_sim_var.set_value(identifier as f64);
+            }
+        }
+    }
+
+    fn read_failure(&self, from: &NamedVariable) -> Option<(u64, FailureType)> {
         let identifier: f64 = from.get_value();
-        if let Some(failure_type) = self.identifier_to_failure_type.get(&(identifier as u64)) {
+        let identifier = identifier as u64;
+        if let Some(failure_type) = self.identifier_to_failure_type.get(&identifier) {
             from.set_value(0.);
-            Some(*failure_type)
+            Some((identifier, *failure_type))
         } else {
             None
         }
@@ -20,11 +20,17 @@ use ::msfs::{
 use failures::Failures;
 use fxhash::FxHashMap;
 use std::fmt::{Display, Formatter};
+use std::fs::File;
+use std::io::BufWriter;
 use std::{error::Error, time::Duration};
-use systems::shared::ElectricalBusType;
+use systems::shared::{reseed_random, ElectricalBusType};
+use systems::simulation::recording::Recorder;
 use systems::simulation::{InitContext, StartState};
 use systems::{
-    failures::FailureType,
+    failures::{
+        cascade::{FailureDependency, FailureOrigin},
+        FailureType,
+    },
     simulation::{
         Aircraft, Simulation, SimulatorReaderWriter, VariableIdentifier, VariableRegistry,
     },
@@ -38,7 +44,9 @@ pub struct MsfsSimulationBuilder<'a, 'b> {
     start_state: StartState,
     sim_connect: &'a mut SimConnect<'b>,
     failures: Option<Failures>,
+    failure_dependencies: Vec<FailureDependency>,
     aspects: Vec<Box<dyn Aspect>>,
+    recording: Option<(String, String)>,
 }
 
 impl<'a, 'b> MsfsSimulationBuilder<'a, 'b> {
@@ -55,7 +63,9 @@ impl<'a, 'b> MsfsSimulationBuilder<'a, 'b> {
             key_prefix: key_prefix.into(),
             sim_connect,
             failures: None,
+            failure_dependencies: vec![],
             aspects: vec![],
+            recording: None,
         }
     }
 
@@ -64,11 +74,35 @@ impl<'a, 'b> MsfsSimulationBuilder<'a, 'b> {
         aircraft_ctor_fn: U,
     ) -> Result<(Simulation<T>, MsfsHandler), Box<dyn Error>> {
         let mut registry = self.variable_registry.unwrap();
-        let simulation = Simulation::new(self.start_state, aircraft_ctor_fn, &mut registry);
+        // The seed has to be known before the aircraft is constructed, as constructing it
+        // can already consume random numbers.
+        let seed = self.recording.as_ref().map(|_| reseed_random());
+        let mut simulation = Simulation::new(self.start_state, aircraft_ctor_fn, &mut registry);
+        simulation.set_failure_dependencies(&self.failure_dependencies);
+
+        let recorder = match (self.recording, seed) {
+            (Some((path, configuration)), Some(seed)) => Some(Recorder::new(
+                BufWriter::new(File::create(path)?),
+                seed,
+                self.start_state,
+                &configuration,
+                registry
+                    .name_to_identifier
+                    .iter()
+                    .map(|(name, identifier)| (name.clone(), *identifier)),
+            )?),
+            _ => None,
+        };
 
         Ok((
             simulation,
-            MsfsHandler::new(registry, self.aspects, self.failures, self.sim_connect)?,
+            MsfsHandler::new(
+                registry,
+                self.aspects,
+                self.failures,
+                recorder,
+                self.sim_connect,
+            )?,
         ))
     }
 
@@ -120,10 +154,7 @@ impl<'a, 'b> MsfsSimulationBuilder<'a, 'b> {
     }
 
     pub fn with_failures(mut self, failures: Vec<(u64, FailureType)>) -> Self {
-        let mut f = Failures::new(
-            NamedVariable::from(&format!("{}{}", &self.key_prefix, "FAILURE_ACTIVATE")),
-            NamedVariable::from(&format!("{}{}", &self.key_prefix, "FAILURE_DEACTIVATE")),
-        );
+        let mut f = Failures::new(&self.key_prefix);
         for failure in failures {
             f.add(failure.0, failure.1);
         }
@@ -133,6 +164,32 @@ impl<'a, 'b> MsfsSimulationBuilder<'a, 'b> {
         self
     }
 
+    /// Sets the dependencies through which the activation of one failure can lead to others.
+    pub fn with_failure_dependencies(mut self, dependencies: &[FailureDependency]) -> Self {
+        self.failure_dependencies = dependencies.to_vec();
+
+        self
+    }
+
+    /// Records all values read and written by the simulation into the file at the given path,
+    /// when the given variable is set at the time the simulation is built. The `configuration`
+    /// is the aircraft configuration the aircraft is built from, which is stored in the recording.
+    /// The recording can be replayed outside of the simulator using
+    /// [`systems::simulation::recording::replay`].
+    pub fn with_recording(
+        mut self,
+        enabled_variable: Variable,
+        path: &str,
+        configuration: &str,
+    ) -> Self {
+        let enabled_variable_value: VariableValue = (&enabled_variable).into();
+        if enabled_variable_value.read() > 0. {
+            self.recording = Some((path.to_owned(), configuration.to_owned()));
+        }
+
+        self
+    }
+
     pub fn provides_aircraft_variable(
         mut self,
         name: &str,
@@ -164,6 +221,7 @@ pub struct MsfsHandler {
     variables: Option<MsfsVariableRegistry>,
     aspects: Vec<Box<dyn Aspect>>,
     failures: Option<Failures>,
+    recorder: Option<Recorder<BufWriter<File>>>,
     time: Time,
 }
 impl MsfsHandler {
@@ -171,12 +229,14 @@ impl MsfsHandler {
         variables: MsfsVariableRegistry,
         aspects: Vec<Box<dyn Aspect>>,
         failures: Option<Failures>,
+        recorder: Option<Recorder<BufWriter<File>>>,
         sim_connect: &mut SimConnect,
     ) -> Result<Self, Box<dyn Error>> {
         Ok(Self {
             variables: Some(variables),
             aspects,
             failures,
+            recorder,
             time: Time::new(sim_connect)?,
         })
     }
@@ -192,11 +252,27 @@ impl MsfsHandler {
                 if !self.time.is_pausing() {
                     let delta_time = self.time.take();
                     self.pre_tick(sim_connect, delta_time)?;
-                    if let Some(failures) = &self.failures {
-                        Self::read_failures_into_simulation(failures, simulation);
+                    if let Some(failures) = &mut self.failures {
+                        Self::read_failures_into_simulation(
+                            failures,
+                            simulation,
+                            self.recorder.as_mut(),
+                        );
+                        Self::publish_induced_failures(
+                            failures,
+                            simulation,
+                            self.recorder.as_mut(),
+                        );
                     }
 
-                    simulation.tick(delta_time, self.time.simulation_time(), self);
+                    self.tick(simulation, delta_time);
+                    if let Some(failures) = &mut self.failures {
+                        Self::publish_induced_failures(
+                            failures,
+                            simulation,
+                            self.recorder.as_mut(),
+                        );
+                    }
                     self.post_tick(sim_connect)?;
                 }
             }
@@ -215,6 +291,24 @@ impl MsfsHandler {
         Ok(())
     }
 
+    fn tick<T: Aircraft>(&mut self, simulation: &mut Simulation<T>, delta_time: Duration) {
+        let simulation_time = self.time.simulation_time();
+        if let Some(mut recorder) = self.recorder.take() {
+            simulation.tick(
+                delta_time,
+                simulation_time,
+                &mut recorder.reader_writer(self),
+            );
+
+            // Recording stops when the file can no longer be written to, the flight continues.
+            if recorder.finish_tick(delta_time, simulation_time).is_ok() {
+                self.recorder = Some(recorder);
+            }
+        } else {
+            simulation.tick(delta_time, simulation_time, self);
+        }
+    }
+
     fn handle_message(&mut self, message: &SimConnectRecv) {
         if let Some(mut variables) = self.variables.take() {
             for aspect in self.aspects.iter_mut() {
@@ -261,15 +355,55 @@ impl MsfsHandler {
     fn read_failures_into_simulation<T: Aircraft>(
         failures: &Failures,
         simulation: &mut Simulation<T>,
+        mut recorder: Option<&mut Recorder<BufWriter<File>>>,
     ) {
-        if let Some(failure_type) = failures.read_failure_activate() {
+        if let Some((identifier, failure_type)) = failures.read_failure_activate() {
             simulation.activate_failure(failure_type);
+            if let Some(recorder) = recorder.as_mut() {
+                recorder.record_failure(identifier, true);
+            }
         }
 
-        if let Some(failure_type) = failures.read_failure_deactivate() {
+        if let Some((identifier, failure_type)) = failures.read_failure_deactivate() {
             simulation.deactivate_failure(failure_type);
+            if let Some(recorder) = recorder.as_mut() {
+                recorder.record_failure(identifier, false);
+            }
+        }
+
+        if let Some((failure_type, trigger)) = failures.read_failure_arm() {
+            simulation.arm_failure(failure_type, trigger);
+        }
+
+        if let Some(failure_type) = failures.read_failure_disarm() {
+            simulation.disarm_failure(failure_type);
         }
     }
+
+    /// Failures activated by the simulation itself are written back for the failures
+    /// orchestrator, so that they are shown as active and can be deactivated like any other
+    /// failure. They are recorded as if they were activated by the simulator before the next
+    /// tick, which is when they take effect.
+    fn publish_induced_failures<T: Aircraft>(
+        failures: &mut Failures,
+        simulation: &mut Simulation<T>,
+        mut recorder: Option<&mut Recorder<BufWriter<File>>>,
+    ) {
+        for induced_failure in simulation.take_induced_failures() {
+            if let Some(identifier) = failures.identifier_of(induced_failure.failure_type()) {
+                let cause = match induced_failure.origin() {
+                    FailureOrigin::Cascaded(cause) => failures.identifier_of(cause).unwrap_or(0),
+                    FailureOrigin::Commanded | FailureOrigin::Scheduled => 0,
+                };
+                failures.queue_induced_failure(identifier, cause);
+                if let Some(recorder) = recorder.as_mut() {
+                    recorder.record_failure(identifier, true);
+                }
+            }
+        }
+
+        failures.write_induced_failure();
+    }
 }
 impl SimulatorReaderWriter for MsfsHandler {
     fn read(&mut self, identifier: &VariableIdentifier) -> f64 {